@@ -0,0 +1,76 @@
+#[macro_use]
+extern crate criterion;
+
+#[path = "../tests/utils/mod.rs"]
+#[macro_use]
+mod utils;
+
+inject_indy_dependencies!();
+
+extern crate indyrs as indy;
+extern crate indyrs as api;
+extern crate indy_sys;
+
+use crate::utils::{anoncreds, blob_storage};
+use crate::utils::constants::*;
+use crate::utils::Setup;
+
+use indy::WalletHandle;
+
+use criterion::{Criterion, Benchmark};
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const MAX_CRED_NUMS: [u32; 2] = [1000, 10000];
+const THREAD_POOL_SIZES: [usize; 3] = [1, 2, 4];
+
+// every iteration needs new tag as existing revocation registry is returned without tails generation
+static TAG_SEQ: AtomicUsize = AtomicUsize::new(0);
+
+fn create_revoc_reg(wallet_handle: WalletHandle, cred_def_id: &str, tails_writer_handle: i32, max_cred_num: u32) {
+    let tag = format!("bench_{}", TAG_SEQ.fetch_add(1, Ordering::SeqCst));
+
+    anoncreds::issuer_create_and_store_revoc_reg(wallet_handle,
+                                                 ISSUER_DID,
+                                                 None,
+                                                 &tag,
+                                                 cred_def_id,
+                                                 &json!({"max_cred_num": max_cred_num, "issuance_type": "ISSUANCE_ON_DEMAND"}).to_string(),
+                                                 tails_writer_handle).unwrap();
+}
+
+fn bench(c: &mut Criterion) {
+    let setup = Setup::wallet();
+
+    let (cred_def_id, _) = anoncreds::issuer_create_credential_definition(setup.wallet_handle,
+                                                                         ISSUER_DID,
+                                                                         &anoncreds::gvt_schema_json(),
+                                                                         TAG_1,
+                                                                         None,
+                                                                         Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+    let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+    let wallet_handle = setup.wallet_handle;
+
+    for &max_cred_num in MAX_CRED_NUMS.iter() {
+        for &thread_pool_size in THREAD_POOL_SIZES.iter() {
+            indy::set_runtime_config(&json!({"crypto_thread_pool_size": thread_pool_size}).to_string());
+
+            let cred_def_id = cred_def_id.clone();
+
+            c.bench(
+                &format!("tails_generation_{}", max_cred_num),
+                Benchmark::new(
+                    format!("threads_{}", thread_pool_size),
+                    move |b| b.iter(|| create_revoc_reg(wallet_handle, &cred_def_id, tails_writer_handle, max_cred_num))
+                ).sample_size(10),
+            );
+        }
+    }
+
+    indy::set_runtime_config(r#"{"crypto_thread_pool_size": 4}"#);
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
@@ -89,6 +89,60 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
     
+    extern indy_error_t indy_issuer_create_revoc_reg_pool(indy_handle_t command_handle,
+                                                          indy_handle_t wallet_handle,
+                                                          const char *  issuer_did,
+                                                          const char *  cred_def_id,
+                                                          const char *  config_json,
+                                                          indy_handle_t tails_writer_handle,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   revoc_reg_pool_json)
+                                                          );
+
+    extern indy_error_t indy_issuer_create_credential_from_revoc_reg_pool(indy_handle_t command_handle,
+                                                                          indy_handle_t wallet_handle,
+                                                                          const char *  cred_offer_json,
+                                                                          const char *  cred_req_json,
+                                                                          const char *  cred_values_json,
+                                                                          indy_handle_t blob_storage_reader_handle,
+
+                                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                                               indy_error_t  err,
+                                                                                               const char*   cred_json,
+                                                                                               const char*   cred_revoc_id,
+                                                                                               const char*   revoc_reg_delta_json)
+                                                                          );
+
+    extern indy_error_t indy_issuer_rollover_revoc_reg_pool(indy_handle_t command_handle,
+                                                            indy_handle_t wallet_handle,
+                                                            const char *  cred_def_id,
+                                                            indy_handle_t tails_writer_handle,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   revoc_reg_pool_json)
+                                                            );
+
+    extern indy_error_t indy_issuer_get_revoc_reg_pool(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  cred_def_id,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   revoc_reg_pool_json)
+                                                       );
+
+    extern indy_error_t indy_issuer_mark_revoc_reg_published(indy_handle_t command_handle,
+                                                             indy_handle_t wallet_handle,
+                                                             const char *  cred_def_id,
+                                                             const char *  rev_reg_id,
+
+                                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                                  indy_error_t  err)
+                                                             );
+
     extern indy_error_t indy_issuer_revoke_credential(indy_handle_t command_handle,
                                                       indy_handle_t wallet_handle,
                                                       indy_handle_t blob_storage_reader_handle,
@@ -100,6 +154,18 @@ extern "C" {
                                                                            const char*   revoc_reg_delta_json)
                                                       );
 
+    extern indy_error_t indy_issuer_update_revocation_registry(indy_handle_t command_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               indy_handle_t blob_storage_reader_handle,
+                                                               const char *  rev_reg_id,
+                                                               const char *  revoked_ids_json,
+                                                               const char *  unrevoked_ids_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   revoc_reg_delta_json)
+                                                               );
+
 /*    extern indy_error_t indy_issuer_recover_credential(indy_handle_t command_handle,
                                                        indy_handle_t wallet_handle,
                                                        indy_handle_t blob_storage_reader_handle,
@@ -129,6 +195,43 @@ extern "C" {
                                                                               indy_error_t  err,
                                                                               const char*   out_master_secret_id)
                                                          );
+
+    extern indy_error_t indy_prover_export_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  master_secret_id,
+                                                         const char *  recipient_vk,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   encrypted_master_secret_json)
+                                                         );
+
+    extern indy_error_t indy_prover_import_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  my_vk,
+                                                         const char *  encrypted_master_secret_json,
+                                                         const char *  master_secret_id,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   out_master_secret_id)
+                                                         );
+
+    extern indy_error_t indy_prover_get_master_secrets(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   master_secrets_json)
+                                                       );
+
+    extern indy_error_t indy_prover_retire_master_secret(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  master_secret_id,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err)
+                                                         );
     
     
     extern indy_error_t indy_prover_create_credential_req(indy_handle_t command_handle,
@@ -261,6 +364,38 @@ extern "C" {
                                                  );
 
 
+    extern indy_error_t indy_prover_create_proof_auto(indy_handle_t command_handle,
+                                                      indy_handle_t wallet_handle,
+                                                      const char *  proof_req_json,
+                                                      const char *  master_secret_name,
+                                                      const char *  schemas_json,
+                                                      const char *  credential_defs_json,
+                                                      const char *  rev_states_json,
+                                                      const char *  options_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   proof_json,
+                                                                           const char*   requested_credentials_json)
+                                                      );
+
+    extern indy_error_t indy_prover_create_proof_with_revocation_cache(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       const char *  proof_req_json,
+                                                                       const char *  requested_credentials_json,
+                                                                       const char *  master_secret_name,
+                                                                       const char *  schemas_json,
+                                                                       const char *  credential_defs_json,
+                                                                       indy_handle_t blob_storage_reader_handle,
+                                                                       const char *  rev_reg_defs_json,
+                                                                       const char *  rev_reg_deltas_json,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   proof_json)
+                                                                       );
+
+
     extern indy_error_t indy_verifier_verify_proof(indy_handle_t command_handle,
                                                    const char *  proof_request_json,
                                                    const char *  proof_json,
@@ -275,6 +410,33 @@ extern "C" {
                                                    );
 
 
+    extern indy_error_t indy_verifier_verify_proof_with_report(indy_handle_t command_handle,
+                                                               const char *  proof_request_json,
+                                                               const char *  proof_json,
+                                                               const char *  schemas_json,
+                                                               const char *  credential_defs_jsons,
+                                                               const char *  rev_reg_defs_json,
+                                                               const char *  rev_regs_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   verification_report_json)
+                                                               );
+
+
+    extern indy_error_t indy_verifier_verify_proof_with_ledger(indy_handle_t command_handle,
+                                                               indy_handle_t pool_handle,
+                                                               indy_handle_t wallet_handle,
+                                                               const char *  proof_request_json,
+                                                               const char *  proof_json,
+                                                               const char *  options_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   verification_result_json)
+                                                               );
+
+
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_reg_def_json,
@@ -301,6 +463,25 @@ extern "C" {
                                                                           const char*   updated_rev_state_json)
                                                      );
 
+    extern indy_error_t indy_prover_refresh_revocation_states(indy_handle_t command_handle,
+                                                              indy_handle_t pool_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              indy_handle_t blob_storage_reader_handle,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   refreshed_json)
+                                                              );
+
+    extern indy_error_t indy_prover_prune_revocation_states(indy_handle_t command_handle,
+                                                            indy_handle_t wallet_handle,
+                                                            indy_u64_t    timestamp,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   pruned_json)
+                                                            );
+
 
     extern indy_error_t indy_generate_nonce(indy_handle_t command_handle,
                                             void           (*cb)(indy_handle_t command_handle_,
@@ -315,6 +496,61 @@ extern "C" {
                                                                  const char*   res)
                                             );
 
+    extern indy_error_t indy_credential_to_w3c(indy_handle_t command_handle,
+                                               const char *  cred_json,
+                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    const char*   w3c_cred_json)
+                                               );
+
+    extern indy_error_t indy_credential_from_w3c(indy_handle_t command_handle,
+                                                 const char *  w3c_cred_json,
+                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                      indy_error_t  err,
+                                                                      const char*   cred_json)
+                                                 );
+
+    extern indy_error_t indy_proof_to_w3c_presentation(indy_handle_t command_handle,
+                                                       const char *  proof_json,
+                                                       const char *  proof_req_json,
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   w3c_presentation_json)
+                                                       );
+
+    extern indy_error_t indy_proof_from_w3c_presentation(indy_handle_t command_handle,
+                                                         const char *  w3c_presentation_json,
+                                                         const char *  proof_req_json,
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   proof_json)
+                                                         );
+
+    extern indy_error_t indy_encode_credential_values(indy_handle_t command_handle,
+                                                      const char *  typed_values_json,
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   cred_values_json)
+                                                      );
+
+    extern indy_error_t indy_decode_credential_value(indy_handle_t command_handle,
+                                                     const char *  encoded,
+                                                     const char *  encoding_json,
+                                                     void           (*cb)(indy_handle_t command_handle_,
+                                                                          indy_error_t  err,
+                                                                          const char*   raw)
+                                                     );
+
+    extern indy_error_t indy_build_predicate_info(indy_handle_t command_handle,
+                                                  const char *  attr_name,
+                                                  const char *  p_type,
+                                                  const char *  value,
+                                                  const char *  encoding_json,
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   predicate_info_json)
+                                                  );
+
 #ifdef __cplusplus
 }
 #endif
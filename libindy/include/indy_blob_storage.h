@@ -18,6 +18,10 @@ extern "C" {
                                                       void           (*fn)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t handle)
                                                      );
 
+    extern indy_error_t indy_set_tails_progress_callback(const void*  context,
+                                                         void         (*progress_cb)(const void* context, indy_u32_t tails_generated, indy_u32_t tails_total)
+                                                        );
+
 
 #ifdef __cplusplus
 }
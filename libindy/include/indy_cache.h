@@ -88,6 +88,54 @@ extern "C" {
                                                               const char*   cred_def_json)
                                          );
 
+    /// Resolves DID into W3C DID Core compliant DID Document.
+    /// DID Document is assembled from NYM transaction and `endpoint` and `diddocContent` attributes of the DID.
+    /// State proofs of all ledger replies are verified.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// did: DID to resolve. Either unqualified (treated as `did:sov`) or fully qualified DID.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// DID resolution result json:
+    /// {
+    ///     didDocument: W3C DID Document
+    ///     didResolutionMetadata: {
+    ///         contentType: "application/did+ld+json",
+    ///         retrieved: time of retrieving data from the ledger (in seconds since epoch)
+    ///     }
+    ///     didDocumentMetadata: {
+    ///         nymSeqNo: (optional) sequence number of NYM transaction,
+    ///         nymTxnTime: (optional) time of NYM transaction,
+    ///         endpointSeqNo: (optional) sequence number of `endpoint` ATTRIB transaction,
+    ///         diddocContentSeqNo: (optional) sequence number of `diddocContent` ATTRIB transaction,
+    ///         deactivated: (optional) true if NYM has no verkey
+    ///     }
+    /// }
+    extern indy_error_t indy_resolve_did(indy_handle_t command_handle,
+                                         indy_handle_t pool_handle,
+                                         indy_handle_t wallet_handle,
+                                         const char *  did,
+                                         const char *  options_json,
+                                         void          (*cb)(indy_handle_t command_handle_,
+                                                             indy_error_t  err,
+                                                             const char*   resolution_result_json)
+                                        );
+
     /// Purge schema cache.
     ///
     /// EXPERIMENTAL
@@ -68,6 +68,45 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes read request message to validator pool using the given read policy
+    /// instead of the one the pool was opened with.
+    ///
+    /// The request is sent to the validator pool as is. It's assumed that it's already prepared.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json.
+    /// read_policy_json: Read policy json:
+    /// {
+    ///     "retries": int (optional) - the number of additional rounds for a read request
+    ///         which can't be completed by queried nodes (0 by default).
+    ///     "retry_backoff": int (optional) - delay before the first retry in ms (500 by default).
+    ///         Each next retry waits twice as long as the previous one.
+    ///     "hedge_after": int (optional) - if there is no acceptable reply after this delay (in ms)
+    ///         one more node is queried (disabled by default).
+    ///     "full_consensus": bool (optional) - require consensus of pool nodes
+    ///         even if a reply contains valid state proof (false by default).
+    /// }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_submit_request_with_policy(indy_handle_t command_handle,
+                                                        indy_handle_t pool_handle,
+                                                        const char *  request_json,
+                                                        const char *  read_policy_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_result_json)
+                                                       );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -567,6 +606,55 @@ extern "C" {
                                                                             const char*   request_json)
                                                        );
 
+    /// Parse a GET_VALIDATOR_INFO response to get typed information about pool nodes.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_validator_info_response: response of GET_VALIDATOR_INFO request
+    ///     (map of node aliases to node replies as returned by indy_submit_action).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Validator info json: map of node aliases to node information or the reason why the node didn't provide it:
+    /// {
+    ///     <node alias>: {
+    ///         "validator_info": {
+    ///             "alias": string,
+    ///             "did": string (optional),
+    ///             "verkey": string (optional),
+    ///             "timestamp": int (optional),
+    ///             "node_info": {
+    ///                 "name", "mode", "client_port", "node_port", "bls_key",
+    ///                 "metrics": {"uptime": int, "transaction_count": {<ledger>: int}},
+    ///                 "committed_ledger_root_hashes": {<ledger id>: string},
+    ///                 "view_change_status": {"view_no": int, "vc_in_progress": bool, "last_complete_view_no": int},
+    ///                 "catchup_status": {"ledger_statuses": {<ledger id>: string}, "number_txns_in_catchup": {<ledger id>: int}}
+    ///             } (optional),
+    ///             "pool_info": {
+    ///                 "read_only": bool, "total_nodes_count": int, "f_value": int,
+    ///                 "reachable_nodes_count": int, "unreachable_nodes_count": int,
+    ///                 "reachable_nodes": [[<node alias>, <node rank>]], "unreachable_nodes": [[<node alias>, <node rank>]],
+    ///                 "blacklisted_nodes": [string]
+    ///             } (optional),
+    ///             "software": {"indy_node": string, "sovrin": string, "os_version": string} (optional)
+    ///         }
+    ///     } or {
+    ///         "error": string - "timeout" or reason of rejection
+    ///     },
+    ///     ...
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_parse_get_validator_info_response(indy_handle_t command_handle,
+                                                               const char *  get_validator_info_response,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   validator_info_json)
+                                                               );
+
 
     /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
     ///
@@ -597,6 +685,117 @@ extern "C" {
                                                                         const char*   request_json)
                                                    );
 
+    /// Parse a GET_TXN response to get the transaction in a typed form independent of the ledger transaction format version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_txn_response: response of GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction json:
+    /// {
+    ///     "ver": string - version of the transaction format stored in the ledger ("0" or "1"),
+    ///     "type": string - transaction type code,
+    ///     "typeName": string - one of NODE, NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY,
+    ///                 AUTH_RULE, AUTH_RULES, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML or CUSTOM
+    ///                 (types unknown to libindy, for example payment plugin transactions),
+    ///     "data": object - transaction data corresponding to "typeName":
+    ///         NODE: {"dest": string, "data": {"alias", "node_ip", "node_port", "client_ip", "client_port", "services", "blskey", "blskey_pop"}}
+    ///         NYM: {"dest": string, "verkey": string, "role": string, "alias": string}
+    ///         ATTRIB: {"dest": string, "raw": string, "hash": string, "enc": string}
+    ///         SCHEMA: {"name": string, "version": string, "attr_names": [string]}
+    ///         CRED_DEF: {"ref": int, "signature_type": string, "tag": string, "data": object}
+    ///         REVOC_REG_DEF: {"id": string, "revocDefType": string, "tag": string, "credDefId": string, "value": object}
+    ///         REVOC_REG_ENTRY: {"revocDefType": string, "revocRegDefId": string, "value": object}
+    ///         AUTH_RULE: {"auth_type": string, "auth_action": string, "field": string, "old_value": string, "new_value": string, "constraint": object}
+    ///         AUTH_RULES: {"rules": [<AUTH_RULE data>]}
+    ///         TXN_AUTHR_AGRMT: {"version": string, "text": string, "ratification_ts": int, "retirement_ts": int}
+    ///         TXN_AUTHR_AGRMT_AML: {"version": string, "aml": object, "amlContext": string}
+    ///         CUSTOM: transaction data as it is stored in the ledger
+    ///     "metadata": {
+    ///         "seqNo": int, "txnTime": int, "txnId": string,
+    ///         "from": string - DID of the transaction author, "reqId": int, "digest": string, "payloadDigest": string,
+    ///         "endorser": string - DID of the transaction endorser,
+    ///         "taaAcceptance": {"mechanism": string, "taaDigest": string, "time": int}
+    ///     } - fields are present only if they are set in the transaction
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_txn_response(indy_handle_t command_handle,
+                                                    const char *  get_txn_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   txn_json)
+                                                    );
+
+    /// Extracts an inclusion proof of the transaction from a GET_TXN response.
+    ///
+    /// The proof is self-contained and can be verified later without connection to the pool
+    /// (look at `indy_verify_txn_inclusion_proof`).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_txn_response: response of GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction inclusion proof json:
+    /// {
+    ///     "seqNo": int - sequence number of the transaction,
+    ///     "ledgerSize": int - size of the ledger the audit path was built for,
+    ///     "rootHash": string - base58 encoded root hash of the ledger merkle tree,
+    ///     "auditPath": [string] - base58 encoded hashes from the transaction leaf to the root,
+    ///     "txn": object - transaction as it is stored in the ledger ("txn", "txnMetadata", "reqSignature", "ver"),
+    ///     "multiSignature": object - BLS multi-signature of pool nodes over the root hash
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_extract_txn_inclusion_proof(indy_handle_t command_handle,
+                                                         const char *  get_txn_response,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   proof_json)
+                                                         );
+
+    /// Verifies an inclusion proof of the transaction offline.
+    ///
+    /// Checks that the audit path leads from the transaction to the root hash
+    /// and that the root hash is signed by enough pool nodes with the given BLS keys.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// proof_json: transaction inclusion proof as returned by `indy_extract_txn_inclusion_proof`.
+    /// nodes_bls_keys_json: known BLS keys of pool nodes:
+    ///     {
+    ///         <node alias>: <base58 encoded BLS key>,
+    ///         ...
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// valid: true - if proof is valid, false - otherwise
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_verify_txn_inclusion_proof(indy_handle_t command_handle,
+                                                        const char *  proof_json,
+                                                        const char *  nodes_bls_keys_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             indy_bool_t   valid)
+                                                        );
+
     /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
     ///
     /// #Params
@@ -20,6 +20,11 @@ extern "C" {
                                               void          (*cb)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t pool_handle)
                                               );
     
+    extern indy_error_t indy_open_pool_registry(indy_handle_t command_handle,
+                                                const char *  config,
+                                                void          (*cb)(indy_handle_t command_handle_, indy_error_t err, indy_handle_t pool_handle)
+                                                );
+    
     extern indy_error_t indy_refresh_pool_ledger(indy_handle_t command_handle,
                                                  indy_handle_t handle,
                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
@@ -29,6 +34,11 @@ extern "C" {
                                         void          (*fn)(indy_handle_t command_handle_, indy_error_t err, const char *const pools)
                                         );
     
+    extern indy_error_t indy_get_pool_status(indy_handle_t command_handle,
+                                             indy_handle_t handle,
+                                             void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const pool_status_json)
+                                             );
+    
     extern indy_error_t indy_close_pool_ledger(indy_handle_t command_handle,
                                                indy_handle_t handle,
                                                void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
@@ -1,4 +1,4 @@
-use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, SearchHandle, INVALID_SEARCH_HANDLE};
+use indy_api_types::{ErrorCode, IndyHandle, CommandHandle, WalletHandle, PoolHandle, SearchHandle, INVALID_SEARCH_HANDLE};
 use indy_api_types::errors::prelude::*;
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::anoncreds::AnoncredsCommand;
@@ -10,15 +10,21 @@ use crate::domain::crypto::did::DidValue;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
+use crate::domain::anoncreds::credential_attr_encoding::{AttributeEncoding, TypedCredentialValues};
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use crate::domain::anoncreds::credential::{Credential, CredentialValues};
+use crate::domain::anoncreds::credential_selection::CreateProofAutoOptions;
+use crate::domain::anoncreds::master_secret::EncryptedMasterSecret;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition, RevocationRegistryId, RevocationRegistryDefinitions};
-use crate::domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
+use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltas};
+use crate::domain::anoncreds::revocation_registry_pool::RevocationRegistryPoolConfig;
 use crate::domain::anoncreds::proof::Proof;
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
+use crate::domain::anoncreds::proof_verification::VerifyProofWithLedgerOptions;
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
 use crate::domain::anoncreds::revocation_registry::RevocationRegistries;
 use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+use crate::domain::anoncreds::w3c::{W3CCredential, W3CPresentation};
 use indy_utils::ctypes;
 
 use libc::c_char;
@@ -644,6 +650,318 @@ pub extern fn indy_issuer_create_credential(command_handle: CommandHandle,
     res
 }
 
+/// Create a pool of revocation registries managed by issuer for the given credential definition.
+///
+/// The pool creates the first revocation registry (the same way as indy_issuer_create_and_store_revoc_reg does)
+/// and tracks usage of registries in the wallet.
+/// Credentials are issued from the pool by indy_issuer_create_credential_from_revoc_reg_pool
+/// which switches to the next registry when active one is full.
+/// The next registry is generated ahead of time by indy_issuer_rollover_revoc_reg_pool
+/// (`rollover_needed` field of the pool is set when usage of active registry reaches the threshold).
+///
+/// Definitions and initial entries of generated registries must be published to the ledger
+/// (see `unpublished` field of the result) and marked by indy_issuer_mark_revoc_reg_published.
+/// The pool never switches to the next registry until it is marked published.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// issuer_did: a DID of the issuer
+/// cred_def_id: id of stored in ledger credential definition
+/// config_json: type-specific configuration of revocation registry pool as json:
+///     {
+///         "issuance_type": (optional) type of issuance. Currently supported:
+///             1) ISSUANCE_BY_DEFAULT: all indices are assumed to be issued and initial accumulator is calculated over all indices;
+///                Revocation Registry is updated only during revocation.
+///             2) ISSUANCE_ON_DEMAND: nothing is issued initially accumulator is 1 (used by default);
+///         "max_cred_num": maximum number of credentials the each registry can process (default 100000).
+///         "tag_prefix": (optional) prefix of registry tags, registries are tagged as <tag_prefix><number> ("pool_" by default).
+///         "rollover_threshold": (optional) percentage of registry capacity which usage sets `rollover_needed` flag (90 by default).
+///     }
+/// tails_writer_handle: handle of blob storage to store tails
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_pool_json: revocation registry pool json
+///     {
+///         "cred_def_id": string,
+///         "issuer_did": string,
+///         "config": <see config_json above>,
+///         "active": string, - id of revocation registry used for issuance
+///         "next": Optional<string>, - id of pre-generated revocation registry
+///         "registries": [string], - ids of all registries of the pool
+///         "unpublished": [{ - registries which still need publishing to the ledger
+///             "rev_reg_id": string,
+///             "rev_reg_def": <revocation registry definition json>, - for indy_build_revoc_reg_def_request
+///             "rev_reg_entry": <revocation registry json>, - initial entry for indy_build_revoc_reg_entry_request
+///         }],
+///         "rollover_needed": bool, - the next registry should be generated by indy_issuer_rollover_revoc_reg_pool
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_revoc_reg_pool(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                issuer_did: *const c_char,
+                                                cred_def_id: *const c_char,
+                                                config_json: *const c_char,
+                                                tails_writer_handle: IndyHandle,
+                                                cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                     revoc_reg_pool_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_revoc_reg_pool: >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle);
+
+    check_useful_validatable_string!(issuer_did, ErrorCode::CommonInvalidParam3, DidValue);
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam4, CredentialDefinitionId);
+    check_useful_validatable_json!(config_json, ErrorCode::CommonInvalidParam5, RevocationRegistryPoolConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_issuer_create_revoc_reg_pool: entities >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}, tails_writer_handle: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateRevocationRegistryPool(
+                    wallet_handle,
+                    issuer_did,
+                    cred_def_id,
+                    config_json,
+                    tails_writer_handle,
+                    boxed_callback_string!("indy_issuer_create_revoc_reg_pool", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_revoc_reg_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Issue Credential for the given Cred Request using active revocation registry of the pool
+/// created by indy_issuer_create_revoc_reg_pool.
+///
+/// If active revocation registry is full the pool switches to the next one once it is published
+/// (AnoncredsRevocationRegistryFullError is returned while the next registry is not generated or published).
+/// If usage of active revocation registry reaches the pool threshold `rollover_needed` flag of the pool is set
+/// (see indy_issuer_get_revoc_reg_pool). Registries are never generated by this call.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_offer_json: a cred offer created by indy_issuer_create_credential_offer
+/// cred_req_json: a credential request created by indy_prover_create_credential_req
+/// cred_values_json: a credential containing attribute values for each of requested attribute names (see indy_issuer_create_credential)
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json: Credential json containing signed credential values (see indy_issuer_create_credential)
+/// cred_revoc_id: local id for revocation info (Can be used for revocation of this credential)
+/// revoc_reg_delta_json: Revocation registry delta json with a newly issued credential
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_create_credential_from_revoc_reg_pool(command_handle: CommandHandle,
+                                                                wallet_handle: WalletHandle,
+                                                                cred_offer_json: *const c_char,
+                                                                cred_req_json: *const c_char,
+                                                                cred_values_json: *const c_char,
+                                                                blob_storage_reader_handle: IndyHandle,
+                                                                cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                     cred_json: *const c_char,
+                                                                                     cred_revoc_id: *const c_char,
+                                                                                     revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_create_credential_from_revoc_reg_pool: >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, \
+    blob_storage_reader_handle: {:?}", wallet_handle, cred_offer_json, cred_req_json, cred_values_json, blob_storage_reader_handle);
+
+    check_useful_validatable_json!(cred_offer_json, ErrorCode::CommonInvalidParam3, CredentialOffer);
+    check_useful_validatable_json!(cred_req_json, ErrorCode::CommonInvalidParam4, CredentialRequest);
+    check_useful_validatable_json!(cred_values_json, ErrorCode::CommonInvalidParam5, CredentialValues);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_issuer_create_credential_from_revoc_reg_pool: entities >>> wallet_handle: {:?}, cred_offer_json: {:?}, cred_req_json: {:?}, cred_values_json: {:?}, \
+    blob_storage_reader_handle: {:?}", wallet_handle, cred_offer_json, secret!(&cred_req_json), secret!(&cred_values_json), blob_storage_reader_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::CreateCredentialFromRevocationRegistryPool(
+                    wallet_handle,
+                    cred_offer_json,
+                    cred_req_json,
+                    cred_values_json,
+                    blob_storage_reader_handle,
+                    Box::new(move |result| {
+                        let (err, cred_json, revoc_id, revoc_reg_delta_json) = prepare_result_3!(result, String::new(), None, None);
+                        trace!("indy_issuer_create_credential_from_revoc_reg_pool: cred_json: {:?}, revoc_id: {:?}, revoc_reg_delta_json: {:?}",
+                               secret!(cred_json.as_str()), secret!(&revoc_id), revoc_reg_delta_json);
+                        let cred_json = ctypes::string_to_cstring(cred_json);
+                        let revoc_id = revoc_id.map(ctypes::string_to_cstring);
+                        let revoc_reg_delta_json = revoc_reg_delta_json.map(ctypes::string_to_cstring);
+                        cb(command_handle, err, cred_json.as_ptr(),
+                           revoc_id.as_ref().map(|id| id.as_ptr()).unwrap_or(ptr::null()),
+                           revoc_reg_delta_json.as_ref().map(|delta| delta.as_ptr()).unwrap_or(ptr::null()))
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_create_credential_from_revoc_reg_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Generate the next revocation registry of the pool created by indy_issuer_create_revoc_reg_pool ahead of time.
+/// Does nothing if the pool already has the next registry.
+///
+/// The generated registry is added to `unpublished` list of the pool: it must be published to the ledger
+/// and marked by indy_issuer_mark_revoc_reg_published before active registry is full.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition
+/// tails_writer_handle: handle of blob storage to store tails
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_pool_json: revocation registry pool json (see indy_issuer_create_revoc_reg_pool)
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_rollover_revoc_reg_pool(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  cred_def_id: *const c_char,
+                                                  tails_writer_handle: IndyHandle,
+                                                  cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                       revoc_reg_pool_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_rollover_revoc_reg_pool: >>> wallet_handle: {:?}, cred_def_id: {:?}, tails_writer_handle: {:?}", wallet_handle, cred_def_id, tails_writer_handle);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_issuer_rollover_revoc_reg_pool: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, tails_writer_handle: {:?}", wallet_handle, cred_def_id, tails_writer_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::RolloverRevocationRegistryPool(
+                    wallet_handle,
+                    cred_def_id,
+                    tails_writer_handle,
+                    boxed_callback_string!("indy_issuer_rollover_revoc_reg_pool", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_rollover_revoc_reg_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Get revocation registry pool stored in the wallet for the given credential definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_pool_json: revocation registry pool json (see indy_issuer_create_revoc_reg_pool)
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_get_revoc_reg_pool(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cred_def_id: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                  revoc_reg_pool_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_revoc_reg_pool: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_get_revoc_reg_pool: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetRevocationRegistryPool(
+                    wallet_handle,
+                    cred_def_id,
+                    boxed_callback_string!("indy_issuer_get_revoc_reg_pool", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_revoc_reg_pool: <<< res: {:?}", res);
+
+    res
+}
+
+/// Mark revocation registry of the pool as published to the ledger.
+/// The registry is removed from `unpublished` list of the pool.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition
+/// rev_reg_id: id of published revocation registry
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_mark_revoc_reg_published(command_handle: CommandHandle,
+                                                   wallet_handle: WalletHandle,
+                                                   cred_def_id: *const c_char,
+                                                   rev_reg_id: *const c_char,
+                                                   cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_mark_revoc_reg_published: >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}", wallet_handle, cred_def_id, rev_reg_id);
+
+    check_useful_validatable_string!(cred_def_id, ErrorCode::CommonInvalidParam3, CredentialDefinitionId);
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam4, RevocationRegistryId);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_issuer_mark_revoc_reg_published: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}", wallet_handle, cred_def_id, rev_reg_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::MarkRevocationRegistryPublished(
+                    wallet_handle,
+                    cred_def_id,
+                    rev_reg_id,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_mark_revoc_reg_published:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_mark_revoc_reg_published: <<< res: {:?}", res);
+
+    res
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -711,6 +1029,81 @@ pub extern fn indy_issuer_revoke_credential(command_handle: CommandHandle,
     res
 }
 
+/// Revoke and unrevoke a batch of credentials identified by cred_revoc_ids (returned by indy_issuer_create_credential).
+///
+/// The corresponding credential definition and revocation registry must be already
+/// created an stored into the wallet.
+///
+/// Revocation registry accumulator is updated for all the given ids at once and stored into the wallet
+/// only if all the ids are processed successfully.
+/// This call returns a single revoc registry delta as json file intended to be shared as REVOC_REG_ENTRY transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_cfg_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`).
+/// rev_reg_id: id of revocation registry stored in wallet
+/// revoked_ids_json: local ids for revocation info related to issued credentials to revoke
+///     [<cred_revoc_id>, ...]
+/// unrevoked_ids_json: local ids for revocation info related to previously revoked credentials to unrevoke
+///     [<cred_revoc_id>, ...]
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// revoc_reg_delta_json: Revocation registry delta json with revoked and unrevoked credentials
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of unrevoked indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_update_revocation_registry(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     blob_storage_reader_cfg_handle: IndyHandle,
+                                                     rev_reg_id: *const c_char,
+                                                     revoked_ids_json: *const c_char,
+                                                     unrevoked_ids_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          revoc_reg_delta_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_update_revocation_registry: >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, revoked_ids_json: {:?}, unrevoked_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, revoked_ids_json, unrevoked_ids_json);
+
+    check_useful_validatable_string!(rev_reg_id, ErrorCode::CommonInvalidParam4, RevocationRegistryId);
+    check_useful_json!(revoked_ids_json, ErrorCode::CommonInvalidParam5, Vec<String>);
+    check_useful_json!(unrevoked_ids_json, ErrorCode::CommonInvalidParam6, Vec<String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_issuer_update_revocation_registry: entities >>> wallet_handle: {:?}, blob_storage_reader_cfg_handle: {:?}, rev_reg_id: {:?}, revoked_ids_json: {:?}, unrevoked_ids_json: {:?}",
+           wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, secret!(&revoked_ids_json), secret!(&unrevoked_ids_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::UpdateRevocationRegistry(
+                    wallet_handle,
+                    blob_storage_reader_cfg_handle,
+                    rev_reg_id,
+                    revoked_ids_json,
+                    unrevoked_ids_json,
+                    boxed_callback_string!("indy_issuer_update_revocation_registry", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_update_revocation_registry: <<< res: {:?}", res);
+
+    res
+}
+
 /*/// Recover a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -770,116 +1163,315 @@ pub extern fn indy_issuer_recover_credential(command_handle: CommandHandle,
                     })
                 ))));
 
-    prepare_result!(result)
-}*/
+    prepare_result!(result)
+}*/
+
+/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
+/// Send common delta to ledger to reduce the load.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// rev_reg_delta_json: revocation registry delta.
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of issued indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// other_rev_reg_delta_json: revocation registry delta for which PrevAccum value is equal to value of accum field of rev_reg_delta_json parameter.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// merged_rev_reg_delta: Merged revocation registry delta
+/// {
+///     value: {
+///         prevAccum: string - previous accumulator value.
+///         accum: string - current accumulator value.
+///         issued: array<number> an array of issued indices.
+///         revoked: array<number> an array of revoked indices.
+///     },
+///     ver: string - version revocation registry delta json
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
+                                                           rev_reg_delta_json: *const c_char,
+                                                           other_rev_reg_delta_json: *const c_char,
+                                                           cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                merged_rev_reg_delta: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_merge_revocation_registry_deltas: >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
+           rev_reg_delta_json, other_rev_reg_delta_json);
+
+    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam2, RevocationRegistryDelta);
+    check_useful_validatable_json!(other_rev_reg_delta_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDelta);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_merge_revocation_registry_deltas: entities >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
+           rev_reg_delta_json, other_rev_reg_delta_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::MergeRevocationRegistryDeltas(
+                    rev_reg_delta_json,
+                    other_rev_reg_delta_json,
+                    boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_merge_revocation_registry_deltas: <<< res: {:?}", res);
+
+    res
+}
+
+/// Creates a master secret with a given id and stores it in the wallet.
+/// The id must be unique.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// master_secret_id: (optional, if not present random one will be generated) new master id
+///
+/// #Returns
+/// out_master_secret_id: Id of generated master secret
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_create_master_secret(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               master_secret_id: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    out_master_secret_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_prover_create_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::CreateMasterSecret(
+                    wallet_handle,
+                    master_secret_id,
+                    boxed_callback_string!("indy_prover_create_master_secret", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_create_master_secret: <<< res: {:?}", res);
+
+    res
+}
+
+/// Exports a master secret stored in the wallet encrypted for the recipient key.
+///
+/// Master secret is encrypted with anonymous encryption (see indy_crypto_anon_crypt),
+/// so only owner of the recipient key is able to import it (see indy_prover_import_master_secret).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// master_secret_id: id of master secret to export
+/// recipient_vk: verkey of the recipient (usually key stored in the wallet of another device)
+///
+/// #Returns
+/// encrypted_master_secret_json: encrypted master secret
+///     {
+///         "ciphertext": string - base64 encoded encrypted master secret with its id
+///     }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_prover_export_master_secret(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               master_secret_id: *const c_char,
+                                               recipient_vk: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    encrypted_master_secret_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_export_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}, recipient_vk: {:?}", wallet_handle, master_secret_id, recipient_vk);
+
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(recipient_vk, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_prover_export_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}, recipient_vk: {:?}", wallet_handle, master_secret_id, recipient_vk);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ExportMasterSecret(
+                    wallet_handle,
+                    master_secret_id,
+                    recipient_vk,
+                    boxed_callback_string!("indy_prover_export_master_secret", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_export_master_secret: <<< res: {:?}", res);
+
+    res
+}
+
+/// Imports a master secret exported by indy_prover_export_master_secret into the wallet.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// my_vk: verkey the master secret was encrypted for. The key must be stored in the wallet.
+/// encrypted_master_secret_json: encrypted master secret returned by indy_prover_export_master_secret
+/// master_secret_id: (optional, if not present id of exported master secret will be used) id of imported master secret
+///
+/// #Returns
+/// out_master_secret_id: Id of imported master secret
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_prover_import_master_secret(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               my_vk: *const c_char,
+                                               encrypted_master_secret_json: *const c_char,
+                                               master_secret_id: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    out_master_secret_id: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_import_master_secret: >>> wallet_handle: {:?}, my_vk: {:?}, encrypted_master_secret_json: {:?}, master_secret_id: {:?}",
+           wallet_handle, my_vk, encrypted_master_secret_json, master_secret_id);
+
+    check_useful_c_str!(my_vk, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(encrypted_master_secret_json, ErrorCode::CommonInvalidParam4, EncryptedMasterSecret);
+    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_prover_import_master_secret: entities >>> wallet_handle: {:?}, my_vk: {:?}, encrypted_master_secret_json: {:?}, master_secret_id: {:?}",
+           wallet_handle, my_vk, encrypted_master_secret_json, master_secret_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Prover(
+                ProverCommand::ImportMasterSecret(
+                    wallet_handle,
+                    my_vk,
+                    encrypted_master_secret_json,
+                    master_secret_id,
+                    boxed_callback_string!("indy_prover_import_master_secret", cb, command_handle)
+                ))));
+
+    let res = prepare_result!(result);
 
-/// Merge two revocation registry deltas (returned by indy_issuer_create_credential or indy_issuer_revoke_credential) to accumulate common delta.
-/// Send common delta to ledger to reduce the load.
+    trace!("indy_prover_import_master_secret: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets master secrets stored in the wallet together with credentials bound to each of them.
+///
+/// NOTE: Credentials stored before master secret binding was tracked don't keep id of their master secret.
+/// If the wallet has a single master secret such credentials are bound to it, otherwise they are not listed.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
-/// rev_reg_delta_json: revocation registry delta.
-/// {
-///     value: {
-///         prevAccum: string - previous accumulator value.
-///         accum: string - current accumulator value.
-///         issued: array<number> an array of issued indices.
-///         revoked: array<number> an array of revoked indices.
-///     },
-///     ver: string - version revocation registry delta json
-/// }
-///
-/// other_rev_reg_delta_json: revocation registry delta for which PrevAccum value is equal to value of accum field of rev_reg_delta_json parameter.
-/// cb: Callback that takes command result as parameter.
+/// wallet_handle: wallet handle (created by open_wallet).
 ///
 /// #Returns
-/// merged_rev_reg_delta: Merged revocation registry delta
-/// {
-///     value: {
-///         prevAccum: string - previous accumulator value.
-///         accum: string - current accumulator value.
-///         issued: array<number> an array of issued indices.
-///         revoked: array<number> an array of revoked indices.
-///     },
-///     ver: string - version revocation registry delta json
-/// }
+/// master_secrets_json: list of master secrets
+///     [{
+///         "id": string, - id of master secret
+///         "retired_at": Optional<int>, - time of retirement of master secret (see indy_prover_retire_master_secret)
+///         "cred_ids": [string] - ids of credentials bound to master secret
+///     }]
 ///
 /// #Errors
 /// Anoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
-                                                           rev_reg_delta_json: *const c_char,
-                                                           other_rev_reg_delta_json: *const c_char,
-                                                           cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
-                                                                                merged_rev_reg_delta: *const c_char)>) -> ErrorCode {
-    trace!("indy_issuer_merge_revocation_registry_deltas: >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
-           rev_reg_delta_json, other_rev_reg_delta_json);
-
-    check_useful_validatable_json!(rev_reg_delta_json, ErrorCode::CommonInvalidParam2, RevocationRegistryDelta);
-    check_useful_validatable_json!(other_rev_reg_delta_json, ErrorCode::CommonInvalidParam3, RevocationRegistryDelta);
-    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+pub extern fn indy_prover_get_master_secrets(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                  master_secrets_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_get_master_secrets: >>> wallet_handle: {:?}", wallet_handle);
 
-    trace!("indy_issuer_merge_revocation_registry_deltas: entities >>> rev_reg_delta_json: {:?}, other_rev_reg_delta_json: {:?}",
-           rev_reg_delta_json, other_rev_reg_delta_json);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
 
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
-            AnoncredsCommand::Issuer(
-                IssuerCommand::MergeRevocationRegistryDeltas(
-                    rev_reg_delta_json,
-                    other_rev_reg_delta_json,
-                    boxed_callback_string!("indy_issuer_merge_revocation_registry_deltas", cb, command_handle)
+            AnoncredsCommand::Prover(
+                ProverCommand::GetMasterSecrets(
+                    wallet_handle,
+                    boxed_callback_string!("indy_prover_get_master_secrets", cb, command_handle)
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_issuer_merge_revocation_registry_deltas: <<< res: {:?}", res);
+    trace!("indy_prover_get_master_secrets: <<< res: {:?}", res);
 
     res
 }
 
-/// Creates a master secret with a given id and stores it in the wallet.
-/// The id must be unique.
+/// Marks a master secret as retired, for example after suspected compromise.
+///
+/// Retired master secret can't be used for new credential requests,
+/// but credentials bound to it remain provable until they are re-issued for a new master secret.
 ///
 /// #Params
 /// command_handle: command handle to map callback to user context.
 /// wallet_handle: wallet handle (created by open_wallet).
-/// master_secret_id: (optional, if not present random one will be generated) new master id
-///
-/// #Returns
-/// out_master_secret_id: Id of generated master secret
+/// master_secret_id: id of master secret to retire
 ///
 /// #Errors
 /// Anoncreds*
 /// Common*
 /// Wallet*
 #[no_mangle]
-pub extern fn indy_prover_create_master_secret(command_handle: CommandHandle,
+pub extern fn indy_prover_retire_master_secret(command_handle: CommandHandle,
                                                wallet_handle: WalletHandle,
                                                master_secret_id: *const c_char,
-                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
-                                                                    out_master_secret_id: *const c_char)>) -> ErrorCode {
-    trace!("indy_prover_create_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+                                               cb: Option<extern fn(
+                                                   command_handle_: CommandHandle,
+                                                   err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_prover_retire_master_secret: >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
 
-    check_useful_opt_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam3);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    trace!("indy_prover_create_master_secret: entities >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
-
     let result = CommandExecutor::instance()
         .send(Command::Anoncreds(
             AnoncredsCommand::Prover(
-                ProverCommand::CreateMasterSecret(
+                ProverCommand::RetireMasterSecret(
                     wallet_handle,
                     master_secret_id,
-                    boxed_callback_string!("indy_prover_create_master_secret", cb, command_handle)
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_prover_retire_master_secret: ");
+                        cb(command_handle, err)
+                    })
                 ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_prover_create_master_secret: <<< res: {:?}", res);
+    trace!("indy_prover_retire_master_secret: <<< res: {:?}", res);
 
     res
 }
@@ -1109,6 +1701,7 @@ pub extern fn indy_prover_get_credential_attr_tag_policy(command_handle: Command
 ///         "issuer_did": <credential issuer did>,
 ///         "cred_def_id": <credential definition id>,
 ///         "rev_reg_id": <credential revocation registry id>, // "None" as string if not present
+///         "created_at": <time credential was stored as a total number of seconds from Unix Epoch>,
 ///         // for every attribute in <credential values> that credential attribute tagging policy marks taggable
 ///         "attr::<attribute name>::marker": "1",
 ///         "attr::<attribute name>::value": <attribute raw value>,
@@ -1925,6 +2518,8 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///         },
 ///     }
 /// Note: use credential_id instead rev_reg_id in case proving several credentials from the same revocation registry.
+/// Revocation states missed in `rev_states_json` are looked up in the wallet
+/// (stored by `indy_prover_create_proof_with_revocation_cache` and `indy_prover_refresh_revocation_states`).
 /// cb: Callback that takes command result as parameter.
 ///
 /// where
@@ -2063,6 +2658,189 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
     res
 }
 
+/// Creates a proof according to the given proof request choosing credentials from the wallet automatically.
+///
+/// For every requested attribute and predicate credentials satisfying it are searched in the wallet
+/// (the same way as `indy_prover_search_credentials_for_proof_req` does) and one of them is chosen
+/// according to selection policy. All attributes are revealed.
+/// Revocable credentials are used for referents requiring non-revocation only if `rev_states_json`
+/// contains a revocation state for them, the newest revocation state fitting the non-revoked interval is used.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// proof_req_json: proof request json (see `indy_prover_create_proof` for the format)
+/// master_secret_id: the id of the master secret stored in the wallet
+/// schemas_json: all schemas participating in the proof request (see `indy_prover_create_proof` for the format)
+/// credential_defs_json: all credential definitions participating in the proof request (see `indy_prover_create_proof` for the format)
+/// rev_states_json: all revocation states participating in the proof request (see `indy_prover_create_proof` for the format)
+/// options_json: (optional) selection options
+///     {
+///         "policy": (optional, "newest" by default) policy used to choose one of suitable credentials:
+///             "newest" - the latest stored credential,
+///             "oldest" - the earliest stored credential,
+///             "fewest_credentials" - minimize the number of different credentials used in the proof,
+///             "prefer_non_revocable" - credentials without revocation support, the latest stored one among them,
+///         "self_attested_attributes": (optional) {
+///             "<attr_referent>": <self attested value>, // used only for attributes without restrictions
+///         }
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof_json: created proof json (see `indy_prover_create_proof` for the format)
+/// requested_credentials_json: the selection made (see `indy_prover_create_proof` for the format)
+///
+/// #Errors
+/// WalletItemNotFound - no credentials satisfy some of the referents, the error message lists them
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_create_proof_auto(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            proof_req_json: *const c_char,
+                                            master_secret_id: *const c_char,
+                                            schemas_json: *const c_char,
+                                            credential_defs_json: *const c_char,
+                                            rev_states_json: *const c_char,
+                                            options_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                 proof_json: *const c_char,
+                                                                 requested_credentials_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_proof_auto: >>> wallet_handle: {:?}, proof_req_json: {:?}, master_secret_id: {:?}, \
+    schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}, options_json: {:?}",
+           wallet_handle, proof_req_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json);
+
+    check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam5, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam6, CredentialDefinitions);
+    check_useful_json!(rev_states_json, ErrorCode::CommonInvalidParam7, RevocationStates);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam8, CreateProofAutoOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_prover_create_proof_auto: entities >>> wallet_handle: {:?}, proof_req_json: {:?}, master_secret_id: {:?}, \
+    schemas_json: {:?}, credential_defs_json: {:?}, rev_states_json: {:?}, options_json: {:?}",
+           wallet_handle, proof_req_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProofAuto(
+            wallet_handle,
+            proof_req_json,
+            master_secret_id,
+            schemas_json,
+            credential_defs_json,
+            rev_states_json,
+            options_json.unwrap_or_default(),
+            Box::new(move |result| {
+                let (err, proof_json, requested_credentials_json) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_prover_create_proof_auto: proof_json: {:?}, requested_credentials_json: {:?}", proof_json, requested_credentials_json);
+                let proof_json = ctypes::string_to_cstring(proof_json);
+                let requested_credentials_json = ctypes::string_to_cstring(requested_credentials_json);
+                cb(command_handle, err, proof_json.as_ptr(), requested_credentials_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_create_proof_auto: <<< res: {:?}", res);
+
+    res
+}
+
+/// Creates a proof according to the given proof request using revocation states stored in the wallet.
+///
+/// Revocation state for every requested credential and timestamp is kept in the wallet by
+/// `(rev_reg_id, cred_rev_id, timestamp)`. If there is no stored state for the requested timestamp
+/// it is built from revocation registry delta provided for this timestamp: the last state stored before
+/// the timestamp is updated by the difference with the registry state (or a new state is created if there is none),
+/// and the result is stored in the wallet for further proofs.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// proof_req_json: proof request json (see `indy_prover_create_proof` for the format)
+/// requested_credentials_json: either a credential or self-attested attribute for each requested attribute (see `indy_prover_create_proof` for the format)
+/// master_secret_id: the id of the master secret stored in the wallet
+/// schemas_json: all schemas participating in the proof request (see `indy_prover_create_proof` for the format)
+/// credential_defs_json: all credential definitions participating in the proof request (see `indy_prover_create_proof` for the format)
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// rev_reg_defs_json: revocation registry definitions of requested credentials
+///     {
+///         "rev_reg_def1_id": <rev_reg_def1>,
+///         "rev_reg_def2_id": <rev_reg_def2>,
+///     }
+/// rev_reg_deltas_json: revocation registry deltas which cover the whole registry existence time till requested timestamps
+///                      (only needed for timestamps without stored revocation states)
+///     {
+///         "rev_reg_def1_id": {
+///             "timestamp1": <rev_reg_delta1>,
+///             "timestamp2": <rev_reg_delta2>,
+///         },
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Proof json (see `indy_prover_create_proof` for the format)
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_create_proof_with_revocation_cache(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             proof_req_json: *const c_char,
+                                                             requested_credentials_json: *const c_char,
+                                                             master_secret_id: *const c_char,
+                                                             schemas_json: *const c_char,
+                                                             credential_defs_json: *const c_char,
+                                                             blob_storage_reader_handle: IndyHandle,
+                                                             rev_reg_defs_json: *const c_char,
+                                                             rev_reg_deltas_json: *const c_char,
+                                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                  proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_create_proof_with_revocation_cache: >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, \
+    master_secret_id: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, blob_storage_reader_handle: {:?}, rev_reg_defs_json: {:?}, rev_reg_deltas_json: {:?}",
+           wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json,
+           blob_storage_reader_handle, rev_reg_defs_json, rev_reg_deltas_json);
+
+    check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_validatable_json!(requested_credentials_json, ErrorCode::CommonInvalidParam4, RequestedCredentials);
+    check_useful_c_str!(master_secret_id, ErrorCode::CommonInvalidParam5);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam6, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam7, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam9, RevocationRegistryDefinitions);
+    check_useful_json!(rev_reg_deltas_json, ErrorCode::CommonInvalidParam10, RevocationRegistryDeltas);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam11);
+
+    trace!("indy_prover_create_proof_with_revocation_cache: entities >>> wallet_handle: {:?}, proof_req_json: {:?}, requested_credentials_json: {:?}, \
+    master_secret_id: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, blob_storage_reader_handle: {:?}, rev_reg_defs_json: {:?}, rev_reg_deltas_json: {:?}",
+           wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json,
+           blob_storage_reader_handle, rev_reg_defs_json, rev_reg_deltas_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::CreateProofWithRevocationCache(
+            wallet_handle,
+            proof_req_json,
+            requested_credentials_json,
+            master_secret_id,
+            schemas_json,
+            credential_defs_json,
+            blob_storage_reader_handle,
+            rev_reg_defs_json,
+            rev_reg_deltas_json,
+            boxed_callback_string!("indy_prover_create_proof_with_revocation_cache", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_create_proof_with_revocation_cache: <<< res: {:?}", res);
+
+    res
+}
+
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
 ///
@@ -2237,24 +3015,210 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
 
     let result = CommandExecutor::instance()
-        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProof(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verifier_verify_proof: valid: {:?}", valid);
+
+                cb(command_handle, err, valid)
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a proof (of multiple credential) resolving all the entities it refers to from the ledger.
+///
+/// Schemas, credential definitions, revocation registry definitions and revocation registries
+/// (for timestamps specified in the proof) are received through the cache (see `indy_get_schema` and `indy_get_cred_def`).
+/// Entities which can't be resolved are reported as failures of the result instead of an error.
+/// Timestamps of revocation registries must belong to non-revoked intervals requested in the proof request.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet) used to cache received ledger entities.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof` for the format)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof` for the format)
+/// options_json:
+///  {
+///    submitterDid: (optional, string) DID to use as submitter of ledger read requests.
+///    cache: (optional, object) cache options applied to all received entities (see `indy_get_schema`).
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verification result json:
+///  {
+///    valid: bool - true if proof is valid, false - otherwise,
+///    failures: [  // every failed check, empty if proof is valid
+///        {
+///            check: string - step of verification which rejected a part of the proof, one of:
+///                   "referents" - requested referent is missing in the proof or received referent is not requested,
+///                   "attribute_value" - revealed raw value does not match its encoding in the proof,
+///                   "restriction" - credential does not satisfy requested restrictions,
+///                   "non_revoked_interval" - non-revocation timestamp is missing or out of requested interval,
+///                   "identifiers" - entity referred by the proof identifiers is missing or can not be resolved,
+///                   "crypto" - cryptographic proof verification (including predicates and non-revocation) failed
+///                              or was not evaluated because entities it refers to are missing,
+///            referent: (optional) string - referent of requested attribute or predicate the failure belongs to,
+///            sub_proof_index: (optional) int - sub proof the failure belongs to,
+///            message: string - human readable details
+///        }
+///    ]
+///  }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_ledger(command_handle: CommandHandle,
+                                                     pool_handle: PoolHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     proof_request_json: *const c_char,
+                                                     proof_json: *const c_char,
+                                                     options_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          verification_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_ledger: >>> pool_handle: {:?}, wallet_handle: {:?}, proof_request_json: {:?}, proof_json: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, proof_request_json, proof_json, options_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam4, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam5, Proof);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam6, VerifyProofWithLedgerOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_verifier_verify_proof_with_ledger: entities >>> pool_handle: {:?}, wallet_handle: {:?}, proof_request_json: {:?}, proof_json: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, proof_request_json, proof_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithLedger(
+            pool_handle,
+            wallet_handle,
+            proof_request_json,
+            proof_json,
+            options_json,
+            boxed_callback_string!("indy_verifier_verify_proof_with_ledger", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_ledger: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies a proof (of multiple credential) the same way as `indy_verifier_verify_proof`
+/// but doesn't stop on the first rejected check and reports the outcome for every requested referent,
+/// every sub proof and the cryptographic proof.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof` for the format)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof` for the format)
+/// schemas_json: all schemas participating in the proof (see `indy_verifier_verify_proof` for the format)
+/// credential_defs_json: all credential definitions participating in the proof (see `indy_verifier_verify_proof` for the format)
+/// rev_reg_defs_json: all revocation registry definitions participating in the proof (see `indy_verifier_verify_proof` for the format)
+/// rev_regs_json: all revocation registries participating in the proof (see `indy_verifier_verify_proof` for the format)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// verification report json:
+///  {
+///    valid: bool - true if every part of the proof is valid, false - otherwise,
+///    attributes: {  // requested attributes and attributes received in the proof but not requested
+///        "<attr_referent>": <referent_report>,
+///    },
+///    predicates: {  // requested predicates and predicates received in the proof but not requested
+///        "<predicate_referent>": <referent_report>,
+///    },
+///    identifiers: [  // one entry per sub proof
+///        { valid: bool, sub_proof_index: int, reasons: [<reason>] }
+///    ],
+///    revocation: [  // one entry per sub proof proving non-revocation
+///        { valid: bool, sub_proof_index: int, reasons: [<reason>] }
+///    ],
+///    crypto: { valid: bool, reasons: [<reason>] }
+///  }
+/// where referent_report:
+///  {
+///    valid: bool,
+///    sub_proof_index: (optional) int - sub proof the referent is proved by,
+///    reasons: [<reason>]
+///  }
+/// reason:
+///  {
+///    code: string - one of:
+///          "missing_in_proof" - requested referent is not present in the proof,
+///          "not_requested" - referent present in the proof is not requested,
+///          "identifier_not_found" - sub proof identifier of the referent is not present in the proof,
+///          "encoding_mismatch" - revealed raw value does not match its encoding in the proof,
+///          "restriction_not_satisfied" - credential does not satisfy requested restrictions,
+///          "timestamp_missing" - non-revocation is requested but the proof doesn't contain timestamp,
+///          "timestamp_out_of_interval" - non-revocation timestamp is before the start or after the end of requested interval,
+///          "schema_not_found" - schema referred by the sub proof is not provided,
+///          "credential_definition_not_found" - credential definition referred by the sub proof is not provided,
+///          "revocation_registry_id_missing" - sub proof has timestamp but no revocation registry id,
+///          "revocation_registry_definition_not_found" - revocation registry definition is not provided,
+///          "revocation_registry_not_found" - revocation registry for the timestamp is not provided,
+///          "crypto_proof_invalid" - cryptographic proof verification (including predicates and non-revocation) failed,
+///          "not_evaluated" - cryptographic proof is not verified because entities it refers to are missing
+///    message: string - human readable details
+///  }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_report(command_handle: CommandHandle,
+                                                     proof_request_json: *const c_char,
+                                                     proof_json: *const c_char,
+                                                     schemas_json: *const c_char,
+                                                     credential_defs_json: *const c_char,
+                                                     rev_reg_defs_json: *const c_char,
+                                                     rev_regs_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          verification_report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_report: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_validatable_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, Schemas);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, CredentialDefinitions);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, RevocationRegistryDefinitions);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, RevocationRegistries);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_with_report: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithReport(
             proof_request_json,
             proof_json,
             schemas_json,
             credential_defs_json,
             rev_reg_defs_json,
             rev_regs_json,
-            Box::new(move |result| {
-                let (err, valid) = prepare_result_1!(result, false);
-                trace!("indy_verifier_verify_proof: valid: {:?}", valid);
-
-                cb(command_handle, err, valid)
-            })
+            boxed_callback_string!("indy_verifier_verify_proof_with_report", cb, command_handle)
         ))));
 
     let res = prepare_result!(result);
 
-    trace!("indy_verifier_verify_proof: <<< res: {:?}", res);
+    trace!("indy_verifier_verify_proof_with_report: <<< res: {:?}", res);
 
     res
 }
@@ -2400,6 +3364,107 @@ pub extern fn indy_update_revocation_state(command_handle: CommandHandle,
     res
 }
 
+/// Refreshes all revocation states stored in the wallet to the current ledger state in one call.
+///
+/// For every stored credential revocation state (see `indy_prover_create_proof_with_revocation_cache`)
+/// the latest one is updated by the current revocation registry delta fetched from the ledger,
+/// and the result is stored in the wallet by the timestamp of the registry state.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// blob_storage_reader_handle: configuration of blob storage reader handle that will allow to read revocation tails (returned by `indy_open_blob_storage_reader`)
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// refreshed_json: list of refreshed revocation states
+///     [{
+///         "rev_reg_id": string,
+///         "cred_rev_id": string,
+///         "timestamp": integer
+///     }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_prover_refresh_revocation_states(command_handle: CommandHandle,
+                                                    pool_handle: PoolHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    blob_storage_reader_handle: IndyHandle,
+                                                    cb: Option<extern fn(
+                                                        command_handle_: CommandHandle, err: ErrorCode,
+                                                        refreshed_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_refresh_revocation_states: >>> pool_handle: {:?}, wallet_handle: {:?}, blob_storage_reader_handle: {:?}",
+           pool_handle, wallet_handle, blob_storage_reader_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::RefreshRevocationStates(
+            pool_handle,
+            wallet_handle,
+            blob_storage_reader_handle,
+            boxed_callback_string!("indy_prover_refresh_revocation_states", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_refresh_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
+/// Deletes revocation states stored in the wallet (see `indy_prover_create_proof_with_revocation_cache`)
+/// which are older than the given timestamp.
+///
+/// The latest stored state of every credential is always kept, as further states are built by updating it.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context
+/// wallet_handle: wallet handle (created by open_wallet).
+/// timestamp: states of revocation registry before this time (in seconds since Unix Epoch) are deleted
+/// cb: Callback that takes command result as parameter
+///
+/// #Returns
+/// pruned_json: list of deleted revocation states
+///     [{
+///         "rev_reg_id": string,
+///         "cred_rev_id": string,
+///         "timestamp": integer
+///     }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+#[no_mangle]
+pub extern fn indy_prover_prune_revocation_states(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  timestamp: u64,
+                                                  cb: Option<extern fn(
+                                                      command_handle_: CommandHandle, err: ErrorCode,
+                                                      pruned_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_prover_prune_revocation_states: >>> wallet_handle: {:?}, timestamp: {:?}", wallet_handle, timestamp);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Prover(ProverCommand::PruneRevocationStates(
+            wallet_handle,
+            timestamp,
+            boxed_callback_string!("indy_prover_prune_revocation_states", cb, command_handle)
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_prover_prune_revocation_states: <<< res: {:?}", res);
+
+    res
+}
+
 
 ///  Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
@@ -2486,3 +3551,388 @@ pub  extern fn indy_to_unqualified(command_handle: CommandHandle,
     res
 }
 
+/// Converts credential (returned by indy_issuer_create_credential) into W3C Verifiable Credential data model.
+///
+/// Credential values are represented as `credentialSubject` attributes.
+/// Anoncreds specific data (signature, signature correctness proof, revocation data and encoded values)
+/// are placed into `proof.proofValue` of `AnonCredsProof2023` type, so the conversion is lossless.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// cred_json: credential json
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// w3c_cred_json: credential in W3C Verifiable Credential data model
+/// {
+///     "@context": ["https://www.w3.org/2018/credentials/v1", <anoncreds context>],
+///     "type": ["VerifiableCredential", "AnonCredsCredential"],
+///     "issuer": string, // fully qualified DID of issuer (unqualified DIDs are qualified with `sov` method)
+///     "credentialSchema": {
+///         "type": "AnonCredsDefinition",
+///         "definition": string, // credential definition id
+///         "schema": string, // schema id
+///         "revocation": Optional<string>, // revocation registry id
+///     },
+///     "credentialSubject": {<attr_name>: <raw_value>, ...},
+///     "proof": {
+///         "type": "AnonCredsProof2023",
+///         "proofValue": string, // base64 encoded anoncreds data
+///     }
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_credential_to_w3c(command_handle: CommandHandle,
+                                     cred_json: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          w3c_cred_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_credential_to_w3c: >>> cred_json: {:?}", cred_json);
+
+    check_useful_validatable_json!(cred_json, ErrorCode::CommonInvalidParam2, Credential);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_credential_to_w3c: entities >>> cred_json: {:?}", secret!(&cred_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::CredentialToW3C(
+            cred_json,
+            boxed_callback_string!("indy_credential_to_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_credential_to_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Converts credential in W3C Verifiable Credential data model (returned by indy_credential_to_w3c)
+/// back into anoncreds credential.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// w3c_cred_json: credential in W3C Verifiable Credential data model
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_json: credential json as returned by indy_issuer_create_credential
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_credential_from_w3c(command_handle: CommandHandle,
+                                       w3c_cred_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode,
+                                                            cred_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_credential_from_w3c: >>> w3c_cred_json: {:?}", w3c_cred_json);
+
+    check_useful_validatable_json!(w3c_cred_json, ErrorCode::CommonInvalidParam2, W3CCredential);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_credential_from_w3c: entities >>> w3c_cred_json: {:?}", secret!(&w3c_cred_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::CredentialFromW3C(
+            w3c_cred_json,
+            boxed_callback_string!("indy_credential_from_w3c", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_credential_from_w3c: <<< res: {:?}", res);
+
+    res
+}
+
+/// Converts proof (returned by indy_prover_create_proof) into W3C Verifiable Presentation data model.
+///
+/// Every sub proof is represented as a Verifiable Credential with revealed attribute values and
+/// proved predicates in `credentialSubject`. Proof request nonce is set as presentation `proof.challenge`.
+/// Anoncreds specific data are placed into `proof.proofValue` fields, so the conversion is lossless.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_json: proof json
+/// proof_req_json: proof request json the proof was created for (used to resolve attribute names)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// w3c_presentation_json: proof in W3C Verifiable Presentation data model
+/// {
+///     "@context": ["https://www.w3.org/2018/credentials/v1", <anoncreds context>],
+///     "type": ["VerifiablePresentation", "AnonCredsPresentation"],
+///     "verifiableCredential": [{
+///         ...  // the same as returned by indy_credential_to_w3c
+///         "credentialSchema": {..., "timestamp": Optional<number>},
+///         "credentialSubject": {
+///             <attr_name>: <raw_value>, // revealed attribute
+///             <attr_name>: [{"type": "AnonCredsPredicate", "predicate": ">=", "value": int}], // proved predicates
+///         },
+///     }],
+///     "proof": {
+///         "type": "AnonCredsPresentationProof2023",
+///         "challenge": string, // proof request nonce
+///         "proofValue": string, // base64 encoded anoncreds data
+///     }
+/// }
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_proof_to_w3c_presentation(command_handle: CommandHandle,
+                                             proof_json: *const c_char,
+                                             proof_req_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  w3c_presentation_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_proof_to_w3c_presentation: >>> proof_json: {:?}, proof_req_json: {:?}", proof_json, proof_req_json);
+
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam2, Proof);
+    check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_proof_to_w3c_presentation: entities >>> proof_json: {:?}, proof_req_json: {:?}", proof_json, proof_req_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::ProofToW3CPresentation(
+            proof_json,
+            proof_req_json,
+            boxed_callback_string!("indy_proof_to_w3c_presentation", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_proof_to_w3c_presentation: <<< res: {:?}", res);
+
+    res
+}
+
+/// Converts proof in W3C Verifiable Presentation data model (returned by indy_proof_to_w3c_presentation)
+/// back into anoncreds proof that can be verified by indy_verifier_verify_proof.
+/// credentialSubject of every credential must match revealed attributes and predicates of the proof
+/// (by attribute name and value), otherwise CommonInvalidStructure error is returned.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// w3c_presentation_json: proof in W3C Verifiable Presentation data model
+/// proof_req_json: proof request json the proof was created for (used to resolve attribute names)
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// proof_json: proof json as returned by indy_prover_create_proof
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_proof_from_w3c_presentation(command_handle: CommandHandle,
+                                               w3c_presentation_json: *const c_char,
+                                               proof_req_json: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_proof_from_w3c_presentation: >>> w3c_presentation_json: {:?}, proof_req_json: {:?}", w3c_presentation_json, proof_req_json);
+
+    check_useful_validatable_json!(w3c_presentation_json, ErrorCode::CommonInvalidParam2, W3CPresentation);
+    check_useful_validatable_json!(proof_req_json, ErrorCode::CommonInvalidParam3, ProofRequest);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_proof_from_w3c_presentation: entities >>> w3c_presentation_json: {:?}, proof_req_json: {:?}", w3c_presentation_json, proof_req_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::ProofFromW3CPresentation(
+            w3c_presentation_json,
+            proof_req_json,
+            boxed_callback_string!("indy_proof_from_w3c_presentation", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_proof_from_w3c_presentation: <<< res: {:?}", res);
+
+    res
+}
+
+/// Encodes credential attribute values according to their types.
+///
+/// Predicates can be proved only for attributes which encoded value fits into 32-bit signed integer,
+/// so types allow to use predicates for dates, timestamps and decimals in the uniform way.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// typed_values_json: credential attribute values with optional encoding
+///     {
+///         "attr1" : string, // raw value which is encoded as "string"
+///         "attr2" : {
+///             "value": string | number | boolean, // raw value
+///             "encoding": Optional<{ // "string" encoding is used by default
+///                 "type": string, // one of:
+///                                 //   "integer" - 32-bit signed integer is encoded as itself
+///                                 //   "string" - 32-bit signed integer is encoded as itself,
+///                                 //              any other string as SHA-256 hash interpreted as big-endian number
+///                                 //   "boolean" - true is encoded as 1, false as 0
+///                                 //   "date" - YYYY-MM-DD date is encoded as number of days since 1970-01-01
+///                                 //   "timestamp" - number of seconds since epoch is encoded as itself
+///                                 //   "decimal" - decimal is encoded as integer multiplied by 10^scale
+///                 "scale": int, // required for "decimal" type only (max 9)
+///             }>
+///         },
+///         ...
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// cred_values_json: credential values json that can be passed to indy_issuer_create_credential
+///     {
+///         "attr1" : {"raw": string, "encoded": string},
+///         ...
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_encode_credential_values(command_handle: CommandHandle,
+                                            typed_values_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 cred_values_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_encode_credential_values: >>> typed_values_json: {:?}", typed_values_json);
+
+    check_useful_validatable_json!(typed_values_json, ErrorCode::CommonInvalidParam2, TypedCredentialValues);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_encode_credential_values: entities >>> typed_values_json: {:?}", secret!(&typed_values_json));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::EncodeCredentialValues(
+            typed_values_json,
+            boxed_callback_string!("indy_encode_credential_values", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_encode_credential_values: <<< res: {:?}", res);
+
+    res
+}
+
+/// Decodes encoded credential attribute value back to raw value.
+///
+/// Note that string values encoded as hash can't be decoded.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// encoded: encoded value
+/// encoding_json: encoding of value (see indy_encode_credential_values)
+///     {
+///         "type": string,
+///         "scale": int, // for "decimal" type only
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// raw: raw value
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_decode_credential_value(command_handle: CommandHandle,
+                                           encoded: *const c_char,
+                                           encoding_json: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode,
+                                                                raw: *const c_char)>) -> ErrorCode {
+    trace!("indy_decode_credential_value: >>> encoded: {:?}, encoding_json: {:?}", encoded, encoding_json);
+
+    check_useful_c_str!(encoded, ErrorCode::CommonInvalidParam2);
+    check_useful_validatable_json!(encoding_json, ErrorCode::CommonInvalidParam3, AttributeEncoding);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_decode_credential_value: entities >>> encoded: {:?}, encoding_json: {:?}", secret!(&encoded), encoding_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::DecodeCredentialValue(
+            encoded,
+            encoding_json,
+            boxed_callback_string!("indy_decode_credential_value", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_decode_credential_value: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds predicate info for proof request from typed value.
+///
+/// For example, predicate "born before 2000-01-01" can be built as
+///     indy_build_predicate_info("birthdate", "<", "2000-01-01", {"type": "date"})
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// attr_name: attribute name
+/// p_type: predicate type (">=", ">", "<=", "<")
+/// value: raw predicate value
+/// encoding_json: encoding of attribute (see indy_encode_credential_values)
+///     {
+///         "type": string, // "integer", "date", "timestamp" or "decimal"
+///         "scale": int, // for "decimal" type only
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// predicate_info_json: predicate info that can be used in `requested_predicates` of proof request
+///     {
+///         "name": string,
+///         "p_type": string,
+///         "p_value": int, // encoded predicate value
+///         "restrictions": null,
+///         "non_revoked": null,
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_predicate_info(command_handle: CommandHandle,
+                                        attr_name: *const c_char,
+                                        p_type: *const c_char,
+                                        value: *const c_char,
+                                        encoding_json: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             predicate_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_predicate_info: >>> attr_name: {:?}, p_type: {:?}, value: {:?}, encoding_json: {:?}",
+           attr_name, p_type, value, encoding_json);
+
+    check_useful_c_str!(attr_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(p_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(value, ErrorCode::CommonInvalidParam4);
+    check_useful_validatable_json!(encoding_json, ErrorCode::CommonInvalidParam5, AttributeEncoding);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_build_predicate_info: entities >>> attr_name: {:?}, p_type: {:?}, value: {:?}, encoding_json: {:?}",
+           attr_name, p_type, value, encoding_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::BuildPredicateInfo(
+            attr_name,
+            p_type,
+            value,
+            encoding_json,
+            boxed_callback_string!("indy_build_predicate_info", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_predicate_info: <<< res: {:?}", res);
+
+    res
+}
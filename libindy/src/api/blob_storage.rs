@@ -2,10 +2,11 @@
 use indy_api_types::{ErrorCode, IndyHandle, CommandHandle};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::blob_storage::BlobStorageCommand;
+use crate::commands::anoncreds::tails::{self, TailsProgressCB};
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 
-use libc::c_char;
+use libc::{c_char, c_void};
 
 #[no_mangle]
 pub extern fn indy_open_blob_storage_reader(command_handle: CommandHandle,
@@ -72,3 +73,29 @@ pub extern fn indy_open_blob_storage_writer(command_handle: CommandHandle,
 
     res
 }
+
+/// Set callback that reports progress of tails generation performed by `indy_issuer_create_and_store_revoc_reg`.
+///
+/// Tails are generated in chunks on the crypto thread pool (see `crypto_thread_pool_size` of `indy_set_runtime_config`).
+/// Callback is called from libindy thread every time the next chunk is written to the blob storage.
+///
+/// #Params
+/// context: pointer to some context that will be passed to the callback
+/// progress_cb: callback that receives number of generated tails and total number of tails.
+///     Pass NULL to stop progress reporting.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_set_tails_progress_callback(context: *const c_void,
+                                               progress_cb: Option<TailsProgressCB>) -> ErrorCode {
+    trace!("indy_set_tails_progress_callback >>> context: {:?}, progress_cb: {:?}", context, progress_cb);
+
+    tails::set_tails_progress_callback(context, progress_cb);
+
+    let res = ErrorCode::Success;
+
+    trace!("indy_set_tails_progress_callback: <<< res: {:?}", res);
+
+    res
+}
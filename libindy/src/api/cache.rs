@@ -53,7 +53,7 @@ pub extern fn indy_get_cred_def(command_handle: CommandHandle,
         .send(Command::Cache(CacheCommand::GetCredDef(
             pool_handle,
             wallet_handle,
-            submitter_did,
+            Some(submitter_did),
             id,
             options_json,
             boxed_callback_string!("indy_get_cred_def", cb, command_handle)
@@ -111,7 +111,7 @@ pub extern fn indy_get_schema(command_handle: CommandHandle,
         .send(Command::Cache(CacheCommand::GetSchema(
             pool_handle,
             wallet_handle,
-            submitter_did,
+            Some(submitter_did),
             id,
             options_json,
             boxed_callback_string!("indy_get_schema", cb, command_handle)
@@ -124,6 +124,79 @@ pub extern fn indy_get_schema(command_handle: CommandHandle,
     res
 }
 
+/// Resolves DID into W3C DID Core compliant DID Document.
+/// DID Document is assembled from NYM transaction and `endpoint` and `diddocContent` attributes of the DID.
+/// State proofs of all ledger replies are verified.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// did: DID to resolve. Either unqualified (treated as `did:sov`) or fully qualified DID.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// DID resolution result json:
+/// {
+///     didDocument: W3C DID Document
+///     didResolutionMetadata: {
+///         contentType: "application/did+ld+json",
+///         retrieved: time of retrieving data from the ledger (in seconds since epoch)
+///     }
+///     didDocumentMetadata: {
+///         nymSeqNo: (optional) sequence number of NYM transaction,
+///         nymTxnTime: (optional) time of NYM transaction,
+///         endpointSeqNo: (optional) sequence number of `endpoint` ATTRIB transaction,
+///         diddocContentSeqNo: (optional) sequence number of `diddocContent` ATTRIB transaction,
+///         deactivated: (optional) true if NYM has no verkey
+///     }
+/// }
+#[no_mangle]
+pub extern fn indy_resolve_did(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               wallet_handle: WalletHandle,
+                               did: *const c_char,
+                               options_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    resolution_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_resolve_did: >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, did, options_json);
+
+    check_useful_validatable_string!(did, ErrorCode::CommonInvalidParam4, DidValue);
+    check_useful_json!(options_json, ErrorCode::CommonInvalidParam5, GetCacheOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_resolve_did: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, did, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::ResolveDid(
+            pool_handle,
+            wallet_handle,
+            did,
+            options_json,
+            boxed_callback_string!("indy_resolve_did", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_resolve_did: <<< res: {:?}", res);
+
+    res
+}
+
 /// Purge credential definition cache.
 ///
 /// EXPERIMENTAL
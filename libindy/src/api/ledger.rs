@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use indy_api_types::{CommandHandle, ErrorCode, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
 use indy_api_types::validation::Validatable;
@@ -16,6 +18,8 @@ use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
 use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
 use crate::domain::ledger::node::NodeOperationData;
 use crate::domain::ledger::pool::Schedule;
+use crate::domain::ledger::txn::TxnInclusionProof;
+use crate::domain::pool::ReadPolicy;
 
 /// Signs and submits request message to validator pool.
 ///
@@ -118,6 +122,67 @@ pub extern fn indy_submit_request(command_handle: CommandHandle,
     res
 }
 
+/// Publishes read request message to validator pool using the given read policy
+/// instead of the one the pool was opened with.
+///
+/// The request is sent to the validator pool as is. It's assumed that it's already prepared.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json.
+/// read_policy_json: Read policy json:
+/// {
+///     "retries": int (optional) - the number of additional rounds for a read request
+///         which can't be completed by queried nodes (0 by default).
+///     "retry_backoff": int (optional) - delay before the first retry in ms (500 by default).
+///         Each next retry waits twice as long as the previous one.
+///     "hedge_after": int (optional) - if there is no acceptable reply after this delay (in ms)
+///         one more node is queried (disabled by default).
+///     "full_consensus": bool (optional) - require consensus of pool nodes
+///         even if a reply contains valid state proof (false by default).
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_submit_request_with_policy(command_handle: CommandHandle,
+                                              pool_handle: PoolHandle,
+                                              request_json: *const c_char,
+                                              read_policy_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   request_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_request_with_policy: >>> pool_handle: {:?}, request_json: {:?}, read_policy_json: {:?}",
+           pool_handle, request_json, read_policy_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_validatable_json!(read_policy_json, ErrorCode::CommonInvalidParam4, ReadPolicy);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_submit_request_with_policy: entities >>> pool_handle: {:?}, request_json: {:?}, read_policy_json: {:?}",
+           pool_handle, request_json, read_policy_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitRequestWithPolicy(
+            pool_handle,
+            request_json,
+            read_policy_json,
+            boxed_callback_string!("indy_submit_request_with_policy", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_request_with_policy: <<< res: {:?}", res);
+
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -987,6 +1052,72 @@ pub extern fn indy_build_get_validator_info_request(command_handle: CommandHandl
     prepare_result!(result)
 }
 
+/// Parse a GET_VALIDATOR_INFO response to get typed information about pool nodes.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_validator_info_response: response of GET_VALIDATOR_INFO request
+///     (map of node aliases to node replies as returned by indy_submit_action).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Validator info json: map of node aliases to node information or the reason why the node didn't provide it:
+/// {
+///     <node alias>: {
+///         "validator_info": {
+///             "alias": string,
+///             "did": string (optional),
+///             "verkey": string (optional),
+///             "timestamp": int (optional),
+///             "node_info": {
+///                 "name", "mode", "client_port", "node_port", "bls_key",
+///                 "metrics": {"uptime": int, "transaction_count": {<ledger>: int}},
+///                 "committed_ledger_root_hashes": {<ledger id>: string},
+///                 "view_change_status": {"view_no": int, "vc_in_progress": bool, "last_complete_view_no": int},
+///                 "catchup_status": {"ledger_statuses": {<ledger id>: string}, "number_txns_in_catchup": {<ledger id>: int}}
+///             } (optional),
+///             "pool_info": {
+///                 "read_only": bool, "total_nodes_count": int, "f_value": int,
+///                 "reachable_nodes_count": int, "unreachable_nodes_count": int,
+///                 "reachable_nodes": [[<node alias>, <node rank>]], "unreachable_nodes": [[<node alias>, <node rank>]],
+///                 "blacklisted_nodes": [string]
+///             } (optional),
+///             "software": {"indy_node": string, "sovrin": string, "os_version": string} (optional)
+///         }
+///     } or {
+///         "error": string - "timeout" or reason of rejection
+///     },
+///     ...
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_parse_get_validator_info_response(command_handle: CommandHandle,
+                                                     get_validator_info_response: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                          err: ErrorCode,
+                                                                          validator_info_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_validator_info_response: >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    check_useful_c_str!(get_validator_info_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_validator_info_response: entities >>> get_validator_info_response: {:?}", get_validator_info_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetValidatorInfoResponse(
+            get_validator_info_response,
+            boxed_callback_string!("indy_parse_get_validator_info_response", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_validator_info_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
 ///
 /// #Params
@@ -1036,6 +1167,175 @@ pub extern fn indy_build_get_txn_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_TXN response to get the transaction in a typed form independent of the ledger transaction format version.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_txn_response: response of GET_TXN request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction json:
+/// {
+///     "ver": string - version of the transaction format stored in the ledger ("0" or "1"),
+///     "type": string - transaction type code,
+///     "typeName": string - one of NODE, NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY,
+///                 AUTH_RULE, AUTH_RULES, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML or CUSTOM
+///                 (types unknown to libindy, for example payment plugin transactions),
+///     "data": object - transaction data corresponding to "typeName":
+///         NODE: {"dest": string, "data": {"alias", "node_ip", "node_port", "client_ip", "client_port", "services", "blskey", "blskey_pop"}}
+///         NYM: {"dest": string, "verkey": string, "role": string, "alias": string}
+///         ATTRIB: {"dest": string, "raw": string, "hash": string, "enc": string}
+///         SCHEMA: {"name": string, "version": string, "attr_names": [string]}
+///         CRED_DEF: {"ref": int, "signature_type": string, "tag": string, "data": object}
+///         REVOC_REG_DEF: {"id": string, "revocDefType": string, "tag": string, "credDefId": string, "value": object}
+///         REVOC_REG_ENTRY: {"revocDefType": string, "revocRegDefId": string, "value": object}
+///         AUTH_RULE: {"auth_type": string, "auth_action": string, "field": string, "old_value": string, "new_value": string, "constraint": object}
+///         AUTH_RULES: {"rules": [<AUTH_RULE data>]}
+///         TXN_AUTHR_AGRMT: {"version": string, "text": string, "ratification_ts": int, "retirement_ts": int}
+///         TXN_AUTHR_AGRMT_AML: {"version": string, "aml": object, "amlContext": string}
+///         CUSTOM: transaction data as it is stored in the ledger
+///     "metadata": {
+///         "seqNo": int, "txnTime": int, "txnId": string,
+///         "from": string - DID of the transaction author, "reqId": int, "digest": string, "payloadDigest": string,
+///         "endorser": string - DID of the transaction endorser,
+///         "taaAcceptance": {"mechanism": string, "taaDigest": string, "time": int}
+///     } - fields are present only if they are set in the transaction
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                          get_txn_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_txn_response: >>> get_txn_response: {:?}", get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_txn_response: entities >>> get_txn_response: {:?}", get_txn_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetTxnResponse(
+            get_txn_response,
+            boxed_callback_string!("indy_parse_get_txn_response", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_txn_response: <<< res: {:?}", res);
+
+    res
+}
+
+/// Extracts an inclusion proof of the transaction from a GET_TXN response.
+///
+/// The proof is self-contained and can be verified later without connection to the pool
+/// (look at `indy_verify_txn_inclusion_proof`).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_txn_response: response of GET_TXN request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction inclusion proof json:
+/// {
+///     "seqNo": int - sequence number of the transaction,
+///     "ledgerSize": int - size of the ledger the audit path was built for,
+///     "rootHash": string - base58 encoded root hash of the ledger merkle tree,
+///     "auditPath": [string] - base58 encoded hashes from the transaction leaf to the root,
+///     "txn": object - transaction as it is stored in the ledger ("txn", "txnMetadata", "reqSignature", "ver"),
+///     "multiSignature": object - BLS multi-signature of pool nodes over the root hash
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_extract_txn_inclusion_proof(command_handle: CommandHandle,
+                                               get_txn_response: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    proof_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_extract_txn_inclusion_proof: >>> get_txn_response: {:?}", get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_extract_txn_inclusion_proof: entities >>> get_txn_response: {:?}", get_txn_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ExtractTxnInclusionProof(
+            get_txn_response,
+            boxed_callback_string!("indy_extract_txn_inclusion_proof", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_extract_txn_inclusion_proof: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies an inclusion proof of the transaction offline.
+///
+/// Checks that the audit path leads from the transaction to the root hash
+/// and that the root hash is signed by enough pool nodes with the given BLS keys.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// proof_json: transaction inclusion proof as returned by `indy_extract_txn_inclusion_proof`.
+/// nodes_bls_keys_json: known BLS keys of pool nodes:
+///     {
+///         <node alias>: <base58 encoded BLS key>,
+///         ...
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// valid: true - if proof is valid, false - otherwise
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_verify_txn_inclusion_proof(command_handle: CommandHandle,
+                                              proof_json: *const c_char,
+                                              nodes_bls_keys_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   valid: bool)>) -> ErrorCode {
+    trace!("indy_verify_txn_inclusion_proof: >>> proof_json: {:?}, nodes_bls_keys_json: {:?}", proof_json, nodes_bls_keys_json);
+
+    check_useful_validatable_json!(proof_json, ErrorCode::CommonInvalidParam2, TxnInclusionProof);
+    check_useful_json!(nodes_bls_keys_json, ErrorCode::CommonInvalidParam3, HashMap<String, String>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_verify_txn_inclusion_proof: entities >>> proof_json: {:?}, nodes_bls_keys_json: {:?}", proof_json, nodes_bls_keys_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::VerifyTxnInclusionProof(
+            proof_json,
+            nodes_bls_keys_json,
+            Box::new(move |result| {
+                let (err, valid) = prepare_result_1!(result, false);
+                trace!("indy_verify_txn_inclusion_proof: valid: {:?}", valid);
+
+                cb(command_handle, err, valid)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_txn_inclusion_proof: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// #Params
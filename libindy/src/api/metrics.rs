@@ -8,6 +8,8 @@ use libc::c_char;
 ///
 /// #Returns
 /// Map in the JSON format. Where keys are names of metrics.
+/// Pool metrics (`pool_node_requests_count`, `pool_node_latency_ms`, `pool_node_health_score`)
+/// are tagged with `pool` and `node` names of the opened pools.
 ///
 /// #Errors
 /// Common*
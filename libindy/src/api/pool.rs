@@ -2,7 +2,7 @@
 use indy_api_types::{ErrorCode, CommandHandle, PoolHandle, INVALID_POOL_HANDLE};
 use crate::commands::{Command, CommandExecutor};
 use crate::commands::pool::PoolCommand;
-use crate::domain::pool::{PoolConfig, PoolOpenConfig};
+use crate::domain::pool::{PoolConfig, PoolOpenConfig, PoolRegistryConfig};
 use indy_api_types::errors::prelude::*;
 use indy_utils::ctypes;
 use indy_api_types::validation::Validatable;
@@ -80,6 +80,18 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///         By default Libindy sends a read requests to 2 nodes in the pool.
 ///         If response isn't received or `state proof` is invalid Libindy sends the request again but to 2 (`number_read_nodes`) * 2 = 4 nodes and so far until completion.
 ///     "socks_proxy": string (optional) - ZMQ socks proxy host name and port (example: proxy1.intranet.company.com:1080)
+///     "read_policy": object (optional) - default strategy of sending read requests:
+///     {
+///         "retries": int (optional) - the number of additional rounds for a read request
+///             which can't be completed by queried nodes (0 by default).
+///         "retry_backoff": int (optional) - delay before the first retry in ms (500 by default).
+///             Each next retry waits twice as long as the previous one.
+///         "hedge_after": int (optional) - if there is no acceptable reply after this delay (in ms)
+///             one more node is queried (disabled by default).
+///         "full_consensus": bool (optional) - require consensus of pool nodes
+///             even if a reply contains valid state proof (false by default).
+///     }
+///         Nodes are queried in order of their health (reply rate and latency) observed so far.
 /// }
 ///
 /// #Returns
@@ -121,6 +133,67 @@ pub extern fn indy_open_pool_ledger(command_handle: CommandHandle,
     res
 }
 
+/// Opens a registry of pool ledgers that routes requests to different networks by DID method namespace.
+///
+/// Pools of the registry are not connected on opening. Each pool is opened lazily
+/// on the first request that targets its network and is reused afterwards.
+/// Pool ledger configurations of all networks must be previously created
+/// with indy_create_pool_ledger_config method.
+///
+/// The returned handle can be passed to ledger methods instead of a pool handle:
+///     indy_submit_request, indy_sign_and_submit_request, indy_submit_action - routed to the default network
+///     indy_get_schema, indy_get_cred_def (cache and ledger), indy_resolve_did - routed by the namespace of requested id
+///         (for example `did:indy:sovrin:staging:...` or `did:sov:...`). Unqualified ids are routed to the default network.
+///
+/// #Params
+/// config: Registry configuration json.
+/// {
+///     "networks": {
+///         "<DID method namespace>": {  (example: "did:sov", "did:indy:sovrin", "did:indy:sovrin:staging")
+///             "pool_name": string, name of the pool ledger configuration.
+///             "config": <pool open config> (optional), runtime pool configuration json (see indy_open_pool_ledger).
+///         },
+///         ...
+///     },
+///     "default": string (optional), namespace of the network to use for unqualified identifiers.
+/// }
+///
+/// #Returns
+/// Handle to opened pool registry. Use indy_close_pool_ledger to close the registry and all pools opened by it.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_open_pool_registry(command_handle: CommandHandle,
+                                      config: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           pool_handle: PoolHandle)>) -> ErrorCode {
+    trace!("indy_open_pool_registry: >>> config: {:?}", config);
+
+    check_useful_validatable_json!(config, ErrorCode::CommonInvalidParam2, PoolRegistryConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_open_pool_registry: entities >>> config: {:?}", config);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::OpenRegistry(
+            config,
+            Box::new(move |result| {
+                let (err, pool_handle) = prepare_result_1!(result, INVALID_POOL_HANDLE);
+                trace!("indy_open_pool_registry: pool_handle: {:?}", pool_handle);
+                cb(command_handle, err, pool_handle)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_open_pool_registry: <<< res: {:?}", res);
+
+    res
+}
+
 /// Refreshes a local copy of a pool ledger and updates pool nodes connections.
 ///
 /// #Params
@@ -189,10 +262,71 @@ pub extern fn indy_list_pools(command_handle: CommandHandle,
     res
 }
 
+/// Returns status of opened pool: known nodes, their connection state and the pool ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle returned by indy_open_pool_ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Pool status json:
+/// {
+///     "name": string - name of the pool ledger configuration,
+///     "ledger": {
+///         "size": int - the number of transactions in the pool ledger,
+///         "root": string - base58 encoded merkle root of the pool ledger
+///     },
+///     "last_catchup": int (optional) - time of the last completed catch-up (unix timestamp),
+///     "nodes": [{
+///         "alias": string,
+///         "dest": string - node identifier,
+///         "client_address": string (optional) - address requests are sent to,
+///         "node_address": string (optional) - address used for communication between nodes,
+///         "services": array<string>,
+///         "connected": bool - whether the pool holds open connection to the node,
+///         "health": {
+///             "replies": int - the number of acceptable replies received from the node,
+///             "failures": int - the number of timeouts and invalid replies,
+///             "latency_ms": int (optional) - smoothed reply latency,
+///             "score": int - percentage of requests answered by the node
+///         }
+///     }]
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_status(command_handle: CommandHandle,
+                                   handle: PoolHandle,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        pool_status_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_status: >>> handle: {:?}", handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_status: entities >>> handle: {:?}", handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetStatus(
+            handle,
+            boxed_callback_string!("indy_get_pool_status", cb, command_handle)
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_status: <<< res: {:?}", res);
+
+    res
+}
+
 /// Closes opened pool ledger, opened nodes connections and frees allocated resources.
+/// If handle refers to a pool registry, all pools opened by the registry are closed as well.
 ///
 /// #Params
-/// handle: pool handle returned by indy_open_pool_ledger.
+/// handle: pool handle returned by indy_open_pool_ledger or indy_open_pool_registry.
 ///
 /// #Returns
 /// Error code
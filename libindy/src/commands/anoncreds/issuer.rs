@@ -44,6 +44,11 @@ use crate::domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
     RevocationRegistryDeltaV1,
 };
+use crate::domain::anoncreds::revocation_registry_pool::{
+    RevocationRegistryPool,
+    RevocationRegistryPoolConfig,
+    UnpublishedRevocationRegistry,
+};
 use crate::domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, SchemaId};
 use crate::domain::crypto::did::DidValue;
 use indy_api_types::domain::wallet::Tags;
@@ -131,6 +136,13 @@ pub enum IssuerCommand {
         RevocationRegistryId, //revocation registry id
         String, //credential revoc id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    UpdateRevocationRegistry(
+        WalletHandle,
+        i32, // blob storage reader config handle
+        RevocationRegistryId, //revocation registry id
+        Vec<String>, //credential revoc ids to revoke
+        Vec<String>, //credential revoc ids to unrevoke
+        Box<dyn Fn(IndyResult<String>) + Send>),
     /*    RecoverCredential(
             WalletHandle,
             i32, // blob storage reader config handle
@@ -141,6 +153,34 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateRevocationRegistryPool(
+        WalletHandle,
+        DidValue, // issuer did
+        CredentialDefinitionId, // credential definition id
+        RevocationRegistryPoolConfig, // config
+        i32, // tails writer handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateCredentialFromRevocationRegistryPool(
+        WalletHandle,
+        CredentialOffer, // credential offer
+        CredentialRequest, // credential request
+        CredentialValues, // credential values
+        i32, // blob storage reader config handle
+        Box<dyn Fn(IndyResult<(String, Option<String>, Option<String>)>) + Send>),
+    RolloverRevocationRegistryPool(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        i32, // tails writer handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocationRegistryPool(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    MarkRevocationRegistryPublished(
+        WalletHandle,
+        CredentialDefinitionId, // credential definition id
+        RevocationRegistryId, // revocation registry id
+        Box<dyn Fn(IndyResult<()>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
@@ -220,6 +260,10 @@ impl IssuerCommandExecutor {
                 debug!(target: "issuer_command_executor", "RevokeCredential command received");
                 cb(self.revoke_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
             }
+            IssuerCommand::UpdateRevocationRegistry(wallet_handle, blob_storage_reader_handle, rev_reg_id, revoked_ids, unrevoked_ids, cb) => {
+                debug!(target: "issuer_command_executor", "UpdateRevocationRegistry command received");
+                cb(self.update_revocation_registry(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &revoked_ids, &unrevoked_ids));
+            }
             /*            IssuerCommand::RecoverCredential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id, cb) => {
                             debug!(target: "issuer_command_executor", "RecoverCredential command received");
                             cb(self.recovery_credential(wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_revoc_id));
@@ -229,6 +273,26 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::CreateRevocationRegistryPool(wallet_handle, issuer_did, cred_def_id, config, tails_writer_handle, cb) => {
+                debug!(target: "issuer_command_executor", "CreateRevocationRegistryPool command received");
+                cb(self.create_revocation_registry_pool(wallet_handle, &issuer_did, &cred_def_id, &config, tails_writer_handle));
+            }
+            IssuerCommand::CreateCredentialFromRevocationRegistryPool(wallet_handle, cred_offer, cred_req, cred_values, blob_storage_reader_handle, cb) => {
+                debug!(target: "issuer_command_executor", "CreateCredentialFromRevocationRegistryPool command received");
+                cb(self.new_credential_from_revocation_registry_pool(wallet_handle, &cred_offer, &cred_req, &cred_values, blob_storage_reader_handle));
+            }
+            IssuerCommand::RolloverRevocationRegistryPool(wallet_handle, cred_def_id, tails_writer_handle, cb) => {
+                debug!(target: "issuer_command_executor", "RolloverRevocationRegistryPool command received");
+                cb(self.rollover_revocation_registry_pool(wallet_handle, &cred_def_id, tails_writer_handle));
+            }
+            IssuerCommand::GetRevocationRegistryPool(wallet_handle, cred_def_id, cb) => {
+                debug!(target: "issuer_command_executor", "GetRevocationRegistryPool command received");
+                cb(self.get_revocation_registry_pool(wallet_handle, &cred_def_id));
+            }
+            IssuerCommand::MarkRevocationRegistryPublished(wallet_handle, cred_def_id, rev_reg_id, cb) => {
+                debug!(target: "issuer_command_executor", "MarkRevocationRegistryPublished command received");
+                cb(self.mark_revocation_registry_published(wallet_handle, &cred_def_id, &rev_reg_id));
+            }
         };
     }
 
@@ -581,14 +645,14 @@ impl IssuerCommandExecutor {
 
         let cred_def: CredentialDefinition = self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
 
-        let (revoc_public_keys, revoc_key_private, revoc_registry, mut revoc_tails_generator) =
+        let (revoc_public_keys, revoc_key_private, revoc_registry, revoc_tails_generator) =
             self.anoncreds_service.issuer.new_revocation_registry(&CredentialDefinitionV1::from(cred_def),
                                                                   max_cred_num,
                                                                   issuance_type.to_bool(),
                                                                   &issuer_did)?;
 
         let (tails_location, tails_hash) =
-            store_tails_from_generator(self.blob_storage_service.clone(), tails_writer_handle, &mut revoc_tails_generator)?;
+            store_tails_from_generator(self.blob_storage_service.clone(), tails_writer_handle, &revoc_tails_generator)?;
 
         let revoc_reg_def_value = RevocationRegistryDefinitionValue {
             max_cred_num,
@@ -855,6 +919,93 @@ impl IssuerCommandExecutor {
         Ok(rev_reg_delta_json)
     }
 
+    fn update_revocation_registry(&self,
+                                  wallet_handle: WalletHandle,
+                                  blob_storage_reader_handle: i32,
+                                  rev_reg_id: &RevocationRegistryId,
+                                  revoked_ids: &[String],
+                                  unrevoked_ids: &[String]) -> IndyResult<String> {
+        debug!("update_revocation_registry >>> wallet_handle: {:?}, blob_storage_reader_handle:  {:?}, rev_reg_id: {:?}, revoked_ids: {:?}, unrevoked_ids: {:?}",
+               wallet_handle, blob_storage_reader_handle, rev_reg_id, secret!(revoked_ids), secret!(unrevoked_ids));
+
+        let revoked = revoked_ids
+            .iter()
+            .map(|cred_revoc_id| parse_cred_rev_id(cred_revoc_id))
+            .collect::<IndyResult<HashSet<u32>>>()?;
+
+        let issued = unrevoked_ids
+            .iter()
+            .map(|cred_revoc_id| parse_cred_rev_id(cred_revoc_id))
+            .collect::<IndyResult<HashSet<u32>>>()?;
+
+        if revoked.is_empty() && issued.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Lists of revocation ids to revoke and unrevoke are empty"));
+        }
+
+        if let Some(cred_revoc_id) = revoked.intersection(&issued).next() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Revocation id: {:?} is requested to be revoked and unrevoked at the same time", cred_revoc_id)));
+        }
+
+        let revocation_registry_definition: RevocationRegistryDefinitionV1 =
+            RevocationRegistryDefinitionV1::from(
+                self._wallet_get_rev_reg_def(wallet_handle, &rev_reg_id)?);
+
+        let mut rev_reg: RevocationRegistryV1 =
+            RevocationRegistryV1::from(
+                self._wallet_get_rev_reg(wallet_handle, &rev_reg_id)?);
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       &revocation_registry_definition)?;
+
+        if let Some(cred_revoc_id) = revoked.iter().chain(issued.iter())
+            .find(|cred_revoc_id| **cred_revoc_id > revocation_registry_definition.value.max_cred_num + 1) {
+            return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+        }
+
+        let mut rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rev_reg_id)?;
+
+        // `used_ids` contains issued credentials for ISSUANCE_ON_DEMAND registry and revoked ones for ISSUANCE_BY_DEFAULT
+        let (ids_to_remove, ids_to_insert) = match revocation_registry_definition.value.issuance_type {
+            IssuanceType::ISSUANCE_ON_DEMAND => (&revoked, &issued),
+            IssuanceType::ISSUANCE_BY_DEFAULT => (&issued, &revoked),
+        };
+
+        for cred_revoc_id in ids_to_remove {
+            if !rev_reg_info.used_ids.remove(cred_revoc_id) {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+            }
+        }
+
+        for cred_revoc_id in ids_to_insert {
+            if !rev_reg_info.used_ids.insert(*cred_revoc_id) {
+                return Err(err_msg(IndyErrorKind::InvalidUserRevocId, format!("Revocation id: {:?} not found in RevocationRegistry", cred_revoc_id)));
+            }
+        }
+
+        let rev_reg_delta =
+            self.anoncreds_service.issuer.update_revocation_registry(&mut rev_reg.value,
+                                                                     revocation_registry_definition.value.max_cred_num,
+                                                                     &issued,
+                                                                     &revoked,
+                                                                     &sdk_tails_accessor)?;
+
+        let rev_reg_delta = RevocationRegistryDelta::RevocationRegistryDeltaV1(RevocationRegistryDeltaV1 { value: rev_reg_delta });
+
+        let rev_reg_delta_json = serde_json::to_string(&rev_reg_delta)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
+
+        let rev_reg = RevocationRegistry::RevocationRegistryV1(rev_reg);
+
+        // Registry and its info are stored only after all the ids are processed successfully
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg)?;
+        self.wallet_service.update_indy_object(wallet_handle, &rev_reg_id.0, &rev_reg_info)?;
+
+        debug!("update_revocation_registry <<< rev_reg_delta_json: {:?}", rev_reg_delta_json);
+
+        Ok(rev_reg_delta_json)
+    }
+
     fn _recovery_credential(&self,
                             wallet_handle: WalletHandle,
                             blob_storage_reader_handle: i32,
@@ -931,6 +1082,199 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    fn create_revocation_registry_pool(&self,
+                                       wallet_handle: WalletHandle,
+                                       issuer_did: &DidValue,
+                                       cred_def_id: &CredentialDefinitionId,
+                                       config: &RevocationRegistryPoolConfig,
+                                       tails_writer_handle: i32) -> IndyResult<String> {
+        debug!("create_revocation_registry_pool >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config: {:?}, tails_writer_handle: {:?}",
+               wallet_handle, issuer_did, cred_def_id, config, tails_writer_handle);
+
+        if self.wallet_service.record_exists::<RevocationRegistryPool>(wallet_handle, &cred_def_id.0)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists,
+                               format!("Revocation registry pool already exists for credential definition: {}", cred_def_id.0)));
+        }
+
+        let mut pool = RevocationRegistryPool {
+            cred_def_id: cred_def_id.clone(),
+            issuer_did: issuer_did.clone(),
+            config: config.clone(),
+            active: RevocationRegistryId(String::new()),
+            next: None,
+            registries: Vec::new(),
+            unpublished: Vec::new(),
+            rollover_needed: false,
+        };
+
+        pool.active = self._add_pool_revocation_registry(wallet_handle, &mut pool, tails_writer_handle)?;
+
+        self.wallet_service.add_indy_object(wallet_handle, &cred_def_id.0, &pool, &HashMap::new())?;
+
+        let res = serde_json::to_string(&pool)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryPool")?;
+
+        debug!("create_revocation_registry_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn new_credential_from_revocation_registry_pool(&self,
+                                                    wallet_handle: WalletHandle,
+                                                    cred_offer: &CredentialOffer,
+                                                    cred_request: &CredentialRequest,
+                                                    cred_values: &CredentialValues,
+                                                    blob_storage_reader_handle: i32) -> IndyResult<(String, Option<String>, Option<String>)> {
+        debug!("new_credential_from_revocation_registry_pool >>> wallet_handle: {:?}, cred_offer: {:?}, cred_req: {:?}, cred_values_json: {:?}, \
+               blob_storage_reader_handle: {:?}",
+               wallet_handle, secret!(&cred_offer), secret!(&cred_request), secret!(&cred_values), blob_storage_reader_handle);
+
+        let cred_def_id = match cred_offer.method_name {
+            Some(ref method_name) => cred_offer.cred_def_id.qualify(method_name),
+            None => cred_offer.cred_def_id.clone()
+        };
+
+        let mut pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        let (curr_id, max_cred_num) = self._get_revocation_registry_usage(wallet_handle, &pool.active)?;
+
+        // registries are generated ahead of time by rollover_revocation_registry_pool and
+        // credentials can't be issued from the registry until it is published
+        if curr_id >= max_cred_num {
+            let next = match pool.next {
+                Some(ref next) if pool.is_published(next) => next.clone(),
+                Some(ref next) =>
+                    return Err(err_msg(IndyErrorKind::RevocationRegistryFull,
+                                       format!("Revocation registry {} is full and the next registry {} is not published yet", pool.active.0, next.0))),
+                None =>
+                    return Err(err_msg(IndyErrorKind::RevocationRegistryFull,
+                                       format!("Revocation registry {} is full and the next registry is not generated yet", pool.active.0)))
+            };
+
+            info!("Revocation registry {:?} is full, switching to {:?}", pool.active, next);
+
+            pool.active = next;
+            pool.next = None;
+            pool.rollover_needed = false;
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &pool)?;
+        }
+
+        let res = self.new_credential(wallet_handle, cred_offer, cred_request, cred_values,
+                                      Some(&pool.active), Some(blob_storage_reader_handle))?;
+
+        let (curr_id, max_cred_num) = self._get_revocation_registry_usage(wallet_handle, &pool.active)?;
+
+        if !pool.rollover_needed && pool.needs_rollover(curr_id, max_cred_num) {
+            pool.rollover_needed = true;
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &pool)?;
+        }
+
+        debug!("new_credential_from_revocation_registry_pool <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}",
+               secret!(&res.0), secret!(&res.1), res.2);
+
+        Ok(res)
+    }
+
+    fn rollover_revocation_registry_pool(&self,
+                                         wallet_handle: WalletHandle,
+                                         cred_def_id: &CredentialDefinitionId,
+                                         tails_writer_handle: i32) -> IndyResult<String> {
+        debug!("rollover_revocation_registry_pool >>> wallet_handle: {:?}, cred_def_id: {:?}, tails_writer_handle: {:?}",
+               wallet_handle, cred_def_id, tails_writer_handle);
+
+        let mut pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        if pool.next.is_none() {
+            pool.next = Some(self._add_pool_revocation_registry(wallet_handle, &mut pool, tails_writer_handle)?);
+            pool.rollover_needed = false;
+            self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &pool)?;
+        }
+
+        let res = serde_json::to_string(&pool)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryPool")?;
+
+        debug!("rollover_revocation_registry_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_revocation_registry_pool(&self,
+                                    wallet_handle: WalletHandle,
+                                    cred_def_id: &CredentialDefinitionId) -> IndyResult<String> {
+        debug!("get_revocation_registry_pool >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+        let res = self.wallet_service.get_indy_record_value::<RevocationRegistryPool>(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        debug!("get_revocation_registry_pool <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn mark_revocation_registry_published(&self,
+                                          wallet_handle: WalletHandle,
+                                          cred_def_id: &CredentialDefinitionId,
+                                          rev_reg_id: &RevocationRegistryId) -> IndyResult<()> {
+        debug!("mark_revocation_registry_published >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}", wallet_handle, cred_def_id, rev_reg_id);
+
+        let mut pool: RevocationRegistryPool =
+            self.wallet_service.get_indy_object(wallet_handle, &cred_def_id.0, &RecordOptions::id_value())?;
+
+        let unpublished_count = pool.unpublished.len();
+        pool.unpublished.retain(|unpublished| unpublished.rev_reg_id != *rev_reg_id);
+
+        if pool.unpublished.len() == unpublished_count {
+            return Err(err_msg(IndyErrorKind::WalletItemNotFound,
+                               format!("Unpublished revocation registry {} not found in pool", rev_reg_id.0)));
+        }
+
+        self.wallet_service.update_indy_object(wallet_handle, &cred_def_id.0, &pool)?;
+
+        debug!("mark_revocation_registry_published <<<");
+
+        Ok(())
+    }
+
+    fn _add_pool_revocation_registry(&self,
+                                     wallet_handle: WalletHandle,
+                                     pool: &mut RevocationRegistryPool,
+                                     tails_writer_handle: i32) -> IndyResult<RevocationRegistryId> {
+        let config = RevocationRegistryConfig {
+            issuance_type: pool.config.issuance_type.clone(),
+            max_cred_num: pool.config.max_cred_num,
+        };
+
+        let (rev_reg_id, rev_reg_def_json, rev_reg_json) =
+            self.create_and_store_revocation_registry(wallet_handle,
+                                                      &pool.issuer_did,
+                                                      None,
+                                                      &pool.next_tag(),
+                                                      &pool.cred_def_id,
+                                                      &config,
+                                                      tails_writer_handle)?;
+
+        let rev_reg_id = RevocationRegistryId(rev_reg_id);
+
+        pool.registries.push(rev_reg_id.clone());
+        pool.unpublished.push(UnpublishedRevocationRegistry {
+            rev_reg_id: rev_reg_id.clone(),
+            rev_reg_def: serde_json::from_str(&rev_reg_def_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition")?,
+            rev_reg_entry: serde_json::from_str(&rev_reg_json)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistry")?,
+        });
+
+        Ok(rev_reg_id)
+    }
+
+    fn _get_revocation_registry_usage(&self, wallet_handle: WalletHandle, rev_reg_id: &RevocationRegistryId) -> IndyResult<(u32, u32)> {
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(self._wallet_get_rev_reg_def(wallet_handle, rev_reg_id)?);
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, rev_reg_id)?;
+
+        Ok((rev_reg_info.curr_id, rev_reg_def.value.max_cred_num))
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: WalletHandle, id: &str, schema_id: &SchemaId) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, &schema_id.0, &Tags::new())
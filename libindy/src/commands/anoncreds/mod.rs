@@ -1,7 +1,7 @@
 pub mod issuer;
 pub mod prover;
 pub mod verifier;
-mod tails;
+pub mod tails;
 
 use crate::commands::anoncreds::issuer::{IssuerCommand, IssuerCommandExecutor};
 use crate::commands::anoncreds::prover::{ProverCommand, ProverCommandExecutor};
@@ -13,6 +13,13 @@ use crate::services::pool::PoolService;
 use indy_wallet::WalletService;
 use crate::services::crypto::CryptoService;
 use crate::services::anoncreds::helpers::to_unqualified;
+use crate::services::anoncreds::w3c;
+use crate::services::anoncreds::encoding;
+use crate::domain::anoncreds::credential::Credential;
+use crate::domain::anoncreds::credential_attr_encoding::{AttributeEncoding, TypedCredentialValues};
+use crate::domain::anoncreds::proof::Proof;
+use crate::domain::anoncreds::proof_request::{PredicateTypes, ProofRequest};
+use crate::domain::anoncreds::w3c::{W3CCredential, W3CPresentation};
 
 use indy_api_types::errors::prelude::*;
 
@@ -24,6 +31,33 @@ pub enum AnoncredsCommand {
     Verifier(VerifierCommand),
     ToUnqualified(
         String, // entity
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CredentialToW3C(
+        Credential, // credential
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    CredentialFromW3C(
+        W3CCredential, // w3c credential
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ProofToW3CPresentation(
+        Proof, // proof
+        ProofRequest, // proof request
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ProofFromW3CPresentation(
+        W3CPresentation, // w3c presentation
+        ProofRequest, // proof request
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    EncodeCredentialValues(
+        TypedCredentialValues, // typed credential values
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    DecodeCredentialValue(
+        String, // encoded value
+        AttributeEncoding, // encoding
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    BuildPredicateInfo(
+        String, // attribute name
+        String, // predicate type
+        String, // predicate value
+        AttributeEncoding, // encoding
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
@@ -68,6 +102,47 @@ impl AnoncredsCommandExecutor {
                 debug!("ToUnqualified command received");
                 cb(to_unqualified(&entity));
             }
+            AnoncredsCommand::CredentialToW3C(credential, cb) => {
+                debug!("CredentialToW3C command received");
+                cb(w3c::credential_to_w3c(credential).and_then(|res| _to_json(&res)));
+            }
+            AnoncredsCommand::CredentialFromW3C(credential, cb) => {
+                debug!("CredentialFromW3C command received");
+                cb(w3c::credential_from_w3c(credential).and_then(|res| _to_json(&res)));
+            }
+            AnoncredsCommand::ProofToW3CPresentation(proof, proof_req, cb) => {
+                debug!("ProofToW3CPresentation command received");
+                cb(w3c::proof_to_w3c_presentation(proof, &proof_req).and_then(|res| _to_json(&res)));
+            }
+            AnoncredsCommand::ProofFromW3CPresentation(presentation, proof_req, cb) => {
+                debug!("ProofFromW3CPresentation command received");
+                cb(w3c::proof_from_w3c_presentation(presentation, &proof_req).and_then(|res| _to_json(&res)));
+            }
+            AnoncredsCommand::EncodeCredentialValues(values, cb) => {
+                debug!("EncodeCredentialValues command received");
+                cb(encoding::encode_credential_values(values).and_then(|res| _to_json(&res)));
+            }
+            AnoncredsCommand::DecodeCredentialValue(encoded, encoding, cb) => {
+                debug!("DecodeCredentialValue command received");
+                cb(encoding::decode_value(&encoded, &encoding));
+            }
+            AnoncredsCommand::BuildPredicateInfo(name, p_type, value, encoding, cb) => {
+                debug!("BuildPredicateInfo command received");
+                cb(_build_predicate_info(&name, &p_type, &value, &encoding));
+            }
         };
     }
 }
+
+fn _build_predicate_info(name: &str, p_type: &str, value: &str, encoding: &AttributeEncoding) -> IndyResult<String> {
+    let p_type: PredicateTypes = serde_json::from_value(serde_json::Value::String(p_type.to_string()))
+        .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid predicate type: {}", p_type))?;
+
+    encoding::build_predicate_info(name, p_type, value, encoding)
+        .and_then(|res| _to_json(&res))
+}
+
+fn _to_json<T: ::serde::Serialize>(value: &T) -> IndyResult<String> {
+    serde_json::to_string(value)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize entity")
+}
@@ -1,36 +1,43 @@
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use ursa::cl::{new_nonce, RevocationRegistry, Witness};
+use ursa::cl::{new_nonce, RevocationRegistry, RevocationRegistryDelta as CryptoRevocationRegistryDelta, Witness};
 
 use serde_json::Value;
 
+use crate::commands::{Command, CommandExecutor};
+use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::ledger::LedgerCommand;
 use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use crate::domain::anoncreds::credential::{Credential, CredentialInfo};
 use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest, RequestedCredential};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::{CredentialRequest, CredentialRequestMetadata};
-use crate::domain::anoncreds::master_secret::MasterSecret;
+use crate::domain::anoncreds::credential_selection::{CreateProofAutoOptions, CredentialCandidate, CREDENTIAL_CREATED_AT_TAG};
+use crate::domain::anoncreds::master_secret::{CREDENTIAL_MASTER_SECRET_TAG, EncryptedMasterSecret, ExportedMasterSecret, MASTER_SECRET_RETIRED_AT_TAG, MasterSecret, MasterSecretInfo};
 use crate::domain::anoncreds::proof_request::{NonRevocedInterval, PredicateInfo, ProofRequest, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::RequestedCredentials;
-use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
-use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
-use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates};
+use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryDefinitions, RevocationRegistryId};
+use crate::domain::anoncreds::revocation_registry_delta::{rev_reg_deltas_map_to_rev_reg_deltas_v1_map, RevocationRegistryDelta, RevocationRegistryDeltaV1, RevocationRegistryDeltas};
+use crate::domain::anoncreds::revocation_state::{RevocationState, RevocationStates, StoredRevocationState, StoredRevocationStateInfo};
 use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
 use crate::domain::crypto::did::DidValue;
+use crate::domain::crypto::key::Key;
 use indy_api_types::errors::prelude::*;
 use crate::services::anoncreds::AnoncredsService;
 use crate::services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
 use crate::services::blob_storage::BlobStorageService;
 use crate::services::crypto::CryptoService;
 use indy_wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
-use indy_utils::{next_search_handle};
+use indy_utils::{next_command_handle, next_search_handle};
+use indy_utils::crypto::base64;
 use crate::utils::wql::Query;
 
 use super::tails::SDKTailsAccessor;
-use indy_api_types::{WalletHandle, SearchHandle};
+use indy_api_types::{WalletHandle, SearchHandle, PoolHandle, CommandHandle};
 use crate::commands::BoxedCallbackStringStringSend;
 
 pub enum ProverCommand {
@@ -38,6 +45,24 @@ pub enum ProverCommand {
         WalletHandle,
         Option<String>, // master secret id
         Box<dyn Fn(IndyResult<String>) + Send>),
+    ExportMasterSecret(
+        WalletHandle,
+        String, // master secret id
+        String, // recipient verkey
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ImportMasterSecret(
+        WalletHandle,
+        String, // my verkey
+        EncryptedMasterSecret, // encrypted master secret
+        Option<String>, // master secret id
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetMasterSecrets(
+        WalletHandle,
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    RetireMasterSecret(
+        WalletHandle,
+        String, // master secret id
+        Box<dyn Fn(IndyResult<()>) + Send>),
     CreateCredentialRequest(
         WalletHandle,
         DidValue, // prover did
@@ -112,6 +137,26 @@ pub enum ProverCommand {
         CredentialDefinitions, // credential defs
         RevocationStates, // revocation states
         Box<dyn Fn(IndyResult<String>) + Send>),
+    CreateProofAuto(
+        WalletHandle,
+        ProofRequest, // proof request
+        String, // master secret name
+        Schemas, // schemas
+        CredentialDefinitions, // credential defs
+        RevocationStates, // revocation states
+        CreateProofAutoOptions, // options
+        BoxedCallbackStringStringSend),
+    CreateProofWithRevocationCache(
+        WalletHandle,
+        ProofRequest, // proof request
+        RequestedCredentials, // requested credentials
+        String, // master secret name
+        Schemas, // schemas
+        CredentialDefinitions, // credential defs
+        i32, // blob storage reader handle
+        RevocationRegistryDefinitions, // revocation registry definitions
+        RevocationRegistryDeltas, // revocation registry deltas
+        Box<dyn Fn(IndyResult<String>) + Send>),
     CreateRevocationState(
         i32, // blob storage reader handle
         RevocationRegistryDefinition, // revocation registry definition
@@ -126,7 +171,36 @@ pub enum ProverCommand {
         RevocationRegistryDelta, // revocation registry delta
         u64, //timestamp
         String, //credential revocation id
-        Box<dyn Fn(IndyResult<String>) + Send>)
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    RefreshRevocationStates(
+        PoolHandle,
+        WalletHandle,
+        i32, // blob storage reader handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    RefreshRevocationStatesContinue(
+        CommandHandle, // refresh id
+        RevocationLedgerObject, // fetched ledger object
+    ),
+    PruneRevocationStates(
+        WalletHandle,
+        u64, // timestamp
+        Box<dyn Fn(IndyResult<String>) + Send>),
+}
+
+pub enum RevocationLedgerObject {
+    RevRegDef(RevocationRegistryId, IndyResult<(String, String)>),
+    RevRegDelta(RevocationRegistryId, IndyResult<(String, String, u64)>),
+}
+
+struct PendingRefresh {
+    wallet_handle: WalletHandle,
+    blob_storage_reader_handle: i32,
+    stored_rev_states: Vec<StoredRevocationState>,
+    pending: usize,
+    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+    rev_reg_deltas: HashMap<RevocationRegistryId, (RevocationRegistryDeltaV1, u64)>,
+    error: Option<IndyError>,
+    cb: Box<dyn Fn(IndyResult<String>)>,
 }
 
 struct SearchForProofRequest {
@@ -154,6 +228,7 @@ pub struct ProverCommandExecutor {
     blob_storage_service: Rc<BlobStorageService>,
     searches: RefCell<HashMap<SearchHandle, Box<WalletSearch>>>,
     searches_for_proof_requests: RefCell<HashMap<SearchHandle, Box<HashMap<String, SearchForProofRequest>>>>,
+    pending_refreshes: RefCell<HashMap<CommandHandle, PendingRefresh>>,
 }
 
 impl ProverCommandExecutor {
@@ -168,6 +243,7 @@ impl ProverCommandExecutor {
             blob_storage_service,
             searches: RefCell::new(HashMap::new()),
             searches_for_proof_requests: RefCell::new(HashMap::new()),
+            pending_refreshes: RefCell::new(HashMap::new()),
         }
     }
 
@@ -177,6 +253,22 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "CreateMasterSecret command received");
                 cb(self.create_master_secret(wallet_handle, master_secret_id.as_ref().map(String::as_str)));
             }
+            ProverCommand::ExportMasterSecret(wallet_handle, master_secret_id, recipient_vk, cb) => {
+                debug!(target: "prover_command_executor", "ExportMasterSecret command received");
+                cb(self.export_master_secret(wallet_handle, &master_secret_id, &recipient_vk));
+            }
+            ProverCommand::ImportMasterSecret(wallet_handle, my_vk, encrypted_master_secret, master_secret_id, cb) => {
+                debug!(target: "prover_command_executor", "ImportMasterSecret command received");
+                cb(self.import_master_secret(wallet_handle, &my_vk, &encrypted_master_secret, master_secret_id.as_ref().map(String::as_str)));
+            }
+            ProverCommand::GetMasterSecrets(wallet_handle, cb) => {
+                debug!(target: "prover_command_executor", "GetMasterSecrets command received");
+                cb(self.get_master_secrets(wallet_handle));
+            }
+            ProverCommand::RetireMasterSecret(wallet_handle, master_secret_id, cb) => {
+                debug!(target: "prover_command_executor", "RetireMasterSecret command received");
+                cb(self.retire_master_secret(wallet_handle, &master_secret_id));
+            }
             ProverCommand::CreateCredentialRequest(wallet_handle, prover_did, credential_offer,
                                                    credential_def, master_secret_name, cb) => {
                 debug!(target: "prover_command_executor", "CreateCredentialRequest command received");
@@ -246,6 +338,24 @@ impl ProverCommandExecutor {
                                      &cred_defs_map_to_cred_defs_v1_map(cred_defs),
                                      &rev_states));
             }
+            ProverCommand::CreateProofAuto(wallet_handle, proof_req, master_secret_name,
+                                           schemas, cred_defs, rev_states, options, cb) => {
+                debug!(target: "prover_command_executor", "CreateProofAuto command received");
+                cb(self.create_proof_auto(wallet_handle, &proof_req, &master_secret_name,
+                                          &schemas_map_to_schemas_v1_map(schemas),
+                                          &cred_defs_map_to_cred_defs_v1_map(cred_defs),
+                                          &rev_states, options));
+            }
+            ProverCommand::CreateProofWithRevocationCache(wallet_handle, proof_req, requested_credentials, master_secret_name,
+                                                          schemas, cred_defs, blob_storage_reader_handle, rev_reg_defs, rev_reg_deltas, cb) => {
+                debug!(target: "prover_command_executor", "CreateProofWithRevocationCache command received");
+                cb(self.create_proof_with_revocation_cache(wallet_handle, &proof_req, &requested_credentials, &master_secret_name,
+                                                           &schemas_map_to_schemas_v1_map(schemas),
+                                                           &cred_defs_map_to_cred_defs_v1_map(cred_defs),
+                                                           blob_storage_reader_handle,
+                                                           &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                                           &rev_reg_deltas_map_to_rev_reg_deltas_v1_map(rev_reg_deltas)));
+            }
             ProverCommand::CreateRevocationState(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, cred_rev_id, cb) => {
                 debug!(target: "prover_command_executor", "CreateRevocationState command received");
                 cb(self.create_revocation_state(blob_storage_reader_handle, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
@@ -254,6 +364,18 @@ impl ProverCommandExecutor {
                 debug!(target: "prover_command_executor", "UpdateRevocationState command received");
                 cb(self.update_revocation_state(blob_storage_reader_handle, rev_state, rev_reg_def, rev_reg_delta, timestamp, &cred_rev_id));
             }
+            ProverCommand::RefreshRevocationStates(pool_handle, wallet_handle, blob_storage_reader_handle, cb) => {
+                debug!(target: "prover_command_executor", "RefreshRevocationStates command received");
+                self.refresh_revocation_states(pool_handle, wallet_handle, blob_storage_reader_handle, cb);
+            }
+            ProverCommand::RefreshRevocationStatesContinue(refresh_id, ledger_object) => {
+                debug!(target: "prover_command_executor", "RefreshRevocationStatesContinue command received");
+                self._refresh_revocation_states_continue(refresh_id, ledger_object);
+            }
+            ProverCommand::PruneRevocationStates(wallet_handle, timestamp, cb) => {
+                debug!(target: "prover_command_executor", "PruneRevocationStates command received");
+                cb(self.prune_revocation_states(wallet_handle, timestamp));
+            }
         };
     }
 
@@ -274,6 +396,8 @@ impl ProverCommandExecutor {
             value: master_secret
         };
 
+        self._wallet_bind_untagged_credentials(wallet_handle)?;
+
         self.wallet_service.add_indy_object(wallet_handle, &master_secret_id, &master_secret, &HashMap::new())?;
 
         debug!("create_master_secret <<< master_secret_id: {:?}", master_secret_id);
@@ -281,6 +405,149 @@ impl ProverCommandExecutor {
         Ok(master_secret_id)
     }
 
+    fn export_master_secret(&self,
+                            wallet_handle: WalletHandle,
+                            master_secret_id: &str,
+                            recipient_vk: &str) -> IndyResult<String> {
+        debug!("export_master_secret >>> wallet_handle: {:?}, master_secret_id: {:?}, recipient_vk: {:?}", wallet_handle, master_secret_id, recipient_vk);
+
+        self.crypto_service.validate_key(recipient_vk)?;
+
+        let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, master_secret_id)?;
+
+        let exported_master_secret = ExportedMasterSecret {
+            id: master_secret_id.to_string(),
+            value: master_secret.value,
+        };
+
+        let exported_master_secret_json = serde_json::to_vec(&exported_master_secret)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ExportedMasterSecret")?;
+
+        let ciphertext = self.crypto_service.crypto_box_seal(recipient_vk, &exported_master_secret_json)?;
+
+        let encrypted_master_secret = EncryptedMasterSecret {
+            ciphertext: base64::encode(&ciphertext)
+        };
+
+        let res = serde_json::to_string(&encrypted_master_secret)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize EncryptedMasterSecret")?;
+
+        debug!("export_master_secret <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn import_master_secret(&self,
+                            wallet_handle: WalletHandle,
+                            my_vk: &str,
+                            encrypted_master_secret: &EncryptedMasterSecret,
+                            master_secret_id: Option<&str>) -> IndyResult<String> {
+        debug!("import_master_secret >>> wallet_handle: {:?}, my_vk: {:?}, encrypted_master_secret: {:?}, master_secret_id: {:?}",
+               wallet_handle, my_vk, encrypted_master_secret, master_secret_id);
+
+        self.crypto_service.validate_key(my_vk)?;
+
+        let my_key: Key = self.wallet_service.get_indy_object(wallet_handle, my_vk, &RecordOptions::id_value())?;
+
+        let ciphertext = base64::decode(&encrypted_master_secret.ciphertext)?;
+
+        let exported_master_secret_json = self.crypto_service.crypto_box_seal_open(&my_key, &ciphertext)?;
+
+        let exported_master_secret: ExportedMasterSecret = serde_json::from_slice(&exported_master_secret_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize ExportedMasterSecret")?;
+
+        let master_secret_id = master_secret_id.map(String::from).unwrap_or(exported_master_secret.id);
+
+        if self.wallet_service.record_exists::<MasterSecret>(wallet_handle, &master_secret_id)? {
+            return Err(err_msg(IndyErrorKind::MasterSecretDuplicateName, format!("MasterSecret already exists {}", master_secret_id)));
+        }
+
+        let master_secret = MasterSecret {
+            value: exported_master_secret.value
+        };
+
+        self._wallet_bind_untagged_credentials(wallet_handle)?;
+
+        self.wallet_service.add_indy_object(wallet_handle, &master_secret_id, &master_secret, &HashMap::new())?;
+
+        debug!("import_master_secret <<< master_secret_id: {:?}", master_secret_id);
+
+        Ok(master_secret_id)
+    }
+
+    fn get_master_secrets(&self, wallet_handle: WalletHandle) -> IndyResult<String> {
+        debug!("get_master_secrets >>> wallet_handle: {:?}", wallet_handle);
+
+        let mut master_secrets_search =
+            self.wallet_service.search_indy_records::<MasterSecret>(wallet_handle, "{}", &_search_options_id_tags())?;
+
+        let mut master_secret_records = Vec::new();
+
+        while let Some(master_secret_record) = master_secrets_search.fetch_next_record()? {
+            master_secret_records.push(master_secret_record);
+        }
+
+        let mut cred_ids_by_master_secret: HashMap<String, Vec<String>> = HashMap::new();
+        let mut untagged_cred_ids = Vec::new();
+
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, "{}", &_search_options_id_tags())?;
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            let cred_id = credential_record.get_id().to_string();
+
+            match credential_record.get_tags().and_then(|tags| tags.get(CREDENTIAL_MASTER_SECRET_TAG)) {
+                Some(master_secret_id) => cred_ids_by_master_secret.entry(master_secret_id.to_string()).or_insert_with(Vec::new).push(cred_id),
+                None => untagged_cred_ids.push(cred_id)
+            }
+        }
+
+        // credentials stored before master secret binding was tracked have no tag,
+        // they belong to the master secret if the wallet has a single one
+        if let [ref master_secret_record] = master_secret_records[..] {
+            cred_ids_by_master_secret.entry(master_secret_record.get_id().to_string()).or_insert_with(Vec::new).extend(untagged_cred_ids);
+        }
+
+        let master_secrets: Vec<MasterSecretInfo> = master_secret_records
+            .into_iter()
+            .map(|master_secret_record| {
+                let retired_at = master_secret_record.get_tags()
+                    .and_then(|tags| tags.get(MASTER_SECRET_RETIRED_AT_TAG))
+                    .and_then(|retired_at| retired_at.parse::<u64>().ok());
+
+                MasterSecretInfo {
+                    id: master_secret_record.get_id().to_string(),
+                    retired_at,
+                    cred_ids: cred_ids_by_master_secret.remove(master_secret_record.get_id()).unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let res = serde_json::to_string(&master_secrets)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize list of MasterSecretInfo")?;
+
+        debug!("get_master_secrets <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn retire_master_secret(&self, wallet_handle: WalletHandle, master_secret_id: &str) -> IndyResult<()> {
+        debug!("retire_master_secret >>> wallet_handle: {:?}, master_secret_id: {:?}", wallet_handle, master_secret_id);
+
+        if self._wallet_get_master_secret_retired_at(wallet_handle, master_secret_id)?.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidState, format!("MasterSecret {} is already retired", master_secret_id)));
+        }
+
+        let mut tags = HashMap::new();
+        tags.insert(MASTER_SECRET_RETIRED_AT_TAG.to_string(), _get_cur_time().to_string());
+
+        self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("MasterSecret"), master_secret_id, &tags)?;
+
+        debug!("retire_master_secret <<<");
+
+        Ok(())
+    }
+
     fn create_credential_request(&self,
                                  wallet_handle: WalletHandle,
                                  prover_did: &DidValue,
@@ -292,6 +559,11 @@ impl ProverCommandExecutor {
 
         self.crypto_service.validate_did(&prover_did)?;
 
+        if self._wallet_get_master_secret_retired_at(wallet_handle, master_secret_id)?.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("MasterSecret {} is retired and can't be used for new credential requests", master_secret_id)));
+        }
+
         let master_secret: MasterSecret = self._wallet_get_master_secret(wallet_handle, &master_secret_id)?;
 
         let (blinded_ms, ms_blinding_data, blinded_ms_correctness_proof) =
@@ -347,11 +619,21 @@ impl ProverCommandExecutor {
         // Cascade whether we updated policy or not: could be a retroactive cred attr tags reset to existing policy
         if retroactive {
             let query_json = format!(r#"{{"cred_def_id": "{}"}}"#, cred_def_id.0);
-            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &SearchOptions::id_value())?;
+            let mut credentials_search = self.wallet_service.search_indy_records::<Credential>(wallet_handle, query_json.as_str(), &_search_options_id_value_tags())?;
 
             while let Some(credential_record) = credentials_search.fetch_next_record()? {
                 let (_, credential) = self._get_credential(&credential_record)?;
-                let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+                let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol)?;
+
+                // keep the time credential was stored, it isn't derived from the credential itself
+                if let Some(created_at) = credential_record.get_tags().and_then(|tags| tags.get(CREDENTIAL_CREATED_AT_TAG)) {
+                    cred_tags.insert(CREDENTIAL_CREATED_AT_TAG.to_string(), created_at.to_string());
+                }
+
+                if let Some(master_secret_id) = credential_record.get_tags().and_then(|tags| tags.get(CREDENTIAL_MASTER_SECRET_TAG)) {
+                    cred_tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), master_secret_id.to_string());
+                }
+
                 self.wallet_service.update_record_tags(wallet_handle, self.wallet_service.add_prefix("Credential").as_str(), credential_record.get_id(), &cred_tags)?;
             }
         }
@@ -409,7 +691,9 @@ impl ProverCommandExecutor {
             None
         };
 
-        let cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        let mut cred_tags = self.anoncreds_service.prover.build_credential_tags(&credential, catpol.as_ref())?;
+        cred_tags.insert(CREDENTIAL_CREATED_AT_TAG.to_string(), _get_cur_time().to_string());
+        cred_tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), cred_req_metadata.master_secret_name.clone());
         self.wallet_service.add_indy_object(wallet_handle, &out_cred_id, credential, &cred_tags)?;
 
         debug!("store_credential <<< out_cred_id: {:?}", out_cred_id);
@@ -704,13 +988,15 @@ impl ProverCommandExecutor {
             credentials.insert(cred_referent, credential);
         }
 
+        let rev_states = self._add_stored_revocation_states(wallet_handle, requested_credentials, &credentials, rev_states)?;
+
         let proof = self.anoncreds_service.prover.create_proof(&credentials,
                                                                &proof_req,
                                                                &requested_credentials,
                                                                &master_secret.value,
                                                                schemas,
                                                                cred_defs,
-                                                               rev_states)?;
+                                                               &rev_states)?;
 
         let proof_json = serde_json::to_string(&proof)
             .to_indy(IndyErrorKind::InvalidState, "Cannot serialize FullProof")?;
@@ -720,6 +1006,150 @@ impl ProverCommandExecutor {
         Ok(proof_json)
     }
 
+    fn create_proof_auto(&self,
+                         wallet_handle: WalletHandle,
+                         proof_request: &ProofRequest,
+                         master_secret_id: &str,
+                         schemas: &HashMap<SchemaId, SchemaV1>,
+                         cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                         rev_states: &RevocationStates,
+                         options: CreateProofAutoOptions) -> IndyResult<(String, String)> {
+        debug!("create_proof_auto >>> wallet_handle: {:?}, proof_request: {:?}, master_secret_id: {:?}, schemas: {:?}, \
+        cred_defs: {:?}, rev_states: {:?}, options: {:?}",
+               wallet_handle, proof_request, master_secret_id, schemas, cred_defs, rev_states, options);
+
+        let proof_req = proof_request.value();
+        let proof_req_version = proof_request.version();
+
+        let mut self_attested_attributes: HashMap<String, String> = HashMap::new();
+        let mut attr_candidates: HashMap<String, Vec<CredentialCandidate>> = HashMap::new();
+
+        for (attr_id, requested_attr) in proof_req.requested_attributes.iter() {
+            // self attested values can be used only for attributes without restrictions
+            if let Some(value) = options.self_attested_attributes.get(attr_id) {
+                if _is_unrestricted(&requested_attr.restrictions) {
+                    self_attested_attributes.insert(attr_id.to_string(), value.to_string());
+                    continue;
+                }
+            }
+
+            let query = self.anoncreds_service.prover.process_proof_request_restrictions(&proof_req_version,
+                                                                                         &requested_attr.name,
+                                                                                         &requested_attr.names,
+                                                                                         &attr_id,
+                                                                                         &requested_attr.restrictions,
+                                                                                         &None)?;
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &requested_attr.non_revoked);
+
+            let candidates = self._query_credential_candidates(wallet_handle, &query, None, &interval, rev_states)?;
+
+            attr_candidates.insert(attr_id.to_string(), candidates);
+        }
+
+        let mut predicate_candidates: HashMap<String, Vec<CredentialCandidate>> = HashMap::new();
+
+        for (predicate_id, requested_predicate) in proof_req.requested_predicates.iter() {
+            let query = self.anoncreds_service.prover.process_proof_request_restrictions(&proof_req_version,
+                                                                                         &Some(requested_predicate.name.clone()),
+                                                                                         &None,
+                                                                                         &predicate_id,
+                                                                                         &requested_predicate.restrictions,
+                                                                                         &None)?;
+            let interval = get_non_revoc_interval(&proof_req.non_revoked, &requested_predicate.non_revoked);
+
+            let candidates = self._query_credential_candidates(wallet_handle, &query, Some(&requested_predicate), &interval, rev_states)?;
+
+            predicate_candidates.insert(predicate_id.to_string(), candidates);
+        }
+
+        let requested_credentials = self.anoncreds_service.prover.select_credentials(&attr_candidates,
+                                                                                     &predicate_candidates,
+                                                                                     self_attested_attributes,
+                                                                                     options.policy)?;
+
+        let proof_json = self.create_proof(wallet_handle,
+                                           proof_request,
+                                           &requested_credentials,
+                                           master_secret_id,
+                                           schemas,
+                                           cred_defs,
+                                           rev_states)?;
+
+        let requested_credentials_json = serde_json::to_string(&requested_credentials)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RequestedCredentials")?;
+
+        debug!("create_proof_auto <<< proof_json: {:?}, requested_credentials_json: {:?}", proof_json, requested_credentials_json);
+
+        Ok((proof_json, requested_credentials_json))
+    }
+
+    fn create_proof_with_revocation_cache(&self,
+                                          wallet_handle: WalletHandle,
+                                          proof_req: &ProofRequest,
+                                          requested_credentials: &RequestedCredentials,
+                                          master_secret_id: &str,
+                                          schemas: &HashMap<SchemaId, SchemaV1>,
+                                          cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                          blob_storage_reader_handle: i32,
+                                          rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                          rev_reg_deltas: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryDeltaV1>>) -> IndyResult<String> {
+        debug!("create_proof_with_revocation_cache >>> wallet_handle: {:?}, proof_req: {:?}, requested_credentials: {:?}, master_secret_id: {:?}, \
+        schemas: {:?}, cred_defs: {:?}, blob_storage_reader_handle: {:?}, rev_reg_defs: {:?}, rev_reg_deltas: {:?}",
+               wallet_handle, proof_req, requested_credentials, master_secret_id, schemas, cred_defs, blob_storage_reader_handle, rev_reg_defs, rev_reg_deltas);
+
+        let mut rev_states: RevocationStates = HashMap::new();
+
+        for (cred_id, timestamp) in _get_requested_timestamps(requested_credentials) {
+            let credential: Credential = self.wallet_service.get_indy_object(wallet_handle, &cred_id, &RecordOptions::id_value())?;
+
+            let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id, credential.signature.extract_index()) {
+                (Some(rev_reg_id), Some(rev_idx)) => (rev_reg_id, rev_idx.to_string()),
+                _ => continue
+            };
+
+            let rev_state = match self._get_stored_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id, timestamp)? {
+                Some(stored_rev_state) => stored_rev_state.rev_state,
+                None => {
+                    let rev_reg_delta = rev_reg_deltas.get(&rev_reg_id)
+                        .and_then(|rev_reg_deltas| rev_reg_deltas.get(&timestamp))
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                               format!("RevocationState isn't stored and RevocationRegistryDelta not found for id: {:?} and timestamp: {:?}", rev_reg_id, timestamp)))?;
+
+                    let rev_reg_def = rev_reg_defs.get(&rev_reg_id)
+                        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("RevocationRegistryDefinition not found for id: {:?}", rev_reg_id)))?;
+
+                    let last_rev_state = self._get_last_stored_revocation_state(wallet_handle, &rev_reg_id, &cred_rev_id, timestamp)?;
+
+                    let stored_rev_state = self._build_stored_revocation_state(blob_storage_reader_handle,
+                                                                               &rev_reg_id,
+                                                                               rev_reg_def,
+                                                                               rev_reg_delta.clone(),
+                                                                               timestamp,
+                                                                               &cred_rev_id,
+                                                                               last_rev_state)?;
+
+                    self._store_revocation_state(wallet_handle, &stored_rev_state)?;
+
+                    stored_rev_state.rev_state
+                }
+            };
+
+            rev_states.entry(cred_id).or_insert_with(HashMap::new).insert(timestamp, rev_state);
+        }
+
+        let proof_json = self.create_proof(wallet_handle,
+                                           proof_req,
+                                           requested_credentials,
+                                           master_secret_id,
+                                           schemas,
+                                           cred_defs,
+                                           &rev_states)?;
+
+        debug!("create_proof_with_revocation_cache <<< proof_json: {:?}", proof_json);
+
+        Ok(proof_json)
+    }
+
     fn create_revocation_state(&self,
                                blob_storage_reader_handle: i32,
                                revoc_reg_def: RevocationRegistryDefinition,
@@ -788,6 +1218,361 @@ impl ProverCommandExecutor {
         Ok(rev_state_json)
     }
 
+    fn refresh_revocation_states(&self,
+                                 pool_handle: PoolHandle,
+                                 wallet_handle: WalletHandle,
+                                 blob_storage_reader_handle: i32,
+                                 cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("refresh_revocation_states >>> pool_handle: {:?}, wallet_handle: {:?}, blob_storage_reader_handle: {:?}",
+               pool_handle, wallet_handle, blob_storage_reader_handle);
+
+        let stored_rev_states = try_cb!(self._get_latest_stored_revocation_states(wallet_handle), cb);
+
+        let rev_reg_ids: HashSet<RevocationRegistryId> = stored_rev_states.iter()
+            .map(|stored_rev_state| stored_rev_state.rev_reg_id.clone())
+            .collect();
+
+        let pending = rev_reg_ids.len() * 2;
+        let refresh_id = next_command_handle();
+
+        self.pending_refreshes.borrow_mut().insert(refresh_id, PendingRefresh {
+            wallet_handle,
+            blob_storage_reader_handle,
+            stored_rev_states,
+            pending,
+            rev_reg_defs: HashMap::new(),
+            rev_reg_deltas: HashMap::new(),
+            error: None,
+            cb,
+        });
+
+        if pending == 0 {
+            return self._complete_refresh(refresh_id);
+        }
+
+        let to = _get_cur_time() as i64;
+
+        for rev_reg_id in rev_reg_ids {
+            let rev_reg_def_id = rev_reg_id.clone();
+
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDef(
+                        pool_handle,
+                        None,
+                        rev_reg_id.clone(),
+                        Box::new(move |result| {
+                            ProverCommandExecutor::_send_revocation_ledger_object(refresh_id, RevocationLedgerObject::RevRegDef(rev_reg_def_id.clone(), result))
+                        })
+                    )
+                )
+            ).unwrap();
+
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDelta(
+                        pool_handle,
+                        None,
+                        rev_reg_id.clone(),
+                        None,
+                        to,
+                        Box::new(move |result| {
+                            ProverCommandExecutor::_send_revocation_ledger_object(refresh_id, RevocationLedgerObject::RevRegDelta(rev_reg_id.clone(), result))
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+    }
+
+    fn _send_revocation_ledger_object(refresh_id: CommandHandle, ledger_object: RevocationLedgerObject) {
+        CommandExecutor::instance().send(
+            Command::Anoncreds(
+                AnoncredsCommand::Prover(
+                    ProverCommand::RefreshRevocationStatesContinue(
+                        refresh_id,
+                        ledger_object,
+                    )
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _refresh_revocation_states_continue(&self, refresh_id: CommandHandle, ledger_object: RevocationLedgerObject) {
+        let completed = {
+            let mut pending_refreshes = self.pending_refreshes.borrow_mut();
+            let refresh = pending_refreshes.get_mut(&refresh_id).expect("FIXME INVALID STATE");
+
+            if let Err(err) = ProverCommandExecutor::_store_revocation_ledger_object(refresh, ledger_object) {
+                if refresh.error.is_none() {
+                    refresh.error = Some(err);
+                }
+            }
+
+            refresh.pending -= 1;
+            refresh.pending == 0
+        };
+
+        if completed {
+            self._complete_refresh(refresh_id);
+        }
+    }
+
+    fn _store_revocation_ledger_object(refresh: &mut PendingRefresh, ledger_object: RevocationLedgerObject) -> IndyResult<()> {
+        match ledger_object {
+            RevocationLedgerObject::RevRegDef(id, result) => {
+                let (_, rev_reg_def_json) = result?;
+                let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(&rev_reg_def_json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDefinition")?;
+                refresh.rev_reg_defs.insert(id, RevocationRegistryDefinitionV1::from(rev_reg_def));
+            }
+            RevocationLedgerObject::RevRegDelta(id, result) => {
+                let (_, rev_reg_delta_json, timestamp) = result?;
+                let rev_reg_delta: RevocationRegistryDelta = serde_json::from_str(&rev_reg_delta_json)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDelta")?;
+                refresh.rev_reg_deltas.insert(id, (RevocationRegistryDeltaV1::from(rev_reg_delta), timestamp));
+            }
+        }
+        Ok(())
+    }
+
+    fn _complete_refresh(&self, refresh_id: CommandHandle) {
+        let refresh = self.pending_refreshes.borrow_mut().remove(&refresh_id).expect("FIXME INVALID STATE");
+        let cb = refresh.cb;
+
+        if let Some(err) = refresh.error {
+            return cb(Err(err));
+        }
+
+        // all states are built before any is stored, so a failure does not leave the cache partially refreshed
+        let mut refreshed_rev_states: Vec<StoredRevocationState> = Vec::new();
+
+        for stored_rev_state in refresh.stored_rev_states {
+            let (rev_reg_delta, timestamp) = match refresh.rev_reg_deltas.get(&stored_rev_state.rev_reg_id) {
+                Some((rev_reg_delta, timestamp)) if *timestamp > stored_rev_state.rev_state.timestamp => (rev_reg_delta.clone(), *timestamp),
+                _ => continue
+            };
+
+            let rev_reg_def = try_cb!(refresh.rev_reg_defs.get(&stored_rev_state.rev_reg_id)
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("RevocationRegistryDefinition not found for id: {:?}", stored_rev_state.rev_reg_id))), cb);
+
+            let rev_reg_id = stored_rev_state.rev_reg_id.clone();
+            let cred_rev_id = stored_rev_state.cred_rev_id.clone();
+
+            let stored_rev_state = try_cb!(self._build_stored_revocation_state(refresh.blob_storage_reader_handle,
+                                                                               &rev_reg_id,
+                                                                               rev_reg_def,
+                                                                               rev_reg_delta,
+                                                                               timestamp,
+                                                                               &cred_rev_id,
+                                                                               Some(stored_rev_state)), cb);
+
+            refreshed_rev_states.push(stored_rev_state);
+        }
+
+        for stored_rev_state in refreshed_rev_states.iter() {
+            try_cb!(self._store_revocation_state(refresh.wallet_handle, stored_rev_state), cb);
+        }
+
+        let refreshed: Vec<StoredRevocationStateInfo> = refreshed_rev_states.iter().map(StoredRevocationState::info).collect();
+
+        let res = serde_json::to_string(&refreshed)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize refreshed revocation states");
+
+        debug!("refresh_revocation_states <<< res: {:?}", res);
+
+        cb(res)
+    }
+
+    fn prune_revocation_states(&self, wallet_handle: WalletHandle, timestamp: u64) -> IndyResult<String> {
+        debug!("prune_revocation_states >>> wallet_handle: {:?}, timestamp: {:?}", wallet_handle, timestamp);
+
+        // the latest state of every credential is kept as the base for incremental updates
+        let latest_ids: HashSet<String> = self._get_latest_stored_revocation_states(wallet_handle)?
+            .iter()
+            .map(StoredRevocationState::id)
+            .collect();
+
+        let pruned_rev_states: Vec<StoredRevocationState> = self._search_stored_revocation_states(wallet_handle, "{}")?
+            .into_iter()
+            .filter(|stored_rev_state| stored_rev_state.rev_state.timestamp < timestamp && !latest_ids.contains(&stored_rev_state.id()))
+            .collect();
+
+        for stored_rev_state in pruned_rev_states.iter() {
+            self.wallet_service.delete_indy_record::<StoredRevocationState>(wallet_handle, &stored_rev_state.id())?;
+        }
+
+        let pruned: Vec<StoredRevocationStateInfo> = pruned_rev_states.iter().map(StoredRevocationState::info).collect();
+
+        let res = serde_json::to_string(&pruned)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize pruned revocation states")?;
+
+        debug!("prune_revocation_states <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _add_stored_revocation_states(&self,
+                                     wallet_handle: WalletHandle,
+                                     requested_credentials: &RequestedCredentials,
+                                     credentials: &HashMap<String, Credential>,
+                                     rev_states: &RevocationStates) -> IndyResult<RevocationStates> {
+        let mut rev_states = rev_states.clone();
+
+        for (cred_id, timestamp) in _get_requested_timestamps(requested_credentials) {
+            let credential = match credentials.get(&cred_id) {
+                Some(credential) => credential,
+                None => continue
+            };
+
+            let (rev_reg_id, cred_rev_id) = match (credential.rev_reg_id.as_ref(), credential.signature.extract_index()) {
+                (Some(rev_reg_id), Some(rev_idx)) => (rev_reg_id, rev_idx.to_string()),
+                _ => continue
+            };
+
+            let provided = [rev_reg_id.0.as_str(), cred_id.as_str()].iter()
+                .filter_map(|key| rev_states.get(*key))
+                .any(|rev_states_for_timestamp| rev_states_for_timestamp.contains_key(&timestamp));
+
+            if provided {
+                continue;
+            }
+
+            if let Some(stored_rev_state) = self._get_stored_revocation_state(wallet_handle, rev_reg_id, &cred_rev_id, timestamp)? {
+                rev_states.entry(cred_id).or_insert_with(HashMap::new).insert(timestamp, stored_rev_state.rev_state);
+            }
+        }
+
+        Ok(rev_states)
+    }
+
+    fn _build_stored_revocation_state(&self,
+                                      blob_storage_reader_handle: i32,
+                                      rev_reg_id: &RevocationRegistryId,
+                                      rev_reg_def: &RevocationRegistryDefinitionV1,
+                                      rev_reg_delta: RevocationRegistryDeltaV1,
+                                      timestamp: u64,
+                                      cred_rev_id: &str,
+                                      last_rev_state: Option<StoredRevocationState>) -> IndyResult<StoredRevocationState> {
+        let rev_idx = parse_cred_rev_id(cred_rev_id)?;
+
+        let sdk_tails_accessor = SDKTailsAccessor::new(self.blob_storage_service.clone(),
+                                                       blob_storage_reader_handle,
+                                                       rev_reg_def)?;
+
+        let issuance_by_default = rev_reg_def.value.issuance_type.to_bool();
+
+        let (issued, revoked) = self.anoncreds_service.prover.get_rev_reg_delta_indices(&rev_reg_delta.value)?;
+
+        let witness = match last_rev_state {
+            Some(last_rev_state) => {
+                // ledger delta is built from the registry creation, so only the difference with the last state is applied
+                let (added, removed) = self.anoncreds_service.prover.get_rev_reg_increment(issuance_by_default,
+                                                                                           (&last_rev_state.issued, &last_rev_state.revoked),
+                                                                                           (&issued, &revoked));
+
+                let increment = CryptoRevocationRegistryDelta::from_parts(Some(&last_rev_state.rev_state.rev_reg),
+                                                                        &RevocationRegistry::from(rev_reg_delta.value.clone()),
+                                                                        &added,
+                                                                        &removed);
+
+                let mut witness = last_rev_state.rev_state.witness;
+                witness.update(rev_idx, rev_reg_def.value.max_cred_num, &increment, &sdk_tails_accessor)?;
+                witness
+            }
+            None => Witness::new(rev_idx, rev_reg_def.value.max_cred_num, issuance_by_default, &rev_reg_delta.value, &sdk_tails_accessor)?
+        };
+
+        Ok(StoredRevocationState {
+            rev_reg_id: rev_reg_id.clone(),
+            cred_rev_id: cred_rev_id.to_string(),
+            rev_state: RevocationState {
+                witness,
+                rev_reg: RevocationRegistry::from(rev_reg_delta.value),
+                timestamp,
+            },
+            issued,
+            revoked,
+        })
+    }
+
+    fn _store_revocation_state(&self, wallet_handle: WalletHandle, stored_rev_state: &StoredRevocationState) -> IndyResult<()> {
+        let id = stored_rev_state.id();
+
+        if self.wallet_service.record_exists::<StoredRevocationState>(wallet_handle, &id)? {
+            self.wallet_service.update_indy_object(wallet_handle, &id, stored_rev_state)?;
+        } else {
+            self.wallet_service.add_indy_object(wallet_handle, &id, stored_rev_state, &stored_rev_state.tags())?;
+        }
+
+        Ok(())
+    }
+
+    fn _get_stored_revocation_state(&self,
+                                    wallet_handle: WalletHandle,
+                                    rev_reg_id: &RevocationRegistryId,
+                                    cred_rev_id: &str,
+                                    timestamp: u64) -> IndyResult<Option<StoredRevocationState>> {
+        let id = StoredRevocationState::record_id(rev_reg_id, cred_rev_id, timestamp);
+
+        if !self.wallet_service.record_exists::<StoredRevocationState>(wallet_handle, &id)? {
+            return Ok(None);
+        }
+
+        self.wallet_service.get_indy_object(wallet_handle, &id, &RecordOptions::id_value()).map(Some)
+    }
+
+    fn _get_last_stored_revocation_state(&self,
+                                         wallet_handle: WalletHandle,
+                                         rev_reg_id: &RevocationRegistryId,
+                                         cred_rev_id: &str,
+                                         timestamp: u64) -> IndyResult<Option<StoredRevocationState>> {
+        let query_json = json!({"rev_reg_id": rev_reg_id.0, "cred_rev_id": cred_rev_id}).to_string();
+
+        let last_rev_state = self._search_stored_revocation_states(wallet_handle, &query_json)?
+            .into_iter()
+            .filter(|stored_rev_state| stored_rev_state.rev_state.timestamp < timestamp)
+            .max_by_key(|stored_rev_state| stored_rev_state.rev_state.timestamp);
+
+        Ok(last_rev_state)
+    }
+
+    fn _get_latest_stored_revocation_states(&self, wallet_handle: WalletHandle) -> IndyResult<Vec<StoredRevocationState>> {
+        let mut latest_rev_states: HashMap<(RevocationRegistryId, String), StoredRevocationState> = HashMap::new();
+
+        for stored_rev_state in self._search_stored_revocation_states(wallet_handle, "{}")? {
+            let key = (stored_rev_state.rev_reg_id.clone(), stored_rev_state.cred_rev_id.clone());
+
+            let is_latest = latest_rev_states.get(&key)
+                .map(|latest| latest.rev_state.timestamp < stored_rev_state.rev_state.timestamp)
+                .unwrap_or(true);
+
+            if is_latest {
+                latest_rev_states.insert(key, stored_rev_state);
+            }
+        }
+
+        Ok(latest_rev_states.into_iter().map(|(_, stored_rev_state)| stored_rev_state).collect())
+    }
+
+    fn _search_stored_revocation_states(&self, wallet_handle: WalletHandle, query_json: &str) -> IndyResult<Vec<StoredRevocationState>> {
+        let mut search = self.wallet_service.search_indy_records::<StoredRevocationState>(wallet_handle, query_json, &SearchOptions::id_value())?;
+
+        let mut stored_rev_states = Vec::new();
+
+        while let Some(record) = search.fetch_next_record()? {
+            let value = record.get_value()
+                .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, format!("RevocationState not found for id: {}", record.get_id())))?;
+
+            let stored_rev_state: StoredRevocationState = serde_json::from_str(value)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize StoredRevocationState")?;
+
+            stored_rev_states.push(stored_rev_state);
+        }
+
+        Ok(stored_rev_states)
+    }
+
     fn _get_credential_info(&self,
                             referent: &str,
                             credential: Credential) -> CredentialInfo {
@@ -838,6 +1623,61 @@ impl ProverCommandExecutor {
         Ok(credentials)
     }
 
+    fn _query_credential_candidates(&self,
+                                    wallet_handle: WalletHandle,
+                                    query_json: &Query,
+                                    predicate_info: Option<&PredicateInfo>,
+                                    interval: &Option<NonRevocedInterval>,
+                                    rev_states: &RevocationStates) -> IndyResult<Vec<CredentialCandidate>> {
+        debug!("_query_credential_candidates >>> wallet_handle: {:?}, query_json: {:?}, predicate_info: {:?}, interval: {:?}",
+               wallet_handle, query_json, predicate_info, interval);
+
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, &query_json.to_string(), &_search_options_id_value_tags())?;
+
+        let mut candidates: Vec<CredentialCandidate> = Vec::new();
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            let (referent, credential) = self._get_credential(&credential_record)?;
+
+            if let Some(predicate) = predicate_info {
+                if !self._credential_satisfies_predicate(&credential, predicate)? { continue; }
+            }
+
+            // non-revocation can be proved only with a revocation state for the credential
+            let timestamp = match (interval, credential.rev_reg_id.as_ref()) {
+                (Some(interval), Some(rev_reg_id)) => match _get_rev_state_timestamp(rev_states, rev_reg_id, &referent, interval) {
+                    Some(timestamp) => Some(timestamp),
+                    None => continue
+                },
+                _ => None
+            };
+
+            let created_at = credential_record.get_tags()
+                .and_then(|tags| tags.get(CREDENTIAL_CREATED_AT_TAG))
+                .and_then(|created_at| created_at.parse::<u64>().ok())
+                .unwrap_or(0);
+
+            candidates.push(CredentialCandidate {
+                cred_id: referent,
+                rev_reg_id: credential.rev_reg_id,
+                created_at,
+                timestamp,
+            });
+        }
+
+        debug!("_query_credential_candidates <<< candidates: {:?}", candidates);
+
+        Ok(candidates)
+    }
+
+    fn _credential_satisfies_predicate(&self, credential: &Credential, predicate: &PredicateInfo) -> IndyResult<bool> {
+        let values = self.anoncreds_service.prover.get_credential_values_for_attribute(&credential.values.0, &predicate.name)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Credential values not found"))?;
+
+        self.anoncreds_service.prover.attribute_satisfy_predicate(predicate, &values.encoded)
+    }
+
     fn _get_requested_credentials(&self,
                                   credentials_search: &mut WalletSearch,
                                   predicate_info: Option<&PredicateInfo>,
@@ -853,11 +1693,7 @@ impl ProverCommandExecutor {
             let (referent, credential) = self._get_credential(&credential_record)?;
 
             if let Some(predicate) = predicate_info {
-                let values = self.anoncreds_service.prover.get_credential_values_for_attribute(&credential.values.0, &predicate.name)
-                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Credential values not found"))?;
-
-                let satisfy = self.anoncreds_service.prover.attribute_satisfy_predicate(predicate, &values.encoded)?;
-                if !satisfy { continue; }
+                if !self._credential_satisfies_predicate(&credential, predicate)? { continue; }
             }
 
             credentials.push(
@@ -881,5 +1717,107 @@ impl ProverCommandExecutor {
     fn _wallet_get_master_secret(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<MasterSecret> {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
+
+    // credentials stored before master secret binding was tracked have no tag and are bound to the single
+    // master secret of the wallet, the binding is stored before another master secret makes it ambiguous
+    fn _wallet_bind_untagged_credentials(&self, wallet_handle: WalletHandle) -> IndyResult<()> {
+        let mut master_secrets_search =
+            self.wallet_service.search_indy_records::<MasterSecret>(wallet_handle, "{}", &_search_options_id_tags())?;
+
+        let master_secret_id = match (master_secrets_search.fetch_next_record()?, master_secrets_search.fetch_next_record()?) {
+            (Some(master_secret_record), None) => master_secret_record.get_id().to_string(),
+            _ => return Ok(())
+        };
+
+        let mut credentials_search =
+            self.wallet_service.search_indy_records::<Credential>(wallet_handle, "{}", &_search_options_id_tags())?;
+
+        let mut untagged_cred_ids = Vec::new();
+
+        while let Some(credential_record) = credentials_search.fetch_next_record()? {
+            if credential_record.get_tags().map(|tags| !tags.contains_key(CREDENTIAL_MASTER_SECRET_TAG)).unwrap_or(true) {
+                untagged_cred_ids.push(credential_record.get_id().to_string());
+            }
+        }
+
+        let mut tags = HashMap::new();
+        tags.insert(CREDENTIAL_MASTER_SECRET_TAG.to_string(), master_secret_id);
+
+        for cred_id in untagged_cred_ids {
+            self.wallet_service.add_record_tags(wallet_handle, &self.wallet_service.add_prefix("Credential"), &cred_id, &tags)?;
+        }
+
+        Ok(())
+    }
+
+    fn _wallet_get_master_secret_retired_at(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<Option<u64>> {
+        let master_secret_record = self.wallet_service.get_indy_record::<MasterSecret>(wallet_handle, key, &_record_options_id_tags())?;
+
+        Ok(master_secret_record.get_tags()
+            .and_then(|tags| tags.get(MASTER_SECRET_RETIRED_AT_TAG))
+            .and_then(|retired_at| retired_at.parse::<u64>().ok()))
+    }
+}
+
+fn _is_unrestricted(restrictions: &Option<Query>) -> bool {
+    match restrictions {
+        Some(Query::And(queries)) | Some(Query::Or(queries)) => queries.is_empty(),
+        Some(_) => false,
+        None => true
+    }
+}
+
+fn _get_rev_state_timestamp(rev_states: &RevocationStates,
+                            rev_reg_id: &RevocationRegistryId,
+                            cred_id: &str,
+                            interval: &NonRevocedInterval) -> Option<u64> {
+    rev_states.get(&rev_reg_id.0)
+        .or_else(|| rev_states.get(cred_id))?
+        .keys()
+        .filter(|&&timestamp| interval.to.map(|to| timestamp <= to).unwrap_or(true))
+        .max()
+        .cloned()
+}
+
+fn _get_requested_timestamps(requested_credentials: &RequestedCredentials) -> HashSet<(String, u64)> {
+    let attrs = requested_credentials.requested_attributes.values()
+        .filter_map(|attr| attr.timestamp.map(|timestamp| (attr.cred_id.clone(), timestamp)));
+
+    let predicates = requested_credentials.requested_predicates.values()
+        .filter_map(|predicate| predicate.timestamp.map(|timestamp| (predicate.cred_id.clone(), timestamp)));
+
+    attrs.chain(predicates).collect()
+}
+
+fn _search_options_id_value_tags() -> String {
+    json!({
+        "retrieveRecords": true,
+        "retrieveTotalCount": false,
+        "retrieveType": false,
+        "retrieveValue": true,
+        "retrieveTags": true,
+    }).to_string()
 }
 
+fn _search_options_id_tags() -> String {
+    json!({
+        "retrieveRecords": true,
+        "retrieveTotalCount": false,
+        "retrieveType": false,
+        "retrieveValue": false,
+        "retrieveTags": true,
+    }).to_string()
+}
+
+fn _record_options_id_tags() -> String {
+    json!({
+        "retrieveType": false,
+        "retrieveValue": false,
+        "retrieveTags": true,
+    }).to_string()
+}
+
+fn _get_cur_time() -> u64 {
+    let since_epoch = SystemTime::now().duration_since(UNIX_EPOCH).expect("Time has gone backwards");
+    since_epoch.as_secs()
+}
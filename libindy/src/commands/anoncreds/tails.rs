@@ -7,10 +7,39 @@ use ursa::errors::prelude::{UrsaCryptoError, UrsaCryptoErrorKind};
 
 use rust_base58::{ToBase58, FromBase58};
 
+use libc::c_void;
+
+use std::collections::BTreeMap;
 use std::rc::Rc;
+use std::sync::Mutex;
+use std::sync::mpsc::channel;
 
 const TAILS_BLOB_TAG_SZ: u8 = 2;
 const TAIL_SIZE: usize = Tail::BYTES_REPR_SIZE;
+const TAILS_CHUNK_SIZE: u32 = 1024;
+
+pub type TailsProgressCB = extern fn(context: *const c_void,
+                                     tails_generated: u32,
+                                     tails_total: u32);
+
+lazy_static! {
+    // context pointer is stored as usize as raw pointers are not Send
+    static ref TAILS_PROGRESS_CALLBACK: Mutex<Option<(usize, TailsProgressCB)>> = Mutex::new(None);
+}
+
+pub fn set_tails_progress_callback(context: *const c_void, cb: Option<TailsProgressCB>) {
+    *TAILS_PROGRESS_CALLBACK.lock().unwrap() = cb.map(|cb| (context as usize, cb));
+}
+
+fn _get_tails_progress_callback() -> Option<(usize, TailsProgressCB)> {
+    *TAILS_PROGRESS_CALLBACK.lock().unwrap()
+}
+
+fn _report_tails_progress(progress_cb: Option<(usize, TailsProgressCB)>, tails_generated: u32, tails_total: u32) {
+    if let Some((context, cb)) = progress_cb {
+        cb(context as *const c_void, tails_generated, tails_total)
+    }
+}
 
 pub struct SDKTailsAccessor {
     tails_service: Rc<BlobStorageService>,
@@ -64,9 +93,11 @@ impl RevocationTailsAccessor for SDKTailsAccessor {
     }
 }
 
+/// Generates tails in chunks of `TAILS_CHUNK_SIZE` on the crypto thread pool and appends them
+/// to the blob in the original order as soon as all preceding chunks are ready.
 pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
                                   writer_handle: i32,
-                                  rtg: &mut RevocationTailsGenerator) -> IndyResult<(String, String)> {
+                                  rtg: &RevocationTailsGenerator) -> IndyResult<(String, String)> {
     debug!("store_tails_from_generator >>> writer_handle: {:?}", writer_handle);
 
     let blob_handle = service.create_blob(writer_handle)?;
@@ -74,9 +105,46 @@ pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
     let version = vec![0u8, TAILS_BLOB_TAG_SZ];
     service.append(blob_handle, version.as_slice())?;
 
-    while let Some(tail) = rtg.try_next()? {
-        let tail_bytes = tail.to_bytes()?;
-        service.append(blob_handle, tail_bytes.as_slice())?;
+    let tails_total = rtg.count();
+    // callback is copied out so it is never called under the lock and stays the same during generation
+    let progress_cb = _get_tails_progress_callback();
+    let chunks = _split_tails_generator(rtg, TAILS_CHUNK_SIZE)?;
+    let chunks_count = chunks.len();
+
+    let (sender, receiver) = channel();
+
+    {
+        let thread_pool = crate::commands::THREADPOOL.lock().unwrap();
+
+        for (chunk_id, mut chunk) in chunks.into_iter().enumerate() {
+            let sender = sender.clone();
+            thread_pool.execute(move || {
+                let _ = sender.send((chunk_id, _generate_tails_chunk(&mut chunk)));
+            });
+        }
+    }
+
+    // receiver iteration ends as soon as all workers finished (or panicked) and dropped their senders
+    drop(sender);
+
+    let mut pending = BTreeMap::new();
+    let mut next_chunk_id = 0;
+    let mut tails_generated = 0;
+
+    for (chunk_id, chunk) in receiver.iter() {
+        pending.insert(chunk_id, chunk?);
+
+        while let Some((tails_count, tails_bytes)) = pending.remove(&next_chunk_id) {
+            service.append(blob_handle, tails_bytes.as_slice())?;
+
+            next_chunk_id += 1;
+            tails_generated += tails_count;
+            _report_tails_progress(progress_cb, tails_generated, tails_total);
+        }
+    }
+
+    if next_chunk_id != chunks_count {
+        return Err(err_msg(IndyErrorKind::InvalidState, "Tails generation has been interrupted"));
     }
 
     let res = service.finalize(blob_handle).map(|(location, hash)| (location, hash.to_base58()))?;
@@ -84,3 +152,136 @@ pub fn store_tails_from_generator(service: Rc<BlobStorageService>,
     debug!("store_tails_from_generator <<< res: {:?}", res);
     Ok(res)
 }
+
+/// Splits generator into independent generators covering consecutive ranges of tails.
+///
+/// Ursa doesn't expose generator state, so ranges are set through its serialized form.
+fn _split_tails_generator(rtg: &RevocationTailsGenerator, chunk_size: u32) -> IndyResult<Vec<RevocationTailsGenerator>> {
+    let mut rtg_json = serde_json::to_value(rtg)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationTailsGenerator")?;
+
+    let from = rtg_json["current_index"].as_u64()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Invalid RevocationTailsGenerator: current_index not found"))?;
+    let to = rtg_json["size"].as_u64()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "Invalid RevocationTailsGenerator: size not found"))?;
+
+    let mut chunks = Vec::new();
+    let mut start = from;
+
+    while start < to {
+        let end = ::std::cmp::min(start + chunk_size as u64, to);
+
+        rtg_json["current_index"] = json!(start);
+        rtg_json["size"] = json!(end);
+
+        chunks.push(serde_json::from_value(rtg_json.clone())
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationTailsGenerator")?);
+
+        start = end;
+    }
+
+    Ok(chunks)
+}
+
+fn _generate_tails_chunk(rtg: &mut RevocationTailsGenerator) -> IndyResult<(u32, Vec<u8>)> {
+    let mut tails_bytes = Vec::with_capacity(rtg.count() as usize * TAIL_SIZE);
+    let mut tails_count = 0;
+
+    while let Some(tail) = rtg.try_next()? {
+        tails_bytes.extend(tail.to_bytes()?);
+        tails_count += 1;
+    }
+
+    Ok((tails_count, tails_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use indy_utils::environment;
+
+    use crate::services::anoncreds::issuer::Issuer;
+    use crate::domain::anoncreds::credential_definition::{CredentialDefinitionId, CredentialDefinitionV1, SignatureType};
+    use crate::domain::anoncreds::schema::{AttributeNames, SchemaId};
+    use crate::domain::crypto::did::DidValue;
+
+    fn _tails_generator(max_cred_num: u32) -> RevocationTailsGenerator {
+        let attr_names = AttributeNames::from(vec!["name".to_string()].into_iter().collect::<HashSet<String>>());
+        let (cred_def_data, _, _) = Issuer::new_credential_definition(&attr_names, true).unwrap();
+
+        let cred_def = CredentialDefinitionV1 {
+            id: CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:CL:1:tag".to_string()),
+            schema_id: SchemaId("1".to_string()),
+            signature_type: SignatureType::CL,
+            tag: "tag".to_string(),
+            value: cred_def_data,
+        };
+
+        let (_, _, _, rtg) = Issuer::new()
+            .new_revocation_registry(&cred_def, max_cred_num, true, &DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string()))
+            .unwrap();
+        rtg
+    }
+
+    fn _serial_tails_bytes(mut rtg: RevocationTailsGenerator) -> Vec<u8> {
+        let mut tails_bytes = Vec::new();
+        while let Some(tail) = rtg.try_next().unwrap() {
+            tails_bytes.extend(tail.to_bytes().unwrap());
+        }
+        tails_bytes
+    }
+
+    lazy_static! {
+        static ref REPORTED_PROGRESS: Mutex<Vec<(u32, u32)>> = Mutex::new(Vec::new());
+    }
+
+    extern fn _record_progress(_context: *const c_void, tails_generated: u32, tails_total: u32) {
+        REPORTED_PROGRESS.lock().unwrap().push((tails_generated, tails_total));
+    }
+
+    #[test]
+    fn split_tails_generator_works() {
+        let rtg = _tails_generator(5);
+
+        let chunks = _split_tails_generator(&rtg, 4).unwrap();
+        assert_eq!(vec![4, 4, 3], chunks.iter().map(RevocationTailsGenerator::count).collect::<Vec<u32>>());
+
+        let mut actual = Vec::new();
+        for mut chunk in chunks {
+            actual.extend(_generate_tails_chunk(&mut chunk).unwrap().1);
+        }
+
+        assert_eq!(_serial_tails_bytes(rtg), actual);
+    }
+
+    #[test]
+    fn store_tails_from_generator_works_same_as_serial_generation() {
+        // more than one chunk of tails
+        let rtg = _tails_generator(TAILS_CHUNK_SIZE / 2 + 100);
+        let tails_total = rtg.count();
+        assert!(tails_total > TAILS_CHUNK_SIZE);
+
+        let base_dir = environment::tmp_file_path("store_tails_from_generator_works_same_as_serial_generation");
+        let service = Rc::new(BlobStorageService::new());
+        let writer_handle = service.open_writer("default", &json!({"base_dir": base_dir, "uri_pattern": ""}).to_string()).unwrap();
+
+        set_tails_progress_callback(::std::ptr::null(), Some(_record_progress));
+        let res = store_tails_from_generator(service, writer_handle, &rtg);
+        set_tails_progress_callback(::std::ptr::null(), None);
+
+        let (location, _) = res.unwrap();
+
+        let mut expected = vec![0u8, TAILS_BLOB_TAG_SZ];
+        expected.extend(_serial_tails_bytes(rtg));
+
+        assert_eq!(expected, ::std::fs::read(&location).unwrap());
+
+        let reported_progress = REPORTED_PROGRESS.lock().unwrap().clone();
+        assert_eq!(vec![(TAILS_CHUNK_SIZE, tails_total), (tails_total, tails_total)], reported_progress);
+
+        ::std::fs::remove_dir_all(base_dir).unwrap();
+    }
+}
@@ -1,14 +1,22 @@
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 
-use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
+use crate::commands::{Command, CommandExecutor};
+use crate::commands::anoncreds::AnoncredsCommand;
+use crate::commands::cache::CacheCommand;
+use crate::domain::anoncreds::credential_definition::{cred_defs_map_to_cred_defs_v1_map, CredentialDefinition, CredentialDefinitionV1, CredentialDefinitionId, CredentialDefinitions};
 use crate::domain::anoncreds::proof::Proof;
 use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
-use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistryV1, RevocationRegistries};
-use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
-use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, SchemaV1, SchemaId, Schemas};
+use crate::domain::anoncreds::proof_verification::{ProofVerificationResult, VerificationCheck, VerificationFailure, VerifyProofWithLedgerOptions};
+use crate::domain::anoncreds::revocation_registry::{rev_regs_map_to_rev_regs_local_map, RevocationRegistry, RevocationRegistryV1, RevocationRegistries};
+use crate::domain::anoncreds::revocation_registry_definition::{rev_reg_defs_map_to_rev_reg_defs_v1_map, RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId, RevocationRegistryDefinitions};
+use crate::domain::anoncreds::schema::{schemas_map_to_schemas_v1_map, Schema, SchemaV1, SchemaId, Schemas};
 use indy_api_types::errors::prelude::*;
+use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
+use indy_utils::next_command_handle;
 use crate::services::anoncreds::AnoncredsService;
+use crate::services::anoncreds::verifier::Verifier;
 
 pub enum VerifierCommand {
     VerifyProof(
@@ -19,18 +27,72 @@ pub enum VerifierCommand {
         RevocationRegistryDefinitions, // rev reg defs
         RevocationRegistries, // rev reg entries
         Box<dyn Fn(IndyResult<bool>) + Send>),
+    VerifyProofWithReport(
+        ProofRequest, // proof request
+        Proof, // proof
+        Schemas, // credential schemas
+        CredentialDefinitions, // credential defs
+        RevocationRegistryDefinitions, // rev reg defs
+        RevocationRegistries, // rev reg entries
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyProofWithLedger(
+        PoolHandle,
+        WalletHandle,
+        ProofRequest, // proof request
+        Proof, // proof
+        VerifyProofWithLedgerOptions, // options
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyProofWithLedgerContinue(
+        CommandHandle, // verification id
+        LedgerObject, // fetched ledger object
+    ),
     GenerateNonce(
         Box<dyn Fn(IndyResult<String>) + Send>)
 }
 
+pub enum LedgerObject {
+    Schema(SchemaId, IndyResult<String>),
+    CredDef(CredentialDefinitionId, IndyResult<String>),
+    RevRegDef(RevocationRegistryId, IndyResult<String>),
+    RevReg(RevocationRegistryId, u64, IndyResult<String>),
+}
+
+impl LedgerObject {
+    fn description(&self) -> String {
+        match self {
+            LedgerObject::Schema(id, _) => format!("Schema {:?}", id.0),
+            LedgerObject::CredDef(id, _) => format!("CredentialDefinition {:?}", id.0),
+            LedgerObject::RevRegDef(id, _) => format!("RevocationRegistryDefinition {:?}", id.0),
+            LedgerObject::RevReg(id, timestamp, _) => format!("RevocationRegistry {:?} for timestamp {}", id.0, timestamp),
+        }
+    }
+}
+
+struct PendingVerification {
+    proof_req: ProofRequest,
+    proof: Proof,
+    pending: usize,
+    schemas: HashMap<SchemaId, SchemaV1>,
+    cred_defs: HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+    rev_reg_defs: HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+    rev_regs: HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>,
+    // entities which can't be resolved from the ledger, reported as failures of verification
+    failures: Vec<VerificationFailure>,
+    // error which doesn't allow to complete verification at all
+    error: Option<IndyError>,
+    cb: Box<dyn Fn(IndyResult<String>)>,
+}
+
 pub struct VerifierCommandExecutor {
     anoncreds_service: Rc<AnoncredsService>,
+    pending_verifications: RefCell<HashMap<CommandHandle, PendingVerification>>,
 }
 
 impl VerifierCommandExecutor {
     pub fn new(anoncreds_service: Rc<AnoncredsService>) -> VerifierCommandExecutor {
         VerifierCommandExecutor {
             anoncreds_service,
+            pending_verifications: RefCell::new(HashMap::new()),
         }
     }
 
@@ -44,6 +106,22 @@ impl VerifierCommandExecutor {
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
                                      &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
+            VerifierCommand::VerifyProofWithReport(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithReport command received");
+                cb(self.verify_proof_with_report(&proof_request.value(), proof,
+                                                 &schemas_map_to_schemas_v1_map(schemas),
+                                                 &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                                 &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                                 &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+            }
+            VerifierCommand::VerifyProofWithLedger(pool_handle, wallet_handle, proof_request, proof, options, cb) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithLedger command received");
+                self.verify_proof_with_ledger(pool_handle, wallet_handle, proof_request, proof, options, cb);
+            }
+            VerifierCommand::VerifyProofWithLedgerContinue(verification_id, ledger_object) => {
+                debug!(target: "verifier_command_executor", "VerifyProofWithLedgerContinue command received");
+                self._verify_proof_with_ledger_continue(verification_id, ledger_object);
+            }
             VerifierCommand::GenerateNonce(cb) => {
                 debug!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
@@ -74,6 +152,241 @@ impl VerifierCommandExecutor {
         Ok(result)
     }
 
+    fn verify_proof_with_report(&self,
+                                proof_req: &ProofRequestPayload,
+                                proof: Proof,
+                                schemas: &HashMap<SchemaId, SchemaV1>,
+                                cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                                rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                                rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_with_report >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.anoncreds_service.verifier.verify_report(&proof,
+                                                                   &proof_req,
+                                                                   schemas,
+                                                                   cred_defs,
+                                                                   rev_reg_defs,
+                                                                   rev_regs)?;
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationReport")?;
+
+        debug!("verify_proof_with_report <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_proof_with_ledger(&self,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                proof_req: ProofRequest,
+                                proof: Proof,
+                                options: VerifyProofWithLedgerOptions,
+                                cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("verify_proof_with_ledger >>> pool_handle: {:?}, wallet_handle: {:?}, proof_req: {:?}, proof: {:?}, options: {:?}",
+               pool_handle, wallet_handle, proof_req, proof, options);
+
+        let schema_ids: HashSet<SchemaId> = proof.identifiers.iter().map(|identifier| identifier.schema_id.clone()).collect();
+        let cred_def_ids: HashSet<CredentialDefinitionId> = proof.identifiers.iter().map(|identifier| identifier.cred_def_id.clone()).collect();
+        let rev_reg_ids: HashSet<RevocationRegistryId> = proof.identifiers.iter()
+            .filter(|identifier| identifier.timestamp.is_some())
+            .filter_map(|identifier| identifier.rev_reg_id.clone())
+            .collect();
+        let rev_reg_timestamps: HashSet<(RevocationRegistryId, u64)> = proof.identifiers.iter()
+            .filter_map(|identifier| match (identifier.rev_reg_id.as_ref(), identifier.timestamp) {
+                (Some(rev_reg_id), Some(timestamp)) => Some((rev_reg_id.clone(), timestamp)),
+                _ => None
+            })
+            .collect();
+
+        let pending = schema_ids.len() + cred_def_ids.len() + rev_reg_ids.len() + rev_reg_timestamps.len();
+        let verification_id = next_command_handle();
+
+        self.pending_verifications.borrow_mut().insert(verification_id, PendingVerification {
+            proof_req,
+            proof,
+            pending,
+            schemas: HashMap::new(),
+            cred_defs: HashMap::new(),
+            rev_reg_defs: HashMap::new(),
+            rev_regs: HashMap::new(),
+            failures: Vec::new(),
+            error: None,
+            cb,
+        });
+
+        if pending == 0 {
+            return self._complete_verification(verification_id);
+        }
+
+        let cache_options = options.cache.unwrap_or_default();
+
+        for schema_id in schema_ids {
+            CommandExecutor::instance().send(
+                Command::Cache(
+                    CacheCommand::GetSchema(
+                        pool_handle,
+                        wallet_handle,
+                        options.submitter_did.clone(),
+                        schema_id.clone(),
+                        cache_options.clone(),
+                        Box::new(move |result| {
+                            VerifierCommandExecutor::_send_ledger_object(verification_id, LedgerObject::Schema(schema_id.clone(), result))
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+
+        for cred_def_id in cred_def_ids {
+            CommandExecutor::instance().send(
+                Command::Cache(
+                    CacheCommand::GetCredDef(
+                        pool_handle,
+                        wallet_handle,
+                        options.submitter_did.clone(),
+                        cred_def_id.clone(),
+                        cache_options.clone(),
+                        Box::new(move |result| {
+                            VerifierCommandExecutor::_send_ledger_object(verification_id, LedgerObject::CredDef(cred_def_id.clone(), result))
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+
+        for rev_reg_id in rev_reg_ids {
+            CommandExecutor::instance().send(
+                Command::Cache(
+                    CacheCommand::GetRevocRegDef(
+                        pool_handle,
+                        wallet_handle,
+                        options.submitter_did.clone(),
+                        rev_reg_id.clone(),
+                        cache_options.clone(),
+                        Box::new(move |result| {
+                            VerifierCommandExecutor::_send_ledger_object(verification_id, LedgerObject::RevRegDef(rev_reg_id.clone(), result))
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+
+        for (rev_reg_id, timestamp) in rev_reg_timestamps {
+            CommandExecutor::instance().send(
+                Command::Cache(
+                    CacheCommand::GetRevocReg(
+                        pool_handle,
+                        wallet_handle,
+                        options.submitter_did.clone(),
+                        rev_reg_id.clone(),
+                        timestamp,
+                        cache_options.clone(),
+                        Box::new(move |result| {
+                            VerifierCommandExecutor::_send_ledger_object(verification_id, LedgerObject::RevReg(rev_reg_id.clone(), timestamp, result))
+                        })
+                    )
+                )
+            ).unwrap();
+        }
+    }
+
+    fn _send_ledger_object(verification_id: CommandHandle, ledger_object: LedgerObject) {
+        CommandExecutor::instance().send(
+            Command::Anoncreds(
+                AnoncredsCommand::Verifier(
+                    VerifierCommand::VerifyProofWithLedgerContinue(
+                        verification_id,
+                        ledger_object,
+                    )
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _verify_proof_with_ledger_continue(&self, verification_id: CommandHandle, ledger_object: LedgerObject) {
+        let completed = {
+            let mut pending_verifications = self.pending_verifications.borrow_mut();
+            let verification = pending_verifications.get_mut(&verification_id).expect("FIXME INVALID STATE");
+
+            let description = ledger_object.description();
+
+            if let Err(err) = VerifierCommandExecutor::_store_ledger_object(verification, ledger_object) {
+                match err.kind() {
+                    IndyErrorKind::LedgerItemNotFound | IndyErrorKind::InvalidStructure | IndyErrorKind::InvalidTransaction =>
+                        verification.failures.push(VerificationFailure::new(
+                            VerificationCheck::Identifiers,
+                            format!("{} can't be resolved from the ledger: {}", description, Verifier::failure_message(&err)))),
+                    _ => if verification.error.is_none() {
+                        verification.error = Some(err);
+                    }
+                }
+            }
+
+            verification.pending -= 1;
+            verification.pending == 0
+        };
+
+        if completed {
+            self._complete_verification(verification_id);
+        }
+    }
+
+    fn _store_ledger_object(verification: &mut PendingVerification, ledger_object: LedgerObject) -> IndyResult<()> {
+        match ledger_object {
+            LedgerObject::Schema(id, result) => {
+                let schema: Schema = serde_json::from_str(&result?)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize Schema")?;
+                verification.schemas.insert(id, SchemaV1::from(schema));
+            }
+            LedgerObject::CredDef(id, result) => {
+                let cred_def: CredentialDefinition = serde_json::from_str(&result?)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize CredentialDefinition")?;
+                verification.cred_defs.insert(id, CredentialDefinitionV1::from(cred_def));
+            }
+            LedgerObject::RevRegDef(id, result) => {
+                let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(&result?)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistryDefinition")?;
+                verification.rev_reg_defs.insert(id, RevocationRegistryDefinitionV1::from(rev_reg_def));
+            }
+            LedgerObject::RevReg(id, timestamp, result) => {
+                let rev_reg: RevocationRegistry = serde_json::from_str(&result?)
+                    .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize RevocationRegistry")?;
+                verification.rev_regs.entry(id).or_insert_with(HashMap::new).insert(timestamp, RevocationRegistryV1::from(rev_reg));
+            }
+        }
+        Ok(())
+    }
+
+    fn _complete_verification(&self, verification_id: CommandHandle) {
+        let verification = self.pending_verifications.borrow_mut().remove(&verification_id).expect("FIXME INVALID STATE");
+        let cb = verification.cb;
+
+        if let Some(err) = verification.error {
+            return cb(Err(err));
+        }
+
+        let result = self.anoncreds_service.verifier.verify_with_result(&verification.proof,
+                                                                        &verification.proof_req.value(),
+                                                                        &verification.schemas,
+                                                                        &verification.cred_defs,
+                                                                        &verification.rev_reg_defs,
+                                                                        &verification.rev_regs);
+        let result = try_cb!(result, cb);
+
+        let mut failures = verification.failures;
+        failures.extend(result.failures);
+
+        let res = serde_json::to_string(&ProofVerificationResult::new(failures))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationResult");
+
+        debug!("verify_proof_with_ledger <<< res: {:?}", res);
+
+        cb(res)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
@@ -6,6 +6,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use indy_api_types::domain::wallet::Tags;
 use crate::domain::anoncreds::schema::SchemaId;
 use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
 use indy_api_types::errors::prelude::*;
 use indy_wallet::{WalletService, WalletRecord};
 use indy_api_types::{WalletHandle, PoolHandle, CommandHandle};
@@ -18,11 +19,14 @@ use indy_utils::next_command_handle;
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const DID_DOC_CACHE: &str = "did_doc_cache";
+const REV_REG_DEF_CACHE: &str = "rev_reg_def_cache";
+const REV_REG_CACHE: &str = "rev_reg_cache";
 
 pub enum CacheCommand {
     GetSchema(PoolHandle,
               WalletHandle,
-              DidValue, // submitter_did
+              Option<DidValue>, // submitter_did
               SchemaId, // id
               GetCacheOptions, // options
               Box<dyn Fn(IndyResult<String>) + Send>),
@@ -34,7 +38,7 @@ pub enum CacheCommand {
     ),
     GetCredDef(PoolHandle,
                WalletHandle,
-               DidValue, // submitter_did
+               Option<DidValue>, // submitter_did
                CredentialDefinitionId, // id
                GetCacheOptions, // options
                Box<dyn Fn(IndyResult<String>) + Send>),
@@ -44,6 +48,44 @@ pub enum CacheCommand {
         GetCacheOptions,              // options
         CommandHandle,                          // cb_id
     ),
+    GetRevocRegDef(PoolHandle,
+                   WalletHandle,
+                   Option<DidValue>, // submitter_did
+                   RevocationRegistryId, // id
+                   GetCacheOptions, // options
+                   Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocRegDefContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        CommandHandle,                          // cb_id
+    ),
+    GetRevocReg(PoolHandle,
+                WalletHandle,
+                Option<DidValue>, // submitter_did
+                RevocationRegistryId, // id
+                u64, // timestamp
+                GetCacheOptions, // options
+                Box<dyn Fn(IndyResult<String>) + Send>),
+    GetRevocRegContinue(
+        WalletHandle,
+        String, // cache id
+        IndyResult<(String, String, u64)>, // ledger_response
+        GetCacheOptions,                   // options
+        CommandHandle,                               // cb_id
+    ),
+    ResolveDid(PoolHandle,
+               WalletHandle,
+               DidValue, // did
+               GetCacheOptions, // options
+               Box<dyn Fn(IndyResult<String>) + Send>),
+    ResolveDidContinue(
+        WalletHandle,
+        DidValue, // did
+        IndyResult<String>, // ledger_response
+        GetCacheOptions,    // options
+        CommandHandle,      // cb_id
+    ),
     PurgeSchemaCache(WalletHandle,
                      PurgeOptions, // options
                      Box<dyn Fn(IndyResult<()>) + Send>),
@@ -91,7 +133,7 @@ impl CacheCommandExecutor {
         match command {
             CacheCommand::GetSchema(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "GetSchema command received");
-                self.get_schema(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
+                self.get_schema(pool_handle, wallet_handle, submitter_did.as_ref(), &id, options, cb);
             }
             CacheCommand::GetSchemaContinue(wallet_handle, ledger_response, options, cb_id) => {
                 debug!(target: "non_secrets_command_executor", "GetSchemaContinue command received");
@@ -99,12 +141,36 @@ impl CacheCommandExecutor {
             }
             CacheCommand::GetCredDef(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "GetCredDef command received");
-                self.get_cred_def(pool_handle, wallet_handle, &submitter_did, &id, options, cb);
+                self.get_cred_def(pool_handle, wallet_handle, submitter_did.as_ref(), &id, options, cb);
             }
             CacheCommand::GetCredDefContinue(wallet_handle, ledger_response, options, cb_id) => {
                 debug!(target: "non_secrets_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(wallet_handle, ledger_response, options, cb_id);
             }
+            CacheCommand::GetRevocRegDef(pool_handle, wallet_handle, submitter_did, id, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, wallet_handle, submitter_did.as_ref(), &id, options, cb);
+            }
+            CacheCommand::GetRevocRegDefContinue(wallet_handle, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(wallet_handle, ledger_response, options, cb_id);
+            }
+            CacheCommand::GetRevocReg(pool_handle, wallet_handle, submitter_did, id, timestamp, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocReg command received");
+                self.get_revoc_reg(pool_handle, wallet_handle, submitter_did.as_ref(), &id, timestamp, options, cb);
+            }
+            CacheCommand::GetRevocRegContinue(wallet_handle, cache_id, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "GetRevocRegContinue command received");
+                self._get_revoc_reg_continue(wallet_handle, &cache_id, ledger_response, options, cb_id);
+            }
+            CacheCommand::ResolveDid(pool_handle, wallet_handle, did, options, cb) => {
+                debug!(target: "non_secrets_command_executor", "ResolveDid command received");
+                self.resolve_did(pool_handle, wallet_handle, &did, options, cb);
+            }
+            CacheCommand::ResolveDidContinue(wallet_handle, did, ledger_response, options, cb_id) => {
+                debug!(target: "non_secrets_command_executor", "ResolveDidContinue command received");
+                self._resolve_did_continue(wallet_handle, &did, ledger_response, options, cb_id);
+            }
             CacheCommand::PurgeSchemaCache(wallet_handle, options, cb) => {
                 debug!(target: "non_secrets_command_executor", "PurgeSchemaCache command received");
                 cb(self.purge_schema_cache(wallet_handle, options));
@@ -119,7 +185,7 @@ impl CacheCommandExecutor {
     fn get_schema(&self,
                   pool_handle: PoolHandle,
                   wallet_handle: WalletHandle,
-                  submitter_did: &DidValue,
+                  submitter_did: Option<&DidValue>,
                   id: &SchemaId,
                   options: GetCacheOptions,
                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
@@ -142,7 +208,7 @@ impl CacheCommandExecutor {
             Command::Ledger(
                 LedgerCommand::GetSchema(
                     pool_handle,
-                    Some(submitter_did.clone()),
+                    submitter_did.cloned(),
                     id.clone(),
                     Box::new(move |ledger_response| {
                         CommandExecutor::instance().send(
@@ -201,7 +267,7 @@ impl CacheCommandExecutor {
     fn get_cred_def(&self,
                     pool_handle: PoolHandle,
                     wallet_handle: WalletHandle,
-                    submitter_did: &DidValue,
+                    submitter_did: Option<&DidValue>,
                     id: &CredentialDefinitionId,
                     options: GetCacheOptions,
                     cb: Box<dyn Fn(IndyResult<String>) + Send>) {
@@ -224,7 +290,7 @@ impl CacheCommandExecutor {
             Command::Ledger(
                 LedgerCommand::GetCredDef(
                     pool_handle,
-                    Some(submitter_did.clone()),
+                    submitter_did.cloned(),
                     id.clone(),
                     Box::new(move |ledger_response| {
                         CommandExecutor::instance().send(
@@ -268,6 +334,192 @@ impl CacheCommandExecutor {
         }
     }
 
+    fn get_revoc_reg_def(&self,
+                         pool_handle: PoolHandle,
+                         wallet_handle: WalletHandle,
+                         submitter_did: Option<&DidValue>,
+                         id: &RevocationRegistryId,
+                         options: GetCacheOptions,
+                         cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_revoc_reg_def >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &id.0, &options, REV_REG_DEF_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocRegDef(
+                    pool_handle,
+                    submitter_did.cloned(),
+                    id.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegDefContinue(
+                                    wallet_handle,
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_revoc_reg_def_continue(&self, wallet_handle: WalletHandle, ledger_response: IndyResult<(String, String)>, options: GetCacheOptions, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (rev_reg_def_id, rev_reg_def_json) = try_cb!(ledger_response, cb);
+
+        match self._delete_and_add_record(wallet_handle, options, &rev_reg_def_id, &rev_reg_def_json, REV_REG_DEF_CACHE) {
+            Ok(_) => cb(Ok(rev_reg_def_json)),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_revoc_reg_def_continue failed: {:?}", err))))
+        }
+    }
+
+    /// Revocation registry state is cached by registry id and the requested timestamp,
+    /// as the state of the registry at given moment doesn't change.
+    fn get_revoc_reg(&self,
+                     pool_handle: PoolHandle,
+                     wallet_handle: WalletHandle,
+                     submitter_did: Option<&DidValue>,
+                     id: &RevocationRegistryId,
+                     timestamp: u64,
+                     options: GetCacheOptions,
+                     cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("get_revoc_reg >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, timestamp: {:?}, options: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, timestamp, options);
+
+        let cache_id = format!("{}:{}", id.0, timestamp);
+
+        let cache = self.get_record_from_cache(wallet_handle, &cache_id, &options, REV_REG_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRevocReg(
+                    pool_handle,
+                    submitter_did.cloned(),
+                    id.clone(),
+                    timestamp as i64,
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRevocRegContinue(
+                                    wallet_handle,
+                                    cache_id.clone(),
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_revoc_reg_continue(&self,
+                               wallet_handle: WalletHandle,
+                               cache_id: &str,
+                               ledger_response: IndyResult<(String, String, u64)>,
+                               options: GetCacheOptions,
+                               cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (_, rev_reg_json, _) = try_cb!(ledger_response, cb);
+
+        match self._delete_and_add_record(wallet_handle, options, cache_id, &rev_reg_json, REV_REG_CACHE) {
+            Ok(_) => cb(Ok(rev_reg_json)),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("get_revoc_reg_continue failed: {:?}", err))))
+        }
+    }
+
+    fn resolve_did(&self,
+                   pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: &DidValue,
+                   options: GetCacheOptions,
+                   cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        trace!("resolve_did >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options: {:?}",
+               pool_handle, wallet_handle, did, options);
+
+        let cache = self.get_record_from_cache(wallet_handle, &did.0, &options, DID_DOC_CACHE);
+        let cache = try_cb!(cache, cb);
+
+        check_cache!(cache, options, cb);
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        let did = did.clone();
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetDidDocument(
+                    pool_handle,
+                    did.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::ResolveDidContinue(
+                                    wallet_handle,
+                                    did.clone(),
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _resolve_did_continue(&self,
+                             wallet_handle: WalletHandle,
+                             did: &DidValue,
+                             ledger_response: IndyResult<String>,
+                             options: GetCacheOptions,
+                             cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let resolution_result = try_cb!(ledger_response, cb);
+
+        match self._delete_and_add_record(wallet_handle, options, &did.0, &resolution_result, DID_DOC_CACHE) {
+            Ok(_) => cb(Ok(resolution_result)),
+            Err(err) => cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("resolve_did_continue failed: {:?}", err))))
+        }
+    }
+
     fn get_seconds_since_epoch() -> Result<i32, IndyError> {
         match SystemTime::now().duration_since(UNIX_EPOCH) {
             Ok(ts) => Ok(ts.as_secs() as i32),
@@ -1,7 +1,9 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem;
 use std::rc::Rc;
 use std::string::ToString;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use indy_api_types::{CommandHandle, PoolHandle, WalletHandle};
 use indy_api_types::errors::prelude::*;
@@ -14,6 +16,7 @@ use indy_wallet::{RecordOptions, WalletService};
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
 use crate::commands::{BoxedCallbackStringStringSend, Command, CommandExecutor};
+use crate::commands::pool::PoolCommand;
 use crate::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionId, CredentialDefinitionV1};
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
@@ -22,16 +25,23 @@ use crate::domain::crypto::did::{Did, DidValue};
 use crate::domain::crypto::key::Key;
 use crate::domain::ledger::auth_rule::{AuthRules, Constraint};
 use crate::domain::ledger::author_agreement::{AcceptanceMechanisms, GetTxnAuthorAgreementData};
+use crate::domain::ledger::ddo::{DIDDOC_CONTENT_ATTRIB, DID_LD_JSON_CONTENT_TYPE, DidDocumentMetadata, DidResolutionMetadata, DidResolutionResult, ENDPOINT_ATTRIB};
 use crate::domain::ledger::node::NodeOperationData;
+use crate::domain::ledger::nym::NymData;
 use crate::domain::ledger::pool::Schedule;
 use crate::domain::ledger::request::Request;
+use crate::domain::ledger::txn::TxnInclusionProof;
+use crate::domain::pool::ReadPolicy;
 use crate::services::crypto::CryptoService;
 use crate::services::ledger::LedgerService;
 use crate::services::pool::{
+    extract_txn_inclusion_proof,
     parse_response_metadata,
+    verify_txn_inclusion_proof,
     PoolService
 };
 use crate::utils::crypto::signature_serializer::serialize_signature;
+use crate::utils::qualifier;
 
 pub enum LedgerCommand {
     SignAndSubmitRequest(
@@ -44,6 +54,11 @@ pub enum LedgerCommand {
         PoolHandle, // pool handle
         String, // request json
         Box<dyn Fn(IndyResult<String>) + Send>),
+    SubmitRequestWithPolicy(
+        PoolHandle, // pool handle
+        String, // request json
+        ReadPolicy, // read policy
+        Box<dyn Fn(IndyResult<String>) + Send>),
     SubmitAck(
         CommandHandle,
         IndyResult<String>, // result json or error
@@ -126,11 +141,24 @@ pub enum LedgerCommand {
     BuildGetValidatorInfoRequest(
         DidValue, // submitter did
         Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetValidatorInfoResponse(
+        String, // get validator info response
+        Box<dyn Fn(IndyResult<String>) + Send>),
     BuildGetTxnRequest(
         Option<DidValue>, // submitter did
         Option<String>, // ledger type
         i32, // data
         Box<dyn Fn(IndyResult<String>) + Send>),
+    ParseGetTxnResponse(
+        String, // get txn response
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    ExtractTxnInclusionProof(
+        String, // get txn response
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    VerifyTxnInclusionProof(
+        TxnInclusionProof, // proof
+        HashMap<String, String>, // nodes bls keys
+        Box<dyn Fn(IndyResult<bool>) + Send>),
     BuildPoolConfigRequest(
         DidValue, // submitter did
         bool, // writes
@@ -239,6 +267,50 @@ pub enum LedgerCommand {
         IndyResult<String>,
         CommandHandle,
     ),
+    GetRevocRegDef(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        BoxedCallbackStringStringSend,
+    ),
+    GetRevocRegDefContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocReg(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        i64, // timestamp
+        Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>,
+    ),
+    GetRevocRegContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    GetRevocRegDelta(
+        PoolHandle,
+        Option<DidValue>,
+        RevocationRegistryId,
+        Option<i64>, // from
+        i64, // to
+        Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>,
+    ),
+    GetRevocRegDeltaContinue(
+        IndyResult<String>,
+        CommandHandle,
+    ),
+    RegistryPoolResolved(
+        CommandHandle, // deferred command id
+        IndyResult<PoolHandle>),
+    GetDidDocument(
+        PoolHandle,
+        DidValue, // did
+        Box<dyn Fn(IndyResult<String>) + Send>),
+    GetDidDocumentContinue(
+        IndyResult<String>, // pool response
+        CommandHandle,
+    ),
     BuildTxnAuthorAgreementRequest(
         DidValue, // submitter did
         Option<String>, // text
@@ -293,6 +365,24 @@ pub struct LedgerCommandExecutor {
 
     send_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<String>)>>>,
     pending_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String)>)>>>,
+    pending_revoc_regs: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<(String, String, u64)>)>>>,
+    pending_did_documents: RefCell<HashMap<CommandHandle, (DidDocumentRequest, Box<dyn Fn(IndyResult<String>)>)>>,
+    deferred_commands: RefCell<HashMap<CommandHandle, LedgerCommand>>,
+}
+
+struct DidDocumentRequest {
+    pool_handle: PoolHandle,
+    did: DidValue,
+    ledger_did: DidValue, // `did` as it is written on the ledger
+    step: DidDocumentStep,
+    metadata: DidDocumentMetadata,
+}
+
+// NYM, `endpoint` ATTRIB and `diddocContent` ATTRIB are requested one after another
+enum DidDocumentStep {
+    Nym,
+    Endpoint(NymData),
+    DiddocContent(NymData, Option<String>), // NYM data and `endpoint` ATTRIB
 }
 
 impl LedgerCommandExecutor {
@@ -307,10 +397,18 @@ impl LedgerCommandExecutor {
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_revoc_regs: RefCell::new(HashMap::new()),
+            pending_did_documents: RefCell::new(HashMap::new()),
+            deferred_commands: RefCell::new(HashMap::new()),
         }
     }
 
     pub fn execute(&self, command: LedgerCommand) {
+        let command = match self._defer_for_registry_pool(command) {
+            Some(command) => command,
+            None => return
+        };
+
         match command {
             LedgerCommand::SignAndSubmitRequest(pool_handle, wallet_handle, submitter_did, request_json, cb) => {
                 debug!(target: "ledger_command_executor", "SignAndSubmitRequest command received");
@@ -320,6 +418,10 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_request(handle, &request_json, cb);
             }
+            LedgerCommand::SubmitRequestWithPolicy(handle, request_json, read_policy, cb) => {
+                debug!(target: "ledger_command_executor", "SubmitRequestWithPolicy command received");
+                self.submit_request_with_policy(handle, &request_json, &read_policy, cb);
+            }
             LedgerCommand::SubmitAck(handle, result) => {
                 debug!(target: "ledger_command_executor", "SubmitAck command received");
                 match self.send_callbacks.borrow_mut().remove(&handle) {
@@ -411,10 +513,26 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "BuildGetValidatorInfoRequest command received");
                 cb(self.build_get_validator_info_request(&submitter_did));
             }
+            LedgerCommand::ParseGetValidatorInfoResponse(response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetValidatorInfoResponse command received");
+                cb(self.parse_get_validator_info_response(&response));
+            }
             LedgerCommand::BuildGetTxnRequest(submitter_did, ledger_type, seq_no, cb) => {
                 debug!(target: "ledger_command_executor", "BuildGetTxnRequest command received");
                 cb(self.build_get_txn_request(submitter_did.as_ref(), ledger_type.as_ref().map(String::as_str), seq_no));
             }
+            LedgerCommand::ParseGetTxnResponse(response, cb) => {
+                debug!(target: "ledger_command_executor", "ParseGetTxnResponse command received");
+                cb(self.parse_get_txn_response(&response));
+            }
+            LedgerCommand::ExtractTxnInclusionProof(response, cb) => {
+                debug!(target: "ledger_command_executor", "ExtractTxnInclusionProof command received");
+                cb(self.extract_txn_inclusion_proof(&response));
+            }
+            LedgerCommand::VerifyTxnInclusionProof(proof, nodes_bls_keys, cb) => {
+                debug!(target: "ledger_command_executor", "VerifyTxnInclusionProof command received");
+                cb(self.verify_txn_inclusion_proof(&proof, &nodes_bls_keys));
+            }
             LedgerCommand::BuildPoolConfigRequest(submitter_did, writes, force, cb) => {
                 debug!(target: "ledger_command_executor", "BuildPoolConfigRequest command received");
                 cb(self.build_pool_config_request(&submitter_did, writes, force));
@@ -499,6 +617,42 @@ impl LedgerCommandExecutor {
                 debug!(target: "ledger_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(id, pool_response, cb_id);
             }
+            LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, id, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDef command received");
+                self.get_revoc_reg_def(pool_handle, submitter_did.as_ref(), &id, cb);
+            }
+            LedgerCommand::GetRevocRegDefContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDefContinue command received");
+                self._get_revoc_reg_def_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocReg(pool_handle, submitter_did, id, timestamp, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocReg command received");
+                self.get_revoc_reg(pool_handle, submitter_did.as_ref(), &id, timestamp, cb);
+            }
+            LedgerCommand::GetRevocRegContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegContinue command received");
+                self._get_revoc_reg_continue(pool_response, cb_id);
+            }
+            LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, id, from, to, cb) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDelta command received");
+                self.get_revoc_reg_delta(pool_handle, submitter_did.as_ref(), &id, from, to, cb);
+            }
+            LedgerCommand::GetRevocRegDeltaContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetRevocRegDeltaContinue command received");
+                self._get_revoc_reg_delta_continue(pool_response, cb_id);
+            }
+            LedgerCommand::RegistryPoolResolved(deferred_cmd_id, result) => {
+                debug!(target: "ledger_command_executor", "RegistryPoolResolved command received");
+                self._registry_pool_resolved(deferred_cmd_id, result);
+            }
+            LedgerCommand::GetDidDocument(pool_handle, did, cb) => {
+                debug!(target: "ledger_command_executor", "GetDidDocument command received");
+                self.get_did_document(pool_handle, did.clone(), did, cb);
+            }
+            LedgerCommand::GetDidDocumentContinue(pool_response, cb_id) => {
+                debug!(target: "ledger_command_executor", "GetDidDocumentContinue command received");
+                self._get_did_document_continue(pool_response, cb_id);
+            }
             LedgerCommand::BuildTxnAuthorAgreementRequest(submitter_did, text, version, ratification_ts, retirement_ts, cb) => {
                 debug!(target: "ledger_command_executor", "BuildTxnAuthorAgreementRequest command received");
                 cb(self.build_txn_author_agreement_request(&submitter_did, text.as_ref().map(String::as_str), &version, ratification_ts, retirement_ts));
@@ -546,6 +700,126 @@ impl LedgerCommandExecutor {
         };
     }
 
+    // Requests sent to pool registry are deferred until the pool of the network they belong to is resolved
+    fn _defer_for_registry_pool(&self, command: LedgerCommand) -> Option<LedgerCommand> {
+        let (pool_handle, identifier) = match &command {
+            LedgerCommand::SignAndSubmitRequest(pool_handle, _, _, _, _) => (*pool_handle, None),
+            LedgerCommand::SubmitRequest(pool_handle, _, _) => (*pool_handle, None),
+            LedgerCommand::SubmitRequestWithPolicy(pool_handle, _, _, _) => (*pool_handle, None),
+            LedgerCommand::SubmitAction(pool_handle, _, _, _, _) => (*pool_handle, None),
+            LedgerCommand::GetSchema(pool_handle, _, id, _) => (*pool_handle, Some(id.0.clone())),
+            LedgerCommand::GetCredDef(pool_handle, _, id, _) => (*pool_handle, Some(id.0.clone())),
+            LedgerCommand::GetRevocRegDef(pool_handle, _, id, _) => (*pool_handle, Some(id.0.clone())),
+            LedgerCommand::GetRevocReg(pool_handle, _, id, _, _) => (*pool_handle, Some(id.0.clone())),
+            LedgerCommand::GetRevocRegDelta(pool_handle, _, id, _, _, _) => (*pool_handle, Some(id.0.clone())),
+            LedgerCommand::GetDidDocument(pool_handle, did, _) => (*pool_handle, Some(did.0.clone())),
+            _ => return Some(command)
+        };
+
+        if !self.pool_service.is_registry(pool_handle) {
+            return Some(command);
+        }
+
+        let deferred_cmd_id = next_command_handle();
+        self.deferred_commands.borrow_mut().insert(deferred_cmd_id, command);
+
+        CommandExecutor::instance().send(
+            Command::Pool(
+                PoolCommand::ResolveRegistryPool(
+                    pool_handle,
+                    identifier,
+                    Box::new(move |result| {
+                        CommandExecutor::instance().send(
+                            Command::Ledger(
+                                LedgerCommand::RegistryPoolResolved(
+                                    deferred_cmd_id,
+                                    result,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+
+        None
+    }
+
+    fn _registry_pool_resolved(&self, deferred_cmd_id: CommandHandle, result: IndyResult<PoolHandle>) {
+        let command = self.deferred_commands.borrow_mut().remove(&deferred_cmd_id);
+
+        let command = match command {
+            Some(command) => command,
+            None => return error!("No deferred command for id: {:?}", deferred_cmd_id)
+        };
+
+        match (command, result) {
+            (LedgerCommand::SignAndSubmitRequest(_, wallet_handle, submitter_did, request_json, cb), Ok(pool_handle)) =>
+                self.execute(LedgerCommand::SignAndSubmitRequest(pool_handle, wallet_handle, submitter_did, request_json, cb)),
+            (LedgerCommand::SubmitRequest(_, request_json, cb), Ok(pool_handle)) =>
+                self.execute(LedgerCommand::SubmitRequest(pool_handle, request_json, cb)),
+            (LedgerCommand::SubmitRequestWithPolicy(_, request_json, read_policy, cb), Ok(pool_handle)) =>
+                self.execute(LedgerCommand::SubmitRequestWithPolicy(pool_handle, request_json, read_policy, cb)),
+            (LedgerCommand::SubmitAction(_, request_json, nodes, timeout, cb), Ok(pool_handle)) =>
+                self.execute(LedgerCommand::SubmitAction(pool_handle, request_json, nodes, timeout, cb)),
+            (LedgerCommand::GetSchema(_, submitter_did, id, cb), Ok(pool_handle)) =>
+                match qualifier::indy_namespace(&id.0) {
+                    Some(namespace) =>
+                        self.execute(LedgerCommand::GetSchema(pool_handle, submitter_did, SchemaId(qualifier::strip_indy_namespace(&id.0)),
+                                                              Box::new(move |res| cb(res.and_then(|(id, json)| LedgerCommandExecutor::_restore_indy_namespace(&namespace, &id, &json)))))),
+                    None => self.execute(LedgerCommand::GetSchema(pool_handle, submitter_did, id, cb))
+                },
+            (LedgerCommand::GetCredDef(_, submitter_did, id, cb), Ok(pool_handle)) =>
+                match qualifier::indy_namespace(&id.0) {
+                    Some(namespace) =>
+                        self.execute(LedgerCommand::GetCredDef(pool_handle, submitter_did, CredentialDefinitionId(qualifier::strip_indy_namespace(&id.0)),
+                                                               Box::new(move |res| cb(res.and_then(|(id, json)| LedgerCommandExecutor::_restore_indy_namespace(&namespace, &id, &json)))))),
+                    None => self.execute(LedgerCommand::GetCredDef(pool_handle, submitter_did, id, cb))
+                },
+            (LedgerCommand::GetRevocRegDef(_, submitter_did, id, cb), Ok(pool_handle)) =>
+                match qualifier::indy_namespace(&id.0) {
+                    Some(namespace) =>
+                        self.execute(LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, RevocationRegistryId(qualifier::strip_indy_namespace(&id.0)),
+                                                                   Box::new(move |res| cb(res.and_then(|(id, json)| LedgerCommandExecutor::_restore_indy_namespace(&namespace, &id, &json)))))),
+                    None => self.execute(LedgerCommand::GetRevocRegDef(pool_handle, submitter_did, id, cb))
+                },
+            (LedgerCommand::GetRevocReg(_, submitter_did, id, timestamp, cb), Ok(pool_handle)) =>
+                match qualifier::indy_namespace(&id.0) {
+                    Some(namespace) =>
+                        self.execute(LedgerCommand::GetRevocReg(pool_handle, submitter_did, RevocationRegistryId(qualifier::strip_indy_namespace(&id.0)), timestamp,
+                                                                Box::new(move |res| cb(res.and_then(|(id, json, timestamp)|
+                                                                    LedgerCommandExecutor::_restore_indy_namespace(&namespace, &id, &json)
+                                                                        .map(|(id, json)| (id, json, timestamp))))))),
+                    None => self.execute(LedgerCommand::GetRevocReg(pool_handle, submitter_did, id, timestamp, cb))
+                },
+            (LedgerCommand::GetRevocRegDelta(_, submitter_did, id, from, to, cb), Ok(pool_handle)) =>
+                match qualifier::indy_namespace(&id.0) {
+                    Some(namespace) =>
+                        self.execute(LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, RevocationRegistryId(qualifier::strip_indy_namespace(&id.0)), from, to,
+                                                                     Box::new(move |res| cb(res.and_then(|(id, json, timestamp)|
+                                                                         LedgerCommandExecutor::_restore_indy_namespace(&namespace, &id, &json)
+                                                                             .map(|(id, json)| (id, json, timestamp))))))),
+                    None => self.execute(LedgerCommand::GetRevocRegDelta(pool_handle, submitter_did, id, from, to, cb))
+                },
+            (LedgerCommand::GetDidDocument(_, did, cb), Ok(pool_handle)) => {
+                // namespace has served to pick the network, the ledger itself knows the DID without it
+                let ledger_did = DidValue(qualifier::strip_indy_namespace(&did.0));
+                self.get_did_document(pool_handle, did, ledger_did, cb)
+            }
+            (LedgerCommand::SignAndSubmitRequest(_, _, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::SubmitRequest(_, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::SubmitRequestWithPolicy(_, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::SubmitAction(_, _, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetSchema(_, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetCredDef(_, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetRevocRegDef(_, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetRevocReg(_, _, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetRevocRegDelta(_, _, _, _, _, cb), Err(err)) => cb(Err(err)),
+            (LedgerCommand::GetDidDocument(_, _, cb), Err(err)) => cb(Err(err)),
+            (_, _) => error!("Unexpected deferred command for id: {:?}", deferred_cmd_id)
+        }
+    }
+
     fn register_sp_parser(&self, txn_type: &str,
                           parser: CustomTransactionParser, free: CustomFree) -> IndyResult<()> {
         debug!("register_sp_parser >>> txn_type: {:?}, parser: {:?}, free: {:?}",
@@ -635,6 +909,24 @@ impl LedgerCommandExecutor {
         };
     }
 
+    fn submit_request_with_policy(&self,
+                                  handle: PoolHandle,
+                                  request_json: &str,
+                                  read_policy: &ReadPolicy,
+                                  cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_with_policy >>> handle: {:?}, request_json: {:?}, read_policy: {:?}", handle, request_json, read_policy);
+
+        if let Err(err) = serde_json::from_str::<Request<serde_json::Value>>(&request_json) {
+            return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, format!("Request is invalid json: {:?}", err))));
+        }
+
+        let x: IndyResult<CommandHandle> = self.pool_service.send_tx_with_policy(handle, request_json, Some(read_policy));
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
     fn submit_action(&self,
                      handle: PoolHandle,
                      request_json: &str,
@@ -898,6 +1190,20 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_validator_info_response(&self,
+                                         response: &str) -> IndyResult<String> {
+        debug!("parse_get_validator_info_response >>> response: {:?}", response);
+
+        let validator_info = self.ledger_service.parse_get_validator_info_response(response)?;
+
+        let res = serde_json::to_string(&validator_info)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ValidatorInfo")?;
+
+        debug!("parse_get_validator_info_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_get_txn_request(&self,
                              submitter_did: Option<&DidValue>,
                              ledger_type: Option<&str>,
@@ -914,6 +1220,46 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_txn_response(&self,
+                              response: &str) -> IndyResult<String> {
+        debug!("parse_get_txn_response >>> response: {:?}", response);
+
+        let txn = self.ledger_service.parse_get_txn_response(response)?;
+
+        let res = serde_json::to_string(&txn)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerTxn")?;
+
+        debug!("parse_get_txn_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn extract_txn_inclusion_proof(&self,
+                                   response: &str) -> IndyResult<String> {
+        debug!("extract_txn_inclusion_proof >>> response: {:?}", response);
+
+        let proof = extract_txn_inclusion_proof(response)?;
+
+        let res = serde_json::to_string(&proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize TxnInclusionProof")?;
+
+        debug!("extract_txn_inclusion_proof <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn verify_txn_inclusion_proof(&self,
+                                  proof: &TxnInclusionProof,
+                                  nodes_bls_keys: &HashMap<String, String>) -> IndyResult<bool> {
+        debug!("verify_txn_inclusion_proof >>> proof: {:?}, nodes_bls_keys: {:?}", proof, nodes_bls_keys);
+
+        let res = verify_txn_inclusion_proof(proof, nodes_bls_keys)?;
+
+        debug!("verify_txn_inclusion_proof <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_pool_config_request(&self,
                                  submitter_did: &DidValue,
                                  writes: bool,
@@ -1324,6 +1670,199 @@ impl LedgerCommandExecutor {
         cb(self.ledger_service.parse_get_cred_def_response(&pool_response, id.get_method().as_ref().map(String::as_str)))
     }
 
+    fn get_revoc_reg_def(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, cb: BoxedCallbackStringStringSend) {
+        let request_json = try_cb!(self.build_get_revoc_reg_def_request(submitter_did, id), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDefContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_def_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.parse_revoc_reg_def_response(&pool_response))
+    }
+
+    fn get_revoc_reg(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, timestamp: i64,
+                     cb: Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>) {
+        let request_json = try_cb!(self.build_get_revoc_reg_request(submitter_did, id, timestamp), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_revoc_regs.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_revoc_regs.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.parse_revoc_reg_response(&pool_response))
+    }
+
+    fn get_revoc_reg_delta(&self, pool_handle: i32, submitter_did: Option<&DidValue>, id: &RevocationRegistryId, from: Option<i64>, to: i64,
+                           cb: Box<dyn Fn(IndyResult<(String, String, u64)>) + Send>) {
+        let request_json = try_cb!(self.build_get_revoc_reg_delta_request(submitter_did, id, from, to), cb);
+
+        let cb_id = next_command_handle();
+        self.pending_revoc_regs.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRevocRegDeltaContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_revoc_reg_delta_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let cb = self.pending_revoc_regs.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.parse_revoc_reg_delta_response(&pool_response))
+    }
+
+    // Ledger replies don't know about `did:indy` namespaces, so they are added back to the identifiers of returned entity
+    fn _restore_indy_namespace(namespace: &str, id: &str, json: &str) -> IndyResult<(String, String)> {
+        let mut json: Value = serde_json::from_str(json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize ledger entity")?;
+
+        if let Some(object) = json.as_object_mut() {
+            for field in &["id", "schemaId", "credDefId"] {
+                if let Some(Value::String(value)) = object.get_mut(*field) {
+                    *value = qualifier::add_indy_namespace(value, namespace);
+                }
+            }
+        }
+
+        Ok((qualifier::add_indy_namespace(id, namespace), json.to_string()))
+    }
+
+    fn get_did_document(&self, pool_handle: PoolHandle, did: DidValue, ledger_did: DidValue, cb: Box<dyn Fn(IndyResult<String>) + Send>) {
+        debug!("get_did_document >>> pool_handle: {:?}, did: {:?}, ledger_did: {:?}", pool_handle, did, ledger_did);
+
+        try_cb!(self.crypto_service.validate_did(&did), cb);
+
+        let request = DidDocumentRequest {
+            pool_handle,
+            did,
+            ledger_did,
+            step: DidDocumentStep::Nym,
+            metadata: DidDocumentMetadata {
+                nym_seq_no: None,
+                nym_txn_time: None,
+                endpoint_seq_no: None,
+                diddoc_content_seq_no: None,
+                deactivated: None,
+            },
+        };
+
+        let request_json = try_cb!(self.ledger_service.build_get_nym_request(None, &request.ledger_did), cb);
+
+        self._submit_did_document_request(request, &request_json, cb);
+    }
+
+    fn _submit_did_document_request(&self, request: DidDocumentRequest, request_json: &str, cb: Box<dyn Fn(IndyResult<String>)>) {
+        let cb_id = next_command_handle();
+        let pool_handle = request.pool_handle;
+        self.pending_did_documents.borrow_mut().insert(cb_id, (request, cb));
+
+        self.submit_request(pool_handle, request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetDidDocumentContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_did_document_continue(&self, pool_response: IndyResult<String>, cb_id: CommandHandle) {
+        let (mut request, cb) = self.pending_did_documents.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        let metadata = parse_response_metadata(&pool_response).ok();
+
+        // State proofs of all replies are verified by the pool before we get here.
+        match mem::replace(&mut request.step, DidDocumentStep::Nym) {
+            DidDocumentStep::Nym => {
+                let nym_data = try_cb!(self.ledger_service.parse_get_nym_response(&pool_response), cb);
+                let nym_data: NymData = try_cb!(serde_json::from_str(&nym_data)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize NYM data"), cb);
+
+                request.metadata.nym_seq_no = metadata.as_ref().and_then(|metadata| metadata.seq_no);
+                request.metadata.nym_txn_time = metadata.as_ref().and_then(|metadata| metadata.txn_time);
+                request.metadata.deactivated = if nym_data.verkey.is_none() { Some(true) } else { None };
+                request.step = DidDocumentStep::Endpoint(nym_data);
+
+                let request_json = try_cb!(self.ledger_service.build_get_attrib_request(None, &request.ledger_did, Some(ENDPOINT_ATTRIB), None, None), cb);
+                self._submit_did_document_request(request, &request_json, cb)
+            }
+            DidDocumentStep::Endpoint(nym_data) => {
+                let endpoint = try_cb!(self.ledger_service.parse_get_attrib_response(&pool_response), cb);
+
+                request.metadata.endpoint_seq_no = endpoint.as_ref().and(metadata.as_ref()).and_then(|metadata| metadata.seq_no);
+                request.step = DidDocumentStep::DiddocContent(nym_data, endpoint);
+
+                let request_json = try_cb!(self.ledger_service.build_get_attrib_request(None, &request.ledger_did, Some(DIDDOC_CONTENT_ATTRIB), None, None), cb);
+                self._submit_did_document_request(request, &request_json, cb)
+            }
+            DidDocumentStep::DiddocContent(nym_data, endpoint) => {
+                let diddoc_content = try_cb!(self.ledger_service.parse_get_attrib_response(&pool_response), cb);
+                request.metadata.diddoc_content_seq_no = diddoc_content.as_ref().and(metadata.as_ref()).and_then(|metadata| metadata.seq_no);
+
+                cb(self._build_did_resolution_result(request, nym_data, endpoint, diddoc_content))
+            }
+        }
+    }
+
+    fn _build_did_resolution_result(&self, request: DidDocumentRequest, nym_data: NymData,
+                                    endpoint: Option<String>, diddoc_content: Option<String>) -> IndyResult<String> {
+        let did_document = self.ledger_service.build_did_document(&request.did,
+                                                                  &nym_data,
+                                                                  endpoint.as_ref().map(String::as_str),
+                                                                  diddoc_content.as_ref().map(String::as_str))?;
+
+        let retrieved = SystemTime::now().duration_since(UNIX_EPOCH)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot get time")?
+            .as_secs();
+
+        let result = DidResolutionResult {
+            did_document,
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: DID_LD_JSON_CONTENT_TYPE.to_string(),
+                retrieved,
+            },
+            did_document_metadata: request.metadata,
+        };
+
+        serde_json::to_string(&result)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DID resolution result")
+    }
+
     fn build_ledgers_freeze_request(&self, submitter_did: &DidValue, ledgers_ids: Vec<u64>) -> IndyResult<String>{
         debug!("build_ledgers_freeze_request >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
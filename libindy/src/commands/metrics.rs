@@ -14,6 +14,8 @@ const OPENED_WALLETS_COUNT: &str = "opened";
 const OPENED_WALLET_IDS_COUNT: &str = "opened_ids";
 const PENDING_FOR_IMPORT_WALLETS_COUNT: &str = "pending_for_import";
 const PENDING_FOR_OPEN_WALLETS_COUNT: &str = "pending_for_open";
+const NODE_REQUESTS_SUCCESS: &str = "success";
+const NODE_REQUESTS_FAILURE: &str = "failure";
 
 pub enum MetricsCommand {
     CollectMetrics(Box<dyn Fn(IndyResult<String>) + Send>),
@@ -49,6 +51,7 @@ impl MetricsCommandExecutor {
         let mut metrics_map = serde_json::Map::new();
         self.append_threapool_metrics(&mut metrics_map)?;
         self.append_wallet_metrics(&mut metrics_map)?;
+        self.append_pool_metrics(&mut metrics_map)?;
         self.metrics_service
             .append_command_metrics(&mut metrics_map)?;
         let res = serde_json::to_string(&metrics_map)
@@ -133,6 +136,52 @@ impl MetricsCommandExecutor {
         Ok(())
     }
 
+    fn append_pool_metrics(&self, metrics_map: &mut Map<String, Value>) -> IndyResult<()> {
+        let mut requests_count = Vec::new();
+        let mut latency = Vec::new();
+        let mut health_score = Vec::new();
+
+        for (pool_name, nodes_health) in crate::services::pool::get_nodes_health() {
+            for (node_alias, health) in nodes_health {
+                let mut tags = HashMap::<String, String>::new();
+                tags.insert(String::from("pool"), pool_name.clone());
+                tags.insert(String::from("node"), node_alias);
+
+                let mut success_tags = tags.clone();
+                success_tags.insert(String::from("result"), String::from(NODE_REQUESTS_SUCCESS));
+                requests_count.push(MetricsValue::new(health.replies, success_tags));
+
+                let mut failure_tags = tags.clone();
+                failure_tags.insert(String::from("result"), String::from(NODE_REQUESTS_FAILURE));
+                requests_count.push(MetricsValue::new(health.failures, failure_tags));
+
+                if let Some(latency_ms) = health.latency_ms {
+                    latency.push(MetricsValue::new(latency_ms.round() as usize, tags.clone()));
+                }
+
+                health_score.push(MetricsValue::new(health.score(), tags));
+            }
+        }
+
+        metrics_map.insert(
+            String::from("pool_node_requests_count"),
+            serde_json::to_value(requests_count)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("pool_node_latency_ms"),
+            serde_json::to_value(latency)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+        metrics_map.insert(
+            String::from("pool_node_health_score"),
+            serde_json::to_value(health_score)
+                .to_indy(IndyErrorKind::IOError, "Unable to convert json")?,
+        );
+
+        Ok(())
+    }
+
     fn get_metric_json(&self, label: &str, value: usize) -> IndyResult<Value> {
         let mut tag = HashMap::<String, String>::new();
         tag.insert(String::from("label"), String::from(label));
@@ -81,7 +81,7 @@ impl InstrumentedCommand {
 
 
 lazy_static! {
-    static ref THREADPOOL: Mutex<ThreadPool> = Mutex::new(ThreadPool::new(4));
+    pub(crate) static ref THREADPOOL: Mutex<ThreadPool> = Mutex::new(ThreadPool::new(4));
 }
 
 pub fn indy_set_runtime_config(config: IndyConfig) {
@@ -1,9 +1,10 @@
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use crate::commands::{Command, CommandExecutor};
 use crate::domain::ledger::request::ProtocolVersion;
-use crate::domain::pool::{PoolConfig, PoolOpenConfig};
+use crate::domain::pool::{PoolConfig, PoolOpenConfig, PoolRegistryConfig};
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::PoolService;
 use indy_api_types::{PoolHandle, CommandHandle};
@@ -25,6 +26,9 @@ pub enum PoolCommand {
         PoolHandle, // pool handle
         IndyResult<()>),
     List(Box<dyn Fn(IndyResult<String>) + Send>),
+    GetStatus(
+        PoolHandle, // pool handle
+        Box<dyn Fn(IndyResult<String>) + Send>),
     Close(
         PoolHandle, // pool handle
         Box<dyn Fn(IndyResult<()>) + Send>),
@@ -38,6 +42,17 @@ pub enum PoolCommand {
     SetProtocolVersion(
         usize, // protocol version
         Box<dyn Fn(IndyResult<()>) + Send>),
+    OpenRegistry(
+        PoolRegistryConfig, // config
+        Box<dyn Fn(IndyResult<PoolHandle>) + Send>),
+    ResolveRegistryPool(
+        PoolHandle, // registry handle
+        Option<String>, // identifier
+        Box<dyn Fn(IndyResult<PoolHandle>) + Send>),
+    RegistryPoolOpened(
+        PoolHandle, // registry handle
+        String, // DID method namespace
+        IndyResult<PoolHandle>),
 }
 
 pub struct PoolCommandExecutor {
@@ -45,6 +60,7 @@ pub struct PoolCommandExecutor {
     close_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     refresh_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<()>)>>>,
     open_callbacks: RefCell<HashMap<CommandHandle, Box<dyn Fn(IndyResult<PoolHandle>)>>>,
+    registry_callbacks: RefCell<HashMap<(PoolHandle, String), Vec<Box<dyn Fn(IndyResult<PoolHandle>)>>>>,
 }
 
 impl PoolCommandExecutor {
@@ -54,6 +70,7 @@ impl PoolCommandExecutor {
             close_callbacks: RefCell::new(HashMap::new()),
             refresh_callbacks: RefCell::new(HashMap::new()),
             open_callbacks: RefCell::new(HashMap::new()),
+            registry_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -91,6 +108,10 @@ impl PoolCommandExecutor {
                 debug!(target: "pool_command_executor", "List command received");
                 cb(self.list());
             }
+            PoolCommand::GetStatus(handle, cb) => {
+                debug!(target: "pool_command_executor", "GetStatus command received");
+                cb(self.get_status(handle));
+            }
             PoolCommand::Close(handle, cb) => {
                 debug!(target: "pool_command_executor", "Close command received");
                 self.close(handle, cb);
@@ -132,6 +153,18 @@ impl PoolCommandExecutor {
                 debug!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
             }
+            PoolCommand::OpenRegistry(config, cb) => {
+                debug!(target: "pool_command_executor", "OpenRegistry command received");
+                cb(self.open_registry(config));
+            }
+            PoolCommand::ResolveRegistryPool(handle, identifier, cb) => {
+                debug!(target: "pool_command_executor", "ResolveRegistryPool command received");
+                self.resolve_registry_pool(handle, identifier.as_ref().map(String::as_str), cb);
+            }
+            PoolCommand::RegistryPoolOpened(handle, namespace, result) => {
+                debug!(target: "pool_command_executor", "RegistryPoolOpened command received");
+                self._registry_pool_opened(handle, &namespace, result);
+            }
         };
     }
 
@@ -185,9 +218,25 @@ impl PoolCommandExecutor {
         Ok(res)
     }
 
+    fn get_status(&self, pool_handle: PoolHandle) -> IndyResult<String> {
+        debug!("get_status >>> pool_handle: {:?}", pool_handle);
+
+        let status = self.pool_service.get_status(pool_handle)?;
+
+        let res = ::serde_json::to_string(&status)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool status")?;
+
+        debug!("get_status << res: {:?}", res);
+        Ok(res)
+    }
+
     fn close(&self, pool_handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         debug!("close >>> handle: {:?}", pool_handle);
 
+        if self.pool_service.is_registry(pool_handle) {
+            return self._close_registry(pool_handle, cb);
+        }
+
         let result = self.pool_service.close(pool_handle)
             .and_then(|cmd_id| {
                 match self.close_callbacks.try_borrow_mut() {
@@ -203,6 +252,46 @@ impl PoolCommandExecutor {
         debug!("close <<<");
     }
 
+    fn _close_registry(&self, registry_handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
+        debug!("_close_registry >>> registry_handle: {:?}", registry_handle);
+
+        let pool_handles = try_cb!(self.pool_service.remove_registry(registry_handle), cb);
+
+        let mut cmd_ids = Vec::new();
+        for pool_handle in pool_handles {
+            match self.pool_service.close(pool_handle) {
+                Ok(cmd_id) => cmd_ids.push(cmd_id),
+                Err(err) => warn!("Can't close pool {:?} of registry {:?}: {:?}", pool_handle, registry_handle, err)
+            }
+        }
+
+        if cmd_ids.is_empty() {
+            return cb(Ok(()));
+        }
+
+        // registry is closed when all its pools are closed
+        let pending = Rc::new(Cell::new(cmd_ids.len()));
+        let cb: Box<dyn Fn(IndyResult<()>)> = cb;
+        let cb: Rc<dyn Fn(IndyResult<()>)> = Rc::from(cb);
+
+        let mut close_callbacks = try_cb!(self.close_callbacks.try_borrow_mut().map_err(IndyError::from), cb);
+        for cmd_id in cmd_ids {
+            let pending = pending.clone();
+            let cb = cb.clone();
+            close_callbacks.insert(cmd_id, Box::new(move |result: IndyResult<()>| {
+                if let Err(err) = result {
+                    warn!("Can't close pool of registry: {:?}", err);
+                }
+                pending.set(pending.get() - 1);
+                if pending.get() == 0 {
+                    cb(Ok(()));
+                }
+            }));
+        }
+
+        debug!("_close_registry <<<");
+    }
+
     fn refresh(&self, handle: PoolHandle, cb: Box<dyn Fn(IndyResult<()>) + Send>) {
         debug!("refresh >>> handle: {:?}", handle);
 
@@ -221,6 +310,77 @@ impl PoolCommandExecutor {
         debug!("refresh <<<");
     }
 
+    fn open_registry(&self, config: PoolRegistryConfig) -> IndyResult<PoolHandle> {
+        debug!("open_registry >>> config: {:?}", config);
+
+        let res = self.pool_service.open_registry(config)?;
+
+        debug!("open_registry << res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn resolve_registry_pool(&self, registry_handle: PoolHandle, identifier: Option<&str>, cb: Box<dyn Fn(IndyResult<PoolHandle>) + Send>) {
+        debug!("resolve_registry_pool >>> registry_handle: {:?}, identifier: {:?}", registry_handle, identifier);
+
+        let (namespace, network, pool_handle) =
+            try_cb!(self.pool_service.resolve_registry_network(registry_handle, identifier), cb);
+
+        if let Some(pool_handle) = pool_handle {
+            return cb(Ok(pool_handle));
+        }
+
+        // pools are opened lazily on the first request to the network
+        let key = (registry_handle, namespace.clone());
+        {
+            let mut registry_callbacks = try_cb!(self.registry_callbacks.try_borrow_mut().map_err(IndyError::from), cb);
+
+            if let Some(callbacks) = registry_callbacks.get_mut(&key) {
+                callbacks.push(cb);
+                return;
+            }
+
+            registry_callbacks.insert(key, vec![cb]);
+        }
+
+        self.open(&network.pool_name, network.config, Box::new(move |result| {
+            CommandExecutor::instance().send(
+                Command::Pool(
+                    PoolCommand::RegistryPoolOpened(registry_handle, namespace.clone(), result)
+                )
+            ).unwrap();
+        }));
+
+        debug!("resolve_registry_pool <<<");
+    }
+
+    fn _registry_pool_opened(&self, registry_handle: PoolHandle, namespace: &str, result: IndyResult<PoolHandle>) {
+        debug!("_registry_pool_opened >>> registry_handle: {:?}, namespace: {:?}, result: {:?}", registry_handle, namespace, result);
+
+        let result = result.and_then(|pool_handle| {
+            self.pool_service.add_registry_pool(registry_handle, namespace, pool_handle)
+                .map(|_| pool_handle)
+                .map_err(|err| {
+                    // registry has been closed while pool was opening
+                    let _ = self.pool_service.close(pool_handle);
+                    err
+                })
+        });
+
+        let callbacks = self.registry_callbacks.borrow_mut()
+            .remove(&(registry_handle, namespace.to_string()))
+            .unwrap_or_default();
+
+        for cb in callbacks {
+            match result {
+                Ok(pool_handle) => cb(Ok(pool_handle)),
+                Err(ref err) => cb(Err(err_msg(err.kind(), err.to_string())))
+            }
+        }
+
+        debug!("_registry_pool_opened <<<");
+    }
+
     fn set_protocol_version(&self, version: usize) -> IndyResult<()> {
         debug!("set_protocol_version >>> version: {:?}", version);
 
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use indy_api_types::validation::Validatable;
+
+/// Encoding rule of credential attribute value.
+///
+/// Predicates can be proved only for attributes which encoded value fits into 32-bit signed integer.
+#[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum AttributeEncoding {
+    /// 32-bit signed integer is encoded as itself
+    Integer,
+    /// 32-bit signed integer string is encoded as itself, any other string as SHA-256 hash interpreted as big-endian number
+    String,
+    /// `true` is encoded as 1, `false` as 0
+    Boolean,
+    /// `YYYY-MM-DD` date is encoded as number of days since 1970-01-01
+    Date,
+    /// number of seconds since 1970-01-01T00:00:00Z is encoded as itself
+    Timestamp,
+    /// decimal number is encoded as integer multiplied by 10^scale
+    Decimal { scale: u32 },
+}
+
+impl Default for AttributeEncoding {
+    fn default() -> Self {
+        AttributeEncoding::String
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TypedAttributeValue {
+    Typed {
+        value: serde_json::Value,
+        #[serde(default)]
+        encoding: AttributeEncoding,
+    },
+    Raw(String),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct TypedCredentialValues(pub HashMap<String, TypedAttributeValue>);
+
+impl Validatable for TypedCredentialValues {
+    fn validate(&self) -> Result<(), String> {
+        if self.0.is_empty() {
+            return Err(String::from("TypedCredentialValues validation failed: empty list has been passed"));
+        }
+
+        for (name, value) in self.0.iter() {
+            if let TypedAttributeValue::Typed { encoding, .. } = value {
+                encoding.validate()
+                    .map_err(|err| format!("{} for attribute \"{}\"", err, name))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Validatable for AttributeEncoding {
+    fn validate(&self) -> Result<(), String> {
+        match self {
+            AttributeEncoding::Decimal { scale } if *scale > 9 =>
+                Err(format!("AttributeEncoding validation failed: decimal scale {} is too big", scale)),
+            _ => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_credential_values_deserialize_works() {
+        let values: TypedCredentialValues = serde_json::from_str(r#"{
+            "name": "Alex",
+            "age": {"value": 28, "encoding": {"type": "integer"}},
+            "price": {"value": "12.5", "encoding": {"type": "decimal", "scale": 2}},
+            "nickname": {"value": "Al"}
+        }"#).unwrap();
+
+        match &values.0["name"] {
+            TypedAttributeValue::Raw(value) => assert_eq!("Alex", value),
+            _ => panic!("Raw value expected")
+        }
+
+        match &values.0["price"] {
+            TypedAttributeValue::Typed { value, encoding } => {
+                assert_eq!(json!("12.5"), *value);
+                assert_eq!(AttributeEncoding::Decimal { scale: 2 }, *encoding);
+            }
+            _ => panic!("Typed value expected")
+        }
+
+        match &values.0["nickname"] {
+            TypedAttributeValue::Typed { encoding, .. } => assert_eq!(AttributeEncoding::String, *encoding),
+            _ => panic!("Typed value expected")
+        }
+    }
+
+    #[test]
+    fn attribute_encoding_validate_works_for_big_scale() {
+        assert!(AttributeEncoding::Decimal { scale: 2 }.validate().is_ok());
+        assert!(AttributeEncoding::Decimal { scale: 10 }.validate().is_err());
+    }
+
+    #[test]
+    fn typed_credential_values_validate_works_for_big_scale() {
+        let values: TypedCredentialValues = serde_json::from_str(r#"{
+            "name": "Alex",
+            "price": {"value": "12.5", "encoding": {"type": "decimal", "scale": 2}}
+        }"#).unwrap();
+        assert!(values.validate().is_ok());
+
+        let values: TypedCredentialValues = serde_json::from_str(r#"{
+            "price": {"value": "12.5", "encoding": {"type": "decimal", "scale": 4000000000}}
+        }"#).unwrap();
+        assert!(values.validate().is_err());
+    }
+}
@@ -151,6 +151,15 @@ impl CredentialDefinitionId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, String, SchemaId, String)> {
+        // namespace of `did:indy` DIDs adds delimiters, so it's parsed without one and kept on the DIDs
+        if let Some(namespace) = qualifier::indy_namespace(&self.0) {
+            return CredentialDefinitionId(qualifier::strip_indy_namespace(&self.0)).parts()
+                .map(|(did, signature_type, schema_id, tag)| (DidValue(qualifier::add_indy_namespace(&did.0, &namespace)),
+                                                              signature_type,
+                                                              SchemaId(qualifier::add_indy_namespace(&schema_id.0, &namespace)),
+                                                              tag));
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 4 {
@@ -215,6 +224,10 @@ impl CredentialDefinitionId {
     }
 
     pub fn qualify(&self, method: &str) -> CredentialDefinitionId {
+        if self.is_fully_qualified() {
+            return self.clone();
+        }
+
         match self.parts() {
             Some((did, signature_type, schema_id, tag)) => {
                 CredentialDefinitionId::new(&did.qualify(method), &schema_id.qualify(method), &signature_type, &tag)
@@ -235,7 +248,8 @@ impl CredentialDefinitionId {
 
 impl Validatable for CredentialDefinitionId {
     fn validate(&self) -> Result<(), String> {
-        self.parts().ok_or(format!("Credential Definition Id validation failed: {:?}, doesn't match pattern", self.0))?;
+        self.parts()
+            .ok_or(format!("Credential Definition Id validation failed: {:?}, doesn't match pattern", self.0))?;
         Ok(())
     }
 }
@@ -294,6 +308,10 @@ mod tests {
         CredentialDefinitionId("creddef:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string())
     }
 
+    fn _cred_def_id_with_indy_namespace() -> CredentialDefinitionId {
+        CredentialDefinitionId("creddef:indy:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:3:CL:schema:indy:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string())
+    }
+
     mod to_unqualified {
         use super::*;
 
@@ -326,6 +344,25 @@ mod tests {
         fn test_cred_def_id_parts_for_id_as_qualified_with_schema_as_seq_no() {
             assert_eq!(_cred_def_id_unqualified_with_schema_as_seq_no(), _cred_def_id_qualified_with_schema_as_seq_no().to_unqualified());
         }
+
+        #[test]
+        fn test_cred_def_id_unqualify_for_id_with_indy_namespace() {
+            assert_eq!(_cred_def_id_unqualified(), _cred_def_id_with_indy_namespace().to_unqualified());
+        }
+    }
+
+    mod qualify {
+        use super::*;
+
+        #[test]
+        fn test_cred_def_id_qualify_for_id_as_unqualified() {
+            assert_eq!(_cred_def_id_qualified(), _cred_def_id_unqualified().qualify("sov"));
+        }
+
+        #[test]
+        fn test_cred_def_id_qualify_for_id_with_indy_namespace() {
+            assert_eq!(_cred_def_id_with_indy_namespace(), _cred_def_id_with_indy_namespace().qualify("sov"));
+        }
     }
 
     mod parts {
@@ -384,6 +421,15 @@ mod tests {
             assert_eq!(_schema_id_seq_no(), schema_id);
             assert_eq!(_tag(), tag);
         }
+
+        #[test]
+        fn test_cred_def_id_parts_for_id_with_indy_namespace() {
+            let (did, signature_type, schema_id, tag) = _cred_def_id_with_indy_namespace().parts().unwrap();
+            assert_eq!(DidValue("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e".to_string()), did);
+            assert_eq!(_signature_type(), signature_type);
+            assert_eq!(SchemaId("schema:indy:did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string()), schema_id);
+            assert_eq!(_tag(), tag);
+        }
     }
 
     mod validate {
@@ -418,5 +464,10 @@ mod tests {
         fn test_validate_cred_def_id_as_fully_qualified_with_schema_as_seq_no() {
             _cred_def_id_qualified_with_schema_as_seq_no().validate().unwrap();
         }
+
+        #[test]
+        fn test_validate_cred_def_id_with_indy_namespace() {
+            _cred_def_id_with_indy_namespace().validate().unwrap();
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use super::revocation_registry_definition::RevocationRegistryId;
+
+/// Name of credential tag keeping the time credential was stored in the wallet.
+pub const CREDENTIAL_CREATED_AT_TAG: &str = "created_at";
+
+/// Policy used to choose a credential for a referent when several credentials satisfy it.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum CredentialSelectionPolicy {
+    Newest,
+    Oldest,
+    FewestCredentials,
+    PreferNonRevocable,
+}
+
+impl Default for CredentialSelectionPolicy {
+    fn default() -> Self {
+        CredentialSelectionPolicy::Newest
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Default)]
+pub struct CreateProofAutoOptions {
+    #[serde(default)]
+    pub policy: CredentialSelectionPolicy,
+    #[serde(default)]
+    pub self_attested_attributes: HashMap<String, String>,
+}
+
+/// Credential satisfying a referent of proof request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CredentialCandidate {
+    pub cred_id: String,
+    pub rev_reg_id: Option<RevocationRegistryId>,
+    pub created_at: u64,
+    pub timestamp: Option<u64>,
+}
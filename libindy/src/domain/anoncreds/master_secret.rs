@@ -2,9 +2,46 @@ use ursa::cl::MasterSecret as CryptoMasterSecret;
 
 use indy_api_types::validation::Validatable;
 
+/// Name of master secret tag keeping the time master secret was retired.
+pub const MASTER_SECRET_RETIRED_AT_TAG: &str = "retired_at";
+
+/// Name of credential tag keeping id of master secret the credential is bound to.
+pub const CREDENTIAL_MASTER_SECRET_TAG: &str = "master_secret_id";
+
 #[derive(Debug, Deserialize, Serialize)]
 pub struct MasterSecret {
     pub value: CryptoMasterSecret,
 }
 
 impl Validatable for MasterSecret {}
+
+/// Master secret with its id as it is encrypted for transfer between wallets.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ExportedMasterSecret {
+    pub id: String,
+    pub value: CryptoMasterSecret,
+}
+
+/// Master secret encrypted for the recipient key by anonymous encryption.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EncryptedMasterSecret {
+    /// base64 encoded anonymously encrypted `ExportedMasterSecret` json
+    pub ciphertext: String,
+}
+
+impl Validatable for EncryptedMasterSecret {
+    fn validate(&self) -> Result<(), String> {
+        if self.ciphertext.is_empty() {
+            return Err(String::from("EncryptedMasterSecret validation failed: empty ciphertext"));
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct MasterSecretInfo {
+    pub id: String,
+    pub retired_at: Option<u64>,
+    pub cred_ids: Vec<String>,
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use super::super::cache::GetCacheOptions;
+use super::super::crypto::did::DidValue;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyProofWithLedgerOptions {
+    pub submitter_did: Option<DidValue>,
+    pub cache: Option<GetCacheOptions>,
+}
+
+/// Verification step which rejected a part of the proof.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationCheck {
+    Referents,
+    AttributeValue,
+    Restriction,
+    NonRevokedInterval,
+    Identifiers,
+    Crypto,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct VerificationFailure {
+    pub check: VerificationCheck,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub referent: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sub_proof_index: Option<u32>,
+    pub message: String,
+}
+
+impl VerificationFailure {
+    pub fn new(check: VerificationCheck, message: String) -> VerificationFailure {
+        VerificationFailure { check, referent: None, sub_proof_index: None, message }
+    }
+
+    pub fn for_referent(check: VerificationCheck, referent: &str, sub_proof_index: Option<u32>, message: String) -> VerificationFailure {
+        VerificationFailure { check, referent: Some(referent.to_string()), sub_proof_index, message }
+    }
+
+    pub fn for_sub_proof(check: VerificationCheck, sub_proof_index: u32, message: String) -> VerificationFailure {
+        VerificationFailure { check, referent: None, sub_proof_index: Some(sub_proof_index), message }
+    }
+}
+
+/// Result of proof verification listing every failed check instead of the first one only.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ProofVerificationResult {
+    pub valid: bool,
+    pub failures: Vec<VerificationFailure>,
+}
+
+impl ProofVerificationResult {
+    pub fn new(failures: Vec<VerificationFailure>) -> ProofVerificationResult {
+        ProofVerificationResult { valid: failures.is_empty(), failures }
+    }
+}
+
+/// Reason why a part of the proof was rejected by verification report.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationReasonCode {
+    MissingInProof,
+    NotRequested,
+    IdentifierNotFound,
+    EncodingMismatch,
+    RestrictionNotSatisfied,
+    TimestampMissing,
+    TimestampOutOfInterval,
+    SchemaNotFound,
+    CredentialDefinitionNotFound,
+    RevocationRegistryIdMissing,
+    RevocationRegistryDefinitionNotFound,
+    RevocationRegistryNotFound,
+    CryptoProofInvalid,
+    NotEvaluated,
+}
+
+impl VerificationReasonCode {
+    /// Verification step the reason is reported by in `ProofVerificationResult`.
+    pub fn check(&self) -> VerificationCheck {
+        match self {
+            VerificationReasonCode::MissingInProof | VerificationReasonCode::NotRequested => VerificationCheck::Referents,
+            VerificationReasonCode::EncodingMismatch => VerificationCheck::AttributeValue,
+            VerificationReasonCode::RestrictionNotSatisfied => VerificationCheck::Restriction,
+            VerificationReasonCode::TimestampMissing | VerificationReasonCode::TimestampOutOfInterval => VerificationCheck::NonRevokedInterval,
+            VerificationReasonCode::IdentifierNotFound
+            | VerificationReasonCode::SchemaNotFound
+            | VerificationReasonCode::CredentialDefinitionNotFound
+            | VerificationReasonCode::RevocationRegistryIdMissing
+            | VerificationReasonCode::RevocationRegistryDefinitionNotFound
+            | VerificationReasonCode::RevocationRegistryNotFound => VerificationCheck::Identifiers,
+            VerificationReasonCode::CryptoProofInvalid | VerificationReasonCode::NotEvaluated => VerificationCheck::Crypto,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct VerificationReason {
+    pub code: VerificationReasonCode,
+    pub message: String,
+}
+
+impl VerificationReason {
+    pub fn new(code: VerificationReasonCode, message: String) -> VerificationReason {
+        VerificationReason { code, message }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ReferentReport {
+    pub valid: bool,
+    pub sub_proof_index: Option<u32>,
+    pub reasons: Vec<VerificationReason>,
+}
+
+impl ReferentReport {
+    pub fn new(sub_proof_index: Option<u32>, reasons: Vec<VerificationReason>) -> ReferentReport {
+        ReferentReport { valid: reasons.is_empty(), sub_proof_index, reasons }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct SubProofReport {
+    pub valid: bool,
+    pub sub_proof_index: u32,
+    pub reasons: Vec<VerificationReason>,
+}
+
+impl SubProofReport {
+    pub fn new(sub_proof_index: u32, reasons: Vec<VerificationReason>) -> SubProofReport {
+        SubProofReport { valid: reasons.is_empty(), sub_proof_index, reasons }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CheckReport {
+    pub valid: bool,
+    pub reasons: Vec<VerificationReason>,
+}
+
+impl CheckReport {
+    pub fn new(reasons: Vec<VerificationReason>) -> CheckReport {
+        CheckReport { valid: reasons.is_empty(), reasons }
+    }
+}
+
+/// Per-referent report of proof verification.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ProofVerificationReport {
+    pub valid: bool,
+    pub attributes: HashMap<String, ReferentReport>,
+    pub predicates: HashMap<String, ReferentReport>,
+    pub identifiers: Vec<SubProofReport>,
+    pub revocation: Vec<SubProofReport>,
+    pub crypto: CheckReport,
+}
+
+impl ProofVerificationReport {
+    /// Lists every rejected part of the report as a failure of `ProofVerificationResult`.
+    pub fn failures(&self) -> Vec<VerificationFailure> {
+        let mut failures = Vec::new();
+
+        for (referent, report) in self.attributes.iter().chain(self.predicates.iter()) {
+            failures.extend(report.reasons.iter().map(|reason|
+                VerificationFailure::for_referent(reason.code.check(), referent, report.sub_proof_index, reason.message.clone())));
+        }
+
+        for report in self.identifiers.iter().chain(self.revocation.iter()) {
+            failures.extend(report.reasons.iter().map(|reason|
+                VerificationFailure::for_sub_proof(reason.code.check(), report.sub_proof_index, reason.message.clone())));
+        }
+
+        failures.extend(self.crypto.reasons.iter().map(|reason|
+            VerificationFailure::new(reason.code.check(), reason.message.clone())));
+
+        failures
+    }
+}
@@ -1,5 +1,9 @@
 use ursa::cl::RevocationRegistryDelta as RegistryDelta;
 
+use std::collections::HashMap;
+
+use super::revocation_registry_definition::RevocationRegistryId;
+
 use indy_api_types::validation::Validatable;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,4 +27,19 @@ impl From<RevocationRegistryDelta> for RevocationRegistryDeltaV1 {
     }
 }
 
-impl Validatable for RevocationRegistryDelta {}
\ No newline at end of file
+impl Validatable for RevocationRegistryDelta {}
+
+pub type RevocationRegistryDeltas = HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryDelta>>;
+
+pub fn rev_reg_deltas_map_to_rev_reg_deltas_v1_map(rev_reg_deltas: RevocationRegistryDeltas) -> HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryDeltaV1>> {
+    rev_reg_deltas
+        .into_iter()
+        .map(|(rev_reg_id, rev_reg_delta_to_timestamps)| {
+            let val = rev_reg_delta_to_timestamps
+                .into_iter()
+                .map(|(timestamp, rev_reg_delta)| (timestamp, RevocationRegistryDeltaV1::from(rev_reg_delta)))
+                .collect();
+            (rev_reg_id, val)
+        })
+        .collect()
+}
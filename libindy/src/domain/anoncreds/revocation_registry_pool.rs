@@ -0,0 +1,150 @@
+use indy_api_types::validation::Validatable;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::revocation_registry_definition::{IssuanceType, RevocationRegistryId};
+use super::super::crypto::did::DidValue;
+
+pub const DEFAULT_TAG_PREFIX: &str = "pool_";
+pub const DEFAULT_ROLLOVER_THRESHOLD: u32 = 90;
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct RevocationRegistryPoolConfig {
+    pub issuance_type: Option<IssuanceType>,
+    pub max_cred_num: Option<u32>,
+    pub tag_prefix: Option<String>,
+    /// Percentage of used registry capacity that triggers generation of the next registry
+    pub rollover_threshold: Option<u32>,
+}
+
+impl RevocationRegistryPoolConfig {
+    pub fn tag_prefix(&self) -> &str {
+        self.tag_prefix.as_ref().map(String::as_str).unwrap_or(DEFAULT_TAG_PREFIX)
+    }
+
+    pub fn rollover_threshold(&self) -> u32 {
+        self.rollover_threshold.unwrap_or(DEFAULT_ROLLOVER_THRESHOLD)
+    }
+}
+
+impl Validatable for RevocationRegistryPoolConfig {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(0) = self.max_cred_num {
+            return Err(String::from("RevocationRegistryPoolConfig validation failed: `max_cred_num` must be greater than 0"));
+        }
+
+        if let Some(threshold) = self.rollover_threshold {
+            if threshold == 0 || threshold > 100 {
+                return Err(String::from("RevocationRegistryPoolConfig validation failed: `rollover_threshold` must be in range 1..100"));
+            }
+        }
+
+        if self.tag_prefix().contains(':') {
+            return Err(String::from("RevocationRegistryPoolConfig validation failed: `tag_prefix` must not contain `:`"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Revocation registry which definition and initial entry still have to be published on the ledger.
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct UnpublishedRevocationRegistry {
+    pub rev_reg_id: RevocationRegistryId,
+    pub rev_reg_def: serde_json::Value,
+    pub rev_reg_entry: serde_json::Value,
+}
+
+/// Set of revocation registries managed by issuer for one credential definition.
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct RevocationRegistryPool {
+    pub cred_def_id: CredentialDefinitionId,
+    pub issuer_did: DidValue,
+    pub config: RevocationRegistryPoolConfig,
+    pub active: RevocationRegistryId,
+    pub next: Option<RevocationRegistryId>,
+    pub registries: Vec<RevocationRegistryId>,
+    pub unpublished: Vec<UnpublishedRevocationRegistry>,
+    /// Usage of the active registry reached the threshold and there is no next registry yet
+    #[serde(default)]
+    pub rollover_needed: bool,
+}
+
+impl RevocationRegistryPool {
+    pub fn next_tag(&self) -> String {
+        format!("{}{}", self.config.tag_prefix(), self.registries.len() + 1)
+    }
+
+    pub fn is_published(&self, rev_reg_id: &RevocationRegistryId) -> bool {
+        !self.unpublished.iter().any(|unpublished| unpublished.rev_reg_id == *rev_reg_id)
+    }
+
+    pub fn needs_rollover(&self, curr_id: u32, max_cred_num: u32) -> bool {
+        self.next.is_none() && curr_id as u64 * 100 >= self.config.rollover_threshold() as u64 * max_cred_num as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _pool(threshold: Option<u32>) -> RevocationRegistryPool {
+        RevocationRegistryPool {
+            cred_def_id: CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string()),
+            issuer_did: DidValue("NcYxiDXkpYi6ov5FcYDi1e".to_string()),
+            config: RevocationRegistryPoolConfig { issuance_type: None, max_cred_num: Some(10), tag_prefix: None, rollover_threshold: threshold },
+            active: RevocationRegistryId("NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:pool_1".to_string()),
+            next: None,
+            registries: vec![RevocationRegistryId("NcYxiDXkpYi6ov5FcYDi1e:4:NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag:CL_ACCUM:pool_1".to_string())],
+            unpublished: Vec::new(),
+            rollover_needed: false,
+        }
+    }
+
+    #[test]
+    fn next_tag_works() {
+        assert_eq!("pool_2", _pool(None).next_tag());
+    }
+
+    #[test]
+    fn needs_rollover_works() {
+        let pool = _pool(Some(50));
+        assert!(!pool.needs_rollover(4, 10));
+        assert!(pool.needs_rollover(5, 10));
+
+        let pool = _pool(None);
+        assert!(!pool.needs_rollover(8, 10));
+        assert!(pool.needs_rollover(9, 10));
+    }
+
+    #[test]
+    fn needs_rollover_works_for_existing_next() {
+        let mut pool = _pool(Some(50));
+        pool.next = Some(pool.active.clone());
+        assert!(!pool.needs_rollover(10, 10));
+    }
+
+    #[test]
+    fn is_published_works() {
+        let mut pool = _pool(None);
+        assert!(pool.is_published(&pool.active));
+
+        pool.unpublished.push(UnpublishedRevocationRegistry {
+            rev_reg_id: pool.active.clone(),
+            rev_reg_def: serde_json::Value::Null,
+            rev_reg_entry: serde_json::Value::Null,
+        });
+        assert!(!pool.is_published(&pool.active));
+    }
+
+    #[test]
+    fn config_validate_works() {
+        let config = RevocationRegistryPoolConfig { issuance_type: None, max_cred_num: None, tag_prefix: None, rollover_threshold: Some(101) };
+        assert!(config.validate().is_err());
+
+        let config = RevocationRegistryPoolConfig { issuance_type: None, max_cred_num: None, tag_prefix: Some("a:b".to_string()), rollover_threshold: None };
+        assert!(config.validate().is_err());
+
+        let config = RevocationRegistryPoolConfig { issuance_type: None, max_cred_num: Some(5), tag_prefix: None, rollover_threshold: Some(80) };
+        assert!(config.validate().is_ok());
+    }
+}
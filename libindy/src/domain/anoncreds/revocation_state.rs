@@ -1,5 +1,7 @@
 use ursa::cl::{Witness, RevocationRegistry};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use super::revocation_registry_definition::RevocationRegistryId;
 
 use indy_api_types::validation::Validatable;
 
@@ -20,3 +22,50 @@ impl Validatable for RevocationState {
 }
 
 pub type RevocationStates = HashMap<String, HashMap<u64, RevocationState>>;
+
+/// Revocation state of a credential cached in the prover wallet.
+/// Indices of issued and revoked credentials of the registry state are kept
+/// to update the witness by the difference with a newer registry state.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StoredRevocationState {
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub rev_state: RevocationState,
+    #[serde(default)]
+    pub issued: HashSet<u32>,
+    #[serde(default)]
+    pub revoked: HashSet<u32>,
+}
+
+impl StoredRevocationState {
+    pub fn record_id(rev_reg_id: &RevocationRegistryId, cred_rev_id: &str, timestamp: u64) -> String {
+        format!("{}:{}:{}", rev_reg_id.0, cred_rev_id, timestamp)
+    }
+
+    pub fn id(&self) -> String {
+        StoredRevocationState::record_id(&self.rev_reg_id, &self.cred_rev_id, self.rev_state.timestamp)
+    }
+
+    pub fn tags(&self) -> HashMap<String, String> {
+        let mut tags = HashMap::new();
+        tags.insert("rev_reg_id".to_string(), self.rev_reg_id.0.clone());
+        tags.insert("cred_rev_id".to_string(), self.cred_rev_id.clone());
+        tags.insert("timestamp".to_string(), self.rev_state.timestamp.to_string());
+        tags
+    }
+
+    pub fn info(&self) -> StoredRevocationStateInfo {
+        StoredRevocationStateInfo {
+            rev_reg_id: self.rev_reg_id.clone(),
+            cred_rev_id: self.cred_rev_id.clone(),
+            timestamp: self.rev_state.timestamp,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredRevocationStateInfo {
+    pub rev_reg_id: RevocationRegistryId,
+    pub cred_rev_id: String,
+    pub timestamp: u64,
+}
@@ -127,6 +127,12 @@ impl SchemaId {
     }
 
     pub fn parts(&self) -> Option<(DidValue, String, String)> {
+        // namespace of `did:indy` DID adds delimiters, so it's parsed without one and kept on the DID
+        if let Some(namespace) = qualifier::indy_namespace(&self.0) {
+            return SchemaId(qualifier::strip_indy_namespace(&self.0)).parts()
+                .map(|(did, name, version)| (DidValue(qualifier::add_indy_namespace(&did.0, &namespace)), name, version));
+        }
+
         let parts = self.0.split_terminator(DELIMITER).collect::<Vec<&str>>();
 
         if parts.len() == 1 {
@@ -154,6 +160,10 @@ impl SchemaId {
     }
 
     pub fn qualify(&self, method: &str) -> SchemaId {
+        if self.is_fully_qualified() {
+            return self.clone();
+        }
+
         match self.parts() {
             Some((did, name, version)) => {
                 SchemaId::new(&did.qualify(method), &name, &version)
@@ -178,7 +188,8 @@ impl Validatable for SchemaId {
             return Ok(());
         }
 
-        self.parts().ok_or(format!("SchemaId validation failed: {:?}, doesn't match pattern", self.0))?;
+        self.parts()
+            .ok_or(format!("SchemaId validation failed: {:?}, doesn't match pattern", self.0))?;
 
         Ok(())
     }
@@ -208,6 +219,10 @@ mod tests {
         SchemaId("schema:sov:did:sov:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string())
     }
 
+    fn _schema_id_with_indy_namespace() -> SchemaId {
+        SchemaId("schema:indy:did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string())
+    }
+
     fn _schema_id_invalid() -> SchemaId {
         SchemaId("NcYxiDXkpYi6ov5FcYDi1e:2".to_string())
     }
@@ -234,6 +249,30 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert_eq!(_schema_id_invalid(), _schema_id_invalid().to_unqualified());
         }
+
+        #[test]
+        fn test_schema_id_unqualify_for_id_with_indy_namespace() {
+            assert_eq!(_schema_id_unqualified(), _schema_id_with_indy_namespace().to_unqualified());
+        }
+    }
+
+    mod qualify {
+        use super::*;
+
+        #[test]
+        fn test_schema_id_qualify_for_id_as_unqualified() {
+            assert_eq!(_schema_id_qualified(), _schema_id_unqualified().qualify("sov"));
+        }
+
+        #[test]
+        fn test_schema_id_qualify_for_id_as_qualified() {
+            assert_eq!(_schema_id_qualified(), _schema_id_qualified().qualify("indy"));
+        }
+
+        #[test]
+        fn test_schema_id_qualify_for_id_with_indy_namespace() {
+            assert_eq!(_schema_id_with_indy_namespace(), _schema_id_with_indy_namespace().qualify("sov"));
+        }
     }
 
     mod parts {
@@ -260,6 +299,14 @@ mod tests {
         fn test_schema_id_parts_for_invalid_unqualified() {
             assert!(_schema_id_invalid().parts().is_none());
         }
+
+        #[test]
+        fn test_schema_id_parts_for_id_with_indy_namespace() {
+            let (did, name, version) = _schema_id_with_indy_namespace().parts().unwrap();
+            assert_eq!(DidValue("did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e".to_string()), did);
+            assert_eq!("gvt", name);
+            assert_eq!("1.0", version);
+        }
     }
 
     mod validate {
@@ -280,6 +327,11 @@ mod tests {
             _schema_id_qualified().validate().unwrap();
         }
 
+        #[test]
+        fn test_validate_schema_id_as_fully_qualified_with_indy_namespace() {
+            _schema_id_with_indy_namespace().validate().unwrap();
+        }
+
         #[test]
         fn test_validate_schema_id_for_invalid_unqualified() {
             _schema_id_invalid().validate().unwrap_err();
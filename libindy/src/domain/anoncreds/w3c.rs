@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use ursa::cl::{
+    CredentialSignature,
+    RevocationRegistry,
+    SignatureCorrectnessProof,
+    Witness
+};
+
+use indy_api_types::validation::Validatable;
+
+use super::credential_definition::CredentialDefinitionId;
+use super::proof::RequestedProof;
+use super::proof_request::PredicateTypes;
+use super::revocation_registry_definition::RevocationRegistryId;
+use super::schema::SchemaId;
+
+pub const W3C_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+pub const ANONCREDS_CONTEXT: &str = "https://raw.githubusercontent.com/hyperledger/anoncreds-spec/main/data/anoncreds-w3c-context.json";
+
+pub const W3C_CREDENTIAL_TYPE: &str = "VerifiableCredential";
+pub const ANONCREDS_CREDENTIAL_TYPE: &str = "AnonCredsCredential";
+pub const W3C_PRESENTATION_TYPE: &str = "VerifiablePresentation";
+pub const ANONCREDS_PRESENTATION_TYPE: &str = "AnonCredsPresentation";
+
+pub const ANONCREDS_CREDENTIAL_SCHEMA_TYPE: &str = "AnonCredsDefinition";
+pub const ANONCREDS_CREDENTIAL_PROOF_TYPE: &str = "AnonCredsProof2023";
+pub const ANONCREDS_PRESENTATION_PROOF_TYPE: &str = "AnonCredsPresentationProof2023";
+pub const ANONCREDS_PREDICATE_TYPE: &str = "AnonCredsPredicate";
+
+/// Anoncreds credential represented in W3C Verifiable Credential data model.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CCredential {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub issuer: String,
+    pub credential_schema: W3CCredentialSchema,
+    pub credential_subject: HashMap<String, W3CAttributeValue>,
+    pub proof: W3CProof,
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct W3CCredentialSchema {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub definition: CredentialDefinitionId,
+    pub schema: SchemaId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revocation: Option<RevocationRegistryId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<u64>,
+}
+
+/// Value of credential subject attribute: raw value or predicates proved for it.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum W3CAttributeValue {
+    Value(String),
+    Predicates(Vec<W3CPredicate>),
+}
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct W3CPredicate {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub predicate: PredicateTypes,
+    pub value: i32,
+}
+
+/// `proofValue` is base64 encoded json of anoncreds data which has no W3C representation.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CProof {
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    pub proof_value: String,
+}
+
+/// Anoncreds proof represented in W3C Verifiable Presentation data model.
+/// Every sub proof is represented as a verifiable credential.
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct W3CPresentation {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    #[serde(rename = "type")]
+    pub type_: Vec<String>,
+    pub verifiable_credential: Vec<W3CCredential>,
+    pub proof: W3CProof,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CredentialProofValue {
+    pub signature: CredentialSignature,
+    pub signature_correctness_proof: SignatureCorrectnessProof,
+    pub rev_reg: Option<RevocationRegistry>,
+    pub witness: Option<Witness>,
+    pub encoded: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PresentationProofValue {
+    pub aggregated_proof: serde_json::Value,
+    pub requested_proof: RequestedProof,
+}
+
+impl Validatable for W3CCredential {
+    fn validate(&self) -> Result<(), String> {
+        if !self.type_.iter().any(|type_| type_ == W3C_CREDENTIAL_TYPE) {
+            return Err(format!("W3C Credential validation failed: `type` must contain {}", W3C_CREDENTIAL_TYPE));
+        }
+        if self.proof.type_ != ANONCREDS_CREDENTIAL_PROOF_TYPE {
+            return Err(format!("W3C Credential validation failed: unsupported proof type {}", self.proof.type_));
+        }
+        Ok(())
+    }
+}
+
+impl Validatable for W3CPresentation {
+    fn validate(&self) -> Result<(), String> {
+        if !self.type_.iter().any(|type_| type_ == W3C_PRESENTATION_TYPE) {
+            return Err(format!("W3C Presentation validation failed: `type` must contain {}", W3C_PRESENTATION_TYPE));
+        }
+        if self.proof.type_ != ANONCREDS_PRESENTATION_PROOF_TYPE {
+            return Err(format!("W3C Presentation validation failed: unsupported proof type {}", self.proof.type_));
+        }
+        Ok(())
+    }
+}
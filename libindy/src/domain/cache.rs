@@ -4,7 +4,7 @@ pub struct PurgeOptions {
     pub max_age: Option<i32>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct GetCacheOptions {
     pub no_cache: Option<bool>,     // Skip usage of cache,
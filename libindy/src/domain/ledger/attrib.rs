@@ -1,5 +1,5 @@
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+use super::response::{GetReplyResultV1, ReplyType};
 use super::super::crypto::did::ShortDidValue;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -60,6 +60,12 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+impl ReplyType for GetAttrReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
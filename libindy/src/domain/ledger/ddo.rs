@@ -15,4 +15,114 @@ impl GetDdoOperation {
             dest
         }
     }
-}
\ No newline at end of file
+}
+
+pub const DEFAULT_DID_METHOD: &str = "sov";
+pub const ENDPOINT_ATTRIB: &str = "endpoint";
+pub const DIDDOC_CONTENT_ATTRIB: &str = "diddocContent";
+
+pub const DID_CORE_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+pub const ED25519_2018_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+pub const X25519_2019_CONTEXT: &str = "https://w3id.org/security/suites/x25519-2019/v1";
+pub const DID_LD_JSON_CONTENT_TYPE: &str = "application/did+ld+json";
+
+pub const ENDPOINT_SERVICE_TYPE: &str = "endpoint";
+pub const DID_COMMUNICATION_SERVICE_TYPE: &str = "did-communication";
+pub const DID_COMMUNICATION_ACCEPT: &str = "didcomm/aip2;env=rfc19";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub key_agreement: Vec<VerificationMethod>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub service: Vec<DidDocumentService>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentService {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipient_keys: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub routing_keys: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub accept: Vec<String>,
+}
+
+/// Value of `endpoint` ATTRIB.
+/// Both the Sovrin DID method format (`endpoint`, `routingKeys`, `types`)
+/// and the legacy libindy format (`ha`, `verkey`) are accepted.
+#[derive(Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidEndpoint {
+    pub endpoint: Option<String>,
+    pub ha: Option<String>,
+    #[serde(default)]
+    pub routing_keys: Vec<String>,
+    pub types: Option<Vec<String>>,
+}
+
+impl DidEndpoint {
+    pub fn service_endpoint(&self) -> Option<String> {
+        self.endpoint.clone()
+            .or_else(|| self.ha.clone())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct DidEndpointAttrib {
+    pub endpoint: DidEndpoint
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+    pub retrieved: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nym_seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nym_txn_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoint_seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diddoc_content_seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_document: serde_json::Value,
+    pub did_resolution_metadata: DidResolutionMetadata,
+    pub did_document_metadata: DidDocumentMetadata,
+}
@@ -1,4 +1,15 @@
+use std::collections::HashSet;
+
+use serde_json::Value;
+
+use indy_api_types::validation::Validatable;
+
+use super::auth_rule::Constraint;
+use super::author_agreement::AcceptanceMechanisms;
 use super::constants::GET_TXN;
+use super::node::NodeOperationData;
+use super::request::TxnAuthrAgrmtAcceptanceData;
+use super::response::ReplyType;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetTxnOperation {
@@ -19,6 +30,170 @@ impl GetTxnOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetTxnReplyResult {
+    pub data: Option<Value>,
+}
+
+impl ReplyType for GetTxnReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN
+    }
+}
+
+/// Ledger transaction normalized from GET_TXN response.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct LedgerTxn {
+    /// version of transaction format stored in the ledger
+    pub ver: String,
+    #[serde(rename = "type")]
+    pub txn_type: String,
+    #[serde(flatten)]
+    pub data: LedgerTxnData,
+    pub metadata: LedgerTxnMetadata,
+}
+
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(tag = "typeName", content = "data")]
+pub enum LedgerTxnData {
+    #[serde(rename = "NODE")]
+    Node(NodeTxnData),
+    #[serde(rename = "NYM")]
+    Nym(NymTxnData),
+    #[serde(rename = "ATTRIB")]
+    Attrib(AttribTxnData),
+    #[serde(rename = "SCHEMA")]
+    Schema(SchemaTxnData),
+    #[serde(rename = "CRED_DEF")]
+    CredDef(CredDefTxnData),
+    #[serde(rename = "REVOC_REG_DEF")]
+    RevocRegDef(RevocRegDefTxnData),
+    #[serde(rename = "REVOC_REG_ENTRY")]
+    RevocRegEntry(RevocRegEntryTxnData),
+    #[serde(rename = "AUTH_RULE")]
+    AuthRule(AuthRuleTxnData),
+    #[serde(rename = "AUTH_RULES")]
+    AuthRules(AuthRulesTxnData),
+    #[serde(rename = "TXN_AUTHR_AGRMT")]
+    TxnAuthrAgrmt(TxnAuthrAgrmtTxnData),
+    #[serde(rename = "TXN_AUTHR_AGRMT_AML")]
+    TxnAuthrAgrmtAml(TxnAuthrAgrmtAmlTxnData),
+    /// Transaction of type unknown to libindy (for example, payment plugin transaction).
+    /// Data is returned as it is stored in the ledger.
+    #[serde(rename = "CUSTOM")]
+    Custom(Value),
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeTxnData {
+    pub dest: String,
+    pub data: NodeOperationData,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct NymTxnData {
+    pub dest: String,
+    pub verkey: Option<String>,
+    pub role: Option<String>,
+    pub alias: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AttribTxnData {
+    pub dest: String,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SchemaTxnData {
+    pub name: String,
+    pub version: String,
+    pub attr_names: HashSet<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct CredDefTxnData {
+    #[serde(rename = "ref")]
+    pub schema_ref: u64,
+    pub signature_type: String,
+    pub tag: Option<String>,
+    pub data: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegDefTxnData {
+    pub id: String,
+    pub revoc_def_type: String,
+    pub tag: String,
+    pub cred_def_id: String,
+    pub value: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocRegEntryTxnData {
+    pub revoc_def_type: String,
+    pub revoc_reg_def_id: String,
+    pub value: Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AuthRuleTxnData {
+    pub auth_type: String,
+    pub auth_action: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub constraint: Constraint,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct AuthRulesTxnData {
+    pub rules: Vec<AuthRuleTxnData>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct TxnAuthrAgrmtTxnData {
+    pub version: String,
+    pub text: Option<String>,
+    pub ratification_ts: Option<u64>,
+    pub retirement_ts: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct TxnAuthrAgrmtAmlTxnData {
+    pub version: String,
+    pub aml: AcceptanceMechanisms,
+    #[serde(rename = "amlContext")]
+    pub aml_context: Option<String>,
+}
+
+#[derive(Serialize, Debug, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerTxnMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seq_no: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub req_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload_digest: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endorser: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub taa_acceptance: Option<TxnAuthrAgrmtAcceptanceData>,
+}
+
 #[derive(Deserialize, Debug)]
 pub enum LedgerType {
     POOL = 0,
@@ -35,3 +210,41 @@ impl LedgerType {
         }
     }
 }
+
+/// Self-contained proof that a transaction is included into the ledger.
+///
+/// Contains the transaction as it is stored in the ledger, the audit path from
+/// the transaction leaf to the merkle tree root and BLS multi-signature of pool nodes
+/// over this root, so it can be verified later without connection to the pool.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnInclusionProof {
+    pub seq_no: u64,
+    pub ledger_size: u64,
+    pub root_hash: String,
+    pub audit_path: Vec<String>,
+    pub txn: Value,
+    pub multi_signature: Value,
+}
+
+impl Validatable for TxnInclusionProof {
+    fn validate(&self) -> Result<(), String> {
+        if self.seq_no == 0 {
+            return Err(String::from("Transaction seqNo must be greater than 0"));
+        }
+
+        if self.seq_no > self.ledger_size {
+            return Err(String::from("Transaction seqNo must not be greater than ledgerSize"));
+        }
+
+        if self.root_hash.is_empty() {
+            return Err(String::from("Empty rootHash"));
+        }
+
+        if !self.txn.is_object() {
+            return Err(String::from("Transaction must be an object"));
+        }
+
+        Ok(())
+    }
+}
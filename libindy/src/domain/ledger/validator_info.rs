@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use super::constants::GET_VALIDATOR_INFO;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -14,3 +16,107 @@ impl GetValidatorInfoOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+pub struct GetValidatorInfoResult {
+    pub data: ValidatorInfo,
+}
+
+/// Reply of a single node to GET_VALIDATOR_INFO request.
+/// Names of node fields are normalized to snake case.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ValidatorInfo {
+    pub alias: String,
+    pub did: Option<String>,
+    pub verkey: Option<String>,
+    pub timestamp: Option<u64>,
+    #[serde(rename(deserialize = "Node_info"))]
+    pub node_info: Option<NodeInfo>,
+    #[serde(rename(deserialize = "Pool_info"))]
+    pub pool_info: Option<PoolInfo>,
+    #[serde(rename(deserialize = "Software"))]
+    pub software: Option<SoftwareInfo>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeInfo {
+    #[serde(rename(deserialize = "Name"))]
+    pub name: Option<String>,
+    #[serde(rename(deserialize = "Mode"))]
+    pub mode: Option<String>,
+    #[serde(rename(deserialize = "Client_port"))]
+    pub client_port: Option<u64>,
+    #[serde(rename(deserialize = "Node_port"))]
+    pub node_port: Option<u64>,
+    #[serde(rename(deserialize = "BLS_key"))]
+    pub bls_key: Option<String>,
+    #[serde(rename(deserialize = "Metrics"))]
+    pub metrics: Option<NodeMetrics>,
+    #[serde(rename(deserialize = "Committed_ledger_root_hashes"), default)]
+    pub committed_ledger_root_hashes: HashMap<String, String>,
+    #[serde(rename(deserialize = "View_change_status"))]
+    pub view_change_status: Option<ViewChangeStatus>,
+    #[serde(rename(deserialize = "Catchup_status"))]
+    pub catchup_status: Option<CatchupStatus>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct NodeMetrics {
+    pub uptime: Option<u64>,
+    #[serde(rename(deserialize = "transaction-count"), default)]
+    pub transaction_count: HashMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct ViewChangeStatus {
+    #[serde(rename(deserialize = "View_No"))]
+    pub view_no: Option<u64>,
+    #[serde(rename(deserialize = "VC_in_progress"), default)]
+    pub vc_in_progress: bool,
+    #[serde(rename(deserialize = "Last_complete_view_no"))]
+    pub last_complete_view_no: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct CatchupStatus {
+    #[serde(rename(deserialize = "Ledger_statuses"), default)]
+    pub ledger_statuses: HashMap<String, String>,
+    #[serde(rename(deserialize = "Number_txns_in_catchup"), default)]
+    pub number_txns_in_catchup: HashMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct PoolInfo {
+    #[serde(rename(deserialize = "Read_only"), default)]
+    pub read_only: bool,
+    #[serde(rename(deserialize = "Total_nodes_count"))]
+    pub total_nodes_count: Option<u64>,
+    pub f_value: Option<u64>,
+    #[serde(rename(deserialize = "Reachable_nodes_count"))]
+    pub reachable_nodes_count: Option<u64>,
+    #[serde(rename(deserialize = "Unreachable_nodes_count"))]
+    pub unreachable_nodes_count: Option<u64>,
+    #[serde(rename(deserialize = "Reachable_nodes"), default)]
+    pub reachable_nodes: Vec<(String, Option<u64>)>,
+    #[serde(rename(deserialize = "Unreachable_nodes"), default)]
+    pub unreachable_nodes: Vec<(String, Option<u64>)>,
+    #[serde(rename(deserialize = "Blacklisted_nodes"), default)]
+    pub blacklisted_nodes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SoftwareInfo {
+    #[serde(rename(deserialize = "indy-node"))]
+    pub indy_node: Option<String>,
+    pub sovrin: Option<String>,
+    #[serde(rename(deserialize = "OS_version"))]
+    pub os_version: Option<String>,
+}
+
+/// Result of GET_VALIDATOR_INFO request for a single node:
+/// parsed info or reason why the node didn't provide it.
+#[derive(Serialize, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum NodeValidatorInfo {
+    ValidatorInfo(ValidatorInfo),
+    Error(String),
+}
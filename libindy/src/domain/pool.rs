@@ -1,3 +1,7 @@
+use std::collections::HashMap;
+
+use regex::Regex;
+
 use indy_api_types::validation::Validatable;
 
 pub const POOL_CON_ACTIVE_TO: i64 = 5;
@@ -5,6 +9,7 @@ pub const POOL_ACK_TIMEOUT: i64 = 20;
 pub const POOL_REPLY_TIMEOUT: i64 = 60;
 pub const MAX_REQ_PER_POOL_CON: usize = 5;
 pub const NUMBER_READ_NODES: u8 = 2;
+pub const READ_RETRY_BACKOFF: u64 = 500;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PoolConfig {
@@ -35,6 +40,8 @@ pub struct PoolOpenConfig {
     pub number_read_nodes: u8,
     #[serde(default = "PoolOpenConfig::default_socks_proxy")]
     pub socks_proxy: String,
+    #[serde(default = "PoolOpenConfig::default_read_policy")]
+    pub read_policy: ReadPolicy,
 }
 
 impl Validatable for PoolOpenConfig {
@@ -54,6 +61,7 @@ impl Validatable for PoolOpenConfig {
         if self.number_read_nodes == 0 {
             return Err(String::from("`number_read_nodes` must be greater than 0"));
         }
+        self.read_policy.validate()?;
         Ok(())
     }
 }
@@ -68,6 +76,7 @@ impl Default for PoolOpenConfig {
             preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
             number_read_nodes: PoolOpenConfig::default_number_read_nodes(),
             socks_proxy: PoolOpenConfig::default_socks_proxy(),
+            read_policy: PoolOpenConfig::default_read_policy(),
         }
     }
 }
@@ -96,4 +105,135 @@ impl PoolOpenConfig {
     fn default_number_read_nodes() -> u8 { NUMBER_READ_NODES }
 
     fn default_socks_proxy() -> String { String::new() }
+
+    fn default_read_policy() -> ReadPolicy { ReadPolicy::default() }
+}
+
+/// Strategy of sending read requests (requests with state proof) to the pool.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReadPolicy {
+    /// Number of additional rounds for a read which can't be completed by queried nodes
+    #[serde(default)]
+    pub retries: u8,
+    /// Delay before the first retry (in ms). Doubled for each next retry
+    #[serde(default = "ReadPolicy::default_retry_backoff")]
+    pub retry_backoff: u64,
+    /// Query one more node if there is no acceptable reply after this delay (in ms)
+    #[serde(default)]
+    pub hedge_after: Option<u64>,
+    /// Require consensus of pool nodes even if a reply contains valid state proof
+    #[serde(default)]
+    pub full_consensus: bool,
+}
+
+impl Validatable for ReadPolicy {
+    fn validate(&self) -> Result<(), String> {
+        if self.retry_backoff == 0 {
+            return Err(String::from("`retry_backoff` must be greater than 0"));
+        }
+        if self.hedge_after == Some(0) {
+            return Err(String::from("`hedge_after` must be greater than 0"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ReadPolicy {
+    fn default() -> Self {
+        ReadPolicy {
+            retries: 0,
+            retry_backoff: ReadPolicy::default_retry_backoff(),
+            hedge_after: None,
+            full_consensus: false,
+        }
+    }
+}
+
+impl ReadPolicy {
+    fn default_retry_backoff() -> u64 { READ_RETRY_BACKOFF }
+
+    /// Delay before `attempt` retry (starting from 1)
+    pub fn retry_delay(&self, attempt: u8) -> u64 {
+        self.retry_backoff.saturating_mul(1u64 << u64::from(attempt.saturating_sub(1)).min(16))
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PoolRegistryConfig {
+    pub networks: HashMap<String, PoolRegistryNetwork>,
+    pub default: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PoolRegistryNetwork {
+    pub pool_name: String,
+    pub config: Option<PoolOpenConfig>,
+}
+
+impl Validatable for PoolRegistryConfig {
+    fn validate(&self) -> Result<(), String> {
+        lazy_static! {
+            static ref REGEX_NAMESPACE: Regex = Regex::new("^did:[a-z0-9]+(:[a-z][a-z0-9]*)*$").unwrap();
+        }
+
+        if self.networks.is_empty() {
+            return Err(String::from("`networks` must not be empty"));
+        }
+
+        for (namespace, network) in self.networks.iter() {
+            if !REGEX_NAMESPACE.is_match(namespace) {
+                return Err(format!("Invalid DID method namespace: {}. Expected format `did:<method>[:<namespace>]`", namespace));
+            }
+            if network.pool_name.is_empty() {
+                return Err(format!("`pool_name` of {} network must not be empty", namespace));
+            }
+            if let Some(ref config) = network.config {
+                config.validate()?;
+            }
+        }
+
+        if let Some(ref default) = self.default {
+            if !self.networks.contains_key(default) {
+                return Err(format!("Default network {} is not configured", default));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Snapshot of an opened pool: known nodes, their connection state and pool ledger.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolStatus {
+    pub name: String,
+    pub ledger: PoolLedgerInfo,
+    /// Time of the last completed catch-up (unix timestamp)
+    pub last_catchup: Option<i64>,
+    pub nodes: Vec<PoolNodeStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolLedgerInfo {
+    pub size: usize,
+    pub root: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolNodeStatus {
+    pub alias: String,
+    pub dest: String,
+    pub client_address: Option<String>,
+    pub node_address: Option<String>,
+    pub services: Vec<String>,
+    /// Whether the pool holds open connection to the node
+    pub connected: bool,
+    pub health: PoolNodeHealth,
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PoolNodeHealth {
+    pub replies: usize,
+    pub failures: usize,
+    pub latency_ms: Option<u64>,
+    pub score: usize,
 }
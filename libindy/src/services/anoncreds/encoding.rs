@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use indy_api_types::errors::prelude::*;
+use indy_api_types::validation::Validatable;
+use indy_utils::crypto::hash::hash;
+use ursa::bn::BigNumber;
+
+use crate::domain::anoncreds::credential::{AttributeValues, CredentialValues};
+use crate::domain::anoncreds::credential_attr_encoding::{AttributeEncoding, TypedAttributeValue, TypedCredentialValues};
+use crate::domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes};
+
+pub fn encode_credential_values(values: TypedCredentialValues) -> IndyResult<CredentialValues> {
+    trace!("encode_credential_values >>> values: {:?}", secret!(&values));
+
+    let mut res: HashMap<String, AttributeValues> = HashMap::new();
+
+    for (name, value) in values.0 {
+        let (raw, encoding) = match value {
+            TypedAttributeValue::Raw(raw) => (raw, AttributeEncoding::String),
+            TypedAttributeValue::Typed { value, encoding } => (_value_to_raw(&name, value)?, encoding),
+        };
+
+        let encoded = encode_value(&raw, &encoding)
+            .map_err(|err| err.extend(format!("Invalid value of attribute \"{}\"", name)))?;
+
+        res.insert(name, AttributeValues { raw, encoded });
+    }
+
+    let res = CredentialValues(res);
+
+    trace!("encode_credential_values <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn encode_value(raw: &str, encoding: &AttributeEncoding) -> IndyResult<String> {
+    _validate_encoding(encoding)?;
+
+    match encoding {
+        AttributeEncoding::Integer =>
+            Ok(_parse_i32(raw)?.to_string()),
+        AttributeEncoding::String =>
+            match raw.parse::<i32>() {
+                Ok(value) => Ok(value.to_string()),
+                Err(_) => Ok(BigNumber::from_bytes(&hash(raw.as_bytes())?)?.to_dec()?)
+            }
+        AttributeEncoding::Boolean =>
+            match raw {
+                "true" => Ok("1".to_string()),
+                "false" => Ok("0".to_string()),
+                _ => Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid boolean value: {}", raw)))
+            }
+        AttributeEncoding::Date =>
+            Ok(_parse_date(raw)?.to_string()),
+        AttributeEncoding::Timestamp =>
+            Ok(_parse_i32(raw)?.to_string()),
+        AttributeEncoding::Decimal { scale } =>
+            Ok(_parse_decimal(raw, *scale)?.to_string()),
+    }
+}
+
+pub fn decode_value(encoded: &str, encoding: &AttributeEncoding) -> IndyResult<String> {
+    trace!("decode_value >>> encoded: {:?}, encoding: {:?}", secret!(encoded), encoding);
+
+    _validate_encoding(encoding)?;
+
+    let res = match encoding {
+        AttributeEncoding::Integer | AttributeEncoding::Timestamp =>
+            _parse_i32(encoded)?.to_string(),
+        AttributeEncoding::String =>
+            encoded.parse::<i32>()
+                .map(|value| value.to_string())
+                .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, "String value encoded as hash can't be decoded"))?,
+        AttributeEncoding::Boolean =>
+            match encoded {
+                "1" => "true".to_string(),
+                "0" => "false".to_string(),
+                _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid encoded boolean value: {}", encoded)))
+            }
+        AttributeEncoding::Date =>
+            _format_date(_parse_i32(encoded)?),
+        AttributeEncoding::Decimal { scale } =>
+            _format_decimal(_parse_i32(encoded)?, *scale),
+    };
+
+    trace!("decode_value <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn build_predicate_info(name: &str, p_type: PredicateTypes, value: &str, encoding: &AttributeEncoding) -> IndyResult<PredicateInfo> {
+    trace!("build_predicate_info >>> name: {:?}, p_type: {:?}, value: {:?}, encoding: {:?}", name, p_type, value, encoding);
+
+    if *encoding == AttributeEncoding::Boolean {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Predicates aren't supported for boolean values"));
+    }
+
+    let p_value = encode_value(value, encoding)?
+        .parse::<i32>()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidStructure,
+                             format!("Predicate value \"{}\" isn't encoded as 32-bit integer", value)))?;
+
+    let res = PredicateInfo {
+        name: name.to_string(),
+        p_type,
+        p_value,
+        restrictions: None,
+        non_revoked: None,
+    };
+
+    trace!("build_predicate_info <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+fn _validate_encoding(encoding: &AttributeEncoding) -> IndyResult<()> {
+    encoding.validate()
+        .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, err))
+}
+
+fn _value_to_raw(name: &str, value: serde_json::Value) -> IndyResult<String> {
+    match value {
+        serde_json::Value::String(value) => Ok(value),
+        serde_json::Value::Number(value) => Ok(value.to_string()),
+        serde_json::Value::Bool(value) => Ok(value.to_string()),
+        _ => Err(err_msg(IndyErrorKind::InvalidStructure,
+                         format!("Value of attribute \"{}\" must be string, number or boolean", name)))
+    }
+}
+
+fn _parse_i32(value: &str) -> IndyResult<i32> {
+    value.parse::<i32>()
+        .to_indy(IndyErrorKind::InvalidStructure, format!("Value \"{}\" isn't 32-bit integer", value))
+}
+
+fn _parse_date(value: &str) -> IndyResult<i32> {
+    let invalid = || err_msg(IndyErrorKind::InvalidStructure, format!("Invalid date \"{}\": YYYY-MM-DD expected", value));
+
+    let parts: Vec<&str> = value.split('-').collect();
+    if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+        return Err(invalid());
+    }
+
+    let year = parts[0].parse::<i64>().map_err(|_| invalid())?;
+    let month = parts[1].parse::<u32>().map_err(|_| invalid())?;
+    let day = parts[2].parse::<u32>().map_err(|_| invalid())?;
+
+    if month < 1 || month > 12 || day < 1 || day > _days_in_month(year, month) {
+        return Err(invalid());
+    }
+
+    Ok(_days_from_civil(year, month, day) as i32)
+}
+
+fn _format_date(days: i32) -> String {
+    let (year, month, day) = _civil_from_days(days as i64);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+fn _parse_decimal(value: &str, scale: u32) -> IndyResult<i32> {
+    let invalid = || err_msg(IndyErrorKind::InvalidStructure,
+                             format!("Invalid decimal \"{}\" for scale {}", value, scale));
+
+    let (negative, unsigned) = if value.starts_with('-') { (true, &value[1..]) } else { (false, value) };
+
+    let (int_part, frac_part) = match unsigned.find('.') {
+        Some(pos) => (&unsigned[..pos], &unsigned[pos + 1..]),
+        None => (unsigned, "")
+    };
+
+    if int_part.is_empty() || frac_part.len() > scale as usize
+        || !int_part.chars().chain(frac_part.chars()).all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+
+    let digits = format!("{}{:0<width$}", int_part, frac_part, width = scale as usize);
+
+    let res = digits.parse::<i64>().map_err(|_| invalid())?;
+    let res = if negative { -res } else { res };
+
+    if res < i32::min_value() as i64 || res > i32::max_value() as i64 {
+        return Err(invalid());
+    }
+
+    Ok(res as i32)
+}
+
+fn _format_decimal(value: i32, scale: u32) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+
+    let divisor = 10i64.pow(scale);
+    let abs = (value as i64).abs();
+    let sign = if value < 0 { "-" } else { "" };
+
+    format!("{}{}.{:0width$}", sign, abs / divisor, abs % divisor, width = scale as usize)
+}
+
+fn _is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn _days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if _is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31
+    }
+}
+
+// Proleptic Gregorian calendar conversions (http://howardhinnant.github.io/date_algorithms.html)
+fn _days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = (if year >= 0 { year } else { year - 399 }) / 400;
+    let yoe = year - era * 400;
+    let month = month as i64;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+fn _civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = (if days >= 0 { days } else { days - 146096 }) / 146097;
+    let doe = days - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_value_works_for_integer() {
+        assert_eq!("28", encode_value("28", &AttributeEncoding::Integer).unwrap());
+        assert_eq!("-5", encode_value("-5", &AttributeEncoding::Integer).unwrap());
+        assert!(encode_value("2147483648", &AttributeEncoding::Integer).is_err());
+        assert!(encode_value("abc", &AttributeEncoding::Integer).is_err());
+    }
+
+    #[test]
+    fn encode_value_works_for_string() {
+        assert_eq!("28", encode_value("28", &AttributeEncoding::String).unwrap());
+        assert_eq!("99262857098057710338306967609588410025648622308394250666849665532448612202874",
+                   encode_value("Alex", &AttributeEncoding::String).unwrap());
+    }
+
+    #[test]
+    fn encode_value_works_for_boolean() {
+        assert_eq!("1", encode_value("true", &AttributeEncoding::Boolean).unwrap());
+        assert_eq!("0", encode_value("false", &AttributeEncoding::Boolean).unwrap());
+        assert!(encode_value("yes", &AttributeEncoding::Boolean).is_err());
+    }
+
+    #[test]
+    fn encode_value_works_for_date() {
+        assert_eq!("0", encode_value("1970-01-01", &AttributeEncoding::Date).unwrap());
+        assert_eq!("10957", encode_value("2000-01-01", &AttributeEncoding::Date).unwrap());
+        assert_eq!("11016", encode_value("2000-02-29", &AttributeEncoding::Date).unwrap());
+        assert_eq!("-1", encode_value("1969-12-31", &AttributeEncoding::Date).unwrap());
+        assert!(encode_value("1999-02-29", &AttributeEncoding::Date).is_err());
+        assert!(encode_value("2000-1-1", &AttributeEncoding::Date).is_err());
+    }
+
+    #[test]
+    fn encode_value_works_for_decimal() {
+        assert_eq!("1250", encode_value("12.5", &AttributeEncoding::Decimal { scale: 2 }).unwrap());
+        assert_eq!("-1205", encode_value("-12.05", &AttributeEncoding::Decimal { scale: 2 }).unwrap());
+        assert_eq!("1200", encode_value("12", &AttributeEncoding::Decimal { scale: 2 }).unwrap());
+        assert!(encode_value("12.345", &AttributeEncoding::Decimal { scale: 2 }).is_err());
+        assert!(encode_value("1.2.3", &AttributeEncoding::Decimal { scale: 2 }).is_err());
+        assert!(encode_value("99999999.99", &AttributeEncoding::Decimal { scale: 2 }).is_err());
+    }
+
+    #[test]
+    fn encode_value_works_for_decimal_with_big_scale() {
+        let res = encode_value("12.5", &AttributeEncoding::Decimal { scale: 10 });
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+        let res = encode_value("12.5", &AttributeEncoding::Decimal { scale: u32::max_value() });
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+        let res = decode_value("1250", &AttributeEncoding::Decimal { scale: 20 });
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn decode_value_works() {
+        assert_eq!("2000-01-01", decode_value("10957", &AttributeEncoding::Date).unwrap());
+        assert_eq!("1969-12-31", decode_value("-1", &AttributeEncoding::Date).unwrap());
+        assert_eq!("-12.05", decode_value("-1205", &AttributeEncoding::Decimal { scale: 2 }).unwrap());
+        assert_eq!("0.05", decode_value("5", &AttributeEncoding::Decimal { scale: 2 }).unwrap());
+        assert_eq!("true", decode_value("1", &AttributeEncoding::Boolean).unwrap());
+        assert_eq!("1589558400", decode_value("1589558400", &AttributeEncoding::Timestamp).unwrap());
+        assert!(decode_value("1139481716457488690172217916278103335", &AttributeEncoding::String).is_err());
+    }
+
+    #[test]
+    fn date_round_trip_works() {
+        for days in (-700000..2900000i32).step_by(997) {
+            let date = _format_date(days);
+            assert_eq!(days, _parse_date(&date).unwrap());
+        }
+    }
+
+    #[test]
+    fn build_predicate_info_works_for_date() {
+        let predicate = build_predicate_info("birthdate", PredicateTypes::LT, "2000-01-01", &AttributeEncoding::Date).unwrap();
+        assert_eq!("birthdate", predicate.name);
+        assert_eq!(PredicateTypes::LT, predicate.p_type);
+        assert_eq!(10957, predicate.p_value);
+    }
+
+    #[test]
+    fn build_predicate_info_works_for_hashed_string() {
+        let res = build_predicate_info("name", PredicateTypes::GE, "Alex", &AttributeEncoding::String);
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn encode_credential_values_works() {
+        let values: TypedCredentialValues = serde_json::from_str(r#"{
+            "name": "Alex",
+            "age": {"value": 28, "encoding": {"type": "integer"}},
+            "birthdate": {"value": "1992-05-15", "encoding": {"type": "date"}}
+        }"#).unwrap();
+
+        let values = encode_credential_values(values).unwrap();
+
+        assert_eq!(AttributeValues { raw: "28".to_string(), encoded: "28".to_string() }, values.0["age"]);
+        assert_eq!(AttributeValues { raw: "1992-05-15".to_string(), encoded: "8170".to_string() }, values.0["birthdate"]);
+        assert_eq!("Alex", values.0["name"].raw);
+    }
+}
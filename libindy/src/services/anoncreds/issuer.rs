@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use ursa::cl::{
     CredentialKeyCorrectnessProof,
     CredentialPrivateKey,
@@ -164,6 +166,32 @@ impl Issuer {
         Ok(rev_reg_delta)
     }
 
+    pub fn update_revocation_registry<RTA>(&self,
+                                           rev_reg: &mut RevocationRegistry,
+                                           max_cred_num: u32,
+                                           issued: &HashSet<u32>,
+                                           revoked: &HashSet<u32>,
+                                           rev_tails_accessor: &RTA) -> IndyResult<RevocationRegistryDelta> where RTA: RevocationTailsAccessor {
+        trace!("update_revocation_registry >>> rev_reg: {:?}, max_cred_num: {:?}, issued: {:?}, revoked: {:?}",
+               rev_reg, max_cred_num, secret!(issued), secret!(revoked));
+
+        let prev_rev_reg = rev_reg.clone();
+
+        for rev_idx in revoked {
+            CryptoIssuer::revoke_credential(rev_reg, max_cred_num, *rev_idx, rev_tails_accessor)?;
+        }
+
+        for rev_idx in issued {
+            CryptoIssuer::recovery_credential(rev_reg, max_cred_num, *rev_idx, rev_tails_accessor)?;
+        }
+
+        let rev_reg_delta = RevocationRegistryDelta::from_parts(Some(&prev_rev_reg), rev_reg, issued, revoked);
+
+        trace!("update_revocation_registry <<< rev_reg_delta {:?}", rev_reg_delta);
+
+        Ok(rev_reg_delta)
+    }
+
     #[allow(dead_code)]
     pub fn recovery<RTA>(&self,
                          rev_reg: &mut RevocationRegistry,
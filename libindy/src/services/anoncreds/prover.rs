@@ -1,5 +1,6 @@
+use std::cmp::Ordering;
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use ursa::cl::{
     BlindedCredentialSecrets,
@@ -7,6 +8,7 @@ use ursa::cl::{
     CredentialPublicKey,
     CredentialSecretsBlindingFactors,
     MasterSecret,
+    RevocationRegistryDelta as CryptoRevocationRegistryDelta,
     SubProofRequest,
 };
 use ursa::cl::issuer::Issuer as CryptoIssuer;
@@ -18,10 +20,11 @@ use crate::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolic
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1 as CredentialDefinition, CredentialDefinitionId};
 use crate::domain::anoncreds::credential_offer::CredentialOffer;
 use crate::domain::anoncreds::credential_request::CredentialRequestMetadata;
+use crate::domain::anoncreds::credential_selection::{CredentialCandidate, CredentialSelectionPolicy};
 use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof, RevealedAttributeInfo, SubProofReferent, RevealedAttributeGroupInfo, AttributeValue};
 use crate::domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes, ProofRequest, ProofRequestPayload, ProofRequestsVersion, RequestedAttributeInfo, RequestedPredicateInfo, ProofRequestExtraQuery};
 use crate::domain::anoncreds::requested_credential::ProvingCredentialKey;
-use crate::domain::anoncreds::requested_credential::RequestedCredentials;
+use crate::domain::anoncreds::requested_credential::{RequestedAttribute, RequestedCredentials};
 use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
 use crate::domain::anoncreds::revocation_state::RevocationState;
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
@@ -141,11 +144,17 @@ impl Prover {
                     .clone()
                     .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Revocation Registry Id not found"))?;
 
-                let rev_states_for_timestamp = rev_states.get(&rev_reg_id.0)
-                    .or(rev_states.get(cred_key.cred_id.as_str()))
-                    .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("RevocationState not found by id: {:?}", rev_reg_id)))?;
+                let rev_states_for_timestamp = [rev_reg_id.0.as_str(), cred_key.cred_id.as_str()].iter()
+                    .filter_map(|key| rev_states.get(*key))
+                    .collect::<Vec<&HashMap<u64, RevocationState>>>();
 
-                Some(rev_states_for_timestamp.get(&timestamp)
+                if rev_states_for_timestamp.is_empty() {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("RevocationState not found by id: {:?}", rev_reg_id)));
+                }
+
+                // states can be provided both by registry and by credential id
+                Some(rev_states_for_timestamp.iter()
+                    .find_map(|rev_states_for_timestamp| rev_states_for_timestamp.get(&timestamp))
                     .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("RevocationInfo not found by timestamp: {:?}", timestamp)))?)
             } else { None };
 
@@ -325,6 +334,173 @@ impl Prover {
         format!("attr::{}::value", attr_common_view(&attr))
     }
 
+    pub fn select_credentials(&self,
+                              attr_candidates: &HashMap<String, Vec<CredentialCandidate>>,
+                              predicate_candidates: &HashMap<String, Vec<CredentialCandidate>>,
+                              self_attested_attributes: HashMap<String, String>,
+                              policy: CredentialSelectionPolicy) -> IndyResult<RequestedCredentials> {
+        trace!("select_credentials >>> attr_candidates: {:?}, predicate_candidates: {:?}, self_attested_attributes: {:?}, policy: {:?}",
+               attr_candidates, predicate_candidates, self_attested_attributes, policy);
+
+        let mut unsatisfied_referents: Vec<&str> = attr_candidates
+            .iter()
+            .chain(predicate_candidates.iter())
+            .filter(|(_, candidates)| candidates.is_empty())
+            .map(|(referent, _)| referent.as_str())
+            .collect();
+
+        if !unsatisfied_referents.is_empty() {
+            unsatisfied_referents.sort();
+            return Err(err_msg(IndyErrorKind::WalletItemNotFound,
+                               format!("No credentials found in the wallet for referents: {}", unsatisfied_referents.join(", "))));
+        }
+
+        let (attrs, predicates) = match policy {
+            CredentialSelectionPolicy::FewestCredentials =>
+                Prover::_select_fewest_credentials(attr_candidates, predicate_candidates),
+            _ => (Prover::_select_by_policy(attr_candidates, policy),
+                  Prover::_select_by_policy(predicate_candidates, policy))
+        };
+
+        let requested_credentials = RequestedCredentials {
+            self_attested_attributes,
+            requested_attributes: attrs
+                .into_iter()
+                .map(|(referent, candidate)| (referent, RequestedAttribute {
+                    cred_id: candidate.cred_id.clone(),
+                    timestamp: candidate.timestamp,
+                    revealed: true,
+                }))
+                .collect(),
+            requested_predicates: predicates
+                .into_iter()
+                .map(|(referent, candidate)| (referent, ProvingCredentialKey {
+                    cred_id: candidate.cred_id.clone(),
+                    timestamp: candidate.timestamp,
+                }))
+                .collect(),
+        };
+
+        trace!("select_credentials <<< requested_credentials: {:?}", requested_credentials);
+
+        Ok(requested_credentials)
+    }
+
+    fn _select_by_policy(candidates: &HashMap<String, Vec<CredentialCandidate>>,
+                         policy: CredentialSelectionPolicy) -> HashMap<String, &CredentialCandidate> {
+        candidates
+            .iter()
+            .filter_map(|(referent, candidates)| {
+                let candidate = match policy {
+                    CredentialSelectionPolicy::Oldest =>
+                        candidates.iter().min_by(|a, b| Prover::_compare_by_age(a, b)),
+                    CredentialSelectionPolicy::PreferNonRevocable =>
+                        candidates.iter().max_by(|a, b| a.rev_reg_id.is_none().cmp(&b.rev_reg_id.is_none())
+                            .then_with(|| Prover::_compare_by_age(a, b))),
+                    _ => candidates.iter().max_by(|a, b| Prover::_compare_by_age(a, b))
+                };
+                candidate.map(|candidate| (referent.to_string(), candidate))
+            })
+            .collect()
+    }
+
+    // Greedy set cover: repeatedly take the credential satisfying the most of not yet covered referents.
+    fn _select_fewest_credentials<'a>(attr_candidates: &'a HashMap<String, Vec<CredentialCandidate>>,
+                                      predicate_candidates: &'a HashMap<String, Vec<CredentialCandidate>>)
+                                      -> (HashMap<String, &'a CredentialCandidate>, HashMap<String, &'a CredentialCandidate>) {
+        let mut attrs: HashMap<String, &CredentialCandidate> = HashMap::new();
+        let mut predicates: HashMap<String, &CredentialCandidate> = HashMap::new();
+
+        loop {
+            let mut coverage: HashMap<&str, (usize, &CredentialCandidate)> = HashMap::new();
+
+            let uncovered = attr_candidates.iter().filter(|(referent, _)| !attrs.contains_key(*referent))
+                .chain(predicate_candidates.iter().filter(|(referent, _)| !predicates.contains_key(*referent)));
+
+            for (_, candidates) in uncovered {
+                for candidate in candidates {
+                    coverage.entry(candidate.cred_id.as_str()).or_insert((0, candidate)).0 += 1;
+                }
+            }
+
+            let cred_id = match coverage
+                .values()
+                .max_by(|(count_a, a), (count_b, b)| count_a.cmp(count_b).then_with(|| Prover::_compare_by_age(a, b))) {
+                Some((_, candidate)) => candidate.cred_id.clone(),
+                None => break
+            };
+
+            let covered_attrs = Prover::_find_candidates(attr_candidates, &attrs, &cred_id);
+            let covered_predicates = Prover::_find_candidates(predicate_candidates, &predicates, &cred_id);
+
+            attrs.extend(covered_attrs);
+            predicates.extend(covered_predicates);
+        }
+
+        (attrs, predicates)
+    }
+
+    fn _find_candidates<'a>(candidates: &'a HashMap<String, Vec<CredentialCandidate>>,
+                            selected: &HashMap<String, &'a CredentialCandidate>,
+                            cred_id: &str) -> Vec<(String, &'a CredentialCandidate)> {
+        candidates
+            .iter()
+            .filter(|(referent, _)| !selected.contains_key(*referent))
+            .filter_map(|(referent, candidates)|
+                candidates.iter()
+                    .find(|candidate| candidate.cred_id == cred_id)
+                    .map(|candidate| (referent.to_string(), candidate)))
+            .collect()
+    }
+
+    fn _compare_by_age(a: &CredentialCandidate, b: &CredentialCandidate) -> Ordering {
+        a.created_at.cmp(&b.created_at).then_with(|| a.cred_id.cmp(&b.cred_id))
+    }
+
+    /// Returns indices of issued and revoked credentials listed in revocation registry delta.
+    pub fn get_rev_reg_delta_indices(&self, rev_reg_delta: &CryptoRevocationRegistryDelta) -> IndyResult<(HashSet<u32>, HashSet<u32>)> {
+        #[derive(Deserialize)]
+        struct RevocationRegistryDeltaIndices {
+            #[serde(default)]
+            issued: HashSet<u32>,
+            #[serde(default)]
+            revoked: HashSet<u32>,
+        }
+
+        let rev_reg_delta = serde_json::to_value(rev_reg_delta)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryDelta")?;
+
+        let indices: RevocationRegistryDeltaIndices = serde_json::from_value(rev_reg_delta)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta indices")?;
+
+        Ok((indices.issued, indices.revoked))
+    }
+
+    /// Returns indices of credentials to add to and to remove from the accumulator
+    /// to move it between two registry states described by indices of full registry deltas.
+    pub fn get_rev_reg_increment(&self,
+                                 issuance_by_default: bool,
+                                 from: (&HashSet<u32>, &HashSet<u32>),
+                                 to: (&HashSet<u32>, &HashSet<u32>)) -> (HashSet<u32>, HashSet<u32>) {
+        // the same way as witness is built: issuance by default accumulates all not revoked credentials
+        let is_accumulated = |(issued, revoked): (&HashSet<u32>, &HashSet<u32>), idx: u32|
+            if issuance_by_default { !revoked.contains(&idx) } else { issued.contains(&idx) };
+
+        let indices: HashSet<u32> = from.0.iter().chain(from.1.iter()).chain(to.0.iter()).chain(to.1.iter()).cloned().collect();
+
+        let added = indices.iter()
+            .filter(|idx| !is_accumulated(from, **idx) && is_accumulated(to, **idx))
+            .cloned()
+            .collect();
+
+        let removed = indices.iter()
+            .filter(|idx| is_accumulated(from, **idx) && !is_accumulated(to, **idx))
+            .cloned()
+            .collect();
+
+        (added, removed)
+    }
+
     pub fn attribute_satisfy_predicate(&self,
                                        predicate: &PredicateInfo,
                                        attribute_value: &str) -> IndyResult<bool> {
@@ -1252,4 +1428,134 @@ mod tests {
             assert_eq!(expected_query, query);
         }
     }
+
+    mod select_credentials {
+        use super::*;
+        use crate::domain::anoncreds::revocation_registry_definition::RevocationRegistryId;
+
+        fn _candidate(cred_id: &str, created_at: u64, revocable: bool) -> CredentialCandidate {
+            CredentialCandidate {
+                cred_id: cred_id.to_string(),
+                rev_reg_id: if revocable { Some(RevocationRegistryId(REV_REG_ID.to_string())) } else { None },
+                created_at,
+                timestamp: None,
+            }
+        }
+
+        fn _candidates() -> (HashMap<String, Vec<CredentialCandidate>>, HashMap<String, Vec<CredentialCandidate>>) {
+            let attrs = hashmap!(
+                "attr1_referent".to_string() => vec![_candidate("cred_1", 1, false), _candidate("cred_2", 2, true), _candidate("cred_3", 3, false)],
+                "attr2_referent".to_string() => vec![_candidate("cred_3", 3, false), _candidate("cred_4", 4, true)]
+            );
+            let predicates = hashmap!(
+                "predicate1_referent".to_string() => vec![_candidate("cred_1", 1, false), _candidate("cred_3", 3, false), _candidate("cred_4", 4, true)]
+            );
+            (attrs, predicates)
+        }
+
+        fn _select(policy: CredentialSelectionPolicy) -> RequestedCredentials {
+            let (attrs, predicates) = _candidates();
+            Prover::new().select_credentials(&attrs, &predicates, HashMap::new(), policy).unwrap()
+        }
+
+        #[test]
+        fn select_credentials_works_for_newest() {
+            let requested_credentials = _select(CredentialSelectionPolicy::Newest);
+            assert_eq!("cred_3", requested_credentials.requested_attributes["attr1_referent"].cred_id);
+            assert_eq!("cred_4", requested_credentials.requested_attributes["attr2_referent"].cred_id);
+            assert_eq!("cred_4", requested_credentials.requested_predicates["predicate1_referent"].cred_id);
+        }
+
+        #[test]
+        fn select_credentials_works_for_oldest() {
+            let requested_credentials = _select(CredentialSelectionPolicy::Oldest);
+            assert_eq!("cred_1", requested_credentials.requested_attributes["attr1_referent"].cred_id);
+            assert_eq!("cred_3", requested_credentials.requested_attributes["attr2_referent"].cred_id);
+            assert_eq!("cred_1", requested_credentials.requested_predicates["predicate1_referent"].cred_id);
+        }
+
+        #[test]
+        fn select_credentials_works_for_prefer_non_revocable() {
+            let requested_credentials = _select(CredentialSelectionPolicy::PreferNonRevocable);
+            assert_eq!("cred_3", requested_credentials.requested_attributes["attr1_referent"].cred_id);
+            assert_eq!("cred_3", requested_credentials.requested_attributes["attr2_referent"].cred_id);
+            assert_eq!("cred_3", requested_credentials.requested_predicates["predicate1_referent"].cred_id);
+        }
+
+        #[test]
+        fn select_credentials_works_for_fewest_credentials() {
+            let (mut attrs, predicates) = _candidates();
+            attrs.insert("attr3_referent".to_string(), vec![_candidate("cred_4", 4, true)]);
+
+            let requested_credentials = Prover::new().select_credentials(&attrs, &predicates, HashMap::new(), CredentialSelectionPolicy::FewestCredentials).unwrap();
+
+            assert_eq!("cred_4", requested_credentials.requested_attributes["attr2_referent"].cred_id);
+            assert_eq!("cred_4", requested_credentials.requested_attributes["attr3_referent"].cred_id);
+            assert_eq!("cred_4", requested_credentials.requested_predicates["predicate1_referent"].cred_id);
+            assert_eq!("cred_3", requested_credentials.requested_attributes["attr1_referent"].cred_id);
+        }
+
+        #[test]
+        fn select_credentials_works_for_self_attested_attributes() {
+            let (attrs, predicates) = _candidates();
+            let self_attested = hashmap!("attr3_referent".to_string() => "value".to_string());
+
+            let requested_credentials = Prover::new().select_credentials(&attrs, &predicates, self_attested.clone(), CredentialSelectionPolicy::Newest).unwrap();
+
+            assert_eq!(self_attested, requested_credentials.self_attested_attributes);
+        }
+
+        #[test]
+        fn select_credentials_works_for_unsatisfied_referents() {
+            let (mut attrs, mut predicates) = _candidates();
+            attrs.insert("attr3_referent".to_string(), vec![]);
+            predicates.insert("predicate2_referent".to_string(), vec![]);
+
+            let err = Prover::new().select_credentials(&attrs, &predicates, HashMap::new(), CredentialSelectionPolicy::Newest).unwrap_err();
+
+            assert_eq!(IndyErrorKind::WalletItemNotFound, err.kind());
+            assert!(err.to_string().contains("attr3_referent, predicate2_referent"));
+        }
+    }
+
+    mod get_rev_reg_increment {
+        use super::*;
+
+        fn _set(indices: &[u32]) -> HashSet<u32> {
+            indices.iter().cloned().collect()
+        }
+
+        #[test]
+        fn get_rev_reg_increment_works_for_issuance_by_default() {
+            let prover = Prover::new();
+
+            let (added, removed) = prover.get_rev_reg_increment(true,
+                                                                (&_set(&[]), &_set(&[1, 2])),
+                                                                (&_set(&[2]), &_set(&[1, 3])));
+            assert_eq!(_set(&[2]), added);
+            assert_eq!(_set(&[3]), removed);
+        }
+
+        #[test]
+        fn get_rev_reg_increment_works_for_issuance_on_demand() {
+            let prover = Prover::new();
+
+            let (added, removed) = prover.get_rev_reg_increment(false,
+                                                                (&_set(&[1, 2]), &_set(&[])),
+                                                                (&_set(&[2, 3]), &_set(&[1])));
+            assert_eq!(_set(&[3]), added);
+            assert_eq!(_set(&[1]), removed);
+        }
+
+        #[test]
+        fn get_rev_reg_increment_works_for_same_state() {
+            let prover = Prover::new();
+
+            let (added, removed) = prover.get_rev_reg_increment(false,
+                                                                (&_set(&[1, 2]), &_set(&[3])),
+                                                                (&_set(&[1, 2]), &_set(&[3])));
+            assert!(added.is_empty());
+            assert!(removed.is_empty());
+        }
+    }
 }
@@ -3,6 +3,10 @@ use std::collections::{HashMap, HashSet};
 use crate::domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinitionId};
 use crate::domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeInfo};
 use crate::domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequestPayload, NonRevocedInterval};
+use crate::domain::anoncreds::proof_verification::{
+    CheckReport, ProofVerificationReport, ProofVerificationResult, ReferentReport, SubProofReport,
+    VerificationReason, VerificationReasonCode,
+};
 use crate::domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinitionV1, RevocationRegistryId};
 use crate::domain::anoncreds::schema::{SchemaV1, SchemaId};
@@ -11,9 +15,10 @@ use crate::services::anoncreds::helpers::*;
 
 use ursa::bn::BigNumber;
 use ursa::cl::{CredentialPublicKey, new_nonce, Nonce};
-use ursa::cl::verifier::Verifier as CryptoVerifier;
+use ursa::cl::verifier::{Verifier as CryptoVerifier, ProofVerifier};
 use crate::utils::wql::Query;
 use regex::Regex;
+use failure::Fail;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct Filter {
@@ -73,7 +78,244 @@ impl Verifier {
                                                              &received_self_attested_attrs,
                                                              &received_predicates)?;
 
+        let valid = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    /// Verifies proof the same way as `verify` but doesn't stop on the first rejected check and
+    /// lists every failure of `verify_report` together with the referent or sub proof it belongs to.
+    /// Timestamps of revocation registries must also be inside of requested non-revoked intervals.
+    pub fn verify_with_result(&self,
+                              full_proof: &Proof,
+                              proof_req: &ProofRequestPayload,
+                              schemas: &HashMap<SchemaId, SchemaV1>,
+                              cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                              rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                              rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationResult> {
+        trace!("verify_with_result >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.verify_report(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        let result = ProofVerificationResult::new(report.failures());
+
+        trace!("verify_with_result <<< result: {:?}", result);
+
+        Ok(result)
+    }
+
+    /// Verifies proof and reports the outcome for every requested referent, every sub proof
+    /// and the crypto proof instead of stopping on the first rejected check.
+    pub fn verify_report(&self,
+                         full_proof: &Proof,
+                         proof_req: &ProofRequestPayload,
+                         schemas: &HashMap<SchemaId, SchemaV1>,
+                         cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                         rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                         rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationReport> {
+        trace!("verify_report >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let requested_proof = &full_proof.requested_proof;
+        let self_attested_attrs = Verifier::_received_self_attested_attrs(full_proof);
+
+        let mut attributes: HashMap<String, ReferentReport> = HashMap::new();
+
+        for (referent, info) in proof_req.requested_attributes.iter() {
+            let sub_proof_index = requested_proof.revealed_attrs.get(referent).map(|attr| attr.sub_proof_index)
+                .or_else(|| requested_proof.revealed_attr_groups.get(referent).map(|attr| attr.sub_proof_index))
+                .or_else(|| requested_proof.unrevealed_attrs.get(referent).map(|attr| attr.sub_proof_index));
+
+            let mut reasons = Vec::new();
+
+            match sub_proof_index {
+                Some(sub_proof_index) => {
+                    if let Err(err) = Verifier::_verify_revealed_attr_referent_value(proof_req, full_proof, referent)
+                        .and_then(|_| Verifier::_verify_revealed_attr_group_referent_values(proof_req, full_proof, referent)) {
+                        reasons.push(Verifier::_reason(VerificationReasonCode::EncodingMismatch, &err));
+                    }
+
+                    match Verifier::_get_proof_identifier(full_proof, sub_proof_index) {
+                        Ok(identifier) => {
+                            let mut identifiers = HashMap::new();
+                            identifiers.insert(referent.to_string(), identifier.clone());
+
+                            if let Err(err) = Verifier::_verify_attribute_restrictions(proof_req, referent, info, requested_proof, &identifiers) {
+                                reasons.push(Verifier::_reason(VerificationReasonCode::RestrictionNotSatisfied, &err));
+                            }
+
+                            Verifier::_report_timestamp(proof_req, referent, &info.non_revoked, &identifier, &mut reasons);
+                        }
+                        Err(err) => reasons.push(Verifier::_reason(VerificationReasonCode::IdentifierNotFound, &err))
+                    }
+                }
+                None if self_attested_attrs.contains(referent) => {
+                    if !Verifier::_is_self_attested(referent, info, &self_attested_attrs) {
+                        reasons.push(VerificationReason::new(
+                            VerificationReasonCode::RestrictionNotSatisfied,
+                            format!("Attribute with referent \"{}\" has restrictions and can't be self attested", referent)));
+                    }
+                }
+                None => reasons.push(VerificationReason::new(
+                    VerificationReasonCode::MissingInProof,
+                    format!("Attribute with referent \"{}\" not found in Proof", referent)))
+            }
+
+            attributes.insert(referent.to_string(), ReferentReport::new(sub_proof_index, reasons));
+        }
+
+        let received_attrs = requested_proof.revealed_attrs.iter().map(|(referent, attr)| (referent, Some(attr.sub_proof_index)))
+            .chain(requested_proof.revealed_attr_groups.iter().map(|(referent, attr)| (referent, Some(attr.sub_proof_index))))
+            .chain(requested_proof.unrevealed_attrs.iter().map(|(referent, attr)| (referent, Some(attr.sub_proof_index))))
+            .chain(requested_proof.self_attested_attrs.keys().map(|referent| (referent, None)));
+
+        for (referent, sub_proof_index) in received_attrs {
+            if !proof_req.requested_attributes.contains_key(referent) {
+                attributes.insert(referent.to_string(), Verifier::_not_requested_report(referent, sub_proof_index));
+            }
+        }
+
+        let requested_attrs: HashMap<String, AttributeInfo> = proof_req.requested_attributes
+            .iter()
+            .filter(|&(referent, info)| !Verifier::_is_self_attested(&referent, &info, &self_attested_attrs))
+            .map(|(referent, info)| (referent.to_string(), info.clone()))
+            .collect();
+
+        let mut predicates: HashMap<String, ReferentReport> = HashMap::new();
+
+        for (referent, info) in proof_req.requested_predicates.iter() {
+            let sub_proof_index = requested_proof.predicates.get(referent).map(|predicate| predicate.sub_proof_index);
+
+            let mut reasons = Vec::new();
+
+            match sub_proof_index {
+                Some(sub_proof_index) => match Verifier::_get_proof_identifier(full_proof, sub_proof_index) {
+                    Ok(identifier) => {
+                        let mut identifiers = HashMap::new();
+                        identifiers.insert(referent.to_string(), identifier.clone());
+
+                        if let Err(err) = Verifier::_verify_predicate_restrictions(referent, info, requested_proof, &requested_attrs, &identifiers) {
+                            reasons.push(Verifier::_reason(VerificationReasonCode::RestrictionNotSatisfied, &err));
+                        }
+
+                        Verifier::_report_timestamp(proof_req, referent, &info.non_revoked, &identifier, &mut reasons);
+                    }
+                    Err(err) => reasons.push(Verifier::_reason(VerificationReasonCode::IdentifierNotFound, &err))
+                },
+                None => reasons.push(VerificationReason::new(
+                    VerificationReasonCode::MissingInProof,
+                    format!("Predicate with referent \"{}\" not found in Proof", referent)))
+            }
+
+            predicates.insert(referent.to_string(), ReferentReport::new(sub_proof_index, reasons));
+        }
+
+        for (referent, predicate) in requested_proof.predicates.iter() {
+            if !proof_req.requested_predicates.contains_key(referent) {
+                predicates.insert(referent.to_string(), Verifier::_not_requested_report(referent, Some(predicate.sub_proof_index)));
+            }
+        }
+
+        let mut identifiers: Vec<SubProofReport> = Vec::new();
+        let mut revocation: Vec<SubProofReport> = Vec::new();
+
+        for (sub_proof_index, identifier) in full_proof.identifiers.iter().enumerate() {
+            let sub_proof_index = sub_proof_index as u32;
+
+            let mut reasons = Vec::new();
+
+            if !schemas.contains_key(&identifier.schema_id) {
+                reasons.push(VerificationReason::new(
+                    VerificationReasonCode::SchemaNotFound,
+                    format!("Schema not found for id: {:?}", identifier.schema_id)));
+            }
+
+            if !cred_defs.contains_key(&identifier.cred_def_id) {
+                reasons.push(VerificationReason::new(
+                    VerificationReasonCode::CredentialDefinitionNotFound,
+                    format!("CredentialDefinition not found for id: {:?}", identifier.cred_def_id)));
+            }
+
+            identifiers.push(SubProofReport::new(sub_proof_index, reasons));
+
+            if let Some(timestamp) = identifier.timestamp {
+                let mut reasons = Vec::new();
+
+                match identifier.rev_reg_id {
+                    Some(ref rev_reg_id) => {
+                        if !rev_reg_defs.contains_key(rev_reg_id) {
+                            reasons.push(VerificationReason::new(
+                                VerificationReasonCode::RevocationRegistryDefinitionNotFound,
+                                format!("RevocationRegistryDefinition not found for id: {:?}", rev_reg_id)));
+                        }
+
+                        if !rev_regs.get(rev_reg_id).map(|rev_regs| rev_regs.contains_key(&timestamp)).unwrap_or(false) {
+                            reasons.push(VerificationReason::new(
+                                VerificationReasonCode::RevocationRegistryNotFound,
+                                format!("RevocationRegistry not found for id: {:?} and timestamp: {:?}", rev_reg_id, timestamp)));
+                        }
+                    }
+                    None => reasons.push(VerificationReason::new(
+                        VerificationReasonCode::RevocationRegistryIdMissing,
+                        "Revocation Registry Id not found".to_string()))
+                }
+
+                revocation.push(SubProofReport::new(sub_proof_index, reasons));
+            }
+        }
+
+        let crypto = if identifiers.iter().chain(revocation.iter()).all(|report| report.valid) {
+            match Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs) {
+                Ok(true) => CheckReport::new(Vec::new()),
+                Ok(false) => CheckReport::new(vec![VerificationReason::new(
+                    VerificationReasonCode::CryptoProofInvalid,
+                    "Proof is not valid for the credential definitions or revocation registries it refers to".to_string())]),
+                Err(err) => match err.kind() {
+                    IndyErrorKind::InvalidStructure | IndyErrorKind::ProofRejected =>
+                        CheckReport::new(vec![Verifier::_reason(VerificationReasonCode::CryptoProofInvalid, &err)]),
+                    _ => return Err(err)
+                }
+            }
+        } else {
+            CheckReport::new(vec![VerificationReason::new(
+                VerificationReasonCode::NotEvaluated,
+                "Crypto proof is not evaluated because ledger entities it refers to are missing".to_string())])
+        };
+
+        let valid = attributes.values().chain(predicates.values()).all(|report| report.valid)
+            && identifiers.iter().chain(revocation.iter()).all(|report| report.valid)
+            && crypto.valid;
+
+        let report = ProofVerificationReport { valid, attributes, predicates, identifiers, revocation, crypto };
+
+        trace!("verify_report <<< report: {:?}", report);
+
+        Ok(report)
+    }
+
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequestPayload,
+                            schemas: &HashMap<SchemaId, SchemaV1>,
+                            cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
+
+        Verifier::_add_sub_proof_requests(&mut proof_verifier, full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        Ok(proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?)
+    }
+
+    fn _add_sub_proof_requests(proof_verifier: &mut ProofVerifier,
+                               full_proof: &Proof,
+                               proof_req: &ProofRequestPayload,
+                               schemas: &HashMap<SchemaId, SchemaV1>,
+                               cred_defs: &HashMap<CredentialDefinitionId, CredentialDefinitionV1>,
+                               rev_reg_defs: &HashMap<RevocationRegistryId, RevocationRegistryDefinitionV1>,
+                               rev_regs: &HashMap<RevocationRegistryId, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<()> {
         let non_credential_schema = build_non_credential_schema()?;
 
         for sub_proof_index in 0..full_proof.identifiers.len() {
@@ -122,11 +364,59 @@ impl Verifier {
                                                  rev_reg.as_ref().map(|r_reg| &r_reg.value))?;
         }
 
-        let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
+        Ok(())
+    }
 
-        trace!("verify <<< valid: {:?}", valid);
+    /// Joins messages of the error causes, falling back to the error kind.
+    pub fn failure_message(err: &IndyError) -> String {
+        let message = <dyn Fail>::iter_chain(err)
+            .skip(1)
+            .map(|cause| cause.to_string())
+            .collect::<Vec<String>>()
+            .join(": ");
 
-        Ok(valid)
+        if message.is_empty() { err.kind().to_string() } else { message }
+    }
+
+    fn _reason(code: VerificationReasonCode, err: &IndyError) -> VerificationReason {
+        VerificationReason::new(code, Verifier::failure_message(err))
+    }
+
+    fn _not_requested_report(referent: &str, sub_proof_index: Option<u32>) -> ReferentReport {
+        ReferentReport::new(sub_proof_index, vec![VerificationReason::new(
+            VerificationReasonCode::NotRequested,
+            format!("Referent \"{}\" is not requested by ProofRequest", referent))])
+    }
+
+    fn _report_timestamp(proof_req: &ProofRequestPayload,
+                         referent: &str,
+                         local_interval: &Option<NonRevocedInterval>,
+                         identifier: &Identifier,
+                         reasons: &mut Vec<VerificationReason>) {
+        let interval = match get_non_revoc_interval(&proof_req.non_revoked, local_interval) {
+            Some(interval) => interval,
+            None => return
+        };
+
+        let timestamp = match identifier.timestamp {
+            Some(timestamp) => timestamp,
+            None => {
+                reasons.push(VerificationReason::new(
+                    VerificationReasonCode::TimestampMissing,
+                    format!("Non-revoked interval is requested for referent \"{}\" but Proof doesn't contain timestamp", referent)));
+                return;
+            }
+        };
+
+        let before_from = interval.from.map(|from| timestamp < from).unwrap_or(false);
+        let after_to = interval.to.map(|to| timestamp > to).unwrap_or(false);
+
+        if before_from || after_to {
+            reasons.push(VerificationReason::new(
+                VerificationReasonCode::TimestampOutOfInterval,
+                format!("Revocation registry timestamp {} for referent \"{}\" is out of non-revoked interval from {:?} to {:?}",
+                        timestamp, referent, interval.from, interval.to)));
+        }
     }
 
     pub fn generate_nonce(&self) -> IndyResult<Nonce> {
@@ -324,35 +614,57 @@ impl Verifier {
 
     fn _verify_revealed_attribute_values(proof_req: &ProofRequestPayload,
                                          proof: &Proof) -> IndyResult<()> {
-        for (attr_referent, attr_info) in proof.requested_proof.revealed_attrs.iter() {
-
-            let attr_name = proof_req.requested_attributes.get(attr_referent)
-                .as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
-                .name.as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-            Verifier::_verify_revealed_attribute_value(attr_name.as_str(), proof, &attr_info)?;
+        for attr_referent in proof.requested_proof.revealed_attrs.keys() {
+            Verifier::_verify_revealed_attr_referent_value(proof_req, proof, attr_referent)?;
         }
 
-        for (attr_referent, attr_infos) in proof.requested_proof.revealed_attr_groups.iter() {
-            let attr_names = proof_req.requested_attributes.get(attr_referent)
-                .as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
-                .names.as_ref()
-                .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
-            if attr_infos.values.len() != attr_names.len() {
-                error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
-                return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))
-            }
-            for attr_name in attr_names {
-                let attr_info = &attr_infos.values.get(attr_name)
-                    .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
-                Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
-                    sub_proof_index: attr_infos.sub_proof_index,
-                    raw: attr_info.raw.clone(),
-                    encoded: attr_info.encoded.clone()
-                })?;
-            }
+        for attr_referent in proof.requested_proof.revealed_attr_groups.keys() {
+            Verifier::_verify_revealed_attr_group_referent_values(proof_req, proof, attr_referent)?;
+        }
+        Ok(())
+    }
+
+    fn _verify_revealed_attr_referent_value(proof_req: &ProofRequestPayload,
+                                            proof: &Proof,
+                                            attr_referent: &str) -> IndyResult<()> {
+        let attr_info = match proof.requested_proof.revealed_attrs.get(attr_referent) {
+            Some(attr_info) => attr_info,
+            None => return Ok(())
+        };
+
+        let attr_name = proof_req.requested_attributes.get(attr_referent)
+            .as_ref()
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
+            .name.as_ref()
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
+        Verifier::_verify_revealed_attribute_value(attr_name.as_str(), proof, &attr_info)
+    }
+
+    fn _verify_revealed_attr_group_referent_values(proof_req: &ProofRequestPayload,
+                                                   proof: &Proof,
+                                                   attr_referent: &str) -> IndyResult<()> {
+        let attr_infos = match proof.requested_proof.revealed_attr_groups.get(attr_referent) {
+            Some(attr_infos) => attr_infos,
+            None => return Ok(())
+        };
+
+        let attr_names = proof_req.requested_attributes.get(attr_referent)
+            .as_ref()
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?
+            .names.as_ref()
+            .ok_or(IndyError::from_msg(IndyErrorKind::ProofRejected, format!("Attribute with referent \"{}\" not found in ProofRequests", attr_referent)))?;
+        if attr_infos.values.len() != attr_names.len() {
+            error!("Proof Revealed Attr Group does not match Proof Request Attribute Group, proof request attrs: {:?}, referent: {:?}, attr_infos: {:?}", proof_req.requested_attributes, attr_referent, attr_infos);
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))
+        }
+        for attr_name in attr_names {
+            let attr_info = &attr_infos.values.get(attr_name)
+                .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof Revealed Attr Group does not match Proof Request Attribute Group"))?;
+            Verifier::_verify_revealed_attribute_value(attr_name, proof, &RevealedAttributeInfo {
+                sub_proof_index: attr_infos.sub_proof_index,
+                raw: attr_info.raw.clone(),
+                encoded: attr_info.encoded.clone()
+            })?;
         }
         Ok(())
     }
@@ -398,74 +710,93 @@ impl Verifier {
             .map(|(referent, info)| (referent.to_string(), info.clone()))
             .collect();
 
-        for (referent, info) in requested_attrs.clone() {
-            if let Some(ref query) = info.restrictions {
-                let filter = Verifier::_gather_filter_info(&referent, &proof_attr_identifiers)?;
-
-                let name_value_map: HashMap<String, Option<&str>> = if let Some(name) = info.name {
-                    let mut map = HashMap::new();
-                    map.insert(name.clone(), requested_proof.revealed_attrs.get(&referent).map(|attr| attr.raw.as_str()));
-                    map
-                } else if let Some(names) = info.names {
-                    let mut map = HashMap::new();
-                    let attrs = requested_proof.revealed_attr_groups.get(&referent)
-                        .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
-                    for name in names {
-                        let val = attrs.values.get(&name).map(|attr| attr.raw.as_str());
-                        map.insert(name, val);
-                    }
-                    map
-                } else {
-                    error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current proof request: {:?}"#, proof_req);
-                    return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#));
-                };
-
-                Verifier::_do_process_operator(&name_value_map, &query, &filter)
-                    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{:?}\" attributes", &name_value_map)))?;
-            }
+        for (referent, info) in requested_attrs.iter() {
+            Verifier::_verify_attribute_restrictions(proof_req, referent, info, requested_proof, &proof_attr_identifiers)?;
         }
 
         for (referent, info) in proof_req.requested_predicates.iter() {
-            if let Some(ref query) = info.restrictions {
-                let filter = Verifier::_gather_filter_info(&referent, received_predicates)?;
-
-                // start with the predicate requested attribute, which is un-revealed
-                let mut attr_value_map = HashMap::new();
-                attr_value_map.insert(info.name.to_string(), None);
-
-                // include any revealed attributes for the same credential (based on sub_proof_index)
-                let pred_sub_proof_index = requested_proof.predicates.get(referent).unwrap().sub_proof_index;
-                for attr_referent in requested_proof.revealed_attrs.keys() {
-                    let attr_info = requested_proof.revealed_attrs.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        let attr_name = requested_attrs.get(attr_referent).unwrap().name.clone();
-                        if let Some(name) = attr_name {
-                            attr_value_map.insert(name, Some(attr_info.raw.as_str()));
-                        }
-                    }
-                }
-                for attr_referent in requested_proof.revealed_attr_groups.keys() {
-                    let attr_info = requested_proof.revealed_attr_groups.get(attr_referent).unwrap();
-                    let attr_sub_proof_index = attr_info.sub_proof_index;
-                    if pred_sub_proof_index == attr_sub_proof_index {
-                        for name in attr_info.values.keys() {
-                            let raw_val = attr_info.values.get(name).unwrap().raw.as_str();
-                            attr_value_map.insert(name.clone(), Some(raw_val.clone()));
-                        }
-                    }
-                }
+            Verifier::_verify_predicate_restrictions(referent, info, requested_proof, &requested_attrs, received_predicates)?;
+        }
 
-                Verifier::_do_process_operator(&attr_value_map, &query, &filter)
-                    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
+        Ok(())
+    }
 
-                // old style :-/ which fails for attribute restrictions on predicates
-                //Verifier::_process_operator(&info.name, &query, &filter, None)
-                //    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
+    fn _verify_attribute_restrictions(proof_req: &ProofRequestPayload,
+                                      referent: &str,
+                                      info: &AttributeInfo,
+                                      requested_proof: &RequestedProof,
+                                      proof_attr_identifiers: &HashMap<String, Identifier>) -> IndyResult<()> {
+        let query = match info.restrictions {
+            Some(ref query) => query,
+            None => return Ok(())
+        };
+
+        let filter = Verifier::_gather_filter_info(&referent, proof_attr_identifiers)?;
+
+        let name_value_map: HashMap<String, Option<&str>> = if let Some(ref name) = info.name {
+            let mut map = HashMap::new();
+            map.insert(name.clone(), requested_proof.revealed_attrs.get(referent).map(|attr| attr.raw.as_str()));
+            map
+        } else if let Some(ref names) = info.names {
+            let mut map = HashMap::new();
+            let attrs = requested_proof.revealed_attr_groups.get(referent)
+                .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?;
+            for name in names {
+                let val = attrs.values.get(name).map(|attr| attr.raw.as_str());
+                map.insert(name.clone(), val);
+            }
+            map
+        } else {
+            error!(r#"Proof Request attribute restriction should contain "name" or "names" param. Current proof request: {:?}"#, proof_req);
+            return Err(IndyError::from_msg(IndyErrorKind::InvalidStructure, r#"Proof Request attribute restriction should contain "name" or "names" param"#));
+        };
+
+        Verifier::_do_process_operator(&name_value_map, &query, &filter)
+            .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{:?}\" attributes", &name_value_map)))
+    }
+
+    fn _verify_predicate_restrictions(referent: &str,
+                                      info: &PredicateInfo,
+                                      requested_proof: &RequestedProof,
+                                      requested_attrs: &HashMap<String, AttributeInfo>,
+                                      received_predicates: &HashMap<String, Identifier>) -> IndyResult<()> {
+        let query = match info.restrictions {
+            Some(ref query) => query,
+            None => return Ok(())
+        };
+
+        let filter = Verifier::_gather_filter_info(&referent, received_predicates)?;
+
+        // start with the predicate requested attribute, which is un-revealed
+        let mut attr_value_map = HashMap::new();
+        attr_value_map.insert(info.name.to_string(), None);
+
+        // include any revealed attributes for the same credential (based on sub_proof_index)
+        let pred_sub_proof_index = requested_proof.predicates.get(referent)
+            .ok_or(IndyError::from_msg(IndyErrorKind::InvalidStructure, "Proof does not have referent from proof request"))?
+            .sub_proof_index;
+        for (attr_referent, attr_info) in requested_proof.revealed_attrs.iter() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                let attr_name = requested_attrs.get(attr_referent).and_then(|info| info.name.clone());
+                if let Some(name) = attr_name {
+                    attr_value_map.insert(name, Some(attr_info.raw.as_str()));
+                }
+            }
+        }
+        for attr_info in requested_proof.revealed_attr_groups.values() {
+            if pred_sub_proof_index == attr_info.sub_proof_index {
+                for (name, value) in attr_info.values.iter() {
+                    attr_value_map.insert(name.clone(), Some(value.raw.as_str()));
+                }
             }
         }
 
-        Ok(())
+        // old style :-/ which fails for attribute restrictions on predicates
+        //Verifier::_process_operator(&info.name, &query, &filter, None)
+        //    .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))?;
+
+        Verifier::_do_process_operator(&attr_value_map, &query, &filter)
+            .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" predicate", &info.name)))
     }
 
     fn _is_self_attested(referent: &str, info: &AttributeInfo, self_attested_attrs: &HashSet<String>) -> bool {
@@ -969,4 +1300,55 @@ mod tests {
         Verifier::_validate_timestamp(&_received(), "referent_2", &None, &Some(_interval())).unwrap_err();
         Verifier::_validate_timestamp(&_received(), "referent_3", &None, &Some(_interval())).unwrap_err();
     }
+
+    fn _timestamp_reasons(proof_req: &ProofRequestPayload, referent: &str, local_interval: &Option<NonRevocedInterval>,
+                          identifier: &Identifier) -> Vec<VerificationReasonCode> {
+        let mut reasons = Vec::new();
+        Verifier::_report_timestamp(proof_req, referent, local_interval, identifier, &mut reasons);
+        reasons.into_iter().map(|reason| reason.code).collect()
+    }
+
+    #[test]
+    fn report_timestamp_works() {
+        let mut proof_req: ProofRequestPayload = serde_json::from_value(json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "0.1",
+        })).unwrap();
+
+        let received = _received();
+        let with_timestamp = &received["referent_1"];
+        let without_timestamp = &received["referent_2"];
+
+        assert!(_timestamp_reasons(&proof_req, "referent_2", &None, without_timestamp).is_empty());
+        assert!(_timestamp_reasons(&proof_req, "referent_1", &Some(_interval()), with_timestamp).is_empty());
+        assert_eq!(vec![VerificationReasonCode::TimestampOutOfInterval],
+                   _timestamp_reasons(&proof_req, "referent_1", &Some(NonRevocedInterval { from: None, to: Some(1233) }), with_timestamp));
+        assert_eq!(vec![VerificationReasonCode::TimestampMissing],
+                   _timestamp_reasons(&proof_req, "referent_2", &Some(_interval()), without_timestamp));
+
+        assert!(_timestamp_reasons(&proof_req, "referent_1", &Some(NonRevocedInterval { from: Some(1234), to: Some(1234) }), with_timestamp).is_empty());
+        assert_eq!(vec![VerificationReasonCode::TimestampOutOfInterval],
+                   _timestamp_reasons(&proof_req, "referent_1", &Some(NonRevocedInterval { from: Some(1235), to: None }), with_timestamp));
+        assert_eq!(vec![VerificationReasonCode::TimestampOutOfInterval],
+                   _timestamp_reasons(&proof_req, "referent_1", &Some(NonRevocedInterval { from: Some(1235), to: Some(2000) }), with_timestamp));
+
+        proof_req.non_revoked = Some(NonRevocedInterval { from: None, to: Some(1000) });
+        assert_eq!(vec![VerificationReasonCode::TimestampOutOfInterval],
+                   _timestamp_reasons(&proof_req, "referent_1", &None, with_timestamp));
+        assert!(_timestamp_reasons(&proof_req, "referent_1", &Some(_interval()), with_timestamp).is_empty());
+    }
+
+    #[test]
+    fn failure_message_works_for_extended_error() {
+        let err = err_msg(IndyErrorKind::ProofRejected, "$eq operator validation failed")
+            .extend("Requested restriction validation failed");
+        assert_eq!("Requested restriction validation failed: $eq operator validation failed", Verifier::failure_message(&err));
+    }
+
+    #[test]
+    fn failure_message_works_for_error_without_message() {
+        let err = IndyError::from(IndyErrorKind::InvalidStructure);
+        assert_eq!(IndyErrorKind::InvalidStructure.to_string(), Verifier::failure_message(&err));
+    }
 }
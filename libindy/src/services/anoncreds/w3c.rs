@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+
+use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::base64;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::domain::anoncreds::credential::{AttributeValues, Credential, CredentialValues};
+use crate::domain::anoncreds::credential_definition::CredentialDefinitionId;
+use crate::domain::anoncreds::proof::{Identifier, Proof, RequestedProof};
+use crate::domain::anoncreds::proof_request::{ProofRequest, ProofRequestPayload};
+use crate::domain::anoncreds::w3c::*;
+use crate::domain::ledger::ddo::DEFAULT_DID_METHOD;
+
+pub fn credential_to_w3c(credential: Credential) -> IndyResult<W3CCredential> {
+    trace!("credential_to_w3c >>> credential: {:?}", secret!(&credential));
+
+    let issuer = _issuer(&credential.cred_def_id)?;
+
+    let credential_subject = credential.values.0.iter()
+        .map(|(name, value)| (name.clone(), W3CAttributeValue::Value(value.raw.clone())))
+        .collect();
+
+    let encoded = credential.values.0.into_iter()
+        .map(|(name, value)| (name, value.encoded))
+        .collect();
+
+    let proof_value = CredentialProofValue {
+        signature: credential.signature,
+        signature_correctness_proof: credential.signature_correctness_proof,
+        rev_reg: credential.rev_reg,
+        witness: credential.witness,
+        encoded,
+    };
+
+    let res = W3CCredential {
+        context: vec![W3C_CONTEXT.to_string(), ANONCREDS_CONTEXT.to_string()],
+        type_: vec![W3C_CREDENTIAL_TYPE.to_string(), ANONCREDS_CREDENTIAL_TYPE.to_string()],
+        issuer,
+        credential_schema: W3CCredentialSchema {
+            type_: ANONCREDS_CREDENTIAL_SCHEMA_TYPE.to_string(),
+            definition: credential.cred_def_id,
+            schema: credential.schema_id,
+            revocation: credential.rev_reg_id,
+            timestamp: None,
+        },
+        credential_subject,
+        proof: W3CProof {
+            type_: ANONCREDS_CREDENTIAL_PROOF_TYPE.to_string(),
+            challenge: None,
+            proof_value: _encode_proof_value(&proof_value)?,
+        },
+    };
+
+    trace!("credential_to_w3c <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn credential_from_w3c(credential: W3CCredential) -> IndyResult<Credential> {
+    trace!("credential_from_w3c >>> credential: {:?}", secret!(&credential));
+
+    let mut proof_value: CredentialProofValue = _decode_proof_value(&credential.proof.proof_value)?;
+
+    let mut values: HashMap<String, AttributeValues> = HashMap::new();
+
+    for (name, value) in credential.credential_subject {
+        let raw = match value {
+            W3CAttributeValue::Value(raw) => raw,
+            W3CAttributeValue::Predicates(_) =>
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("W3C Credential attribute \"{}\" must contain raw value", name)))
+        };
+
+        let encoded = proof_value.encoded.remove(&name)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Encoded value not found for W3C Credential attribute \"{}\"", name)))?;
+
+        values.insert(name, AttributeValues { raw, encoded });
+    }
+
+    let res = Credential {
+        schema_id: credential.credential_schema.schema,
+        cred_def_id: credential.credential_schema.definition,
+        rev_reg_id: credential.credential_schema.revocation,
+        values: CredentialValues(values),
+        signature: proof_value.signature,
+        signature_correctness_proof: proof_value.signature_correctness_proof,
+        rev_reg: proof_value.rev_reg,
+        witness: proof_value.witness,
+    };
+
+    trace!("credential_from_w3c <<< res: {:?}", secret!(&res));
+
+    Ok(res)
+}
+
+pub fn proof_to_w3c_presentation(proof: Proof, proof_req: &ProofRequest) -> IndyResult<W3CPresentation> {
+    trace!("proof_to_w3c_presentation >>> proof: {:?}, proof_req: {:?}", proof, proof_req);
+
+    let proof_req = proof_req.value();
+
+    let mut crypto_proof = serde_json::to_value(&proof.proof)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+    let sub_proofs = match crypto_proof["proofs"].take() {
+        serde_json::Value::Array(sub_proofs) => sub_proofs,
+        _ => return Err(err_msg(IndyErrorKind::InvalidStructure, "Proof doesn't contain sub proofs"))
+    };
+
+    if sub_proofs.len() != proof.identifiers.len() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure,
+                           "Number of sub proofs doesn't correspond to number of identifiers"));
+    }
+
+    let mut subjects: Vec<HashMap<String, W3CAttributeValue>> = proof.identifiers.iter().map(|_| HashMap::new()).collect();
+
+    for (referent, info) in proof.requested_proof.revealed_attrs.iter() {
+        let name = proof_req.requested_attributes.get(referent)
+            .and_then(|attr_info| attr_info.name.clone())
+            .unwrap_or_else(|| referent.clone());
+
+        _get_subject(&mut subjects, info.sub_proof_index)?
+            .insert(name, W3CAttributeValue::Value(info.raw.clone()));
+    }
+
+    for info in proof.requested_proof.revealed_attr_groups.values() {
+        let subject = _get_subject(&mut subjects, info.sub_proof_index)?;
+        for (name, value) in info.values.iter() {
+            subject.insert(name.clone(), W3CAttributeValue::Value(value.raw.clone()));
+        }
+    }
+
+    for (referent, info) in proof.requested_proof.predicates.iter() {
+        let predicate_info = proof_req.requested_predicates.get(referent)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Predicate \"{}\" not found in Proof Request", referent)))?;
+
+        let predicate = W3CPredicate {
+            type_: ANONCREDS_PREDICATE_TYPE.to_string(),
+            predicate: predicate_info.p_type.clone(),
+            value: predicate_info.p_value,
+        };
+
+        let subject = _get_subject(&mut subjects, info.sub_proof_index)?;
+
+        match subject.entry(predicate_info.name.clone())
+            .or_insert_with(|| W3CAttributeValue::Predicates(Vec::new())) {
+            W3CAttributeValue::Predicates(ref mut predicates) => predicates.push(predicate),
+            W3CAttributeValue::Value(_) => {} // revealed value is more informative than predicate
+        }
+    }
+
+    let mut verifiable_credential: Vec<W3CCredential> = Vec::new();
+
+    for ((identifier, sub_proof), credential_subject) in proof.identifiers.into_iter().zip(sub_proofs).zip(subjects) {
+        let issuer = _issuer(&identifier.cred_def_id)?;
+
+        verifiable_credential.push(W3CCredential {
+            context: vec![W3C_CONTEXT.to_string(), ANONCREDS_CONTEXT.to_string()],
+            type_: vec![W3C_CREDENTIAL_TYPE.to_string(), ANONCREDS_CREDENTIAL_TYPE.to_string()],
+            issuer,
+            credential_schema: W3CCredentialSchema {
+                type_: ANONCREDS_CREDENTIAL_SCHEMA_TYPE.to_string(),
+                definition: identifier.cred_def_id,
+                schema: identifier.schema_id,
+                revocation: identifier.rev_reg_id,
+                timestamp: identifier.timestamp,
+            },
+            credential_subject,
+            proof: W3CProof {
+                type_: ANONCREDS_CREDENTIAL_PROOF_TYPE.to_string(),
+                challenge: None,
+                proof_value: _encode_proof_value(&sub_proof)?,
+            },
+        })
+    }
+
+    let proof_value = PresentationProofValue {
+        aggregated_proof: crypto_proof["aggregated_proof"].take(),
+        requested_proof: proof.requested_proof,
+    };
+
+    let res = W3CPresentation {
+        context: vec![W3C_CONTEXT.to_string(), ANONCREDS_CONTEXT.to_string()],
+        type_: vec![W3C_PRESENTATION_TYPE.to_string(), ANONCREDS_PRESENTATION_TYPE.to_string()],
+        verifiable_credential,
+        proof: W3CProof {
+            type_: ANONCREDS_PRESENTATION_PROOF_TYPE.to_string(),
+            challenge: Some(proof_req.nonce.to_dec()?),
+            proof_value: _encode_proof_value(&proof_value)?,
+        },
+    };
+
+    trace!("proof_to_w3c_presentation <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+pub fn proof_from_w3c_presentation(presentation: W3CPresentation, proof_req: &ProofRequest) -> IndyResult<Proof> {
+    trace!("proof_from_w3c_presentation >>> presentation: {:?}, proof_req: {:?}", presentation, proof_req);
+
+    let proof_value: PresentationProofValue = _decode_proof_value(&presentation.proof.proof_value)?;
+
+    let mut identifiers: Vec<Identifier> = Vec::new();
+    let mut sub_proofs: Vec<serde_json::Value> = Vec::new();
+    let mut subjects: Vec<HashMap<String, W3CAttributeValue>> = Vec::new();
+
+    for credential in presentation.verifiable_credential {
+        if credential.proof.type_ != ANONCREDS_CREDENTIAL_PROOF_TYPE {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("W3C Presentation contains credential with unsupported proof type {}", credential.proof.type_)));
+        }
+
+        sub_proofs.push(_decode_proof_value(&credential.proof.proof_value)?);
+        subjects.push(credential.credential_subject);
+
+        identifiers.push(Identifier {
+            schema_id: credential.credential_schema.schema,
+            cred_def_id: credential.credential_schema.definition,
+            rev_reg_id: credential.credential_schema.revocation,
+            timestamp: credential.credential_schema.timestamp,
+        });
+    }
+
+    _check_credential_subjects(&subjects, &proof_value.requested_proof, proof_req.value())?;
+
+    let crypto_proof = serde_json::from_value(json!({
+        "proofs": sub_proofs,
+        "aggregated_proof": proof_value.aggregated_proof,
+    }))
+        .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize Proof from W3C Presentation")?;
+
+    let res = Proof {
+        proof: crypto_proof,
+        requested_proof: proof_value.requested_proof,
+        identifiers,
+    };
+
+    trace!("proof_from_w3c_presentation <<< res: {:?}", res);
+
+    Ok(res)
+}
+
+fn _issuer(cred_def_id: &CredentialDefinitionId) -> IndyResult<String> {
+    let did = cred_def_id.issuer_did()
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Issuer DID can't be parsed from Credential Definition Id: {:?}", cred_def_id)))?;
+
+    let did = if did.is_fully_qualified() { did } else { did.qualify(DEFAULT_DID_METHOD) };
+
+    Ok(did.0)
+}
+
+// credentialSubject isn't covered by the anoncreds proof, so it must be exactly what requested_proof
+// (which is covered) states: every revealed value and predicate is present under its attribute name
+// and nothing is added.
+fn _check_credential_subjects(subjects: &[HashMap<String, W3CAttributeValue>],
+                              requested_proof: &RequestedProof,
+                              proof_req: &ProofRequestPayload) -> IndyResult<()> {
+    let mismatch = |sub_proof_index: usize, msg: String|
+        err_msg(IndyErrorKind::InvalidStructure,
+                format!("W3C Presentation credential {} doesn't match requested proof: {}", sub_proof_index, msg));
+
+    for (index, subject) in subjects.iter().enumerate() {
+        let mut revealed: Vec<(&str, &str)> = requested_proof.revealed_attrs.iter()
+            .filter(|(_, info)| info.sub_proof_index as usize == index)
+            .map(|(referent, info)| {
+                let name = proof_req.requested_attributes.get(referent)
+                    .and_then(|attr_info| attr_info.name.as_ref())
+                    .unwrap_or(referent);
+                (name.as_str(), info.raw.as_str())
+            })
+            .collect();
+
+        for info in requested_proof.revealed_attr_groups.values().filter(|info| info.sub_proof_index as usize == index) {
+            for (name, value) in info.values.iter() {
+                revealed.push((name.as_str(), value.raw.as_str()));
+            }
+        }
+
+        let proved_predicates: Vec<&str> = requested_proof.predicates.iter()
+            .filter(|(_, info)| info.sub_proof_index as usize == index)
+            .map(|(referent, _)| proof_req.requested_predicates.get(referent)
+                .map(|predicate_info| predicate_info.name.as_str())
+                .ok_or_else(|| mismatch(index, format!("predicate \"{}\" not found in Proof Request", referent))))
+            .collect::<IndyResult<Vec<&str>>>()?;
+
+        for (name, value) in subject.iter() {
+            match value {
+                W3CAttributeValue::Value(ref raw) if revealed.contains(&(name.as_str(), raw.as_str())) => {}
+                W3CAttributeValue::Value(_) =>
+                    return Err(mismatch(index, format!("attribute \"{}\" value isn't revealed", name))),
+                // a predicate over an attribute that is revealed as well is presented by the value only
+                W3CAttributeValue::Predicates(ref predicates) => {
+                    let proved = proved_predicates.iter().filter(|proved_name| **proved_name == name.as_str()).count();
+                    if predicates.len() > proved {
+                        return Err(mismatch(index, format!("{} predicates presented for attribute \"{}\", {} proved", predicates.len(), name, proved)));
+                    }
+                }
+            }
+        }
+
+        for (name, raw) in revealed {
+            match subject.get(name) {
+                Some(W3CAttributeValue::Value(ref value)) if value == raw => {}
+                _ => return Err(mismatch(index, format!("revealed attribute \"{}\" is missing or differs", name)))
+            }
+        }
+    }
+
+    let max_index = requested_proof.revealed_attrs.values().map(|info| info.sub_proof_index)
+        .chain(requested_proof.revealed_attr_groups.values().map(|info| info.sub_proof_index))
+        .chain(requested_proof.predicates.values().map(|info| info.sub_proof_index))
+        .max();
+
+    if let Some(index) = max_index {
+        if index as usize >= subjects.len() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Sub proof with index {} not found in W3C Presentation", index)));
+        }
+    }
+
+    Ok(())
+}
+
+fn _get_subject(subjects: &mut Vec<HashMap<String, W3CAttributeValue>>, sub_proof_index: u32) -> IndyResult<&mut HashMap<String, W3CAttributeValue>> {
+    subjects.get_mut(sub_proof_index as usize)
+        .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Sub proof with index {} not found in Proof", sub_proof_index)))
+}
+
+fn _encode_proof_value<T: Serialize>(value: &T) -> IndyResult<String> {
+    let json = serde_json::to_vec(value)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize proof value")?;
+
+    Ok(base64::encode(&json))
+}
+
+fn _decode_proof_value<T: DeserializeOwned>(proof_value: &str) -> IndyResult<T> {
+    let json = base64::decode(proof_value)?;
+
+    serde_json::from_slice(&json)
+        .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize proof value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use indy_api_types::validation::Validatable;
+
+    use crate::domain::anoncreds::proof::{RevealedAttributeInfo, SubProofReferent};
+
+    fn _identifier() -> Identifier {
+        Identifier {
+            schema_id: crate::domain::anoncreds::schema::SchemaId("NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0".to_string()),
+            cred_def_id: crate::domain::anoncreds::credential_definition::CredentialDefinitionId("NcYxiDXkpYi6ov5FcYDi1e:3:CL:NcYxiDXkpYi6ov5FcYDi1e:2:gvt:1.0:tag".to_string()),
+            rev_reg_id: None,
+            timestamp: None,
+        }
+    }
+
+    #[test]
+    fn encode_decode_proof_value_works() {
+        let value = json!({"a": 1, "b": ["c"]});
+        let decoded: serde_json::Value = _decode_proof_value(&_encode_proof_value(&value).unwrap()).unwrap();
+        assert_eq!(value, decoded);
+    }
+
+    #[test]
+    fn decode_proof_value_works_for_invalid_base64() {
+        let res: IndyResult<serde_json::Value> = _decode_proof_value("!!!");
+        assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn w3c_credential_validate_works_for_missed_type() {
+        let credential = W3CCredential {
+            context: vec![W3C_CONTEXT.to_string()],
+            type_: vec![ANONCREDS_CREDENTIAL_TYPE.to_string()],
+            issuer: "did:sov:NcYxiDXkpYi6ov5FcYDi1e".to_string(),
+            credential_schema: W3CCredentialSchema {
+                type_: ANONCREDS_CREDENTIAL_SCHEMA_TYPE.to_string(),
+                definition: _identifier().cred_def_id,
+                schema: _identifier().schema_id,
+                revocation: None,
+                timestamp: None,
+            },
+            credential_subject: HashMap::new(),
+            proof: W3CProof {
+                type_: ANONCREDS_CREDENTIAL_PROOF_TYPE.to_string(),
+                challenge: None,
+                proof_value: String::new(),
+            },
+        };
+
+        assert!(credential.validate().is_err());
+    }
+
+    #[test]
+    fn get_subject_works_for_unknown_sub_proof_index() {
+        let mut subjects = vec![HashMap::new()];
+        assert!(_get_subject(&mut subjects, 0).is_ok());
+        assert_eq!(IndyErrorKind::InvalidStructure, _get_subject(&mut subjects, 1).unwrap_err().kind());
+    }
+
+    #[test]
+    fn attribute_value_deserialization_works() {
+        let value: W3CAttributeValue = serde_json::from_str(r#""Alex""#).unwrap();
+        assert_eq!(W3CAttributeValue::Value("Alex".to_string()), value);
+
+        let value: W3CAttributeValue = serde_json::from_str(r#"[{"type":"AnonCredsPredicate","predicate":">=","value":18}]"#).unwrap();
+        match value {
+            W3CAttributeValue::Predicates(predicates) => assert_eq!(18, predicates[0].value),
+            _ => panic!("Predicates expected")
+        }
+    }
+
+    #[test]
+    fn requested_proof_round_trip_through_proof_value_works() {
+        let proof_value = PresentationProofValue {
+            aggregated_proof: json!({"c_hash": "1", "c_list": []}),
+            requested_proof: _requested_proof(),
+        };
+
+        let decoded: PresentationProofValue = _decode_proof_value(&_encode_proof_value(&proof_value).unwrap()).unwrap();
+        assert_eq!("Alex", decoded.requested_proof.revealed_attrs["attr1_referent"].raw);
+        assert_eq!(0, decoded.requested_proof.predicates["predicate1_referent"].sub_proof_index);
+        assert_eq!(proof_value.aggregated_proof, decoded.aggregated_proof);
+    }
+
+    fn _requested_proof() -> RequestedProof {
+        let mut revealed_attrs = HashMap::new();
+        revealed_attrs.insert("attr1_referent".to_string(), RevealedAttributeInfo { sub_proof_index: 0, raw: "Alex".to_string(), encoded: "1139481716457488690172217916278103335".to_string() });
+        let mut predicates = HashMap::new();
+        predicates.insert("predicate1_referent".to_string(), SubProofReferent { sub_proof_index: 0 });
+
+        RequestedProof {
+            revealed_attrs,
+            revealed_attr_groups: HashMap::new(),
+            self_attested_attrs: HashMap::new(),
+            unrevealed_attrs: HashMap::new(),
+            predicates,
+        }
+    }
+
+    fn _subject(name: W3CAttributeValue) -> HashMap<String, W3CAttributeValue> {
+        let mut subject = HashMap::new();
+        subject.insert("name".to_string(), name);
+        subject.insert("age".to_string(), W3CAttributeValue::Predicates(vec![W3CPredicate {
+            type_: ANONCREDS_PREDICATE_TYPE.to_string(),
+            predicate: ">=".to_string(),
+            value: 18,
+        }]));
+        subject
+    }
+
+    fn _proof_request() -> ProofRequest {
+        serde_json::from_value(json!({
+            "nonce": "123432421212",
+            "name": "proof_req_1",
+            "version": "0.1",
+            "requested_attributes": {
+                "attr1_referent": {"name": "name"}
+            },
+            "requested_predicates": {
+                "predicate1_referent": {"name": "age", "p_type": ">=", "p_value": 18}
+            }
+        })).unwrap()
+    }
+
+    fn _check(subjects: &[HashMap<String, W3CAttributeValue>], requested_proof: &RequestedProof) -> IndyResult<()> {
+        _check_credential_subjects(subjects, requested_proof, _proof_request().value())
+    }
+
+    #[test]
+    fn check_credential_subjects_works() {
+        let subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        _check(&subjects, &_requested_proof()).unwrap();
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_value_under_other_name() {
+        let mut subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        subjects[0].remove("name");
+        subjects[0].insert("nickname".to_string(), W3CAttributeValue::Value("Alex".to_string()));
+        let err = _check(&subjects, &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_predicate_under_other_name() {
+        let mut subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        let predicates = subjects[0].remove("age").unwrap();
+        subjects[0].insert("height".to_string(), predicates);
+        let err = _check(&subjects, &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_changed_value() {
+        let subjects = vec![_subject(W3CAttributeValue::Value("Bob".to_string()))];
+        let err = _check(&subjects, &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_added_value() {
+        let mut subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        subjects[0].insert("sex".to_string(), W3CAttributeValue::Value("male".to_string()));
+        let err = _check(&subjects, &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_missed_value() {
+        let mut subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        subjects[0].remove("name");
+        let err = _check(&subjects, &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_unproved_predicate() {
+        let mut requested_proof = _requested_proof();
+        requested_proof.predicates.clear();
+        let subjects = vec![_subject(W3CAttributeValue::Value("Alex".to_string()))];
+        let err = _check(&subjects, &requested_proof).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn check_credential_subjects_works_for_missed_credential() {
+        let err = _check(&[], &_requested_proof()).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+
+    #[test]
+    fn issuer_works() {
+        assert_eq!("did:sov:NcYxiDXkpYi6ov5FcYDi1e", _issuer(&_identifier().cred_def_id).unwrap());
+    }
+
+    #[test]
+    fn issuer_works_for_invalid_cred_def_id() {
+        let err = _issuer(&CredentialDefinitionId("invalid".to_string())).unwrap_err();
+        assert_eq!(IndyErrorKind::InvalidStructure, err.kind());
+    }
+}
@@ -4,13 +4,20 @@ use super::{ReadableBlob, Reader, ReaderType};
 use indy_api_types::errors::prelude::*;
 
 use serde_json;
+use std::cmp;
+use std::collections::BTreeMap;
 use std::fs::File;
 use std::io::{Read, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::channel;
 use indy_utils::crypto::hash::Hash;
 
+const VERIFY_CHUNK_SIZE: usize = 1024 * 1024;
+const VERIFY_CHUNKS_IN_FLIGHT: usize = 4;
+
 pub struct DefaultReader {
     file: File,
+    path: PathBuf,
     hash: Vec<u8>,
 }
 
@@ -32,9 +39,10 @@ impl Reader for DefaultReaderConfig {
     fn open(&self, hash: &[u8], _location: &str) -> IndyResult<Box<dyn ReadableBlob>> {
         let mut path = PathBuf::from(&self.base_dir);
         path.push(hash.to_base58());
-        let file = File::open(path)?;
+        let file = File::open(&path)?;
         Ok(Box::new(DefaultReader {
             file,
+            path,
             hash: hash.to_owned()
         }))
     }
@@ -42,20 +50,49 @@ impl Reader for DefaultReaderConfig {
 
 impl ReadableBlob for DefaultReader {
 
+    /// Chunks of the blob are read on the crypto thread pool in parallel
+    /// and are fed to the hasher in the original order as soon as all preceding chunks are ready.
     fn verify(&mut self) -> IndyResult<bool> {
-        self.file.seek(SeekFrom::Start(0))?;
+        let blob_size = self.file.metadata()?.len();
+        let chunks_count = ((blob_size + VERIFY_CHUNK_SIZE as u64 - 1) / VERIFY_CHUNK_SIZE as u64) as usize;
+
+        let (sender, receiver) = channel();
+
+        let read_chunk = |chunk_id: usize| {
+            let sender = sender.clone();
+            let path = self.path.clone();
+
+            crate::commands::THREADPOOL.lock().unwrap().execute(move || {
+                let _ = sender.send((chunk_id, _read_chunk(&path, chunk_id)));
+            });
+        };
+
+        // number of chunks kept in memory is limited by VERIFY_CHUNKS_IN_FLIGHT
+        let mut scheduled = cmp::min(chunks_count, VERIFY_CHUNKS_IN_FLIGHT);
+        (0..scheduled).for_each(&read_chunk);
+
         let mut hasher = Hash::new_context()?;
-        let mut buf = [0u8; 1024];
+        let mut pending = BTreeMap::new();
+        let mut next_chunk_id = 0;
 
-        loop {
-            let sz = self.file.read(&mut buf)?;
+        while next_chunk_id < chunks_count {
+            let (chunk_id, chunk) = receiver.recv()
+                .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Reading of blob has been interrupted"))?;
 
-            if sz == 0 {
-                return Ok(hasher.finish()?.to_vec().eq(&self.hash));
-            }
+            pending.insert(chunk_id, chunk?);
 
-            hasher.update(&buf[0..sz])?;
+            while let Some(chunk) = pending.remove(&next_chunk_id) {
+                hasher.update(&chunk)?;
+                next_chunk_id += 1;
+
+                if scheduled < chunks_count {
+                    read_chunk(scheduled);
+                    scheduled += 1;
+                }
+            }
         }
+
+        Ok(hasher.finish()?.to_vec().eq(&self.hash))
     }
 
     fn close(&self) -> IndyResult<()> {
@@ -75,6 +112,17 @@ impl ReadableBlob for DefaultReader {
     }
 }
 
+fn _read_chunk(path: &Path, chunk_id: usize) -> IndyResult<Vec<u8>> {
+    // each chunk uses own file as cloned handles share the cursor
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start((chunk_id * VERIFY_CHUNK_SIZE) as u64))?;
+
+    let mut chunk = Vec::with_capacity(VERIFY_CHUNK_SIZE);
+    file.take(VERIFY_CHUNK_SIZE as u64).read_to_end(&mut chunk)?;
+
+    Ok(chunk)
+}
+
 pub struct DefaultReaderType {}
 
 impl DefaultReaderType {
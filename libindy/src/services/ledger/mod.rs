@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use hex::FromHex;
 use ursa::cl::RevocationRegistryDelta as CryproRevocationRegistryDelta;
 use serde::de::DeserializeOwned;
@@ -11,10 +13,10 @@ use crate::domain::anoncreds::revocation_registry_definition::{RevocationRegistr
 use crate::domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use crate::domain::anoncreds::schema::{Schema, SchemaV1, SchemaId};
 use crate::domain::crypto::did::DidValue;
-use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use crate::domain::ledger::constants::{GET_VALIDATOR_INFO, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
+use crate::domain::ledger::attrib::{AttribOperation, GetAttribOperation, GetAttrReplyResult};
+use crate::domain::ledger::constants::{ATTRIB, AUTH_RULE, AUTH_RULES, CRED_DEF, NODE, NYM, REVOC_REG_DEF, REVOC_REG_ENTRY, SCHEMA, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, GET_VALIDATOR_INFO, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
 use crate::domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
-use crate::domain::ledger::ddo::GetDdoOperation;
+use crate::domain::ledger::ddo::*;
 use crate::domain::ledger::node::{NodeOperation, NodeOperationData};
 use crate::domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, NymData, NymOperation};
 use crate::domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation, Schedule};
@@ -23,13 +25,17 @@ use crate::domain::ledger::response::{Message, Reply, ReplyType};
 use crate::domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
 use crate::domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
 use crate::domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
-use crate::domain::ledger::txn::{GetTxnOperation, LedgerType};
-use crate::domain::ledger::validator_info::GetValidatorInfoOperation;
+use crate::domain::ledger::txn::{GetTxnOperation, GetTxnReplyResult, LedgerTxn, LedgerTxnData, LedgerTxnMetadata, LedgerType};
+use crate::domain::ledger::validator_info::{GetValidatorInfoOperation, GetValidatorInfoResult, NodeValidatorInfo};
 use crate::domain::ledger::auth_rule::*;
 use crate::domain::ledger::author_agreement::*;
 use crate::domain::ledger::ledgers_freeze::{LedgersFreezeOperation, GetFrozenLedgersOperation};
 use indy_api_types::errors::prelude::*;
+use indy_utils::crypto::ed25519_sign;
 use indy_utils::crypto::hash::hash as openssl_hash;
+use rust_base58::{FromBase58, ToBase58};
+
+use crate::utils::crypto::verkey_builder::{build_full_verkey, split_verkey};
 
 pub mod merkletree;
 
@@ -564,6 +570,249 @@ impl LedgerService {
 
         Ok(res)
     }
+
+    pub fn parse_get_validator_info_response(&self, response: &str) -> IndyResult<HashMap<String, NodeValidatorInfo>> {
+        trace!("parse_get_validator_info_response >>> response: {:?}", response);
+
+        let replies: HashMap<String, String> = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot parse GetValidatorInfo response: map of node replies expected")?;
+
+        let res = replies
+            .into_iter()
+            .map(|(node_alias, reply)| {
+                let info = match serde_json::from_str::<Message<GetValidatorInfoResult>>(&reply) {
+                    Ok(Message::Reply(reply)) => NodeValidatorInfo::ValidatorInfo(reply.result().data),
+                    Ok(Message::Reject(response)) | Ok(Message::ReqNACK(response)) => NodeValidatorInfo::Error(response.reason),
+                    Err(_) if reply == "timeout" => NodeValidatorInfo::Error(reply),
+                    Err(err) => NodeValidatorInfo::Error(format!("Invalid reply: {}", err)),
+                };
+                (node_alias, info)
+            })
+            .collect();
+
+        trace!("parse_get_validator_info_response <<< {:?}", res);
+
+        Ok(res)
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_txn_response(&self, get_txn_response: &str) -> IndyResult<LedgerTxn> {
+        let reply: Reply<GetTxnReplyResult> = LedgerService::parse_response(get_txn_response)?;
+
+        let data = match reply.result().data {
+            Some(Value::String(data)) => serde_json::from_str(&data)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Transaction is invalid json")?,
+            Some(Value::Null) | None => return Err(err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction not found")),
+            Some(data) => data
+        };
+
+        // Transactions of version 0 keep all the fields on the top level.
+        let (ver, txn_type, txn_data, metadata) = match data["ver"].as_str() {
+            Some(ver) => {
+                let txn = &data["txn"];
+                let metadata = LedgerTxnMetadata {
+                    seq_no: data["txnMetadata"]["seqNo"].as_u64(),
+                    txn_time: data["txnMetadata"]["txnTime"].as_u64(),
+                    txn_id: data["txnMetadata"]["txnId"].as_str().map(String::from),
+                    from: txn["metadata"]["from"].as_str().map(String::from),
+                    req_id: txn["metadata"]["reqId"].as_u64(),
+                    digest: txn["metadata"]["digest"].as_str().map(String::from),
+                    payload_digest: txn["metadata"]["payloadDigest"].as_str().map(String::from),
+                    endorser: txn["metadata"]["endorser"].as_str().map(String::from),
+                    taa_acceptance: serde_json::from_value(txn["metadata"]["taaAcceptance"].clone())
+                        .to_indy(IndyErrorKind::InvalidTransaction, "Invalid transaction author agreement acceptance")?,
+                };
+                (ver.to_string(), txn["type"].as_str(), txn["data"].clone(), metadata)
+            }
+            None => {
+                let metadata = LedgerTxnMetadata {
+                    seq_no: data["seqNo"].as_u64(),
+                    txn_time: data["txnTime"].as_u64(),
+                    from: data["identifier"].as_str().map(String::from),
+                    req_id: data["reqId"].as_u64(),
+                    digest: data["digest"].as_str().map(String::from),
+                    ..LedgerTxnMetadata::default()
+                };
+                ("0".to_string(), data["type"].as_str(), data.clone(), metadata)
+            }
+        };
+
+        let txn_type = txn_type
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "Transaction type not found"))?
+            .to_string();
+
+        let data = LedgerService::_parse_txn_data(&txn_type, txn_data)?;
+
+        Ok(LedgerTxn { ver, txn_type, data, metadata })
+    }
+
+    fn _parse_txn_data(txn_type: &str, txn_data: Value) -> IndyResult<LedgerTxnData> {
+        fn parse<T: DeserializeOwned>(data: Value) -> IndyResult<T> {
+            let data = match data {
+                Value::String(data) => serde_json::from_str(&data),
+                data => serde_json::from_value(data)
+            };
+            data.to_indy(IndyErrorKind::InvalidTransaction, "Transaction data doesn't correspond to transaction type")
+        }
+
+        let data = match txn_type {
+            NODE => LedgerTxnData::Node(parse(txn_data)?),
+            NYM => LedgerTxnData::Nym(parse(txn_data)?),
+            ATTRIB => LedgerTxnData::Attrib(parse(txn_data)?),
+            SCHEMA => LedgerTxnData::Schema(parse(txn_data["data"].clone())?),
+            CRED_DEF => LedgerTxnData::CredDef(parse(txn_data)?),
+            REVOC_REG_DEF => LedgerTxnData::RevocRegDef(parse(txn_data)?),
+            REVOC_REG_ENTRY => LedgerTxnData::RevocRegEntry(parse(txn_data)?),
+            AUTH_RULE => LedgerTxnData::AuthRule(parse(txn_data)?),
+            AUTH_RULES => LedgerTxnData::AuthRules(parse(txn_data)?),
+            TXN_AUTHR_AGRMT => LedgerTxnData::TxnAuthrAgrmt(parse(txn_data)?),
+            TXN_AUTHR_AGRMT_AML => LedgerTxnData::TxnAuthrAgrmtAml(parse(txn_data)?),
+            _ => LedgerTxnData::Custom(txn_data)
+        };
+
+        Ok(data)
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_attrib_response(&self, get_attrib_response: &str) -> IndyResult<Option<String>> {
+        let reply: Reply<GetAttrReplyResult> = match LedgerService::parse_response(get_attrib_response) {
+            Ok(reply) => reply,
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => return Ok(None),
+            Err(err) => return Err(err)
+        };
+
+        let raw = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => res.data,
+            GetAttrReplyResult::GetAttrReplyResultV1(res) => res.txn.data.raw
+        };
+
+        Ok(Some(raw))
+    }
+
+    pub fn build_did_document(&self, did: &DidValue, nym_data: &NymData,
+                              endpoint_attrib: Option<&str>, diddoc_content_attrib: Option<&str>) -> IndyResult<serde_json::Value> {
+        trace!("build_did_document >>> did: {:?}, nym_data: {:?}, endpoint_attrib: {:?}, diddoc_content_attrib: {:?}",
+               did, nym_data, endpoint_attrib, diddoc_content_attrib);
+
+        let id = if did.is_fully_qualified() { did.0.clone() } else { did.qualify(DEFAULT_DID_METHOD).0 };
+
+        let mut did_doc = DidDocument {
+            context: vec![DID_CORE_CONTEXT.to_string(), ED25519_2018_CONTEXT.to_string()],
+            id: id.clone(),
+            verification_method: Vec::new(),
+            authentication: Vec::new(),
+            assertion_method: Vec::new(),
+            key_agreement: Vec::new(),
+            service: Vec::new(),
+        };
+
+        // A NYM without verkey has been deactivated and has no keys to publish
+        if let Some(ref verkey) = nym_data.verkey {
+            let verkey = build_full_verkey(&nym_data.did.0, Some(verkey.as_str()))?;
+            let (verkey, _) = split_verkey(&verkey);
+
+            let key_id = format!("{}#key-1", id);
+
+            did_doc.verification_method.push(VerificationMethod {
+                id: key_id.clone(),
+                type_: "Ed25519VerificationKey2018".to_string(),
+                controller: id.clone(),
+                public_key_base58: verkey.to_string(),
+            });
+            did_doc.authentication.push(key_id.clone());
+            did_doc.assertion_method.push(key_id);
+
+            let verkey = ed25519_sign::PublicKey::from_slice(&verkey.from_base58()?)?;
+            let x25519_key = ed25519_sign::vk_to_curve25519(&verkey)?;
+
+            did_doc.context.push(X25519_2019_CONTEXT.to_string());
+            did_doc.key_agreement.push(VerificationMethod {
+                id: format!("{}#key-agreement-1", id),
+                type_: "X25519KeyAgreementKey2019".to_string(),
+                controller: id.clone(),
+                public_key_base58: x25519_key[..].to_base58(),
+            });
+        }
+
+        if let Some(endpoint_attrib) = endpoint_attrib {
+            let attrib: DidEndpointAttrib = serde_json::from_str(endpoint_attrib)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Cannot parse endpoint ATTRIB")?;
+
+            if let Some(service_endpoint) = attrib.endpoint.service_endpoint() {
+                let types = attrib.endpoint.types.clone()
+                    .unwrap_or_else(|| vec![ENDPOINT_SERVICE_TYPE.to_string(), DID_COMMUNICATION_SERVICE_TYPE.to_string()]);
+
+                if types.iter().any(|type_| type_ == ENDPOINT_SERVICE_TYPE) {
+                    did_doc.service.push(DidDocumentService {
+                        id: format!("{}#endpoint", id),
+                        type_: ENDPOINT_SERVICE_TYPE.to_string(),
+                        service_endpoint: service_endpoint.clone(),
+                        priority: None,
+                        recipient_keys: Vec::new(),
+                        routing_keys: Vec::new(),
+                        accept: Vec::new(),
+                    });
+                }
+
+                // DIDComm senders look recipient keys up among Ed25519 verification methods, X25519 keys are derived from them
+                if types.iter().any(|type_| type_ == DID_COMMUNICATION_SERVICE_TYPE) && !did_doc.verification_method.is_empty() {
+                    did_doc.service.push(DidDocumentService {
+                        id: format!("{}#did-communication", id),
+                        type_: DID_COMMUNICATION_SERVICE_TYPE.to_string(),
+                        service_endpoint,
+                        priority: Some(0),
+                        recipient_keys: did_doc.verification_method.iter().map(|key| key.id.clone()).collect(),
+                        routing_keys: attrib.endpoint.routing_keys.clone(),
+                        accept: vec![DID_COMMUNICATION_ACCEPT.to_string()],
+                    });
+                }
+            }
+        }
+
+        let mut did_doc = serde_json::to_value(&did_doc)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize DID Document")?;
+
+        if let Some(diddoc_content_attrib) = diddoc_content_attrib {
+            let attrib: serde_json::Value = serde_json::from_str(diddoc_content_attrib)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Cannot parse diddocContent ATTRIB")?;
+
+            LedgerService::_merge_did_document_content(&mut did_doc, &attrib[DIDDOC_CONTENT_ATTRIB])?;
+        }
+
+        trace!("build_did_document <<< did_doc: {:?}", did_doc);
+
+        Ok(did_doc)
+    }
+
+    fn _merge_did_document_content(did_doc: &mut serde_json::Value, content: &serde_json::Value) -> IndyResult<()> {
+        let content = content.as_object()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidTransaction, "diddocContent ATTRIB must be a JSON object"))?;
+
+        let did_doc = did_doc.as_object_mut()
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidState, "DID Document must be a JSON object"))?;
+
+        for (key, value) in content {
+            match (did_doc.get_mut(key), value) {
+                (None, value) => {
+                    did_doc.insert(key.clone(), value.clone());
+                }
+                (Some(serde_json::Value::Array(existing)), serde_json::Value::Array(values)) => {
+                    for value in values {
+                        if !existing.contains(value) {
+                            existing.push(value.clone());
+                        }
+                    }
+                }
+                (Some(existing), value) if *existing == *value => {}
+                (Some(_), _) => {
+                    return Err(err_msg(IndyErrorKind::InvalidTransaction,
+                                       format!("diddocContent ATTRIB conflicts with DID Document field: {}", key)));
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -587,6 +836,247 @@ mod tests {
         DidValue(DEST.to_string())
     }
 
+    mod parse_get_validator_info_response {
+        use super::*;
+
+        #[test]
+        fn parse_get_validator_info_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let reply = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_VALIDATOR_INFO,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "data": {
+                        "alias": "Node1",
+                        "did": "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv",
+                        "timestamp": 1579000000,
+                        "Node_info": {
+                            "Name": "Node1",
+                            "Mode": "participating",
+                            "Client_port": 9702,
+                            "Metrics": {"uptime": 3600, "transaction-count": {"ledger": 5, "pool": 4}},
+                            "Committed_ledger_root_hashes": {"0": "root0"},
+                            "Catchup_status": {"Ledger_statuses": {"0": "synced"}, "Number_txns_in_catchup": {"0": 0}}
+                        },
+                        "Pool_info": {
+                            "Read_only": false,
+                            "Total_nodes_count": 4,
+                            "f_value": 1,
+                            "Reachable_nodes": [["Node1", 0], ["Node2", null]],
+                            "Unreachable_nodes": [],
+                            "Blacklisted_nodes": []
+                        },
+                        "Software": {"indy-node": "1.12.1"}
+                    }
+                }
+            }).to_string();
+
+            let response = json!({
+                "Node1": reply,
+                "Node2": "timeout",
+                "Node3": json!({"op": "REQNACK", "reqId": 1, "reason": "not allowed"}).to_string(),
+            }).to_string();
+
+            let res = ledger_service.parse_get_validator_info_response(&response).unwrap();
+
+            match res["Node1"] {
+                NodeValidatorInfo::ValidatorInfo(ref info) => {
+                    assert_eq!("Node1", info.alias);
+                    let node_info = info.node_info.as_ref().unwrap();
+                    assert_eq!(Some("participating".to_string()), node_info.mode);
+                    assert_eq!(Some(3600), node_info.metrics.as_ref().unwrap().uptime);
+                    assert_eq!("synced", node_info.catchup_status.as_ref().unwrap().ledger_statuses["0"]);
+                    let pool_info = info.pool_info.as_ref().unwrap();
+                    assert_eq!(Some(1), pool_info.f_value);
+                    assert_eq!(("Node2".to_string(), None), pool_info.reachable_nodes[1]);
+                    assert_eq!(Some("1.12.1".to_string()), info.software.as_ref().unwrap().indy_node);
+                }
+                _ => panic!("validator info expected")
+            }
+            assert_eq!(NodeValidatorInfo::Error("timeout".to_string()), res["Node2"]);
+            assert_eq!(NodeValidatorInfo::Error("not allowed".to_string()), res["Node3"]);
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_invalid_reply() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"Node1": "{}"}).to_string();
+            let res = ledger_service.parse_get_validator_info_response(&response).unwrap();
+            assert_match!(NodeValidatorInfo::Error(_), res["Node1"]);
+        }
+
+        #[test]
+        fn parse_get_validator_info_response_works_for_invalid_response() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_validator_info_response("[]");
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod parse_get_txn_response {
+        use super::*;
+
+        use crate::domain::ledger::txn::{AttribTxnData, NymTxnData, SchemaTxnData};
+
+        fn _reply(data: serde_json::Value) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN,
+                    "identifier": IDENTIFIER,
+                    "reqId": 1,
+                    "seqNo": 10,
+                    "data": data
+                }
+            }).to_string()
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_nym() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({
+                "txn": {
+                    "type": NYM,
+                    "data": {"dest": DEST, "verkey": VERKEY, "role": ENDORSER},
+                    "metadata": {
+                        "from": IDENTIFIER,
+                        "reqId": 1,
+                        "digest": "digest",
+                        "payloadDigest": "payload_digest",
+                        "endorser": "V4SGRU86Z58d6TV7PBUe6f",
+                        "taaAcceptance": {"mechanism": "on_file", "taaDigest": "taa_digest", "time": 1579000000}
+                    },
+                    "protocolVersion": 2
+                },
+                "txnMetadata": {"seqNo": 10, "txnTime": 1579000001, "txnId": "txn_id"},
+                "reqSignature": {},
+                "ver": "1"
+            }));
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+
+            assert_eq!(LedgerTxn {
+                ver: "1".to_string(),
+                txn_type: NYM.to_string(),
+                data: LedgerTxnData::Nym(NymTxnData {
+                    dest: DEST.to_string(),
+                    verkey: Some(VERKEY.to_string()),
+                    role: Some(ENDORSER.to_string()),
+                    alias: None,
+                }),
+                metadata: LedgerTxnMetadata {
+                    seq_no: Some(10),
+                    txn_time: Some(1579000001),
+                    txn_id: Some("txn_id".to_string()),
+                    from: Some(IDENTIFIER.to_string()),
+                    req_id: Some(1),
+                    digest: Some("digest".to_string()),
+                    payload_digest: Some("payload_digest".to_string()),
+                    endorser: Some("V4SGRU86Z58d6TV7PBUe6f".to_string()),
+                    taa_acceptance: Some(TxnAuthrAgrmtAcceptanceData {
+                        mechanism: "on_file".to_string(),
+                        taa_digest: "taa_digest".to_string(),
+                        time: 1579000000,
+                    }),
+                },
+            }, txn);
+
+            let txn = serde_json::to_value(&txn).unwrap();
+            assert_eq!("NYM", txn["typeName"]);
+            assert_eq!(DEST, txn["data"]["dest"]);
+            assert_eq!(10, txn["metadata"]["seqNo"]);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_schema() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({
+                "txn": {
+                    "type": SCHEMA,
+                    "data": {"data": {"name": "gvt", "version": "1.0", "attr_names": ["name", "age"]}},
+                    "metadata": {"from": IDENTIFIER}
+                },
+                "txnMetadata": {"seqNo": 10},
+                "ver": "1"
+            }));
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+
+            assert_match!(LedgerTxnData::Schema(SchemaTxnData { ref name, .. }), txn.data, name, "gvt");
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_custom_type() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({
+                "txn": {
+                    "type": "10001",
+                    "data": {"outputs": [{"address": "pay:null:address", "amount": 10}]},
+                    "metadata": {}
+                },
+                "txnMetadata": {"seqNo": 10},
+                "ver": "1"
+            }));
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+
+            assert_eq!("10001", txn.txn_type);
+            assert_eq!(LedgerTxnData::Custom(json!({"outputs": [{"address": "pay:null:address", "amount": 10}]})), txn.data);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_txn_version_0() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({
+                "type": ATTRIB,
+                "dest": DEST,
+                "raw": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#,
+                "identifier": IDENTIFIER,
+                "reqId": 1,
+                "seqNo": 10,
+                "txnTime": 1579000001
+            }).to_string().into());
+
+            let txn = ledger_service.parse_get_txn_response(&response).unwrap();
+
+            assert_eq!("0", txn.ver);
+            assert_match!(LedgerTxnData::Attrib(AttribTxnData { ref dest, .. }), txn.data, dest, DEST);
+            assert_eq!(Some(10), txn.metadata.seq_no);
+            assert_eq!(Some(IDENTIFIER.to_string()), txn.metadata.from);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_get_txn_response(&_reply(serde_json::Value::Null));
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_data_not_corresponding_to_type() {
+            let ledger_service = LedgerService::new();
+
+            let response = _reply(json!({
+                "txn": {"type": SCHEMA, "data": {"data": {"name": "gvt"}}, "metadata": {}},
+                "txnMetadata": {"seqNo": 10},
+                "ver": "1"
+            }));
+
+            let res = ledger_service.parse_get_txn_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
     #[test]
     fn build_nym_request_works_for_only_required_fields() {
         let ledger_service = LedgerService::new();
@@ -1294,6 +1784,176 @@ mod tests {
         assert_eq!(1562284800, LedgerService::datetime_to_date_timestamp(1562284800));
     }
 
+    mod did_document {
+        use super::*;
+        use crate::domain::crypto::did::ShortDidValue;
+
+        fn _nym_data() -> NymData {
+            NymData {
+                did: dest().to_short(),
+                verkey: Some(VERKEY.to_string()),
+                role: None,
+            }
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "dest": DEST,
+                    "raw": "endpoint",
+                    "data": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#,
+                }
+            }).to_string();
+
+            let raw = ledger_service.parse_get_attrib_response(&response).unwrap();
+            assert_eq!(Some(r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#.to_string()), raw);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_ATTR,
+                    "identifier": IDENTIFIER,
+                    "dest": DEST,
+                    "raw": "endpoint",
+                    "data": null,
+                }
+            }).to_string();
+
+            let raw = ledger_service.parse_get_attrib_response(&response).unwrap();
+            assert_eq!(None, raw);
+        }
+
+        #[test]
+        fn build_did_document_works_for_nym_only() {
+            let ledger_service = LedgerService::new();
+
+            let did_doc = ledger_service.build_did_document(&dest(), &_nym_data(), None, None).unwrap();
+
+            let id = format!("did:sov:{}", DEST);
+            assert_eq!(json!(id), did_doc["id"]);
+            assert_eq!(json!(VERKEY), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!([format!("{}#key-1", id)]), did_doc["authentication"]);
+            assert_eq!(json!("X25519KeyAgreementKey2019"), did_doc["keyAgreement"][0]["type"]);
+            assert!(did_doc["service"].is_null());
+        }
+
+        #[test]
+        fn build_did_document_works_for_fully_qualified_did() {
+            let ledger_service = LedgerService::new();
+
+            let did = dest().qualify("sov");
+            let did_doc = ledger_service.build_did_document(&did, &_nym_data(), None, None).unwrap();
+
+            assert_eq!(json!(did.0), did_doc["id"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_abbreviated_verkey() {
+            let ledger_service = LedgerService::new();
+
+            let nym_data = NymData {
+                did: ShortDidValue("8wZcEriaNLNKtteJvx7f8i".to_string()),
+                verkey: Some("~NcYxiDXkpYi6ov5FcYDi1e".to_string()),
+                role: None,
+            };
+
+            let did_doc = ledger_service.build_did_document(&DidValue("8wZcEriaNLNKtteJvx7f8i".to_string()), &nym_data, None, None).unwrap();
+
+            assert_eq!(json!("5L2HBnzbu6Auh2pkDRbFt5f4prvgE2LzknkuYLsKkacp"), did_doc["verificationMethod"][0]["publicKeyBase58"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_deactivated_nym() {
+            let ledger_service = LedgerService::new();
+
+            let nym_data = NymData { verkey: None, .._nym_data() };
+
+            let did_doc = ledger_service.build_did_document(&dest(), &nym_data, None, None).unwrap();
+
+            assert_eq!(json!([]), did_doc["verificationMethod"]);
+            assert!(did_doc["keyAgreement"].is_null());
+        }
+
+        #[test]
+        fn build_did_document_works_for_legacy_endpoint() {
+            let ledger_service = LedgerService::new();
+
+            let endpoint = r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#;
+            let did_doc = ledger_service.build_did_document(&dest(), &_nym_data(), Some(endpoint), None).unwrap();
+
+            let services = did_doc["service"].as_array().unwrap();
+            assert_eq!(2, services.len());
+            assert_eq!(json!("endpoint"), services[0]["type"]);
+            assert_eq!(json!("127.0.0.1:5555"), services[0]["serviceEndpoint"]);
+            assert_eq!(json!("did-communication"), services[1]["type"]);
+            assert_eq!(json!([format!("did:sov:{}#key-1", DEST)]), services[1]["recipientKeys"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_endpoint_with_types() {
+            let ledger_service = LedgerService::new();
+
+            let endpoint = json!({
+                "endpoint": {
+                    "endpoint": "https://agent.example.com",
+                    "routingKeys": [VERKEY],
+                    "types": ["did-communication"]
+                }
+            }).to_string();
+
+            let did_doc = ledger_service.build_did_document(&dest(), &_nym_data(), Some(&endpoint), None).unwrap();
+
+            let services = did_doc["service"].as_array().unwrap();
+            assert_eq!(1, services.len());
+            assert_eq!(json!("https://agent.example.com"), services[0]["serviceEndpoint"]);
+            assert_eq!(json!([VERKEY]), services[0]["routingKeys"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_diddoc_content() {
+            let ledger_service = LedgerService::new();
+
+            let diddoc_content = json!({
+                "diddocContent": {
+                    "@context": ["https://identity.foundation/didcomm-messaging/service-endpoint/v1"],
+                    "service": [{"id": "did:sov:VsKV7grR1BUE29mG2Fm2kX#didcomm", "type": "DIDCommMessaging", "serviceEndpoint": "https://example.com"}],
+                    "alsoKnownAs": ["https://example.com"]
+                }
+            }).to_string();
+
+            let did_doc = ledger_service.build_did_document(&dest(), &_nym_data(), None, Some(&diddoc_content)).unwrap();
+
+            assert_eq!(4, did_doc["@context"].as_array().unwrap().len());
+            assert_eq!(json!("DIDCommMessaging"), did_doc["service"][0]["type"]);
+            assert_eq!(json!(["https://example.com"]), did_doc["alsoKnownAs"]);
+        }
+
+        #[test]
+        fn build_did_document_works_for_conflicting_diddoc_content() {
+            let ledger_service = LedgerService::new();
+
+            let diddoc_content = json!({
+                "diddocContent": {
+                    "id": "did:sov:other"
+                }
+            }).to_string();
+
+            let res = ledger_service.build_did_document(&dest(), &_nym_data(), None, Some(&diddoc_content));
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
     fn check_request(request: &str, expected_result: serde_json::Value) {
         let request: serde_json::Value = serde_json::from_str(request).unwrap();
         assert_eq!(request["operation"], expected_result);
@@ -67,9 +67,27 @@ impl From<&IssuerCommand> for CommandMetric {
             IssuerCommand::RevokeCredential(_, _, _, _, _) => {
                 CommandMetric::IssuerCommandRevokeCredential
             }
+            IssuerCommand::UpdateRevocationRegistry(_, _, _, _, _, _) => {
+                CommandMetric::IssuerCommandUpdateRevocationRegistry
+            }
             IssuerCommand::MergeRevocationRegistryDeltas(_, _, _) => {
                 CommandMetric::IssuerCommandMergeRevocationRegistryDeltas
             }
+            IssuerCommand::CreateRevocationRegistryPool(_, _, _, _, _, _) => {
+                CommandMetric::IssuerCommandCreateRevocationRegistryPool
+            }
+            IssuerCommand::CreateCredentialFromRevocationRegistryPool(_, _, _, _, _, _) => {
+                CommandMetric::IssuerCommandCreateCredentialFromRevocationRegistryPool
+            }
+            IssuerCommand::RolloverRevocationRegistryPool(_, _, _, _) => {
+                CommandMetric::IssuerCommandRolloverRevocationRegistryPool
+            }
+            IssuerCommand::GetRevocationRegistryPool(_, _, _) => {
+                CommandMetric::IssuerCommandGetRevocationRegistryPool
+            }
+            IssuerCommand::MarkRevocationRegistryPublished(_, _, _, _) => {
+                CommandMetric::IssuerCommandMarkRevocationRegistryPublished
+            }
         }
     }
 }
@@ -78,6 +96,10 @@ impl From<&ProverCommand> for CommandMetric {
     fn from(cmd: &ProverCommand) -> Self {
         match cmd {
             ProverCommand::CreateMasterSecret(_, _, _) => { CommandMetric::ProverCommandCreateMasterSecret }
+            ProverCommand::ExportMasterSecret(_, _, _, _) => { CommandMetric::ProverCommandExportMasterSecret }
+            ProverCommand::ImportMasterSecret(_, _, _, _, _) => { CommandMetric::ProverCommandImportMasterSecret }
+            ProverCommand::GetMasterSecrets(_, _) => { CommandMetric::ProverCommandGetMasterSecrets }
+            ProverCommand::RetireMasterSecret(_, _, _) => { CommandMetric::ProverCommandRetireMasterSecret }
             ProverCommand::CreateCredentialRequest(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateCredentialRequest }
             ProverCommand::SetCredentialAttrTagPolicy(_, _, _, _, _) => { CommandMetric::ProverCommandSetCredentialAttrTagPolicy }
             ProverCommand::GetCredentialAttrTagPolicy(_, _, _) => { CommandMetric::ProverCommandGetCredentialAttrTagPolicy }
@@ -93,8 +115,13 @@ impl From<&ProverCommand> for CommandMetric {
             ProverCommand::FetchCredentialForProofReq(_, _, _, _) => { CommandMetric::ProverCommandFetchCredentialForProofReq }
             ProverCommand::CloseCredentialsSearchForProofReq(_, _) => { CommandMetric::ProverCommandCloseCredentialsSearchForProofReq }
             ProverCommand::CreateProof(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProof }
+            ProverCommand::CreateProofAuto(_, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProofAuto }
+            ProverCommand::CreateProofWithRevocationCache(_, _, _, _, _, _, _, _, _, _) => { CommandMetric::ProverCommandCreateProofWithRevocationCache }
             ProverCommand::CreateRevocationState(_, _, _, _, _, _) => { CommandMetric::ProverCommandCreateRevocationState }
             ProverCommand::UpdateRevocationState(_, _, _, _, _, _, _) => { CommandMetric::ProverCommandUpdateRevocationState }
+            ProverCommand::RefreshRevocationStates(_, _, _, _) => { CommandMetric::ProverCommandRefreshRevocationStates }
+            ProverCommand::RefreshRevocationStatesContinue(_, _) => { CommandMetric::ProverCommandRefreshRevocationStatesContinue }
+            ProverCommand::PruneRevocationStates(_, _, _) => { CommandMetric::ProverCommandPruneRevocationStates }
         }
     }
 }
@@ -103,6 +130,9 @@ impl From<&VerifierCommand> for CommandMetric {
     fn from(cmd: &VerifierCommand) -> Self {
         match cmd {
             VerifierCommand::VerifyProof(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProof }
+            VerifierCommand::VerifyProofWithReport(_, _, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProofWithReport }
+            VerifierCommand::VerifyProofWithLedger(_, _, _, _, _, _) => { CommandMetric::VerifierCommandVerifyProofWithLedger }
+            VerifierCommand::VerifyProofWithLedgerContinue(_, _) => { CommandMetric::VerifierCommandVerifyProofWithLedgerContinue }
             VerifierCommand::GenerateNonce(_) => { CommandMetric::VerifierCommandGenerateNonce }
         }
     }
@@ -118,6 +148,13 @@ impl From<&Command> for CommandMetric {
                     AnoncredsCommand::Prover(cmd) => { cmd.into() }
                     AnoncredsCommand::Verifier(cmd) => { cmd.into() }
                     AnoncredsCommand::ToUnqualified(_, _) => { CommandMetric::AnoncredsCommandToUnqualified }
+                    AnoncredsCommand::CredentialToW3C(_, _) => { CommandMetric::AnoncredsCommandCredentialToW3C }
+                    AnoncredsCommand::CredentialFromW3C(_, _) => { CommandMetric::AnoncredsCommandCredentialFromW3C }
+                    AnoncredsCommand::ProofToW3CPresentation(_, _, _) => { CommandMetric::AnoncredsCommandProofToW3CPresentation }
+                    AnoncredsCommand::ProofFromW3CPresentation(_, _) => { CommandMetric::AnoncredsCommandProofFromW3CPresentation }
+                    AnoncredsCommand::EncodeCredentialValues(_, _) => { CommandMetric::AnoncredsCommandEncodeCredentialValues }
+                    AnoncredsCommand::DecodeCredentialValue(_, _, _) => { CommandMetric::AnoncredsCommandDecodeCredentialValue }
+                    AnoncredsCommand::BuildPredicateInfo(_, _, _, _, _) => { CommandMetric::AnoncredsCommandBuildPredicateInfo }
                 }
             }
             Command::BlobStorage(cmd) => {
@@ -145,6 +182,7 @@ impl From<&Command> for CommandMetric {
                 match cmd {
                     LedgerCommand::SignAndSubmitRequest(_, _, _, _, _) => { CommandMetric::LedgerCommandSignAndSubmitRequest }
                     LedgerCommand::SubmitRequest(_, _, _) => { CommandMetric::LedgerCommandSubmitRequest }
+                    LedgerCommand::SubmitRequestWithPolicy(_, _, _, _) => { CommandMetric::LedgerCommandSubmitRequestWithPolicy }
                     LedgerCommand::SubmitAck(_, _) => { CommandMetric::LedgerCommandSubmitAck }
                     LedgerCommand::SubmitAction(_, _, _, _, _) => { CommandMetric::LedgerCommandSubmitAction }
                     LedgerCommand::SignRequest(_, _, _, _) => { CommandMetric::LedgerCommandSignRequest }
@@ -163,7 +201,11 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::ParseGetCredDefResponse(_, _) => { CommandMetric::LedgerCommandParseGetCredDefResponse }
                     LedgerCommand::BuildNodeRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildNodeRequest }
                     LedgerCommand::BuildGetValidatorInfoRequest(_, _) => { CommandMetric::LedgerCommandBuildGetValidatorInfoRequest }
+                    LedgerCommand::ParseGetValidatorInfoResponse(_, _) => { CommandMetric::LedgerCommandParseGetValidatorInfoResponse }
                     LedgerCommand::BuildGetTxnRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildGetTxnRequest }
+                    LedgerCommand::ParseGetTxnResponse(_, _) => { CommandMetric::LedgerCommandParseGetTxnResponse }
+                    LedgerCommand::ExtractTxnInclusionProof(_, _) => { CommandMetric::LedgerCommandExtractTxnInclusionProof }
+                    LedgerCommand::VerifyTxnInclusionProof(_, _, _) => { CommandMetric::LedgerCommandVerifyTxnInclusionProof }
                     LedgerCommand::BuildPoolConfigRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildPoolConfigRequest }
                     LedgerCommand::BuildPoolRestartRequest(_, _, _, _) => { CommandMetric::LedgerCommandBuildPoolRestartRequest }
                     LedgerCommand::BuildPoolUpgradeRequest(_, _, _, _, _, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildPoolUpgradeRequest }
@@ -180,10 +222,19 @@ impl From<&Command> for CommandMetric {
                     LedgerCommand::BuildAuthRuleRequest(_, _, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildAuthRuleRequest }
                     LedgerCommand::BuildAuthRulesRequest(_, _, _) => { CommandMetric::LedgerCommandBuildAuthRulesRequest }
                     LedgerCommand::BuildGetAuthRuleRequest(_, _, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildGetAuthRuleRequest }
+                    LedgerCommand::GetDidDocument(_, _, _) => { CommandMetric::LedgerCommandGetDidDocument }
+                    LedgerCommand::GetDidDocumentContinue(_, _) => { CommandMetric::LedgerCommandGetDidDocumentContinue }
+                    LedgerCommand::RegistryPoolResolved(_, _) => { CommandMetric::LedgerCommandRegistryPoolResolved }
                     LedgerCommand::GetSchema(_, _, _, _) => { CommandMetric::LedgerCommandGetSchema }
                     LedgerCommand::GetSchemaContinue(_, _, _) => { CommandMetric::LedgerCommandGetSchemaContinue }
                     LedgerCommand::GetCredDef(_, _, _, _) => { CommandMetric::LedgerCommandGetCredDef }
                     LedgerCommand::GetCredDefContinue(_, _, _) => { CommandMetric::LedgerCommandGetCredDefContinue }
+                    LedgerCommand::GetRevocRegDef(_, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDef }
+                    LedgerCommand::GetRevocRegDefContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegDefContinue }
+                    LedgerCommand::GetRevocReg(_, _, _, _, _) => { CommandMetric::LedgerCommandGetRevocReg }
+                    LedgerCommand::GetRevocRegContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegContinue }
+                    LedgerCommand::GetRevocRegDelta(_, _, _, _, _, _) => { CommandMetric::LedgerCommandGetRevocRegDelta }
+                    LedgerCommand::GetRevocRegDeltaContinue(_, _) => { CommandMetric::LedgerCommandGetRevocRegDeltaContinue }
                     LedgerCommand::BuildTxnAuthorAgreementRequest(_, _, _, _, _, _) => { CommandMetric::LedgerCommandBuildTxnAuthorAgreementRequest }
                     LedgerCommand::BuildDisableAllTxnAuthorAgreementsRequest(_, _) => { CommandMetric::LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest }
                     LedgerCommand::BuildGetTxnAuthorAgreementRequest(_, _, _) => { CommandMetric::LedgerCommandBuildGetTxnAuthorAgreementRequest }
@@ -202,11 +253,15 @@ impl From<&Command> for CommandMetric {
                     PoolCommand::Open(_, _, _) => { CommandMetric::PoolCommandOpen }
                     PoolCommand::OpenAck(_, _, _) => { CommandMetric::PoolCommandOpenAck }
                     PoolCommand::List(_) => { CommandMetric::PoolCommandList }
+                    PoolCommand::GetStatus(_, _) => { CommandMetric::PoolCommandGetStatus }
                     PoolCommand::Close(_, _) => { CommandMetric::PoolCommandClose }
                     PoolCommand::CloseAck(_, _) => { CommandMetric::PoolCommandCloseAck }
                     PoolCommand::Refresh(_, _) => { CommandMetric::PoolCommandRefresh }
                     PoolCommand::RefreshAck(_, _) => { CommandMetric::PoolCommandRefreshAck }
                     PoolCommand::SetProtocolVersion(_, _) => { CommandMetric::PoolCommandSetProtocolVersion }
+                    PoolCommand::OpenRegistry(_, _) => { CommandMetric::PoolCommandOpenRegistry }
+                    PoolCommand::ResolveRegistryPool(_, _, _) => { CommandMetric::PoolCommandResolveRegistryPool }
+                    PoolCommand::RegistryPoolOpened(_, _, _) => { CommandMetric::PoolCommandRegistryPoolOpened }
                 }
             }
             Command::Did(cmd) => {
@@ -314,6 +369,8 @@ impl From<&Command> for CommandMetric {
                     CacheCommand::GetSchemaContinue(_, _, _, _) => { CommandMetric::CacheCommandGetSchemaContinue }
                     CacheCommand::GetCredDef(_, _, _, _, _, _) => { CommandMetric::CacheCommandGetCredDef }
                     CacheCommand::GetCredDefContinue(_, _, _, _) => { CommandMetric::CacheCommandGetCredDefContinue }
+                    CacheCommand::ResolveDid(_, _, _, _, _) => { CommandMetric::CacheCommandResolveDid }
+                    CacheCommand::ResolveDidContinue(_, _, _, _, _) => { CommandMetric::CacheCommandResolveDidContinue }
                     CacheCommand::PurgeSchemaCache(_, _, _) => { CommandMetric::CacheCommandPurgeSchemaCache }
                     CacheCommand::PurgeCredDefCache(_, _, _) => { CommandMetric::CacheCommandPurgeCredDefCache }
                 }
@@ -340,9 +397,19 @@ pub enum CommandMetric {
     IssuerCommandCreateCredentialOffer,
     IssuerCommandCreateCredential,
     IssuerCommandRevokeCredential,
+    IssuerCommandUpdateRevocationRegistry,
     IssuerCommandMergeRevocationRegistryDeltas,
+    IssuerCommandCreateRevocationRegistryPool,
+    IssuerCommandCreateCredentialFromRevocationRegistryPool,
+    IssuerCommandRolloverRevocationRegistryPool,
+    IssuerCommandGetRevocationRegistryPool,
+    IssuerCommandMarkRevocationRegistryPublished,
     // ProverCommand
     ProverCommandCreateMasterSecret,
+    ProverCommandExportMasterSecret,
+    ProverCommandImportMasterSecret,
+    ProverCommandGetMasterSecrets,
+    ProverCommandRetireMasterSecret,
     ProverCommandCreateCredentialRequest,
     ProverCommandSetCredentialAttrTagPolicy,
     ProverCommandGetCredentialAttrTagPolicy,
@@ -358,13 +425,28 @@ pub enum CommandMetric {
     ProverCommandFetchCredentialForProofReq,
     ProverCommandCloseCredentialsSearchForProofReq,
     ProverCommandCreateProof,
+    ProverCommandCreateProofAuto,
+    ProverCommandCreateProofWithRevocationCache,
     ProverCommandCreateRevocationState,
     ProverCommandUpdateRevocationState,
+    ProverCommandRefreshRevocationStates,
+    ProverCommandRefreshRevocationStatesContinue,
+    ProverCommandPruneRevocationStates,
     // VerifierCommand
     VerifierCommandVerifyProof,
+    VerifierCommandVerifyProofWithReport,
+    VerifierCommandVerifyProofWithLedger,
+    VerifierCommandVerifyProofWithLedgerContinue,
     VerifierCommandGenerateNonce,
     // AnoncredsCommand
     AnoncredsCommandToUnqualified,
+    AnoncredsCommandCredentialToW3C,
+    AnoncredsCommandCredentialFromW3C,
+    AnoncredsCommandProofToW3CPresentation,
+    AnoncredsCommandProofFromW3CPresentation,
+    AnoncredsCommandEncodeCredentialValues,
+    AnoncredsCommandDecodeCredentialValue,
+    AnoncredsCommandBuildPredicateInfo,
     // BlobStorage
     BlobStorageCommandOpenReader,
     BlobStorageCommandOpenWriter,
@@ -383,6 +465,7 @@ pub enum CommandMetric {
     LedgerCommandSignAndSubmitRequest,
     // LedgerCommand
     LedgerCommandSubmitRequest,
+    LedgerCommandSubmitRequestWithPolicy,
     LedgerCommandSubmitAck,
     LedgerCommandSubmitAction,
     LedgerCommandSignRequest,
@@ -401,7 +484,11 @@ pub enum CommandMetric {
     LedgerCommandParseGetCredDefResponse,
     LedgerCommandBuildNodeRequest,
     LedgerCommandBuildGetValidatorInfoRequest,
+    LedgerCommandParseGetValidatorInfoResponse,
     LedgerCommandBuildGetTxnRequest,
+    LedgerCommandParseGetTxnResponse,
+    LedgerCommandExtractTxnInclusionProof,
+    LedgerCommandVerifyTxnInclusionProof,
     LedgerCommandBuildPoolConfigRequest,
     LedgerCommandBuildPoolRestartRequest,
     LedgerCommandBuildPoolUpgradeRequest,
@@ -418,10 +505,19 @@ pub enum CommandMetric {
     LedgerCommandBuildAuthRuleRequest,
     LedgerCommandBuildAuthRulesRequest,
     LedgerCommandBuildGetAuthRuleRequest,
+    LedgerCommandGetDidDocument,
+    LedgerCommandGetDidDocumentContinue,
+    LedgerCommandRegistryPoolResolved,
     LedgerCommandGetSchema,
     LedgerCommandGetSchemaContinue,
     LedgerCommandGetCredDef,
     LedgerCommandGetCredDefContinue,
+    LedgerCommandGetRevocRegDef,
+    LedgerCommandGetRevocRegDefContinue,
+    LedgerCommandGetRevocReg,
+    LedgerCommandGetRevocRegContinue,
+    LedgerCommandGetRevocRegDelta,
+    LedgerCommandGetRevocRegDeltaContinue,
     LedgerCommandBuildTxnAuthorAgreementRequest,
     LedgerCommandBuildDisableAllTxnAuthorAgreementsRequest,
     LedgerCommandBuildGetTxnAuthorAgreementRequest,
@@ -437,11 +533,15 @@ pub enum CommandMetric {
     PoolCommandOpen,
     PoolCommandOpenAck,
     PoolCommandList,
+    PoolCommandGetStatus,
     PoolCommandClose,
     PoolCommandCloseAck,
     PoolCommandRefresh,
     PoolCommandRefreshAck,
     PoolCommandSetProtocolVersion,
+    PoolCommandOpenRegistry,
+    PoolCommandResolveRegistryPool,
+    PoolCommandRegistryPoolOpened,
     // DidCommand
     DidCommandCreateAndStoreMyDid,
     DidCommandReplaceKeysStart,
@@ -531,6 +631,8 @@ pub enum CommandMetric {
     CacheCommandGetSchemaContinue,
     CacheCommandGetCredDef,
     CacheCommandGetCredDefContinue,
+    CacheCommandResolveDid,
+    CacheCommandResolveDidContinue,
     CacheCommandPurgeSchemaCache,
     CacheCommandPurgeCredDefCache,
     // MetricsCommand
@@ -1,4 +1,5 @@
 use indy_api_types::errors::prelude::*;
+use crate::domain::pool::ReadPolicy;
 use crate::services::pool::events::PoolEvent;
 
 use super::zmq;
@@ -43,7 +44,8 @@ impl Commander {
             let timeout = LittleEndian::read_i32(cmd_parts[2].as_slice());
             let timeout = if timeout == -1 { None } else { Some(timeout) };
 
-            let nodes = if let Some(nodes) = cmd_parts.get(3) {
+            // empty nodes part is sent if only read policy is specified
+            let nodes = if let Some(nodes) = cmd_parts.get(3).filter(|nodes| !nodes.is_empty()) {
                 Some(String::from_utf8(nodes.clone())
                     .to_indy(IndyErrorKind::InvalidState, "Invalid utf8 sequence in command") // FIXME: review kind
                     .map_err(map_err_trace!()).ok()?)
@@ -51,7 +53,15 @@ impl Commander {
                 None
             };
 
-            Some(PoolEvent::SendRequest(id, cmd_s, timeout, nodes))
+            let read_policy = if let Some(read_policy) = cmd_parts.get(4) {
+                Some(serde_json::from_slice::<ReadPolicy>(read_policy)
+                    .to_indy(IndyErrorKind::InvalidState, "Invalid read policy in command")
+                    .map_err(map_err_trace!()).ok()?)
+            } else {
+                None
+            };
+
+            Some(PoolEvent::SendRequest(id, cmd_s, timeout, nodes, read_policy))
         }
     }
 
@@ -151,9 +161,29 @@ mod commander_tests {
         LittleEndian::write_i32(&mut buf_to, -1);
         let msg = "test";
         send_cmd_sock.send_multipart(&[msg.as_bytes(), &buf, &buf_to], zmq::DONTWAIT).expect("FIXME");
-        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, msg_, None, None)), cmd.fetch_events(),
+        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, msg_, None, None, None)), cmd.fetch_events(),
                       cmd_id_, cmd_id,
                       msg_, msg);
     }
 
+    #[test]
+    pub fn commander_fetch_send_request_event_works_for_read_policy() {
+        let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets("send_request_with_read_policy");
+
+        let cmd = Commander::new(recv_cmd_sock);
+
+        let cmd_id: CommandHandle = next_command_handle();
+        let mut buf = [0u8; 4];
+        LittleEndian::write_i32(&mut buf, cmd_id);
+        let mut buf_to = [0u8; 4];
+        LittleEndian::write_i32(&mut buf_to, -1);
+        let msg = "test";
+        let read_policy = ReadPolicy { retries: 2, hedge_after: Some(300), ..ReadPolicy::default() };
+        let read_policy_json = serde_json::to_string(&read_policy).unwrap();
+        send_cmd_sock.send_multipart(&[msg.as_bytes(), &buf, &buf_to, &[], read_policy_json.as_bytes()], zmq::DONTWAIT).expect("FIXME");
+        assert_match!(Some(PoolEvent::SendRequest(cmd_id_, _, None, None, Some(read_policy_))), cmd.fetch_events(),
+                      cmd_id_, cmd_id,
+                      read_policy_, read_policy);
+    }
+
 }
\ No newline at end of file
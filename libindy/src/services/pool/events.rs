@@ -2,6 +2,7 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use crate::domain::ledger::constants;
+use crate::domain::pool::ReadPolicy;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::{PoolService, types:: *};
@@ -66,9 +67,26 @@ pub enum NetworkerEvent {
         String, //req_id
         Option<String>, //node_alias
     ),
+    Schedule(
+        String, //req_id
+        String, //timer
+        i64, //delay in ms
+    ),
+    NodeReplied(
+        String, //req_id
+        String, //node_alias
+    ),
+    NodeFailed(
+        String, //req_id
+        String, //node_alias
+    ),
     Timeout,
 }
 
+/// Timers scheduled for request are reported as timeouts of these pseudo nodes
+pub const HEDGE_TIMER: &str = "#hedge";
+pub const RETRY_TIMER: &str = "#retry";
+
 pub const COMMAND_EXIT : &str = "exit";
 pub const COMMAND_CONNECT : &str = "connect";
 pub const COMMAND_REFRESH : &str = "refresh";
@@ -107,6 +125,7 @@ pub enum PoolEvent {
         String, // request
         Option<i32>, // timeout
         Option<String>, // node list
+        Option<ReadPolicy>, // read policy
     ),
     Timeout(
         String, //req_id
@@ -138,7 +157,8 @@ pub enum RequestEvent {
         String, // message
         String, // req_id
         Option<Vec<u8>>, // expected key for State Proof in Reply,
-        (Option<u64>, Option<u64>) // expected timestamps for freshness comparison
+        (Option<u64>, Option<u64>), // expected timestamps for freshness comparison
+        ReadPolicy,
     ),
     CustomConsensusRequest(
         String, // message
@@ -187,7 +207,7 @@ pub enum RequestEvent {
 impl RequestEvent {
     pub fn get_req_id(&self) -> String {
         match *self {
-            RequestEvent::CustomSingleRequest(_, ref id, _, _) => id.to_string(),
+            RequestEvent::CustomSingleRequest(_, ref id, _, _, _) => id.to_string(),
             RequestEvent::CustomConsensusRequest(_, ref id) => id.to_string(),
             RequestEvent::CustomFullRequest(_, ref id, _, _) => id.to_string(),
             RequestEvent::Reply(_, _, _, ref id) => id.to_string(),
@@ -232,7 +252,7 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         Message::Pong => RequestEvent::Pong,
                     })
             }
-            PoolEvent::SendRequest(_, msg, timeout, nodes) => {
+            PoolEvent::SendRequest(_, msg, timeout, nodes, read_policy) => {
                 let parsed_req = _parse_req_id_and_op(&msg);
                 if let Ok((ref req, ref req_id, ref op)) = parsed_req {
                     if REQUEST_FOR_FULL.contains(&op.as_str()) {
@@ -244,9 +264,9 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                     } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str()) {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
                         let timestamps = _parse_timestamp_from_req_for_builtin_sp(req, &op);
-                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps))
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps, read_policy.unwrap_or_default()))
                     } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
-                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
+                        Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None), read_policy.unwrap_or_default()))
                     } else {
                         Some(RequestEvent::CustomConsensusRequest(msg, req_id.clone()))
                     }
@@ -10,7 +10,7 @@ use self::zmq::Socket;
 
 use std::{fs, io};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::sync::Mutex;
 
@@ -19,20 +19,24 @@ use serde::de::DeserializeOwned;
 
 use crate::api::ledger::{CustomFree, CustomTransactionParser};
 use crate::domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{PoolConfig, PoolLedgerInfo, PoolNodeHealth, PoolNodeStatus, PoolOpenConfig, PoolRegistryConfig, PoolRegistryNetwork, PoolStatus, ReadPolicy},
     ledger::response::{
         Message,
         Reply,
         ResponseMetadata
-    }
+    },
+    ledger::txn::TxnInclusionProof
 };
 use indy_api_types::errors::*;
 use crate::services::pool::pool::{Pool, ZMQPool};
 use crate::utils::environment;
+use crate::utils::qualifier;
 use crate::services::pool::events::{COMMAND_EXIT, COMMAND_CONNECT, COMMAND_REFRESH};
+use crate::services::pool::request_handler::DEFAULT_GENERATOR;
 use indy_api_types::{CommandHandle, PoolHandle};
 use indy_utils::{next_command_handle, next_pool_handle};
-use ursa::bls::VerKey;
+use ursa::bls::{Generator, VerKey};
+use rust_base58::{FromBase58, ToBase58};
 
 mod catchup;
 mod commander;
@@ -44,6 +48,8 @@ mod request_handler;
 mod state_proof;
 mod types;
 
+pub use self::networker::NodeHealth;
+
 lazy_static! {
     static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new());
 }
@@ -53,6 +59,12 @@ type Nodes = HashMap<String, Option<VerKey>>;
 pub struct PoolService {
     open_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
     pending_pools: RefCell<HashMap<PoolHandle, ZMQPool>>,
+    registries: RefCell<HashMap<PoolHandle, PoolRegistry>>,
+}
+
+struct PoolRegistry {
+    config: PoolRegistryConfig,
+    open_pools: HashMap<String, PoolHandle>,
 }
 
 impl PoolService {
@@ -60,6 +72,7 @@ impl PoolService {
         PoolService {
             open_pools: RefCell::new(HashMap::new()),
             pending_pools: RefCell::new(HashMap::new()),
+            registries: RefCell::new(HashMap::new()),
         }
     }
 
@@ -159,7 +172,7 @@ impl PoolService {
         let (send_cmd_sock, recv_cmd_sock) = pool_create_pair_of_sockets(&format!("pool_{}", name));
 
         new_pool.work(recv_cmd_sock);
-        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None)?;
+        self._send_msg(pool_handle, COMMAND_CONNECT, &send_cmd_sock, None, None, None)?;
 
         self.pending_pools.try_borrow_mut()?
             .insert(new_pool.get_id(), ZMQPool::new(new_pool, send_cmd_sock));
@@ -177,8 +190,90 @@ impl PoolService {
     }
 
 
+    pub fn open_registry(&self, config: PoolRegistryConfig) -> IndyResult<PoolHandle> {
+        for network in config.networks.values() {
+            if !environment::pool_path(&network.pool_name).exists() {
+                return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool ledger config with name \"{}\" doesn't exist", network.pool_name)));
+            }
+        }
+
+        let registry_handle: PoolHandle = next_pool_handle();
+
+        self.registries.try_borrow_mut()?
+            .insert(registry_handle, PoolRegistry { config, open_pools: HashMap::new() });
+
+        Ok(registry_handle)
+    }
+
+    pub fn is_registry(&self, handle: PoolHandle) -> bool {
+        self.registries.try_borrow()
+            .map(|registries| registries.contains_key(&handle))
+            .unwrap_or(false)
+    }
+
+    /// Finds network of the registry which serves DID method namespace of `identifier`.
+    /// Unqualified identifiers are served by the default network.
+    /// Returns namespace, network configuration and handle of the pool if it's already opened.
+    pub fn resolve_registry_network(&self, handle: PoolHandle, identifier: Option<&str>) -> IndyResult<(String, PoolRegistryNetwork, Option<PoolHandle>)> {
+        let registries = self.registries.try_borrow()?;
+
+        let registry = registries.get(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool registry with requested handle {:?}", handle)))?;
+
+        let namespace = identifier.and_then(qualifier::did_namespace)
+            .or_else(|| registry.config.default.clone())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("Identifier {:?} isn't qualified and default network isn't configured", identifier)))?;
+
+        let network = registry.config.networks.get(&namespace)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, format!("No network configured for DID method namespace {}", namespace)))?;
+
+        // pool can be closed by handle without the registry knowing about it
+        let pool_handle = registry.open_pools.get(&namespace)
+            .cloned()
+            .filter(|pool_handle| self.open_pools.try_borrow().map(|pools| pools.contains_key(pool_handle)).unwrap_or(false));
+
+        Ok((namespace, network.clone(), pool_handle))
+    }
+
+    pub fn add_registry_pool(&self, handle: PoolHandle, namespace: &str, pool_handle: PoolHandle) -> IndyResult<()> {
+        self.registries.try_borrow_mut()?
+            .get_mut(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool registry with requested handle {:?}", handle)))?
+            .open_pools.insert(namespace.to_string(), pool_handle);
+
+        Ok(())
+    }
+
+    /// Forgets the registry and returns handles of pools opened through it.
+    pub fn remove_registry(&self, handle: PoolHandle) -> IndyResult<Vec<PoolHandle>> {
+        let registry = self.registries.try_borrow_mut()?
+            .remove(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool registry with requested handle {:?}", handle)))?;
+
+        let open_pools = self.open_pools.try_borrow()?;
+
+        Ok(registry.open_pools.values()
+            .filter(|pool_handle| open_pools.contains_key(pool_handle))
+            .cloned()
+            .collect())
+    }
+
     pub fn send_tx(&self, handle: PoolHandle, msg: &str) -> IndyResult<CommandHandle> {
-        self.send_action(handle, msg, None, None)
+        self.send_tx_with_policy(handle, msg, None)
+    }
+
+    /// Sends request using `read_policy` or default read policy of the pool if it isn't specified.
+    pub fn send_tx_with_policy(&self, handle: PoolHandle, msg: &str, read_policy: Option<&ReadPolicy>) -> IndyResult<CommandHandle> {
+        let pools = self.open_pools.try_borrow()?;
+
+        if let Some(ref pool) = pools.get(&handle) {
+            let cmd_id: CommandHandle = next_command_handle();
+            let read_policy = read_policy.unwrap_or_else(|| pool.pool.get_read_policy());
+            self._send_msg(cmd_id, msg, &pool.cmd_socket, None, None, Some(read_policy))?;
+            Ok(cmd_id)
+        } else {
+            Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
+        }
     }
 
     pub fn send_action(&self, handle: PoolHandle, msg: &str, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<CommandHandle> {
@@ -186,7 +281,7 @@ impl PoolService {
 
         if let Some(ref pool) = pools.get(&handle) {
             let cmd_id: CommandHandle = next_command_handle();
-            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout)?;
+            self._send_msg(cmd_id, msg, &pool.cmd_socket, nodes, timeout, None)?;
             Ok(cmd_id)
         } else {
             Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))
@@ -220,7 +315,10 @@ impl PoolService {
         let mut pools = self.open_pools.try_borrow_mut()?;
 
         match pools.remove(&handle) {
-            Some(ref pool) => self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None)?,
+            Some(ref pool) => {
+                self._send_msg(cmd_id, COMMAND_EXIT, &pool.cmd_socket, None, None, None)?;
+                POOLS_RUNTIME.lock().unwrap().remove(pool.pool.get_name());
+            }
             None => return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
         }
 
@@ -231,19 +329,74 @@ impl PoolService {
         self.send_action(handle, COMMAND_REFRESH, None, None)
     }
 
-    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<()> {
+    fn _send_msg(&self, cmd_id: CommandHandle, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>, read_policy: Option<&ReadPolicy>) -> IndyResult<()> {
         let mut buf = [0u8; 4];
         let mut buf_to = [0u8; 4];
         LittleEndian::write_i32(&mut buf, cmd_id);
         let timeout = timeout.unwrap_or(-1);
         LittleEndian::write_i32(&mut buf_to, timeout);
-        if let Some(nodes) = nodes {
+        if let Some(read_policy) = read_policy {
+            let read_policy = serde_json::to_string(read_policy)
+                .to_indy(IndyErrorKind::InvalidState, "Can't serialize read policy")?;
+            Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to, nodes.unwrap_or("").as_bytes(), read_policy.as_bytes()], zmq::DONTWAIT)?)
+        } else if let Some(nodes) = nodes {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to, nodes.as_bytes()], zmq::DONTWAIT)?)
         } else {
             Ok(socket.send_multipart(&[msg.as_bytes(), &buf, &buf_to], zmq::DONTWAIT)?)
         }
     }
 
+    pub fn get_status(&self, handle: PoolHandle) -> IndyResult<PoolStatus> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {:?}", handle)))?;
+        let name = pool.pool.get_name();
+
+        let merkle = merkle_tree_factory::create(name)?;
+        let node_state = merkle_tree_factory::build_node_state(&merkle)?;
+        let runtime = get_pool_runtime(name);
+
+        let mut nodes: Vec<PoolNodeStatus> = node_state.into_iter()
+            .map(|(_, txn)| {
+                let data = txn.txn.data.data;
+                let health = runtime.nodes_health.get(&data.alias).cloned().unwrap_or_default();
+
+                PoolNodeStatus {
+                    connected: runtime.connected_nodes.contains(&data.alias),
+                    health: PoolNodeHealth {
+                        replies: health.replies,
+                        failures: health.failures,
+                        latency_ms: health.latency_ms.map(|latency| latency.round() as u64),
+                        score: health.score(),
+                    },
+                    client_address: match (data.client_ip, data.client_port) {
+                        (Some(ip), Some(port)) => Some(format!("tcp://{}:{}", ip, port)),
+                        _ => None
+                    },
+                    node_address: match (data.node_ip, data.node_port) {
+                        (Some(ip), Some(port)) => Some(format!("tcp://{}:{}", ip, port)),
+                        _ => None
+                    },
+                    services: data.services.unwrap_or_default(),
+                    dest: txn.txn.data.dest,
+                    alias: data.alias,
+                }
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.alias.cmp(&b.alias));
+
+        Ok(PoolStatus {
+            name: name.to_string(),
+            ledger: PoolLedgerInfo {
+                size: merkle.count(),
+                root: merkle.root_hash().as_slice().to_base58(),
+            },
+            last_catchup: runtime.last_catchup,
+            nodes,
+        })
+    }
+
     pub fn list(&self) -> IndyResult<Vec<serde_json::Value>> {
         let mut pool = Vec::new();
         let pool_home_path = environment::pool_home_path();
@@ -271,6 +424,45 @@ pub fn set_freshness_threshold(threshold: u64) {
     *th = ::std::cmp::max(threshold, 300);
 }
 
+/// State of an opened pool observed by its worker thread.
+#[derive(Clone, Debug, Default)]
+pub struct PoolRuntime {
+    pub nodes_health: HashMap<String, NodeHealth>,
+    pub connected_nodes: HashSet<String>,
+    pub last_catchup: Option<i64>,
+}
+
+lazy_static! {
+    static ref POOLS_RUNTIME: Mutex<HashMap<String, PoolRuntime>> = Mutex::new(HashMap::new());
+}
+
+fn _update_pool_runtime<F: FnOnce(&mut PoolRuntime)>(pool_name: &str, update: F) {
+    update(POOLS_RUNTIME.lock().unwrap().entry(pool_name.to_string()).or_insert_with(PoolRuntime::default));
+}
+
+pub fn update_nodes_health(pool_name: &str, nodes_health: &HashMap<String, NodeHealth>) {
+    _update_pool_runtime(pool_name, |runtime| runtime.nodes_health = nodes_health.clone());
+}
+
+pub fn update_connected_nodes(pool_name: &str, connected_nodes: &HashSet<String>) {
+    _update_pool_runtime(pool_name, |runtime| runtime.connected_nodes = connected_nodes.clone());
+}
+
+pub fn update_last_catchup(pool_name: &str) {
+    _update_pool_runtime(pool_name, |runtime| runtime.last_catchup = Some(time::get_time().sec));
+}
+
+pub fn get_pool_runtime(pool_name: &str) -> PoolRuntime {
+    POOLS_RUNTIME.lock().unwrap().get(pool_name).cloned().unwrap_or_default()
+}
+
+/// Health of nodes observed by opened pools: pool name -> node alias -> health
+pub fn get_nodes_health() -> HashMap<String, HashMap<String, NodeHealth>> {
+    POOLS_RUNTIME.lock().unwrap().iter()
+        .map(|(pool_name, runtime)| (pool_name.clone(), runtime.nodes_health.clone()))
+        .collect()
+}
+
 
 pub fn parse_response_metadata(response: &str) -> IndyResult<ResponseMetadata> {
     trace!("indy::services::pool::parse_response_metadata << response: {}", response);
@@ -296,6 +488,54 @@ pub fn get_last_signed_time(response: &str) -> Option<u64> {
     c.ok().and_then(|resp| resp.last_txn_time)
 }
 
+pub fn extract_txn_inclusion_proof(response: &str) -> IndyResult<TxnInclusionProof> {
+    trace!("indy::services::pool::extract_txn_inclusion_proof << response: {}", response);
+    let message: Message<serde_json::Value> = serde_json::from_str(response)
+        .to_indy(IndyErrorKind::InvalidTransaction, "Cannot deserialize transaction Response")?;
+
+    let response_object: Reply<serde_json::Value> = _handle_response_message_type(message)?;
+    let response_result = response_object.result();
+
+    let proof = state_proof::parse_reply_for_txn_inclusion_proof(&response_result)
+        .map_err(|err| err_msg(IndyErrorKind::InvalidTransaction, format!("Cannot extract transaction inclusion proof: {}", err)))?;
+
+    trace!("indy::services::pool::extract_txn_inclusion_proof >> proof: {:?}", proof);
+
+    Ok(proof)
+}
+
+pub fn verify_txn_inclusion_proof(proof: &TxnInclusionProof, nodes_bls_keys: &HashMap<String, String>) -> IndyResult<bool> {
+    trace!("indy::services::pool::verify_txn_inclusion_proof << proof: {:?}, nodes_bls_keys: {:?}", proof, nodes_bls_keys);
+
+    if nodes_bls_keys.is_empty() {
+        return Err(err_msg(IndyErrorKind::InvalidStructure, "Empty list of nodes BLS keys"));
+    }
+
+    let nodes = nodes_bls_keys
+        .iter()
+        .map(|(alias, blskey)| {
+            let blskey = blskey
+                .from_base58()
+                .map_err(|_| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid BLS key for node: {}", alias)))?;
+            let blskey = VerKey::from_bytes(&blskey)
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid BLS key for node: {}", alias))?;
+            Ok((alias.clone(), Some(blskey)))
+        })
+        .collect::<IndyResult<Nodes>>()?;
+
+    let generator = DEFAULT_GENERATOR
+        .from_base58()
+        .map_err(|_| err_msg(IndyErrorKind::InvalidState, "Invalid BLS generator"))
+        .and_then(|generator| Generator::from_bytes(&generator)
+            .to_indy(IndyErrorKind::InvalidState, "Invalid BLS generator"))?;
+
+    let res = state_proof::verify_txn_inclusion_proof(proof, &nodes, pool::_get_f(nodes.len()), &generator);
+
+    trace!("indy::services::pool::verify_txn_inclusion_proof >> res: {:?}", res);
+
+    Ok(res)
+}
+
 fn _handle_response_message_type<T>(message: Message<T>) -> IndyResult<Reply<T>> where T: DeserializeOwned + ::std::fmt::Debug {
     trace!("handle_response_message_type >>> message {:?}", message);
 
@@ -444,6 +684,77 @@ mod tests {
             test::cleanup_storage("pool_service_delete_works_for_opened");
         }
 
+        fn _registry_config(pool_name: &str) -> PoolRegistryConfig {
+            serde_json::from_value(json!({
+                "networks": {
+                    "did:sov": {"pool_name": pool_name},
+                    "did:indy:sovrin:staging": {"pool_name": pool_name}
+                },
+                "default": "did:sov"
+            })).unwrap()
+        }
+
+        #[test]
+        fn pool_service_resolve_registry_network_works() {
+            test::cleanup_storage("pool_service_resolve_registry_network_works");
+
+            let pool_name = "pool_service_resolve_registry_network_works";
+            fs::create_dir_all(environment::pool_path(pool_name)).unwrap();
+
+            let ps = PoolService::new();
+            let registry_handle = ps.open_registry(_registry_config(pool_name)).unwrap();
+            assert!(ps.is_registry(registry_handle));
+
+            let (namespace, network, pool_handle) = ps.resolve_registry_network(registry_handle, Some("did:indy:sovrin:staging:NcYxiDXkpYi6ov5FcYDi1e")).unwrap();
+            assert_eq!("did:indy:sovrin:staging", namespace);
+            assert_eq!(pool_name, network.pool_name);
+            assert_eq!(None, pool_handle);
+
+            let (namespace, _, _) = ps.resolve_registry_network(registry_handle, Some("NcYxiDXkpYi6ov5FcYDi1e")).unwrap();
+            assert_eq!("did:sov", namespace);
+
+            let (namespace, _, _) = ps.resolve_registry_network(registry_handle, None).unwrap();
+            assert_eq!("did:sov", namespace);
+
+            let res = ps.resolve_registry_network(registry_handle, Some("did:indy:sovrin:NcYxiDXkpYi6ov5FcYDi1e"));
+            assert_eq!(IndyErrorKind::InvalidStructure, res.unwrap_err().kind());
+
+            test::cleanup_storage("pool_service_resolve_registry_network_works");
+        }
+
+        #[test]
+        fn pool_service_resolve_registry_network_works_for_opened_pool() {
+            test::cleanup_storage("pool_service_resolve_registry_network_works_for_opened_pool");
+
+            let pool_name = "pool_service_resolve_registry_network_works_for_opened_pool";
+            fs::create_dir_all(environment::pool_path(pool_name)).unwrap();
+
+            let (send_cmd_sock, _recv_cmd_sock) = pool_create_pair_of_sockets("pool_service_resolve_registry_network_works_for_opened_pool");
+            let ps = PoolService::new();
+            let registry_handle = ps.open_registry(_registry_config(pool_name)).unwrap();
+
+            let pool_id = next_pool_handle();
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new(pool_name, pool_id, PoolOpenConfig::default()), send_cmd_sock));
+            ps.add_registry_pool(registry_handle, "did:sov", pool_id).unwrap();
+
+            let (_, _, pool_handle) = ps.resolve_registry_network(registry_handle, Some("did:sov:NcYxiDXkpYi6ov5FcYDi1e")).unwrap();
+            assert_eq!(Some(pool_id), pool_handle);
+
+            assert_eq!(vec![pool_id], ps.remove_registry(registry_handle).unwrap());
+            assert!(!ps.is_registry(registry_handle));
+
+            test::cleanup_storage("pool_service_resolve_registry_network_works_for_opened_pool");
+        }
+
+        #[test]
+        fn pool_service_open_registry_works_for_not_created_pool() {
+            test::cleanup_storage("pool_service_open_registry_works_for_not_created_pool");
+
+            let ps = PoolService::new();
+            let res = ps.open_registry(_registry_config("pool_service_open_registry_works_for_not_created_pool"));
+            assert_eq!(IndyErrorKind::PoolNotCreated, res.unwrap_err().kind());
+        }
+
         #[test]
         fn pool_send_tx_works() {
             test::cleanup_storage("pool_send_tx_works");
@@ -505,6 +816,46 @@ mod tests {
             assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
         }
 
+        #[test]
+        fn pool_get_status_works() {
+            test::cleanup_storage("pool_get_status_works");
+            _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+            let pool_name = "pool_get_status_works";
+            {
+                let mut file = test::test_pool_create_poolfile(pool_name);
+                file.write_all(test::gen_txns().join("\n").as_bytes()).unwrap();
+            }
+
+            let (send_cmd_sock, _recv_cmd_sock) = pool_create_pair_of_sockets(pool_name);
+            let pool_id = next_pool_handle();
+            let ps = PoolService::new();
+            ps.open_pools.borrow_mut().insert(pool_id, ZMQPool::new(Pool::new(pool_name, pool_id, PoolOpenConfig::default()), send_cmd_sock));
+
+            update_connected_nodes(pool_name, &vec!["Node1".to_string()].into_iter().collect());
+
+            let status = ps.get_status(pool_id).unwrap();
+            assert_eq!(pool_name, status.name);
+            assert_eq!(4, status.ledger.size);
+            assert!(!status.ledger.root.is_empty());
+            assert_eq!(4, status.nodes.len());
+            assert_eq!("Node1", status.nodes[0].alias);
+            assert!(status.nodes[0].connected);
+            assert!(!status.nodes[1].connected);
+            assert!(status.nodes[0].client_address.is_some());
+            assert_eq!(100, status.nodes[0].health.score);
+
+            test::cleanup_storage("pool_get_status_works");
+        }
+
+        #[test]
+        fn pool_get_status_works_for_invalid_handle() {
+            test::cleanup_storage("pool_get_status_works_for_invalid_handle");
+            let ps = PoolService::new();
+            let res = ps.get_status(INVALID_POOL_HANDLE);
+            assert_eq!(IndyErrorKind::InvalidPoolHandle, res.unwrap_err().kind());
+        }
+
         #[test]
         fn pool_refresh_works_for_invalid_handle() {
             test::cleanup_storage("pool_refresh_works_for_invalid_handle");
@@ -7,6 +7,7 @@ use time::Tm;
 
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::events::*;
+use crate::services::pool::{update_connected_nodes, update_nodes_health};
 use crate::services::pool::types::*;
 use indy_utils::sequence;
 use indy_utils::crypto::base64;
@@ -16,8 +17,54 @@ use super::time::Duration;
 use super::zmq::PollItem;
 use super::zmq::Socket as ZSocket;
 
+const LATENCY_SMOOTHING: f64 = 0.3;
+const LATENCY_BUCKET_MS: u64 = 100;
+const FAILURE_PENALTY: u64 = 10; // in latency buckets
+
+/// Statistics of node responses used to prefer fast and reliable nodes for reads.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NodeHealth {
+    pub replies: usize,
+    pub failures: usize,
+    pub consecutive_failures: usize,
+    pub latency_ms: Option<f64>,
+}
+
+impl NodeHealth {
+    fn record_reply(&mut self, latency_ms: Option<i64>) {
+        self.replies += 1;
+        self.consecutive_failures = 0;
+
+        if let Some(latency_ms) = latency_ms {
+            let latency_ms = ::std::cmp::max(latency_ms, 0) as f64;
+            self.latency_ms = Some(match self.latency_ms {
+                Some(avg) => avg + LATENCY_SMOOTHING * (latency_ms - avg),
+                None => latency_ms
+            });
+        }
+    }
+
+    fn record_failure(&mut self) {
+        self.failures += 1;
+        self.consecutive_failures += 1;
+    }
+
+    /// Percentage of requests answered by node. Nodes without history have full score.
+    pub fn score(&self) -> usize {
+        let total = self.replies + self.failures;
+        if total == 0 { 100 } else { self.replies * 100 / total }
+    }
+
+    /// The lower rank, the earlier node is queried.
+    /// Latency is bucketed to keep load balanced between nodes with similar latency.
+    fn rank(&self) -> u64 {
+        self.latency_ms.map(|latency| latency as u64 / LATENCY_BUCKET_MS).unwrap_or(0)
+            + self.consecutive_failures as u64 * FAILURE_PENALTY
+    }
+}
+
 pub trait Networker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self;
+    fn new(pool_name: &str, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self;
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent>;
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
@@ -25,9 +72,12 @@ pub trait Networker {
 }
 
 pub struct ZMQNetworker {
+    pool_name: String,
     req_id_mappings: HashMap<String, i32>,
     pool_connections: BTreeMap<i32, PoolConnection>,
     nodes: Vec<RemoteNode>,
+    nodes_health: HashMap<String, NodeHealth>,
+    connected_nodes: HashSet<String>,
     active_timeout: i64,
     conn_limit: usize,
     preordered_nodes: Vec<String>,
@@ -35,11 +85,14 @@ pub struct ZMQNetworker {
 }
 
 impl Networker for ZMQNetworker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self {
+    fn new(pool_name: &str, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, socks_proxy: String) -> Self {
         ZMQNetworker {
+            pool_name: pool_name.to_string(),
             req_id_mappings: HashMap::new(),
             pool_connections: BTreeMap::new(),
             nodes: Vec::new(),
+            nodes_health: HashMap::new(),
+            connected_nodes: HashSet::new(),
             active_timeout,
             conn_limit,
             preordered_nodes,
@@ -57,7 +110,7 @@ impl Networker for ZMQNetworker {
     }
 
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
-        match pe.clone() {
+        let res = match pe.clone() {
             Some(NetworkerEvent::SendAllRequest(_, req_id, _, _)) | Some(NetworkerEvent::SendOneRequest(_, req_id, _)) | Some(NetworkerEvent::Resend(req_id, _)) => {
                 let num = self.req_id_mappings.get(&req_id).copied().or_else(|| {
                     trace!("sending new request");
@@ -86,7 +139,7 @@ impl Networker for ZMQNetworker {
                     None => {
                         trace!("send request in new conn");
                         let pc_id = sequence::get_next_id();
-                        let mut pc = PoolConnection::new(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone(), self.socks_proxy.clone());
+                        let mut pc = PoolConnection::new(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone(), &self.nodes_health, self.socks_proxy.clone());
                         pc.send_request(pe).expect("FIXME");
                         self.pool_connections.insert(pc_id, pc);
                         self.req_id_mappings.insert(req_id.clone(), pc_id);
@@ -139,6 +192,24 @@ impl Networker for ZMQNetworker {
 
                 None
             }
+            Some(NetworkerEvent::Schedule(req_id, timer, delay)) => {
+                match self.req_id_mappings.get(&req_id).and_then(|idx| self.pool_connections.get(idx)) {
+                    Some(pc) => pc.schedule(&req_id, &timer, delay),
+                    None => warn!("Can't schedule {} for unknown req_id {}", timer, req_id)
+                }
+                None
+            }
+            Some(NetworkerEvent::NodeReplied(req_id, node_alias)) => {
+                let latency = self.req_id_mappings.get(&req_id)
+                    .and_then(|idx| self.pool_connections.get(idx))
+                    .and_then(|pc| pc.take_latency(&req_id, &node_alias));
+                self._update_node_health(&node_alias, |health| health.record_reply(latency));
+                None
+            }
+            Some(NetworkerEvent::NodeFailed(_, node_alias)) => {
+                self._update_node_health(&node_alias, NodeHealth::record_failure);
+                None
+            }
             Some(NetworkerEvent::Timeout) => {
                 let pc_to_delete: Vec<i32> = self.pool_connections.iter()
                     .filter(|(_, v)| v.is_orphaned())
@@ -151,7 +222,11 @@ impl Networker for ZMQNetworker {
                 None
             }
             _ => None
-        }
+        };
+
+        self._update_connected_nodes();
+
+        res
     }
 
     fn get_timeout(&self) -> ((String, String), i64) {
@@ -167,6 +242,25 @@ impl Networker for ZMQNetworker {
     }
 }
 
+impl ZMQNetworker {
+    fn _update_node_health<F: FnOnce(&mut NodeHealth)>(&mut self, node_alias: &str, update: F) {
+        update(self.nodes_health.entry(node_alias.to_string()).or_insert_with(NodeHealth::default));
+        update_nodes_health(&self.pool_name, &self.nodes_health);
+    }
+
+    fn _update_connected_nodes(&mut self) {
+        let connected_nodes: HashSet<String> = self.pool_connections.values()
+            .flat_map(|pc| pc.connected_nodes())
+            .cloned()
+            .collect();
+
+        if connected_nodes != self.connected_nodes {
+            update_connected_nodes(&self.pool_name, &connected_nodes);
+            self.connected_nodes = connected_nodes;
+        }
+    }
+}
+
 pub struct PoolConnection {
     nodes: Vec<RemoteNode>,
     sockets: Vec<Option<ZSocket>>,
@@ -174,6 +268,7 @@ pub struct PoolConnection {
     key_pair: zmq::CurveKeyPair,
     resend: RefCell<HashMap<String, (usize, String)>>,
     timeouts: RefCell<HashMap<(String, String), Tm>>,
+    sent: RefCell<HashMap<(String, String), Tm>>,
     time_created: time::Tm,
     req_cnt: usize,
     active_timeout: i64,
@@ -181,18 +276,20 @@ pub struct PoolConnection {
 }
 
 impl PoolConnection {
-    fn new(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>, socks_proxy: String) -> Self {
+    fn new(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>, nodes_health: &HashMap<String, NodeHealth>, socks_proxy: String) -> Self {
         trace!("PoolConnection::new: from nodes {:?}", nodes);
 
         nodes.shuffle(&mut thread_rng());
 
-        if !preordered_nodes.is_empty() {
-            nodes.sort_by_key(|node: &RemoteNode| -> usize {
-                preordered_nodes.iter()
-                    .position(|&ref name| node.name.eq(name))
-                    .unwrap_or(usize::max_value())
-            });
-        }
+        nodes.sort_by_key(|node: &RemoteNode| -> (usize, u64) {
+            let position = preordered_nodes.iter()
+                .position(|&ref name| node.name.eq(name))
+                .unwrap_or(usize::max_value());
+            let rank = nodes_health.get(&node.name)
+                .map(NodeHealth::rank)
+                .unwrap_or(0);
+            (position, rank)
+        });
 
         let mut sockets: Vec<Option<ZSocket>> = Vec::with_capacity(nodes.len());
 
@@ -206,6 +303,7 @@ impl PoolConnection {
             resend: RefCell::new(HashMap::new()),
             time_created: time::now(),
             timeouts: RefCell::new(HashMap::new()),
+            sent: RefCell::new(HashMap::new()),
             req_cnt: 0,
             active_timeout,
             socks_proxy
@@ -233,6 +331,13 @@ impl PoolConnection {
         vec
     }
 
+    fn connected_nodes<'a>(&'a self) -> impl Iterator<Item=&'a String> + 'a {
+        self.nodes.iter()
+            .zip(self.sockets.iter())
+            .filter(|(_, socket)| socket.is_some())
+            .map(|(node, _)| &node.name)
+    }
+
     fn get_poll_items(&self) -> Vec<PollItem> {
         self.sockets.iter()
             .flat_map(|zs: &Option<ZSocket>| zs.as_ref().map(|zs| zs.as_poll_item(zmq::POLLIN)))
@@ -309,10 +414,20 @@ impl PoolConnection {
                 let keys_to_remove: Vec<(String, String)> = self.timeouts.borrow().keys()
                     .cloned().filter(|&(ref req_id_timeout, _)| req_id == req_id_timeout).collect();
                 keys_to_remove.iter().for_each(|key| { self.timeouts.borrow_mut().remove(key); });
+                self.sent.borrow_mut().retain(|&(ref req_id_sent, _), _| req_id != req_id_sent);
             }
         }
     }
 
+    fn schedule(&self, req_id: &str, timer: &str, delay: i64) {
+        self.timeouts.borrow_mut().insert((req_id.to_string(), timer.to_string()), time::now() + Duration::milliseconds(delay));
+    }
+
+    fn take_latency(&self, req_id: &str, node_alias: &str) -> Option<i64> {
+        self.sent.borrow_mut().remove(&(req_id.to_string(), node_alias.to_string()))
+            .map(|sent| (time::now() - sent).num_milliseconds())
+    }
+
     fn has_active_requests(&self) -> bool {
         !self.timeouts.borrow().is_empty()
     }
@@ -327,6 +442,7 @@ impl PoolConnection {
             let s = self._get_socket(idx)?;
             s.send(&req, zmq::DONTWAIT)?;
         }
+        self.sent.borrow_mut().insert((req_id.clone(), self.nodes[idx].name.clone()), time::now());
         self.timeouts.borrow_mut().insert((req_id, self.nodes[idx].name.clone()), time::now() + Duration::seconds(timeout));
         trace!("_send_msg_to_one_node <<");
         Ok(())
@@ -375,7 +491,7 @@ pub struct MockNetworker {
 
 #[cfg(test)]
 impl Networker for MockNetworker {
-    fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>, _socks_proxy: String) -> Self {
+    fn new(_pool_name: &str, _active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>, _socks_proxy: String) -> Self {
         MockNetworker {
             events: Vec::new(),
         }
@@ -415,6 +531,7 @@ pub mod networker_tests {
     const REQ_ID: &str = "1";
     const MESSAGE: &str = "msg";
     const NODE_NAME: &str = "n1";
+    const POOL_NAME: &str = "networker_tests";
 
     pub fn _remote_node(txn: &NodeTransactionV1) -> RemoteNode {
         RemoteNode {
@@ -433,12 +550,12 @@ pub mod networker_tests {
 
         #[test]
         pub fn networker_new_works() {
-            ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
         }
 
         #[test]
         pub fn networker_process_event_works() {
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(None);
         }
 
@@ -447,7 +564,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             assert_eq!(0, networker.nodes.len());
 
@@ -462,7 +579,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             assert!(networker.pool_connections.is_empty());
@@ -488,7 +605,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
             networker.process_event(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
@@ -511,7 +628,7 @@ pub mod networker_tests {
 
             let send_cnt = 2;
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec!["n2".to_string(), "n1".to_string()], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec!["n2".to_string(), "n1".to_string()], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
 
@@ -541,7 +658,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
             networker.process_event(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(vec![NODE_NAME.to_string()]))));
@@ -557,7 +674,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -580,7 +697,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -603,7 +720,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -630,9 +747,9 @@ pub mod networker_tests {
         fn networker_process_timeout_event_works() {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
-            let conn = PoolConnection::new(vec![rn.clone()], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let conn = PoolConnection::new(vec![rn.clone()], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.pool_connections.insert(1, conn);
@@ -649,7 +766,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
 
@@ -660,12 +777,54 @@ pub mod networker_tests {
             assert!(networker.pool_connections.is_empty());
         }
 
+        #[test]
+        fn networker_process_clean_timeout_event_keeps_connection_with_scheduled_retry() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            networker.process_event(Some(NetworkerEvent::Schedule(REQ_ID.to_string(), HEDGE_TIMER.to_string(), 100)));
+
+            _roll_back_timeout(&mut networker);
+
+            // the same order of events request handler emits when it retries read request
+            networker.process_event(Some(NetworkerEvent::Schedule(REQ_ID.to_string(), RETRY_TIMER.to_string(), 100)));
+            networker.process_event(Some(NetworkerEvent::CleanTimeout(REQ_ID.to_string(), Some(txn.txn.data.data.alias.clone()))));
+            networker.process_event(Some(NetworkerEvent::CleanTimeout(REQ_ID.to_string(), Some(HEDGE_TIMER.to_string()))));
+
+            assert_eq!(1, networker.pool_connections.len());
+            assert!(networker.req_id_mappings.contains_key(REQ_ID));
+
+            let ((req_id, timer), _) = networker.get_timeout();
+            assert_eq!(REQ_ID, req_id);
+            assert_eq!(RETRY_TIMER, timer);
+        }
+
+        #[test]
+        fn networker_process_event_works_for_connected_nodes() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new("networker_process_event_works_for_connected_nodes", POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+            assert!(networker.connected_nodes.is_empty());
+
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
+            assert!(networker.connected_nodes.contains(&txn.txn.data.data.alias));
+
+            _roll_back_timeout(&mut networker);
+            networker.process_event(Some(NetworkerEvent::CleanTimeout(REQ_ID.to_string(), Some(txn.txn.data.data.alias.clone()))));
+            assert!(networker.connected_nodes.is_empty());
+        }
+
         #[test]
         fn networker_process_second_request_after_cleaning_timeout_works() {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -683,7 +842,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -702,7 +861,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            let mut networker = ZMQNetworker::new(POOL_NAME, POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -718,6 +877,56 @@ pub mod networker_tests {
         }
     }
 
+    #[cfg(test)]
+    mod node_health {
+        use super::*;
+
+        #[test]
+        fn node_health_record_reply_works() {
+            let mut health = NodeHealth::default();
+
+            health.record_reply(Some(100));
+            assert_eq!(Some(100.0), health.latency_ms);
+
+            health.record_reply(Some(200));
+            assert_eq!(Some(130), health.latency_ms.map(|latency| latency.round() as i64));
+            assert_eq!(2, health.replies);
+            assert_eq!(100, health.score());
+        }
+
+        #[test]
+        fn node_health_record_failure_works() {
+            let mut health = NodeHealth::default();
+
+            health.record_failure();
+            health.record_failure();
+            assert_eq!(2, health.consecutive_failures);
+            assert_eq!(0, health.score());
+            assert_eq!(2 * FAILURE_PENALTY, health.rank());
+
+            health.record_reply(None);
+            assert_eq!(0, health.consecutive_failures);
+            assert_eq!(33, health.score());
+            assert_eq!(0, health.rank());
+        }
+
+        #[test]
+        fn networker_process_node_health_events_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let mut networker = ZMQNetworker::new("networker_process_node_health_events_works", POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], String::new());
+            networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
+
+            networker.process_event(Some(NetworkerEvent::NodeFailed(REQ_ID.to_string(), NODE_NAME.to_string())));
+            networker.process_event(Some(NetworkerEvent::NodeReplied(REQ_ID.to_string(), NODE_NAME.to_string())));
+
+            let expected = NodeHealth { replies: 1, failures: 1, consecutive_failures: 0, latency_ms: None };
+            assert_eq!(expected, networker.nodes_health[NODE_NAME]);
+            assert_eq!(expected, crate::services::pool::get_nodes_health()["networker_process_node_health_events_works"][NODE_NAME]);
+        }
+    }
+
     #[cfg(test)]
     mod remote_node {
         use super::*;
@@ -764,7 +973,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
         }
 
         #[test]
@@ -780,7 +989,7 @@ pub mod networker_tests {
                 nodes.push(_remote_node(&txn));
             }
 
-            let pc = PoolConnection::new(nodes, POOL_CON_ACTIVE_TO, vec![], String::new());
+            let pc = PoolConnection::new(nodes, POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             let act_names: Vec<String> = pc.nodes.iter().map(|n| n.name.to_string()).collect();
 
@@ -809,6 +1018,7 @@ pub mod networker_tests {
             let pc = PoolConnection::new(vec![rn_1.clone(), rn_2.clone(), rn_3.clone(), rn_4.clone(), rn_5.clone()],
                                          POOL_CON_ACTIVE_TO,
                                          vec![rn_2.name.clone(), rn_1.name.clone(), rn_5.name.clone()],
+                                         &HashMap::new(),
                                          String::new());
 
             assert_eq!(rn_2.name, pc.nodes[0].name);
@@ -816,12 +1026,51 @@ pub mod networker_tests {
             assert_eq!(rn_5.name, pc.nodes[2].name);
         }
 
+        #[test]
+        fn pool_connection_new_works_for_nodes_health() {
+            let mut txn = nodes_emulator::node();
+            let rn_1 = _remote_node(&txn);
+
+            txn.txn.data.data.alias = "n2".to_string();
+            let rn_2 = _remote_node(&txn);
+
+            txn.txn.data.data.alias = "n3".to_string();
+            let rn_3 = _remote_node(&txn);
+
+            let mut nodes_health = HashMap::new();
+            nodes_health.insert(rn_1.name.clone(), NodeHealth { failures: 2, consecutive_failures: 2, ..NodeHealth::default() });
+            nodes_health.insert(rn_2.name.clone(), NodeHealth { replies: 1, latency_ms: Some(1000.0), ..NodeHealth::default() });
+
+            let pc = PoolConnection::new(vec![rn_1.clone(), rn_2.clone(), rn_3.clone()], POOL_CON_ACTIVE_TO, vec![], &nodes_health, String::new());
+
+            assert_eq!(rn_3.name, pc.nodes[0].name);
+            assert_eq!(rn_2.name, pc.nodes[1].name);
+            assert_eq!(rn_1.name, pc.nodes[2].name);
+        }
+
+        #[test]
+        fn pool_connection_schedule_works() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
+            conn.schedule(REQ_ID, HEDGE_TIMER, 100);
+
+            let ((req_id, timer), timeout) = conn.get_timeout();
+            assert_eq!(REQ_ID, req_id);
+            assert_eq!(HEDGE_TIMER, timer);
+            assert!(timeout <= 100);
+
+            conn.clean_timeout(REQ_ID, None);
+            assert!(!conn.has_active_requests());
+        }
+
         #[test]
         fn pool_connection_is_active_works() {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             assert!(conn.is_active());
 
@@ -835,7 +1084,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             assert!(!conn.has_active_requests());
 
@@ -849,7 +1098,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             let ((req_id, node_alias), timeout) = conn.get_timeout();
             assert_eq!(req_id, "".to_string());
@@ -870,7 +1119,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -890,7 +1139,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -906,7 +1155,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             let _socket = conn._get_socket(0).unwrap();
         }
@@ -917,7 +1166,7 @@ pub mod networker_tests {
             let mut rn = _remote_node(&txn);
             rn.zaddr = "invalid_address".to_string();
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             let res = conn._get_socket(0);
             assert_kind!(IndyErrorKind::IOError, res);
@@ -929,7 +1178,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
             conn.send_request(Some(NetworkerEvent::SendOneRequest("msg2".to_string(), "12".to_string(), POOL_ACK_TIMEOUT))).unwrap();
@@ -949,7 +1198,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec!["n1".to_string(), "n2".to_string()], String::new());
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec!["n1".to_string(), "n2".to_string()], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -969,7 +1218,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None))).unwrap();
 
@@ -985,7 +1234,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -1006,7 +1255,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
 
@@ -1024,7 +1273,7 @@ pub mod networker_tests {
             let mut rn = _remote_node(&txn);
             rn.zaddr = "invalid_address".to_string();
 
-            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], String::new());
+            let mut conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![], &HashMap::new(), String::new());
 
             let res = conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
             assert_kind!(IndyErrorKind::IOError, res);
@@ -13,12 +13,12 @@ use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
 use crate::commands::pool::PoolCommand;
 use crate::domain::ledger::request::ProtocolVersion;
-use crate::domain::pool::PoolOpenConfig;
+use crate::domain::pool::{PoolOpenConfig, ReadPolicy};
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::commander::Commander;
 use crate::services::pool::events::*;
-use crate::services::pool::{merkle_tree_factory, Nodes};
+use crate::services::pool::{merkle_tree_factory, Nodes, update_last_catchup};
 use crate::services::pool::networker::{Networker, ZMQNetworker};
 use crate::services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
 use rust_base58::{FromBase58, ToBase58};
@@ -331,6 +331,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
+                            update_last_catchup(&pool_name);
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
                         } else {
@@ -379,7 +380,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             PoolState::Terminated(state.into())
                         }
                     }
-                    PoolEvent::SendRequest(cmd_id, _, _, _) => {
+                    PoolEvent::SendRequest(cmd_id, _, _, _, _) => {
                         trace!("received request to send");
                         let re: Option<RequestEvent> = pe.into();
                         match re.as_ref().map(|r| r.get_req_id()) {
@@ -439,6 +440,7 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                     PoolEvent::Synced(merkle) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
+                            update_last_catchup(&pool_name);
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh, Ok(()));
                             PoolState::Active((state, nodes).into())
                         } else {
@@ -476,6 +478,7 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     preordered_nodes: Vec<String>,
     number_read_nodes: u8,
     socks_proxy: String,
+    read_policy: ReadPolicy,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
@@ -493,6 +496,7 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             preordered_nodes: config.preordered_nodes,
             number_read_nodes: config.number_read_nodes,
             socks_proxy: config.socks_proxy,
+            read_policy: config.read_policy,
         }
     }
 
@@ -524,6 +528,10 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn get_id(&self) -> PoolHandle {
         self.id
     }
+
+    pub fn get_read_policy(&self) -> &ReadPolicy {
+        &self.read_policy
+    }
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
@@ -536,7 +544,7 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     pub fn new(cmd_socket: zmq::Socket, name: String, id: PoolHandle, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize,
                preordered_nodes: Vec<String>, number_read_nodes: u8, socks_proxy: String) -> Self {
-        let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes, socks_proxy)));
+        let networker = Rc::new(RefCell::new(S::new(&name, active_timeout, conn_limit, preordered_nodes, socks_proxy)));
         PoolThread {
             pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout, number_read_nodes)),
             events: VecDeque::new(),
@@ -603,7 +611,7 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
-fn _get_f(cnt: usize) -> usize {
+pub(super) fn _get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
     }
@@ -814,7 +822,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_new_initialization_works() {
-            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "name", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
         }
 
         #[test]
@@ -824,7 +832,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_check_cache_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_check_cache_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             assert_match!(PoolState::GettingCatchupTarget(_), p.state);
@@ -835,7 +843,7 @@ mod tests {
         #[test]
         pub fn pool_wrapper_check_cache_works_for_no_pool_created() {
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))),
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))),
                             "pool_wrapper_check_cache_works_for_no_pool_created", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
@@ -844,7 +852,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_terminated_close_works() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_terminated_close_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_terminated_close_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let cmd_id: CommandHandle = next_command_handle();
@@ -855,7 +863,7 @@ mod tests {
         #[test]
         pub fn pool_wrapper_terminated_refresh_works() {
             test::cleanup_pool("pool_wrapper_terminated_refresh_works");
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_terminated_refresh_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_terminated_refresh_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
 
@@ -874,7 +882,7 @@ mod tests {
                 pool_name: "pool_wrapper_terminated_timeout_works".to_string(),
                 id: next_pool_handle(),
                 state: PoolState::Terminated(TerminatedState {
-                    networker: Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))),
+                    networker: Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))),
                 }),
                 timeout: 0,
                 extended_timeout: 0,
@@ -895,7 +903,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_cloe_works_from_initialization() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_cloe_works_from_initialization", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::Close(cmd_id));
             assert_match!(PoolState::Closed(_), p.state);
@@ -909,7 +917,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_close_works_from_getting_catchup_target");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_close_works_from_getting_catchup_target", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let cmd_id: CommandHandle = next_command_handle();
@@ -927,7 +935,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_catchup_target_not_found_works");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_catchup_target_not_found_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_catchup_target_not_found_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::CatchupTargetNotFound(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout")));
@@ -944,7 +952,7 @@ mod tests {
             _write_genesis_txns("pool_wrapper_getting_catchup_target_synced_works");
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new()))), "pool_wrapper_getting_catchup_target_synced_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new()))), "pool_wrapper_getting_catchup_target_synced_works", next_pool_handle(), 0, 0, NUMBER_READ_NODES);
             let cmd_id: CommandHandle = next_command_handle();
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
@@ -962,7 +970,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(
                 Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0, vec![], String::new()))),
                 "pool_wrapper_getting_catchup_target_synced_works_for_node_state_error",
                 next_pool_handle(),
@@ -988,7 +996,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(
                 Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0,
                                        vec![],
                                        String::new()))),
@@ -1015,7 +1023,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(RefCell::new(
-                    MockNetworker::new(0, 0, vec![], String::new()))),
+                    MockNetworker::new("", 0, 0, vec![], String::new()))),
                             "pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error",
                             next_pool_handle(),
                             0,
@@ -1041,7 +1049,7 @@ mod tests {
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(
                     RefCell::new(
-                        MockNetworker::new(0,
+                        MockNetworker::new("", 0,
                                            0,
                                            vec![],
                                            String::new()))),
@@ -1070,7 +1078,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(
                 Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0,
                                        vec![],
                                        String::new()))),
@@ -1098,7 +1106,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(
                 Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0,
                                        vec![],
                                        String::new()))),
@@ -1131,7 +1139,7 @@ mod tests {
             }).to_string();
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(
-                RefCell::new(MockNetworker::new(0,
+                RefCell::new(MockNetworker::new("", 0,
                                                 0,
                                                 vec![],
                                                 String::new()))),
@@ -1143,7 +1151,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1172,6 +1180,7 @@ mod tests {
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(RefCell::new(
                     MockNetworker::new(
+                        "",
                         0,
                         0,
                         vec![],
@@ -1184,7 +1193,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1226,7 +1235,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(
                 Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0,
                                        vec![],
                                        String::new()))),
@@ -1238,7 +1247,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1267,7 +1276,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(RefCell::new(
-                    MockNetworker::new(0,
+                    MockNetworker::new("", 0,
                                        0,
                                        vec![],
                                        String::new()))),
@@ -1277,7 +1286,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
                 PoolState::Active(state) => {
@@ -1318,7 +1327,7 @@ mod tests {
             let rep = serde_json::to_string(&rep).unwrap();
 
             let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(
-                RefCell::new(MockNetworker::new(0,
+                RefCell::new(MockNetworker::new("", 0,
                                                 0,
                                                 vec![],
                                                 String::new()))),
@@ -1330,7 +1339,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1362,6 +1371,7 @@ mod tests {
 
             let p: PoolSM<MockNetworker, MockRequestHandler> =
                 PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(
+                    "",
                     0,
                     0,
                     vec![],
@@ -1374,7 +1384,7 @@ mod tests {
             let p = p.handle_event(PoolEvent::CheckCache(cmd_id));
             let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
             let cmd_id: CommandHandle = next_command_handle();
-            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None));
+            let p = p.handle_event(PoolEvent::SendRequest(cmd_id, req, None, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep.to_string(), "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -14,10 +14,11 @@ use self::super::THRESHOLD;
 use crate::commands::Command;
 use crate::commands::CommandExecutor;
 use crate::commands::ledger::LedgerCommand;
+use crate::domain::pool::ReadPolicy;
 use indy_api_types::errors::prelude::*;
 use crate::services::ledger::merkletree::merkletree::MerkleTree;
 use crate::services::pool::catchup::{build_catchup_req, CatchupProgress, check_cons_proofs, check_nodes_responses_on_status};
-use crate::services::pool::events::NetworkerEvent;
+use crate::services::pool::events::{HEDGE_TIMER, NetworkerEvent, RETRY_TIMER};
 use crate::services::pool::events::PoolEvent;
 use crate::services::pool::events::RequestEvent;
 use crate::services::pool::{get_last_signed_time, Nodes};
@@ -147,6 +148,8 @@ struct SingleState<T: Networker> {
     networker: Rc<RefCell<T>>,
     sp_key: Option<Vec<u8>>,
     timestamps: (Option<u64>, Option<u64>),
+    read_policy: ReadPolicy,
+    attempt: u8,
 }
 
 struct FullState<T: Networker> {
@@ -157,8 +160,8 @@ struct FullState<T: Networker> {
 
 struct FinishState {}
 
-impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))> for SingleState<T> {
-    fn from((state, sp_key, timestamps): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))) -> Self {
+impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), ReadPolicy)> for SingleState<T> {
+    fn from((state, sp_key, timestamps, read_policy): (StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>), ReadPolicy)) -> Self {
         SingleState {
             denied_nodes: HashSet::new(),
             replies: HashMap::new(),
@@ -166,6 +169,8 @@ impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u6
             networker: state.networker.clone(),
             sp_key,
             timestamps,
+            read_policy,
+            attempt: 0,
         }
     }
 }
@@ -279,14 +284,21 @@ impl<T: Networker> RequestSM<T> {
                             }
                         }
                     }
-                    RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps) => {
+                    RequestEvent::CustomSingleRequest(msg, req_id, _, _, read_policy) if read_policy.full_consensus => {
+                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, None)));
+                        (RequestState::Consensus(state.into()), None)
+                    }
+                    RequestEvent::CustomSingleRequest(msg, req_id, sp_key, timestamps, read_policy) => {
                         state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(msg.clone(), req_id.clone(), timeout)));
 
                         for _ in 0..number_read_nodes - 1 {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
                         }
 
-                        (RequestState::Single((state, sp_key, timestamps).into()), None)
+                        let state: SingleState<T> = (state, sp_key, timestamps, read_policy).into();
+                        state.schedule_hedge(&req_id);
+
+                        (RequestState::Single(state), None)
                     }
                     RequestEvent::CustomFullRequest(msg, req_id, local_timeout, nodes_to_send) => {
                         let timeout = local_timeout.map(|to| to as i64).unwrap_or(extended_timeout);
@@ -331,6 +343,7 @@ impl<T: Networker> RequestSM<T> {
                     RequestEvent::Reject(_, raw_msg, node_alias, req_id)
                     => {
                         if let Ok((_, result_without_proof)) = _get_msg_result_without_state_proof(&raw_msg) {
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeReplied(req_id.clone(), node_alias.clone())));
                             let hashable = HashableValue { inner: result_without_proof };
 
                             let cnt = {
@@ -353,6 +366,7 @@ impl<T: Networker> RequestSM<T> {
                                 (RequestState::finish(), None)
                             }
                         } else {
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeFailed(req_id.clone(), node_alias.clone())));
                             state.denied_nodes.insert(node_alias.clone());
                             if state.denied_nodes.len() + state.replies.len() == nodes.len() {
                                 _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
@@ -367,6 +381,7 @@ impl<T: Networker> RequestSM<T> {
                         (RequestState::Consensus(state), None)
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
+                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeFailed(req_id.clone(), node_alias.clone())));
                         state.timeout_nodes.insert(node_alias.clone());
                         if state.is_consensus_reachable(f, nodes.len()) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
@@ -393,6 +408,7 @@ impl<T: Networker> RequestSM<T> {
                         trace!("reply on single request");
                         state.timeout_nodes.remove(&node_alias);
                         if let Ok((result, result_without_proof)) = _get_msg_result_without_state_proof(&raw_msg) {
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeReplied(req_id.clone(), node_alias.clone())));
                             let hashable = HashableValue { inner: result_without_proof };
 
                             let last_write_time = get_last_signed_time(&raw_msg).unwrap_or(0);
@@ -415,6 +431,7 @@ impl<T: Networker> RequestSM<T> {
                                 (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
                             }
                         } else {
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeFailed(req_id.clone(), node_alias.clone())));
                             state.denied_nodes.insert(node_alias.clone());
                             (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
                         }
@@ -423,7 +440,14 @@ impl<T: Networker> RequestSM<T> {
                         state.networker.borrow_mut().process_event(Some(NetworkerEvent::ExtendTimeout(req_id, node_alias, extended_timeout)));
                         (RequestState::Single(state), None)
                     }
+                    RequestEvent::Timeout(req_id, node_alias) if node_alias == HEDGE_TIMER => {
+                        (state.hedge(req_id, nodes.len(), timeout), None)
+                    }
+                    RequestEvent::Timeout(req_id, node_alias) if node_alias == RETRY_TIMER => {
+                        (state.retry(req_id, number_read_nodes, timeout), None)
+                    }
                     RequestEvent::Timeout(req_id, node_alias) => {
+                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodeFailed(req_id.clone(), node_alias.clone())));
                         state.timeout_nodes.insert(node_alias.clone());
                         (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
                     }
@@ -654,12 +678,27 @@ impl<T: Networker> SingleState<T> {
             < total_nodes_cnt
     }
 
-    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+    fn try_to_continue(mut self, req_id: String, node_alias: String, cmd_ids: &[CommandHandle], nodes_cnt: usize, timeout: i64) -> RequestState<T> {
         if self.is_consensus_reachable(nodes_cnt) {
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
             RequestState::Single(self)
+        } else if self.attempt < self.read_policy.retries {
+            self.attempt += 1;
+            self.denied_nodes.clear();
+            self.replies.clear();
+            self.timeout_nodes.clear();
+
+            let delay = self.read_policy.retry_delay(self.attempt);
+            debug!("Read request {} can't be completed, retry {} in {} ms", req_id, self.attempt, delay);
+
+            // Retry timer must be scheduled before cleaning the others, otherwise the networker could
+            // consider the connection orphaned and drop it together with the pending retry
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Schedule(req_id.clone(), RETRY_TIMER.to_string(), delay as i64)));
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(node_alias))));
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(HEDGE_TIMER.to_string()))));
+            RequestState::Single(self)
         } else {
             //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
             _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
@@ -669,6 +708,37 @@ impl<T: Networker> SingleState<T> {
     }
 }
 
+impl<T: Networker> SingleState<T> {
+    fn schedule_hedge(&self, req_id: &str) {
+        if let Some(hedge_after) = self.read_policy.hedge_after {
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Schedule(req_id.to_string(), HEDGE_TIMER.to_string(), hedge_after as i64)));
+        }
+    }
+
+    fn hedge(self, req_id: String, nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+        self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(HEDGE_TIMER.to_string()))));
+
+        if self.is_consensus_reachable(nodes_cnt) {
+            debug!("No acceptable reply for read request {} yet, query one more node", req_id);
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id, timeout)));
+        }
+
+        RequestState::Single(self)
+    }
+
+    fn retry(self, req_id: String, number_read_nodes: u8, timeout: i64) -> RequestState<T> {
+        self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id.clone(), Some(RETRY_TIMER.to_string()))));
+
+        for _ in 0..number_read_nodes {
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
+        }
+
+        self.schedule_hedge(&req_id);
+
+        RequestState::Single(self)
+    }
+}
+
 impl<T: Networker> ConsensusState<T> {
     fn is_consensus_reachable(&self, f: usize, total_nodes_cnt: usize) -> bool {
         let rep_no: usize = self.replies.values().map(|set| set.len()).sum();
@@ -924,7 +994,7 @@ pub mod tests {
     }
 
     fn _request_handler(pool_name: &str, f: usize, nodes_cnt: usize) -> RequestHandlerImpl<MockNetworker> {
-        let networker = Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], String::new())));
+        let networker = Rc::new(RefCell::new(MockNetworker::new("", 0, 0, vec![], String::new())));
 
         let mut default_nodes: Nodes = HashMap::new();
         default_nodes.insert(NODE.to_string(), None);
@@ -991,10 +1061,32 @@ pub mod tests {
         #[test]
         fn request_handler_process_custom_single_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_custom_single_req_event_from_start_works_for_full_consensus_policy() {
+            let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works_for_full_consensus_policy", 0, 1);
+            let read_policy = ReadPolicy { full_consensus: true, ..ReadPolicy::default() };
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), read_policy)));
+            assert_match!(RequestState::Consensus(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_custom_single_req_event_from_start_works_for_hedge_policy() {
+            let mut request_handler = _request_handler("request_handler_process_custom_single_req_event_from_start_works_for_hedge_policy", 0, 1);
+            let read_policy = ReadPolicy { hedge_after: Some(200), ..ReadPolicy::default() };
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), read_policy)));
+            match request_handler.request_wrapper.unwrap().state {
+                RequestState::Single(state) => {
+                    let networker = state.networker.borrow();
+                    assert_match!(Some(Some(NetworkerEvent::Schedule(_, _, 200))), networker.events.last());
+                }
+                _ => panic!("unexpected state")
+            }
+        }
+
         #[test]
         fn request_handler_process_consensus_full_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_consensus_full_req_event_from_start_works", 0, 1);
@@ -1223,7 +1315,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_consensus_reached() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1251,7 +1343,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(
                 RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() - 300), NODE.to_string(), REQ_ID.to_string()))
             );
@@ -1263,7 +1355,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_future", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(
                 Some(RequestEvent::Reply(Reply::default(), correct_state_proof_reply(_get_cur_time() + 300), NODE.to_string(), REQ_ID.to_string()))
             );
@@ -1294,7 +1386,7 @@ pub mod tests {
             set_freshness_threshold(600);
             add_state_proof_parser();
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_freshness_filtering", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             //
             request_handler.process_event(Some(RequestEvent::Reply(
                 Reply::default(),
@@ -1321,7 +1413,7 @@ pub mod tests {
             add_state_proof_parser();
 
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_state_proof_from_past", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, Some(_get_cur_time() - 400)))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, Some(_get_cur_time() - 400)), ReadPolicy::default())));
 
             {
                 request_handler.process_event(
@@ -1348,7 +1440,7 @@ pub mod tests {
             add_state_proof_parser();
 
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_freshness_filtering_from_env_variable", 2, 4);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             //
             request_handler.process_event(Some(RequestEvent::Reply(
                 Reply::default(),
@@ -1373,7 +1465,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_not_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1381,7 +1473,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_cannot_be_completed() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_cannot_be_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1389,7 +1481,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reply_event_from_single_state_works_for_invalid_message() {
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_invalid_message", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), "".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1397,7 +1489,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqack_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_reqack_event_from_single_state_works", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::ReqACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1405,7 +1497,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_single_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_single_state_works_for_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1414,7 +1506,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reqnack_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reqnack_event_from_single_state_works_for_not_completed", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1422,7 +1514,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_single_state_works_for_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_single_state_works_for_completed", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
@@ -1431,7 +1523,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_reject_event_from_single_state_works_for_not_completed() {
             let mut request_handler = _request_handler("request_handler_process_reject_event_from_single_state_works_for_not_completed", 1, 3);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "{}".to_string(), NODE.to_string(), REQ_ID.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1439,7 +1531,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1447,15 +1539,51 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_single_state_works_for_cannot_be_completed() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works_for_cannot_be_completed", 1, 1);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
+        #[test]
+        fn request_handler_process_timeout_event_from_single_state_works_for_retry_policy() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works_for_retry_policy", 1, 1);
+            let read_policy = ReadPolicy { retries: 1, retry_backoff: 300, ..ReadPolicy::default() };
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), read_policy)));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            match request_handler.request_wrapper.as_ref().unwrap().state {
+                RequestState::Single(ref state) => {
+                    assert_eq!(1, state.attempt);
+                    assert_match!(Some(Some(NetworkerEvent::Schedule(_, _, 300))), state.networker.borrow().events.last());
+                }
+                _ => panic!("unexpected state")
+            }
+
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), RETRY_TIMER.to_string())));
+            assert_match!(RequestState::Single(_), request_handler.request_wrapper.as_ref().unwrap().state);
+
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
+            assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
+        }
+
+        #[test]
+        fn request_handler_process_timeout_event_from_single_state_works_for_hedge_timer() {
+            let mut request_handler = _request_handler("request_handler_process_timeout_event_from_single_state_works_for_hedge_timer", 1, 2);
+            let read_policy = ReadPolicy { hedge_after: Some(100), ..ReadPolicy::default() };
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), read_policy)));
+            request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), HEDGE_TIMER.to_string())));
+            match request_handler.request_wrapper.unwrap().state {
+                RequestState::Single(state) => {
+                    assert!(state.timeout_nodes.is_empty());
+                    assert_match!(Some(Some(NetworkerEvent::Resend(_, _))), state.networker.borrow().events.last());
+                }
+                _ => panic!("unexpected state")
+            }
+        }
+
         #[test]
         fn request_handler_process_terminate_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_terminate_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1463,7 +1591,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_single_state_works", 1, 2);
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Pong));
             assert_match!(RequestState::Single(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1474,7 +1602,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached_with_mixed_msgs", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), REJECT_REPLY.to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), NACK_REPLY.to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -1497,7 +1625,7 @@ pub mod tests {
             // some nodes accept, some reject and some nack.  the end result is consensus should not be reached
             let mut request_handler = _request_handler("request_handler_process_reply_event_from_single_state_works_for_consensus_reached_with_0_concensus", 1, 4);
 
-            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None))));
+            request_handler.process_event(Some(RequestEvent::CustomSingleRequest(MESSAGE.to_string(), REQ_ID.to_string(), None, (None, None), ReadPolicy::default())));
             request_handler.process_event(Some(RequestEvent::Reply(Reply::default(), SIMPLE_REPLY.to_string(), NODE.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::Reject(Response::default(), "".to_string(), NODE_2.to_string(), REQ_ID.to_string())));
             request_handler.process_event(Some(RequestEvent::ReqNACK(Response::default(), "".to_string(), NODE_3.to_string(), REQ_ID.to_string())));
@@ -12,7 +12,7 @@ use serde_json;
 use serde_json::Value as SJsonValue;
 
 use indy_api_types::ErrorCode;
-use crate::domain::ledger::{constants, request::ProtocolVersion};
+use crate::domain::ledger::{constants, request::ProtocolVersion, txn::TxnInclusionProof};
 use indy_api_types::errors::prelude::*;
 use crate::services::pool::events::{REQUESTS_FOR_STATE_PROOFS, REQUESTS_FOR_MULTI_STATE_PROOFS};
 use indy_utils::crypto::hash::hash as openssl_hash;
@@ -145,6 +145,80 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
     true
 }
 
+pub fn parse_reply_for_txn_inclusion_proof(json_msg: &SJsonValue) -> Result<TxnInclusionProof, String> {
+    trace!("parse_reply_for_txn_inclusion_proof: >>> json_msg: {:?}", json_msg);
+
+    if json_msg["type"].as_str() != Some(constants::GET_TXN) {
+        return Err("Reply is not for GET_TXN request".to_string());
+    }
+
+    let parsed_data: SJsonValue = match json_msg["data"] {
+        SJsonValue::String(ref str) => serde_json::from_str(str).map_err(|_| "Data field is invalid json".to_string())?,
+        ref data => data.clone()
+    };
+
+    let seq_no = parsed_data["txnMetadata"]["seqNo"].as_u64()
+        .or_else(|| json_msg["seqNo"].as_u64())
+        .ok_or_else(|| "No transaction seq_no for this proof".to_string())?;
+
+    let audit_path: Vec<String> = serde_json::from_value(parsed_data["auditPath"].clone())
+        .map_err(|_| "No proof".to_string())?;
+
+    let parsed_sp = _parse_reply_for_builtin_sp(json_msg, constants::GET_TXN, seq_no.to_string().as_bytes())
+        .and_then(|mut parsed_sps| parsed_sps.pop())
+        .ok_or_else(|| "No audit proof for the transaction".to_string())?;
+
+    if parsed_sp.multi_signature.is_null() {
+        return Err("No multi signature for this proof".to_string());
+    }
+
+    let (ledger_size, value) = match parsed_sp.kvs_to_verify {
+        KeyValuesInSP::Simple(KeyValueSimpleData { mut kvs, verification_type: KeyValueSimpleDataVerificationType::MerkleTree(length) }) =>
+            (length, kvs.pop().and_then(|(_, value)| value)),
+        _ => return Err("Unexpected proof format".to_string())
+    };
+
+    let txn = value
+        .ok_or_else(|| "Transaction not found".to_string())
+        .and_then(|value| serde_json::from_str::<SJsonValue>(&value).map_err(|err| err.to_string()))?;
+
+    let proof = TxnInclusionProof {
+        seq_no,
+        ledger_size,
+        root_hash: parsed_sp.root_hash,
+        audit_path,
+        txn,
+        multi_signature: parsed_sp.multi_signature,
+    };
+
+    trace!("parse_reply_for_txn_inclusion_proof: <<< proof: {:?}", proof);
+
+    Ok(proof)
+}
+
+pub fn verify_txn_inclusion_proof(proof: &TxnInclusionProof,
+                                  nodes: &Nodes,
+                                  f: usize,
+                                  gen: &Generator) -> bool {
+    trace!("verify_txn_inclusion_proof: >>> proof: {:?}", proof);
+
+    let parsed_sp = ParsedSP {
+        proof_nodes: base64::encode(json!(proof.audit_path).to_string().as_bytes()),
+        root_hash: proof.root_hash.clone(),
+        kvs_to_verify: KeyValuesInSP::Simple(KeyValueSimpleData {
+            kvs: vec![(base64::encode(proof.seq_no.to_string().as_bytes()), Some(proof.txn.to_string()))],
+            verification_type: KeyValueSimpleDataVerificationType::MerkleTree(proof.ledger_size),
+        }),
+        multi_signature: proof.multi_signature.clone(),
+    };
+
+    let res = verify_parsed_sp(vec![parsed_sp], nodes, f, gen);
+
+    trace!("verify_txn_inclusion_proof: <<< res: {:?}", res);
+
+    res
+}
+
 #[logfn(Trace)]
 pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Vec<u8>> {
     let type_ = json_msg["operation"]["type"].as_str()?;
@@ -1605,6 +1679,172 @@ mod tests {
                    }));
     }
 
+    mod txn_inclusion_proof {
+        use super::*;
+
+        use ursa::bls::{Signature, SignKey};
+        use crate::services::pool::request_handler::DEFAULT_GENERATOR;
+
+        const ROOT_HASH: &str = "CrA5sqYe3ruf2uY7d8re7ePmyHqptHqANtMZcfZd4BvK";
+
+        fn _generator() -> Generator {
+            Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap()
+        }
+
+        fn _nodes_and_multi_signature(participants: &[&str]) -> (Nodes, SJsonValue) {
+            let gen = _generator();
+            let value = json!({
+                "ledger_id": 1,
+                "pool_state_root_hash": "7siDH8Qanh82UviK4zjBSfLXcoCvLaeGkrByi1ow9Tsm",
+                "state_root_hash": "9i3tKoAJ4HQvXaCVhRkTyqvdU1QK4QUvjzyKAe2vrEEh",
+                "timestamp": 1558699624,
+                "txn_root_hash": ROOT_HASH,
+            });
+            let message = rmp_serde::to_vec_named(&value).unwrap();
+
+            let mut nodes = HashMap::new();
+            let mut signatures: Vec<Signature> = Vec::new();
+            for alias in ["Node1", "Node2", "Node3", "Node4"].iter() {
+                let sign_key = SignKey::new(None).unwrap();
+                nodes.insert(alias.to_string(), Some(VerKey::new(&gen, &sign_key).unwrap()));
+                if participants.contains(alias) {
+                    signatures.push(Bls::sign(&message, &sign_key).unwrap());
+                }
+            }
+
+            let signatures = signatures.iter().collect::<Vec<&Signature>>();
+            let signature = MultiSignature::new(signatures.as_slice()).unwrap();
+
+            (nodes, json!({
+                "signature": signature.as_bytes().to_base58(),
+                "participants": participants,
+                "value": value,
+            }))
+        }
+
+        fn _proof(multi_signature: SJsonValue) -> TxnInclusionProof {
+            TxnInclusionProof {
+                seq_no: 3,
+                ledger_size: 5,
+                root_hash: ROOT_HASH.to_string(),
+                audit_path: vec![
+                    "Gf9aBhHCtBpTYbJXQWnt1DU8q33hwi6nN4f3NhnsBgMZ".to_string(),
+                    "68TGAdRjeQ29eNcuFYhsX5uLakGQLgKMKp5wSyPzt9Nq".to_string(),
+                    "25KLEkkyCEPSBj4qMFE3AcH87mFocyJEuPJ5xzPGwDgz".to_string(),
+                ],
+                txn: json!({"3": "3"}),
+                multi_signature,
+            }
+        }
+
+        #[test]
+        fn verify_txn_inclusion_proof_works() {
+            let (nodes, multi_signature) = _nodes_and_multi_signature(&["Node1", "Node2", "Node3"]);
+            let proof = _proof(multi_signature);
+            assert!(verify_txn_inclusion_proof(&proof, &nodes, 1, &_generator()));
+        }
+
+        #[test]
+        fn verify_txn_inclusion_proof_works_for_not_enough_participants() {
+            let (nodes, multi_signature) = _nodes_and_multi_signature(&["Node1", "Node2"]);
+            let proof = _proof(multi_signature);
+            assert!(!verify_txn_inclusion_proof(&proof, &nodes, 1, &_generator()));
+        }
+
+        #[test]
+        fn verify_txn_inclusion_proof_works_for_unknown_bls_keys() {
+            let (_, multi_signature) = _nodes_and_multi_signature(&["Node1", "Node2", "Node3"]);
+            let (other_nodes, _) = _nodes_and_multi_signature(&["Node1", "Node2", "Node3"]);
+            let proof = _proof(multi_signature);
+            assert!(!verify_txn_inclusion_proof(&proof, &other_nodes, 1, &_generator()));
+        }
+
+        #[test]
+        fn verify_txn_inclusion_proof_works_for_changed_txn() {
+            let (nodes, multi_signature) = _nodes_and_multi_signature(&["Node1", "Node2", "Node3"]);
+            let mut proof = _proof(multi_signature);
+            proof.txn = json!({"3": "4"});
+            assert!(!verify_txn_inclusion_proof(&proof, &nodes, 1, &_generator()));
+        }
+
+        #[test]
+        fn verify_txn_inclusion_proof_works_for_changed_root_hash() {
+            let (nodes, multi_signature) = _nodes_and_multi_signature(&["Node1", "Node2", "Node3"]);
+            let mut proof = _proof(multi_signature);
+            proof.root_hash = "Gf9aBhHCtBpTYbJXQWnt1DU8q33hwi6nN4f3NhnsBgMZ".to_string();
+            assert!(!verify_txn_inclusion_proof(&proof, &nodes, 1, &_generator()));
+        }
+
+        #[test]
+        fn parse_reply_for_txn_inclusion_proof_works() {
+            let json_msg = json!({
+                "type": constants::GET_TXN,
+                "seqNo": 2,
+                "data": {
+                    "auditPath": ["1", "2"],
+                    "ledgerSize": 2,
+                    "rootHash": "123",
+                    "txn": {"test1": "test2"},
+                    "txnMetadata": {"seqNo": 2},
+                },
+                "state_proof": {
+                    "multi_signature": {"signature": "ms"}
+                }
+            });
+
+            let proof = parse_reply_for_txn_inclusion_proof(&json_msg).unwrap();
+
+            assert_eq!(proof, TxnInclusionProof {
+                seq_no: 2,
+                ledger_size: 2,
+                root_hash: "123".to_string(),
+                audit_path: vec!["1".to_string(), "2".to_string()],
+                txn: json!({"txn": {"test1": "test2"}, "txnMetadata": {"seqNo": 2}}),
+                multi_signature: json!({"signature": "ms"}),
+            });
+        }
+
+        #[test]
+        fn parse_reply_for_txn_inclusion_proof_works_for_no_multi_signature() {
+            let json_msg = json!({
+                "type": constants::GET_TXN,
+                "data": {
+                    "auditPath": ["1", "2"],
+                    "ledgerSize": 2,
+                    "rootHash": "123",
+                    "txn": {"test1": "test2"},
+                    "txnMetadata": {"seqNo": 2},
+                }
+            });
+
+            assert!(parse_reply_for_txn_inclusion_proof(&json_msg).is_err());
+        }
+
+        #[test]
+        fn parse_reply_for_txn_inclusion_proof_works_for_no_txn() {
+            let json_msg = json!({
+                "type": constants::GET_TXN,
+                "seqNo": 2,
+                "data": null,
+                "state_proof": {
+                    "multi_signature": {"signature": "ms"}
+                }
+            });
+
+            assert!(parse_reply_for_txn_inclusion_proof(&json_msg).is_err());
+        }
+
+        #[test]
+        fn parse_reply_for_txn_inclusion_proof_works_for_other_type() {
+            let json_msg = json!({
+                "type": constants::GET_NYM,
+                "data": null,
+            });
+
+            assert!(parse_reply_for_txn_inclusion_proof(&json_msg).is_err());
+        }
+    }
+
     #[test]
     fn transaction_handler_parse_generic_reply_for_proof_checking_works_for_plugged() {
         extern fn parse(msg: *const c_char, parsed: *mut *const c_char) -> ErrorCode {
@@ -2,6 +2,9 @@ use regex::Regex;
 
 lazy_static! {
     pub static ref REGEX: Regex = Regex::new("^[a-z0-9]+:([a-z0-9]+):(.*)$").unwrap();
+    pub static ref DID_METHOD_REGEX: Regex = Regex::new("did:([a-z0-9]+):").unwrap();
+    pub static ref INDY_NAMESPACE_REGEX: Regex = Regex::new("did:indy:((?:[a-z][a-z0-9]*:)*[a-z][a-z0-9]*):([1-9A-HJ-NP-Za-km-z]{21,22})").unwrap();
+    pub static ref INDY_NO_NAMESPACE_REGEX: Regex = Regex::new("did:indy:([1-9A-HJ-NP-Za-km-z]{21,22})(:|/|#|$)").unwrap();
 }
 
 pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
@@ -9,6 +12,9 @@ pub fn qualify(entity: &str, prefix: &str, method: &str) -> String {
 }
 
 pub fn to_unqualified(entity: &str) -> String {
+    // unqualified DID doesn't keep the network, so `did:indy` namespace is dropped as well
+    let entity = &strip_indy_namespace(entity);
+
     match REGEX.captures(entity) {
         None => entity.to_string(),
         Some(caps) => {
@@ -30,6 +36,34 @@ pub fn is_fully_qualified(entity: &str) -> bool {
     REGEX.is_match(&entity)
 }
 
+/// Returns namespace of the first `did:indy` DID found in entity.
+/// `did:indy:sovrin:staging:<id>` has namespace `sovrin:staging`.
+pub fn indy_namespace(entity: &str) -> Option<String> {
+    INDY_NAMESPACE_REGEX.captures(entity)
+        .and_then(|caps| caps.get(1).map(|m| m.as_str().to_string()))
+}
+
+/// Removes namespaces of all `did:indy` DIDs found in entity: `did:indy:sovrin:<id>` becomes `did:indy:<id>`.
+pub fn strip_indy_namespace(entity: &str) -> String {
+    INDY_NAMESPACE_REGEX.replace_all(entity, "did:indy:$2").to_string()
+}
+
+/// Adds namespace to all `did:indy` DIDs found in entity which don't have one.
+pub fn add_indy_namespace(entity: &str, namespace: &str) -> String {
+    INDY_NO_NAMESPACE_REGEX.replace_all(entity, format!("did:indy:{}:$1$2", namespace).as_str()).to_string()
+}
+
+/// Returns DID method namespace of the first DID found in entity:
+/// `did:indy:<namespace>` for `did:indy` DIDs and `did:<method>` for all other methods.
+pub fn did_namespace(entity: &str) -> Option<String> {
+    if let Some(namespace) = indy_namespace(entity) {
+        return Some(format!("did:indy:{}", namespace));
+    }
+
+    DID_METHOD_REGEX.captures(entity)
+        .and_then(|caps| caps.get(1).map(|m| format!("did:{}", m.as_str())))
+}
+
 macro_rules! qualifiable_type (($newtype:ident) => (
 
     #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
@@ -51,4 +85,52 @@ macro_rules! qualifiable_type (($newtype:ident) => (
             self.0.starts_with($newtype::PREFIX) && qualifier::is_fully_qualified(&self.0)
         }
     }
-));
\ No newline at end of file
+));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+
+    #[test]
+    fn indy_namespace_works() {
+        assert_eq!(Some("sovrin".to_string()), indy_namespace(&format!("did:indy:sovrin:{}", DID)));
+        assert_eq!(Some("sovrin:staging".to_string()), indy_namespace(&format!("did:indy:sovrin:staging:{}", DID)));
+        assert_eq!(Some("idunion".to_string()), indy_namespace(&format!("schema:indy:did:indy:idunion:{}:2:gvt:1.0", DID)));
+        assert_eq!(None, indy_namespace(&format!("did:indy:{}", DID)));
+        assert_eq!(None, indy_namespace(&format!("did:sov:{}", DID)));
+        assert_eq!(None, indy_namespace(DID));
+    }
+
+    #[test]
+    fn strip_indy_namespace_works() {
+        assert_eq!(format!("did:indy:{}", DID), strip_indy_namespace(&format!("did:indy:sovrin:staging:{}", DID)));
+        assert_eq!(format!("schema:indy:did:indy:{}:2:gvt:1.0", DID),
+                   strip_indy_namespace(&format!("schema:indy:did:indy:sovrin:{}:2:gvt:1.0", DID)));
+        assert_eq!(format!("did:sov:{}", DID), strip_indy_namespace(&format!("did:sov:{}", DID)));
+    }
+
+    #[test]
+    fn add_indy_namespace_works() {
+        assert_eq!(format!("did:indy:sovrin:staging:{}", DID), add_indy_namespace(&format!("did:indy:{}", DID), "sovrin:staging"));
+        assert_eq!(format!("schema:indy:did:indy:sovrin:{}:2:gvt:1.0", DID),
+                   add_indy_namespace(&format!("schema:indy:did:indy:{}:2:gvt:1.0", DID), "sovrin"));
+        assert_eq!(format!("did:indy:idunion:{}", DID), add_indy_namespace(&format!("did:indy:idunion:{}", DID), "sovrin"));
+        assert_eq!(format!("did:sov:{}", DID), add_indy_namespace(&format!("did:sov:{}", DID), "sovrin"));
+    }
+
+    #[test]
+    fn to_unqualified_works_for_indy_namespace() {
+        assert_eq!(DID, to_unqualified(&format!("did:indy:sovrin:staging:{}", DID)));
+        assert_eq!(DID, to_unqualified(&format!("did:indy:{}", DID)));
+        assert_eq!(DID, to_unqualified(&format!("did:sov:{}", DID)));
+    }
+
+    #[test]
+    fn did_namespace_works() {
+        assert_eq!(Some("did:indy:sovrin:staging".to_string()), did_namespace(&format!("did:indy:sovrin:staging:{}", DID)));
+        assert_eq!(Some("did:sov".to_string()), did_namespace(&format!("creddef:sov:did:sov:{}:3:CL:1:tag", DID)));
+        assert_eq!(None, did_namespace(&format!("{}:2:gvt:1.0", DID)));
+    }
+}
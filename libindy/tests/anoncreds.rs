@@ -3180,6 +3180,149 @@ mod high_cases {
         }
     }
 
+    mod prover_create_proof_auto {
+        use super::*;
+
+        fn _proof_request() -> String {
+            json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
+                   }),
+                   "attr2_referent": json!({
+                       "name":"phone"
+                   })
+               }),
+               "requested_predicates": json!({
+                   "predicate1_referent": json!({
+                       "name":"age",
+                       "p_type":">=",
+                       "p_value":18,
+                       "restrictions": json!({ "cred_def_id": anoncreds::issuer_1_gvt_cred_def_id() })
+                   })
+               }),
+            }).to_string()
+        }
+
+        fn _options() -> String {
+            json!({
+                "policy": "fewest_credentials",
+                "self_attested_attributes": json!({ "attr2_referent": "8-800-300" })
+            }).to_string()
+        }
+
+        #[test]
+        fn prover_create_proof_auto_works() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let (proof_json, requested_credentials_json) = anoncreds::prover_create_proof_auto(wallet_handle,
+                                                                                              &_proof_request(),
+                                                                                              COMMON_MASTER_SECRET,
+                                                                                              &anoncreds::schemas_for_proof(),
+                                                                                              &anoncreds::cred_defs_for_proof(),
+                                                                                              "{}",
+                                                                                              Some(&_options())).unwrap();
+
+            let requested_credentials: serde_json::Value = serde_json::from_str(&requested_credentials_json).unwrap();
+            assert_eq!(json!({ "attr2_referent": "8-800-300" }), requested_credentials["self_attested_attributes"]);
+            assert_eq!(CREDENTIAL1_ID, requested_credentials["requested_attributes"]["attr1_referent"]["cred_id"]);
+            assert_eq!(true, requested_credentials["requested_attributes"]["attr1_referent"]["revealed"]);
+            assert_eq!(CREDENTIAL1_ID, requested_credentials["requested_predicates"]["predicate1_referent"]["cred_id"]);
+
+            let proof: Proof = serde_json::from_str(&proof_json).unwrap();
+            assert_eq!("Alex", proof.requested_proof.revealed_attrs["attr1_referent"].raw);
+            assert_eq!("8-800-300", proof.requested_proof.self_attested_attrs["attr2_referent"]);
+
+            let valid = anoncreds::verifier_verify_proof(&_proof_request(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_create_proof_auto_works_for_unsatisfied_referents() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_create_proof_auto(wallet_handle,
+                                                          &_proof_request().replace("\"p_value\":18", "\"p_value\":100"),
+                                                          COMMON_MASTER_SECRET,
+                                                          &anoncreds::schemas_for_proof(),
+                                                          &anoncreds::cred_defs_for_proof(),
+                                                          "{}",
+                                                          None);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn prover_create_proof_auto_works_for_invalid_options() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let res = anoncreds::prover_create_proof_auto(wallet_handle,
+                                                          &_proof_request(),
+                                                          COMMON_MASTER_SECRET,
+                                                          &anoncreds::schemas_for_proof(),
+                                                          &anoncreds::cred_defs_for_proof(),
+                                                          "{}",
+                                                          Some(r#"{"policy": "random"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+    }
+
+    mod prover_refresh_revocation_states {
+        use super::*;
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_no_stored_states() {
+            let setup = Setup::wallet();
+
+            let refreshed_json = anoncreds::prover_refresh_revocation_states(1, setup.wallet_handle, 1).unwrap();
+            assert_eq!("[]", refreshed_json);
+        }
+
+        #[test]
+        fn prover_refresh_revocation_states_works_for_invalid_wallet_handle() {
+            let res = anoncreds::prover_refresh_revocation_states(1, INVALID_WALLET_HANDLE, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
+    mod prover_prune_revocation_states {
+        use super::*;
+
+        #[test]
+        fn prover_prune_revocation_states_works_for_no_stored_states() {
+            let setup = Setup::wallet();
+
+            let pruned_json = anoncreds::prover_prune_revocation_states(setup.wallet_handle, u64::max_value()).unwrap();
+            assert_eq!("[]", pruned_json);
+        }
+
+        #[test]
+        fn prover_prune_revocation_states_works_for_invalid_wallet_handle() {
+            let res = anoncreds::prover_prune_revocation_states(INVALID_WALLET_HANDLE, 1);
+            assert_code!(ErrorCode::WalletInvalidHandle, res);
+        }
+    }
+
     mod verifier_verify_proof {
         use super::*;
 
@@ -3344,6 +3487,122 @@ mod high_cases {
         assert!(!valid);
     }
 
+    mod verifier_verify_proof_with_report {
+        use super::*;
+
+        fn _reason_code(report: &serde_json::Value) -> &str {
+            report["reasons"][0]["code"].as_str().unwrap()
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_report_works_for_correct_proof() {
+            let report = anoncreds::verifier_verify_proof_with_report(&anoncreds::proof_request_attr(),
+                                                                      &anoncreds::proof_json(),
+                                                                      &anoncreds::schemas_for_proof(),
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(true, report["valid"]);
+            assert_eq!(json!({"valid": true, "sub_proof_index": 0, "reasons": []}), report["attributes"]["attr1_referent"]);
+            assert_eq!(json!([{"valid": true, "sub_proof_index": 0, "reasons": []}]), report["identifiers"]);
+            assert_eq!(json!([]), report["revocation"]);
+            assert_eq!(json!({"valid": true, "reasons": []}), report["crypto"]);
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_report_works_for_encoding_mismatch() {
+            let proof_json = anoncreds::proof_json().replace(r#""raw":"Alex","encoded":"1139481716457488690172217916278103335""#,
+                                                             r#""raw":"Alex","encoded":"1111111111111111111111111111111111111""#);
+
+            let report = anoncreds::verifier_verify_proof_with_report(&anoncreds::proof_request_attr(),
+                                                                      &proof_json,
+                                                                      &anoncreds::schemas_for_proof(),
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!(false, report["attributes"]["attr1_referent"]["valid"]);
+            assert_eq!("encoding_mismatch", _reason_code(&report["attributes"]["attr1_referent"]));
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_report_works_for_missing_referent() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name"
+                   }),
+                   "attr2_referent": json!({
+                       "name":"sex"
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let report = anoncreds::verifier_verify_proof_with_report(&proof_req_json,
+                                                                      &anoncreds::proof_json(),
+                                                                      &anoncreds::schemas_for_proof(),
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!(true, report["attributes"]["attr1_referent"]["valid"]);
+            assert_eq!("missing_in_proof", _reason_code(&report["attributes"]["attr2_referent"]));
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_report_works_for_missing_schema() {
+            let report = anoncreds::verifier_verify_proof_with_report(&anoncreds::proof_request_attr(),
+                                                                      &anoncreds::proof_json(),
+                                                                      "{}",
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!("schema_not_found", _reason_code(&report["identifiers"][0]));
+            assert_eq!("not_evaluated", _reason_code(&report["crypto"]));
+        }
+
+        #[test]
+        fn verifier_verify_proof_with_report_works_for_restriction_not_satisfied() {
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({
+                   "attr1_referent": json!({
+                       "name":"name",
+                       "restrictions": json!({"schema_name": "xyz"})
+                   })
+               }),
+               "requested_predicates": json!({}),
+            }).to_string();
+
+            let report = anoncreds::verifier_verify_proof_with_report(&proof_req_json,
+                                                                      &anoncreds::proof_json(),
+                                                                      &anoncreds::schemas_for_proof(),
+                                                                      &anoncreds::cred_defs_for_proof(),
+                                                                      "{}",
+                                                                      "{}").unwrap();
+            let report: serde_json::Value = serde_json::from_str(&report).unwrap();
+
+            assert_eq!(false, report["valid"]);
+            assert_eq!("restriction_not_satisfied", _reason_code(&report["attributes"]["attr1_referent"]));
+            assert_eq!(json!({"valid": true, "reasons": []}), report["crypto"]);
+        }
+    }
+
     mod verifier_verify_proof_with_proof_req_restrictions {
         use super::*;
 
@@ -3797,6 +4056,640 @@ mod high_cases {
             assert_eq!(anoncreds::local_gvt_cred_def_id(), cred_req.cred_def_id.0);
         }
     }
+
+    mod w3c {
+        use super::*;
+
+        #[test]
+        fn credential_to_w3c_works_for_round_trip() {
+            let (credential_def_json, credential_offer, _, _) = anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+            let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("credential_to_w3c_works_for_round_trip").unwrap();
+
+            anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_req, credential_req_meta) = anoncreds::prover_create_credential_req(prover_wallet_handle,
+                                                                                                DID_MY1,
+                                                                                                &credential_offer,
+                                                                                                credential_def_json,
+                                                                                                COMMON_MASTER_SECRET).unwrap();
+
+            let (credential_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                              &credential_offer,
+                                                                              &credential_req,
+                                                                              &anoncreds::gvt_credential_values_json(),
+                                                                              None,
+                                                                              None).unwrap();
+
+            let w3c_credential_json = anoncreds::credential_to_w3c(&credential_json).unwrap();
+            let w3c_credential: serde_json::Value = serde_json::from_str(&w3c_credential_json).unwrap();
+
+            assert_eq!(json!(["VerifiableCredential", "AnonCredsCredential"]), w3c_credential["type"]);
+            assert_eq!(format!("did:sov:{}", ISSUER_DID), w3c_credential["issuer"].as_str().unwrap());
+            assert_eq!(json!("Alex"), w3c_credential["credentialSubject"]["name"]);
+            assert_eq!(json!("AnonCredsProof2023"), w3c_credential["proof"]["type"]);
+
+            let restored_credential_json = anoncreds::credential_from_w3c(&w3c_credential_json).unwrap();
+
+            let credential: serde_json::Value = serde_json::from_str(&credential_json).unwrap();
+            let restored_credential: serde_json::Value = serde_json::from_str(&restored_credential_json).unwrap();
+            assert_eq!(credential, restored_credential);
+
+            anoncreds::prover_store_credential(prover_wallet_handle,
+                                               CREDENTIAL1_ID,
+                                               &credential_req_meta,
+                                               &restored_credential_json,
+                                               &credential_def_json,
+                                               None).unwrap();
+
+            wallet::close_wallet(wallet_handle).unwrap();
+            wallet::close_wallet(prover_wallet_handle).unwrap();
+            wallet::delete_wallet(&prover_wallet_config, WALLET_CREDENTIALS).unwrap();
+        }
+
+        #[test]
+        fn proof_to_w3c_presentation_works_for_round_trip() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c_presentation(&anoncreds::proof_json(),
+                                                                             &anoncreds::proof_request_attr()).unwrap();
+            let w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+
+            assert_eq!(json!(["VerifiablePresentation", "AnonCredsPresentation"]), w3c_presentation["type"]);
+            assert_eq!(json!("123432421212"), w3c_presentation["proof"]["challenge"]);
+            assert_eq!(1, w3c_presentation["verifiableCredential"].as_array().unwrap().len());
+            assert_eq!(json!("Alex"), w3c_presentation["verifiableCredential"][0]["credentialSubject"]["name"]);
+
+            let proof_json = anoncreds::proof_from_w3c_presentation(&w3c_presentation_json, &anoncreds::proof_request_attr()).unwrap();
+
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr(),
+                                                         &proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        fn proof_to_w3c_presentation_works_for_predicates() {
+            anoncreds::init_common_wallet();
+
+            let wallet_handle = wallet::open_wallet(ANONCREDS_WALLET_CONFIG, WALLET_CREDENTIALS).unwrap();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "revealed":true })
+                 }),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+                 })
+            }).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(wallet_handle,
+                                                            &anoncreds::proof_request_attr_and_predicate(),
+                                                            &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET,
+                                                            &anoncreds::schemas_for_proof(),
+                                                            &anoncreds::cred_defs_for_proof(),
+                                                            "{}").unwrap();
+
+            let w3c_presentation_json = anoncreds::proof_to_w3c_presentation(&proof_json,
+                                                                             &anoncreds::proof_request_attr_and_predicate()).unwrap();
+            let w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+
+            let credential_subject = &w3c_presentation["verifiableCredential"][0]["credentialSubject"];
+            assert_eq!(json!("Alex"), credential_subject["name"]);
+            assert_eq!(json!([{"type": "AnonCredsPredicate", "predicate": ">=", "value": 18}]), credential_subject["age"]);
+
+            let restored_proof_json = anoncreds::proof_from_w3c_presentation(&w3c_presentation_json,
+                                                                             &anoncreds::proof_request_attr_and_predicate()).unwrap();
+
+            let valid = anoncreds::verifier_verify_proof(&anoncreds::proof_request_attr_and_predicate(),
+                                                         &restored_proof_json,
+                                                         &anoncreds::schemas_for_proof(),
+                                                         &anoncreds::cred_defs_for_proof(),
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+
+            wallet::close_wallet(wallet_handle).unwrap();
+        }
+
+        #[test]
+        fn proof_from_w3c_presentation_works_for_invalid_presentation() {
+            let res = anoncreds::proof_from_w3c_presentation(r#"{"type": ["VerifiablePresentation"]}"#, &anoncreds::proof_request_attr());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn proof_from_w3c_presentation_works_for_value_moved_to_other_attribute() {
+            let w3c_presentation_json = anoncreds::proof_to_w3c_presentation(&anoncreds::proof_json(),
+                                                                             &anoncreds::proof_request_attr()).unwrap();
+            let mut w3c_presentation: serde_json::Value = serde_json::from_str(&w3c_presentation_json).unwrap();
+
+            let credential_subject = w3c_presentation["verifiableCredential"][0]["credentialSubject"].as_object_mut().unwrap();
+            let value = credential_subject.remove("name").unwrap();
+            credential_subject.insert("sex".to_string(), value);
+
+            let res = anoncreds::proof_from_w3c_presentation(&w3c_presentation.to_string(), &anoncreds::proof_request_attr());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
+    mod encode_credential_values {
+        use super::*;
+
+        #[test]
+        fn encode_credential_values_works() {
+            let cred_values_json = anoncreds::encode_credential_values(r#"{
+                "name": "Alex",
+                "age": {"value": 28, "encoding": {"type": "integer"}},
+                "birthdate": {"value": "1992-05-15", "encoding": {"type": "date"}},
+                "balance": {"value": "-12.5", "encoding": {"type": "decimal", "scale": 2}},
+                "verified": {"value": true, "encoding": {"type": "boolean"}}
+            }"#).unwrap();
+            let cred_values: serde_json::Value = serde_json::from_str(&cred_values_json).unwrap();
+
+            assert_eq!(json!({"raw": "28", "encoded": "28"}), cred_values["age"]);
+            assert_eq!(json!({"raw": "1992-05-15", "encoded": "8170"}), cred_values["birthdate"]);
+            assert_eq!(json!({"raw": "-12.5", "encoded": "-1250"}), cred_values["balance"]);
+            assert_eq!(json!({"raw": "true", "encoded": "1"}), cred_values["verified"]);
+            assert_eq!(json!("99262857098057710338306967609588410025648622308394250666849665532448612202874"), cred_values["name"]["encoded"]);
+        }
+
+        #[test]
+        fn encode_credential_values_works_for_invalid_date() {
+            let res = anoncreds::encode_credential_values(r#"{"birthdate": {"value": "1992-02-30", "encoding": {"type": "date"}}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn encode_credential_values_works_for_too_big_decimal_scale() {
+            let res = anoncreds::encode_credential_values(r#"{"price": {"value": "12.5", "encoding": {"type": "decimal", "scale": 4000000000}}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn decode_credential_value_works() {
+            assert_eq!("1992-05-15", anoncreds::decode_credential_value("8170", r#"{"type": "date"}"#).unwrap());
+            assert_eq!("-12.50", anoncreds::decode_credential_value("-1250", r#"{"type": "decimal", "scale": 2}"#).unwrap());
+        }
+
+        #[test]
+        fn build_predicate_info_works_for_date() {
+            let predicate_info_json = anoncreds::build_predicate_info("birthdate", "<", "2000-01-01", r#"{"type": "date"}"#).unwrap();
+            let predicate_info: serde_json::Value = serde_json::from_str(&predicate_info_json).unwrap();
+
+            assert_eq!(json!("birthdate"), predicate_info["name"]);
+            assert_eq!(json!("<"), predicate_info["p_type"]);
+            assert_eq!(json!(10957), predicate_info["p_value"]);
+        }
+
+        #[test]
+        fn build_predicate_info_works_for_invalid_predicate_type() {
+            let res = anoncreds::build_predicate_info("birthdate", "!=", "2000-01-01", r#"{"type": "date"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn typed_values_work_for_date_predicate_proof() {
+            let setup = Setup::wallet();
+
+            let (schema_id, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID, "bio", SCHEMA_VERSION, r#"["name","birthdate"]"#).unwrap();
+
+            let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(setup.wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               &schema_json,
+                                                                                               TAG_1,
+                                                                                               None,
+                                                                                               Some(&anoncreds::default_cred_def_config())).unwrap();
+
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(setup.wallet_handle, &cred_def_id).unwrap();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(setup.wallet_handle,
+                                                                                                  DID_MY1,
+                                                                                                  &cred_offer_json,
+                                                                                                  &cred_def_json,
+                                                                                                  COMMON_MASTER_SECRET).unwrap();
+
+            let cred_values_json = anoncreds::encode_credential_values(r#"{
+                "name": "Alex",
+                "birthdate": {"value": "1992-05-15", "encoding": {"type": "date"}}
+            }"#).unwrap();
+
+            let (cred_json, _, _) = anoncreds::issuer_create_credential(setup.wallet_handle,
+                                                                        &cred_offer_json,
+                                                                        &cred_req_json,
+                                                                        &cred_values_json,
+                                                                        None,
+                                                                        None).unwrap();
+
+            anoncreds::prover_store_credential(setup.wallet_handle,
+                                               CREDENTIAL1_ID,
+                                               &cred_req_metadata_json,
+                                               &cred_json,
+                                               &cred_def_json,
+                                               None).unwrap();
+
+            let predicate_info_json = anoncreds::build_predicate_info("birthdate", "<", "2000-01-01", r#"{"type": "date"}"#).unwrap();
+
+            let proof_req_json = json!({
+               "nonce":"123432421212",
+               "name":"proof_req_1",
+               "version":"0.1",
+               "requested_attributes": json!({}),
+               "requested_predicates": json!({
+                   "predicate1_referent": serde_json::from_str::<serde_json::Value>(&predicate_info_json).unwrap()
+               }),
+            }).to_string();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({}),
+                 "requested_predicates": json!({
+                    "predicate1_referent": json!({ "cred_id": CREDENTIAL1_ID })
+                 })
+            }).to_string();
+
+            let schemas_json = json!({
+                schema_id.clone(): serde_json::from_str::<serde_json::Value>(&schema_json).unwrap()
+            }).to_string();
+
+            let cred_defs_json = json!({
+                cred_def_id.clone(): serde_json::from_str::<serde_json::Value>(&cred_def_json).unwrap()
+            }).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(setup.wallet_handle,
+                                                            &proof_req_json,
+                                                            &requested_credentials_json,
+                                                            COMMON_MASTER_SECRET,
+                                                            &schemas_json,
+                                                            &cred_defs_json,
+                                                            "{}").unwrap();
+
+            let valid = anoncreds::verifier_verify_proof(&proof_req_json,
+                                                         &proof_json,
+                                                         &schemas_json,
+                                                         &cred_defs_json,
+                                                         "{}",
+                                                         "{}").unwrap();
+            assert!(valid);
+        }
+    }
+
+    mod issuer_revoc_reg_pool {
+        use super::*;
+        use crate::utils::blob_storage;
+        use indy::{IndyError, WalletHandle};
+
+        fn _revocation_cred_def(wallet_handle: WalletHandle) -> (String, String) {
+            anoncreds::issuer_create_credential_definition(wallet_handle,
+                                                           ISSUER_DID,
+                                                           &anoncreds::gvt_schema_json(),
+                                                           TAG_1,
+                                                           None,
+                                                           Some(&anoncreds::revocation_cred_def_config())).unwrap()
+        }
+
+        #[test]
+        fn issuer_create_revoc_reg_pool_works() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, _) = _revocation_cred_def(setup.wallet_handle);
+
+            let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+            let pool_json = anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle,
+                                                                    ISSUER_DID,
+                                                                    &cred_def_id,
+                                                                    r#"{"max_cred_num": 2}"#,
+                                                                    tails_writer_handle).unwrap();
+            let pool: serde_json::Value = serde_json::from_str(&pool_json).unwrap();
+
+            assert!(pool["active"].as_str().unwrap().ends_with(":pool_1"));
+            assert_eq!(1, pool["registries"].as_array().unwrap().len());
+            assert_eq!(pool["active"], pool["unpublished"][0]["rev_reg_id"]);
+
+            assert_eq!(pool_json, anoncreds::issuer_get_revoc_reg_pool(setup.wallet_handle, &cred_def_id).unwrap());
+        }
+
+        #[test]
+        fn issuer_create_revoc_reg_pool_works_for_duplicate() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, _) = _revocation_cred_def(setup.wallet_handle);
+
+            let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+            anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle, ISSUER_DID, &cred_def_id, r#"{"max_cred_num": 2}"#, tails_writer_handle).unwrap();
+
+            let res = anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle, ISSUER_DID, &cred_def_id, r#"{"max_cred_num": 2}"#, tails_writer_handle);
+            assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+        }
+
+        #[test]
+        fn issuer_create_revoc_reg_pool_works_for_invalid_threshold() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, _) = _revocation_cred_def(setup.wallet_handle);
+
+            let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+            let res = anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle, ISSUER_DID, &cred_def_id, r#"{"rollover_threshold": 0}"#, tails_writer_handle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        fn _issue_from_pool(wallet_handle: WalletHandle, cred_def_id: &str, cred_def_json: &str,
+                            blob_storage_reader_handle: i32) -> Result<String, IndyError> {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).unwrap();
+
+            let (cred_req_json, _) = anoncreds::prover_create_credential_req(wallet_handle,
+                                                                             DID_MY1,
+                                                                             &cred_offer_json,
+                                                                             cred_def_json,
+                                                                             COMMON_MASTER_SECRET).unwrap();
+
+            let (cred_json, cred_rev_id, revoc_reg_delta_json) =
+                anoncreds::issuer_create_credential_from_revoc_reg_pool(wallet_handle,
+                                                                        &cred_offer_json,
+                                                                        &cred_req_json,
+                                                                        &anoncreds::gvt_credential_values_json(),
+                                                                        blob_storage_reader_handle)?;
+            assert!(cred_rev_id.is_some());
+            assert!(revoc_reg_delta_json.is_some());
+
+            let cred: serde_json::Value = serde_json::from_str(&cred_json).unwrap();
+            Ok(cred["rev_reg_id"].as_str().unwrap().to_string())
+        }
+
+        fn _get_pool(wallet_handle: WalletHandle, cred_def_id: &str) -> serde_json::Value {
+            let pool_json = anoncreds::issuer_get_revoc_reg_pool(wallet_handle, cred_def_id).unwrap();
+            serde_json::from_str(&pool_json).unwrap()
+        }
+
+        #[test]
+        fn issuer_create_credential_from_revoc_reg_pool_works_for_rollover() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, cred_def_json) = _revocation_cred_def(setup.wallet_handle);
+
+            let tails_writer_config = anoncreds::tails_writer_config();
+            let tails_writer_handle = blob_storage::open_writer("default", &tails_writer_config).unwrap();
+
+            anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle,
+                                                    ISSUER_DID,
+                                                    &cred_def_id,
+                                                    r#"{"max_cred_num": 2, "issuance_type": "ISSUANCE_ON_DEMAND", "rollover_threshold": 50}"#,
+                                                    tails_writer_handle).unwrap();
+
+            let blob_storage_reader_handle = blob_storage::open_reader(TYPE, &tails_writer_config).unwrap();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+            let first_rev_reg_id = _issue_from_pool(setup.wallet_handle, &cred_def_id, &cred_def_json, blob_storage_reader_handle).unwrap();
+            assert_eq!(first_rev_reg_id, _issue_from_pool(setup.wallet_handle, &cred_def_id, &cred_def_json, blob_storage_reader_handle).unwrap());
+
+            let pool = _get_pool(setup.wallet_handle, &cred_def_id);
+            assert_eq!(json!(true), pool["rollover_needed"]);
+            assert_eq!(serde_json::Value::Null, pool["next"]);
+
+            // active registry is full and there is no next one
+            let res = _issue_from_pool(setup.wallet_handle, &cred_def_id, &cred_def_json, blob_storage_reader_handle);
+            assert_code!(ErrorCode::AnoncredsRevocationRegistryFullError, res);
+
+            let pool_json = anoncreds::issuer_rollover_revoc_reg_pool(setup.wallet_handle, &cred_def_id, tails_writer_handle).unwrap();
+            let pool: serde_json::Value = serde_json::from_str(&pool_json).unwrap();
+
+            let next_rev_reg_id = pool["next"].as_str().unwrap().to_string();
+            assert_ne!(first_rev_reg_id, next_rev_reg_id);
+            assert_eq!(json!(false), pool["rollover_needed"]);
+            assert_eq!(2, pool["registries"].as_array().unwrap().len());
+            assert_eq!(2, pool["unpublished"].as_array().unwrap().len());
+
+            // the next registry is not published yet
+            let res = _issue_from_pool(setup.wallet_handle, &cred_def_id, &cred_def_json, blob_storage_reader_handle);
+            assert_code!(ErrorCode::AnoncredsRevocationRegistryFullError, res);
+
+            anoncreds::issuer_mark_revoc_reg_published(setup.wallet_handle, &cred_def_id, &first_rev_reg_id).unwrap();
+            anoncreds::issuer_mark_revoc_reg_published(setup.wallet_handle, &cred_def_id, &next_rev_reg_id).unwrap();
+
+            assert_eq!(next_rev_reg_id, _issue_from_pool(setup.wallet_handle, &cred_def_id, &cred_def_json, blob_storage_reader_handle).unwrap());
+
+            let pool = _get_pool(setup.wallet_handle, &cred_def_id);
+            assert_eq!(json!(next_rev_reg_id), pool["active"]);
+            assert_eq!(serde_json::Value::Null, pool["next"]);
+            assert_eq!(0, pool["unpublished"].as_array().unwrap().len());
+
+            let res = anoncreds::issuer_mark_revoc_reg_published(setup.wallet_handle, &cred_def_id, &first_rev_reg_id);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+
+        #[test]
+        fn issuer_rollover_revoc_reg_pool_works_for_existing_next() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, _) = _revocation_cred_def(setup.wallet_handle);
+
+            let tails_writer_handle = blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+
+            anoncreds::issuer_create_revoc_reg_pool(setup.wallet_handle, ISSUER_DID, &cred_def_id, r#"{"max_cred_num": 2}"#, tails_writer_handle).unwrap();
+
+            let pool_json = anoncreds::issuer_rollover_revoc_reg_pool(setup.wallet_handle, &cred_def_id, tails_writer_handle).unwrap();
+            assert_eq!(pool_json, anoncreds::issuer_rollover_revoc_reg_pool(setup.wallet_handle, &cred_def_id, tails_writer_handle).unwrap());
+        }
+
+        #[test]
+        fn issuer_get_revoc_reg_pool_works_for_not_created() {
+            let setup = Setup::wallet();
+
+            let res = anoncreds::issuer_get_revoc_reg_pool(setup.wallet_handle, &anoncreds::issuer_1_gvt_cred_def_id());
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
+
+    mod prover_master_secret_rotation {
+        use super::*;
+        use crate::utils::crypto;
+        use crate::utils::anoncreds::CREDENTIAL2_ID;
+        use indy::WalletHandle;
+
+        const MASTER_SECRET_1: &str = "master_secret_1";
+        const MASTER_SECRET_2: &str = "master_secret_2";
+
+        fn _issue_credential(wallet_handle: WalletHandle, cred_def_id: &str, cred_def_json: &str, master_secret_id: &str, cred_id: &str) {
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(wallet_handle, cred_def_id).unwrap();
+
+            let (cred_req_json, cred_req_metadata_json) = anoncreds::prover_create_credential_req(wallet_handle,
+                                                                                                  DID_MY1,
+                                                                                                  &cred_offer_json,
+                                                                                                  cred_def_json,
+                                                                                                  master_secret_id).unwrap();
+
+            let (cred_json, _, _) = anoncreds::issuer_create_credential(wallet_handle,
+                                                                        &cred_offer_json,
+                                                                        &cred_req_json,
+                                                                        &anoncreds::gvt_credential_values_json(),
+                                                                        None,
+                                                                        None).unwrap();
+
+            anoncreds::prover_store_credential(wallet_handle,
+                                               cred_id,
+                                               &cred_req_metadata_json,
+                                               &cred_json,
+                                               cred_def_json,
+                                               None).unwrap();
+        }
+
+        fn _prove(wallet_handle: WalletHandle, cred_def_id: &str, cred_def_json: &str, master_secret_id: &str, cred_id: &str) -> bool {
+            let proof_req_json = anoncreds::proof_request_attr();
+
+            let requested_credentials_json = json!({
+                 "self_attested_attributes": json!({}),
+                 "requested_attributes": json!({
+                    "attr1_referent": json!({ "cred_id": cred_id, "revealed": true })
+                 }),
+                 "requested_predicates": json!({})
+            }).to_string();
+
+            let schemas_json = json!({
+                anoncreds::gvt_schema_id(): serde_json::from_str::<serde_json::Value>(&anoncreds::gvt_schema_json()).unwrap()
+            }).to_string();
+
+            let cred_defs_json = json!({
+                cred_def_id: serde_json::from_str::<serde_json::Value>(cred_def_json).unwrap()
+            }).to_string();
+
+            let proof_json = anoncreds::prover_create_proof(wallet_handle,
+                                                            &proof_req_json,
+                                                            &requested_credentials_json,
+                                                            master_secret_id,
+                                                            &schemas_json,
+                                                            &cred_defs_json,
+                                                            "{}").unwrap();
+
+            anoncreds::verifier_verify_proof(&proof_req_json, &proof_json, &schemas_json, &cred_defs_json, "{}", "{}").unwrap()
+        }
+
+        fn _cred_def(wallet_handle: WalletHandle) -> (String, String) {
+            anoncreds::issuer_create_credential_definition(wallet_handle,
+                                                           ISSUER_DID,
+                                                           &anoncreds::gvt_schema_json(),
+                                                           TAG_1,
+                                                           None,
+                                                           Some(&anoncreds::default_cred_def_config())).unwrap()
+        }
+
+        #[test]
+        fn prover_export_import_master_secret_works() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, cred_def_json) = _cred_def(setup.wallet_handle);
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            _issue_credential(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_1, CREDENTIAL1_ID);
+
+            let recipient_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+
+            let encrypted_master_secret_json = anoncreds::prover_export_master_secret(setup.wallet_handle, MASTER_SECRET_1, &recipient_vk).unwrap();
+            assert!(!encrypted_master_secret_json.contains(MASTER_SECRET_1));
+
+            let master_secret_id = anoncreds::prover_import_master_secret(setup.wallet_handle,
+                                                                          &recipient_vk,
+                                                                          &encrypted_master_secret_json,
+                                                                          Some(MASTER_SECRET_2)).unwrap();
+            assert_eq!(MASTER_SECRET_2, master_secret_id);
+
+            // credential bound to exported master secret is provable with imported one
+            assert!(_prove(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_2, CREDENTIAL1_ID));
+        }
+
+        #[test]
+        fn prover_import_master_secret_works_for_duplicate() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            let recipient_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+
+            let encrypted_master_secret_json = anoncreds::prover_export_master_secret(setup.wallet_handle, MASTER_SECRET_1, &recipient_vk).unwrap();
+
+            let res = anoncreds::prover_import_master_secret(setup.wallet_handle, &recipient_vk, &encrypted_master_secret_json, None);
+            assert_code!(ErrorCode::AnoncredsMasterSecretDuplicateNameError, res);
+        }
+
+        #[test]
+        fn prover_import_master_secret_works_for_other_key() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            let recipient_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+            let other_vk = crypto::create_key(setup.wallet_handle, None).unwrap();
+
+            let encrypted_master_secret_json = anoncreds::prover_export_master_secret(setup.wallet_handle, MASTER_SECRET_1, &recipient_vk).unwrap();
+
+            let res = anoncreds::prover_import_master_secret(setup.wallet_handle, &other_vk, &encrypted_master_secret_json, Some(MASTER_SECRET_2));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn prover_retire_master_secret_works() {
+            let setup = Setup::wallet();
+
+            let (cred_def_id, cred_def_json) = _cred_def(setup.wallet_handle);
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            _issue_credential(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_1, CREDENTIAL1_ID);
+
+            anoncreds::prover_retire_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            // new credential requests must use new master secret
+            let cred_offer_json = anoncreds::issuer_create_credential_offer(setup.wallet_handle, &cred_def_id).unwrap();
+            let res = anoncreds::prover_create_credential_req(setup.wallet_handle, DID_MY1, &cred_offer_json, &cred_def_json, MASTER_SECRET_1);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_2).unwrap();
+            _issue_credential(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_2, CREDENTIAL2_ID);
+
+            // old credentials remain provable
+            assert!(_prove(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_1, CREDENTIAL1_ID));
+            assert!(_prove(setup.wallet_handle, &cred_def_id, &cred_def_json, MASTER_SECRET_2, CREDENTIAL2_ID));
+
+            let master_secrets_json = anoncreds::prover_get_master_secrets(setup.wallet_handle).unwrap();
+            let master_secrets: Vec<serde_json::Value> = serde_json::from_str(&master_secrets_json).unwrap();
+            assert_eq!(2, master_secrets.len());
+
+            let master_secret_1 = master_secrets.iter().find(|ms| ms["id"] == json!(MASTER_SECRET_1)).unwrap();
+            assert!(master_secret_1["retired_at"].is_u64());
+            assert_eq!(json!([CREDENTIAL1_ID]), master_secret_1["cred_ids"]);
+
+            let master_secret_2 = master_secrets.iter().find(|ms| ms["id"] == json!(MASTER_SECRET_2)).unwrap();
+            assert!(master_secret_2["retired_at"].is_null());
+            assert_eq!(json!([CREDENTIAL2_ID]), master_secret_2["cred_ids"]);
+        }
+
+        #[test]
+        fn prover_retire_master_secret_works_for_already_retired() {
+            let setup = Setup::wallet();
+
+            anoncreds::prover_create_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+            anoncreds::prover_retire_master_secret(setup.wallet_handle, MASTER_SECRET_1).unwrap();
+
+            let res = anoncreds::prover_retire_master_secret(setup.wallet_handle, MASTER_SECRET_1);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+        }
+
+        #[test]
+        fn prover_retire_master_secret_works_for_unknown() {
+            let setup = Setup::wallet();
+
+            let res = anoncreds::prover_retire_master_secret(setup.wallet_handle, MASTER_SECRET_1);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+        }
+    }
 }
 
 #[cfg(not(feature = "only_high_cases"))]
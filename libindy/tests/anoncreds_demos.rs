@@ -1793,6 +1793,193 @@ mod demos {
         unimplemented!();
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_states_cached_in_wallet() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_states_cached_in_wallet_issuer").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_states_cached_in_wallet_prover").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (schema_id, schema_json,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Issuance Credential for Prover
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        let (_, revoc_reg_delta_1_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_1_json = revoc_reg_delta_1_json.unwrap();
+
+        //5. Prover creates Proof for the first timestamp, revocation state is built and stored in the wallet
+        let proof_request = json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "name":"name"
+               })
+           }),
+           "non_revoked": json!({ "to":200 })
+        }).to_string();
+
+        let requested_credentials = |timestamp: u64| json!({
+             "self_attested_attributes": json!({}),
+             "requested_attributes": json!({
+                "attr1_referent": json!({ "cred_id": CREDENTIAL1_ID, "timestamp":timestamp, "revealed":true })
+             }),
+             "requested_predicates": json!({})
+        }).to_string();
+
+        let schemas_json = json!({
+            schema_id: serde_json::from_str::<Schema>(&schema_json).unwrap()
+        }).to_string();
+
+        let credential_defs_json = json!({
+            cred_def_id: serde_json::from_str::<CredentialDefinition>(&cred_def_json).unwrap()
+        }).to_string();
+
+        let rev_reg_defs_json = json!({
+            rev_reg_id.clone(): serde_json::from_str::<RevocationRegistryDefinition>(&revoc_reg_def_json).unwrap()
+        }).to_string();
+
+        let verify = |proof_json: &str, timestamp: u64, revoc_reg_delta_json: &str| {
+            let rev_regs_json = json!({
+                rev_reg_id.clone(): json!({
+                    timestamp.to_string(): serde_json::from_str::<RevocationRegistry>(revoc_reg_delta_json).unwrap()
+                })
+            }).to_string();
+
+            anoncreds::verifier_verify_proof(&proof_request,
+                                             proof_json,
+                                             &schemas_json,
+                                             &credential_defs_json,
+                                             &rev_reg_defs_json,
+                                             &rev_regs_json).unwrap()
+        };
+
+        let rev_reg_deltas_json = json!({
+            rev_reg_id.clone(): json!({
+                "100": serde_json::from_str::<serde_json::Value>(&revoc_reg_delta_1_json).unwrap()
+            })
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof_with_revocation_cache(prover_wallet_handle,
+                                                                              &proof_request,
+                                                                              &requested_credentials(100),
+                                                                              COMMON_MASTER_SECRET,
+                                                                              &schemas_json,
+                                                                              &credential_defs_json,
+                                                                              blob_storage_reader_handle,
+                                                                              &rev_reg_defs_json,
+                                                                              &rev_reg_deltas_json).unwrap();
+        assert!(verify(&proof_json, 100, &revoc_reg_delta_1_json));
+
+        //6. Issuer issues another Credential
+        let (_, revoc_reg_delta_2_json) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL2_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+        let revoc_reg_delta_json = anoncreds::issuer_merge_revocation_registry_deltas(&revoc_reg_delta_1_json,
+                                                                                      &revoc_reg_delta_2_json.unwrap()).unwrap();
+
+        //7. Prover creates Proof for the second timestamp, stored revocation state is updated incrementally
+        let rev_reg_deltas_json = json!({
+            rev_reg_id.clone(): json!({
+                "200": serde_json::from_str::<serde_json::Value>(&revoc_reg_delta_json).unwrap()
+            })
+        }).to_string();
+
+        let proof_json = anoncreds::prover_create_proof_with_revocation_cache(prover_wallet_handle,
+                                                                              &proof_request,
+                                                                              &requested_credentials(200),
+                                                                              COMMON_MASTER_SECRET,
+                                                                              &schemas_json,
+                                                                              &credential_defs_json,
+                                                                              blob_storage_reader_handle,
+                                                                              &rev_reg_defs_json,
+                                                                              &rev_reg_deltas_json).unwrap();
+        assert!(verify(&proof_json, 200, &revoc_reg_delta_json));
+
+        //8. Prover creates Proof using stored revocation state without providing it
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials(100),
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        "{}").unwrap();
+        assert!(verify(&proof_json, 100, &revoc_reg_delta_1_json));
+
+        //9. Prover can't create Proof for timestamp without stored revocation state and delta
+        let res = anoncreds::prover_create_proof_with_revocation_cache(prover_wallet_handle,
+                                                                       &proof_request,
+                                                                       &requested_credentials(150),
+                                                                       COMMON_MASTER_SECRET,
+                                                                       &schemas_json,
+                                                                       &credential_defs_json,
+                                                                       blob_storage_reader_handle,
+                                                                       &rev_reg_defs_json,
+                                                                       "{}");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //10. Prover prunes outdated revocation states, the latest one is kept
+        let pruned_json = anoncreds::prover_prune_revocation_states(prover_wallet_handle, u64::max_value()).unwrap();
+        let pruned: Vec<serde_json::Value> = serde_json::from_str(&pruned_json).unwrap();
+        assert_eq!(1, pruned.len());
+        assert_eq!(json!(100), pruned[0]["timestamp"]);
+
+        let res = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                 &proof_request,
+                                                 &requested_credentials(100),
+                                                 COMMON_MASTER_SECRET,
+                                                 &schemas_json,
+                                                 &credential_defs_json,
+                                                 "{}");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        let proof_json = anoncreds::prover_create_proof(prover_wallet_handle,
+                                                        &proof_request,
+                                                        &requested_credentials(200),
+                                                        COMMON_MASTER_SECRET,
+                                                        &schemas_json,
+                                                        &credential_defs_json,
+                                                        "{}").unwrap();
+        assert!(verify(&proof_json, 200, &revoc_reg_delta_json));
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
     #[cfg(feature = "revocation_tests")]
     #[test]
     fn anoncreds_works_for_issuance_on_demand_revocation_strategy_revoke_credential() {
@@ -2326,6 +2513,141 @@ mod demos {
         wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_update_revocation_registry_issuance_by_demand() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_update_revocation_registry_issuance_by_demand").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_update_revocation_registry_issuance_by_demand").unwrap();
+
+        //3 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            cred_def_id, cred_def_json,
+            rev_reg_id, revoc_reg_def_json, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_ON_DEMAND"}"#);
+
+        //4. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //5. Issuance of two Credentials
+        let (cred_rev_id_1, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL1_ID,
+            &anoncreds::gvt_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        let (cred_rev_id_2, _) = anoncreds::multi_steps_create_revocation_credential(
+            COMMON_MASTER_SECRET,
+            prover_wallet_handle,
+            issuer_wallet_handle,
+            CREDENTIAL2_ID,
+            &anoncreds::gvt2_credential_values_json(),
+            &cred_def_id,
+            &cred_def_json,
+            &rev_reg_id,
+            &revoc_reg_def_json,
+            blob_storage_reader_handle,
+        );
+
+        //6. Issuer revokes both Credentials at once
+        let revoc_reg_delta_json = anoncreds::issuer_update_revocation_registry(issuer_wallet_handle,
+                                                                                blob_storage_reader_handle,
+                                                                                &rev_reg_id,
+                                                                                &json!([cred_rev_id_1, cred_rev_id_2]).to_string(),
+                                                                                "[]").unwrap();
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        assert_eq!(2, revoc_reg_delta["value"]["revoked"].as_array().unwrap().len());
+        assert!(revoc_reg_delta["value"]["prevAccum"].is_string());
+
+        //7. Issuer can't revoke already revoked Credential
+        let res = anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, &cred_rev_id_1);
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        //8. Issuer unrevokes first Credential
+        let revoc_reg_delta_json = anoncreds::issuer_update_revocation_registry(issuer_wallet_handle,
+                                                                                blob_storage_reader_handle,
+                                                                                &rev_reg_id,
+                                                                                "[]",
+                                                                                &json!([cred_rev_id_1]).to_string()).unwrap();
+        let revoc_reg_delta: serde_json::Value = serde_json::from_str(&revoc_reg_delta_json).unwrap();
+        assert_eq!(json!([cred_rev_id_1.parse::<u32>().unwrap()]), revoc_reg_delta["value"]["issued"]);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_update_revocation_registry_for_not_issued_credential_id_does_not_change_registry() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_update_revocation_registry_for_not_issued_credential_id_does_not_change_registry").unwrap();
+
+        //2 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            _, _,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //3. Issuer revokes batch containing not issued id
+        let res = anoncreds::issuer_update_revocation_registry(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, r#"["1", "10"]"#, "[]");
+        assert_code!(ErrorCode::AnoncredsInvalidUserRevocId, res);
+
+        //4. Valid id from failed batch is still not revoked
+        anoncreds::issuer_revoke_credential(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, "1").unwrap();
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_update_revocation_registry_for_invalid_ids() {
+        Setup::empty();
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_update_revocation_registry_for_invalid_ids").unwrap();
+
+        //2 Issuer creates Schema, Credential Definition and Revocation Registry
+        let (_, _,
+            _, _,
+            rev_reg_id, _, _,
+            blob_storage_reader_handle) = anoncreds::multi_steps_issuer_revocation_preparation(issuer_wallet_handle,
+                                                                                               ISSUER_DID,
+                                                                                               GVT_SCHEMA_NAME,
+                                                                                               GVT_SCHEMA_ATTRIBUTES,
+                                                                                               r#"{"max_cred_num":5, "issuance_type":"ISSUANCE_BY_DEFAULT"}"#);
+
+        //3. Issuer passes empty lists
+        let res = anoncreds::issuer_update_revocation_registry(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, "[]", "[]");
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //4. Issuer passes the same id to revoke and unrevoke
+        let res = anoncreds::issuer_update_revocation_registry(issuer_wallet_handle, blob_storage_reader_handle, &rev_reg_id, r#"["1", "2"]"#, r#"["2"]"#);
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+    }
+
 
     #[test]
     fn anoncreds_works_for_multiple_requested_predicates_from_one_credential() {
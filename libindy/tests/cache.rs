@@ -426,4 +426,83 @@ mod high_cases {
             purge_cred_def_cache(setup.wallet_handle, &json!({"minFresh": 1000}).to_string()).unwrap();
         }
     }
-}
\ No newline at end of file
+    mod did_doc_cache {
+        use super::*;
+        use crate::utils::constants::*;
+        use serde_json::Value;
+
+        fn _publish_endpoint(setup: &Setup) {
+            let attrib_request = utils::ledger::build_attrib_request(&setup.did, &setup.did, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+            let response = utils::ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &attrib_request).unwrap();
+            utils::pool::check_response_type(&response, utils::types::ResponseType::REPLY);
+        }
+
+        #[test]
+        fn indy_resolve_did_works() {
+            let setup = Setup::new_identity();
+
+            _publish_endpoint(&setup);
+
+            let resolution_result = resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did, "{}").unwrap();
+            let resolution_result: Value = serde_json::from_str(&resolution_result).unwrap();
+
+            let did_doc = &resolution_result["didDocument"];
+            assert_eq!(format!("did:sov:{}", setup.did), did_doc["id"].as_str().unwrap());
+            assert_eq!(setup.verkey, did_doc["verificationMethod"][0]["publicKeyBase58"].as_str().unwrap());
+            assert_eq!("127.0.0.1:5555", did_doc["service"][0]["serviceEndpoint"].as_str().unwrap());
+            assert_eq!("application/did+ld+json", resolution_result["didResolutionMetadata"]["contentType"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_fully_qualified_did() {
+            let setup = Setup::new_identity();
+
+            let did = format!("did:sov:{}", setup.did);
+
+            let resolution_result = resolve_did(setup.pool_handle, setup.wallet_handle, &did, "{}").unwrap();
+            let resolution_result: Value = serde_json::from_str(&resolution_result).unwrap();
+
+            assert_eq!(did, resolution_result["didDocument"]["id"].as_str().unwrap());
+            assert!(resolution_result["didDocument"]["service"].is_null());
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_unknown_did() {
+            let setup = Setup::wallet_and_pool();
+
+            let res = resolve_did(setup.pool_handle, setup.wallet_handle, DID_MY2, "{}");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_resolve_did_only_cache_no_cached_data() {
+            let setup = Setup::new_identity();
+
+            let res = resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_resolve_did_cache_works() {
+            let setup = Setup::new_identity();
+
+            let resolution_result = resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did, "{}").unwrap();
+
+            // now retrieve it from cache
+            let cached_resolution_result = resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did,
+                                                       &json!({"noUpdate": true}).to_string()).unwrap();
+
+            assert_eq!(resolution_result, cached_resolution_result);
+        }
+
+        #[test]
+        fn indy_resolve_did_no_store_works() {
+            let setup = Setup::new_identity();
+
+            resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did, &json!({"noStore": true}).to_string()).unwrap();
+
+            let res = resolve_did(setup.pool_handle, setup.wallet_handle, &setup.did, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+    }
+}
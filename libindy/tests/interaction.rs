@@ -509,6 +509,17 @@ impl Verifier {
 
         valid
     }
+
+    pub fn verify_with_ledger(&self, pool: &Pool, wallet_handle: WalletHandle, proof_json: &str) -> Value
+    {
+        let result_json = anoncreds::verifier_verify_proof_with_ledger(pool.pool_handle,
+                                                                       wallet_handle,
+                                                                       &self.proof_request,
+                                                                       proof_json,
+                                                                       &json!({"submitterDid": DID_MY1}).to_string()).unwrap();
+
+        serde_json::from_str(&result_json).unwrap()
+    }
 }
 
 #[cfg(feature = "revocation_tests")]
@@ -599,6 +610,10 @@ fn anoncreds_revocation_interaction_test_one_prover(revocation_registry_config:
     let valid = verifier.verify(&pool, &proof_json);
     assert!(valid);
 
+    // Verifier verifies proof resolving entities from the Ledger
+    let result = verifier.verify_with_ledger(&pool, issuer.issuer_wallet_handle, &proof_json);
+    assert_eq!(json!({"valid": true, "failures": []}), result);
+
     /////////////////////////////////////////////////////////////////////////////////////////
     // Issuer revokes cred_rev_id
     let _rev_reg_delta_json = issuer.revoke_credential(&pool, &cred_rev_id);
@@ -614,6 +629,10 @@ fn anoncreds_revocation_interaction_test_one_prover(revocation_registry_config:
     let valid = verifier.verify(&pool, &proof_json);
     assert!(!valid);
 
+    let result = verifier.verify_with_ledger(&pool, issuer.issuer_wallet_handle, &proof_json);
+    assert_eq!(false, result["valid"]);
+    assert_eq!("crypto", result["failures"][0]["check"]);
+
 
     issuer.close();
     prover.close();
@@ -115,6 +115,28 @@ mod high_cases {
             pool::check_response_type(&nym_response, ResponseType::REPLY);
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_policy_works() {
+            let setup = Setup::trustee();
+
+            let nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let read_policy = json!({"retries": 2, "retry_backoff": 100, "hedge_after": 200}).to_string();
+            let nym_response = ledger::submit_request_with_policy(setup.pool_handle, &nym_request, &read_policy).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_policy_works_for_full_consensus() {
+            let setup = Setup::trustee();
+
+            let nym_request = ledger::build_get_nym_request(Some(&setup.did), &setup.did).unwrap();
+            let read_policy = json!({"full_consensus": true}).to_string();
+            let nym_response = ledger::submit_request_with_policy(setup.pool_handle, &nym_request, &read_policy).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_sign_and_submit_request_works_for_fully_qualified() {
@@ -831,6 +853,37 @@ mod high_cases {
                 serde_json::from_str::<Reply<GetValidatorInfoResult>>(value).unwrap();
             }
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_validator_info_response_works() {
+            let setup = Setup::trustee();
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(&setup.did).unwrap();
+            let get_validator_info_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &get_validator_info_request).unwrap();
+
+            let validator_info = ledger::parse_get_validator_info_response(&get_validator_info_response).unwrap();
+            let validator_info: HashMap<String, serde_json::Value> = serde_json::from_str(&validator_info).unwrap();
+
+            assert!(!validator_info.is_empty());
+            for (node_alias, info) in validator_info {
+                assert_eq!(node_alias, info["validator_info"]["alias"].as_str().unwrap());
+                assert!(info["validator_info"]["pool_info"]["total_nodes_count"].is_u64());
+            }
+        }
+
+        #[test]
+        fn indy_parse_get_validator_info_response_works_for_timeout() {
+            let validator_info = ledger::parse_get_validator_info_response(r#"{"Node1": "timeout"}"#).unwrap();
+            let validator_info: serde_json::Value = serde_json::from_str(&validator_info).unwrap();
+            assert_eq!(json!({"Node1": {"error": "timeout"}}), validator_info);
+        }
+
+        #[test]
+        fn indy_parse_get_validator_info_response_works_for_invalid_response() {
+            let res = ledger::parse_get_validator_info_response("not a map");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod get_txn_requests {
@@ -914,6 +967,80 @@ mod high_cases {
             let expected_schema_data: SchemaData = serde_json::from_str(r#"{"name":"gvt","version":"1.0","attr_names":["name", "age", "sex", "height"]}"#).unwrap();
             assert_eq!(expected_schema_data, get_txn_schema_data);
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_parse_get_txn_response_works() {
+            let setup = Setup::new_identity();
+
+            let schema_request = ledger::build_schema_request(&setup.did, &anoncreds::gvt_schema_json()).unwrap();
+            let schema_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&schema_response, ResponseType::REPLY);
+
+            let seq_no = ledger::extract_seq_no_from_reply(&schema_response).unwrap() as i32;
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let get_txn_request = ledger::build_get_txn_request(Some(&setup.did), seq_no, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let txn_json = ledger::parse_get_txn_response(&get_txn_response).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&txn_json).unwrap();
+
+            assert_eq!(constants::SCHEMA, txn["type"].as_str().unwrap());
+            assert_eq!("SCHEMA", txn["typeName"].as_str().unwrap());
+            assert_eq!("gvt", txn["data"]["name"].as_str().unwrap());
+            assert_eq!(seq_no as u64, txn["metadata"]["seqNo"].as_u64().unwrap());
+            assert_eq!(setup.did, txn["metadata"]["from"].as_str().unwrap());
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_txn_inclusion_proof_works() {
+            let setup = Setup::new_identity();
+
+            let schema_request = ledger::build_schema_request(&setup.did, &anoncreds::gvt_schema_json()).unwrap();
+            let schema_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&schema_response, ResponseType::REPLY);
+
+            let seq_no = ledger::extract_seq_no_from_reply(&schema_response).unwrap() as i32;
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let get_txn_request = ledger::build_get_txn_request(Some(&setup.did), seq_no, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let proof_json = ledger::extract_txn_inclusion_proof(&get_txn_response).unwrap();
+            let proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+            assert_eq!(seq_no as u64, proof["seqNo"].as_u64().unwrap());
+
+            let valid = ledger::verify_txn_inclusion_proof(&proof_json, &pool::test_pool_nodes_bls_keys()).unwrap();
+            assert!(valid);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_txn_inclusion_proof_works_for_changed_txn() {
+            let setup = Setup::new_identity();
+
+            let schema_request = ledger::build_schema_request(&setup.did, &anoncreds::gvt_schema_json()).unwrap();
+            let schema_response = ledger::sign_and_submit_request(setup.pool_handle, setup.wallet_handle, &setup.did, &schema_request).unwrap();
+            pool::check_response_type(&schema_response, ResponseType::REPLY);
+
+            let seq_no = ledger::extract_seq_no_from_reply(&schema_response).unwrap() as i32;
+
+            thread::sleep(std::time::Duration::from_secs(1));
+
+            let get_txn_request = ledger::build_get_txn_request(Some(&setup.did), seq_no, None).unwrap();
+            let get_txn_response = ledger::submit_request(setup.pool_handle, &get_txn_request).unwrap();
+
+            let proof_json = ledger::extract_txn_inclusion_proof(&get_txn_response).unwrap();
+            let mut proof: serde_json::Value = serde_json::from_str(&proof_json).unwrap();
+            proof["txn"]["txn"]["data"]["data"]["version"] = json!("2.0");
+
+            let valid = ledger::verify_txn_inclusion_proof(&proof.to_string(), &pool::test_pool_nodes_bls_keys()).unwrap();
+            assert!(!valid);
+        }
     }
 
     mod pool_config {
@@ -3047,6 +3174,18 @@ mod medium_cases {
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_with_policy_works_for_invalid_policy() {
+            let setup = Setup::pool();
+
+            let res = ledger::submit_request_with_policy(setup.pool_handle, REQUEST, r#"{"retry_backoff": 0}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            let res = ledger::submit_request_with_policy(setup.pool_handle, REQUEST, r#"{"retries": "many"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_sign_and_submit_request_works_for_invalid_json() {
@@ -3769,6 +3908,84 @@ mod medium_cases {
     mod get_txn_requests {
         use super::*;
 
+        #[test]
+        fn indy_parse_get_txn_response_works_for_not_found() {
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_TXN,
+                    "seqNo": 1,
+                    "data": null
+                }
+            }).to_string();
+
+            let res = ledger::parse_get_txn_response(&response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_parse_get_txn_response_works_for_invalid_response() {
+            let res = ledger::parse_get_txn_response("{}");
+            assert_code!(ErrorCode::LedgerNotFound, res);
+        }
+
+        #[test]
+        fn indy_extract_txn_inclusion_proof_works_for_reqnack() {
+            let response = json!({
+                "op": "REQNACK",
+                "reqId": 1,
+                "reason": "some error"
+            }).to_string();
+
+            let res = ledger::extract_txn_inclusion_proof(&response);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+
+        #[test]
+        fn indy_extract_txn_inclusion_proof_works_for_no_proof() {
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": constants::GET_TXN,
+                    "seqNo": 1,
+                    "data": null
+                }
+            }).to_string();
+
+            let res = ledger::extract_txn_inclusion_proof(&response);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+        }
+
+        #[test]
+        fn indy_verify_txn_inclusion_proof_works_for_invalid_proof() {
+            let proof = json!({
+                "seqNo": 0,
+                "ledgerSize": 5,
+                "rootHash": "CrA5sqYe3ruf2uY7d8re7ePmyHqptHqANtMZcfZd4BvK",
+                "auditPath": [],
+                "txn": {},
+                "multiSignature": {}
+            }).to_string();
+
+            let res = ledger::verify_txn_inclusion_proof(&proof, &pool::test_pool_nodes_bls_keys());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_verify_txn_inclusion_proof_works_for_invalid_bls_keys() {
+            let proof = json!({
+                "seqNo": 3,
+                "ledgerSize": 5,
+                "rootHash": "CrA5sqYe3ruf2uY7d8re7ePmyHqptHqANtMZcfZd4BvK",
+                "auditPath": [],
+                "txn": {},
+                "multiSignature": {}
+            }).to_string();
+
+            let res = ledger::verify_txn_inclusion_proof(&proof, r#"{"Node1": "invalid"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn indy_get_txn_request_works_for_invalid_seq_no() {
@@ -55,6 +55,16 @@ mod collect {
         assert!(wallet_count.contains(&json!({"tags":{"label":"pending_for_open"},"value":0})));
     }
 
+    #[test]
+    fn collect_metrics_contains_pool_node_statistics() {
+        let result_metrics = metrics::collect_metrics().unwrap();
+        let metrics_map = serde_json::from_str::<HashMap<String, Value>>(&result_metrics).unwrap();
+
+        assert!(metrics_map.contains_key("pool_node_requests_count"));
+        assert!(metrics_map.contains_key("pool_node_latency_ms"));
+        assert!(metrics_map.contains_key("pool_node_health_score"));
+    }
+
     #[test]
     fn collect_metrics_contains_thread_pool_service_statistics() {
         let result_metrics = metrics::collect_metrics().unwrap();
@@ -110,6 +110,100 @@ mod high_cases {
         }
     }
 
+    mod open_registry {
+        use super::*;
+
+        extern crate futures;
+
+        use self::futures::Future;
+        use crate::utils::{cache, ledger};
+
+        fn registry_config(pool_name: &str) -> String {
+            json!({
+                "networks": {
+                    "did:sov": {"pool_name": pool_name},
+                    "did:indy:test": {"pool_name": pool_name}
+                },
+                "default": "did:sov"
+            }).to_string()
+        }
+
+        #[test]
+        fn open_pool_registry_works() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let registry_handle = pool::open_pool_registry(&registry_config(&setup.name)).unwrap();
+            pool::close(registry_handle).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_registry_works_for_request_routed_to_default_network() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let registry_handle = pool::open_pool_registry(&registry_config(&setup.name)).unwrap();
+
+            let get_nym_req = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let response = indy::ledger::submit_request(registry_handle, &get_nym_req).wait().unwrap();
+            pool::check_response_type(&response, crate::utils::types::ResponseType::REPLY);
+
+            pool::close(registry_handle).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_registry_works_for_reopen_after_close() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let registry_handle = pool::open_pool_registry(&registry_config(&setup.name)).unwrap();
+
+            let get_nym_req = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            indy::ledger::submit_request(registry_handle, &get_nym_req).wait().unwrap();
+
+            pool::close(registry_handle).unwrap();
+
+            // pool opened by registry must be closed together with it
+            let pool_handle = pool::open_pool_ledger(&setup.name, None).unwrap();
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_registry_works_for_get_schema_with_indy_namespace() {
+            let setup = Setup::wallet();
+
+            let (schema_id, _, _) = ledger::post_entities();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let registry_handle = pool::open_pool_registry(&registry_config(&setup.name)).unwrap();
+
+            let schema_id = format!("schema:indy:did:indy:test:{}", schema_id);
+            let options_json = json!({"noStore": true}).to_string();
+            let schema_json = cache::get_schema_cache(registry_handle, setup.wallet_handle, DID_MY1, &schema_id, &options_json).unwrap();
+
+            let schema: serde_json::Value = serde_json::from_str(&schema_json).unwrap();
+            assert_eq!(schema_id, schema["id"].as_str().unwrap());
+            assert_eq!(GVT_SCHEMA_NAME, schema["name"].as_str().unwrap());
+
+            pool::close(registry_handle).unwrap();
+        }
+    }
+
     mod refresh {
         use super::*;
 
@@ -121,6 +215,34 @@ mod high_cases {
         }
     }
 
+    mod get_pool_status {
+        use super::*;
+        use crate::utils::ledger;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_pool_status_works() {
+            let setup = Setup::pool();
+
+            let request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            ledger::submit_request(setup.pool_handle, &request).unwrap();
+
+            let status = pool::get_pool_status(setup.pool_handle).unwrap();
+            let status: serde_json::Value = serde_json::from_str(&status).unwrap();
+
+            assert_eq!(setup.name, status["name"].as_str().unwrap());
+            assert_eq!(4, status["ledger"]["size"].as_u64().unwrap());
+            assert!(!status["ledger"]["root"].as_str().unwrap().is_empty());
+            assert!(status["last_catchup"].is_i64());
+
+            let nodes = status["nodes"].as_array().unwrap();
+            assert_eq!(4, nodes.len());
+            assert_eq!("Node1", nodes[0]["alias"].as_str().unwrap());
+            assert!(nodes.iter().any(|node| node["connected"].as_bool().unwrap()));
+            assert!(nodes.iter().any(|node| node["health"]["replies"].as_u64().unwrap() > 0));
+        }
+    }
+
     mod close {
         use super::*;
 
@@ -380,6 +502,41 @@ mod medium_cases {
 
             pool::close(pool_handle).unwrap();
         }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_config_read_policy() {
+            let setup = Setup::empty();
+
+            let config = json!({"read_policy": {"retries": 1, "hedge_after": 300}}).to_string();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(&setup.name, Some(&config)).unwrap();
+
+            let request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let response = ledger::submit_request(pool_handle, &request).unwrap();
+            pool::check_response_type(&response, crate::utils::types::ResponseType::REPLY);
+
+            pool::close(pool_handle).unwrap();
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_invalid_read_policy() {
+            let setup = Setup::empty();
+
+            let config = json!({"read_policy": {"hedge_after": 0}}).to_string();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(&setup.name, Some(&config));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
     }
 
     mod close {
@@ -432,6 +589,86 @@ mod medium_cases {
         }
     }
 
+    mod open_registry {
+        use super::*;
+
+        extern crate futures;
+
+        use self::futures::Future;
+
+        #[test]
+        fn open_pool_registry_works_for_empty_networks() {
+            Setup::empty();
+
+            let res = pool::open_pool_registry(r#"{"networks": {}}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn open_pool_registry_works_for_invalid_namespace() {
+            let setup = Setup::empty();
+
+            let config = json!({"networks": {"sov": {"pool_name": setup.name}}}).to_string();
+
+            let res = pool::open_pool_registry(&config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn open_pool_registry_works_for_unknown_default_network() {
+            let setup = Setup::empty();
+
+            let config = json!({"networks": {"did:sov": {"pool_name": setup.name}}, "default": "did:indy:sovrin"}).to_string();
+
+            let res = pool::open_pool_registry(&config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn open_pool_registry_works_for_not_created_pool() {
+            let setup = Setup::empty();
+
+            let config = json!({"networks": {"did:sov": {"pool_name": setup.name}}}).to_string();
+
+            let res = pool::open_pool_registry(&config);
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+        }
+
+        #[test]
+        fn open_pool_registry_works_for_request_without_default_network() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({"networks": {"did:sov": {"pool_name": setup.name}}}).to_string();
+            let registry_handle = pool::open_pool_registry(&config).unwrap();
+
+            let get_nym_req = ledger::build_get_nym_request(Some(DID_TRUSTEE), DID_TRUSTEE).unwrap();
+            let res = indy::ledger::submit_request(registry_handle, &get_nym_req).wait();
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(registry_handle).unwrap();
+        }
+
+        #[test]
+        fn close_pool_registry_works_for_twice() {
+            let setup = Setup::empty();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(&setup.name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(&setup.name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({"networks": {"did:sov": {"pool_name": setup.name}}}).to_string();
+            let registry_handle = pool::open_pool_registry(&config).unwrap();
+
+            pool::close(registry_handle).unwrap();
+            let res = pool::close(registry_handle);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
     mod delete {
         use super::*;
 
@@ -477,6 +714,18 @@ mod medium_cases {
         }
     }
 
+    mod get_pool_status {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_status_works_for_invalid_handle() {
+            Setup::empty();
+
+            let res = pool::get_pool_status(0);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
@@ -21,7 +21,7 @@ use crate::utils::domain::anoncreds::credential::{AttributeValues, CredentialInf
 use crate::utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
 use crate::utils::domain::crypto::did::DidValue;
 
-use indy::WalletHandle;
+use indy::{WalletHandle, PoolHandle};
 
 pub static mut CREDENTIAL_DEF_JSON: &'static str = "";
 pub static mut CREDENTIAL_OFFER_JSON: &'static str = "";
@@ -84,6 +84,31 @@ pub fn issuer_revoke_credential(wallet_handle: WalletHandle, blob_storage_reader
     anoncreds::issuer_revoke_credential(wallet_handle, blob_storage_reader_handle, rev_reg_id, cred_revoc_id).wait()
 }
 
+pub fn issuer_create_revoc_reg_pool(wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str, tails_writer_handle: i32) -> Result<String, IndyError> {
+    anoncreds::issuer_create_revoc_reg_pool(wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle).wait()
+}
+
+pub fn issuer_create_credential_from_revoc_reg_pool(wallet_handle: WalletHandle, cred_offer_json: &str, cred_req_json: &str, cred_values_json: &str,
+                                                    blob_storage_reader_handle: i32) -> Result<(String, Option<String>, Option<String>), IndyError> {
+    anoncreds::issuer_create_credential_from_revoc_reg_pool(wallet_handle, cred_offer_json, cred_req_json, cred_values_json, blob_storage_reader_handle).wait()
+}
+
+pub fn issuer_rollover_revoc_reg_pool(wallet_handle: WalletHandle, cred_def_id: &str, tails_writer_handle: i32) -> Result<String, IndyError> {
+    anoncreds::issuer_rollover_revoc_reg_pool(wallet_handle, cred_def_id, tails_writer_handle).wait()
+}
+
+pub fn issuer_get_revoc_reg_pool(wallet_handle: WalletHandle, cred_def_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_revoc_reg_pool(wallet_handle, cred_def_id).wait()
+}
+
+pub fn issuer_mark_revoc_reg_published(wallet_handle: WalletHandle, cred_def_id: &str, rev_reg_id: &str) -> Result<(), IndyError> {
+    anoncreds::issuer_mark_revoc_reg_published(wallet_handle, cred_def_id, rev_reg_id).wait()
+}
+
+pub fn issuer_update_revocation_registry(wallet_handle: WalletHandle, blob_storage_reader_handle: i32, rev_reg_id: &str, revoked_ids_json: &str, unrevoked_ids_json: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_update_revocation_registry(wallet_handle, blob_storage_reader_handle, rev_reg_id, revoked_ids_json, unrevoked_ids_json).wait()
+}
+
 pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_reg_delta: &str) -> Result<String, IndyError> {
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
@@ -92,6 +117,22 @@ pub fn prover_create_master_secret(wallet_handle: WalletHandle, master_secret_id
     anoncreds::prover_create_master_secret(wallet_handle, Some(master_secret_id)).wait()
 }
 
+pub fn prover_export_master_secret(wallet_handle: WalletHandle, master_secret_id: &str, recipient_vk: &str) -> Result<String, IndyError> {
+    anoncreds::prover_export_master_secret(wallet_handle, master_secret_id, recipient_vk).wait()
+}
+
+pub fn prover_import_master_secret(wallet_handle: WalletHandle, my_vk: &str, encrypted_master_secret_json: &str, master_secret_id: Option<&str>) -> Result<String, IndyError> {
+    anoncreds::prover_import_master_secret(wallet_handle, my_vk, encrypted_master_secret_json, master_secret_id).wait()
+}
+
+pub fn prover_get_master_secrets(wallet_handle: WalletHandle) -> Result<String, IndyError> {
+    anoncreds::prover_get_master_secrets(wallet_handle).wait()
+}
+
+pub fn prover_retire_master_secret(wallet_handle: WalletHandle, master_secret_id: &str) -> Result<(), IndyError> {
+    anoncreds::prover_retire_master_secret(wallet_handle, master_secret_id).wait()
+}
+
 pub fn prover_create_credential_req(wallet_handle: WalletHandle, prover_did: &str, cred_offer_json: &str,
                                     cred_def_json: &str, master_secret_id: &str) -> Result<(String, String), IndyError> {
     anoncreds::prover_create_credential_req(wallet_handle, prover_did, cred_offer_json, cred_def_json, master_secret_id).wait()
@@ -160,11 +201,36 @@ pub fn prover_create_proof(wallet_handle: WalletHandle, proof_req_json: &str, re
                                    master_secret_name, schemas_json, cred_defs_json, rev_states_json).wait()
 }
 
+pub fn prover_create_proof_auto(wallet_handle: WalletHandle, proof_req_json: &str, master_secret_name: &str,
+                                schemas_json: &str, cred_defs_json: &str, rev_states_json: &str,
+                                options_json: Option<&str>) -> Result<(String, String), IndyError> {
+    anoncreds::prover_create_proof_auto(wallet_handle, proof_req_json, master_secret_name,
+                                        schemas_json, cred_defs_json, rev_states_json, options_json).wait()
+}
+
+pub fn prover_create_proof_with_revocation_cache(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str,
+                                                 master_secret_name: &str, schemas_json: &str, cred_defs_json: &str,
+                                                 blob_storage_reader_handle: i32, rev_reg_defs_json: &str,
+                                                 rev_reg_deltas_json: &str) -> Result<String, IndyError> {
+    anoncreds::prover_create_proof_with_revocation_cache(wallet_handle, proof_req_json, requested_credentials_json, master_secret_name,
+                                                         schemas_json, cred_defs_json, blob_storage_reader_handle,
+                                                         rev_reg_defs_json, rev_reg_deltas_json).wait()
+}
+
 pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas_json: &str,
                              cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<bool, IndyError> {
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_report(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                         cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_with_report(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
+pub fn verifier_verify_proof_with_ledger(pool_handle: PoolHandle, wallet_handle: WalletHandle, proof_request_json: &str, proof_json: &str, options_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_with_ledger(pool_handle, wallet_handle, proof_request_json, proof_json, options_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -175,6 +241,14 @@ pub fn update_revocation_state(tails_reader_handle: i32, rev_state_json: &str, r
     anoncreds::update_revocation_state(tails_reader_handle, rev_state_json, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
 }
 
+pub fn prover_refresh_revocation_states(pool_handle: PoolHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: i32) -> Result<String, IndyError> {
+    anoncreds::prover_refresh_revocation_states(pool_handle, wallet_handle, blob_storage_reader_handle).wait()
+}
+
+pub fn prover_prune_revocation_states(wallet_handle: WalletHandle, timestamp: u64) -> Result<String, IndyError> {
+    anoncreds::prover_prune_revocation_states(wallet_handle, timestamp).wait()
+}
+
 pub fn generate_nonce() -> Result<String, IndyError> {
     anoncreds::generate_nonce().wait()
 }
@@ -183,6 +257,34 @@ pub fn to_unqualified(entity: &str) -> Result<String, IndyError> {
     anoncreds::to_unqualified(entity).wait()
 }
 
+pub fn credential_to_w3c(cred_json: &str) -> Result<String, IndyError> {
+    anoncreds::credential_to_w3c(cred_json).wait()
+}
+
+pub fn credential_from_w3c(w3c_cred_json: &str) -> Result<String, IndyError> {
+    anoncreds::credential_from_w3c(w3c_cred_json).wait()
+}
+
+pub fn proof_to_w3c_presentation(proof_json: &str, proof_req_json: &str) -> Result<String, IndyError> {
+    anoncreds::proof_to_w3c_presentation(proof_json, proof_req_json).wait()
+}
+
+pub fn proof_from_w3c_presentation(w3c_presentation_json: &str, proof_req_json: &str) -> Result<String, IndyError> {
+    anoncreds::proof_from_w3c_presentation(w3c_presentation_json, proof_req_json).wait()
+}
+
+pub fn encode_credential_values(typed_values_json: &str) -> Result<String, IndyError> {
+    anoncreds::encode_credential_values(typed_values_json).wait()
+}
+
+pub fn decode_credential_value(encoded: &str, encoding_json: &str) -> Result<String, IndyError> {
+    anoncreds::decode_credential_value(encoded, encoding_json).wait()
+}
+
+pub fn build_predicate_info(attr_name: &str, p_type: &str, value: &str, encoding_json: &str) -> Result<String, IndyError> {
+    anoncreds::build_predicate_info(attr_name, p_type, value, encoding_json).wait()
+}
+
 pub fn default_cred_def_config() -> String {
     serde_json::to_string(&CredentialDefinitionConfig { support_revocation: false }).unwrap()
 }
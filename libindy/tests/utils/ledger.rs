@@ -39,6 +39,10 @@ pub fn submit_request(pool_handle: PoolHandle, request_json: &str) -> Result<Str
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_request_with_policy(pool_handle: PoolHandle, request_json: &str, read_policy_json: &str) -> Result<String, IndyError> {
+    ledger::submit_request_with_policy(pool_handle, request_json, read_policy_json).wait()
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, IndyError> {
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
@@ -134,10 +138,26 @@ pub fn build_get_validator_info_request(submitter_did: &str) -> Result<String, I
     ledger::build_get_validator_info_request(submitter_did).wait()
 }
 
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_validator_info_response(get_validator_info_response).wait()
+}
+
 pub fn build_get_txn_request(submitter_did: Option<&str>, data: i32, ledger_type: Option<&str>) -> Result<String, IndyError> {
     ledger::build_get_txn_request(submitter_did, ledger_type, data).wait()
 }
 
+pub fn parse_get_txn_response(get_txn_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_txn_response(get_txn_response).wait()
+}
+
+pub fn extract_txn_inclusion_proof(get_txn_response: &str) -> Result<String, IndyError> {
+    ledger::extract_txn_inclusion_proof(get_txn_response).wait()
+}
+
+pub fn verify_txn_inclusion_proof(proof_json: &str, nodes_bls_keys_json: &str) -> Result<bool, IndyError> {
+    ledger::verify_txn_inclusion_proof(proof_json, nodes_bls_keys_json).wait()
+}
+
 pub fn build_pool_config_request(submitter_did: &str, writes: bool, force: bool) -> Result<String, IndyError> {
     ledger::build_pool_config_request(submitter_did, writes, force).wait()
 }
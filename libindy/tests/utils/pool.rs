@@ -56,6 +56,19 @@ pub fn create_genesis_txn_file_for_test_pool(pool_name: &str,
     create_genesis_txn_file(pool_name, txn_file_data.as_str(), txn_file_path)
 }
 
+pub fn test_pool_nodes_bls_keys() -> String {
+    let nodes_bls_keys = test::gen_txns()
+        .iter()
+        .map(|txn| {
+            let txn: serde_json::Value = serde_json::from_str(txn).unwrap();
+            let node_data = &txn["txn"]["data"]["data"];
+            (node_data["alias"].as_str().unwrap().to_string(), node_data["blskey"].as_str().unwrap().to_string())
+        })
+        .collect::<::std::collections::HashMap<String, String>>();
+
+    serde_json::to_string(&nodes_bls_keys).unwrap()
+}
+
 pub fn create_genesis_txn_file_for_test_pool_with_invalid_nodes(pool_name: &str,
                                                                 txn_file_path: Option<&Path>) -> PathBuf {
     let test_pool_ip = environment::test_pool_ip();
@@ -119,6 +132,10 @@ pub fn open_pool_ledger(pool_name: &str, config: Option<&str>) -> Result<PoolHan
     pool::open_pool_ledger(pool_name, config).wait()
 }
 
+pub fn open_pool_registry(config: &str) -> Result<PoolHandle, IndyError> {
+    pool::open_pool_registry(config).wait()
+}
+
 pub fn dump_correct_genesis_txns_to_cache(pool_name: &str) -> Result<(), ErrorCode> {
     _dump_genesis_txns_to_cache(pool_name, &test::gen_txns())
 }
@@ -174,6 +191,10 @@ pub fn refresh(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
 
+pub fn get_pool_status(pool_handle: PoolHandle) -> Result<String, IndyError> {
+    pool::get_pool_status(pool_handle).wait()
+}
+
 pub fn close(pool_handle: PoolHandle) -> Result<(), IndyError> {
     pool::close_pool_ledger(pool_handle).wait()
 }
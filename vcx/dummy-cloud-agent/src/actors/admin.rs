@@ -7,12 +7,14 @@ use failure::{err_msg, Error};
 use futures::Future;
 use futures::future::ok;
 
-use crate::actors::HandleAdminMessage;
+use crate::actors::{GetFailedWebhookNotifications, HandleAdminMessage, ReplayFailedWebhookNotifications};
 use crate::actors::agent::Agent;
 use crate::actors::agent_connection::AgentConnection;
 use crate::actors::forward_agent::ForwardAgent;
 use crate::actors::forward_agent_connection::ForwardAgentConnection;
-use crate::domain::admin_message::{AdminQuery, GetDetailAgentConnParams, GetDetailAgentParams, ResAdminQuery, ResQueryAdmin};
+use crate::actors::notifier::Notifier;
+use crate::domain::admin_message::{AdminQuery, GetDetailAgentConnParams, GetDetailAgentParams, ResAdminQuery, ResQueryAdmin,
+                                   ResQueryFailedWebhookNotifications, ResQueryReplayedWebhookNotifications};
 use crate::utils::futures::FutureExt;
 
 /// Admin actor is much like Router aware of existing instances such as Forward Agent, Forward Agent Connections,
@@ -112,6 +114,25 @@ impl Admin {
             None => err!(err_msg("Agent connection not found."))
         }
     }
+
+    pub fn get_failed_webhook_notifications(&self) -> Box<dyn Future<Item=ResAdminQuery, Error=Error>> {
+        Notifier::from_registry()
+            .send(GetFailedWebhookNotifications())
+            .from_err()
+            .and_then(|res| res)
+            .map(|notifications| ResAdminQuery::FailedWebhookNotifications(ResQueryFailedWebhookNotifications { notifications }))
+            .into_box()
+    }
+
+    /// Replays failed webhook notification with given id, or all failed notifications if id is not set.
+    pub fn replay_failed_webhook_notifications(&self, notification_id: Option<String>) -> Box<dyn Future<Item=ResAdminQuery, Error=Error>> {
+        Notifier::from_registry()
+            .send(ReplayFailedWebhookNotifications(notification_id))
+            .from_err()
+            .and_then(|res| res)
+            .map(|notification_ids| ResAdminQuery::ReplayedWebhookNotifications(ResQueryReplayedWebhookNotifications { notification_ids }))
+            .into_box()
+    }
 }
 
 #[cfg(test)]
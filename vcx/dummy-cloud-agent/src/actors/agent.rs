@@ -129,6 +129,9 @@ impl Agent {
                     configs: HashMap::new(),
                 };
 
+                #[cfg(test)]
+                crate::utils::tests::AGENT_WALLET_HANDLES.write().unwrap().insert(agent_did.clone(), wallet_handle);
+
                 let agent = agent.start();
 
                 router.write().unwrap()
@@ -232,6 +235,9 @@ impl Agent {
                     configs,
                 };
 
+                #[cfg(test)]
+                crate::utils::tests::AGENT_WALLET_HANDLES.write().unwrap().insert(agent_did.clone(), wallet_handle);
+
                 let agent = agent.start();
 
                 router.write().unwrap()
@@ -729,7 +735,7 @@ impl Agent {
     fn handle_update_configs(&mut self, msg: UpdateConfigs) -> ResponseActFuture<Self, (), Error> {
         for config_option in msg.configs {
             match config_option.name.as_str() {
-                "name" | "logoUrl" | "notificationWebhookUrl" => self.configs.insert(config_option.name, config_option.value),
+                "name" | "logoUrl" | "notificationWebhookUrl" | "notificationWebhookSecret" => self.configs.insert(config_option.name, config_option.value),
                 _ => {
                     warn!("Agent was trying to set up unsupported agent configuration option {}", config_option.name.as_str());
                     continue;
@@ -764,8 +770,9 @@ impl Agent {
     }
 
     fn handle_get_configs(&mut self, msg: GetConfigs) -> Vec<ConfigOption> {
+        // webhook secret is write-only
         self.configs.iter()
-            .filter(|(k, _)| msg.configs.contains(k))
+            .filter(|(k, _)| msg.configs.contains(k) && k.as_str() != "notificationWebhookSecret")
             .map(|(k, v)| ConfigOption { name: k.clone(), value: v.clone() })
             .collect()
     }
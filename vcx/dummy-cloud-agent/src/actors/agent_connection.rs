@@ -6,14 +6,14 @@ use actix::prelude::*;
 use base64;
 use failure::{err_msg, Error, Fail};
 use futures::*;
-use futures::future::Either;
-use futures::future::ok;
+use futures::future::{Either, Loop};
 use rmp_serde;
 use serde_json;
 use uuid::Uuid;
 
-use crate::actors::{HandleA2AMsg, HandleA2ConnMsg, HandleAdminMessage, RemoteMsg, requester};
+use crate::actors::{HandleA2AMsg, HandleA2ConnMsg, HandleAdminMessage, RemoteMsg, SendWebhookNotification};
 use crate::actors::admin::Admin;
+use crate::actors::notifier::Notifier;
 use crate::actors::router::Router;
 use crate::domain::a2a::*;
 use crate::domain::a2connection::*;
@@ -21,12 +21,20 @@ use crate::domain::admin_message::{ResAdminQuery, ResQueryAgentConn};
 use crate::domain::internal_message::InternalMessage;
 use crate::domain::invite::{AgentDetail, ForwardAgentDetail, InviteDetail, RedirectDetail, SenderDetail};
 use crate::domain::key_deligation_proof::KeyDlgProof;
+use crate::domain::message_retention::MessageRetention;
 use crate::domain::payload::{PayloadKinds, PayloadTypes, PayloadV1, PayloadV2, Thread};
 use crate::domain::protocol_type::{ProtocolType, ProtocolTypes};
 use crate::domain::status::{ConnectionStatus, MessageStatusCode};
-use crate::indy::{crypto, did, ErrorCode, IndyError, pairwise, WalletHandle};
+use crate::domain::webhook::WebhookNotification;
+use crate::indy::{crypto, did, ErrorCode, IndyError, non_secrets, pairwise, WalletHandle};
 use crate::utils::futures::*;
-use crate::utils::to_i8;
+use crate::utils::{now_epoch_secs, to_i8};
+
+/// Wallet record type of messages received or sent via agent connections
+const MESSAGE_RECORD_TYPE: &str = "AgentConnectionMessage";
+
+/// Amount of message records fetched from wallet at once when restoring agent connection
+const MESSAGE_SEARCH_BATCH_SIZE: usize = 100;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 struct RemoteConnectionDetail {
@@ -74,6 +82,7 @@ pub struct AgentConnectionConfig {
 /// set, whenever a new message arrives, the agent's owner will be notified by sending HTTP(S)
 /// message containing metadata about received message. The agent's owner can therefore instead
 /// of constant polling for new messages rather download and process message right after its arrival.
+/// If agent's config "notificationWebhookSecret" is set, the notifications are signed with it.
 #[allow(unused)] //FIXME:
 pub struct AgentConnection {
     // Agent wallet handle
@@ -100,6 +109,8 @@ pub struct AgentConnection {
     router: Rc<RwLock<Router>>,
     // Address of admin agent
     admin: Option<Arc<RwLock<Admin>>>,
+    // Messages as last persisted in wallet, keyed by message uid
+    stored_messages: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -122,11 +133,30 @@ struct AgentConnectionState {
     // Agent Connection Status
     #[serde(default)]
     connection_status: ConnectionStatus,
-    // Agent Connection internal messages
-    #[serde(default)]
+    // Agent Connection internal messages. These are persisted as separate wallet records, legacy
+    // states might still contain them though.
+    #[serde(default, skip_serializing)]
     messages: HashMap<String, InternalMessage>,
 }
 
+#[derive(Debug, Deserialize)]
+struct MessageRecords {
+    records: Option<Vec<MessageRecord>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageRecord {
+    value: String,
+}
+
+/// Change of message wallet record required to keep wallet in sync with in-memory messages
+#[derive(Debug)]
+enum MessageRecordChange {
+    Add { id: String, value: String, tags: String },
+    Update { id: String, value: String, tags: String },
+    Delete { id: String },
+}
+
 impl AgentConnection {
     /// Creates new pairwise agent. This is triggered by Agent's owner sending "CreateKeys" message.
     /// The Agent Connection owner is expected to generate connection invitation accordingly to
@@ -156,6 +186,7 @@ impl AgentConnection {
                     },
                     router: router.clone(),
                     admin: admin.clone(),
+                    stored_messages: HashMap::new(),
                 };
 
                 let agent_connection = agent_connection.start();
@@ -209,12 +240,31 @@ impl AgentConnection {
                 let user_pairwise_fut = did::key_for_local_did(wallet_handle, &user_pairwise_did)
                     .map_err(|err| err.context("Can't get Agent Connection User verkey").into());
 
+                let stored_messages_fut = Self::load_messages(wallet_handle, &user_pairwise_did);
+
                 agent_pairwise_did_fut
-                    .join(user_pairwise_fut)
-                    .map(|(agent_pairwise_verkey, user_pairwise_verkey)| (agent_pairwise_did, agent_pairwise_verkey, user_pairwise_did, user_pairwise_verkey, state))
+                    .join3(user_pairwise_fut, stored_messages_fut)
+                    .map(|(agent_pairwise_verkey, user_pairwise_verkey, stored_messages_by_uid)|
+                        (agent_pairwise_did, agent_pairwise_verkey, user_pairwise_did, user_pairwise_verkey, state, stored_messages_by_uid))
             })
-            .and_then(move |(agent_pairwise_did, agent_pairwise_verkey, user_pairwise_did, user_pairwise_verkey, state)| {
-                let agent_connection = AgentConnection {
+            .and_then(move |(agent_pairwise_did, agent_pairwise_verkey, user_pairwise_did, user_pairwise_verkey, state, stored_messages_by_uid)| {
+                // Messages kept in legacy connection state are migrated into wallet records
+                let mut messages = state.messages;
+                let now = now_epoch_secs();
+                for message in messages.values_mut().filter(|message| message.created_at == 0) {
+                    message.created_at = now;
+                    message.updated_at = now;
+                }
+
+                let mut stored_messages = HashMap::new();
+                for (uid, message) in stored_messages_by_uid {
+                    let value = ftry!(serde_json::to_value(&message)
+                        .map_err(|err| err.context("Can't serialize connection message.")));
+                    stored_messages.insert(uid.clone(), value);
+                    messages.insert(uid, message);
+                }
+
+                let mut agent_connection = AgentConnection {
                     wallet_handle,
                     owner_did,
                     owner_verkey,
@@ -228,23 +278,31 @@ impl AgentConnection {
                         agent_key_dlg_proof: state.agent_key_dlg_proof,
                         remote_connection_detail: state.remote_connection_detail,
                         connection_status: state.connection_status,
-                        messages: state.messages,
+                        messages,
                     },
                     router: router.clone(),
                     admin: admin.clone(),
+                    stored_messages,
                 };
 
-                let agent_connection = agent_connection.start();
-                {
-                    let mut router = router.write().unwrap();
-                    router.add_a2a_route(agent_pairwise_did.clone(), agent_pairwise_verkey.clone(), agent_connection.clone().recipient());
-                    router.add_a2conn_route(agent_pairwise_did.clone(), agent_pairwise_verkey.clone(), agent_connection.clone().recipient());
-                }
-                if let Some(admin) = admin {
-                    admin.write().unwrap()
-                        .register_agent_connection(agent_pairwise_did, agent_connection.clone())
-                };
-                future::ok(())
+                agent_connection.apply_message_retention();
+                let (changes, stored_messages) = ftry!(agent_connection.message_changes());
+
+                Self::store_message_changes(wallet_handle, changes)
+                    .map(move |_| {
+                        agent_connection.stored_messages = stored_messages;
+                        let agent_connection = agent_connection.start();
+                        {
+                            let mut router = router.write().unwrap();
+                            router.add_a2a_route(agent_pairwise_did.clone(), agent_pairwise_verkey.clone(), agent_connection.clone().recipient());
+                            router.add_a2conn_route(agent_pairwise_did.clone(), agent_pairwise_verkey.clone(), agent_connection.clone().recipient());
+                        }
+                        if let Some(admin) = admin {
+                            admin.write().unwrap()
+                                .register_agent_connection(agent_pairwise_did, agent_connection.clone())
+                        };
+                    })
+                    .into_box()
             })
             .into_box()
     }
@@ -1053,34 +1111,17 @@ impl AgentConnection {
     }
 
     /// Dispatches metadata about receive message in form of HTTP POST on specified url.
-    /// Any HTTP error codes returned from the target URL are ignored. The HTTP request dispatch is
-    /// non blocking.
+    /// The delivery is handed over to Notifier, which retries failed attempts and keeps
+    /// undelivered notifications for replay. The HTTP request dispatch is non blocking.
     ///
     /// * `webhook_url` - URL address where the data shall be sent
     /// * `msg_notification` - metadata about received message
     fn send_webhook_notification(&self, webhook_url: &str, msg_notification: MessageNotification) {
-        let notification_id = msg_notification.notification_id.clone();
         let ser_msg_notification = serde_json::to_string(&msg_notification).unwrap();
-        debug!("Sending webhook notification {} to {} data", notification_id, webhook_url);
-        let send_notification = requester::REQWEST_CLIENT
-            .post(webhook_url)
-            .header("Accepts", "application/json")
-            .header("Content-type", "application/json")
-            .body(ser_msg_notification)
-            .send()
-            .map_err(move |error| {
-                error!("Problem sending webhook notification. NotificationId {} {:?}",
-                       notification_id, error);
-            })
-            .and_then(move |res| {
-                if let Err(res_err) = res.error_for_status_ref() {
-                    error!("Error code returned from webhook url. NotificationId {} {:?}",
-                           msg_notification.notification_id, res_err);
-                };
-                ok(())
-            });
-
-        Arbiter::spawn_fn(move || { send_notification });
+        debug!("Queueing webhook notification {} to {} data", msg_notification.notification_id, webhook_url);
+        let notification = WebhookNotification::new(&msg_notification.notification_id, webhook_url, ser_msg_notification,
+                                                     self.agent_configs.get("notificationWebhookSecret"));
+        Notifier::from_registry().do_send(SendWebhookNotification(notification));
     }
 
     fn get_webhook_for_message(&self, sender_did: &str, status_code: MessageStatusCode) -> Option<&String> {
@@ -1151,8 +1192,8 @@ impl AgentConnection {
     }
 
     /// Persists in-memory maintained state of agent connections and persists it into wallet as
-    /// pairwise metadata.
-    fn persist_connection_state(&self) -> ResponseActFuture<Self, (), Error> {
+    /// pairwise metadata. Messages are persisted as separate wallet records.
+    fn persist_connection_state(&mut self) -> ResponseActFuture<Self, (), Error> {
         future::ok(())
             .into_actor(self)
             .and_then(move |_, slf, _| {
@@ -1161,14 +1202,157 @@ impl AgentConnection {
                         .map_err(|err| err.context("Can't serialize connection state."))
                 });
 
+                slf.apply_message_retention();
+                let (changes, stored_messages) = ftry_act!(slf, slf.message_changes());
+
                 pairwise::set_pairwise_metadata(slf.wallet_handle, &slf.user_pairwise_did, &metadata)
                     .map_err(|err| err.context("Can't store connection state.").into())
+                    .join(Self::store_message_changes(slf.wallet_handle, changes))
                     .into_actor(slf)
+                    .map(move |_, slf, _| {
+                        // snapshot is updated only once the wallet is in sync, so failed changes are retried next time
+                        slf.stored_messages = stored_messages;
+                    })
                     .into_box()
             })
             .into_box()
     }
 
+    /// Discards messages according to message retention configured for the agency.
+    fn apply_message_retention(&mut self) {
+        let discarded = MessageRetention::get().messages_to_discard(&self.state.messages, now_epoch_secs());
+
+        for uid in discarded {
+            match self.state.messages.remove(&uid) {
+                Some(ref message) if message.status_code == MessageStatusCode::Received =>
+                    warn!("AgentConnection::apply_message_retention >> discarding message {:?} of connection {:?} not yet picked up by the owner",
+                          uid, self.user_pairwise_did),
+                _ =>
+                    debug!("AgentConnection::apply_message_retention >> discarding message {:?} of connection {:?}", uid, self.user_pairwise_did),
+            }
+        }
+    }
+
+    /// Compares in-memory messages with messages last persisted in wallet and returns changes of
+    /// wallet records required to bring the wallet in sync together with the snapshot of persisted
+    /// messages which should replace `stored_messages` once the changes are stored.
+    fn message_changes(&self) -> Result<(Vec<MessageRecordChange>, HashMap<String, serde_json::Value>), Error> {
+        let mut changes = Vec::new();
+        let mut stored_messages = HashMap::new();
+
+        for (uid, message) in self.state.messages.iter() {
+            let value = serde_json::to_value(message)
+                .map_err(|err| err.context("Can't serialize connection message."))?;
+
+            let id = self.message_record_id(uid);
+            let tags = json!({
+                "pairwise_did": self.user_pairwise_did,
+                "status_code": message.status_code.code(),
+            }).to_string();
+
+            match self.stored_messages.get(uid) {
+                None => changes.push(MessageRecordChange::Add { id, value: value.to_string(), tags }),
+                Some(stored_value) if stored_value != &value => changes.push(MessageRecordChange::Update { id, value: value.to_string(), tags }),
+                Some(_) => {}
+            }
+
+            stored_messages.insert(uid.to_string(), value);
+        }
+
+        for uid in self.stored_messages.keys().filter(|uid| !self.state.messages.contains_key(*uid)) {
+            changes.push(MessageRecordChange::Delete { id: self.message_record_id(uid) });
+        }
+
+        Ok((changes, stored_messages))
+    }
+
+    fn message_record_id(&self, uid: &str) -> String {
+        format!("{}:{}", self.user_pairwise_did, uid)
+    }
+
+    fn store_message_changes(wallet_handle: WalletHandle, changes: Vec<MessageRecordChange>) -> ResponseFuture<(), Error> {
+        trace!("AgentConnection::store_message_changes >> {:?}", changes);
+
+        let futures: Vec<_> = changes
+            .into_iter()
+            .map(move |change| match change {
+                MessageRecordChange::Add { id, value, tags } =>
+                    non_secrets::add_wallet_record(wallet_handle, MESSAGE_RECORD_TYPE, &id, &value, Some(&tags))
+                        .then(move |res| match res {
+                            // record may be left by partially failed previous store
+                            Err(IndyError { error_code: ErrorCode::WalletItemAlreadyExists, .. }) =>
+                                non_secrets::update_wallet_record_value(wallet_handle, MESSAGE_RECORD_TYPE, &id, &value)
+                                    .join(non_secrets::update_wallet_record_tags(wallet_handle, MESSAGE_RECORD_TYPE, &id, &tags))
+                                    .map(|_| ())
+                                    .into_box(),
+                            r => future::result(r).into_box()
+                        })
+                        .into_box(),
+                MessageRecordChange::Update { id, value, tags } =>
+                    non_secrets::update_wallet_record_value(wallet_handle, MESSAGE_RECORD_TYPE, &id, &value)
+                        .join(non_secrets::update_wallet_record_tags(wallet_handle, MESSAGE_RECORD_TYPE, &id, &tags))
+                        .map(|_| ())
+                        .into_box(),
+                MessageRecordChange::Delete { id } =>
+                    non_secrets::delete_wallet_record(wallet_handle, MESSAGE_RECORD_TYPE, &id)
+                        .then(|res| match res {
+                            Err(IndyError { error_code: ErrorCode::WalletItemNotFound, .. }) => Ok(()),
+                            r => r
+                        })
+                        .into_box(),
+            })
+            .collect();
+
+        future::join_all(futures)
+            .map(|_| ())
+            .map_err(|err| err.context("Can't store connection messages.").into())
+            .into_box()
+    }
+
+    /// Loads messages of connection with given user pairwise DID from agent's wallet.
+    fn load_messages(wallet_handle: WalletHandle, user_pairwise_did: &str) -> ResponseFuture<HashMap<String, InternalMessage>, Error> {
+        trace!("AgentConnection::load_messages >> {:?}, {:?}", wallet_handle, user_pairwise_did);
+
+        let query = json!({ "pairwise_did": user_pairwise_did }).to_string();
+        let options = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": false,
+        }).to_string();
+
+        non_secrets::open_wallet_search(wallet_handle, MESSAGE_RECORD_TYPE, &query, &options)
+            .map_err(|err| err.context("Can't open connection messages search.").into())
+            .and_then(move |search_handle| {
+                future::loop_fn(HashMap::new(), move |mut messages| {
+                    non_secrets::fetch_wallet_search_next_records(wallet_handle, search_handle, MESSAGE_SEARCH_BATCH_SIZE)
+                        .map_err(|err| err.context("Can't fetch connection messages.").into())
+                        .and_then(move |records| -> Result<Loop<HashMap<String, InternalMessage>, HashMap<String, InternalMessage>>, Error> {
+                            let records = serde_json::from_str::<MessageRecords>(&records)
+                                .map_err(|err| err.context("Can't deserialize connection messages."))?
+                                .records
+                                .unwrap_or_default();
+
+                            let last_batch = records.len() < MESSAGE_SEARCH_BATCH_SIZE;
+
+                            for record in records {
+                                let message = serde_json::from_str::<InternalMessage>(&record.value)
+                                    .map_err(|err| err.context("Can't deserialize connection message."))?;
+                                messages.insert(message.uid.clone(), message);
+                            }
+
+                            Ok(if last_batch { Loop::Break(messages) } else { Loop::Continue(messages) })
+                        })
+                })
+                    .then(move |res| {
+                        non_secrets::close_wallet_search(search_handle)
+                            .then(|_| res)
+                    })
+            })
+            .into_box()
+    }
+
     /// NOTE: Used only on non-Aries didcomm
     fn store_payload_for_connection_request_answer(&mut self,
                                                    msg_uid: &str,
@@ -1327,7 +1511,7 @@ impl AgentConnection {
 
         self.state.messages.get_mut(uid)
             .ok_or(err_msg("Message not found."))
-            .map(|message| message.status_code = status.clone())
+            .map(|message| message.set_status_code(status))
     }
 
     fn answer_message(&mut self, uid: &str, ref_msg_id: &str, status_code: &MessageStatusCode) -> Result<(), Error> {
@@ -1336,7 +1520,7 @@ impl AgentConnection {
         self.state.messages.get_mut(uid)
             .ok_or(err_msg("Message mot found."))
             .map(|message| {
-                message.status_code = status_code.clone();
+                message.set_status_code(status_code);
                 message.ref_msg_id = Some(ref_msg_id.to_string());
             })
     }
@@ -1853,16 +2037,24 @@ impl Handler<HandleA2AMsg> for AgentConnection {
 
     fn handle(&mut self, msg: HandleA2AMsg, _: &mut Self::Context) -> Self::Result {
         trace!("Handler<HandleA2AMsg>::handle >> {:?}", msg);
+        self.apply_message_retention();
         self.handle_a2a_msg(msg.0)
     }
 }
 
 impl Handler<HandleA2ConnMsg> for AgentConnection {
-    type Result = ResponseFuture<A2ConnMessage, Error>;
+    type Result = ResponseActFuture<Self, A2ConnMessage, Error>;
 
     fn handle(&mut self, msg: HandleA2ConnMsg, _: &mut Self::Context) -> Self::Result {
         trace!("Handler<HandleA2ConnectionMsg>::handle >> {:?}", msg);
+        self.apply_message_retention();
         self.handle_agent2conn_message(msg.0)
+            .into_actor(self)
+            .and_then(|msg, slf, _|
+                slf.persist_connection_state()
+                    .map(|_, _, _| msg)
+            )
+            .into_box()
     }
 }
 
@@ -1881,7 +2073,9 @@ impl Handler<HandleAdminMessage> for AgentConnection {
 
             logo: self.agent_configs.get("logoUrl").map_or_else(|| String::from("unknown"), |v| v.clone()),
             name: self.agent_configs.get("name").map_or_else(|| String::from("unknown"), |v| v.clone()),
-            agent_configs: self.agent_configs.iter().map(|(key, value)| (key.clone(), value.clone())).collect(),
+            agent_configs: self.agent_configs.iter()
+                .filter(|(key, _)| key.as_str() != "notificationWebhookSecret")
+                .map(|(key, value)| (key.clone(), value.clone())).collect(),
 
             remote_agent_detail_did: self.state.remote_connection_detail
                 .as_ref().map_or_else(|| "unknown".into(), |r| r.agent_detail.did.clone()),
@@ -1906,6 +2100,7 @@ enum MessageHandlerRole {
 #[cfg(test)]
 mod tests {
     use crate::actors::ForwardA2AMsg;
+    use crate::indy::wallet;
     use crate::utils::tests::*;
     use crate::utils::tests::compose_create_general_message;
 
@@ -2050,6 +2245,66 @@ mod tests {
         });
     }
 
+    #[test]
+    fn agent_get_messages_works_after_restart() {
+        let restart_context = Arc::new(RwLock::new(None));
+        let restart_context_for_check = restart_context.clone();
+
+        run_test(move |forward_agent, _| {
+            setup_agent(forward_agent)
+                .and_then(move |(e_wallet_handle, agent_did, agent_verkey, agent_pw_did, agent_pw_vk, forward_agent)| {
+                    let msg = compose_create_general_message(e_wallet_handle,
+                                                             &agent_did,
+                                                             &agent_verkey,
+                                                             &agent_pw_did,
+                                                             &agent_pw_vk,
+                                                             RemoteMessageType::CredOffer).wait().unwrap();
+
+                    forward_agent
+                        .send(ForwardA2AMsg(msg))
+                        .from_err()
+                        .and_then(|res| res)
+                        .map(move |resp| (e_wallet_handle, resp, agent_did, agent_verkey, agent_pw_did, agent_pw_vk))
+                })
+                .and_then(move |(e_wallet_handle, resp, agent_did, agent_verkey, agent_pw_did, agent_pw_vk)| {
+                    let (_, msg_uid) = decompose_general_message_created(e_wallet_handle, &resp).wait().unwrap();
+
+                    // agent wallet must be closed to be opened again by restarted agency
+                    let agent_wallet_handle = AGENT_WALLET_HANDLES.read().unwrap()[&agent_did];
+                    wallet::close_wallet(agent_wallet_handle).wait().unwrap();
+
+                    *restart_context.write().unwrap() = Some((e_wallet_handle, agent_did, agent_verkey, agent_pw_did, agent_pw_vk, msg_uid));
+                    Ok(())
+                })
+        });
+
+        run_restarted_test(move |forward_agent, _| {
+            let (e_wallet_handle, agent_did, agent_verkey, agent_pw_did, agent_pw_vk, msg_uid) =
+                restart_context_for_check.write().unwrap().take().unwrap();
+
+            let msg = compose_get_messages(e_wallet_handle,
+                                           &agent_did,
+                                           &agent_verkey,
+                                           &agent_pw_did,
+                                           &agent_pw_vk).wait().unwrap();
+
+            forward_agent
+                .send(ForwardA2AMsg(msg))
+                .from_err()
+                .and_then(|res| res)
+                .map(move |resp| {
+                    let (sender_vk, messages) = decompose_get_messages(e_wallet_handle, &resp).wait().unwrap();
+                    assert_eq!(sender_vk, agent_pw_vk);
+                    assert_eq!(1, messages.len());
+                    assert_eq!(msg_uid, messages[0].uid);
+                    assert_eq!(MessageStatusCode::Created, messages[0].status_code);
+                    assert_eq!(Some(MessageDetailPayload::V1(to_i8(&PAYLOAD.to_vec()))), messages[0].payload);
+
+                    wallet::close_wallet(e_wallet_handle).wait().unwrap();
+                })
+        });
+    }
+
     #[test]
     #[ignore] // TODO: FIXME prepare proper message
     fn agent_update_message_status_works() {
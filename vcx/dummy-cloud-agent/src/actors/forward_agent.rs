@@ -8,9 +8,10 @@ use futures::*;
 use futures::future::{Either, ok};
 use serde_json;
 
-use crate::actors::{Endpoint, ForwardA2AMsg, GetEndpoint, HandleA2AMsg, HandleAdminMessage};
+use crate::actors::{Endpoint, ForwardA2AMsg, GetEndpoint, HandleA2AMsg, HandleAdminMessage, RestoreWebhookNotifications};
 use crate::actors::admin::Admin;
 use crate::actors::forward_agent_connection::ForwardAgentConnection;
+use crate::actors::notifier::Notifier;
 use crate::actors::router::Router;
 use crate::domain::a2a::*;
 use crate::domain::admin_message::{ResAdminQuery, ResQueryForwardAgent};
@@ -78,6 +79,15 @@ impl ForwardAgent {
                     .map(|wallet_handle| (wallet_handle, config, wallet_storage_config))
                     .map_err(|err| err.context("Can't open Forward Agent wallet.`").into())
             })
+            .and_then(|(wallet_handle, config, wallet_storage_config)| {
+                // Restore webhook notifications left undelivered by previous run
+                Notifier::from_registry()
+                    .send(RestoreWebhookNotifications(wallet_handle))
+                    .from_err()
+                    .and_then(|res| res)
+                    .map(move |_| (wallet_handle, config, wallet_storage_config))
+                    .map_err(|err: Error| err.context("Can't restore webhook notifications.").into())
+            })
             .and_then(move |(wallet_handle, config, wallet_storage_config)| {
                 #[cfg(test)]
                     unsafe {
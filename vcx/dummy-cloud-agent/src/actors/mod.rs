@@ -3,6 +3,8 @@ use failure::*;
 
 use crate::domain::a2connection::A2ConnMessage;
 use crate::domain::admin_message::{AdminQuery, ResAdminQuery};
+use crate::domain::webhook::WebhookNotification;
+use crate::indy::WalletHandle;
 
 pub mod router;
 pub mod forward_agent;
@@ -11,6 +13,7 @@ pub mod forward_agent_connection;
 pub mod agent;
 pub mod agent_connection;
 pub mod requester;
+pub mod notifier;
 
 #[derive(Debug, Clone)]
 pub struct HandleAdminMessage(pub AdminQuery);
@@ -71,3 +74,34 @@ pub struct RemoteMsg {
 impl Message for RemoteMsg {
     type Result = Result<(), Error>;
 }
+
+#[derive(Debug)]
+pub struct SendWebhookNotification(pub WebhookNotification);
+
+impl Message for SendWebhookNotification {
+    type Result = ();
+}
+
+/// Starts storing undelivered webhook notifications in given Forward Agent wallet and restores the ones
+/// stored by previous run of the agency
+#[derive(Debug)]
+pub struct RestoreWebhookNotifications(pub WalletHandle);
+
+impl Message for RestoreWebhookNotifications {
+    type Result = Result<(), Error>;
+}
+
+#[derive(Debug)]
+pub struct GetFailedWebhookNotifications();
+
+impl Message for GetFailedWebhookNotifications {
+    type Result = Result<Vec<WebhookNotification>, Error>;
+}
+
+/// Replays failed webhook notification with given id, or all failed notifications if id is not set
+#[derive(Debug)]
+pub struct ReplayFailedWebhookNotifications(pub Option<String>);
+
+impl Message for ReplayFailedWebhookNotifications {
+    type Result = Result<Vec<String>, Error>;
+}
@@ -0,0 +1,561 @@
+use std::collections::VecDeque;
+
+use actix::fut;
+use actix::prelude::*;
+use failure::{err_msg, Error, Fail};
+use futures::*;
+use futures::future::Loop;
+
+use crate::actors::{GetFailedWebhookNotifications, ReplayFailedWebhookNotifications, requester, RestoreWebhookNotifications, SendWebhookNotification};
+use crate::domain::webhook::{NOTIFICATION_ID_HEADER, SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookDelivery, WebhookDeliveryConfig, WebhookNotification};
+use crate::indy::{ErrorCode, IndyError, non_secrets, WalletHandle};
+use crate::utils::futures::*;
+use crate::utils::now_epoch_secs;
+
+/// Wallet record type of webhook notifications which failed at least one delivery attempt
+const NOTIFICATION_RECORD_TYPE: &str = "WebhookNotification";
+
+/// Amount of notification records fetched from wallet at once when restoring notifications
+const NOTIFICATION_SEARCH_BATCH_SIZE: usize = 100;
+
+#[derive(Debug, Deserialize)]
+struct NotificationRecords {
+    records: Option<Vec<NotificationRecord>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct NotificationRecord {
+    value: String,
+}
+
+/// Delivers webhook notifications to agents' owners. Notification which can't be delivered is
+/// retried with exponential backoff. Once all delivery attempts are exhausted, the notification
+/// is kept in the list of failed notifications, which can be inspected and replayed via Admin API.
+/// Notifications which failed a delivery attempt are stored in Forward Agent wallet until they are
+/// delivered or dropped, so pending retries and failed notifications survive agency restart.
+///
+/// Notifier is a system service, there's single instance of it per agency. Its configuration
+/// is determined by `webhook_delivery` section of agency configuration.
+pub struct Notifier {
+    // Delivery configuration
+    config: WebhookDeliveryConfig,
+    // Notifications which exhausted all delivery attempts, the oldest first
+    failed: VecDeque<WebhookNotification>,
+    // Forward Agent wallet where undelivered notifications are stored. Set once notifications are restored.
+    wallet_handle: Option<WalletHandle>,
+}
+
+impl Notifier {
+    pub fn new(config: WebhookDeliveryConfig) -> Notifier {
+        trace!("Notifier::new >> {:?}", config);
+
+        Notifier {
+            config,
+            failed: VecDeque::new(),
+            wallet_handle: None,
+        }
+    }
+
+    /// Dispatches notification as HTTP POST to its webhook URL. Any non-success HTTP status
+    /// returned from the webhook URL is considered failed delivery attempt.
+    fn deliver(&mut self, notification: WebhookNotification, ctx: &mut Context<Self>) {
+        debug!("Sending webhook notification {} to {}, attempt {}",
+               notification.notification_id, notification.webhook_url, notification.attempts + 1);
+
+        let timestamp = now_epoch_secs();
+
+        let signature = match notification.sign(timestamp) {
+            Ok(signature) => signature,
+            Err(err) => {
+                error!("Can't sign webhook notification {} {:?}", notification.notification_id, err);
+                return self.add_failed(notification, format!("{:?}", err), ctx);
+            }
+        };
+
+        let mut request = requester::REQWEST_CLIENT
+            .post(&notification.webhook_url)
+            .header("Accepts", "application/json")
+            .header("Content-type", "application/json")
+            .header(NOTIFICATION_ID_HEADER, notification.notification_id.as_str());
+
+        if let Some(signature) = signature {
+            request = request
+                .header(TIMESTAMP_HEADER, timestamp.to_string())
+                .header(SIGNATURE_HEADER, format!("sha256={}", signature));
+        }
+
+        let send_notification = request
+            .body(notification.body.clone())
+            .send()
+            .map_err(|err| format!("Problem sending webhook notification {:?}", err))
+            .and_then(|res| {
+                res.error_for_status()
+                    .map(|_| ())
+                    .map_err(|err| format!("Error code returned from webhook url {:?}", err))
+            })
+            .into_actor(self)
+            .then(move |res, slf, ctx| {
+                match res {
+                    Ok(()) => {
+                        debug!("Webhook notification {} delivered", notification.notification_id);
+                        // only notifications which failed some attempt are stored
+                        if notification.last_error.is_some() {
+                            slf.forget(&notification.notification_id, ctx);
+                        }
+                    }
+                    Err(err) => slf.handle_failed_attempt(notification, err, ctx)
+                }
+                fut::ok(())
+            });
+
+        ctx.spawn(send_notification);
+    }
+
+    fn handle_failed_attempt(&mut self, mut notification: WebhookNotification, err: String, ctx: &mut Context<Self>) {
+        notification.attempts += 1;
+
+        if notification.attempts >= self.config.max_attempts {
+            error!("Webhook notification {} failed after {} attempts. {}",
+                   notification.notification_id, notification.attempts, err);
+            return self.add_failed(notification, err, ctx);
+        }
+
+        let backoff = self.config.backoff(notification.attempts);
+        warn!("Webhook notification {} failed, retrying in {:?}. {}",
+              notification.notification_id, backoff, err);
+        notification.last_error = Some(err);
+        self.store(&notification, ctx);
+
+        ctx.run_later(backoff, move |slf, ctx| slf.deliver(notification, ctx));
+    }
+
+    fn add_failed(&mut self, mut notification: WebhookNotification, err: String, ctx: &mut Context<Self>) {
+        notification.last_error = Some(err);
+        self.store(&notification, ctx);
+        self.keep_failed(notification, ctx);
+    }
+
+    fn keep_failed(&mut self, notification: WebhookNotification, ctx: &mut Context<Self>) {
+        self.failed.push_back(notification);
+        while self.failed.len() > self.config.failed_capacity {
+            if let Some(dropped) = self.failed.pop_front() {
+                warn!("Dropping failed webhook notification {}", dropped.notification_id);
+                self.forget(&dropped.notification_id, ctx);
+            }
+        }
+    }
+
+    fn replay_failed(&mut self, notification_id: Option<String>, ctx: &mut Context<Self>) -> Result<Vec<String>, Error> {
+        let (replayed, failed): (VecDeque<_>, VecDeque<_>) = self.failed
+            .drain(..)
+            .partition(|notification| notification_id.as_ref()
+                .map(|notification_id| notification_id == &notification.notification_id)
+                .unwrap_or(true));
+        self.failed = failed;
+
+        if replayed.is_empty() && notification_id.is_some() {
+            return Err(err_msg("Failed webhook notification not found."));
+        }
+
+        Ok(replayed
+            .into_iter()
+            .map(|mut notification| {
+                let notification_id = notification.notification_id.clone();
+                notification.attempts = 0;
+                self.store(&notification, ctx);
+                self.deliver(notification, ctx);
+                notification_id
+            })
+            .collect())
+    }
+
+    /// Stores undelivered notification into wallet, replacing its previous state.
+    fn store(&self, notification: &WebhookNotification, ctx: &mut Context<Self>) {
+        let wallet_handle = match self.wallet_handle {
+            Some(wallet_handle) => wallet_handle,
+            None => return
+        };
+
+        let notification_id = notification.notification_id.clone();
+        let value = match serde_json::to_string(notification) {
+            Ok(value) => value,
+            Err(err) => return error!("Can't serialize webhook notification {} {:?}", notification_id, err)
+        };
+
+        let store = non_secrets::add_wallet_record(wallet_handle, NOTIFICATION_RECORD_TYPE, &notification_id, &value, None)
+            .then(move |res| match res {
+                Err(IndyError { error_code: ErrorCode::WalletItemAlreadyExists, .. }) =>
+                    non_secrets::update_wallet_record_value(wallet_handle, NOTIFICATION_RECORD_TYPE, &notification_id, &value),
+                r => future::result(r).into_box()
+            })
+            .map_err(|err| error!("Can't store webhook notification {:?}", err))
+            .into_actor(self);
+
+        ctx.spawn(store);
+    }
+
+    /// Removes delivered or dropped notification from wallet.
+    fn forget(&self, notification_id: &str, ctx: &mut Context<Self>) {
+        let wallet_handle = match self.wallet_handle {
+            Some(wallet_handle) => wallet_handle,
+            None => return
+        };
+
+        let forget = non_secrets::delete_wallet_record(wallet_handle, NOTIFICATION_RECORD_TYPE, notification_id)
+            .then(|res| match res {
+                Err(IndyError { error_code: ErrorCode::WalletItemNotFound, .. }) => Ok(()),
+                r => r
+            })
+            .map_err(|err| error!("Can't remove webhook notification {:?}", err))
+            .into_actor(self);
+
+        ctx.spawn(forget);
+    }
+
+    /// Loads notifications stored in wallet by previous run of the agency.
+    fn load(wallet_handle: WalletHandle) -> ResponseFuture<Vec<WebhookNotification>, Error> {
+        let options = json!({
+            "retrieveRecords": true,
+            "retrieveTotalCount": false,
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": false,
+        }).to_string();
+
+        non_secrets::open_wallet_search(wallet_handle, NOTIFICATION_RECORD_TYPE, "{}", &options)
+            .map_err(|err| err.context("Can't open webhook notifications search.").into())
+            .and_then(move |search_handle| {
+                future::loop_fn(Vec::new(), move |mut notifications| {
+                    non_secrets::fetch_wallet_search_next_records(wallet_handle, search_handle, NOTIFICATION_SEARCH_BATCH_SIZE)
+                        .map_err(|err| err.context("Can't fetch webhook notifications.").into())
+                        .and_then(move |records| -> Result<Loop<Vec<WebhookNotification>, Vec<WebhookNotification>>, Error> {
+                            let records = serde_json::from_str::<NotificationRecords>(&records)
+                                .map_err(|err| err.context("Can't deserialize webhook notifications."))?
+                                .records
+                                .unwrap_or_default();
+
+                            let last_batch = records.len() < NOTIFICATION_SEARCH_BATCH_SIZE;
+
+                            for record in records {
+                                let notification = serde_json::from_str::<WebhookNotification>(&record.value)
+                                    .map_err(|err| err.context("Can't deserialize webhook notification."))?;
+                                notifications.push(notification);
+                            }
+
+                            Ok(if last_batch { Loop::Break(notifications) } else { Loop::Continue(notifications) })
+                        })
+                })
+                    .then(move |res| {
+                        non_secrets::close_wallet_search(search_handle)
+                            .then(|_| res)
+                    })
+            })
+            .into_box()
+    }
+
+    /// Starts storing undelivered notifications into the wallet. Notifications stored by previous
+    /// run of the agency are restored: failed ones are kept for replay, pending ones are retried.
+    fn restore(&mut self, wallet_handle: WalletHandle) -> ResponseActFuture<Self, (), Error> {
+        self.wallet_handle = Some(wallet_handle);
+
+        Notifier::load(wallet_handle)
+            .into_actor(self)
+            .map(|mut notifications, slf, ctx| {
+                info!("Restoring {} webhook notifications", notifications.len());
+                notifications.sort_by_key(|notification| notification.created_at);
+
+                for notification in notifications {
+                    if notification.attempts >= slf.config.max_attempts {
+                        slf.keep_failed(notification, ctx);
+                    } else {
+                        slf.deliver(notification, ctx);
+                    }
+                }
+            })
+            .into_box()
+    }
+}
+
+impl Default for Notifier {
+    fn default() -> Self {
+        Notifier::new(WebhookDelivery::get())
+    }
+}
+
+impl Actor for Notifier {
+    type Context = Context<Self>;
+}
+
+impl Supervised for Notifier {}
+
+impl SystemService for Notifier {}
+
+impl Handler<SendWebhookNotification> for Notifier {
+    type Result = ();
+
+    fn handle(&mut self, msg: SendWebhookNotification, ctx: &mut Self::Context) -> Self::Result {
+        trace!("Handler<SendWebhookNotification>::handle >> {:?}", msg);
+        self.deliver(msg.0, ctx)
+    }
+}
+
+impl Handler<RestoreWebhookNotifications> for Notifier {
+    type Result = ResponseActFuture<Self, (), Error>;
+
+    fn handle(&mut self, msg: RestoreWebhookNotifications, _: &mut Self::Context) -> Self::Result {
+        trace!("Handler<RestoreWebhookNotifications>::handle >> {:?}", msg);
+        self.restore(msg.0)
+    }
+}
+
+impl Handler<GetFailedWebhookNotifications> for Notifier {
+    type Result = Result<Vec<WebhookNotification>, Error>;
+
+    fn handle(&mut self, _msg: GetFailedWebhookNotifications, _: &mut Self::Context) -> Self::Result {
+        trace!("Handler<GetFailedWebhookNotifications>::handle >>");
+        Ok(self.failed.iter().map(WebhookNotification::without_secret).collect())
+    }
+}
+
+impl Handler<ReplayFailedWebhookNotifications> for Notifier {
+    type Result = Result<Vec<String>, Error>;
+
+    fn handle(&mut self, msg: ReplayFailedWebhookNotifications, ctx: &mut Self::Context) -> Self::Result {
+        trace!("Handler<ReplayFailedWebhookNotifications>::handle >> {:?}", msg);
+        self.replay_failed(msg.0, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::domain::webhook::sign_body;
+    use crate::indy::wallet;
+    use crate::utils::tests::cleanup_storage;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct ReceivedRequest {
+        headers: Vec<(String, String)>,
+        body: String,
+    }
+
+    impl ReceivedRequest {
+        fn header(&self, name: &str) -> Option<&str> {
+            self.headers.iter()
+                .find(|(key, _)| key.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    /// Starts local HTTP server standing in for webhook receiver. The server answers received
+    /// requests with given HTTP statuses in order and reports each received request.
+    fn start_webhook_stand_in(statuses: Vec<u16>) -> (String, mpsc::Receiver<ReceivedRequest>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/webhook", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for status in statuses {
+                let mut stream = match listener.accept() {
+                    Ok((stream, _)) => stream,
+                    Err(_) => return
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut headers = Vec::new();
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                loop {
+                    line.clear();
+                    reader.read_line(&mut line).unwrap();
+                    let header = line.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let mut parts = header.splitn(2, ':');
+                    headers.push((parts.next().unwrap().trim().to_string(), parts.next().unwrap_or("").trim().to_string()));
+                }
+
+                let content_length = headers.iter()
+                    .find(|(key, _)| key.eq_ignore_ascii_case("content-length"))
+                    .map(|(_, value)| value.parse::<usize>().unwrap())
+                    .unwrap_or(0);
+                let mut body = vec![0; content_length];
+                reader.read_exact(&mut body).unwrap();
+
+                write!(stream, "HTTP/1.1 {} Stand-in\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", status).unwrap();
+                stream.flush().unwrap();
+
+                let _ = sender.send(ReceivedRequest { headers, body: String::from_utf8(body).unwrap() });
+            }
+        });
+
+        (url, receiver)
+    }
+
+    fn start_notifier(config: WebhookDeliveryConfig) -> (Addr<Notifier>, System) {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            let sys = System::new("notifier-test");
+            let notifier = Notifier::new(config).start();
+            sender.send((notifier, System::current())).unwrap();
+            let _ = sys.run();
+        });
+
+        receiver.recv().unwrap()
+    }
+
+    fn _config() -> WebhookDeliveryConfig {
+        WebhookDeliveryConfig {
+            max_attempts: 2,
+            initial_backoff_ms: 10,
+            max_backoff_ms: 100,
+            failed_capacity: 10,
+        }
+    }
+
+    fn _notification(webhook_url: &str, notification_id: &str) -> WebhookNotification {
+        WebhookNotification::new(notification_id, webhook_url, json!({"msgUid": "uid", "notificationId": notification_id}).to_string(),
+                                 Some(&String::from("webhook-secret")))
+    }
+
+    fn _receive(receiver: &mpsc::Receiver<ReceivedRequest>) -> ReceivedRequest {
+        receiver.recv_timeout(Duration::from_secs(10)).expect("Webhook notification not received")
+    }
+
+    fn _failed_notifications(notifier: &Addr<Notifier>, expected: usize) -> Vec<WebhookNotification> {
+        for _ in 0..100 {
+            let failed = notifier.send(GetFailedWebhookNotifications()).wait().unwrap().unwrap();
+            if failed.len() == expected {
+                return failed;
+            }
+            thread::sleep(Duration::from_millis(20));
+        }
+        panic!("Expected {} failed webhook notifications", expected)
+    }
+
+    #[test]
+    fn notifier_sends_signed_notification() {
+        let (webhook_url, receiver) = start_webhook_stand_in(vec![200]);
+        let (notifier, sys) = start_notifier(_config());
+
+        let notification = _notification(&webhook_url, "notification-1");
+        notifier.do_send(SendWebhookNotification(notification.clone()));
+
+        let request = _receive(&receiver);
+        assert_eq!(request.body, notification.body);
+        assert_eq!(request.header(NOTIFICATION_ID_HEADER), Some("notification-1"));
+        let timestamp = request.header(TIMESTAMP_HEADER).unwrap().parse::<u64>().unwrap();
+        assert!(now_epoch_secs() - timestamp < 60);
+        let expected_signature = format!("sha256={}", sign_body("webhook-secret", timestamp, &notification.body).unwrap());
+        assert_eq!(request.header(SIGNATURE_HEADER), Some(expected_signature.as_str()));
+        assert!(_failed_notifications(&notifier, 0).is_empty());
+
+        sys.stop();
+    }
+
+    #[test]
+    fn notifier_retries_failed_notification() {
+        let (webhook_url, receiver) = start_webhook_stand_in(vec![503, 200]);
+        let (notifier, sys) = start_notifier(_config());
+
+        notifier.do_send(SendWebhookNotification(_notification(&webhook_url, "notification-1")));
+
+        let first = _receive(&receiver);
+        let second = _receive(&receiver);
+        assert_eq!(first.body, second.body);
+        assert!(_failed_notifications(&notifier, 0).is_empty());
+
+        sys.stop();
+    }
+
+    #[test]
+    fn notifier_keeps_and_replays_undelivered_notification() {
+        let (webhook_url, receiver) = start_webhook_stand_in(vec![500, 500, 200]);
+        let (notifier, sys) = start_notifier(_config());
+
+        notifier.do_send(SendWebhookNotification(_notification(&webhook_url, "notification-1")));
+        _receive(&receiver);
+        _receive(&receiver);
+
+        let failed = _failed_notifications(&notifier, 1);
+        assert_eq!(failed[0].notification_id, "notification-1");
+        assert_eq!(failed[0].attempts, 2);
+        assert!(failed[0].last_error.is_some());
+        assert!(failed[0].secret.is_none());
+
+        let res = notifier.send(ReplayFailedWebhookNotifications(Some(String::from("unknown")))).wait().unwrap();
+        assert!(res.is_err());
+
+        let replayed = notifier.send(ReplayFailedWebhookNotifications(Some(String::from("notification-1")))).wait().unwrap().unwrap();
+        assert_eq!(replayed, vec!["notification-1".to_string()]);
+
+        let request = _receive(&receiver);
+        assert_eq!(request.header(NOTIFICATION_ID_HEADER), Some("notification-1"));
+        assert!(request.header(SIGNATURE_HEADER).is_some());
+        assert!(_failed_notifications(&notifier, 0).is_empty());
+
+        sys.stop();
+    }
+
+    #[test]
+    fn notifier_restores_undelivered_notifications_from_wallet() {
+        cleanup_storage();
+
+        let wallet_config = json!({"id": "notifier_wallet_id"}).to_string();
+        let wallet_credentials = json!({"key": "notifier_wallet_passphrase"}).to_string();
+        wallet::create_wallet(&wallet_config, &wallet_credentials).wait().unwrap();
+        let wallet_handle = wallet::open_wallet(&wallet_config, &wallet_credentials).wait().unwrap();
+
+        let (webhook_url, receiver) = start_webhook_stand_in(vec![500, 500, 200]);
+        let (notifier, sys) = start_notifier(_config());
+        notifier.send(RestoreWebhookNotifications(wallet_handle)).wait().unwrap().unwrap();
+
+        notifier.do_send(SendWebhookNotification(_notification(&webhook_url, "notification-1")));
+        _receive(&receiver);
+        _receive(&receiver);
+        _failed_notifications(&notifier, 1);
+
+        let stored = (0..100)
+            .map(|_| {
+                thread::sleep(Duration::from_millis(20));
+                Notifier::load(wallet_handle).wait().unwrap()
+            })
+            .find(|stored| stored.len() == 1 && stored[0].attempts == 2)
+            .expect("Failed webhook notification not stored");
+        assert_eq!(stored[0].secret, Some(String::from("webhook-secret")));
+
+        sys.stop();
+
+        // agency restart
+        let (notifier, sys) = start_notifier(_config());
+        notifier.send(RestoreWebhookNotifications(wallet_handle)).wait().unwrap().unwrap();
+
+        let failed = _failed_notifications(&notifier, 1);
+        assert_eq!(failed[0].notification_id, "notification-1");
+
+        notifier.send(ReplayFailedWebhookNotifications(None)).wait().unwrap().unwrap();
+        let request = _receive(&receiver);
+        assert_eq!(request.header(NOTIFICATION_ID_HEADER), Some("notification-1"));
+        assert!(request.header(SIGNATURE_HEADER).is_some());
+
+        let forgotten = (0..100)
+            .map(|_| {
+                thread::sleep(Duration::from_millis(20));
+                Notifier::load(wallet_handle).wait().unwrap()
+            })
+            .any(|stored| stored.is_empty());
+        assert!(forgotten, "Delivered webhook notification not removed");
+
+        sys.stop();
+        wallet::close_wallet(wallet_handle).wait().unwrap();
+    }
+}
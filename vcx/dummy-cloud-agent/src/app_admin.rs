@@ -18,6 +18,11 @@ struct AgentParams {
     did: String,
 }
 
+#[derive(Deserialize)]
+struct WebhookNotificationParams {
+    notification_id: String,
+}
+
 pub fn start_app_admin_server(server_admin_config: &ServerAdminConfig, admin_agent: Arc<RwLock<Admin>>) {
     info!("Creating Admin HttpServer using config {:?}", server_admin_config);
     let mut server = HttpServer::new(move || {
@@ -40,6 +45,18 @@ pub fn start_app_admin_server(server_admin_config: &ServerAdminConfig, admin_age
                     web::resource("/admin/agent-connection/{did}")
                         .route(web::get().to(_get_agent_connection_details))
                 )
+                .service(
+                    web::resource("/admin/webhook/failed")
+                        .route(web::get().to(_get_failed_webhook_notifications))
+                )
+                .service(
+                    web::resource("/admin/webhook/failed/replay")
+                        .route(web::post().to(_replay_failed_webhook_notifications))
+                )
+                .service(
+                    web::resource("/admin/webhook/failed/{notification_id}/replay")
+                        .route(web::post().to(_replay_failed_webhook_notification))
+                )
     });
     for address in &server_admin_config.addresses {
         server = server
@@ -82,3 +99,26 @@ fn _get_forward_agent_details(state: Data<AdminAppData>) -> Box<dyn Future<Item=
     Box::new(f)
 }
 
+fn _get_failed_webhook_notifications(state: Data<AdminAppData>) -> Box<dyn Future<Item=HttpResponse, Error=Error>> {
+    let f = state.admin_agent.read().unwrap()
+        .get_failed_webhook_notifications()
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|err| HttpResponse::InternalServerError().body(format!("{:?}", err)).into());
+    Box::new(f)
+}
+
+fn _replay_failed_webhook_notifications(state: Data<AdminAppData>) -> Box<dyn Future<Item=HttpResponse, Error=Error>> {
+    let f = state.admin_agent.read().unwrap()
+        .replay_failed_webhook_notifications(None)
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|err| HttpResponse::InternalServerError().body(format!("{:?}", err)).into());
+    Box::new(f)
+}
+
+fn _replay_failed_webhook_notification(state: Data<AdminAppData>, info: web::Path<WebhookNotificationParams>) -> Box<dyn Future<Item=HttpResponse, Error=Error>> {
+    let f = state.admin_agent.read().unwrap()
+        .replay_failed_webhook_notifications(Some(info.notification_id.clone()))
+        .map(|res| HttpResponse::Ok().json(res))
+        .map_err(|err| HttpResponse::InternalServerError().body(format!("{:?}", err)).into());
+    Box::new(f)
+}
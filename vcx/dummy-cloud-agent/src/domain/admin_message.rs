@@ -1,5 +1,7 @@
 use indyrs::WalletHandle;
 
+use crate::domain::webhook::WebhookNotification;
+
 // --------
 // Requests
 // --------
@@ -28,6 +30,8 @@ pub enum ResAdminQuery {
     ForwardAgent(ResQueryForwardAgent),
     Agent(ResQueryAgent),
     AgentConn(ResQueryAgentConn),
+    FailedWebhookNotifications(ResQueryFailedWebhookNotifications),
+    ReplayedWebhookNotifications(ResQueryReplayedWebhookNotifications),
 }
 
 // --------
@@ -81,4 +85,16 @@ pub struct ResQueryAgentConn {
     pub remote_forward_agent_detail_did: String,
     pub remote_forward_agent_detail_endpoint: String,
 
-}
\ No newline at end of file
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResQueryFailedWebhookNotifications {
+    pub notifications: Vec<WebhookNotification>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ResQueryReplayedWebhookNotifications {
+    pub notification_ids: Vec<String>,
+}
@@ -1,6 +1,8 @@
 use serde_json::Value;
 
+use crate::domain::message_retention::MessageRetentionConfig;
 use crate::domain::protocol_type::ProtocolTypes;
+use crate::domain::webhook::WebhookDeliveryConfig;
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct Config {
@@ -10,7 +12,9 @@ pub struct Config {
     pub server_admin: Option<ServerAdminConfig>,
     pub wallet_storage: WalletStorageConfig,
     pub protocol_type: Option<ProtocolTypes>,
-    pub indy_runtime: Option<IndyRuntimeConfig>
+    pub indy_runtime: Option<IndyRuntimeConfig>,
+    pub message_retention: Option<MessageRetentionConfig>,
+    pub webhook_delivery: Option<WebhookDeliveryConfig>
 }
 
 #[derive(Clone, Debug, Deserialize)]
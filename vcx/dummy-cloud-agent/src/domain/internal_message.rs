@@ -4,6 +4,7 @@ use crate::domain::invite::RedirectDetail;
 use crate::domain::a2a::RemoteMessageType;
 use crate::domain::payload::Thread;
 use crate::domain::status::MessageStatusCode;
+use crate::utils::now_epoch_secs;
 use crate::utils::rand::rand_string;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -17,6 +18,12 @@ pub struct InternalMessage {
     pub sending_data: HashMap<String, Option<String>>,
     pub thread: Option<Thread>,
     pub redirect_detail: Option<RedirectDetail>,
+    // Unix timestamp (seconds) of message creation
+    #[serde(default)]
+    pub created_at: u64,
+    // Unix timestamp (seconds) of the last status change
+    #[serde(default)]
+    pub updated_at: u64,
 }
 
 impl InternalMessage {
@@ -30,6 +37,7 @@ impl InternalMessage {
                thread: Option<Thread>,
                redirect_detail: Option<RedirectDetail>
     ) -> InternalMessage {
+        let now = now_epoch_secs();
         InternalMessage {
             uid: uid.map(String::from).unwrap_or(rand_string(10)),
             _type: mtype.clone(),
@@ -39,7 +47,14 @@ impl InternalMessage {
             payload,
             sending_data: sending_data.unwrap_or(HashMap::new()),
             thread,
-            redirect_detail
+            redirect_detail,
+            created_at: now,
+            updated_at: now,
         }
     }
-}
\ No newline at end of file
+
+    pub fn set_status_code(&mut self, status_code: &MessageStatusCode) {
+        self.status_code = status_code.clone();
+        self.updated_at = now_epoch_secs();
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::domain::internal_message::InternalMessage;
+use crate::domain::status::MessageStatusCode;
+
+lazy_static! {
+    static ref MESSAGE_RETENTION: RwLock<MessageRetentionConfig> = RwLock::new(MessageRetentionConfig::default());
+}
+
+pub struct MessageRetention {}
+
+impl MessageRetention {
+    pub fn set(message_retention_config: Option<MessageRetentionConfig>) {
+        let mut retention = MESSAGE_RETENTION.write().unwrap();
+        *retention = message_retention_config.unwrap_or_default();
+    }
+
+    pub fn get() -> MessageRetentionConfig {
+        MESSAGE_RETENTION.read().unwrap().clone()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct MessageRetentionConfig {
+    // Seconds a message is kept after it reached given status, keyed by status code (e.g. "MS-104").
+    // Messages in statuses not listed here are kept indefinitely.
+    #[serde(default)]
+    pub ttl: HashMap<String, u64>,
+    // Maximal amount of messages kept per agent connection. Unlimited if not set.
+    pub max_messages_per_connection: Option<usize>,
+}
+
+impl MessageRetentionConfig {
+    /// Returns uids of messages which should be discarded. Messages with expired TTL are
+    /// discarded first. If the connection still exceeds its quota, the oldest messages are
+    /// evicted, messages not yet picked up by the owner (MS-103) last.
+    pub fn messages_to_discard(&self, messages: &HashMap<String, InternalMessage>, now: u64) -> Vec<String> {
+        let (mut expired, mut retained): (Vec<&InternalMessage>, Vec<&InternalMessage>) = messages
+            .values()
+            .partition(|message| self.is_expired(message, now));
+
+        if let Some(max_messages) = self.max_messages_per_connection {
+            if retained.len() > max_messages {
+                retained.sort_by_key(|message| (message.status_code == MessageStatusCode::Received, message.created_at));
+                let overflow = retained.len() - max_messages;
+                expired.extend(retained.drain(..overflow));
+            }
+        }
+
+        expired.into_iter().map(|message| message.uid.clone()).collect()
+    }
+
+    fn is_expired(&self, message: &InternalMessage, now: u64) -> bool {
+        self.ttl.get(message.status_code.code())
+            .map(|ttl| message.updated_at.saturating_add(*ttl) <= now)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::a2a::RemoteMessageType;
+
+    use super::*;
+
+    fn _message(uid: &str, status_code: MessageStatusCode, created_at: u64) -> InternalMessage {
+        let mut message = InternalMessage::new(Some(uid), RemoteMessageType::CredOffer, status_code, "sender_did", None, None, None, None, None);
+        message.created_at = created_at;
+        message.updated_at = created_at;
+        message
+    }
+
+    fn _messages() -> HashMap<String, InternalMessage> {
+        vec![
+            _message("1", MessageStatusCode::Received, 100),
+            _message("2", MessageStatusCode::Accepted, 200),
+            _message("3", MessageStatusCode::Received, 300),
+            _message("4", MessageStatusCode::Reviewed, 400),
+        ].into_iter().map(|message| (message.uid.clone(), message)).collect()
+    }
+
+    #[test]
+    fn default_retention_keeps_all_messages() {
+        let retention = MessageRetentionConfig::default();
+        assert!(retention.messages_to_discard(&_messages(), 1_000_000).is_empty());
+    }
+
+    #[test]
+    fn retention_discards_messages_with_expired_ttl() {
+        let retention: MessageRetentionConfig = serde_json::from_value(json!({
+            "ttl": {"MS-104": 50, "MS-106": 200}
+        })).unwrap();
+
+        assert_eq!(retention.messages_to_discard(&_messages(), 249), Vec::<String>::new());
+        assert_eq!(retention.messages_to_discard(&_messages(), 250), vec!["2".to_string()]);
+
+        let mut discarded = retention.messages_to_discard(&_messages(), 600);
+        discarded.sort();
+        assert_eq!(discarded, vec!["2".to_string(), "4".to_string()]);
+    }
+
+    #[test]
+    fn retention_evicts_oldest_picked_up_messages_over_quota() {
+        let retention: MessageRetentionConfig = serde_json::from_value(json!({
+            "max_messages_per_connection": 2
+        })).unwrap();
+
+        let mut discarded = retention.messages_to_discard(&_messages(), 0);
+        discarded.sort();
+        assert_eq!(discarded, vec!["2".to_string(), "4".to_string()]);
+
+        let retention: MessageRetentionConfig = serde_json::from_value(json!({
+            "max_messages_per_connection": 1
+        })).unwrap();
+
+        let mut discarded = retention.messages_to_discard(&_messages(), 0);
+        discarded.sort();
+        assert_eq!(discarded, vec!["1".to_string(), "2".to_string(), "4".to_string()]);
+    }
+}
@@ -7,6 +7,8 @@ pub mod key_deligation_proof;
 pub mod status;
 pub mod internal_message;
 pub mod message_type;
+pub mod message_retention;
 pub mod payload;
 pub mod protocol_type;
 pub mod key_derivation;
+pub mod webhook;
@@ -17,6 +17,18 @@ pub enum MessageStatusCode {
 }
 
 impl MessageStatusCode{
+    pub fn code(&self) -> &'static str {
+        match self {
+            MessageStatusCode::Created => "MS-101",
+            MessageStatusCode::Sent => "MS-102",
+            MessageStatusCode::Received => "MS-103",
+            MessageStatusCode::Accepted => "MS-104",
+            MessageStatusCode::Rejected => "MS-105",
+            MessageStatusCode::Reviewed => "MS-106",
+            MessageStatusCode::Redirected => "MS-107",
+        }
+    }
+
     pub fn message(&self) -> &'static str{
         match self {
             MessageStatusCode::Created => "message created",
@@ -0,0 +1,166 @@
+use std::sync::RwLock;
+use std::time::Duration;
+
+use failure::Error;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+use crate::utils::now_epoch_secs;
+
+lazy_static! {
+    static ref WEBHOOK_DELIVERY: RwLock<WebhookDeliveryConfig> = RwLock::new(WebhookDeliveryConfig::default());
+}
+
+/// Name of HTTP header carrying HMAC-SHA256 signature of webhook notification timestamp and body
+pub const SIGNATURE_HEADER: &str = "X-Agency-Signature";
+
+/// Name of HTTP header carrying signed time of webhook notification delivery attempt (seconds since epoch)
+pub const TIMESTAMP_HEADER: &str = "X-Agency-Timestamp";
+
+/// Name of HTTP header carrying id of webhook notification
+pub const NOTIFICATION_ID_HEADER: &str = "X-Agency-Notification-Id";
+
+pub struct WebhookDelivery {}
+
+impl WebhookDelivery {
+    pub fn set(webhook_delivery_config: Option<WebhookDeliveryConfig>) {
+        let mut delivery = WEBHOOK_DELIVERY.write().unwrap();
+        *delivery = webhook_delivery_config.unwrap_or_default();
+    }
+
+    pub fn get() -> WebhookDeliveryConfig {
+        WEBHOOK_DELIVERY.read().unwrap().clone()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct WebhookDeliveryConfig {
+    // Amount of delivery attempts before notification is moved to the list of failed notifications
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    // Delay before the first retry in milliseconds. The delay is doubled for each further retry.
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+    // Upper bound of delay between retries in milliseconds
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+    // Amount of failed notifications kept for inspection and replay. The oldest are dropped first.
+    #[serde(default = "default_failed_capacity")]
+    pub failed_capacity: usize,
+}
+
+fn default_max_attempts() -> u32 { 5 }
+
+fn default_initial_backoff_ms() -> u64 { 1000 }
+
+fn default_max_backoff_ms() -> u64 { 60000 }
+
+fn default_failed_capacity() -> usize { 1000 }
+
+impl Default for WebhookDeliveryConfig {
+    fn default() -> Self {
+        WebhookDeliveryConfig {
+            max_attempts: default_max_attempts(),
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            failed_capacity: default_failed_capacity(),
+        }
+    }
+}
+
+impl WebhookDeliveryConfig {
+    /// Delay before next delivery attempt of notification which already failed `attempts` times.
+    pub fn backoff(&self, attempts: u32) -> Duration {
+        let exponent = attempts.saturating_sub(1).min(32);
+        let backoff_ms = self.initial_backoff_ms.saturating_mul(1u64 << exponent);
+        Duration::from_millis(backoff_ms.min(self.max_backoff_ms))
+    }
+}
+
+/// Signs `<timestamp>.<body>` so receivers can reject notifications replayed by third parties
+/// once the timestamp is too old.
+pub fn sign_body(secret: &str, timestamp: u64, body: &str) -> Result<String, Error> {
+    let key = PKey::hmac(secret.as_bytes())?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(format!("{}.", timestamp).as_bytes())?;
+    signer.update(body.as_bytes())?;
+    let signature = signer.sign_to_vec()?;
+    Ok(signature.iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookNotification {
+    pub notification_id: String,
+    pub webhook_url: String,
+    pub body: String,
+    pub attempts: u32,
+    pub last_error: Option<String>,
+    pub created_at: u64,
+    // Secret of the agent's owner used to sign the notification by HMAC-SHA256 ("notificationWebhookSecret"
+    // agent config). Notification is not signed if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+}
+
+impl WebhookNotification {
+    pub fn new(notification_id: &str, webhook_url: &str, body: String, secret: Option<&String>) -> WebhookNotification {
+        WebhookNotification {
+            notification_id: notification_id.to_string(),
+            webhook_url: webhook_url.to_string(),
+            body,
+            attempts: 0,
+            last_error: None,
+            created_at: now_epoch_secs(),
+            secret: secret.cloned(),
+        }
+    }
+
+    /// Returns hex encoded HMAC-SHA256 signature of the timestamp and body, if secret is set.
+    pub fn sign(&self, timestamp: u64) -> Result<Option<String>, Error> {
+        match self.secret.as_ref() {
+            Some(secret) => sign_body(secret, timestamp, &self.body).map(Some),
+            None => Ok(None)
+        }
+    }
+
+    /// Copy of the notification without the secret, to be exposed outside of the agency.
+    pub fn without_secret(&self) -> WebhookNotification {
+        WebhookNotification { secret: None, ..self.clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max() {
+        let config: WebhookDeliveryConfig = serde_json::from_value(json!({
+            "initial_backoff_ms": 100,
+            "max_backoff_ms": 1000
+        })).unwrap();
+
+        assert_eq!(config.max_attempts, 5);
+        assert_eq!(config.backoff(1), Duration::from_millis(100));
+        assert_eq!(config.backoff(2), Duration::from_millis(200));
+        assert_eq!(config.backoff(4), Duration::from_millis(800));
+        assert_eq!(config.backoff(5), Duration::from_millis(1000));
+        assert_eq!(config.backoff(100), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn sign_body_computes_hmac_sha256() {
+        let signature = sign_body("key", 1600000000, "The quick brown fox jumps over the lazy dog").unwrap();
+        assert_eq!(signature, "8e74b20e98f9bae971b1147d7de6b4c1430cd1a64e5c69742b11dd974da4f276");
+
+        let notification = WebhookNotification::new("notification-1", "http://localhost", String::from("body"), None);
+        assert_eq!(notification.sign(1600000000).unwrap(), None);
+
+        let secret = String::from("key");
+        let notification = WebhookNotification::new("notification-1", "http://localhost", String::from("body"), Some(&secret));
+        assert_eq!(notification.sign(1600000000).unwrap(), Some(sign_body("key", 1600000000, "body").unwrap()));
+        assert_eq!(notification.without_secret().secret, None);
+    }
+}
@@ -1,6 +1,7 @@
 pub mod crypto;
 pub mod did;
 pub mod logger;
+pub mod non_secrets;
 pub mod pairwise;
 pub mod wallet;
 pub mod wallet_plugin;
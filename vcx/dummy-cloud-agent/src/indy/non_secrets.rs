@@ -0,0 +1,39 @@
+use futures::*;
+use indyrs::{IndyError, SearchHandle, wallet, WalletHandle};
+
+use crate::utils::futures::*;
+
+pub fn add_wallet_record(wallet_handle: WalletHandle, xtype: &str, id: &str, value: &str, tags_json: Option<&str>) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    wallet::add_wallet_record(wallet_handle, xtype, id, value, tags_json)
+        .into_box()
+}
+
+pub fn update_wallet_record_value(wallet_handle: WalletHandle, xtype: &str, id: &str, value: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    wallet::update_wallet_record_value(wallet_handle, xtype, id, value)
+        .into_box()
+}
+
+pub fn update_wallet_record_tags(wallet_handle: WalletHandle, xtype: &str, id: &str, tags_json: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    wallet::update_wallet_record_tags(wallet_handle, xtype, id, tags_json)
+        .into_box()
+}
+
+pub fn delete_wallet_record(wallet_handle: WalletHandle, xtype: &str, id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    wallet::delete_wallet_record(wallet_handle, xtype, id)
+        .into_box()
+}
+
+pub fn open_wallet_search(wallet_handle: WalletHandle, xtype: &str, query_json: &str, options_json: &str) -> Box<dyn Future<Item=SearchHandle, Error=IndyError>> {
+    wallet::open_wallet_search(wallet_handle, xtype, query_json, options_json)
+        .into_box()
+}
+
+pub fn fetch_wallet_search_next_records(wallet_handle: WalletHandle, search_handle: SearchHandle, count: usize) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    wallet::fetch_wallet_search_next_records(wallet_handle, search_handle, count)
+        .into_box()
+}
+
+pub fn close_wallet_search(search_handle: SearchHandle) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    wallet::close_wallet_search(search_handle)
+        .into_box()
+}
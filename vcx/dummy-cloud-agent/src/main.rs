@@ -33,7 +33,9 @@ use indy::wallet_plugin::{finish_loading_postgres, load_storage_library, seriali
 use crate::app::start_app_server;
 use crate::app_admin::start_app_admin_server;
 use crate::domain::config::{Config, WalletStorageConfig};
+use crate::domain::message_retention::MessageRetention;
 use crate::domain::protocol_type::ProtocolType;
+use crate::domain::webhook::WebhookDelivery;
 use crate::utils::config_env::{get_app_env_config};
 
 #[macro_use]
@@ -111,7 +113,9 @@ fn _start(config_path: &str) {
         wallet_storage: wallet_storage_config,
         protocol_type: protocol_type_config,
         indy_runtime,
-        server_admin: server_admin_config
+        server_admin: server_admin_config,
+        message_retention: message_retention_config,
+        webhook_delivery: webhook_delivery_config
     } = File::open(config_path)
         .context("Can't open config file")
         .and_then(|reader| serde_json::from_reader(reader)
@@ -141,6 +145,8 @@ fn _start(config_path: &str) {
         info!("Starting Forward Agent with config: {:?}", forward_agent_config);
 
         ProtocolType::set(protocol_type_config);
+        MessageRetention::set(message_retention_config);
+        WebhookDelivery::set(webhook_delivery_config);
 
         let admin = match &server_admin_config {
             Some(server_admin_config) if server_admin_config.enabled => {
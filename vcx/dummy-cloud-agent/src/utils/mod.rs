@@ -25,4 +25,11 @@ pub fn to_i8(bytes: &Vec<u8>) -> Vec<i8> {
     let mut buf: Vec<i8> = Vec::new();
     for i in bytes {buf.push(*i as i8);}
     buf.to_owned()
-}
\ No newline at end of file
+}
+
+pub fn now_epoch_secs() -> u64 {
+    ::std::time::SystemTime::now()
+        .duration_since(::std::time::UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::env;
 use std::fs;
 use std::path::PathBuf;
@@ -43,6 +44,11 @@ pub const EDGE_PAIRWISE_DID_INFO_2: &'static str = "{\"did\": \"WNnf2uJPZNmvMmA6
 pub const EDGE_PAIRWISE_DID_VERKEY_2: &'static str = "H1d58X25s91rTXdd46hTfn7mhtPmohQFYRHD379UtytR";
 
 pub static mut FORWARD_AGENT_WALLET_HANDLE: WalletHandle = WalletHandle(0);
+
+lazy_static! {
+    /// Wallet handles of agents created or restored by the agency, by agent DID
+    pub static ref AGENT_WALLET_HANDLES: RwLock<HashMap<String, WalletHandle>> = RwLock::new(HashMap::new());
+}
 pub const FORWARD_AGENT_WALLET_ID: &'static str = "forward_agent_wallet_id";
 pub const FORWARD_AGENT_WALLET_CONFIG: &'static str = "{\"id\": \"forward_agent_wallet_id\"}";
 pub const FORWARD_AGENT_WALLET_PASSPHRASE: &'static str = "forward_agent_wallet_passphrase";
@@ -113,6 +119,22 @@ pub fn run_test<F, B>(f: F)
     env_logger::try_init().ok();
     cleanup_storage();
 
+    run_agency(f)
+}
+
+/// Starts the agency again from wallets left by previous `run_test` of the same test.
+/// Agent wallets opened by previous run must be closed before.
+pub fn run_restarted_test<F, B>(f: F)
+    where
+        F: FnOnce(Addr<ForwardAgent>, Arc<RwLock<Admin>>) -> B + 'static,
+        B: IntoFuture<Item=(), Error=Error> + 'static {
+    run_agency(f)
+}
+
+fn run_agency<F, B>(f: F)
+    where
+        F: FnOnce(Addr<ForwardAgent>, Arc<RwLock<Admin>>) -> B + 'static,
+        B: IntoFuture<Item=(), Error=Error> + 'static {
     System::run(|| {
         Arbiter::spawn_fn(move || {
             let admin = Admin::create();
@@ -599,7 +621,8 @@ pub fn compose_update_configs(wallet_handle: WalletHandle, agent_did: &str, agen
             configs: vec![
                 ConfigOption { name: "zoom_zoom".to_string(), value: "value".to_string() },
                 ConfigOption { name: "name".to_string(), value: "super agent".to_string() },
-                ConfigOption { name: "logoUrl".to_string(), value: "http://logo.url".to_string() }
+                ConfigOption { name: "logoUrl".to_string(), value: "http://logo.url".to_string() },
+                ConfigOption { name: "notificationWebhookSecret".to_string(), value: "webhook-secret".to_string() }
             ]
         }))];
 
@@ -614,7 +637,7 @@ pub fn compose_update_configs(wallet_handle: WalletHandle, agent_did: &str, agen
 pub fn compose_get_configs(wallet_handle: WalletHandle, agent_did: &str, agent_verkey: &str) -> BoxedFuture<Vec<u8>, Error> {
     let msgs = [A2AMessage::Version1(A2AMessageV1::GetConfigs(
         GetConfigs {
-            configs: vec![String::from("name"), String::from("logoUrl")]
+            configs: vec![String::from("name"), String::from("logoUrl"), String::from("notificationWebhookSecret")]
         }))];
 
     let msg = A2AMessage::prepare_authcrypted(wallet_handle,
@@ -128,6 +128,24 @@ vcx_error_t vcx_connection_create_with_invite(vcx_command_handle_t command_handl
                                            const char *invite_details,
                                            void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_connection_handle_t));
 
+// Create a Connection object to the public DID of the inviter (Aries DID Exchange implicit invitation)
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// source_id: institution's personal identification for the user
+//
+// public_did: public DID of the inviter published on the ledger along with its endpoint
+//
+// cb: Callback that provides connection handle and error status of request
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_connection_create_with_public_did(vcx_command_handle_t command_handle,
+                                                  const char *source_id,
+                                                  const char *public_did,
+                                                  void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_connection_handle_t));
+
 // Delete a Connection object and release its handle
 //
 // #Params
@@ -158,6 +176,21 @@ vcx_error_t vcx_connection_deserialize(vcx_command_handle_t command_handle,
                                     const char *connection_data,
                                     void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_connection_handle_t));
 
+// Recreates a connection object from the state stored in the wallet by `persist_objects` mode
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// record_id: id of the stored object returned by vcx_object_store_list
+//
+// cb: Callback that provides handle and provides error status
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_connection_restore(vcx_command_handle_t command_handle,
+                                   const char *record_id,
+                                   void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_connection_handle_t));
+
 // Get the current state of the connection object
 //
 // #Params
@@ -410,6 +443,21 @@ vcx_error_t vcx_credential_deserialize(vcx_command_handle_t command_handle,
                                     const char *credential_data,
                                     void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_credential_handle_t));
 
+// Recreates a credential object from the state stored in the wallet by `persist_objects` mode
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// record_id: id of the stored object returned by vcx_object_store_list
+//
+// cb: Callback that provides handle and provides error status
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_credential_restore(vcx_command_handle_t command_handle,
+                                   const char *record_id,
+                                   void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_credential_handle_t));
+
 // Queries agency for credential offers from the given connection.
 //
 // #Params
@@ -806,6 +854,21 @@ vcx_error_t vcx_disclosed_proof_deserialize(vcx_command_handle_t command_handle,
                                          const char *proof_data,
                                          void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_disclosed_proof_handle_t));
 
+// Recreates a disclosed proof object from the state stored in the wallet by `persist_objects` mode
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// record_id: id of the stored object returned by vcx_object_store_list
+//
+// cb: Callback that provides handle and provides error status
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_disclosed_proof_restore(vcx_command_handle_t command_handle,
+                                        const char *record_id,
+                                        void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_disclosed_proof_handle_t));
+
 // Takes the disclosed proof object and generates a proof from the selected credentials and self attested attributes
 //
 // #Params
@@ -1116,6 +1179,22 @@ vcx_error_t vcx_get_credential(vcx_command_handle_t command_handle,
                             vcx_credential_handle_t credential_handle,
                             void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
 
+// Retrieve Revocation Notification received from the Issuer for the credential.
+// Credential state becomes VcxStateRevoked (7) once the notification has been received.
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// credential_handle: credential handle that was provided during creation. Used to identify credential object
+//
+// cb: Callback that provides error status of api call, or returns Revocation Notification message in json format.
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_credential_get_revocation_notification(vcx_command_handle_t command_handle,
+                                                       vcx_credential_handle_t credential_handle,
+                                                       void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
+
 // Get Proof
 //
 // #Params
@@ -1214,6 +1293,21 @@ vcx_error_t vcx_issuer_credential_deserialize(vcx_command_handle_t command_handl
                                            const char *credential_data,
                                            void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_issuer_credential_handle_t));
 
+// Recreates an issuer credential object from the state stored in the wallet by `persist_objects` mode
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// record_id: id of the stored object returned by vcx_object_store_list
+//
+// cb: Callback that provides handle and provides error status
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_issuer_credential_restore(vcx_command_handle_t command_handle,
+                                          const char *record_id,
+                                          void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_issuer_credential_handle_t));
+
 // Retrieve the txn associated with paying for the issuer_credential
 //
 // #param
@@ -1331,6 +1425,29 @@ vcx_error_t vcx_issuer_send_credential(vcx_command_handle_t command_handle,
                                     vcx_connection_handle_t connection_handle,
                                     void (*cb)(vcx_command_handle_t, vcx_error_t));
 
+// Notify the Holder about revocation of the Credential with an Aries Revocation Notification message
+//
+// Call it after the Credential has been revoked with `vcx_issuer_revoke_credential`.
+// Sending can be retried if it fails, the revocation stays in place.
+//
+// NOTE: Supported only for credentials issued with Aries protocol.
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// credential_handle: Credential handle that was provided during creation. Used to identify credential object
+//
+// comment: (Optional) human readable comment to include into Revocation Notification
+//
+// cb: Callback that provides error status of sending the notification
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_issuer_send_revocation_notification(vcx_command_handle_t command_handle,
+                                                    vcx_issuer_credential_handle_t credential_handle,
+                                                    const char *comment,
+                                                    void (*cb)(vcx_command_handle_t, vcx_error_t));
+
 // Send a credential offer to user showing what will be included in the actual credential
 //
 // #Params
@@ -1432,6 +1549,88 @@ vcx_error_t vcx_download_agent_messages(vcx_command_handle_t command_handle,
                                const char *uids,
                                void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
 
+// Download received messages for all Aries connections and route them to the objects owning their threads.
+// Processed messages are marked as reviewed.
+//
+// #params
+//
+// command_handle: command handle to map callback to user context.
+//
+// cb: Callback that provides list of dispatch events (state_updated, new_protocol, mediation_updated, unhandled, failed)
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_messages_dispatch(vcx_command_handle_t command_handle,
+                                  void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
+
+// Process packed message received by the application's own HTTP endpoint in agency-less mode
+// (`service_endpoint` setting is set).
+//
+// #params
+//
+// command_handle: command handle to map callback to user context.
+//
+// message_raw: raw body of the received HTTP request (packed message)
+//
+// message_len: length of the message
+//
+// cb: Callback that provides dispatch event and packed reply to be returned in the HTTP response body
+//     if the sender asked for `~transport.return_route` (null with length 0 otherwise)
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_inbound_message_receive(vcx_command_handle_t command_handle,
+                                        const unsigned char *message_raw,
+                                        unsigned int message_len,
+                                        void (*cb)(vcx_command_handle_t, vcx_error_t, const char*, const unsigned char*, unsigned int));
+
+// Ask the peer of the established Aries connection to become a mediator (agency-less mode only).
+// Replies of the mediator are processed by `vcx_messages_dispatch` (mediation_updated events).
+//
+// #params
+//
+// command_handle: command handle to map callback to user context.
+//
+// connection_handle: handle of the connection with the mediator
+//
+// cb: Callback that provides error status of the request and the state of the mediation
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_mediation_request(vcx_command_handle_t command_handle,
+                                  vcx_connection_handle_t connection_handle,
+                                  void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_state_t));
+
+// Get the current state of the mediation.
+vcx_error_t vcx_mediation_get_state(vcx_command_handle_t command_handle,
+                                    void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_state_t));
+
+// Ask the mediator for the list of registered recipient keys.
+vcx_error_t vcx_mediation_keylist_query(vcx_command_handle_t command_handle,
+                                        void (*cb)(vcx_command_handle_t, vcx_error_t));
+
+// Ask the mediator how many messages are waiting for pickup.
+vcx_error_t vcx_mediation_status_request(vcx_command_handle_t command_handle,
+                                         void (*cb)(vcx_command_handle_t, vcx_error_t));
+
+// Pick up a batch of messages stored by the mediator.
+// Received messages are processed by the following call of `vcx_messages_dispatch`.
+vcx_error_t vcx_mediation_pickup(vcx_command_handle_t command_handle,
+                                 unsigned int batch_size,
+                                 void (*cb)(vcx_command_handle_t, vcx_error_t));
+
+// Serialize the mediation state to restore it after restart.
+vcx_error_t vcx_mediation_serialize(vcx_command_handle_t command_handle,
+                                    void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
+
+// Restore the mediation state serialized by `vcx_mediation_serialize`.
+vcx_error_t vcx_mediation_deserialize(vcx_command_handle_t command_handle,
+                                      const char *mediation_data,
+                                      void (*cb)(vcx_command_handle_t, vcx_error_t));
+
+// Forget the mediation. New connections use own `service_endpoint` again.
+vcx_error_t vcx_mediation_release();
+
 
 // Update the status of messages from the specified connection
 //
@@ -1494,6 +1693,21 @@ vcx_error_t vcx_proof_deserialize(vcx_command_handle_t command_handle,
                                const char *proof_data,
                                void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_proof_handle_t));
 
+// Recreates a proof object from the state stored in the wallet by `persist_objects` mode
+//
+// #Params
+// command_handle: command handle to map callback to user context.
+//
+// record_id: id of the stored object returned by vcx_object_store_list
+//
+// cb: Callback that provides handle and provides error status
+//
+// #Returns
+// Error code as a u32
+vcx_error_t vcx_proof_restore(vcx_command_handle_t command_handle,
+                              const char *record_id,
+                              void (*cb)(vcx_command_handle_t, vcx_error_t, vcx_proof_handle_t));
+
 // Get the current state of the proof object
 //
 // #Params
@@ -2303,6 +2517,40 @@ vcx_error_t vcx_endorse_transaction(vcx_u32_t command_handle,
                                     const char* transaction
                                     void (*cb)(vcx_command_handle_t, vcx_error_t));
 
+/// List protocol objects stored in the wallet by `persist_objects` mode
+///
+/// #params
+///
+/// command_handle: command handle to map callback to user context.
+/// object_type: one of "connection", "issuer_credential", "credential", "proof", "disclosed_proof"
+/// query_json: (Optional) filter of objects: {"source_id": Optional<string>, "state": Optional<u32>, "thread_id": Optional<string>}
+///
+/// cb: Callback that provides list of objects [{"record_id": string, "source_id": string, "thread_id": Optional<string>, "state": u32}]
+///
+/// #Returns
+/// Error code as a u32
+vcx_error_t vcx_object_store_list(vcx_command_handle_t command_handle,
+                                  const char *object_type,
+                                  const char *query_json,
+                                  void (*cb)(vcx_command_handle_t, vcx_error_t, const char*));
+
+/// Delete protocol object state stored in the wallet by `persist_objects` mode
+///
+/// #params
+///
+/// command_handle: command handle to map callback to user context.
+/// object_type: one of "connection", "issuer_credential", "credential", "proof", "disclosed_proof"
+/// record_id: id of the stored object returned by vcx_object_store_list
+///
+/// cb: Callback that provides success or failure of command
+///
+/// #Returns
+/// Error code as a u32
+vcx_error_t vcx_object_store_delete(vcx_command_handle_t command_handle,
+                                    const char *object_type,
+                                    const char *record_id,
+                                    void (*cb)(vcx_command_handle_t, vcx_error_t));
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
@@ -240,6 +240,14 @@ pub extern fn vcx_connection_create(command_handle: CommandHandle,
 ///         "serviceEndpoint": "https://example.com/endpoint",
 ///         "routingKeys": ["8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K"]
 ///      }
+///     aries out-of-band: https://github.com/hyperledger/aries-rfcs/tree/master/features/0434-outofband#messages
+///      {
+///         "@type": "did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/out-of-band/1.0/invitation",
+///         "@id": "69212a3a-d068-4f9d-a2dd-4741bca89af3",
+///         "label": "Faber",
+///         "handshake_protocols": ["did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/didexchange/1.0"],
+///         "services": ["did:sov:V4SGRU86Z58d6TV7PBUe6f"]
+///      }
 ///
 /// # Returns
 /// Error code as a u32
@@ -274,6 +282,51 @@ pub extern fn vcx_connection_create_with_invite(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Create a Connection object to the public DID of the inviter (Aries DID Exchange implicit invitation).
+/// Verkey and service endpoint of the DID are resolved from the ledger.
+///
+/// # Params
+/// command_handle: command handle to map callback to user context.
+///
+/// source_id: institution's personal identification for the connection
+///
+/// public_did: public DID of the inviter
+///
+/// cb: Callback that provides connection handle and error status of request
+///
+/// # Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_connection_create_with_public_did(command_handle: CommandHandle,
+                                                    source_id: *const c_char,
+                                                    public_did: *const c_char,
+                                                    cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, connection_handle: u32)>) -> u32 {
+    info!("vcx_connection_create_with_public_did >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(source_id, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(public_did, VcxErrorKind::InvalidOption);
+    trace!("vcx_connection_create_with_public_did(command_handle: {}, source_id: {}, public_did: {})", command_handle, source_id, public_did);
+    spawn(move || {
+        match create_connection_with_public_did(&source_id, &public_did) {
+            Ok(handle) => {
+                trace!("vcx_connection_create_with_public_did_cb(command_handle: {}, rc: {}, handle: {}) source_id: {}",
+                       command_handle, error::SUCCESS.message, handle, source_id);
+                cb(command_handle, error::SUCCESS.code_num, handle);
+            }
+            Err(x) => {
+                warn!("vcx_connection_create_with_public_did_cb(command_handle: {}, rc: {}, handle: {}) source_id: {}",
+                      command_handle, x, 0, source_id);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Establishes connection between institution and its user
 ///
 /// # Params
@@ -287,6 +340,12 @@ pub extern fn vcx_connection_create_with_invite(command_handle: CommandHandle,
 /// "{"connection_type":"SMS","phone":"123","use_public_did":true}"
 ///     OR:
 /// "{"connection_type":"QR","phone":"","use_public_did":false}"
+///     Aries: "handshake_protocol" chooses "connections" (default) or "didexchange" protocol,
+///     "use_public_did" makes DID Exchange invitation refer the institution public DID:
+/// "{"handshake_protocol":"didexchange","use_public_did":true}"
+///     Such invitation can be answered by many invitees: the connection keeps waiting for requests
+///     and every request received by `vcx_messages_dispatch` is handled by a new connection
+///     which handle is returned in the dispatch event.
 ///
 /// cb: Callback that provides error status of request
 ///
@@ -520,6 +579,50 @@ pub extern fn vcx_connection_deserialize(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Recreates a connection object from the state stored in the wallet.
+/// Available when the library is initialized with `persist_objects` setting enabled,
+/// which stores the serialized object in the wallet after every state transition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// record_id: id of the stored object returned by `vcx_object_store_list`.
+///
+/// cb: Callback that provides connection_handle and provides error status
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_connection_restore(command_handle: CommandHandle,
+                                     record_id: *const c_char,
+                                     cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, connection_handle: u32)>) -> u32 {
+    info!("vcx_connection_restore >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(record_id, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_connection_restore(command_handle: {}, record_id: {})", command_handle, record_id);
+
+    spawn(move || {
+        match ::connection::restore(&record_id) {
+            Ok(x) => {
+                trace!("vcx_connection_restore_cb(command_handle: {}, rc: {}, connection_handle: {}) record_id: {}",
+                       command_handle, error::SUCCESS.message, x, record_id);
+                cb(command_handle, error::SUCCESS.code_num, x);
+            }
+            Err(x) => {
+                warn!("vcx_connection_restore_cb(command_handle: {}, rc: {}, connection_handle: {}) record_id: {}",
+                      command_handle, x, 0, record_id);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Query the agency for the received messages.
 /// Checks for any messages changing state in the connection and updates the state attribute.
 ///
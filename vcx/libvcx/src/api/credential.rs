@@ -240,6 +240,57 @@ pub extern fn vcx_get_credential(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Retrieve Revocation Notification received from the Issuer for the credential.
+/// Credential state becomes VcxStateRevoked (7) once the notification has been received.
+///
+/// NOTE: Supported only for credentials received with Aries protocol.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// credential_handle: credential handle that was provided during creation. Used to identify credential object
+///
+/// cb: Callback that provides error status of api call, or returns Revocation Notification message in json format.
+///     https://github.com/hyperledger/aries-rfcs/tree/master/features/0183-revocation-notification
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_credential_get_revocation_notification(command_handle: CommandHandle,
+                                                         credential_handle: u32,
+                                                         cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, notification: *const c_char)>) -> u32 {
+    info!("vcx_credential_get_revocation_notification >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    if !credential::is_valid_handle(credential_handle) {
+        return VcxError::from(VcxErrorKind::InvalidCredentialHandle).into()
+    }
+
+    let source_id = credential::get_source_id(credential_handle).unwrap_or_default();
+    trace!("vcx_credential_get_revocation_notification(command_handle: {}, credential_handle: {}) source_id: {})",
+           command_handle, credential_handle, source_id);
+
+    spawn(move || {
+        match credential::get_revocation_notification(credential_handle) {
+            Ok(s) => {
+                trace!("vcx_credential_get_revocation_notification_cb(commmand_handle: {}, rc: {}, msg: {}) source_id: {}",
+                       command_handle, error::SUCCESS.code_num, s, source_id);
+                let msg = CStringUtils::string_to_cstring(s);
+                cb(command_handle, error::SUCCESS.code_num, msg.as_ptr());
+            }
+            Err(e) => {
+                error!("vcx_credential_get_revocation_notification_cb(commmand_handle: {}, rc: {}, msg: {}) source_id: {}",
+                       command_handle, e, "".to_string(), source_id);
+                cb(command_handle, e.into(), ptr::null_mut());
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Delete a Credential from the wallet and release its handle.
 ///
 /// # Params
@@ -761,6 +812,50 @@ pub extern fn vcx_credential_deserialize(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Recreates a credential object from the state stored in the wallet.
+/// Available when the library is initialized with `persist_objects` setting enabled,
+/// which stores the serialized object in the wallet after every state transition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// record_id: id of the stored object returned by `vcx_object_store_list`.
+///
+/// cb: Callback that provides credential_handle and provides error status
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_credential_restore(command_handle: CommandHandle,
+                                     record_id: *const c_char,
+                                     cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, credential_handle: u32)>) -> u32 {
+    info!("vcx_credential_restore >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(record_id, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_credential_restore(command_handle: {}, record_id: {})", command_handle, record_id);
+
+    spawn(move || {
+        match ::credential::restore(&record_id) {
+            Ok(x) => {
+                trace!("vcx_credential_restore_cb(command_handle: {}, rc: {}, credential_handle: {}) record_id: {}",
+                       command_handle, error::SUCCESS.message, x, record_id);
+                cb(command_handle, error::SUCCESS.code_num, x);
+            }
+            Err(x) => {
+                warn!("vcx_credential_restore_cb(command_handle: {}, rc: {}, credential_handle: {}) record_id: {}",
+                      command_handle, x, 0, record_id);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Releases the credential object by de-allocating memory
 ///
 /// #Params
@@ -656,6 +656,50 @@ pub extern fn vcx_issuer_credential_deserialize(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Recreates an issuer credential object from the state stored in the wallet.
+/// Available when the library is initialized with `persist_objects` setting enabled,
+/// which stores the serialized object in the wallet after every state transition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// record_id: id of the stored object returned by `vcx_object_store_list`.
+///
+/// cb: Callback that provides credential_handle and provides error status
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_issuer_credential_restore(command_handle: CommandHandle,
+                                            record_id: *const c_char,
+                                            cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, credential_handle: u32)>) -> u32 {
+    info!("vcx_issuer_credential_restore >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(record_id, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_issuer_credential_restore(command_handle: {}, record_id: {})", command_handle, record_id);
+
+    spawn(move || {
+        match ::issuer_credential::restore(&record_id) {
+            Ok(x) => {
+                trace!("vcx_issuer_credential_restore_cb(command_handle: {}, rc: {}, credential_handle: {}) record_id: {}",
+                       command_handle, error::SUCCESS.message, x, record_id);
+                cb(command_handle, error::SUCCESS.code_num, x);
+            }
+            Err(x) => {
+                warn!("vcx_issuer_credential_restore_cb(command_handle: {}, rc: {}, credential_handle: {}) record_id: {}",
+                      command_handle, x, 0, record_id);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Releases the issuer credential object by deallocating memory
 ///
 /// #Params
@@ -791,6 +835,63 @@ pub extern fn vcx_issuer_revoke_credential(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Notify the Holder about revocation of the Credential with an Aries Revocation Notification message
+/// (https://github.com/hyperledger/aries-rfcs/tree/master/features/0183-revocation-notification)
+///
+/// Call it after the Credential has been revoked with `vcx_issuer_revoke_credential`.
+/// Sending can be retried if it fails, the revocation stays in place.
+///
+/// NOTE: Supported only for credentials issued with Aries protocol.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// credential_handle: Credential handle that was provided during creation. Used to identify credential object
+///
+/// comment: (Optional) human readable comment to include into Revocation Notification
+///
+/// cb: Callback that provides error status of sending the notification
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_issuer_send_revocation_notification(command_handle: CommandHandle,
+                                                      credential_handle: u32,
+                                                      comment: *const c_char,
+                                                      cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_opt_c_str!(comment, VcxErrorKind::InvalidOption);
+
+    if !issuer_credential::is_valid_handle(credential_handle) {
+        return VcxError::from(VcxErrorKind::InvalidIssuerCredentialHandle).into()
+    }
+
+    let source_id = issuer_credential::get_source_id(credential_handle).unwrap_or_default();
+    info!("vcx_issuer_send_revocation_notification(command_handle: {}, credential_handle: {}, comment: {:?}) source_id: {}",
+          command_handle, credential_handle, comment, source_id);
+
+    spawn(move || {
+        let err = match issuer_credential::send_revocation_notification(credential_handle, comment) {
+            Ok(()) => {
+                info!("vcx_issuer_send_revocation_notification_cb(command_handle: {}, credential_handle: {}, rc: {}) source_id: {}",
+                      command_handle, credential_handle, error::SUCCESS.message, source_id);
+                error::SUCCESS.code_num
+            }
+            Err(x) => {
+                warn!("vcx_issuer_send_revocation_notification_cb(command_handle: {}, credential_handle: {}, rc: {}) source_id: {}",
+                      command_handle, credential_handle, x, source_id);
+                x.into()
+            }
+        };
+
+        cb(command_handle, err);
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 #[cfg(test)]
 pub mod tests {
     extern crate serde_json;
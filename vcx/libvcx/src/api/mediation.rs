@@ -0,0 +1,366 @@
+use libc::c_char;
+use utils::cstring::CStringUtils;
+use utils::error;
+use utils::threadpool::spawn;
+use std::ptr;
+use mediation;
+use error::prelude::*;
+use indy_sys::CommandHandle;
+
+/*
+    The API represents a client of an Aries mediator (Coordinate Mediation RFC 0211 and Message Pickup RFC 0212).
+    It lets an edge agent without own endpoint and without agency (agency-less mode, `service_endpoint` setting is set)
+    receive messages through any compliant mediator.
+
+    Mediation is requested through an established Aries connection with the mediator.
+    Messages sent to the mediator ask for `~transport.return_route`, so its replies are returned in HTTP responses
+    and processed by `vcx_messages_dispatch` (`mediation_updated` events).
+
+    Once the mediation is granted:
+        - DIDDoc of new connections contains mediator endpoint and routing keys
+        - recipient keys of new connections are registered on the mediator (and unregistered when a connection is deleted)
+        - messages stored by the mediator are picked up by `vcx_mediation_pickup` and processed by `vcx_messages_dispatch`
+
+    # States
+
+        VcxStateNone - mediation has not been requested
+        VcxStateOfferSent - Mediate Request has been sent
+        VcxStateAccepted - Mediate Grant has been received
+        VcxStateRejected - Mediate Deny has been received
+*/
+
+/// Ask the peer of the established Aries connection to become a mediator.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// connection_handle: handle of the connection with the mediator
+///
+/// cb: Callback that provides error status of the request and the state of the mediation
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_request(command_handle: CommandHandle,
+                                    connection_handle: u32,
+                                    cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, state: u32)>) -> u32 {
+    info!("vcx_mediation_request >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    if !::connection::is_valid_handle(connection_handle) {
+        return VcxError::from(VcxErrorKind::InvalidConnectionHandle).into();
+    }
+
+    trace!("vcx_mediation_request(command_handle: {}, connection_handle: {})", command_handle, connection_handle);
+
+    spawn(move || {
+        match mediation::request_mediation(connection_handle) {
+            Ok(state) => {
+                trace!("vcx_mediation_request_cb(command_handle: {}, rc: {}, state: {})",
+                       command_handle, error::SUCCESS.message, state);
+                cb(command_handle, error::SUCCESS.code_num, state);
+            }
+            Err(x) => {
+                warn!("vcx_mediation_request_cb(command_handle: {}, rc: {}, state: {})",
+                      command_handle, x, 0);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Get the current state of the mediation.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// cb: Callback that provides the state of the mediation (VcxStateNone if mediation has not been requested)
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_get_state(command_handle: CommandHandle,
+                                      cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, state: u32)>) -> u32 {
+    info!("vcx_mediation_get_state >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_get_state(command_handle: {})", command_handle);
+
+    spawn(move || {
+        match mediation::get_state() {
+            Ok(state) => {
+                trace!("vcx_mediation_get_state_cb(command_handle: {}, rc: {}, state: {})",
+                       command_handle, error::SUCCESS.message, state);
+                cb(command_handle, error::SUCCESS.code_num, state);
+            }
+            Err(x) => {
+                warn!("vcx_mediation_get_state_cb(command_handle: {}, rc: {}, state: {})",
+                      command_handle, x, 0);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Ask the mediator for the list of registered recipient keys.
+/// The list is stored in the mediation state (see `vcx_mediation_serialize`) once the reply is dispatched.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// cb: Callback that provides error status of sending the query
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_keylist_query(command_handle: CommandHandle,
+                                          cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    info!("vcx_mediation_keylist_query >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_keylist_query(command_handle: {})", command_handle);
+
+    spawn(move || {
+        let err = match mediation::keylist_query() {
+            Ok(()) => {
+                trace!("vcx_mediation_keylist_query_cb(command_handle: {}, rc: {})", command_handle, error::SUCCESS.message);
+                error::SUCCESS.code_num
+            }
+            Err(x) => {
+                warn!("vcx_mediation_keylist_query_cb(command_handle: {}, rc: {})", command_handle, x);
+                x.into()
+            }
+        };
+
+        cb(command_handle, err);
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Ask the mediator how many messages are waiting for pickup.
+/// The count is stored in the mediation state (see `vcx_mediation_serialize`) once the reply is dispatched.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// cb: Callback that provides error status of sending the request
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_status_request(command_handle: CommandHandle,
+                                           cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    info!("vcx_mediation_status_request >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_status_request(command_handle: {})", command_handle);
+
+    spawn(move || {
+        let err = match mediation::status_request() {
+            Ok(()) => {
+                trace!("vcx_mediation_status_request_cb(command_handle: {}, rc: {})", command_handle, error::SUCCESS.message);
+                error::SUCCESS.code_num
+            }
+            Err(x) => {
+                warn!("vcx_mediation_status_request_cb(command_handle: {}, rc: {})", command_handle, x);
+                x.into()
+            }
+        };
+
+        cb(command_handle, err);
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Pick up a batch of messages stored by the mediator.
+/// Received messages are processed by the following call of `vcx_messages_dispatch`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// batch_size: max number of messages to pick up
+///
+/// cb: Callback that provides error status of sending the pickup request
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_pickup(command_handle: CommandHandle,
+                                   batch_size: u32,
+                                   cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    info!("vcx_mediation_pickup >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_pickup(command_handle: {}, batch_size: {})", command_handle, batch_size);
+
+    spawn(move || {
+        let err = match mediation::pickup(batch_size) {
+            Ok(()) => {
+                trace!("vcx_mediation_pickup_cb(command_handle: {}, rc: {})", command_handle, error::SUCCESS.message);
+                error::SUCCESS.code_num
+            }
+            Err(x) => {
+                warn!("vcx_mediation_pickup_cb(command_handle: {}, rc: {})", command_handle, x);
+                x.into()
+            }
+        };
+
+        cb(command_handle, err);
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Serialize the mediation state (including mediator endpoint, routing keys, registered recipient keys
+/// and pairwise keys of the mediator connection) to restore it after restart.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// cb: Callback that provides json string of the mediation state
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_serialize(command_handle: CommandHandle,
+                                      cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, mediation_data: *const c_char)>) -> u32 {
+    info!("vcx_mediation_serialize >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_serialize(command_handle: {})", command_handle);
+
+    spawn(move || {
+        match mediation::to_string() {
+            Ok(json) => {
+                trace!("vcx_mediation_serialize_cb(command_handle: {}, rc: {}, state: {})",
+                       command_handle, error::SUCCESS.message, json);
+                let msg = CStringUtils::string_to_cstring(json);
+                cb(command_handle, error::SUCCESS.code_num, msg.as_ptr());
+            }
+            Err(x) => {
+                warn!("vcx_mediation_serialize_cb(command_handle: {}, rc: {}, state: {})",
+                      command_handle, x, "null");
+                cb(command_handle, x.into(), ptr::null_mut());
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Restore the mediation state serialized by `vcx_mediation_serialize`.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// mediation_data: json string representing the mediation state
+///
+/// cb: Callback that provides error status of the restoring
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_mediation_deserialize(command_handle: CommandHandle,
+                                        mediation_data: *const c_char,
+                                        cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    info!("vcx_mediation_deserialize >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(mediation_data, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_mediation_deserialize(command_handle: {}, mediation_data: {})", command_handle, mediation_data);
+
+    spawn(move || {
+        let err = match mediation::from_string(&mediation_data) {
+            Ok(()) => {
+                trace!("vcx_mediation_deserialize_cb(command_handle: {}, rc: {})", command_handle, error::SUCCESS.message);
+                error::SUCCESS.code_num
+            }
+            Err(x) => {
+                warn!("vcx_mediation_deserialize_cb(command_handle: {}, rc: {})", command_handle, x);
+                x.into()
+            }
+        };
+
+        cb(command_handle, err);
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Forget the mediation. New connections use own `service_endpoint` again.
+///
+/// #Returns
+/// Success
+#[no_mangle]
+pub extern fn vcx_mediation_release() -> u32 {
+    info!("vcx_mediation_release >>>");
+
+    match mediation::release() {
+        Ok(()) => {
+            trace!("vcx_mediation_release(rc: {})", error::SUCCESS.message);
+            error::SUCCESS.code_num
+        }
+        Err(e) => {
+            warn!("vcx_mediation_release(rc: {})", e);
+            e.into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use api::return_types_u32;
+    use connection::tests::build_test_connection;
+    use utils::devsetup::*;
+    use utils::timeout::TimeoutUtils;
+
+    #[test]
+    fn test_vcx_mediation_request_fails_for_invalid_connection() {
+        let _setup = SetupMocks::init();
+
+        let cb = return_types_u32::Return_U32_U32::new().unwrap();
+        assert_eq!(error::INVALID_CONNECTION_HANDLE.code_num,
+                   vcx_mediation_request(cb.command_handle, 0, Some(cb.get_callback())));
+    }
+
+    #[test]
+    fn test_vcx_mediation_request_fails_without_agency_less_mode() {
+        let _setup = SetupMocks::init();
+
+        let handle = build_test_connection();
+
+        let cb = return_types_u32::Return_U32_U32::new().unwrap();
+        assert_eq!(error::SUCCESS.code_num,
+                   vcx_mediation_request(cb.command_handle, handle, Some(cb.get_callback())));
+        assert_eq!(error::ACTION_NOT_SUPPORTED.code_num,
+                   cb.receive(TimeoutUtils::some_medium()).unwrap_err());
+    }
+}
@@ -7,6 +7,7 @@ pub mod credential_def;
 pub mod schema;
 pub mod credential;
 pub mod disclosed_proof;
+pub mod mediation;
 pub mod wallet;
 pub mod logger;
 pub mod return_types_u32;
@@ -424,6 +424,50 @@ pub extern fn vcx_proof_deserialize(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Recreates a proof object from the state stored in the wallet.
+/// Available when the library is initialized with `persist_objects` setting enabled,
+/// which stores the serialized object in the wallet after every state transition.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// record_id: id of the stored object returned by `vcx_object_store_list`.
+///
+/// cb: Callback that provides proof_handle and provides error status
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_proof_restore(command_handle: CommandHandle,
+                                record_id: *const c_char,
+                                cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, proof_handle: u32)>) -> u32 {
+    info!("vcx_proof_restore >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(record_id, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_proof_restore(command_handle: {}, record_id: {})", command_handle, record_id);
+
+    spawn(move || {
+        match ::proof::restore(&record_id) {
+            Ok(x) => {
+                trace!("vcx_proof_restore_cb(command_handle: {}, rc: {}, proof_handle: {}) record_id: {}",
+                       command_handle, error::SUCCESS.message, x, record_id);
+                cb(command_handle, error::SUCCESS.code_num, x);
+            }
+            Err(x) => {
+                warn!("vcx_proof_restore_cb(command_handle: {}, rc: {}, proof_handle: {}) record_id: {}",
+                      command_handle, x, 0, record_id);
+                cb(command_handle, x.into(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Releases the proof object by de-allocating memory
 ///
 /// #Params
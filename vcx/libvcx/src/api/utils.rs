@@ -11,6 +11,7 @@ use error::prelude::*;
 use indy_sys::CommandHandle;
 use utils::httpclient::AgencyMock;
 use utils::constants::*;
+use object_cache::persistence::{self, ObjectType};
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct UpdateAgentInfo {
@@ -395,6 +396,124 @@ pub extern fn vcx_messages_download(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// Download received messages for all Aries connections in one request and route them to the objects owning
+/// their threads (issuer credential, credential, proof, disclosed proof or connection itself), advancing their states.
+/// Processed messages are marked as reviewed on the agency.
+/// In agency-less mode dispatches queued messages (replies returned in HTTP responses and messages picked up
+/// from the mediator) instead. Replies to them are posted to the peer endpoint. Messages for a connection being
+/// updated by another call are kept in the queue for the next call, failed messages are dropped.
+///
+/// #params
+///
+/// command_handle: command handle to map callback to user context.
+///
+/// cb: Callback that provides list of dispatch events, one per downloaded message:
+///     [
+///         {"event": "state_updated", "connection_handle": u32, "uid": string, "object_type": string, "handle": u32, "state": u32},
+///         {"event": "new_protocol", "connection_handle": u32, "uid": string, "message": <aries message>}, // offer, request or proposal not owned by any object
+///         {"event": "mediation_updated", "connection_handle": u32, "uid": string, "state": u32}, // message received from the mediator (see `vcx_mediation_request`)
+///         {"event": "unhandled", "connection_handle": u32, "uid": string, "message": <aries message>},
+///         {"event": "failed", "connection_handle": u32, "uid": string, "error": string} // message stays unreviewed
+///     ]
+///     object_type is one of "connection", "issuer_credential", "credential", "proof", "disclosed_proof"
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_messages_dispatch(command_handle: CommandHandle,
+                                    cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, events: *const c_char)>) -> u32 {
+    info!("vcx_messages_dispatch >>>");
+
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_messages_dispatch(command_handle: {})", command_handle);
+
+    spawn(move || {
+        match ::dispatcher::dispatch_messages_json() {
+            Ok(x) => {
+                trace!("vcx_messages_dispatch_cb(command_handle: {}, rc: {}, events: {})",
+                       command_handle, error::SUCCESS.message, x);
+
+                let msg = CStringUtils::string_to_cstring(x);
+                cb(command_handle, error::SUCCESS.code_num, msg.as_ptr());
+            }
+            Err(e) => {
+                warn!("vcx_messages_dispatch_cb(command_handle: {}, rc: {}, events: {})",
+                      command_handle, e, "null");
+
+                cb(command_handle, e.into(), ptr::null_mut());
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Process packed message received by the application's own HTTP endpoint in agency-less mode
+/// (`service_endpoint` setting is set). The message is routed to the connection owning its recipient key
+/// and then to the object owning its thread.
+///
+/// #params
+///
+/// command_handle: command handle to map callback to user context.
+///
+/// message_raw: raw body of the received HTTP request (packed message)
+///
+/// message_len: length of the message
+///
+/// cb: Callback that provides dispatch event (see `vcx_messages_dispatch`) and packed reply to be returned
+///     in the HTTP response body if the sender asked for `~transport.return_route` (null with length 0 otherwise)
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_inbound_message_receive(command_handle: CommandHandle,
+                                          message_raw: *const u8,
+                                          message_len: u32,
+                                          cb: Option<extern fn(xcommand_handle: CommandHandle,
+                                                               err: u32,
+                                                               event: *const c_char,
+                                                               reply_raw: *const u8,
+                                                               reply_len: u32)>) -> u32 {
+    info!("vcx_inbound_message_receive >>>");
+
+    check_useful_c_byte_array!(message_raw, message_len, VcxErrorKind::InvalidOption, VcxErrorKind::InvalidOption);
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_inbound_message_receive(command_handle: {}, message_len: {})", command_handle, message_len);
+
+    spawn(move || {
+        match ::dispatcher::receive_message_json(&message_raw) {
+            Ok((event, reply)) => {
+                trace!("vcx_inbound_message_receive_cb(command_handle: {}, rc: {}, event: {}, reply: {:?})",
+                       command_handle, error::SUCCESS.message, event, reply.is_some());
+
+                let event = CStringUtils::string_to_cstring(event);
+
+                match reply {
+                    Some(reply) => {
+                        let (reply_raw, reply_len) = ::utils::cstring::vec_to_pointer(&reply);
+                        cb(command_handle, error::SUCCESS.code_num, event.as_ptr(), reply_raw, reply_len);
+                    }
+                    None => cb(command_handle, error::SUCCESS.code_num, event.as_ptr(), ptr::null(), 0)
+                }
+            }
+            Err(e) => {
+                warn!("vcx_inbound_message_receive_cb(command_handle: {}, rc: {}, event: {})",
+                      command_handle, e, "null");
+
+                cb(command_handle, e.into(), ptr::null_mut(), ptr::null(), 0);
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 /// Update the status of messages from the specified connection
 ///
 /// #params
@@ -563,6 +682,114 @@ pub extern fn vcx_endorse_transaction(command_handle: CommandHandle,
     error::SUCCESS.code_num
 }
 
+/// List protocol objects stored in the wallet by `persist_objects` mode.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// object_type: type of objects to list. One of
+///     "connection", "issuer_credential", "credential", "proof", "disclosed_proof"
+///
+/// query_json: (Optional) filter of objects:
+/// {
+///     "source_id": Optional<string> - source id of object,
+///     "state": Optional<u32> - state of object (see VcxStateType),
+///     "thread_id": Optional<string> - id of protocol thread,
+/// }
+///
+/// cb: Callback that provides list of objects
+///     [{"record_id": string, "source_id": string, "thread_id": Optional<string>, "state": u32}]
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_object_store_list(command_handle: CommandHandle,
+                                    object_type: *const c_char,
+                                    query_json: *const c_char,
+                                    cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32, objects: *const c_char)>) -> u32 {
+    info!("vcx_object_store_list >>>");
+
+    check_useful_c_str!(object_type, VcxErrorKind::InvalidOption);
+    check_useful_opt_c_str!(query_json, VcxErrorKind::InvalidOption);
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_object_store_list(command_handle: {}, object_type: {}, query_json: {:?})",
+           command_handle, object_type, query_json);
+
+    spawn(move || {
+        match persistence::list_json(&object_type, query_json.as_ref().map(String::as_str)) {
+            Ok(x) => {
+                trace!("vcx_object_store_list_cb(command_handle: {}, rc: {}, objects: {})",
+                       command_handle, error::SUCCESS.message, x);
+
+                let msg = CStringUtils::string_to_cstring(x);
+                cb(command_handle, error::SUCCESS.code_num, msg.as_ptr());
+            }
+            Err(e) => {
+                warn!("vcx_object_store_list_cb(command_handle: {}, rc: {}, objects: {})",
+                      command_handle, e, "null");
+
+                cb(command_handle, e.into(), ptr::null_mut());
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
+/// Delete protocol object state stored in the wallet by `persist_objects` mode.
+/// Note that stored state is deleted automatically on release of the object handle.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+///
+/// object_type: type of object. One of
+///     "connection", "issuer_credential", "credential", "proof", "disclosed_proof"
+///
+/// record_id: id of the stored object returned by `vcx_object_store_list`
+///
+/// cb: Callback that provides success or failure of command
+///
+/// #Returns
+/// Error code as a u32
+#[no_mangle]
+pub extern fn vcx_object_store_delete(command_handle: CommandHandle,
+                                      object_type: *const c_char,
+                                      record_id: *const c_char,
+                                      cb: Option<extern fn(xcommand_handle: CommandHandle, err: u32)>) -> u32 {
+    info!("vcx_object_store_delete >>>");
+
+    check_useful_c_str!(object_type, VcxErrorKind::InvalidOption);
+    check_useful_c_str!(record_id, VcxErrorKind::InvalidOption);
+    check_useful_c_callback!(cb, VcxErrorKind::InvalidOption);
+
+    trace!("vcx_object_store_delete(command_handle: {}, object_type: {}, record_id: {})",
+           command_handle, object_type, record_id);
+
+    spawn(move || {
+        match ObjectType::from_str(&object_type).and_then(|object_type| persistence::delete(object_type, &record_id)) {
+            Ok(()) => {
+                trace!("vcx_object_store_delete_cb(command_handle: {}, rc: {})",
+                       command_handle, error::SUCCESS.message);
+
+                cb(command_handle, error::SUCCESS.code_num);
+            }
+            Err(e) => {
+                warn!("vcx_object_store_delete_cb(command_handle: {}, rc: {})",
+                      command_handle, e);
+
+                cb(command_handle, e.into());
+            }
+        };
+
+        Ok(())
+    });
+
+    error::SUCCESS.code_num
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
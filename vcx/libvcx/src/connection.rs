@@ -14,6 +14,7 @@ use messages::thread::Thread;
 use messages::send_message::SendMessageOptions;
 use messages::get_message::{Message, MessagePayload};
 use object_cache::ObjectCache;
+use object_cache::persistence::{self, ObjectRecord, ObjectType};
 use settings;
 use utils::error;
 use utils::libindy::signus::create_and_store_my_did;
@@ -22,13 +23,13 @@ use utils::json::mapped_key_rewrite;
 use utils::json::KeyMatch;
 
 use v3::handlers::connection::connection::Connection as ConnectionV3;
-use v3::handlers::connection::states::ActorDidExchangeState;
+use v3::handlers::connection::states::{ActorDidExchangeState, HandshakeProtocol};
 use v3::handlers::connection::agent::AgentInfo;
 use v3::messages::connection::invite::Invitation as InvitationV3;
 use settings::ProtocolTypes;
 
 lazy_static! {
-    static ref CONNECTION_MAP: ObjectCache<Connections> = Default::default();
+    static ref CONNECTION_MAP: ObjectCache<Connections> = ObjectCache::with_persistence(ObjectType::Connection, connection_record);
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -47,6 +48,8 @@ pub struct ConnectionOptions {
     #[serde(default)]
     pub phone: Option<String>,
     pub use_public_did: Option<bool>,
+    #[serde(default)]
+    pub handshake_protocol: Option<HandshakeProtocol>,
 }
 
 impl Default for ConnectionOptions {
@@ -55,6 +58,7 @@ impl Default for ConnectionOptions {
             connection_type: None,
             phone: None,
             use_public_did: None,
+            handshake_protocol: None,
         }
     }
 }
@@ -702,6 +706,12 @@ pub fn create_connection(source_id: &str) -> VcxResult<u32> {
 pub fn create_connection_with_invite(source_id: &str, details: &str) -> VcxResult<u32> {
     debug!("create connection {} with invite {}", source_id, details);
 
+    // Out-of-Band invitation -- redirect to v3 folder
+    if let Ok(A2AMessage::OutofbandInvitation(invitation)) = serde_json::from_str::<A2AMessage>(details) {
+        let connection = Connections::V3(ConnectionV3::create_with_outofband_invite(source_id, invitation)?);
+        return store_connection(connection);
+    }
+
     // Invitation of new format -- redirect to v3 folder
     if let Ok(invitation) = serde_json::from_str::<InvitationV3>(details) {
         let connection = Connections::V3(ConnectionV3::create_with_invite(source_id, invitation)?);
@@ -736,6 +746,35 @@ pub fn create_connection_with_invite(source_id: &str, details: &str) -> VcxResul
     store_connection(Connections::V1(connection))
 }
 
+pub fn create_connection_with_public_did(source_id: &str, public_did: &str) -> VcxResult<u32> {
+    debug!("create connection {} with public did {}", source_id, public_did);
+
+    if !settings::is_aries_protocol_set() {
+        return Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Connection through public DID is supported for Aries protocol only"));
+    }
+
+    let connection = Connections::V3(ConnectionV3::create_with_public_did(source_id, public_did)?);
+    store_connection(connection)
+}
+
+/// Implicit invitation of the public DID can be answered by any number of invitees, so the connection
+/// inviting through it is not used for the handshake itself: every request is handled by its copy
+/// stored under a new handle, while the inviting connection keeps waiting for requests.
+/// Returns `None` for other connections.
+pub fn spawn_from_public_invitation(handle: u32) -> VcxResult<Option<u32>> {
+    let connection = CONNECTION_MAP.get(handle, |connection| {
+        match connection {
+            Connections::V3(ref connection) if connection.is_public_invitation() => Ok(Some(connection.clone())),
+            _ => Ok(None)
+        }
+    })?;
+
+    match connection {
+        Some(connection) => store_connection(Connections::V3(connection)).map(Some),
+        None => Ok(None)
+    }
+}
+
 pub fn parse_acceptance_details(message: &Message) -> VcxResult<SenderDetail> {
     let my_vk = settings::get_config_value(settings::CONFIG_SDK_TO_REMOTE_VERKEY)?;
 
@@ -973,6 +1012,9 @@ pub fn connect(handle: u32, options: Option<String>) -> VcxResult<u32> {
                 connection.connect(&options_obj)
             }
             Connections::V3(ref mut connection) => {
+                if let Some(protocol) = options_obj.handshake_protocol {
+                    connection.set_handshake_protocol(protocol, options_obj.use_public_did.unwrap_or(false))?;
+                }
                 connection.connect()?;
                 Ok(error::SUCCESS.code_num)
             }
@@ -1009,39 +1051,56 @@ pub fn redirect(handle: u32, redirect_handle: u32) -> VcxResult<u32> {
 
 
 pub fn to_string(handle: u32) -> VcxResult<String> {
-    CONNECTION_MAP.get(handle, |connection| {
-        match connection {
-            Connections::V1(ref connection) => {
-                let object: SerializableObjectWithState<Connection, ConnectionV3> = SerializableObjectWithState::V1 { data: connection.to_owned() };
+    CONNECTION_MAP.get(handle, _to_string)
+}
 
-                ::serde_json::to_string(&object)
-                    .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize Connection: {:?}", err)))
-            }
-            Connections::V3(ref connection) => {
-                let (data, state) = connection.to_owned().into();
-                let object = SerializableObjectWithState::V2 { data, state };
+fn _to_string(connection: &Connections) -> VcxResult<String> {
+    match connection {
+        Connections::V1(ref connection) => {
+            let object: SerializableObjectWithState<Connection, ConnectionV3> = SerializableObjectWithState::V1 { data: connection.to_owned() };
 
-                ::serde_json::to_string(&object)
-                    .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize Connection: {:?}", err)))
-            }
+            ::serde_json::to_string(&object)
+                .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize Connection: {:?}", err)))
         }
-    })
+        Connections::V3(ref connection) => {
+            let (data, state) = connection.to_owned().into();
+            let object = SerializableObjectWithState::V2 { data, state };
+
+            ::serde_json::to_string(&object)
+                .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize Connection: {:?}", err)))
+        }
+    }
+}
+
+fn connection_record(connection: &Connections) -> VcxResult<ObjectRecord> {
+    let (source_id, state) = match connection {
+        Connections::V1(ref connection) => (connection.get_source_id().clone(), connection.get_state()),
+        Connections::V3(ref connection) => (connection.get_source_id(), connection.state())
+    };
+
+    Ok(ObjectRecord::new(source_id, None, state, _to_string(connection)?))
+}
+
+/// Rebuilds a connection handle from the state stored in the wallet by the `persist_objects` mode.
+pub fn restore(record_id: &str) -> VcxResult<u32> {
+    let connection_data = persistence::load(ObjectType::Connection, record_id)?;
+    CONNECTION_MAP.add_restored(record_id, _from_string(&connection_data)?)
 }
 
 pub fn from_string(connection_data: &str) -> VcxResult<u32> {
+    CONNECTION_MAP.add(_from_string(connection_data)?)
+}
+
+fn _from_string(connection_data: &str) -> VcxResult<Connections> {
     let object: SerializableObjectWithState<Connection, ::v3::handlers::connection::states::ActorDidExchangeState> = ::serde_json::from_str(connection_data)
         .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Connection: {:?}", err)))?;
 
-    let handle = match object {
-        SerializableObjectWithState::V1 { data, .. } => {
-            CONNECTION_MAP.add(Connections::V1(data))?
-        }
-        SerializableObjectWithState::V2 { data, state } => {
-            CONNECTION_MAP.add(Connections::V3((data, state).into()))?
-        }
+    let connection = match object {
+        SerializableObjectWithState::V1 { data, .. } => Connections::V1(data),
+        SerializableObjectWithState::V2 { data, state } => Connections::V3((data, state).into()),
     };
 
-    Ok(handle)
+    Ok(connection)
 }
 
 pub fn release(handle: u32) -> VcxResult<()> {
@@ -1242,7 +1301,8 @@ impl From<(Connection, ActorDidExchangeState)> for ConnectionV3 {
     }
 }
 
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
+use v3::messages::a2a::message_family::MessageFamilies;
 use v3::messages::connection::did_doc::DidDoc;
 
 pub fn get_messages(handle: u32) -> VcxResult<HashMap<String, A2AMessage>> {
@@ -1294,6 +1354,53 @@ pub fn send_message_to_self_endpoint(message: A2AMessage, did_doc: &DidDoc) -> V
     ConnectionV3::send_message_to_self_endpoint(&message, did_doc)
 }
 
+pub fn get_v3_pw_dids() -> VcxResult<HashMap<String, u32>> {
+    let pw_dids = CONNECTION_MAP.filter_map(|handle, connection| {
+        match connection {
+            Connections::V1(_) => None,
+            Connections::V3(ref connection) => Some((connection.agent_info().pw_did.to_string(), handle))
+        }
+    })?;
+
+    Ok(pw_dids.into_iter().collect())
+}
+
+/// Connection owning pairwise key `verkey`. Connections busy sending a message are not waited for
+/// (their peer may reply to us in the meantime), so `NotReady` is returned if the owner may be one of them.
+pub fn find_v3_by_verkey(verkey: &str) -> VcxResult<Option<u32>> {
+    // connections spawned from the public invitation keep its key as the previous one
+    let (mut handles, busy) = CONNECTION_MAP.try_filter_map(|handle, connection| {
+        match connection {
+            Connections::V3(ref connection) if connection.agent_info().pw_vk == verkey => Some((false, handle)),
+            Connections::V3(ref connection) if connection.prev_agent_info().map(|info| info.pw_vk == verkey).unwrap_or(false) => Some((true, handle)),
+            _ => None
+        }
+    })?;
+
+    handles.sort();
+
+    match handles.into_iter().next() {
+        Some((_, handle)) => Ok(Some(handle)),
+        None if busy > 0 => Err(VcxError::from_msg(VcxErrorKind::NotReady, format!("Connection for recipient key {} may be busy, retry later", verkey))),
+        None => Ok(None)
+    }
+}
+
+/// Own pairwise keys and remote DIDDoc of the established Aries connection.
+pub fn get_pairwise_info(connection_handle: u32) -> VcxResult<(AgentInfo, DidDoc)> {
+    CONNECTION_MAP.get(connection_handle, |connection| {
+        match connection {
+            Connections::V1(_) => Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Pairwise info is available for Aries connections only")),
+            Connections::V3(ref connection) => {
+                let did_doc = connection.remote_did_doc()
+                    .ok_or(VcxError::from_msg(VcxErrorKind::NotReady, "Remote Connection information is not set"))?;
+
+                Ok((connection.agent_info().clone(), did_doc))
+            }
+        }
+    })
+}
+
 pub fn is_v3_connection(connection_handle: u32) -> VcxResult<bool> {
     CONNECTION_MAP.get(connection_handle, |connection| {
         match connection {
@@ -1321,6 +1428,15 @@ pub fn send_discovery_features(connection_handle: u32, query: Option<String>, co
     })
 }
 
+pub fn get_protocol_version(handle: u32, family: MessageFamilies) -> VcxResult<ProtocolVersion> {
+    CONNECTION_MAP.get(handle, |connection| {
+        match connection {
+            Connections::V1(_) => Ok(ProtocolVersion::V1),
+            Connections::V3(ref connection) => Ok(connection.protocol_version(family.clone()))
+        }
+    })
+}
+
 pub fn get_connection_info(handle: u32) -> VcxResult<String> {
     CONNECTION_MAP.get(handle, |cxn| {
         match cxn {
@@ -4,6 +4,7 @@ use std::convert::TryInto;
 
 use error::prelude::*;
 use object_cache::ObjectCache;
+use object_cache::persistence::{self, ObjectRecord, ObjectType};
 use api::VcxStateType;
 use issuer_credential::{CredentialOffer, CredentialMessage, PaymentInfo};
 use credential_request::CredentialRequest;
@@ -35,12 +36,13 @@ use utils::agent_info::{get_agent_info, MyAgentInfo, get_agent_attr};
 use utils::httpclient::AgencyMock;
 
 use v3::{
+    messages::a2a::A2AMessage,
     messages::issuance::credential_offer::CredentialOffer as CredentialOfferV3,
     handlers::issuance::Holder,
 };
 
 lazy_static! {
-    static ref HANDLE_MAP: ObjectCache<Credentials>  = Default::default();
+    static ref HANDLE_MAP: ObjectCache<Credentials> = ObjectCache::with_persistence(ObjectType::Credential, credential_record);
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -443,6 +445,12 @@ fn create_credential_v3(source_id: &str, offer: &str) -> VcxResult<Option<Creden
         offer => offer //aries offer format
     };
 
+    // Received offer of aries Issue Credential 2.0 protocol
+    if let Ok(A2AMessage::CredentialOfferV2(cred_offer)) = serde_json::from_value::<A2AMessage>(offer_message.clone()) {
+        let holder = Holder::create_v2(cred_offer, source_id)?;
+        return Ok(Some(Credentials::V3(holder)));
+    }
+
     // Received offer of aries format
     if let Ok(cred_offer) = serde_json::from_value::<CredentialOfferV3>(offer_message) {
         let holder = Holder::create(cred_offer, source_id)?;
@@ -457,6 +465,11 @@ pub fn credential_create_with_offer(source_id: &str, offer: &str) -> VcxResult<u
 
     // strict aries protocol is set. Credential Offer must be in aries format
     if settings::is_strict_aries_protocol_set() {
+        if let Ok(A2AMessage::CredentialOfferV2(cred_offer)) = serde_json::from_str::<A2AMessage>(offer) {
+            let holder = Holder::create_v2(cred_offer, source_id)?;
+            return HANDLE_MAP.add(Credentials::V3(holder));
+        }
+
         let cred_offer: CredentialOfferV3 = serde_json::from_str(offer)
             .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson,
                                               format!("Strict `aries` protocol is enabled. Can not parse `aries` formatted Credential Offer: {}", err)))?;
@@ -679,7 +692,7 @@ fn get_credential_offer_msg(connection_handle: u32, msg_id: &str) -> VcxResult<S
     if connection::is_v3_connection(connection_handle)? {
         let credential_offer = Holder::get_credential_offer_message(connection_handle, msg_id)?;
 
-        return serde_json::to_string(&credential_offer).
+        return serde_json::to_string(&_aries_offer_json(credential_offer)).
             map_err(|err| {
                 VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize Offers: {:?}", err))
             });
@@ -716,6 +729,15 @@ fn get_credential_offer_msg(connection_handle: u32, msg_id: &str) -> VcxResult<S
             ))
 }
 
+// Issue Credential 1.0 offers are returned without `@type` as before,
+// 2.0 offers keep it to be recognized by `credential_create_with_offer`
+fn _aries_offer_json(credential_offer: A2AMessage) -> Value {
+    match credential_offer {
+        A2AMessage::CredentialOffer(credential_offer) => json!(credential_offer),
+        credential_offer => json!(credential_offer)
+    }
+}
+
 pub fn get_credential_offer_messages(connection_handle: u32) -> VcxResult<String> {
     trace!("Credential::get_credential_offer_messages >>> connection_handle: {}", connection_handle);
 
@@ -724,17 +746,24 @@ pub fn get_credential_offer_messages(connection_handle: u32) -> VcxResult<String
 
         // strict aries protocol is set. Return aries formatted Credential Offers
         if settings::is_strict_aries_protocol_set() {
+            let credential_offers: Vec<Value> = credential_offers.into_iter().map(_aries_offer_json).collect();
             return Ok(json!(credential_offers).to_string());
         }
 
         // map credential offers into proprietary format
         let msgs: Vec<Vec<::serde_json::Value>> = credential_offers
             .into_iter()
-            .map(|credential_offer| credential_offer.try_into())
-            .collect::<VcxResult<Vec<CredentialOffer>>>()?
-            .into_iter()
-            .map(|msg| vec![json!(msg)])
-            .collect();
+            .map(|credential_offer| {
+                match credential_offer {
+                    A2AMessage::CredentialOffer(credential_offer) => {
+                        let credential_offer: CredentialOffer = credential_offer.try_into()?;
+                        Ok(vec![json!(credential_offer)])
+                    }
+                    // Issue Credential 2.0 offers have no proprietary representation
+                    credential_offer => Ok(vec![_aries_offer_json(credential_offer)])
+                }
+            })
+            .collect::<VcxResult<Vec<Vec<::serde_json::Value>>>>()?;
 
         return serde_json::to_string(&msgs).
             map_err(|err| {
@@ -826,10 +855,28 @@ pub fn is_valid_handle(handle: u32) -> bool {
 }
 
 pub fn to_string(handle: u32) -> VcxResult<String> {
-    HANDLE_MAP.get(handle, |obj| {
-        serde_json::to_string(obj)
-            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize Credential object: {:?}", err)))
-    })
+    HANDLE_MAP.get(handle, _to_string)
+}
+
+fn _to_string(obj: &Credentials) -> VcxResult<String> {
+    serde_json::to_string(obj)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize Credential object: {:?}", err)))
+}
+
+fn credential_record(obj: &Credentials) -> VcxResult<ObjectRecord> {
+    let (source_id, thread_id, state) = match obj {
+        Credentials::Pending(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        Credentials::V1(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        Credentials::V3(ref obj) => (obj.get_source_id(), Some(obj.get_thread_id()), obj.get_status()),
+    };
+
+    Ok(ObjectRecord::new(source_id, thread_id, state, _to_string(obj)?))
+}
+
+/// Rebuilds a handle from the state stored in the wallet by the `persist_objects` mode.
+pub fn restore(record_id: &str) -> VcxResult<u32> {
+    let credential_data = persistence::load(ObjectType::Credential, record_id)?;
+    HANDLE_MAP.add_restored(record_id, _from_string(&credential_data)?)
 }
 
 pub fn get_source_id(handle: u32) -> VcxResult<String> {
@@ -842,11 +889,24 @@ pub fn get_source_id(handle: u32) -> VcxResult<String> {
     }).map_err(handle_err)
 }
 
+pub fn find_by_thread_id(thread_id: &str) -> VcxResult<Option<u32>> {
+    let handles = HANDLE_MAP.filter_map(|handle, obj| {
+        match obj {
+            Credentials::V3(ref obj) if obj.get_thread_id() == thread_id => Some(handle),
+            _ => None
+        }
+    })?;
+
+    Ok(handles.into_iter().next())
+}
+
 pub fn from_string(credential_data: &str) -> VcxResult<u32> {
-    let credential: Credentials = serde_json::from_str(credential_data)
-        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Credential: {:?}", err)))?;
+    HANDLE_MAP.add(_from_string(credential_data)?)
+}
 
-    HANDLE_MAP.add(credential)
+fn _from_string(credential_data: &str) -> VcxResult<Credentials> {
+    serde_json::from_str(credential_data)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Credential: {:?}", err)))
 }
 
 pub fn is_payment_required(handle: u32) -> VcxResult<bool> {
@@ -893,6 +953,20 @@ pub fn get_credential_status(handle: u32) -> VcxResult<u32> {
     })
 }
 
+pub fn get_revocation_notification(handle: u32) -> VcxResult<String> {
+    HANDLE_MAP.get(handle, |obj| {
+        match obj {
+            Credentials::Pending(_) | Credentials::V1(_) => {
+                Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Revocation Notification is supported only for Aries protocol"))
+            }
+            Credentials::V3(ref obj) => {
+                let notification = obj.get_revocation_notification()?;
+                Ok(json!(notification.to_a2a_message()).to_string())
+            }
+        }
+    })
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -5,6 +5,7 @@ use time;
 use std::convert::TryInto;
 
 use object_cache::ObjectCache;
+use object_cache::persistence::{self, ObjectRecord, ObjectType};
 use api::VcxStateType;
 use error::prelude::*;
 
@@ -36,6 +37,7 @@ use utils::libindy::anoncreds;
 use utils::libindy::anoncreds::{get_rev_reg_def_json, get_rev_reg_delta_json};
 
 use v3::{
+    messages::a2a::A2AMessage,
     messages::proof_presentation::presentation_request::PresentationRequest,
     handlers::proof_presentation::prover::prover::Prover,
 };
@@ -44,7 +46,7 @@ use utils::agent_info::{get_agent_info, MyAgentInfo, get_agent_attr};
 use utils::httpclient::AgencyMock;
 
 lazy_static! {
-    static ref HANDLE_MAP: ObjectCache<DisclosedProofs>  = Default::default();
+    static ref HANDLE_MAP: ObjectCache<DisclosedProofs> = ObjectCache::with_persistence(ObjectType::DisclosedProof, disclosed_proof_record);
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -582,6 +584,12 @@ fn apply_agent_info(proof: &mut DisclosedProof, agent_info: &MyAgentInfo) {
 fn create_proof_v3(source_id: &str, proof_req: &str) -> VcxResult<Option<DisclosedProofs>> {
     trace!("create_proof_v3 >>> source_id: {}, proof_req: {}", source_id, proof_req);
 
+    // Received request of aries Present Proof 2.0 protocol
+    if let Ok(A2AMessage::PresentationRequestV2(presentation_request)) = serde_json::from_str::<A2AMessage>(proof_req) {
+        let proof = Prover::create_v2(source_id, presentation_request)?;
+        return Ok(Some(DisclosedProofs::V3(proof)));
+    }
+
     // Received request of new format -- redirect to v3 folder
     if let Ok(presentation_request) = serde_json::from_str::<PresentationRequest>(proof_req) {
         let proof = Prover::create(source_id, presentation_request)?;
@@ -614,6 +622,11 @@ pub fn create_proof(source_id: &str, proof_req: &str) -> VcxResult<u32> {
 
     // strict aries protocol is set. Presentation Request must be in aries format
     if settings::is_strict_aries_protocol_set() {
+        if let Ok(A2AMessage::PresentationRequestV2(presentation_request)) = serde_json::from_str::<A2AMessage>(proof_req) {
+            let proof = Prover::create_v2(source_id, presentation_request)?;
+            return HANDLE_MAP.add(DisclosedProofs::V3(proof));
+        }
+
         let presentation_request: PresentationRequest = serde_json::from_str(proof_req)
             .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson,
                                               format!("Strict `aries` protocol is enabled. Can not parse `aries` formatted Presentation Request: {}", err)))?;
@@ -683,17 +696,48 @@ pub fn update_state(handle: u32, message: Option<String>) -> VcxResult<u32> {
 }
 
 pub fn to_string(handle: u32) -> VcxResult<String> {
-    HANDLE_MAP.get(handle, |obj| {
-        serde_json::to_string(obj)
-            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize DisclosedProof object: {:?}", err)))
-    })
+    HANDLE_MAP.get(handle, _to_string)
+}
+
+fn _to_string(obj: &DisclosedProofs) -> VcxResult<String> {
+    serde_json::to_string(obj)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize DisclosedProof object: {:?}", err)))
+}
+
+fn disclosed_proof_record(obj: &DisclosedProofs) -> VcxResult<ObjectRecord> {
+    let (source_id, thread_id, state) = match obj {
+        DisclosedProofs::Pending(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        DisclosedProofs::V1(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        DisclosedProofs::V3(ref obj) => (obj.get_source_id(), Some(obj.get_thread_id()), obj.state()),
+    };
+
+    Ok(ObjectRecord::new(source_id, thread_id, state, _to_string(obj)?))
+}
+
+/// Rebuilds a handle from the state stored in the wallet by the `persist_objects` mode.
+pub fn restore(record_id: &str) -> VcxResult<u32> {
+    let proof_data = persistence::load(ObjectType::DisclosedProof, record_id)?;
+    HANDLE_MAP.add_restored(record_id, _from_string(&proof_data)?)
+}
+
+pub fn find_by_thread_id(thread_id: &str) -> VcxResult<Option<u32>> {
+    let handles = HANDLE_MAP.filter_map(|handle, obj| {
+        match obj {
+            DisclosedProofs::V3(ref obj) if obj.get_thread_id() == thread_id => Some(handle),
+            _ => None
+        }
+    })?;
+
+    Ok(handles.into_iter().next())
 }
 
 pub fn from_string(proof_data: &str) -> VcxResult<u32> {
-    let proof: DisclosedProofs = serde_json::from_str(proof_data)
-        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("cannot deserialize DisclosedProofs object: {:?}", err)))?;
+    HANDLE_MAP.add(_from_string(proof_data)?)
+}
 
-    HANDLE_MAP.add(proof)
+fn _from_string(proof_data: &str) -> VcxResult<DisclosedProofs> {
+    serde_json::from_str(proof_data)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("cannot deserialize DisclosedProofs object: {:?}", err)))
 }
 
 pub fn release(handle: u32) -> VcxResult<()> {
@@ -863,7 +907,7 @@ pub fn is_valid_handle(handle: u32) -> bool {
 fn get_proof_request(connection_handle: u32, msg_id: &str) -> VcxResult<String> {
     if connection::is_v3_connection(connection_handle)? {
         let presentation_request = Prover::get_presentation_request(connection_handle, msg_id)?;
-        return serde_json::to_string_pretty(&presentation_request)
+        return serde_json::to_string_pretty(&_aries_request_json(presentation_request))
             .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot serialize message: {}", err)));
     }
 
@@ -891,6 +935,15 @@ fn get_proof_request(connection_handle: u32, msg_id: &str) -> VcxResult<String>
     }
 }
 
+// Present Proof 1.0 requests are returned without `@type` as before,
+// 2.0 requests keep it to be recognized by `create_proof`
+fn _aries_request_json(presentation_request: A2AMessage) -> Value {
+    match presentation_request {
+        A2AMessage::PresentationRequest(presentation_request) => json!(presentation_request),
+        presentation_request => json!(presentation_request)
+    }
+}
+
 //TODO one function with credential
 pub fn get_proof_request_messages(connection_handle: u32, match_name: Option<&str>) -> VcxResult<String> {
     if connection::is_v3_connection(connection_handle)? {
@@ -898,13 +951,23 @@ pub fn get_proof_request_messages(connection_handle: u32, match_name: Option<&st
 
         // strict aries protocol is set. return aries formatted Proof Request.
         if settings::is_strict_aries_protocol_set() {
+            let presentation_requests: Vec<Value> = presentation_requests.into_iter().map(_aries_request_json).collect();
             return Ok(json!(presentation_requests).to_string());
         }
 
-        let msgs: Vec<ProofRequestMessage> = presentation_requests
+        let msgs: Vec<Value> = presentation_requests
             .into_iter()
-            .map(|presentation_request| presentation_request.try_into())
-            .collect::<VcxResult<Vec<ProofRequestMessage>>>()?;
+            .map(|presentation_request| {
+                match presentation_request {
+                    A2AMessage::PresentationRequest(presentation_request) => {
+                        let presentation_request: ProofRequestMessage = presentation_request.try_into()?;
+                        Ok(json!(presentation_request))
+                    }
+                    // Present Proof 2.0 requests have no proprietary representation
+                    presentation_request => Ok(_aries_request_json(presentation_request))
+                }
+            })
+            .collect::<VcxResult<Vec<Value>>>()?;
 
         return serde_json::to_string(&msgs).
             map_err(|err| {
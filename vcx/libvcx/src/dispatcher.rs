@@ -0,0 +1,517 @@
+use serde_json;
+
+use api::VcxStateType;
+use error::prelude::*;
+use messages::MessageStatusCode;
+use messages::get_message::{download_messages, Message};
+use messages::update_message::{UIDsByConn, update_messages};
+use object_cache::persistence::ObjectType;
+use settings;
+use v3::messages::a2a::A2AMessage;
+use v3::utils::transport;
+use ::{connection, credential, disclosed_proof, issuer_credential, mediation, proof};
+
+/// Rounds of inbound queue processing per `dispatch_messages` call in agency-less mode.
+/// Each round may queue further messages (e.g. batches picked up from the mediator).
+const MAX_INBOUND_QUEUE_ROUNDS: usize = 10;
+
+/// Result of dispatching a single downloaded message.
+#[derive(Debug, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum DispatchEvent {
+    /// Message was handled by the existing object which state has been advanced.
+    /// Request to the public DID invitation is handled by a new connection: `handle` refers to it.
+    StateUpdated {
+        connection_handle: u32,
+        uid: String,
+        object_type: ObjectType,
+        handle: u32,
+        state: u32,
+    },
+    /// Message starts a new protocol (offer, request or proposal) and no object owns its thread yet.
+    NewProtocol {
+        connection_handle: u32,
+        uid: String,
+        message: A2AMessage,
+    },
+    /// Message was received from the mediator and has advanced the state of the mediation.
+    MediationUpdated {
+        connection_handle: u32,
+        uid: String,
+        state: u32,
+    },
+    /// Message does not belong to any object and does not start a protocol.
+    Unhandled {
+        connection_handle: u32,
+        uid: String,
+        message: A2AMessage,
+    },
+    /// Message could not be decoded or processed. It stays unreviewed on the agency.
+    /// In agency-less mode the message is dropped: `connection_handle` is 0 and `uid` is empty.
+    Failed {
+        connection_handle: u32,
+        uid: String,
+        error: String,
+    },
+}
+
+/// Downloads received messages for all Aries connections in one request, routes every message
+/// to the object owning its thread and marks processed messages as reviewed.
+/// In agency-less mode dispatches messages returned by peers in HTTP responses instead.
+pub fn dispatch_messages() -> VcxResult<Vec<DispatchEvent>> {
+    trace!("dispatch_messages >>>");
+
+    if settings::is_agency_less() {
+        return _dispatch_inbound_queue();
+    }
+
+    let pw_dids = connection::get_v3_pw_dids()?;
+
+    if pw_dids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let received = vec![MessageStatusCode::Received.to_string()];
+    let messages = download_messages(Some(pw_dids.keys().cloned().collect()), Some(received), None)?;
+
+    let mut events = Vec::new();
+    let mut reviewed = Vec::new();
+
+    for messages_by_connection in messages {
+        let connection_handle = match pw_dids.get(&messages_by_connection.pairwise_did) {
+            Some(handle) => *handle,
+            None => {
+                warn!("dispatch_messages: received messages for unknown pairwise DID: {}", messages_by_connection.pairwise_did);
+                continue;
+            }
+        };
+
+        let mut uids = Vec::new();
+
+        for message in messages_by_connection.msgs {
+            let uid = message.uid.clone();
+
+            let event = _dispatch_message(connection_handle, message)
+                .unwrap_or_else(|err| {
+                    warn!("dispatch_messages: cannot process message {}: {}", uid, err);
+                    DispatchEvent::Failed { connection_handle, uid: uid.clone(), error: err.to_string() }
+                });
+
+            match event {
+                DispatchEvent::Failed { .. } => {}
+                _ => uids.push(uid)
+            }
+
+            events.push(event);
+        }
+
+        if !uids.is_empty() {
+            reviewed.push(UIDsByConn { pairwise_did: messages_by_connection.pairwise_did, uids });
+        }
+    }
+
+    if !reviewed.is_empty() {
+        update_messages(MessageStatusCode::Reviewed, reviewed)?;
+    }
+
+    trace!("dispatch_messages <<< events: {:?}", events);
+
+    Ok(events)
+}
+
+pub fn dispatch_messages_json() -> VcxResult<String> {
+    let events = dispatch_messages()?;
+
+    serde_json::to_string(&events)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::SerializationError, format!("Cannot serialize dispatch events: {:?}", err)))
+}
+
+/// Dispatches packed message received by the application over HTTP in agency-less mode.
+/// Returns the dispatch event and the packed reply to return in HTTP response
+/// if the sender asked for `~transport.return_route`.
+pub fn receive_message(packed: &[u8]) -> VcxResult<(DispatchEvent, Option<Vec<u8>>)> {
+    trace!("receive_message >>>");
+
+    let (event, reply) = _receive_message(packed, true)?;
+
+    trace!("receive_message <<< event: {:?}, reply: {:?}", event, reply.is_some());
+
+    Ok((event, reply))
+}
+
+// return route is only honored for messages received over the HTTP request of the application:
+// there is no HTTP response to return the reply in for the queued ones, so their replies are posted
+fn _receive_message(packed: &[u8], return_route: bool) -> VcxResult<(DispatchEvent, Option<Vec<u8>>)> {
+    let inbound = transport::unpack(packed)?;
+
+    let connection_handle = connection::find_v3_by_verkey(&inbound.recipient_vk)?
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidConnectionHandle, format!("Connection not found for recipient key: {}", inbound.recipient_vk)))?;
+
+    match inbound.sender_vk {
+        Some(ref sender_vk) if return_route && inbound.return_route => transport::start_return_route(sender_vk),
+        _ => {}
+    }
+
+    let uid = _message_id(&inbound.message);
+    let event = _route_message(connection_handle, uid, inbound.message);
+    let reply = transport::finish_return_route();
+
+    Ok((event?, reply))
+}
+
+pub fn receive_message_json(packed: &[u8]) -> VcxResult<(String, Option<Vec<u8>>)> {
+    let (event, reply) = receive_message(packed)?;
+
+    let event = serde_json::to_string(&event)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::SerializationError, format!("Cannot serialize dispatch event: {:?}", err)))?;
+
+    Ok((event, reply))
+}
+
+fn _dispatch_inbound_queue() -> VcxResult<Vec<DispatchEvent>> {
+    let mut events = Vec::new();
+    let mut postponed = Vec::new();
+
+    // messages picked up from the mediator are queued while the batch is processed, processing of
+    // messages queued after the last round is left to the next call
+    for _ in 0..MAX_INBOUND_QUEUE_ROUNDS {
+        let inbound = transport::take_inbound()?;
+
+        if inbound.is_empty() {
+            break;
+        }
+
+        for packed in inbound {
+            match _receive_message(&packed, false) {
+                Ok((event, _)) => events.push(event),
+                // owner connection is being updated by another call, message is dispatched by the next call
+                Err(ref err) if err.kind() == VcxErrorKind::NotReady => postponed.push(packed),
+                Err(err) => {
+                    warn!("dispatch_messages: cannot process inbound message: {}", err);
+                    events.push(DispatchEvent::Failed { connection_handle: 0, uid: String::new(), error: err.to_string() });
+                }
+            }
+        }
+    }
+
+    for packed in postponed {
+        transport::queue_inbound(packed)?;
+    }
+
+    Ok(events)
+}
+
+fn _message_id(message: &A2AMessage) -> String {
+    serde_json::to_value(message).ok()
+        .and_then(|message| message["@id"].as_str().map(String::from))
+        .unwrap_or_default()
+}
+
+fn _dispatch_message(connection_handle: u32, message: Message) -> VcxResult<DispatchEvent> {
+    let uid = message.uid.clone();
+    let message = connection::decode_message(connection_handle, message)?;
+
+    _route_message(connection_handle, uid, message)
+}
+
+fn _route_message(connection_handle: u32, uid: String, message: A2AMessage) -> VcxResult<DispatchEvent> {
+    if _is_mediation_message(&message) {
+        let state = mediation::update_state(&connection::get_pw_verkey(connection_handle)?, message)?;
+        return Ok(DispatchEvent::MediationUpdated { connection_handle, uid, state });
+    }
+
+    if let Some((object_type, handle)) = _find_owner(&message)? {
+        let state = _update_state(object_type, handle, &message)?;
+        return Ok(DispatchEvent::StateUpdated { connection_handle, uid, object_type, handle, state });
+    }
+
+    if let A2AMessage::DidExchangeRequest(_) = message {
+        if let Some(handle) = connection::spawn_from_public_invitation(connection_handle)? {
+            return _handle_public_invitation_request(handle, uid, &message);
+        }
+    }
+
+    if _is_connection_message(&message) {
+        let state = _update_state(ObjectType::Connection, connection_handle, &message)?;
+        return Ok(DispatchEvent::StateUpdated { connection_handle, uid, object_type: ObjectType::Connection, handle: connection_handle, state });
+    }
+
+    if _starts_protocol(&message) {
+        return Ok(DispatchEvent::NewProtocol { connection_handle, uid, message });
+    }
+
+    Ok(DispatchEvent::Unhandled { connection_handle, uid, message })
+}
+
+// request is handled by a new connection, it is released if the request is rejected
+fn _handle_public_invitation_request(handle: u32, uid: String, message: &A2AMessage) -> VcxResult<DispatchEvent> {
+    let state = _update_state(ObjectType::Connection, handle, message);
+
+    match state {
+        Ok(state) if state == VcxStateType::VcxStateRequestReceived as u32 =>
+            Ok(DispatchEvent::StateUpdated { connection_handle: handle, uid, object_type: ObjectType::Connection, handle, state }),
+        res => {
+            connection::release(handle)?;
+            res.and_then(|state| Err(VcxError::from_msg(VcxErrorKind::InvalidState, format!("Request to public DID invitation is not accepted, state: {}", state))))
+        }
+    }
+}
+
+fn _find_owner(message: &A2AMessage) -> VcxResult<Option<(ObjectType, u32)>> {
+    let thread_id = match message.thread_id() {
+        Some(thread_id) => thread_id,
+        None => return Ok(None)
+    };
+
+    for object_type in _owner_types(message) {
+        let handle = match object_type {
+            ObjectType::IssuerCredential => issuer_credential::find_by_thread_id(&thread_id)?,
+            ObjectType::Credential => credential::find_by_thread_id(&thread_id)?,
+            ObjectType::Proof => proof::find_by_thread_id(&thread_id)?,
+            ObjectType::DisclosedProof => disclosed_proof::find_by_thread_id(&thread_id)?,
+            ObjectType::Connection => None,
+        };
+
+        if let Some(handle) = handle {
+            return Ok(Some((*object_type, handle)));
+        }
+    }
+
+    Ok(None)
+}
+
+fn _owner_types(message: &A2AMessage) -> &'static [ObjectType] {
+    match message {
+        A2AMessage::CredentialProposal(_) |
+        A2AMessage::CredentialRequest(_) |
+        A2AMessage::CredentialAck(_) |
+        A2AMessage::CredentialProposalV2(_) |
+        A2AMessage::CredentialRequestV2(_) |
+        A2AMessage::CredentialAckV2(_) => &[ObjectType::IssuerCredential],
+        A2AMessage::CredentialOffer(_) |
+        A2AMessage::Credential(_) |
+        A2AMessage::CredentialOfferV2(_) |
+        A2AMessage::CredentialV2(_) |
+        A2AMessage::RevocationNotification(_) => &[ObjectType::Credential],
+        A2AMessage::PresentationProposal(_) |
+        A2AMessage::Presentation(_) |
+        A2AMessage::PresentationProposalV2(_) |
+        A2AMessage::PresentationV2(_) => &[ObjectType::Proof],
+        A2AMessage::PresentationRequest(_) |
+        A2AMessage::PresentationAck(_) |
+        A2AMessage::PresentationRequestV2(_) |
+        A2AMessage::PresentationAckV2(_) => &[ObjectType::DisclosedProof],
+        A2AMessage::Ack(_) |
+        A2AMessage::CommonProblemReport(_) => &[ObjectType::IssuerCredential, ObjectType::Credential, ObjectType::Proof, ObjectType::DisclosedProof],
+        _ => &[]
+    }
+}
+
+fn _is_connection_message(message: &A2AMessage) -> bool {
+    match message {
+        A2AMessage::ConnectionRequest(_) |
+        A2AMessage::ConnectionResponse(_) |
+        A2AMessage::ConnectionProblemReport(_) |
+        A2AMessage::DidExchangeRequest(_) |
+        A2AMessage::DidExchangeResponse(_) |
+        A2AMessage::DidExchangeComplete(_) |
+        A2AMessage::DidExchangeProblemReport(_) |
+        A2AMessage::Ack(_) |
+        A2AMessage::Ping(_) |
+        A2AMessage::PingResponse(_) |
+        A2AMessage::Query(_) |
+        A2AMessage::Disclose(_) => true,
+        _ => false
+    }
+}
+
+fn _is_mediation_message(message: &A2AMessage) -> bool {
+    match message {
+        A2AMessage::MediateGrant(_) |
+        A2AMessage::MediateDeny(_) |
+        A2AMessage::KeylistUpdateResponse(_) |
+        A2AMessage::Keylist(_) |
+        A2AMessage::PickupStatus(_) |
+        A2AMessage::Batch(_) => true,
+        _ => false
+    }
+}
+
+fn _starts_protocol(message: &A2AMessage) -> bool {
+    match message {
+        A2AMessage::CredentialProposal(_) |
+        A2AMessage::CredentialOffer(_) |
+        A2AMessage::PresentationProposal(_) |
+        A2AMessage::PresentationRequest(_) |
+        A2AMessage::CredentialProposalV2(_) |
+        A2AMessage::CredentialOfferV2(_) |
+        A2AMessage::PresentationProposalV2(_) |
+        A2AMessage::PresentationRequestV2(_) => true,
+        _ => false
+    }
+}
+
+fn _update_state(object_type: ObjectType, handle: u32, message: &A2AMessage) -> VcxResult<u32> {
+    let message = serde_json::to_string(message)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::SerializationError, format!("Cannot serialize A2A message: {:?}", err)))?;
+
+    match object_type {
+        ObjectType::Connection => {
+            connection::update_state(handle, Some(message))?;
+            Ok(connection::get_state(handle))
+        }
+        ObjectType::IssuerCredential => issuer_credential::update_state(handle, Some(message)),
+        ObjectType::Credential => {
+            credential::update_state(handle, Some(message))?;
+            credential::get_state(handle)
+        }
+        ObjectType::Proof => proof::update_state(handle, Some(message)),
+        ObjectType::DisclosedProof => disclosed_proof::update_state(handle, Some(message)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::devsetup::SetupAriesMocks;
+    use v3::messages::issuance::credential_offer::CredentialOffer;
+    use v3::messages::issuance::credential_offer::tests::_credential_offer;
+    use v3::messages::issuance::credential_request::tests::_credential_request;
+    use v3::messages::revocation_notification::revoke::tests::_revocation_notification;
+    use v3::messages::trust_ping::ping::tests::_ping;
+    use v3::messages::mediation::mediate::tests::_mediate_grant;
+    use utils::devsetup::SetupLibraryWallet;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    // minimal HTTP endpoint of the application: feeds posted messages to `receive_message`
+    // and returns the reply in the response body
+    fn _serve(stream: TcpStream) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut content_length = 0;
+
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+
+            let line = line.trim().to_lowercase();
+            if line.is_empty() { break; }
+
+            if line.starts_with("content-length:") {
+                content_length = line["content-length:".len()..].trim().parse().unwrap();
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap();
+
+        let (status, reply) = match receive_message(&body) {
+            Ok((_, reply)) => ("200 OK", reply.unwrap_or_default()),
+            Err(err) => ("500 Internal Server Error", err.to_string().into_bytes())
+        };
+
+        let mut stream = stream;
+        write!(stream, "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", status, reply.len()).unwrap();
+        stream.write_all(&reply).unwrap();
+    }
+
+    fn _start_endpoint() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                _serve(stream.unwrap());
+            }
+        });
+
+        endpoint
+    }
+
+    #[test]
+    fn test_thread_id_of_message() {
+        let _setup = SetupAriesMocks::init();
+
+        let offer = CredentialOffer { thread: None, .._credential_offer() };
+        assert_eq!(Some(offer.id.0.clone()), offer.to_a2a_message().thread_id());
+
+        let request = _credential_request().set_thread_id("thread_1");
+        assert_eq!(Some("thread_1".to_string()), request.to_a2a_message().thread_id());
+    }
+
+    #[test]
+    fn test_routing_of_messages() {
+        let _setup = SetupAriesMocks::init();
+
+        let offer = A2AMessage::CredentialOffer(_credential_offer());
+        assert!(_starts_protocol(&offer));
+        assert!(!_is_connection_message(&offer));
+        assert_eq!(&[ObjectType::Credential], _owner_types(&offer));
+
+        let request = A2AMessage::CredentialRequest(_credential_request());
+        assert!(!_starts_protocol(&request));
+        assert_eq!(&[ObjectType::IssuerCredential], _owner_types(&request));
+
+        let notification = A2AMessage::RevocationNotification(_revocation_notification());
+        assert!(!_starts_protocol(&notification));
+        assert_eq!(&[ObjectType::Credential], _owner_types(&notification));
+
+        let ping = A2AMessage::Ping(_ping());
+        assert!(_is_connection_message(&ping));
+        assert!(_owner_types(&ping).is_empty());
+
+        let grant = A2AMessage::MediateGrant(_mediate_grant());
+        assert!(_is_mediation_message(&grant));
+        assert!(!_is_connection_message(&grant));
+        assert!(_owner_types(&grant).is_empty());
+    }
+
+    #[test]
+    fn test_dispatch_inbound_queue_reports_failed_messages() {
+        let _setup = SetupLibraryWallet::init();
+
+        settings::set_config_value(settings::CONFIG_SERVICE_ENDPOINT, "http://127.0.0.1:8080");
+
+        transport::queue_inbound(b"not a packed message".to_vec()).unwrap();
+
+        let events = dispatch_messages().unwrap();
+        assert_eq!(1, events.len());
+        match events[0] {
+            DispatchEvent::Failed { connection_handle, .. } => assert_eq!(0, connection_handle),
+            ref event => panic!("Unexpected event: {:?}", event)
+        }
+
+        assert!(transport::take_inbound().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_agency_less_connection_over_http() {
+        let _setup = SetupLibraryWallet::init();
+
+        // inviter and invitee live in this process and talk to each other through the endpoint over HTTP
+        settings::set_config_value(settings::CONFIG_PROTOCOL_TYPE, "3.0");
+        settings::set_config_value(settings::CONFIG_SERVICE_ENDPOINT, &_start_endpoint());
+
+        let inviter = connection::create_connection("inviter").unwrap();
+        connection::connect(inviter, None).unwrap();
+        let invite_details = connection::get_invite_details(inviter, false).unwrap();
+
+        // request is posted to the inviter which returns its response in the HTTP response
+        let invitee = connection::create_connection_with_invite("invitee", &invite_details).unwrap();
+        connection::connect(invitee, None).unwrap();
+
+        let events = dispatch_messages().unwrap();
+        assert_eq!(1, events.len());
+        match events[0] {
+            DispatchEvent::StateUpdated { handle, state, .. } => {
+                assert_eq!(invitee, handle);
+                assert_eq!(VcxStateType::VcxStateAccepted as u32, state);
+            }
+            ref event => panic!("Unexpected event: {:?}", event)
+        }
+
+        assert_eq!(VcxStateType::VcxStateAccepted as u32, connection::get_state(invitee));
+        assert_eq!(VcxStateType::VcxStateAccepted as u32, connection::get_state(inviter));
+    }
+}
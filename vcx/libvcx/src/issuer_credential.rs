@@ -13,17 +13,18 @@ use credential_request::CredentialRequest;
 use utils::error;
 use utils::libindy::{payments, anoncreds};
 use utils::constants::CRED_MSG;
-use utils::openssl::encode;
+use utils::libindy::anoncreds::libindy_encode_attribute_value;
 use utils::libindy::payments::PaymentTxn;
 use utils::qualifier;
 use object_cache::ObjectCache;
+use object_cache::persistence::{self, ObjectRecord, ObjectType};
 use error::prelude::*;
 
 use v3::handlers::issuance::Issuer;
 use utils::agent_info::{get_agent_info, MyAgentInfo, get_agent_attr};
 
 lazy_static! {
-    static ref ISSUER_CREDENTIAL_MAP: ObjectCache < IssuerCredentials > = Default::default();
+    static ref ISSUER_CREDENTIAL_MAP: ObjectCache<IssuerCredentials> = ObjectCache::with_persistence(ObjectType::IssuerCredential, issuer_credential_record);
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -606,7 +607,7 @@ pub fn encode_attributes(attributes: &str) -> VcxResult<String> {
             }
         };
 
-        let encoded = encode(&first_attr)?;
+        let encoded = libindy_encode_attribute_value(&first_attr)?;
         let attrib_values = json!({
             "raw": first_attr,
             "encoded": encoded
@@ -728,17 +729,48 @@ pub fn is_valid_handle(handle: u32) -> bool {
 }
 
 pub fn to_string(handle: u32) -> VcxResult<String> {
-    ISSUER_CREDENTIAL_MAP.get(handle, |obj| {
-        serde_json::to_string(obj)
-            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize IssuerCredential object: {:?}", err)))
-    })
+    ISSUER_CREDENTIAL_MAP.get(handle, _to_string)
+}
+
+fn _to_string(obj: &IssuerCredentials) -> VcxResult<String> {
+    serde_json::to_string(obj)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize IssuerCredential object: {:?}", err)))
+}
+
+fn issuer_credential_record(obj: &IssuerCredentials) -> VcxResult<ObjectRecord> {
+    let (source_id, thread_id, state) = match obj {
+        IssuerCredentials::Pending(ref obj) => (obj.get_source_id().to_string(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        IssuerCredentials::V1(ref obj) => (obj.get_source_id().to_string(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        IssuerCredentials::V3(ref obj) => (obj.get_source_id()?, Some(obj.get_thread_id()), obj.get_state()?),
+    };
+
+    Ok(ObjectRecord::new(source_id, thread_id, state, _to_string(obj)?))
+}
+
+/// Rebuilds a handle from the state stored in the wallet by the `persist_objects` mode.
+pub fn restore(record_id: &str) -> VcxResult<u32> {
+    let credential_data = persistence::load(ObjectType::IssuerCredential, record_id)?;
+    ISSUER_CREDENTIAL_MAP.add_restored(record_id, _from_string(&credential_data)?)
+}
+
+pub fn find_by_thread_id(thread_id: &str) -> VcxResult<Option<u32>> {
+    let handles = ISSUER_CREDENTIAL_MAP.filter_map(|handle, obj| {
+        match obj {
+            IssuerCredentials::V3(ref obj) if obj.get_thread_id() == thread_id => Some(handle),
+            _ => None
+        }
+    })?;
+
+    Ok(handles.into_iter().next())
 }
 
 pub fn from_string(credential_data: &str) -> VcxResult<u32> {
-    let issuer_credential: IssuerCredentials = serde_json::from_str(credential_data)
-        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize IssuerCredential: {:?}", err)))?;
+    ISSUER_CREDENTIAL_MAP.add(_from_string(credential_data)?)
+}
 
-    ISSUER_CREDENTIAL_MAP.add(issuer_credential)
+fn _from_string(credential_data: &str) -> VcxResult<IssuerCredentials> {
+    serde_json::from_str(credential_data)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize IssuerCredential: {:?}", err)))
 }
 
 pub fn generate_credential_offer_msg(handle: u32) -> VcxResult<(String, String)> {
@@ -817,6 +849,16 @@ pub fn revoke_credential(handle: u32) -> VcxResult<()> {
     })
 }
 
+pub fn send_revocation_notification(handle: u32, comment: Option<String>) -> VcxResult<()> {
+    ISSUER_CREDENTIAL_MAP.get(handle, |obj| {
+        match obj {
+            IssuerCredentials::Pending(_) | IssuerCredentials::V1(_) =>
+                Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Revocation Notification is supported only for Aries protocol")),
+            IssuerCredentials::V3(ref obj) => obj.send_revocation_notification(comment.clone())
+        }
+    })
+}
+
 pub fn convert_to_map(s: &str) -> VcxResult<serde_json::Map<String, serde_json::Value>> {
     serde_json::from_str(s)
         .map_err(|_| {
@@ -1334,6 +1376,14 @@ pub mod tests {
         assert!(credential.rev_cred_payment_txn.is_some());
     }
 
+    #[test]
+    fn test_send_revocation_notification_fails_for_proprietary_protocol() {
+        let _setup = SetupMocks::init();
+
+        let handle = _issuer_credential_create();
+        assert_eq!(VcxErrorKind::ActionNotSupported, send_revocation_notification(handle, None).unwrap_err().kind());
+    }
+
 
     #[test]
     fn test_encode_with_several_attributes_success() {
@@ -60,6 +60,8 @@ pub mod error;
 pub mod credential;
 pub mod object_cache;
 pub mod disclosed_proof;
+pub mod dispatcher;
+pub mod mediation;
 
 pub mod v3;
 
@@ -0,0 +1,224 @@
+use std::sync::Mutex;
+
+use api::VcxStateType;
+use connection;
+use error::prelude::*;
+use settings;
+use v3::handlers::mediation::messages::MediationMessage;
+use v3::handlers::mediation::recipient::MediationRecipientSM;
+use v3::messages::a2a::A2AMessage;
+use v3::messages::mediation::keylist::KeylistUpdate;
+
+/*
+    Client of an Aries mediator (Coordinate Mediation and Message Pickup protocols) used in agency-less mode.
+    Once the mediation is granted, new connections advertise the endpoint and routing keys of the mediator
+    and their recipient keys are registered on the mediator. Messages stored by the mediator are picked up
+    in batches and processed by `vcx_messages_dispatch`.
+*/
+
+lazy_static! {
+    static ref MEDIATION: Mutex<Option<MediationRecipientSM>> = Default::default();
+}
+
+fn _with_mediation<F, R>(closure: F) -> VcxResult<R>
+    where F: FnOnce(&mut Option<MediationRecipientSM>) -> VcxResult<R> {
+    let mut mediation = MEDIATION.lock()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::Common(10), format!("Unable to lock mediation: {:?}", err)))?;
+
+    closure(&mut mediation)
+}
+
+fn _step(message: MediationMessage) -> VcxResult<u32> {
+    let (state, outbound) = _with_mediation(|mediation| {
+        let (sm, outbound) = mediation.clone()
+            .ok_or(VcxError::from_msg(VcxErrorKind::NotReady, "Mediation has not been requested"))?
+            .step(message)?;
+
+        let state = sm.state();
+        let outbound = outbound.map(|outbound| (sm.clone(), outbound));
+        *mediation = Some(sm);
+        Ok((state, outbound))
+    })?;
+
+    // sending is a blocking HTTP call, so the lock is released before it
+    if let Some((sm, outbound)) = outbound {
+        sm.send_message(&outbound)?;
+    }
+
+    Ok(state)
+}
+
+fn _step_granted(message: MediationMessage) -> VcxResult<()> {
+    if !is_granted()? {
+        return Err(VcxError::from_msg(VcxErrorKind::NotReady, "Mediation has not been granted"));
+    }
+
+    _step(message).map(|_| ())
+}
+
+/// Asks the peer of the established Aries connection to become a mediator.
+pub fn request_mediation(connection_handle: u32) -> VcxResult<u32> {
+    trace!("mediation::request_mediation >>> connection_handle: {}", connection_handle);
+
+    if !settings::is_agency_less() {
+        return Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Mediation is supported in agency-less mode only"));
+    }
+
+    let (agent_info, did_doc) = connection::get_pairwise_info(connection_handle)?;
+
+    let sm = MediationRecipientSM::new(agent_info, did_doc)
+        .handle_message(MediationMessage::MediateRequestSend())?;
+
+    let state = sm.state();
+    _with_mediation(|mediation| {
+        *mediation = Some(sm);
+        Ok(state)
+    })
+}
+
+pub fn get_state() -> VcxResult<u32> {
+    _with_mediation(|mediation| {
+        Ok(mediation.as_ref().map(|sm| sm.state()).unwrap_or(VcxStateType::VcxStateNone as u32))
+    })
+}
+
+pub fn is_granted() -> VcxResult<bool> {
+    _with_mediation(|mediation| Ok(mediation.as_ref().map(|sm| sm.is_granted()).unwrap_or(false)))
+}
+
+/// Mediator endpoint and routing keys if the mediation is granted.
+pub fn routing() -> VcxResult<Option<(String, Vec<String>)>> {
+    _with_mediation(|mediation| Ok(mediation.as_ref().and_then(|sm| sm.routing())))
+}
+
+/// Handles a message received from the mediator through connection with `recipient_vk` key.
+pub fn update_state(recipient_vk: &str, message: A2AMessage) -> VcxResult<u32> {
+    trace!("mediation::update_state >>> recipient_vk: {}, message: {:?}", recipient_vk, message);
+
+    let is_mediator = _with_mediation(|mediation| Ok(mediation.as_ref().map(|sm| sm.pw_vk() == recipient_vk).unwrap_or(false)))?;
+
+    if !is_mediator {
+        return Err(VcxError::from_msg(VcxErrorKind::InvalidConnectionHandle, "Message is not received from the mediator"));
+    }
+
+    _step(message.into())
+}
+
+/// Registers recipient key of a new connection on the mediator. Does nothing if mediation is not granted.
+///
+/// The key is recorded as pending before the Keylist Update is sent, and a failure to reach the mediator
+/// does not fail the connection: the key stays pending and is resent with the next key list update
+/// or before the next pickup. Note that the invitation of the connection may therefore be published
+/// before the mediator has confirmed the key; messages sent to it until then can be rejected by the mediator.
+pub fn add_recipient_key(recipient_key: &str) -> VcxResult<()> {
+    _update_keylist(KeylistUpdate::create().add_key(recipient_key))
+}
+
+/// Unregisters recipient key of a deleted connection. Does nothing if mediation is not granted.
+/// As with `add_recipient_key`, the update stays pending if the mediator cannot be reached.
+pub fn remove_recipient_key(recipient_key: &str) -> VcxResult<()> {
+    _update_keylist(KeylistUpdate::create().remove_key(recipient_key))
+}
+
+/// Resends key list updates which have not been confirmed by the mediator yet.
+pub fn register_pending_keys() -> VcxResult<()> {
+    _step_granted(MediationMessage::KeylistUpdateSend(KeylistUpdate::create()))
+}
+
+fn _update_keylist(update: KeylistUpdate) -> VcxResult<()> {
+    if !is_granted()? { return Ok(()); }
+
+    if let Err(err) = _step_granted(MediationMessage::KeylistUpdateSend(update)) {
+        warn!("Unable to send key list update to the mediator, it will be resent later: {}", err);
+    }
+
+    Ok(())
+}
+
+fn _has_pending_updates() -> VcxResult<bool> {
+    _with_mediation(|mediation| Ok(mediation.as_ref().map(|sm| sm.has_pending_updates()).unwrap_or(false)))
+}
+
+pub fn keylist_query() -> VcxResult<()> {
+    _step_granted(MediationMessage::KeylistQuerySend())
+}
+
+pub fn status_request() -> VcxResult<()> {
+    _step_granted(MediationMessage::StatusRequestSend())
+}
+
+pub fn pickup(batch_size: u32) -> VcxResult<()> {
+    if batch_size == 0 {
+        return Err(VcxError::from_msg(VcxErrorKind::InvalidOption, "Batch size must be positive"));
+    }
+
+    // messages for keys unknown to the mediator would not be stored, so they are registered first
+    if _has_pending_updates()? {
+        register_pending_keys()?;
+    }
+
+    _step_granted(MediationMessage::BatchPickupSend(batch_size))
+}
+
+pub fn to_string() -> VcxResult<String> {
+    _with_mediation(|mediation| {
+        let sm = mediation.as_ref()
+            .ok_or(VcxError::from_msg(VcxErrorKind::NotReady, "Mediation has not been requested"))?;
+
+        ::serde_json::to_string(sm)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::SerializationError, format!("Cannot serialize Mediation: {:?}", err)))
+    })
+}
+
+pub fn from_string(mediation_data: &str) -> VcxResult<()> {
+    let sm: MediationRecipientSM = ::serde_json::from_str(mediation_data)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Mediation: {:?}", err)))?;
+
+    _with_mediation(|mediation| {
+        *mediation = Some(sm);
+        Ok(())
+    })
+}
+
+pub fn release() -> VcxResult<()> {
+    _with_mediation(|mediation| {
+        *mediation = None;
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use utils::devsetup::SetupAriesMocks;
+    use v3::handlers::connection::agent::AgentInfo;
+    use v3::messages::connection::did_doc::tests::_did_doc_4;
+
+    #[test]
+    fn test_request_mediation_requires_agency_less_mode() {
+        let _setup = SetupAriesMocks::init();
+
+        assert_eq!(VcxErrorKind::ActionNotSupported, request_mediation(1).unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_mediation_serialization() {
+        let _setup = SetupAriesMocks::init();
+
+        let sm = MediationRecipientSM::new(AgentInfo::default(), _did_doc_4());
+        from_string(&::serde_json::to_string(&sm).unwrap()).unwrap();
+
+        assert_eq!(VcxStateType::VcxStateInitialized as u32, get_state().unwrap());
+        assert_eq!(None, routing().unwrap());
+        assert_eq!(VcxErrorKind::NotReady, pickup(10).unwrap_err().kind());
+        add_recipient_key("key").unwrap();
+
+        let serialized = to_string().unwrap();
+        release().unwrap();
+        assert_eq!(VcxStateType::VcxStateNone as u32, get_state().unwrap());
+
+        from_string(&serialized).unwrap();
+        assert_eq!(VcxStateType::VcxStateInitialized as u32, get_state().unwrap());
+        release().unwrap();
+    }
+}
@@ -368,6 +368,11 @@ impl Message {
             A2AMessage::Presentation(presentation) => {
                 convert_aries_message!(presentation, a2a_message, ProofMessage, Proof)
             }
+            // 2.0 protocol messages have no proprietary representation
+            A2AMessage::CredentialOfferV2(_) => (PayloadKinds::CredOffer, json!(&a2a_message).to_string()),
+            A2AMessage::CredentialV2(_) => (PayloadKinds::Cred, json!(&a2a_message).to_string()),
+            A2AMessage::PresentationRequestV2(_) => (PayloadKinds::ProofRequest, json!(&a2a_message).to_string()),
+            A2AMessage::PresentationV2(_) => (PayloadKinds::Proof, json!(&a2a_message).to_string()),
             msg => {
                 let msg = json!(&msg).to_string();
                 (PayloadKinds::Other(String::from("aries")), msg)
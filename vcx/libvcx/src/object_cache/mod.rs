@@ -1,27 +1,83 @@
 use rand::Rng;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, TryLockError};
 use std::sync::MutexGuard;
 use std::collections::HashMap;
-use std::ops::Deref;
-use std::ops::DerefMut;
 
 use error::prelude::*;
+use settings;
+use utils::uuid::uuid;
+
+pub mod persistence;
+
+use self::persistence::{ObjectRecord, ObjectType};
+
+struct CacheEntry<T> {
+    obj: T,
+    // id of the wallet record holding the object state in `persist_objects` mode
+    record_id: String,
+    // data of the last record written to the wallet, used to skip writes of unchanged objects
+    persisted_data: Option<String>,
+}
+
+impl<T> CacheEntry<T> {
+    fn new(obj: T, record_id: String, persisted_data: Option<String>) -> CacheEntry<T> {
+        CacheEntry { obj, record_id, persisted_data }
+    }
+}
 
 pub struct ObjectCache<T> {
-    pub store: Mutex<HashMap<u32, Mutex<T>>>,
+    store: Mutex<HashMap<u32, Arc<Mutex<CacheEntry<T>>>>>,
+    persistence: Option<(ObjectType, fn(&T) -> VcxResult<ObjectRecord>)>,
 }
 
 impl<T> Default for ObjectCache<T> {
     fn default() -> ObjectCache<T>
     {
         ObjectCache {
-            store: Default::default()
+            store: Default::default(),
+            persistence: None,
         }
     }
 }
 
 impl<T> ObjectCache<T> {
-    fn _lock_store(&self) -> VcxResult<MutexGuard<HashMap<u32, Mutex<T>>>> {
+    /// Creates a cache which stores the record built by `to_record` in the wallet every time
+    /// an object is added or changed while `persist_objects` setting is enabled.
+    pub fn with_persistence(object_type: ObjectType, to_record: fn(&T) -> VcxResult<ObjectRecord>) -> ObjectCache<T> {
+        ObjectCache {
+            store: Default::default(),
+            persistence: Some((object_type, to_record)),
+        }
+    }
+
+    fn _persistence(&self) -> Option<(ObjectType, fn(&T) -> VcxResult<ObjectRecord>)> {
+        match self.persistence {
+            Some(persistence) if settings::persist_objects_enabled() => Some(persistence),
+            _ => None
+        }
+    }
+
+    /// Writes the object state into the wallet if it differs from the last written one.
+    /// Called with the lock of the object held but the lock of the whole store released,
+    /// so concurrent writes of the same object can't be reordered.
+    fn _persist(&self, entry: &mut CacheEntry<T>) -> VcxResult<()> {
+        let (object_type, to_record) = match self._persistence() {
+            Some(persistence) => persistence,
+            None => return Ok(())
+        };
+
+        let record = to_record(&entry.obj)?;
+
+        if entry.persisted_data.as_ref() == Some(&record.data) {
+            return Ok(());
+        }
+
+        persistence::store(object_type, &entry.record_id, &record)?;
+        entry.persisted_data = Some(record.data);
+        Ok(())
+    }
+
+    fn _lock_store(&self) -> VcxResult<MutexGuard<HashMap<u32, Arc<Mutex<CacheEntry<T>>>>>> {
         match self.store.lock() {
             Ok(g) => Ok(g),
             Err(e) => {
@@ -44,7 +100,7 @@ impl<T> ObjectCache<T> {
         let store = self._lock_store()?;
         match store.get(&handle) {
             Some(m) => match m.lock() {
-                Ok(obj) => closure(obj.deref()),
+                Ok(entry) => closure(&entry.obj),
                 Err(_) => Err(VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store")) //TODO better error
             },
             None => Err(VcxError::from_msg(VcxErrorKind::InvalidHandle, format!("Object not found for handle: {}", handle)))
@@ -53,17 +109,76 @@ impl<T> ObjectCache<T> {
 
     pub fn get_mut<F, R>(&self, handle: u32, closure: F) -> VcxResult<R>
         where F: Fn(&mut T) -> VcxResult<R> {
-        let mut store = self._lock_store()?;
-        match store.get_mut(&handle) {
-            Some(m) => match m.lock() {
-                Ok(mut obj) => closure(obj.deref_mut()),
-                Err(_) => Err(VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store")) //TODO better error
-            },
-            None => Err(VcxError::from_msg(VcxErrorKind::InvalidHandle, format!("Object not found for handle: {}", handle)))
+        let entry = {
+            let store = self._lock_store()?;
+            match store.get(&handle) {
+                Some(m) => m.clone(),
+                None => return Err(VcxError::from_msg(VcxErrorKind::InvalidHandle, format!("Object not found for handle: {}", handle)))
+            }
+        };
+
+        let mut entry = entry.lock()
+            .map_err(|_| VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store"))?; //TODO better error
+
+        let res = closure(&mut entry.obj)?;
+        self._persist(&mut entry)?;
+        Ok(res)
+    }
+
+    pub fn filter_map<F, R>(&self, closure: F) -> VcxResult<Vec<R>>
+        where F: Fn(u32, &T) -> Option<R> {
+        let store = self._lock_store()?;
+
+        let mut result = Vec::new();
+
+        for (handle, m) in store.iter() {
+            match m.lock() {
+                Ok(entry) => result.extend(closure(*handle, &entry.obj)),
+                Err(_) => return Err(VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store")) //TODO better error
+            }
         }
+
+        Ok(result)
+    }
+
+    /// Same as `filter_map` but doesn't wait for objects locked by an operation in progress
+    /// (e.g. sending a message and waiting for the HTTP response). They are skipped and their number is returned.
+    pub fn try_filter_map<F, R>(&self, closure: F) -> VcxResult<(Vec<R>, usize)>
+        where F: Fn(u32, &T) -> Option<R> {
+        let store = self._lock_store()?;
+
+        let mut result = Vec::new();
+        let mut busy = 0;
+
+        for (handle, m) in store.iter() {
+            match m.try_lock() {
+                Ok(entry) => result.extend(closure(*handle, &entry.obj)),
+                Err(TryLockError::WouldBlock) => busy += 1,
+                Err(TryLockError::Poisoned(_)) => return Err(VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store")) //TODO better error
+            }
+        }
+
+        Ok((result, busy))
     }
 
     pub fn add(&self, obj: T) -> VcxResult<u32> {
+        let mut entry = CacheEntry::new(obj, uuid(), None);
+        self._persist(&mut entry)?;
+        self._add_entry(entry)
+    }
+
+    /// Adds an object recreated from the wallet record `record_id`, so its further changes
+    /// update the same record.
+    pub fn add_restored(&self, record_id: &str, obj: T) -> VcxResult<u32> {
+        let persisted_data = match self.persistence {
+            Some((_, to_record)) => Some(to_record(&obj)?.data),
+            None => None
+        };
+
+        self._add_entry(CacheEntry::new(obj, record_id.to_string(), persisted_data))
+    }
+
+    fn _add_entry(&self, entry: CacheEntry<T>) -> VcxResult<u32> {
         let mut store = self._lock_store()?;
 
         let mut new_handle = rand::thread_rng().gen::<u32>();
@@ -74,28 +189,48 @@ impl<T> ObjectCache<T> {
             new_handle = rand::thread_rng().gen::<u32>();
         }
 
-        match store.insert(new_handle, Mutex::new(obj)) {
-            Some(_) => Ok(new_handle),
-            None => Ok(new_handle)
-        }
+        store.insert(new_handle, Arc::new(Mutex::new(entry)));
+        Ok(new_handle)
     }
 
     pub fn insert(&self, handle: u32, obj: T) -> VcxResult<()> {
+        let mut entry = CacheEntry::new(obj, uuid(), None);
+        self._persist(&mut entry)?;
+
         let mut store = self._lock_store()?;
 
-        match store.insert(handle, Mutex::new(obj)) {
+        match store.insert(handle, Arc::new(Mutex::new(entry))) {
             _ => Ok(()),
         }
     }
 
+    /// Removes the object from the cache and deletes its state stored in the wallet.
     pub fn release(&self, handle: u32) -> VcxResult<()> {
-        let mut store = self._lock_store()?;
-        match store.remove(&handle) {
-            Some(_) => Ok(()),
-            None => Err(VcxError::from_msg(VcxErrorKind::InvalidHandle, format!("Object not found for handle: {}", handle)))
+        let entry = {
+            let mut store = self._lock_store()?;
+            match store.remove(&handle) {
+                Some(entry) => entry,
+                None => return Err(VcxError::from_msg(VcxErrorKind::InvalidHandle, format!("Object not found for handle: {}", handle)))
+            }
+        };
+
+        let entry = entry.lock()
+            .map_err(|_| VcxError::from_msg(VcxErrorKind::Common(10), "Unable to lock Object Store"))?; //TODO better error
+
+        match (self.persistence, entry.persisted_data.is_some()) {
+            (Some((object_type, _)), true) => match persistence::delete(object_type, &entry.record_id) {
+                Err(ref err) if err.kind() != VcxErrorKind::WalletRecordNotFound => {
+                    warn!("Unable to delete stored state of {:?} object {}: {}", object_type, entry.record_id, err);
+                    Ok(())
+                }
+                _ => Ok(())
+            },
+            _ => Ok(())
         }
     }
 
+    /// Removes all objects from the cache. State stored in the wallet is kept, so objects can be
+    /// restored after restart.
     pub fn drain(&self) -> VcxResult<()> {
         let mut store = self._lock_store()?;
         Ok(store.clear())
@@ -155,4 +290,69 @@ mod tests {
 
         assert_eq!("TEST", string);
     }
+
+    #[test]
+    fn persistence_test() {
+        use error::prelude::*;
+        use object_cache::persistence::{self, ObjectQuery, ObjectRecord, ObjectType};
+        use settings;
+        use utils::devsetup::SetupLibraryWallet;
+
+        fn to_record(obj: &String) -> VcxResult<ObjectRecord> {
+            Ok(ObjectRecord::new("source_id".to_string(), None, 1, obj.clone()))
+        }
+
+        fn stored() -> Vec<persistence::ObjectInfo> {
+            persistence::list(ObjectType::Proof, &ObjectQuery::default()).unwrap()
+        }
+
+        let _setup = SetupLibraryWallet::init();
+
+        let test: ObjectCache<String> = ObjectCache::with_persistence(ObjectType::Proof, to_record);
+        let handle = test.add(String::from("TEST")).unwrap();
+        assert!(stored().is_empty());
+
+        settings::set_config_value(settings::CONFIG_PERSIST_OBJECTS, "true");
+
+        test.get_mut(handle, |obj| {
+            obj.push_str("_UPDATED");
+            Ok(())
+        }).unwrap();
+        let record_id = stored()[0].record_id.clone();
+        assert_eq!("TEST_UPDATED", persistence::load(ObjectType::Proof, &record_id).unwrap());
+
+        // unchanged object is not written again
+        persistence::delete(ObjectType::Proof, &record_id).unwrap();
+        test.get_mut(handle, |_| Ok(())).unwrap();
+        test.get_mut(handle, |_| -> VcxResult<()> { Err(VcxError::from(VcxErrorKind::InvalidState)) }).unwrap_err();
+        assert!(stored().is_empty());
+
+        test.get_mut(handle, |obj| {
+            obj.push_str("_AGAIN");
+            Ok(())
+        }).unwrap();
+        assert_eq!(1, stored().len());
+
+        // objects with the same source id are stored separately
+        let handle_2 = test.add(String::from("TEST2")).unwrap();
+        assert_eq!(2, stored().len());
+
+        // released object is removed from the wallet, drained one is kept
+        test.release(handle_2).unwrap();
+        test.drain().unwrap();
+        assert_eq!(vec![record_id.clone()], stored().into_iter().map(|info| info.record_id).collect::<Vec<String>>());
+
+        // restored object keeps updating its record
+
+        let restored = test.add_restored(&record_id, persistence::load(ObjectType::Proof, &record_id).unwrap()).unwrap();
+        test.get_mut(restored, |obj| {
+            obj.push_str("_RESTORED");
+            Ok(())
+        }).unwrap();
+        assert_eq!(1, stored().len());
+        assert_eq!("TEST_UPDATED_AGAIN_RESTORED", persistence::load(ObjectType::Proof, &record_id).unwrap());
+
+        test.release(restored).unwrap();
+        assert!(stored().is_empty());
+    }
 }
@@ -0,0 +1,227 @@
+use serde_json;
+
+use error::prelude::*;
+use utils::libindy::wallet;
+
+pub const SOURCE_ID_TAG: &str = "source_id";
+pub const THREAD_ID_TAG: &str = "thread_id";
+pub const STATE_TAG: &str = "state";
+
+static RECORD_OPTIONS: &str = r#"{"retrieveType":false,"retrieveValue":true,"retrieveTags":false}"#;
+static SEARCH_OPTIONS: &str = r#"{"retrieveRecords":true,"retrieveTotalCount":false,"retrieveType":false,"retrieveValue":false,"retrieveTags":true}"#;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectType {
+    Connection,
+    IssuerCredential,
+    Credential,
+    Proof,
+    DisclosedProof,
+}
+
+impl ObjectType {
+    pub fn record_type(&self) -> &'static str {
+        match self {
+            ObjectType::Connection => "VcxConnection",
+            ObjectType::IssuerCredential => "VcxIssuerCredential",
+            ObjectType::Credential => "VcxCredential",
+            ObjectType::Proof => "VcxProof",
+            ObjectType::DisclosedProof => "VcxDisclosedProof",
+        }
+    }
+
+    pub fn from_str(object_type: &str) -> VcxResult<ObjectType> {
+        serde_json::from_value(json!(object_type))
+            .map_err(|_| VcxError::from_msg(VcxErrorKind::InvalidOption, format!("Unknown object type: {}", object_type)))
+    }
+}
+
+/// Serialized state of a protocol object as it is stored in the wallet.
+/// The wallet record itself is identified by a generated `record_id` as neither `source_id`
+/// nor `thread_id` are guaranteed to be unique.
+#[derive(Debug)]
+pub struct ObjectRecord {
+    pub source_id: String,
+    pub thread_id: Option<String>,
+    pub state: u32,
+    pub data: String,
+}
+
+impl ObjectRecord {
+    pub fn new(source_id: String, thread_id: Option<String>, state: u32, data: String) -> ObjectRecord {
+        ObjectRecord {
+            source_id,
+            thread_id: thread_id.filter(|thread_id| !thread_id.is_empty()),
+            state,
+            data,
+        }
+    }
+}
+
+/// Metadata of a stored object returned by `list`.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct ObjectInfo {
+    pub record_id: String,
+    pub source_id: String,
+    pub thread_id: Option<String>,
+    pub state: u32,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ObjectQuery {
+    pub source_id: Option<String>,
+    pub state: Option<u32>,
+    pub thread_id: Option<String>,
+}
+
+impl ObjectQuery {
+    pub fn from_str(query: &str) -> VcxResult<ObjectQuery> {
+        serde_json::from_str(query)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize object query: {:?}", err)))
+    }
+
+    fn to_wql(&self) -> String {
+        let mut query = json!({});
+
+        if let Some(ref source_id) = self.source_id {
+            query[SOURCE_ID_TAG] = json!(source_id);
+        }
+
+        if let Some(state) = self.state {
+            query[STATE_TAG] = json!(state.to_string());
+        }
+
+        if let Some(ref thread_id) = self.thread_id {
+            query[THREAD_ID_TAG] = json!(thread_id);
+        }
+
+        query.to_string()
+    }
+}
+
+pub fn store(object_type: ObjectType, record_id: &str, record: &ObjectRecord) -> VcxResult<()> {
+    trace!("persistence::store >>> object_type: {:?}, record_id: {}, source_id: {}, thread_id: {:?}, state: {}",
+           object_type, record_id, record.source_id, record.thread_id, record.state);
+
+    let mut tags = json!({
+        SOURCE_ID_TAG: record.source_id,
+        STATE_TAG: record.state.to_string(),
+    });
+
+    if let Some(ref thread_id) = record.thread_id {
+        tags[THREAD_ID_TAG] = json!(thread_id);
+    }
+
+    let tags = tags.to_string();
+
+    match wallet::update_record_value(object_type.record_type(), record_id, &record.data) {
+        Ok(()) => wallet::update_record_tags(object_type.record_type(), record_id, &tags),
+        Err(ref err) if err.kind() == VcxErrorKind::WalletRecordNotFound =>
+            wallet::add_record(object_type.record_type(), record_id, &record.data, Some(&tags)),
+        Err(err) => Err(err)
+    }
+}
+
+pub fn load(object_type: ObjectType, record_id: &str) -> VcxResult<String> {
+    trace!("persistence::load >>> object_type: {:?}, record_id: {}", object_type, record_id);
+
+    let record = wallet::get_record(object_type.record_type(), record_id, RECORD_OPTIONS)?;
+
+    let record: serde_json::Value = serde_json::from_str(&record)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize wallet record: {:?}", err)))?;
+
+    record["value"].as_str()
+        .map(String::from)
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Stored {:?} object has no value: {}", object_type, record_id)))
+}
+
+pub fn list(object_type: ObjectType, query: &ObjectQuery) -> VcxResult<Vec<ObjectInfo>> {
+    trace!("persistence::list >>> object_type: {:?}, query: {:?}", object_type, query);
+
+    let records = wallet::search_records(object_type.record_type(), &query.to_wql(), SEARCH_OPTIONS)?;
+
+    records
+        .into_iter()
+        .map(|record| {
+            let tags = record.tags.unwrap_or_default();
+
+            let state = tags.get(STATE_TAG)
+                .and_then(|state| state.parse::<u32>().ok())
+                .ok_or(VcxError::from_msg(VcxErrorKind::InvalidState, format!("Stored {:?} object has no state: {}", object_type, record.id)))?;
+
+            let source_id = tags.get(SOURCE_ID_TAG).cloned()
+                .ok_or(VcxError::from_msg(VcxErrorKind::InvalidState, format!("Stored {:?} object has no source id: {}", object_type, record.id)))?;
+
+            Ok(ObjectInfo {
+                record_id: record.id,
+                source_id,
+                thread_id: tags.get(THREAD_ID_TAG).cloned(),
+                state,
+            })
+        })
+        .collect()
+}
+
+pub fn list_json(object_type: &str, query: Option<&str>) -> VcxResult<String> {
+    let object_type = ObjectType::from_str(object_type)?;
+
+    let query = match query {
+        Some(query) => ObjectQuery::from_str(query)?,
+        None => ObjectQuery::default()
+    };
+
+    let objects = list(object_type, &query)?;
+
+    serde_json::to_string(&objects)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("Cannot serialize stored objects: {:?}", err)))
+}
+
+pub fn delete(object_type: ObjectType, record_id: &str) -> VcxResult<()> {
+    trace!("persistence::delete >>> object_type: {:?}, record_id: {}", object_type, record_id);
+
+    wallet::delete_record(object_type.record_type(), record_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use utils::devsetup::{SetupDefaults, SetupLibraryWallet};
+
+    #[test]
+    fn test_object_type_from_str() {
+        let _setup = SetupDefaults::init();
+
+        assert_eq!(ObjectType::IssuerCredential, ObjectType::from_str("issuer_credential").unwrap());
+        assert_eq!(ObjectType::DisclosedProof, ObjectType::from_str("disclosed_proof").unwrap());
+        assert_eq!(VcxErrorKind::InvalidOption, ObjectType::from_str("schema").unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_store_load_list_delete() {
+        let _setup = SetupLibraryWallet::init();
+
+        store(ObjectType::Proof, "record_1", &ObjectRecord::new("proof".to_string(), Some("thread_1".to_string()), 1, "data_1".to_string())).unwrap();
+        store(ObjectType::Proof, "record_2", &ObjectRecord::new("proof".to_string(), None, 1, "data_2".to_string())).unwrap();
+        store(ObjectType::Proof, "record_1", &ObjectRecord::new("proof".to_string(), Some("thread_1".to_string()), 2, "data_1_updated".to_string())).unwrap();
+
+        assert_eq!("data_1_updated", load(ObjectType::Proof, "record_1").unwrap());
+        assert_eq!("data_2", load(ObjectType::Proof, "record_2").unwrap());
+        assert_eq!(VcxErrorKind::WalletRecordNotFound, load(ObjectType::Connection, "record_1").unwrap_err().kind());
+
+        let all = list(ObjectType::Proof, &ObjectQuery::default()).unwrap();
+        assert_eq!(2, all.len());
+
+        let by_source_id = list(ObjectType::Proof, &ObjectQuery { source_id: Some("proof".to_string()), ..ObjectQuery::default() }).unwrap();
+        assert_eq!(2, by_source_id.len());
+
+        let sent = list(ObjectType::Proof, &ObjectQuery { state: Some(2), ..ObjectQuery::default() }).unwrap();
+        assert_eq!(vec![ObjectInfo { record_id: "record_1".to_string(), source_id: "proof".to_string(), thread_id: Some("thread_1".to_string()), state: 2 }], sent);
+
+        let by_thread = list(ObjectType::Proof, &ObjectQuery { thread_id: Some("thread_1".to_string()), ..ObjectQuery::default() }).unwrap();
+        assert_eq!(1, by_thread.len());
+
+        delete(ObjectType::Proof, "record_1").unwrap();
+        assert_eq!(1, list(ObjectType::Proof, &ObjectQuery::default()).unwrap().len());
+    }
+}
@@ -16,8 +16,10 @@ use utils::error;
 use utils::constants::*;
 use utils::libindy::anoncreds;
 use object_cache::ObjectCache;
+use object_cache::persistence::{self, ObjectRecord, ObjectType};
 use error::prelude::*;
-use utils::openssl::encode;
+use utils::libindy::anoncreds::libindy_encode_attribute_value;
+use utils::openssl::encode as legacy_encode;
 use utils::qualifier;
 use messages::proofs::proof_message::get_credential_info;
 
@@ -26,7 +28,7 @@ use utils::agent_info::{get_agent_info, MyAgentInfo, get_agent_attr};
 use settings::get_config_value;
 
 lazy_static! {
-    static ref PROOF_MAP: ObjectCache<Proofs> = Default::default();
+    static ref PROOF_MAP: ObjectCache<Proofs> = ObjectCache::with_persistence(ObjectType::Proof, proof_record);
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -144,9 +146,10 @@ impl Proof {
             let raw = info["raw"].as_str().ok_or(VcxError::from_msg(VcxErrorKind::InvalidProof, format!("Cannot get raw value for \"{}\" attribute", attr1_referent)))?;
             let encoded_ = info["encoded"].as_str().ok_or(VcxError::from_msg(VcxErrorKind::InvalidProof, format!("Cannot get encoded value for \"{}\" attribute", attr1_referent)))?;
 
-            let expected_encoded = encode(&raw)?;
+            let expected_encoded = libindy_encode_attribute_value(&raw)?;
 
-            if expected_encoded != encoded_.to_string() {
+            // credentials issued before libvcx switched to the libindy rule use the legacy encoding
+            if expected_encoded != encoded_ && legacy_encode(&raw)? != encoded_ {
                 return Err(VcxError::from_msg(VcxErrorKind::InvalidProof, format!("Encoded values are different. Expected: {}. From Proof: {}", expected_encoded, encoded_)));
             }
         }
@@ -553,10 +556,28 @@ pub fn release_all() {
 }
 
 pub fn to_string(handle: u32) -> VcxResult<String> {
-    PROOF_MAP.get(handle, |obj| {
-        serde_json::to_string(obj)
-            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize Proof object: {:?}", err)))
-    })
+    PROOF_MAP.get(handle, _to_string)
+}
+
+fn _to_string(obj: &Proofs) -> VcxResult<String> {
+    serde_json::to_string(obj)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidState, format!("cannot serialize Proof object: {:?}", err)))
+}
+
+fn proof_record(obj: &Proofs) -> VcxResult<ObjectRecord> {
+    let (source_id, thread_id, state) = match obj {
+        Proofs::Pending(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        Proofs::V1(ref obj) => (obj.get_source_id(), obj.thread.as_ref().and_then(|thread| thread.thid.clone()), obj.get_state()),
+        Proofs::V3(ref obj) => (obj.get_source_id(), Some(obj.get_thread_id()), obj.state()),
+    };
+
+    Ok(ObjectRecord::new(source_id, thread_id, state, _to_string(obj)?))
+}
+
+/// Rebuilds a handle from the state stored in the wallet by the `persist_objects` mode.
+pub fn restore(record_id: &str) -> VcxResult<u32> {
+    let proof_data = persistence::load(ObjectType::Proof, record_id)?;
+    PROOF_MAP.add_restored(record_id, _from_string(&proof_data)?)
 }
 
 pub fn get_source_id(handle: u32) -> VcxResult<String> {
@@ -569,11 +590,24 @@ pub fn get_source_id(handle: u32) -> VcxResult<String> {
     })
 }
 
+pub fn find_by_thread_id(thread_id: &str) -> VcxResult<Option<u32>> {
+    let handles = PROOF_MAP.filter_map(|handle, obj| {
+        match obj {
+            Proofs::V3(ref obj) if obj.get_thread_id() == thread_id => Some(handle),
+            _ => None
+        }
+    })?;
+
+    Ok(handles.into_iter().next())
+}
+
 pub fn from_string(proof_data: &str) -> VcxResult<u32> {
-    let proof: Proofs = serde_json::from_str(proof_data)
-        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("cannot deserialize Proofs object: {:?}", err)))?;
+    PROOF_MAP.add(_from_string(proof_data)?)
+}
 
-    PROOF_MAP.add(proof)
+fn _from_string(proof_data: &str) -> VcxResult<Proofs> {
+    serde_json::from_str(proof_data)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("cannot deserialize Proofs object: {:?}", err)))
 }
 
 pub fn generate_proof_request_msg(handle: u32) -> VcxResult<String> {
@@ -761,6 +795,25 @@ pub mod tests {
         assert!(BigNum::from_dec_str(&nonce).unwrap().num_bits() < 81)
     }
 
+    #[test]
+    fn test_validate_proof_revealed_attributes_accepts_legacy_encoding() {
+        let _setup = SetupDefaults::init();
+
+        let proof = |raw: &str, encoded: &str| json!({
+            "requested_proof": {"revealed_attrs": {"attr_1": {"sub_proof_index": 0, "raw": raw, "encoded": encoded}}}
+        }).to_string();
+
+        Proof::validate_proof_revealed_attributes(&proof("1234", "1234")).unwrap();
+
+        // value out of i32 range encoded by older libvcx as u32
+        Proof::validate_proof_revealed_attributes(&proof("2147483648", "2147483648")).unwrap();
+        let encoded = libindy_encode_attribute_value("2147483648").unwrap();
+        Proof::validate_proof_revealed_attributes(&proof("2147483648", &encoded)).unwrap();
+
+        let err = Proof::validate_proof_revealed_attributes(&proof("2147483648", "2147483649")).unwrap_err();
+        assert_eq!(VcxErrorKind::InvalidProof, err.kind());
+    }
+
     #[test]
     fn test_to_string_succeeds() {
         let _setup = SetupMocks::init();
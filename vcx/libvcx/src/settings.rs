@@ -53,6 +53,8 @@ pub static CONFIG_DID_METHOD: &str = "did_method";
 pub static COMMUNICATION_METHOD: &str = "communication_method";// proprietary or aries
 pub static CONFIG_ACTORS: &str = "actors"; // inviter, invitee, issuer, holder, prover, verifier, sender, receiver
 pub static MOCK_INDY_PROOF_VALIDATION: &str = "mock_indy_proof_validation";
+pub static CONFIG_SERVICE_ENDPOINT: &str = "service_endpoint"; // own public endpoint for agency-less mode
+pub static CONFIG_PERSIST_OBJECTS: &str = "persist_objects"; // store state of protocol objects in the wallet after every transition
 
 pub static DEFAULT_PROTOCOL_VERSION: usize = 2;
 pub static MAX_SUPPORTED_PROTOCOL_VERSION: usize = 2;
@@ -80,6 +82,7 @@ pub static DEFAULT_PAYMENT_METHOD: &str = "null";
 pub static DEFAULT_PROTOCOL_TYPE: &str = "1.0";
 pub static MAX_THREADPOOL_SIZE: usize = 128;
 pub static MOCK_DEFAULT_INDY_PROOF_VALIDATION: &str = "true";
+pub static DEFAULT_PERSIST_OBJECTS: &str = "false";
 
 lazy_static! {
     static ref SETTINGS: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
@@ -157,6 +160,7 @@ pub fn validate_config(config: &HashMap<String, String>) -> VcxResult<u32> {
     validate_optional_config_val(config.get(CONFIG_INSTITUTION_LOGO_URL), VcxErrorKind::InvalidUrl, Url::parse)?;
 
     validate_optional_config_val(config.get(CONFIG_WEBHOOK_URL), VcxErrorKind::InvalidUrl, Url::parse)?;
+    validate_optional_config_val(config.get(CONFIG_SERVICE_ENDPOINT), VcxErrorKind::InvalidUrl, Url::parse)?;
 
     validate_optional_config_val(config.get(CONFIG_ACTORS), VcxErrorKind::InvalidOption, validation::validate_actors)?;
 
@@ -200,6 +204,18 @@ pub fn indy_mocks_enabled() -> bool {
     }
 }
 
+pub fn is_agency_less() -> bool {
+    get_config_value(CONFIG_SERVICE_ENDPOINT).is_ok()
+}
+
+pub fn persist_objects_enabled() -> bool {
+    let persist = get_config_value(CONFIG_PERSIST_OBJECTS).unwrap_or(DEFAULT_PERSIST_OBJECTS.to_string());
+    match persist.as_ref() {
+        "true" | "TRUE" | "True" => true,
+        "false" | "FALSE" | "False" | _ => false,
+    }
+}
+
 pub fn agency_mocks_enabled() -> bool {
     let config = SETTINGS.read().unwrap();
 
@@ -550,6 +550,28 @@ pub fn libindy_to_unqualified(entity: &str) -> VcxResult<String> {
         .map_err(VcxError::from)
 }
 
+/// Encodes a raw attribute value with the libindy "string" encoding, so libvcx applies
+/// the same rule as `indy_encode_credential_values`: values that parse as i32 are kept
+/// as integers, anything else is encoded as the decimal sha256 of its bytes.
+///
+/// Compatibility: libvcx used to keep values that parse as u32 as integers (`utils::openssl::encode`),
+/// so values in (i32::MAX, u32::MAX] are now encoded as hashes. Credentials issued with the old rule
+/// keep their encoding, proofs for them are accepted by `validate_proof_revealed_attributes`.
+pub fn libindy_encode_attribute_value(raw: &str) -> VcxResult<String> {
+    let values = json!({"value": raw}).to_string();
+
+    let encoded_values = anoncreds::encode_credential_values(&values)
+        .wait()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::EncodeError, format!("Cannot encode string: {}", err)))?;
+
+    let encoded_values: Value = serde_json::from_str(&encoded_values)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::EncodeError, format!("Cannot parse encoded values: {}", err)))?;
+
+    encoded_values["value"]["encoded"].as_str()
+        .map(String::from)
+        .ok_or(VcxError::from_msg(VcxErrorKind::EncodeError, "Cannot get encoded value"))
+}
+
 fn _check_schema_response(response: &str) -> VcxResult<()> {
     // TODO: saved backwardcampatibilyty but actually we can better handle response
     match parse_response(response)? {
@@ -583,6 +605,27 @@ pub mod tests {
     use utils::devsetup::*;
 
 
+    #[test]
+    fn test_encode_attribute_value() {
+        let _setup = SetupDefaults::init();
+
+        // number
+        assert_eq!("1234", libindy_encode_attribute_value("1234").unwrap());
+
+        // number with leading zero
+        assert_eq!("1234", libindy_encode_attribute_value("01234").unwrap());
+
+        // negative number
+        assert_eq!("-1234", libindy_encode_attribute_value("-1234").unwrap());
+
+        // string
+        assert_eq!("32770349619296211525721019403974704547883091481854305319049714074652726739013",
+                   libindy_encode_attribute_value("Cat").unwrap());
+
+        // number out of i32 range is encoded as string
+        assert_ne!("2147483648", libindy_encode_attribute_value("2147483648").unwrap());
+    }
+
     pub fn create_schema(attr_list: &str) -> (String, String) {
         let data = attr_list.to_string();
         let schema_name: String = rand::thread_rng().gen_ascii_chars().take(25).collect::<String>();
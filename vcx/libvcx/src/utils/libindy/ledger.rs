@@ -119,6 +119,12 @@ pub fn libindy_build_get_nym_request(submitter_did: Option<&str>, did: &str) ->
         .map_err(VcxError::from)
 }
 
+pub fn libindy_build_get_attrib_request(submitter_did: Option<&str>, target_did: &str, raw: &str) -> VcxResult<String> {
+    ledger::build_get_attrib_request(submitter_did, target_did, Some(raw), None, None)
+        .wait()
+        .map_err(VcxError::from)
+}
+
 pub mod auth_rule {
     use super::*;
     use std::collections::HashMap;
@@ -347,6 +353,33 @@ pub fn get_nym(did: &str) -> VcxResult<String> {
     libindy_submit_request(&get_nym_req)
 }
 
+pub fn get_attr(did: &str, attr_name: &str) -> VcxResult<String> {
+    let submitter_did = settings::get_config_value(settings::CONFIG_INSTITUTION_DID)?;
+    let get_attr_req = libindy_build_get_attrib_request(Some(&submitter_did), &did, attr_name)?;
+    libindy_submit_request(&get_attr_req)
+}
+
+/// Returns `endpoint` and `routingKeys` published in `endpoint` ATTRIB of the DID.
+pub fn get_service_endpoint(did: &str) -> VcxResult<(String, Vec<String>)> {
+    if settings::indy_mocks_enabled() { return Ok((String::from("http://localhost:8080"), Vec::new())); }
+
+    let get_attr_resp = get_attr(&did, "endpoint")?;
+    let get_attr_resp: serde_json::Value = serde_json::from_str(&get_attr_resp)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidLedgerResponse, format!("{:?}", err)))?;
+    let data: serde_json::Value = serde_json::from_str(&get_attr_resp["result"]["data"].as_str().unwrap_or("{}"))
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidLedgerResponse, format!("{:?}", err)))?;
+
+    let endpoint = data["endpoint"]["endpoint"].as_str()
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidLedgerResponse, format!("Service endpoint is not published for DID: {}", did)))?
+        .to_string();
+
+    let routing_keys = data["endpoint"]["routingKeys"].as_array()
+        .map(|keys| keys.iter().filter_map(|key| key.as_str().map(String::from)).collect())
+        .unwrap_or_default();
+
+    Ok((endpoint, routing_keys))
+}
+
 pub fn get_role(did: &str) -> VcxResult<String> {
     if settings::indy_mocks_enabled() { return Ok(settings::DEFAULT_ROLE.to_string()); }
 
@@ -3,6 +3,7 @@ use indy::did;
 
 use settings;
 use utils::libindy::wallet::get_wallet_handle;
+use utils::libindy::pool::get_pool_handle;
 use error::prelude::*;
 
 pub fn create_and_store_my_did(seed: Option<&str>, method_name: Option<&str>) -> VcxResult<(String, String)> {
@@ -26,3 +27,14 @@ pub fn get_local_verkey(did: &str) -> VcxResult<String> {
         .wait()
         .map_err(VcxError::from)
 }
+
+/// Resolves verkey of the DID from the wallet or from the ledger.
+pub fn get_verkey_from_ledger(did: &str) -> VcxResult<String> {
+    if settings::indy_mocks_enabled() {
+        return Ok(::utils::constants::VERKEY.to_string());
+    }
+
+    did::key_for_did(get_pool_handle()?, get_wallet_handle(), did)
+        .wait()
+        .map_err(VcxError::from)
+}
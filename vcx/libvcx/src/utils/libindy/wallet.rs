@@ -1,10 +1,13 @@
 use futures::Future;
+use std::collections::HashMap;
 use indy::{wallet, ErrorCode};
 
 use settings;
 
 use error::prelude::*;
-use indy::{WalletHandle, INVALID_WALLET_HANDLE};
+use indy::{WalletHandle, SearchHandle, INVALID_WALLET_HANDLE};
+
+const SEARCH_RECORDS_BATCH_SIZE: usize = 100;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WalletRecord {
@@ -27,6 +30,13 @@ impl WalletRecord {
     }
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct SearchRecord {
+    pub id: String,
+    pub value: Option<String>,
+    pub tags: Option<HashMap<String, String>>,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RestoreWalletConfigs {
     pub wallet_name: String,
@@ -214,6 +224,59 @@ pub fn update_record_value(xtype: &str, id: &str, value: &str) -> VcxResult<()>
         .map_err(VcxError::from)
 }
 
+pub fn update_record_tags(xtype: &str, id: &str, tags: &str) -> VcxResult<()> {
+    trace!("update_record_tags >>> xtype: {}, id: {}, tags: {}", secret!(&xtype), secret!(&id), secret!(&tags));
+
+    if settings::indy_mocks_enabled() { return Ok(()); }
+
+    wallet::update_wallet_record_tags(get_wallet_handle(), xtype, id, tags)
+        .wait()
+        .map_err(VcxError::from)
+}
+
+pub fn search_records(xtype: &str, query: &str, options: &str) -> VcxResult<Vec<SearchRecord>> {
+    trace!("search_records >>> xtype: {}, query: {}, options: {}", secret!(&xtype), secret!(&query), options);
+
+    if settings::indy_mocks_enabled() { return Ok(Vec::new()); }
+
+    let search_handle = wallet::open_wallet_search(get_wallet_handle(), xtype, query, options)
+        .wait()
+        .map_err(VcxError::from)?;
+
+    let records = _fetch_all_search_records(search_handle);
+
+    wallet::close_wallet_search(search_handle)
+        .wait()
+        .map_err(VcxError::from)?;
+
+    records
+}
+
+fn _fetch_all_search_records(search_handle: SearchHandle) -> VcxResult<Vec<SearchRecord>> {
+    #[derive(Deserialize)]
+    struct SearchRecords {
+        records: Option<Vec<SearchRecord>>,
+    }
+
+    let mut result = Vec::new();
+
+    loop {
+        let records = wallet::fetch_wallet_search_next_records(get_wallet_handle(), search_handle, SEARCH_RECORDS_BATCH_SIZE)
+            .wait()
+            .map_err(VcxError::from)?;
+
+        let records: SearchRecords = ::serde_json::from_str(&records)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize wallet search records: {:?}", err)))?;
+
+        match records.records {
+            Some(records) if !records.is_empty() => result.extend(records),
+            _ => break
+        }
+    }
+
+    Ok(result)
+}
+
 pub fn export(wallet_handle: WalletHandle, path: &str, backup_key: &str) -> VcxResult<()> {
     trace!("export >>> wallet_handle: {:?}, path: {:?}, backup_key: ****", wallet_handle, path);
 
@@ -40,8 +40,8 @@ pub mod error;
 pub mod httpclient;
 pub mod constants;
 pub mod timeout;
-pub mod openssl;
 pub mod json;
+pub mod openssl;
 pub mod libindy;
 pub mod threadpool;
 pub mod uuid;
@@ -2,6 +2,10 @@ use openssl::sha::sha256;
 use openssl::bn::BigNum;
 use error::prelude::*;
 
+/// Legacy encoding of attribute values used by libvcx before it switched to the libindy rule
+/// (see `libindy_encode_attribute_value`): values that parse as u32 are kept as integers.
+/// Values in (i32::MAX, u32::MAX] are encoded differently by the two rules, so it is still accepted
+/// when checking proofs to keep credentials issued by older versions verifiable.
 pub fn encode(s: &str) -> VcxResult<String> {
     match s.parse::<u32>() {
         Ok(val) => Ok(val.to_string()),
@@ -51,5 +55,14 @@ mod test {
             let encoded_value = encode(value).unwrap();
             assert_eq!(expected_value, encoded_value);
         }
+
+        // number out of i32 range
+        {
+            let value = "2147483648";
+            let expected_value = value;
+
+            let encoded_value = encode(value).unwrap();
+            assert_eq!(expected_value, encoded_value);
+        }
     }
-}
\ No newline at end of file
+}
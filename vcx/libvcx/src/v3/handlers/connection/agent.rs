@@ -7,13 +7,14 @@ use v3::messages::connection::did_doc::DidDoc;
 use v3::messages::a2a::A2AMessage;
 
 use v3::utils::encryption_envelope::EncryptionEnvelope;
+use v3::utils::transport;
 
 use std::collections::HashMap;
 
 use connection::create_agent_keys;
-use utils::httpclient;
-use utils::libindy::signus::create_and_store_my_did;
+use utils::libindy::signus::{create_and_store_my_did, get_local_verkey};
 use settings;
+use mediation;
 use error::prelude::*;
 use settings::ProtocolTypes;
 
@@ -47,17 +48,50 @@ impl AgentInfo {
             Create User Pairwise Agent in old way.
             Send Messages corresponding to V2 Protocol to avoid code changes on Agency side.
         */
+        if settings::is_agency_less() {
+            // does not wait for the mediator to confirm the key, it stays pending until then
+            mediation::add_recipient_key(&pw_vk)?;
+            return Ok(AgentInfo { pw_did, pw_vk, agent_did: String::new(), agent_vk: String::new() });
+        }
+
         let (agent_did, agent_vk) = create_agent_keys("", &pw_did, &pw_vk)?;
 
         Ok(AgentInfo { pw_did, pw_vk, agent_did, agent_vk })
     }
 
+    /// Agent info backed by the public DID of the institution (used for implicit invitations).
+    /// Messages to the public DID cannot be routed through a pairwise cloud agent,
+    /// so it is available in agency-less mode only.
+    pub fn public(did: &str) -> VcxResult<AgentInfo> {
+        trace!("Agent::public >>> did: {:?}", did);
+
+        if !settings::is_agency_less() {
+            return Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Connection through public DID is supported in agency-less mode only"));
+        }
+
+        let pw_vk = get_local_verkey(did)?;
+
+        Ok(AgentInfo { pw_did: did.to_string(), pw_vk, agent_did: String::new(), agent_vk: String::new() })
+    }
+
     pub fn agency_endpoint(&self) -> VcxResult<String> {
+        if settings::is_agency_less() {
+            if let Some((endpoint, _)) = mediation::routing()? {
+                return Ok(endpoint);
+            }
+
+            return settings::get_config_value(settings::CONFIG_SERVICE_ENDPOINT);
+        }
+
         settings::get_config_value(settings::CONFIG_AGENCY_ENDPOINT)
             .map(|str| format!("{}/agency/msg", str))
     }
 
     pub fn routing_keys(&self) -> VcxResult<Vec<String>> {
+        if settings::is_agency_less() {
+            return Ok(mediation::routing()?.map(|(_, routing_keys)| routing_keys).unwrap_or_default());
+        }
+
         let agency_vk = settings::get_config_value(settings::CONFIG_AGENCY_VERKEY)?;
         Ok(vec![self.agent_vk.to_string(), agency_vk])
     }
@@ -69,6 +103,8 @@ impl AgentInfo {
     pub fn update_message_status(&self, uid: String) -> VcxResult<()> {
         trace!("Agent::update_message_status >>> uid: {:?}", uid);
 
+        if settings::is_agency_less() { return Ok(()); }
+
         let messages_to_update = vec![UIDsByConn {
             pairwise_did: self.pw_did.clone(),
             uids: vec![uid],
@@ -80,6 +116,9 @@ impl AgentInfo {
     pub fn get_messages(&self) -> VcxResult<HashMap<String, A2AMessage>> {
         trace!("Agent::get_messages >>>");
 
+        // without agency messages are delivered by application through `vcx_inbound_message_receive`
+        if settings::is_agency_less() { return Ok(HashMap::new()); }
+
         let messages = get_connection_messages(&self.pw_did,
                                                &self.pw_vk,
                                                &self.agent_did,
@@ -101,6 +140,10 @@ impl AgentInfo {
     pub fn get_message_by_id(&self, msg_id: &str) -> VcxResult<A2AMessage> {
         trace!("Agent::get_message_by_id >>> msg_id: {:?}", msg_id);
 
+        if settings::is_agency_less() {
+            return Err(VcxError::from_msg(VcxErrorKind::ActionNotSupported, "Messages cannot be fetched by id without agency"));
+        }
+
         let mut messages = get_connection_messages(&self.pw_did,
                                                    &self.pw_vk,
                                                    &self.agent_did,
@@ -128,19 +171,19 @@ impl AgentInfo {
     pub fn send_message(&self, message: &A2AMessage, did_dod: &DidDoc) -> VcxResult<()> {
         trace!("Agent::send_message >>> message: {:?}, did_doc: {:?}", message, did_dod);
         let envelope = EncryptionEnvelope::create(&message, Some(&self.pw_vk), &did_dod)?;
-        httpclient::post_message(&envelope.0, &did_dod.get_endpoint())?;
-        Ok(())
+        transport::send(envelope.0, &did_dod)
     }
 
     pub fn send_message_anonymously(message: &A2AMessage, did_dod: &DidDoc) -> VcxResult<()> {
         trace!("Agent::send_message_anonymously >>> message: {:?}, did_doc: {:?}", message, did_dod);
         let envelope = EncryptionEnvelope::create(&message, None, &did_dod)?;
-        httpclient::post_message(&envelope.0, &did_dod.get_endpoint())?;
-        Ok(())
+        transport::send(envelope.0, &did_dod)
     }
 
     pub fn delete(&self) -> VcxResult<()> {
         trace!("Agent::delete >>>");
+
+        if settings::is_agency_less() { return mediation::remove_recipient_key(&self.pw_vk); }
         send_delete_connection_message(&self.pw_did, &self.pw_vk, &self.agent_did, &self.agent_vk)
     }
 }
\ No newline at end of file
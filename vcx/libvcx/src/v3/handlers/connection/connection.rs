@@ -1,11 +1,16 @@
 use messages::get_message::Message;
 use error::prelude::*;
+use settings;
+use utils::libindy::ledger::get_service_endpoint;
+use utils::libindy::signus::get_verkey_from_ledger;
 
-use v3::handlers::connection::states::{DidExchangeSM, Actor, ActorDidExchangeState};
+use v3::handlers::connection::states::{DidExchangeSM, Actor, ActorDidExchangeState, HandshakeProtocol};
 use v3::handlers::connection::messages::DidExchangeMessages;
 use v3::handlers::connection::agent::AgentInfo;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
+use v3::messages::a2a::message_family::MessageFamilies;
 use v3::messages::connection::invite::Invitation;
+use v3::messages::out_of_band::invitation::OutofbandInvitation;
 
 use std::collections::HashMap;
 use v3::messages::connection::did_doc::DidDoc;
@@ -43,12 +48,64 @@ impl Connection {
         Ok(connection)
     }
 
+    /// Creates Invitee connection from Out-of-Band invitation.
+    /// DID Exchange is used when the invitation offers it, Connections 1.0 otherwise.
+    pub fn create_with_outofband_invite(source_id: &str, invitation: OutofbandInvitation) -> VcxResult<Connection> {
+        trace!("Connection::create_with_outofband_invite >>> source_id: {}, invitation: {:?}", source_id, invitation);
+
+        let protocol = if invitation.supports(&MessageFamilies::DidExchange) {
+            HandshakeProtocol::DidExchange
+        } else if invitation.supports(&MessageFamilies::Connections) {
+            HandshakeProtocol::Connections
+        } else {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidInviteDetail,
+                                          format!("Out-of-Band invitation does not offer supported handshake protocol: {:?}", invitation.handshake_protocols)));
+        };
+
+        let invite = match invitation.public_did() {
+            Some(did) => Connection::_resolve_public_did(&did)?
+                .set_id(invitation.id.0.clone())
+                .set_label(invitation.label.clone()),
+            None => invitation.inline_invitation()?
+        };
+
+        let mut connection = Connection::create_with_invite(source_id, invite)?;
+        connection.connection_sm.set_handshake(protocol, None)?;
+
+        Ok(connection)
+    }
+
+    /// Creates Invitee connection to the public DID of the Inviter (DID Exchange implicit invitation).
+    pub fn create_with_public_did(source_id: &str, did: &str) -> VcxResult<Connection> {
+        trace!("Connection::create_with_public_did >>> source_id: {}, did: {}", source_id, did);
+
+        let mut connection = Connection::create_with_invite(source_id, Connection::_resolve_public_did(did)?)?;
+        connection.connection_sm.set_handshake(HandshakeProtocol::DidExchange, None)?;
+
+        Ok(connection)
+    }
+
+    fn _resolve_public_did(did: &str) -> VcxResult<Invitation> {
+        let verkey = get_verkey_from_ledger(did)?;
+        let (service_endpoint, routing_keys) = get_service_endpoint(did)?;
+
+        Ok(Invitation::create()
+            .set_id(format!("{}{}", OutofbandInvitation::DID_PREFIX, did))
+            .set_service_endpoint(service_endpoint)
+            .set_recipient_keys(vec![verkey])
+            .set_routing_keys(routing_keys))
+    }
+
     pub fn source_id(&self) -> String { self.connection_sm.source_id().to_string() }
 
     pub fn state(&self) -> u32 { self.connection_sm.state() }
 
     pub fn agent_info(&self) -> &AgentInfo { self.connection_sm.agent_info() }
 
+    pub fn prev_agent_info(&self) -> Option<&AgentInfo> { self.connection_sm.prev_agent_info() }
+
+    pub fn remote_did_doc(&self) -> Option<DidDoc> { self.connection_sm.did_doc() }
+
     pub fn remote_did(&self) -> VcxResult<String> {
         self.connection_sm.remote_did()
     }
@@ -73,6 +130,9 @@ impl Connection {
     pub fn get_invite_details(&self) -> VcxResult<String> {
         trace!("Connection::get_invite_details >>>");
         if let Some(invitation) = self.connection_sm.get_invitation() {
+            if self.connection_sm.protocol() == HandshakeProtocol::DidExchange {
+                return Ok(json!(self.outofband_invitation(invitation)?.to_a2a_message()).to_string());
+            }
             return Ok(json!(invitation.to_a2a_message()).to_string());
         } else if let Some(did_doc) = self.connection_sm.did_doc() {
             let info = json!(Invitation::from(did_doc));
@@ -82,10 +142,44 @@ impl Connection {
         }
     }
 
+    fn outofband_invitation(&self, invitation: &Invitation) -> VcxResult<OutofbandInvitation> {
+        let outofband_invitation = OutofbandInvitation::create()
+            .set_id(invitation.id.0.clone())
+            .set_label(invitation.label.clone())
+            .set_handshake_protocol(MessageFamilies::DidExchange);
+
+        match self.connection_sm.public_did() {
+            Some(did) => Ok(outofband_invitation.set_public_did(did)),
+            None => outofband_invitation.set_service(invitation)
+        }
+    }
+
     pub fn actor(&self) -> Actor {
         self.connection_sm.actor()
     }
 
+    pub fn is_public_invitation(&self) -> bool {
+        self.connection_sm.is_public_invitation()
+    }
+
+    pub fn protocol(&self) -> HandshakeProtocol {
+        self.connection_sm.protocol()
+    }
+
+    /// Chooses the handshake protocol to connect with.
+    /// Inviter using DID Exchange can refer its public DID in the invitation instead of the pairwise keys.
+    pub fn set_handshake_protocol(&mut self, protocol: HandshakeProtocol, use_public_did: bool) -> VcxResult<()> {
+        trace!("Connection::set_handshake_protocol >>> protocol: {:?}, use_public_did: {}", protocol, use_public_did);
+
+        let public_did = match (self.actor(), protocol) {
+            (Actor::Inviter, HandshakeProtocol::DidExchange) if use_public_did =>
+                Some(settings::get_config_value(settings::CONFIG_INSTITUTION_DID)?),
+            _ => None
+        };
+
+        self.connection_sm.set_handshake(protocol, public_did)
+    }
+
     pub fn connect(&mut self) -> VcxResult<()> {
         trace!("Connection::connect >>> source_id: {}", self.connection_sm.source_id());
         self.step(DidExchangeMessages::Connect())
@@ -217,6 +311,19 @@ impl Connection {
         self.handle_message(DidExchangeMessages::DiscoverFeatures((query, comment)))
     }
 
+    /// Version of the protocol family to start with the remote side:
+    /// 2.0 if the remote side has disclosed it through the discovery features protocol, 1.0 otherwise.
+    pub fn protocol_version(&self, family: MessageFamilies) -> ProtocolVersion {
+        let family = family.with_version("2.0");
+        let pid = format!("{}/{}", family.to_string(), family.version());
+
+        let supported = self.connection_sm.get_remote_protocols()
+            .map(|protocols| protocols.iter().any(|protocol| protocol.pid.trim_end_matches('/').ends_with(&pid)))
+            .unwrap_or(false);
+
+        if supported { ProtocolVersion::V2 } else { ProtocolVersion::V1 }
+    }
+
     pub fn get_connection_info(&self) -> VcxResult<String> {
         trace!("Connection::get_connection_info >>>");
 
@@ -270,8 +377,32 @@ struct SideConnectionInfo {
 
 #[cfg(test)]
 mod tests {
+    use utils::devsetup::SetupAriesMocks;
     use v3::messages::a2a::A2AMessage;
+    use v3::messages::out_of_band::invitation::tests::_outofband_invitation;
     use v3::handlers::connection::connection::Connection;
+    use v3::handlers::connection::states::HandshakeProtocol;
+    use v3::test::source_id;
+
+    #[test]
+    fn test_create_with_outofband_invite_works() {
+        let _setup = SetupAriesMocks::init();
+
+        let connection = Connection::create_with_outofband_invite(&source_id(), _outofband_invitation()).unwrap();
+        assert_eq!(HandshakeProtocol::DidExchange, connection.protocol());
+
+        let invite_details: A2AMessage = ::serde_json::from_str(&connection.get_invite_details().unwrap()).unwrap();
+        assert_eq!(A2AMessage::OutofbandInvitation(_outofband_invitation()), invite_details);
+    }
+
+    #[test]
+    fn test_create_with_public_did_works() {
+        let _setup = SetupAriesMocks::init();
+
+        let connection = Connection::create_with_public_did(&source_id(), "V4SGRU86Z58d6TV7PBUe6f").unwrap();
+        assert_eq!(HandshakeProtocol::DidExchange, connection.protocol());
+        assert_eq!("did:sov:V4SGRU86Z58d6TV7PBUe6f", connection.remote_did().unwrap());
+    }
 
     #[test]
     fn test_parse_generic_message_plain_string_should_be_parsed_as_basic_msg() -> Result<(), String> {
@@ -7,6 +7,9 @@ use v3::messages::trust_ping::ping_response::PingResponse;
 use v3::messages::ack::Ack;
 use v3::messages::discovery::query::Query;
 use v3::messages::discovery::disclose::Disclose;
+use v3::messages::did_exchange::request::ExchangeRequest;
+use v3::messages::did_exchange::response::ExchangeResponse;
+use v3::messages::did_exchange::complete::Complete;
 use v3::messages::a2a::A2AMessage;
 
 
@@ -16,6 +19,9 @@ pub enum DidExchangeMessages {
     InvitationReceived(Invitation),
     ExchangeRequestReceived(Request),
     ExchangeResponseReceived(SignedResponse),
+    DidExchangeRequestReceived(ExchangeRequest),
+    DidExchangeResponseReceived(ExchangeResponse),
+    CompleteReceived(Complete),
     AckReceived(Ack),
     ProblemReportReceived(ProblemReport),
     SendPing(Option<String>),
@@ -39,6 +45,15 @@ impl From<A2AMessage> for DidExchangeMessages {
             A2AMessage::ConnectionResponse(request) => {
                 DidExchangeMessages::ExchangeResponseReceived(request)
             }
+            A2AMessage::DidExchangeRequest(request) => {
+                DidExchangeMessages::DidExchangeRequestReceived(request)
+            }
+            A2AMessage::DidExchangeResponse(response) => {
+                DidExchangeMessages::DidExchangeResponseReceived(response)
+            }
+            A2AMessage::DidExchangeComplete(complete) => {
+                DidExchangeMessages::CompleteReceived(complete)
+            }
             A2AMessage::Ping(ping) => {
                 DidExchangeMessages::PingReceived(ping)
             }
@@ -54,7 +69,8 @@ impl From<A2AMessage> for DidExchangeMessages {
             A2AMessage::Disclose(disclose) => {
                 DidExchangeMessages::DiscloseReceived(disclose)
             }
-            A2AMessage::ConnectionProblemReport(report) => {
+            A2AMessage::ConnectionProblemReport(report) |
+            A2AMessage::DidExchangeProblemReport(report) => {
                 DidExchangeMessages::ProblemReportReceived(report)
             }
             _ => {
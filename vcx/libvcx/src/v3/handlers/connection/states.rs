@@ -14,6 +14,10 @@ use v3::messages::connection::did_doc::DidDoc;
 use v3::messages::discovery::query::Query;
 use v3::messages::discovery::disclose::{Disclose, ProtocolDescriptor};
 use v3::messages::a2a::protocol_registry::ProtocolRegistry;
+use v3::messages::did_exchange::request::ExchangeRequest;
+use v3::messages::did_exchange::response::ExchangeResponse;
+use v3::messages::did_exchange::complete::Complete;
+use v3::messages::out_of_band::invitation::OutofbandInvitation;
 
 use std::collections::HashMap;
 
@@ -26,6 +30,31 @@ pub struct DidExchangeSM {
     state: ActorDidExchangeState,
 }
 
+/// Protocol used to establish the connection.
+/// Connections stored before DID Exchange support are treated as Connections 1.0.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum HandshakeProtocol {
+    #[serde(rename = "connections")]
+    Connections,
+    #[serde(rename = "didexchange")]
+    DidExchange,
+}
+
+impl Default for HandshakeProtocol {
+    fn default() -> HandshakeProtocol {
+        HandshakeProtocol::Connections
+    }
+}
+
+impl HandshakeProtocol {
+    fn problem_report_message(&self, problem_report: &ProblemReport) -> A2AMessage {
+        match self {
+            HandshakeProtocol::Connections => problem_report.to_a2a_message(),
+            HandshakeProtocol::DidExchange => A2AMessage::DidExchangeProblemReport(problem_report.clone()),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ActorDidExchangeState {
     Inviter(DidExchangeState),
@@ -64,18 +93,29 @@ impl DidExchangeState {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct NullState {}
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NullState {
+    #[serde(default)]
+    protocol: HandshakeProtocol,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    public_did: Option<String>,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InvitedState {
-    invitation: Invitation
+    invitation: Invitation,
+    #[serde(default)]
+    protocol: HandshakeProtocol,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    public_did: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestedState {
     request: Request,
     did_doc: DidDoc,
+    #[serde(default)]
+    protocol: HandshakeProtocol,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -83,95 +123,142 @@ pub struct RespondedState {
     response: SignedResponse,
     did_doc: DidDoc,
     prev_agent_info: AgentInfo,
+    #[serde(default)]
+    protocol: HandshakeProtocol,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompleteState {
     did_doc: DidDoc,
     protocols: Option<Vec<ProtocolDescriptor>>,
+    #[serde(default)]
+    protocol: HandshakeProtocol,
 }
 
 impl From<(NullState, Invitation)> for InvitedState {
-    fn from((_state, invitation): (NullState, Invitation)) -> InvitedState {
+    fn from((state, invitation): (NullState, Invitation)) -> InvitedState {
         trace!("DidExchangeStateSM: transit state from NullState to InvitedState");
-        InvitedState { invitation }
+        InvitedState { invitation, protocol: state.protocol, public_did: state.public_did }
     }
 }
 
 impl From<(InvitedState, ProblemReport)> for NullState {
     fn from((_state, _error): (InvitedState, ProblemReport)) -> NullState {
         trace!("DidExchangeStateSM: transit state from InvitedState to NullState");
-        NullState {}
+        NullState::default()
     }
 }
 
 impl From<(InvitedState, Request)> for RequestedState {
     fn from((state, request): (InvitedState, Request)) -> RequestedState {
         trace!("DidExchangeStateSM: transit state from InvitedState to RequestedState");
-        RequestedState { request, did_doc: DidDoc::from(state.invitation) }
+        RequestedState { request, did_doc: DidDoc::from(state.invitation), protocol: state.protocol }
     }
 }
 
-impl From<(InvitedState, Request, SignedResponse, AgentInfo)> for RespondedState {
-    fn from((_state, request, response, prev_agent_info): (InvitedState, Request, SignedResponse, AgentInfo)) -> RespondedState {
+impl From<(InvitedState, Request, SignedResponse, AgentInfo, HandshakeProtocol)> for RespondedState {
+    fn from((_state, request, response, prev_agent_info, protocol): (InvitedState, Request, SignedResponse, AgentInfo, HandshakeProtocol)) -> RespondedState {
         trace!("DidExchangeStateSM: transit state from InvitedState to RequestedState");
-        RespondedState { response, did_doc: request.connection.did_doc, prev_agent_info }
+        RespondedState { response, did_doc: request.connection.did_doc, prev_agent_info, protocol }
     }
 }
 
 impl From<(RequestedState, ProblemReport)> for NullState {
     fn from((_state, _error): (RequestedState, ProblemReport)) -> NullState {
         trace!("DidExchangeStateSM: transit state from RequestedState to NullState");
-        NullState {}
+        NullState::default()
     }
 }
 
 impl From<(RequestedState, Response)> for CompleteState {
-    fn from((_state, response): (RequestedState, Response)) -> CompleteState {
+    fn from((state, response): (RequestedState, Response)) -> CompleteState {
         trace!("DidExchangeStateSM: transit state from RequestedState to RespondedState");
-        CompleteState { did_doc: response.connection.did_doc, protocols: None }
+        CompleteState { did_doc: response.connection.did_doc, protocols: None, protocol: state.protocol }
     }
 }
 
 impl From<(RespondedState, ProblemReport)> for NullState {
     fn from((_state, _error): (RespondedState, ProblemReport)) -> NullState {
         trace!("DidExchangeStateSM: transit state from RespondedState to NullState");
-        NullState {}
+        NullState::default()
     }
 }
 
 impl From<(RespondedState, Ack)> for CompleteState {
     fn from((state, _ack): (RespondedState, Ack)) -> CompleteState {
         trace!("DidExchangeStateSM: transit state from RespondedState to CompleteState");
-        CompleteState { did_doc: state.did_doc, protocols: None }
+        CompleteState { did_doc: state.did_doc, protocols: None, protocol: state.protocol }
+    }
+}
+
+impl From<(RespondedState, Complete)> for CompleteState {
+    fn from((state, _complete): (RespondedState, Complete)) -> CompleteState {
+        trace!("DidExchangeStateSM: transit state from RespondedState to CompleteState");
+        CompleteState { did_doc: state.did_doc, protocols: None, protocol: state.protocol }
     }
 }
 
 impl From<(RespondedState, Ping)> for CompleteState {
     fn from((state, _ping): (RespondedState, Ping)) -> CompleteState {
         trace!("DidExchangeStateSM: transit state from RespondedState to CompleteState");
-        CompleteState { did_doc: state.did_doc, protocols: None }
+        CompleteState { did_doc: state.did_doc, protocols: None, protocol: state.protocol }
     }
 }
 
 impl From<(RespondedState, PingResponse)> for CompleteState {
     fn from((state, _ping_response): (RespondedState, PingResponse)) -> CompleteState {
         trace!("DidExchangeStateSM: transit state from RespondedState to CompleteState");
-        CompleteState { did_doc: state.did_doc, protocols: None }
+        CompleteState { did_doc: state.did_doc, protocols: None, protocol: state.protocol }
     }
 }
 
 impl From<(CompleteState, Vec<ProtocolDescriptor>)> for CompleteState {
     fn from((state, protocols): (CompleteState, Vec<ProtocolDescriptor>)) -> CompleteState {
         trace!("DidExchangeStateSM: transit state from CompleteState to CompleteState");
-        CompleteState { did_doc: state.did_doc, protocols: Some(protocols) }
+        CompleteState { did_doc: state.did_doc, protocols: Some(protocols), protocol: state.protocol }
     }
 }
 
 impl InvitedState {
+    /// Answers the request with the Response of the protocol the request was sent with.
+    fn receive_request(self, request: Request, agent_info: AgentInfo, protocol: HandshakeProtocol) -> (DidExchangeState, AgentInfo) {
+        match self.handle_connection_request(&request, &agent_info, protocol) {
+            Ok((response, new_agent_info)) => {
+                (DidExchangeState::Responded((self, request, response, agent_info, protocol).into()), new_agent_info)
+            }
+            Err(err) => {
+                let problem_report = ProblemReport::create()
+                    .set_problem_code(ProblemCode::RequestProcessingError)
+                    .set_explain(err.to_string())
+                    .set_thread_id(&request.id.0);
+
+                agent_info.send_message(&protocol.problem_report_message(&problem_report), &request.connection.did_doc).ok(); // IS is possible?
+                (DidExchangeState::Null((self, problem_report).into()), agent_info)
+            }
+        }
+    }
+
+    fn handle_exchange_request(&self, request: ExchangeRequest) -> VcxResult<Request> {
+        trace!("InvitedState:handle_exchange_request >>> request: {:?}", request);
+
+        let pthid = request.parent_thread_id()
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "Cannot handle DID Exchange Request: parent thread id is not set"))?
+            .to_string();
+
+        let public_invitation_id = self.public_did.as_ref()
+            .map(|did| format!("{}{}", OutofbandInvitation::DID_PREFIX, did));
+
+        if pthid != self.invitation.id.0 && Some(&pthid) != public_invitation_id.as_ref() {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot handle DID Exchange Request: parent thread id does not match: {:?}", pthid)));
+        }
+
+        request.decode()
+    }
+
     fn handle_connection_request(&self, request: &Request,
-                                 agent_info: &AgentInfo) -> VcxResult<(SignedResponse, AgentInfo)> {
-        trace!("InvitedState:handle_connection_request >>> request: {:?}, agent_info: {:?}", request, agent_info);
+                                 agent_info: &AgentInfo,
+                                 protocol: HandshakeProtocol) -> VcxResult<(SignedResponse, AgentInfo)> {
+        trace!("InvitedState:handle_connection_request >>> request: {:?}, agent_info: {:?}, protocol: {:?}", request, agent_info, protocol);
 
         request.connection.did_doc.validate()?;
 
@@ -184,26 +271,33 @@ impl InvitedState {
             .set_did(new_agent_info.pw_did.to_string())
             .set_service_endpoint(new_agent_info.agency_endpoint()?)
             .set_keys(new_agent_info.recipient_keys(), new_agent_info.routing_keys()?)
-            .ask_for_ack();
+            .ask_for_ack()
+            .set_thread_id(&request.id.0);
 
         let signed_response = response.clone()
-            .set_thread_id(&request.id.0)
             .encode(&prev_agent_info.pw_vk)?;
 
-        new_agent_info.send_message(&signed_response.to_a2a_message(), &request.connection.did_doc)?;
+        let message = match protocol {
+            HandshakeProtocol::Connections => signed_response.to_a2a_message(),
+            HandshakeProtocol::DidExchange => ExchangeResponse::from_response(&response, &prev_agent_info.pw_vk)?.to_a2a_message(),
+        };
+
+        new_agent_info.send_message(&message, &request.connection.did_doc)?;
 
         Ok((signed_response, new_agent_info))
     }
 }
 
 impl RequestedState {
+    fn remote_vk(&self) -> VcxResult<String> {
+        self.did_doc.recipient_keys().get(0).cloned()
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidState, "Cannot handle Response: Remote Verkey not found"))
+    }
+
     fn handle_connection_response(&self, response: SignedResponse, agent_info: &AgentInfo) -> VcxResult<Response> {
         trace!("RequestedState:handle_connection_response >>> response: {:?}, agent_info: {:?}", response, agent_info);
 
-        let remote_vk: String = self.did_doc.recipient_keys().get(0).cloned()
-            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidState, "Cannot handle Response: Remote Verkey not found"))?;
-
-        let response: Response = response.decode(&remote_vk)?;
+        let response: Response = response.decode(&self.remote_vk()?)?;
 
         if !response.from_thread(&self.request.id.0) {
             return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot handle Response: thread id does not match: {:?}", response.thread)));
@@ -223,6 +317,34 @@ impl RequestedState {
 
         Ok(response)
     }
+
+    fn handle_exchange_response(&self, response: ExchangeResponse, agent_info: &AgentInfo) -> VcxResult<Response> {
+        trace!("RequestedState:handle_exchange_response >>> response: {:?}, agent_info: {:?}", response, agent_info);
+
+        let response: Response = response.decode(&self.remote_vk()?)?;
+
+        if !response.from_thread(&self.request.id.0) {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot handle Response: thread id does not match: {:?}", response.thread)));
+        }
+
+        let complete = Complete::create()
+            .set_thread_id(&self.request.id.0)
+            .set_parent_thread_id(&self.did_doc.id);
+
+        agent_info.send_message(&complete.to_a2a_message(), &response.connection.did_doc)?;
+
+        Ok(response)
+    }
+
+    fn send_problem_report(&self, err: VcxError, agent_info: &AgentInfo) -> ProblemReport {
+        let problem_report = ProblemReport::create()
+            .set_problem_code(ProblemCode::ResponseProcessingError)
+            .set_explain(err.to_string())
+            .set_thread_id(&self.request.id.0);
+
+        agent_info.send_message(&self.protocol.problem_report_message(&problem_report), &self.did_doc).ok();
+        problem_report
+    }
 }
 
 impl RespondedState {
@@ -311,14 +433,14 @@ impl DidExchangeSM {
             Actor::Inviter => {
                 DidExchangeSM {
                     source_id: source_id.to_string(),
-                    state: ActorDidExchangeState::Inviter(DidExchangeState::Null(NullState {})),
+                    state: ActorDidExchangeState::Inviter(DidExchangeState::Null(NullState::default())),
                     agent_info: AgentInfo::default(),
                 }
             }
             Actor::Invitee => {
                 DidExchangeSM {
                     source_id: source_id.to_string(),
-                    state: ActorDidExchangeState::Invitee(DidExchangeState::Null(NullState {})),
+                    state: ActorDidExchangeState::Invitee(DidExchangeState::Null(NullState::default())),
                     agent_info: AgentInfo::default(),
                 }
             }
@@ -337,6 +459,59 @@ impl DidExchangeSM {
         &self.agent_info
     }
 
+    /// Chooses the handshake protocol before the connection is started.
+    /// Public DID can be used by Inviter with DID Exchange protocol only.
+    pub fn set_handshake(&mut self, protocol: HandshakeProtocol, public_did: Option<String>) -> VcxResult<()> {
+        trace!("DidExchangeSM::set_handshake >>> protocol: {:?}, public_did: {:?}", protocol, public_did);
+
+        if public_did.is_some() && protocol != HandshakeProtocol::DidExchange {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidOption, "Public DID can be used with DID Exchange protocol only"));
+        }
+
+        match self.state {
+            ActorDidExchangeState::Inviter(DidExchangeState::Null(ref mut state)) => {
+                state.protocol = protocol;
+                state.public_did = public_did;
+            }
+            ActorDidExchangeState::Invitee(DidExchangeState::Invited(ref mut state)) if public_did.is_none() => {
+                state.protocol = protocol;
+            }
+            _ => return Err(VcxError::from_msg(VcxErrorKind::NotReady, "Handshake protocol cannot be changed in the current Connection state"))
+        }
+
+        Ok(())
+    }
+
+    pub fn protocol(&self) -> HandshakeProtocol {
+        match self.state {
+            ActorDidExchangeState::Inviter(ref state) | ActorDidExchangeState::Invitee(ref state) =>
+                match state {
+                    DidExchangeState::Null(ref state) => state.protocol,
+                    DidExchangeState::Invited(ref state) => state.protocol,
+                    DidExchangeState::Requested(ref state) => state.protocol,
+                    DidExchangeState::Responded(ref state) => state.protocol,
+                    DidExchangeState::Completed(ref state) => state.protocol,
+                }
+        }
+    }
+
+    pub fn public_did(&self) -> Option<&str> {
+        match self.state {
+            ActorDidExchangeState::Inviter(DidExchangeState::Null(ref state)) => state.public_did.as_ref().map(String::as_str),
+            ActorDidExchangeState::Inviter(DidExchangeState::Invited(ref state)) => state.public_did.as_ref().map(String::as_str),
+            _ => None
+        }
+    }
+
+    /// Inviter waiting for requests to the implicit invitation of its public DID.
+    /// Such invitation can be answered by any number of invitees.
+    pub fn is_public_invitation(&self) -> bool {
+        match self.state {
+            ActorDidExchangeState::Inviter(DidExchangeState::Invited(ref state)) => state.public_did.is_some(),
+            _ => false
+        }
+    }
+
     pub fn source_id(&self) -> &str {
         &self.source_id
     }
@@ -362,7 +537,12 @@ impl DidExchangeSM {
                             debug!("Inviter received ConnectionRequest message");
                             return Some((uid, request));
                         }
-                        problem_report @ A2AMessage::ConnectionProblemReport(_) => {
+                        request @ A2AMessage::DidExchangeRequest(_) => {
+                            debug!("Inviter received DidExchangeRequest message");
+                            return Some((uid, request));
+                        }
+                        problem_report @ A2AMessage::ConnectionProblemReport(_) |
+                        problem_report @ A2AMessage::DidExchangeProblemReport(_) => {
                             debug!("Inviter received ProblemReport message");
                             return Some((uid, problem_report));
                         }
@@ -377,7 +557,12 @@ impl DidExchangeSM {
                             debug!("Invitee received ConnectionResponse message");
                             return Some((uid, response));
                         }
-                        problem_report @ A2AMessage::ConnectionProblemReport(_) => {
+                        response @ A2AMessage::DidExchangeResponse(_) => {
+                            debug!("Invitee received DidExchangeResponse message");
+                            return Some((uid, response));
+                        }
+                        problem_report @ A2AMessage::ConnectionProblemReport(_) |
+                        problem_report @ A2AMessage::DidExchangeProblemReport(_) => {
                             debug!("Invitee received ProblemReport message");
                             return Some((uid, problem_report));
                         }
@@ -392,6 +577,10 @@ impl DidExchangeSM {
                             debug!("Ack message received");
                             return Some((uid, ack));
                         }
+                        complete @ A2AMessage::DidExchangeComplete(_) => {
+                            debug!("Complete message received");
+                            return Some((uid, complete));
+                        }
                         ping @ A2AMessage::Ping(_) => {
                             debug!("Ping message received");
                             return Some((uid, ping));
@@ -400,7 +589,8 @@ impl DidExchangeSM {
                             debug!("PingResponse message received");
                             return Some((uid, ping));
                         }
-                        problem_report @ A2AMessage::ConnectionProblemReport(_) => {
+                        problem_report @ A2AMessage::ConnectionProblemReport(_) |
+                        problem_report @ A2AMessage::DidExchangeProblemReport(_) => {
                             debug!("ProblemReport message received");
                             return Some((uid, problem_report));
                         }
@@ -453,7 +643,10 @@ impl DidExchangeSM {
                     DidExchangeState::Null(state) => {
                         match message {
                             DidExchangeMessages::Connect() => {
-                                agent_info = agent_info.create_agent()?;
+                                agent_info = match state.public_did {
+                                    Some(ref did) => AgentInfo::public(did)?,
+                                    None => agent_info.create_agent()?
+                                };
 
                                 let invite: Invitation = Invitation::create()
                                     .set_label(source_id.to_string())
@@ -471,20 +664,20 @@ impl DidExchangeSM {
                     DidExchangeState::Invited(state) => {
                         match message {
                             DidExchangeMessages::ExchangeRequestReceived(request) => {
-                                match state.handle_connection_request(&request, &agent_info) {
-                                    Ok((response, new_agent_info)) => {
-                                        let prev_agent_info = agent_info.clone();
+                                let (state, new_agent_info) = state.receive_request(request, agent_info, HandshakeProtocol::Connections);
+                                agent_info = new_agent_info;
+                                ActorDidExchangeState::Inviter(state)
+                            }
+                            DidExchangeMessages::DidExchangeRequestReceived(request) => {
+                                match state.handle_exchange_request(request) {
+                                    Ok(request) => {
+                                        let (state, new_agent_info) = state.receive_request(request, agent_info, HandshakeProtocol::DidExchange);
                                         agent_info = new_agent_info;
-                                        ActorDidExchangeState::Inviter(DidExchangeState::Responded((state, request, response, prev_agent_info).into()))
+                                        ActorDidExchangeState::Inviter(state)
                                     }
                                     Err(err) => {
-                                        let problem_report = ProblemReport::create()
-                                            .set_problem_code(ProblemCode::RequestProcessingError)
-                                            .set_explain(err.to_string())
-                                            .set_thread_id(&request.id.0);
-
-                                        agent_info.send_message(&problem_report.to_a2a_message(), &request.connection.did_doc).ok(); // IS is possible?
-                                        ActorDidExchangeState::Inviter(DidExchangeState::Null((state, problem_report).into()))
+                                        warn!("DID Exchange Request is ignored: {}", err);
+                                        ActorDidExchangeState::Inviter(DidExchangeState::Invited(state))
                                     }
                                 }
                             }
@@ -504,6 +697,9 @@ impl DidExchangeSM {
                             DidExchangeMessages::AckReceived(ack) => {
                                 ActorDidExchangeState::Inviter(DidExchangeState::Completed((state, ack).into()))
                             }
+                            DidExchangeMessages::CompleteReceived(complete) => {
+                                ActorDidExchangeState::Inviter(DidExchangeState::Completed((state, complete).into()))
+                            }
                             DidExchangeMessages::PingReceived(ping) => {
                                 state.handle_ping(&ping, &agent_info)?;
                                 ActorDidExchangeState::Inviter(DidExchangeState::Completed((state, ping).into()))
@@ -556,7 +752,12 @@ impl DidExchangeSM {
                                     .set_service_endpoint(agent_info.agency_endpoint()?)
                                     .set_keys(agent_info.recipient_keys(), agent_info.routing_keys()?);
 
-                                agent_info.send_message(&request.to_a2a_message(), &DidDoc::from(state.invitation.clone()))?;
+                                let message = match state.protocol {
+                                    HandshakeProtocol::Connections => request.to_a2a_message(),
+                                    HandshakeProtocol::DidExchange => ExchangeRequest::from_request(&request, &state.invitation.id.0, &agent_info.pw_vk)?.to_a2a_message(),
+                                };
+
+                                agent_info.send_message(&message, &DidDoc::from(state.invitation.clone()))?;
                                 ActorDidExchangeState::Invitee(DidExchangeState::Requested((state, request).into()))
                             }
                             DidExchangeMessages::ProblemReportReceived(problem_report) => {
@@ -575,11 +776,18 @@ impl DidExchangeSM {
                                         ActorDidExchangeState::Invitee(DidExchangeState::Completed((state, response).into()))
                                     }
                                     Err(err) => {
-                                        let problem_report = ProblemReport::create()
-                                            .set_problem_code(ProblemCode::ResponseProcessingError)
-                                            .set_explain(err.to_string())
-                                            .set_thread_id(&state.request.id.0);
-                                        agent_info.send_message(&problem_report.to_a2a_message(), &state.did_doc).ok();
+                                        let problem_report = state.send_problem_report(err, &agent_info);
+                                        ActorDidExchangeState::Invitee(DidExchangeState::Null((state, problem_report).into()))
+                                    }
+                                }
+                            }
+                            DidExchangeMessages::DidExchangeResponseReceived(response) => {
+                                match state.handle_exchange_response(response, &agent_info) {
+                                    Ok(response) => {
+                                        ActorDidExchangeState::Invitee(DidExchangeState::Completed((state, response).into()))
+                                    }
+                                    Err(err) => {
+                                        let problem_report = state.send_problem_report(err, &agent_info);
                                         ActorDidExchangeState::Invitee(DidExchangeState::Null((state, problem_report).into()))
                                     }
                                 }
@@ -694,6 +902,7 @@ pub mod test {
     use v3::messages::ack::tests::_ack;
     use v3::messages::discovery::query::tests::_query;
     use v3::messages::discovery::disclose::tests::_disclose;
+    use v3::messages::did_exchange::complete::tests::_complete;
 
     pub mod inviter {
         use super::*;
@@ -795,6 +1004,56 @@ pub mod test {
                 assert_match!(ActorDidExchangeState::Inviter(DidExchangeState::Null(_)), did_exchange_sm.state);
             }
 
+            #[test]
+            fn test_did_exchange_handle_did_exchange_request_message_from_invited_state() {
+                let _setup = AgencyModeSetup::init();
+
+                let mut did_exchange_sm = inviter_sm();
+                did_exchange_sm.set_handshake(HandshakeProtocol::DidExchange, None).unwrap();
+                did_exchange_sm = did_exchange_sm.to_inviter_invited_state();
+
+                let invitation_id = did_exchange_sm.get_invitation().unwrap().id.0.clone();
+                let request = ExchangeRequest::from_request(&_request(), &invitation_id, &_request().connection.did_doc.recipient_keys()[0]).unwrap();
+
+                did_exchange_sm = did_exchange_sm.step(DidExchangeMessages::DidExchangeRequestReceived(request)).unwrap();
+                assert_match!(ActorDidExchangeState::Inviter(DidExchangeState::Responded(_)), did_exchange_sm.state);
+                assert_eq!(HandshakeProtocol::DidExchange, did_exchange_sm.protocol());
+
+                did_exchange_sm = did_exchange_sm.step(DidExchangeMessages::CompleteReceived(_complete())).unwrap();
+                assert_match!(ActorDidExchangeState::Inviter(DidExchangeState::Completed(_)), did_exchange_sm.state);
+                assert_eq!(HandshakeProtocol::DidExchange, did_exchange_sm.protocol());
+            }
+
+            #[test]
+            fn test_did_exchange_handle_did_exchange_request_for_other_invitation_from_invited_state() {
+                let _setup = AgencyModeSetup::init();
+
+                let mut did_exchange_sm = inviter_sm();
+                did_exchange_sm.set_handshake(HandshakeProtocol::DidExchange, None).unwrap();
+                did_exchange_sm = did_exchange_sm.to_inviter_invited_state();
+
+                let request = ExchangeRequest::from_request(&_request(), "other_invitation_id", &_request().connection.did_doc.recipient_keys()[0]).unwrap();
+
+                did_exchange_sm = did_exchange_sm.step(DidExchangeMessages::DidExchangeRequestReceived(request)).unwrap();
+                assert_match!(ActorDidExchangeState::Inviter(DidExchangeState::Invited(_)), did_exchange_sm.state);
+            }
+
+            #[test]
+            fn test_did_exchange_set_handshake() {
+                let _setup = SetupAriesMocks::init();
+
+                let mut did_exchange_sm = inviter_sm();
+                assert_eq!(HandshakeProtocol::Connections, did_exchange_sm.protocol());
+
+                assert!(did_exchange_sm.set_handshake(HandshakeProtocol::Connections, Some(String::from("V4SGRU86Z58d6TV7PBUe6f"))).is_err());
+
+                did_exchange_sm.set_handshake(HandshakeProtocol::DidExchange, Some(String::from("V4SGRU86Z58d6TV7PBUe6f"))).unwrap();
+                assert_eq!(HandshakeProtocol::DidExchange, did_exchange_sm.protocol());
+                assert_eq!(Some("V4SGRU86Z58d6TV7PBUe6f"), did_exchange_sm.public_did());
+                // not invited yet
+                assert!(!did_exchange_sm.is_public_invitation());
+            }
+
             #[test]
             fn test_did_exchange_handle_problem_report_message_from_invited_state() {
                 let _setup = AgencyModeSetup::init();
@@ -1150,6 +1409,23 @@ pub mod test {
                 .encode(&key).unwrap()
         }
 
+        fn _exchange_response(key: &str) -> ExchangeResponse {
+            let response = Response::default()
+                .set_service_endpoint(_service_endpoint())
+                .set_keys(vec![key.to_string()], vec![])
+                .set_thread_id(&_request().id.0);
+
+            ExchangeResponse::from_response(&response, key).unwrap()
+        }
+
+        fn _did_exchange_requested_sm(key: &str) -> DidExchangeSM {
+            let invitation = Invitation::default().set_recipient_keys(vec![key.to_string()]);
+
+            let mut did_exchange_sm = invitee_sm().step(DidExchangeMessages::InvitationReceived(invitation)).unwrap();
+            did_exchange_sm.set_handshake(HandshakeProtocol::DidExchange, None).unwrap();
+            did_exchange_sm.step(DidExchangeMessages::Connect()).unwrap()
+        }
+
         mod new {
             use super::*;
 
@@ -1262,6 +1538,33 @@ pub mod test {
                 assert_match!(ActorDidExchangeState::Invitee(DidExchangeState::Null(_)), did_exchange_sm.state);
             }
 
+            #[test]
+            fn test_did_exchange_handle_did_exchange_response_message_from_requested_state() {
+                let _setup = AgencyModeSetup::init();
+
+                let key = "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL";
+
+                let mut did_exchange_sm = _did_exchange_requested_sm(key);
+                assert_match!(ActorDidExchangeState::Invitee(DidExchangeState::Requested(_)), did_exchange_sm.state);
+
+                did_exchange_sm = did_exchange_sm.step(DidExchangeMessages::DidExchangeResponseReceived(_exchange_response(key))).unwrap();
+
+                assert_match!(ActorDidExchangeState::Invitee(DidExchangeState::Completed(_)), did_exchange_sm.state);
+                assert_eq!(HandshakeProtocol::DidExchange, did_exchange_sm.protocol());
+            }
+
+            #[test]
+            fn test_did_exchange_handle_did_exchange_response_signed_by_other_key_from_requested_state() {
+                let _setup = AgencyModeSetup::init();
+
+                let mut did_exchange_sm = _did_exchange_requested_sm("GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL");
+
+                let response = _exchange_response(::utils::constants::VERKEY);
+                did_exchange_sm = did_exchange_sm.step(DidExchangeMessages::DidExchangeResponseReceived(response)).unwrap();
+
+                assert_match!(ActorDidExchangeState::Invitee(DidExchangeState::Null(_)), did_exchange_sm.state);
+            }
+
             #[test]
             fn test_did_exchange_handle_problem_report_message_from_requested_state() {
                 let _setup = AgencyModeSetup::init();
@@ -1474,5 +1777,34 @@ pub mod test {
             }
         }
     }
-}
 
+    pub mod legacy {
+        use super::*;
+
+        // serialized before DID Exchange support: states have neither `protocol` nor `public_did`
+        const INVITER_INVITED_SM: &str = r#"{"source_id":"test source id","agent_info":{"pw_did":"VsKV7grR1BUE29mG2Fm2kX","pw_vk":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","agent_did":"","agent_vk":""},"state":{"Inviter":{"Invited":{"invitation":{"@id":"testid","label":"test","recipientKeys":["GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL"],"routingKeys":[],"serviceEndpoint":"https://service-endpoint.com"}}}}}"#;
+        const INVITEE_COMPLETED_SM: &str = r#"{"source_id":"test source id","agent_info":{"pw_did":"VsKV7grR1BUE29mG2Fm2kX","pw_vk":"GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL","agent_did":"","agent_vk":""},"state":{"Invitee":{"Completed":{"did_doc":{"@context":"https://w3id.org/did/v1","id":"testid","publicKey":[],"authentication":[],"service":[{"id":"did:example:123456789abcdefghi;indy","type":"IndyAgent","priority":0,"recipientKeys":["GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL"],"routingKeys":[],"serviceEndpoint":"https://service-endpoint.com"}]},"protocols":null}}}}"#;
+
+        #[test]
+        fn test_deserialize_inviter_invited_state_serialized_before_did_exchange() {
+            let sm: DidExchangeSM = ::serde_json::from_str(INVITER_INVITED_SM).unwrap();
+
+            assert_eq!(Actor::Inviter, sm.actor());
+            assert_eq!(VcxStateType::VcxStateOfferSent as u32, sm.state());
+            assert_eq!(HandshakeProtocol::Connections, sm.protocol());
+            assert_eq!(None, sm.public_did());
+            assert!(!sm.is_public_invitation());
+            assert_eq!("https://service-endpoint.com", sm.get_invitation().unwrap().service_endpoint);
+        }
+
+        #[test]
+        fn test_deserialize_invitee_completed_state_serialized_before_did_exchange() {
+            let sm: DidExchangeSM = ::serde_json::from_str(INVITEE_COMPLETED_SM).unwrap();
+
+            assert_eq!(Actor::Invitee, sm.actor());
+            assert_eq!(VcxStateType::VcxStateAccepted as u32, sm.state());
+            assert_eq!(HandshakeProtocol::Connections, sm.protocol());
+            assert_eq!("GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL", sm.remote_vk().unwrap());
+        }
+    }
+}
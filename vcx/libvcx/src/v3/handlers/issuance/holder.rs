@@ -6,8 +6,10 @@ use v3::messages::issuance::credential::Credential;
 use v3::messages::issuance::credential_offer::CredentialOffer;
 use v3::messages::issuance::credential_request::CredentialRequest;
 use v3::messages::issuance::credential_ack::CredentialAck;
+use v3::messages::ack::Ack;
 use v3::messages::error::ProblemReport;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::revocation_notification::revoke::RevocationNotification;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
 use v3::messages::status::Status;
 use connection;
 
@@ -21,15 +23,18 @@ use credential;
 pub struct HolderSM {
     state: HolderState,
     source_id: String,
-    thread_id: String
+    thread_id: String,
+    #[serde(default)]
+    version: ProtocolVersion,
 }
 
 impl HolderSM {
-    pub fn new(offer: CredentialOffer, source_id: String) -> Self {
+    pub fn new(offer: CredentialOffer, source_id: String, version: ProtocolVersion) -> Self {
         HolderSM {
             thread_id: offer.id.0.clone(),
             state: HolderState::OfferReceived(OfferReceivedState::new(offer)),
             source_id,
+            version,
         }
     }
 
@@ -37,12 +42,17 @@ impl HolderSM {
         self.source_id.clone()
     }
 
+    pub fn thread_id(&self) -> String {
+        self.thread_id.clone()
+    }
+
     pub fn state(&self) -> u32 {
         match self.state {
             HolderState::OfferReceived(_) => VcxStateType::VcxStateRequestReceived as u32,
             HolderState::RequestSent(_) => VcxStateType::VcxStateOfferSent as u32,
             HolderState::Finished(ref status) => {
                 match status.status {
+                    Status::Success if status.revocation_notification.is_some() => VcxStateType::VcxStateRevoked as u32,
                     Status::Success => VcxStateType::VcxStateAccepted as u32,
                     _ => VcxStateType::VcxStateNone as u32,
                 }
@@ -53,7 +63,7 @@ impl HolderSM {
     pub fn update_state(self) -> VcxResult<Self> {
         trace!("Holder::update_state >>> ");
 
-        if self.is_terminal_state() { return Ok(self); }
+        if self.is_terminal_state() && !self.awaits_revocation_notification() { return Ok(self); }
 
         let conn_handle = self.state.get_connection_handle();
         let messages = connection::get_messages(conn_handle)?;
@@ -73,6 +83,14 @@ impl HolderSM {
         trace!("Holder::find_message_to_handle >>> messages: {:?}", messages);
 
         for (uid, message) in messages {
+            let message = match message.into_v1() {
+                Ok(message) => message,
+                Err(err) => {
+                    warn!("Holder::find_message_to_handle: cannot convert message {}: {}", uid, err);
+                    continue;
+                }
+            };
+
             match self.state {
                 HolderState::OfferReceived(_) => {
                     // do not process messages
@@ -93,7 +111,14 @@ impl HolderSM {
                     }
                 }
                 HolderState::Finished(_) => {
-                    // do not process messages
+                    match message {
+                        A2AMessage::RevocationNotification(notification) => {
+                            if notification.thread_id == self.thread_id {
+                                return Some((uid, A2AMessage::RevocationNotification(notification)));
+                            }
+                        }
+                        _ => {}
+                    }
                 }
             };
         }
@@ -105,14 +130,14 @@ impl HolderSM {
         self.state.get_connection_handle()
     }
 
-    pub fn step(state: HolderState, source_id: String, thread_id: String) -> Self {
-        HolderSM { state, source_id, thread_id }
+    pub fn step(state: HolderState, source_id: String, thread_id: String, version: ProtocolVersion) -> Self {
+        HolderSM { state, source_id, thread_id, version }
     }
 
     pub fn handle_message(self, cim: CredentialIssuanceMessage) -> VcxResult<HolderSM> {
         trace!("Holder::handle_message >>> cim: {:?}", cim);
 
-        let HolderSM { state, source_id, thread_id } = self;
+        let HolderSM { state, source_id, thread_id, version } = self;
         let state = match state {
             HolderState::OfferReceived(state_data) => match cim {
                 CredentialIssuanceMessage::CredentialRequestSend(connection_handle) => {
@@ -121,7 +146,7 @@ impl HolderSM {
                         Ok((cred_request, req_meta, cred_def_json)) => {
                             let cred_request = cred_request
                                 .set_thread_id(&thread_id);
-                            connection::send_message(connection_handle, cred_request.to_a2a_message())?;
+                            connection::send_message(connection_handle, cred_request.to_a2a_message().into_version(version)?)?;
                             HolderState::RequestSent((state_data, req_meta, cred_def_json, connection_handle).into())
                         }
                         Err(err) => {
@@ -145,7 +170,7 @@ impl HolderSM {
                         Ok((cred_id, rev_reg_def_json)) => {
                             if credential.please_ack.is_some() {
                                 let ack = CredentialAck::create().set_thread_id(&thread_id);
-                                connection::send_message(state_data.connection_handle, A2AMessage::CredentialAck(ack))?;
+                                connection::send_message(state_data.connection_handle, A2AMessage::CredentialAck(ack).into_version(version)?)?;
                             }
 
                             HolderState::Finished((state_data, cred_id, credential, rev_reg_def_json).into())
@@ -168,12 +193,22 @@ impl HolderSM {
                     HolderState::RequestSent(state_data)
                 }
             },
-            HolderState::Finished(state_data) => {
-                warn!("Exchange is finished, no messages can be sent or received");
-                HolderState::Finished(state_data)
+            HolderState::Finished(state_data) => match cim {
+                CredentialIssuanceMessage::RevocationNotification(notification) => {
+                    if notification.please_ack.is_some() && connection::is_valid_handle(state_data.connection_handle) {
+                        let ack = Ack::create().set_thread_id(&notification.id.0);
+                        connection::send_message(state_data.connection_handle, A2AMessage::Ack(ack))?;
+                    }
+
+                    HolderState::Finished((state_data, notification).into())
+                }
+                _ => {
+                    warn!("Exchange is finished, no messages can be sent or received");
+                    HolderState::Finished(state_data)
+                }
             }
         };
-        Ok(HolderSM::step(state, source_id, thread_id))
+        Ok(HolderSM::step(state, source_id, thread_id, version))
     }
 
     pub fn credential_status(&self) -> u32 {
@@ -190,6 +225,29 @@ impl HolderSM {
         }
     }
 
+    /// Issued revocable credentials keep listening for a Revocation Notification from the Issuer.
+    fn awaits_revocation_notification(&self) -> bool {
+        match self.state {
+            HolderState::Finished(ref state) => {
+                state.status == Status::Success &&
+                    state.rev_reg_def_json.is_some() &&
+                    state.revocation_notification.is_none() &&
+                    connection::is_valid_handle(state.connection_handle)
+            }
+            _ => false
+        }
+    }
+
+    pub fn get_revocation_notification(&self) -> VcxResult<RevocationNotification> {
+        match self.state {
+            HolderState::Finished(ref state) => {
+                state.revocation_notification.clone()
+                    .ok_or(VcxError::from_msg(VcxErrorKind::NotReady, "Revocation Notification has not been received"))
+            }
+            _ => Err(VcxError::from_msg(VcxErrorKind::NotReady, "Cannot get Revocation Notification: Credential Issuance is not finished yet"))
+        }
+    }
+
     pub fn get_credential(&self) -> VcxResult<(String, Credential)> {
         match self.state {
             HolderState::Finished(ref state) => {
@@ -287,9 +345,10 @@ mod test {
     use v3::messages::issuance::credential_request::tests::_credential_request;
     use v3::messages::issuance::credential_proposal::tests::_credential_proposal;
     use v3::messages::issuance::test::{_ack, _problem_report};
+    use v3::messages::revocation_notification::revoke::tests::_revocation_notification;
 
     fn _holder_sm() -> HolderSM {
-        HolderSM::new(_credential_offer(), source_id())
+        HolderSM::new(_credential_offer(), source_id(), ProtocolVersion::V1)
     }
 
     impl HolderSM {
@@ -346,7 +405,7 @@ mod test {
 
             let credential_offer = CredentialOffer::create().set_offers_attach(r#"{"credential offer": {}}"#).unwrap();
 
-            let mut holder_sm = HolderSM::new(credential_offer, "test source".to_string());
+            let mut holder_sm = HolderSM::new(credential_offer, "test source".to_string(), ProtocolVersion::V1);
             holder_sm = holder_sm.handle_message(CredentialIssuanceMessage::CredentialRequestSend(mock_connection())).unwrap();
 
             assert_match!(HolderState::Finished(_), holder_sm.state);
@@ -433,6 +492,20 @@ mod test {
             holder_sm = holder_sm.handle_message(CredentialIssuanceMessage::CredentialAck(_ack())).unwrap();
             assert_match!(HolderState::Finished(_), holder_sm.state);
         }
+
+        #[test]
+        fn test_holder_handle_revocation_notification_from_finished_state() {
+            let _setup = SetupAriesMocks::init();
+
+            let mut holder_sm = _holder_sm().to_finished_state();
+            assert!(holder_sm.get_revocation_notification().is_err());
+
+            holder_sm = holder_sm.handle_message(CredentialIssuanceMessage::RevocationNotification(_revocation_notification())).unwrap();
+
+            assert_match!(HolderState::Finished(_), holder_sm.state);
+            assert_eq!(Status::Success.code(), holder_sm.credential_status());
+            assert_eq!(_revocation_notification(), holder_sm.get_revocation_notification().unwrap());
+        }
     }
 
     mod find_message_to_handle {
@@ -540,6 +613,27 @@ mod test {
 
                 assert!(holder.find_message_to_handle(messages).is_none());
             }
+
+            // Revocation Notification
+            {
+                let messages = map!(
+                    "key_1".to_string() => A2AMessage::CredentialAck(_ack()),
+                    "key_2".to_string() => A2AMessage::RevocationNotification(_revocation_notification())
+                );
+
+                let (uid, message) = holder.find_message_to_handle(messages).unwrap();
+                assert_eq!("key_2", uid);
+                assert_match!(A2AMessage::RevocationNotification(_), message);
+            }
+
+            // No messages for different Thread ID
+            {
+                let messages = map!(
+                    "key_1".to_string() => A2AMessage::RevocationNotification(_revocation_notification().set_thread_id(""))
+                );
+
+                assert!(holder.find_message_to_handle(messages).is_none());
+            }
         }
     }
 
@@ -553,6 +647,9 @@ mod test {
             assert_eq!(VcxStateType::VcxStateRequestReceived as u32, _holder_sm().state());
             assert_eq!(VcxStateType::VcxStateOfferSent as u32, _holder_sm().to_request_sent_state().state());
             assert_eq!(VcxStateType::VcxStateAccepted as u32, _holder_sm().to_finished_state().state());
+            assert_eq!(VcxStateType::VcxStateRevoked as u32, _holder_sm().to_finished_state()
+                .handle_message(CredentialIssuanceMessage::RevocationNotification(_revocation_notification())).unwrap()
+                .state());
         }
     }
 }
@@ -1,11 +1,13 @@
 use api::VcxStateType;
 use v3::handlers::issuance::messages::CredentialIssuanceMessage;
 use v3::handlers::issuance::states::{IssuerState, InitialState };
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
+use v3::messages::a2a::message_family::MessageFamilies;
 use v3::messages::issuance::credential_offer::CredentialOffer;
 use v3::messages::issuance::credential_request::CredentialRequest;
 use v3::messages::issuance::credential::Credential;
 use v3::messages::error::ProblemReport;
+use v3::messages::revocation_notification::revoke::RevocationNotification;
 use v3::messages::mime_type::MimeType;
 use error::{VcxResult, VcxError, VcxErrorKind};
 use utils::libindy::anoncreds::{self, libindy_issuer_create_credential_offer, revoke_credential};
@@ -18,14 +20,17 @@ use connection;
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct IssuerSM {
     state: IssuerState,
-    source_id: String
+    source_id: String,
+    #[serde(default)]
+    version: ProtocolVersion,
 }
 
 impl IssuerSM {
     pub fn new(cred_def_id: &str, credential_data: &str, rev_reg_id: Option<String>, tails_file: Option<String>, source_id: &str) -> Self {
         IssuerSM {
             state: IssuerState::Initial(InitialState::new(cred_def_id, credential_data, rev_reg_id, tails_file)),
-            source_id: source_id.to_string()
+            source_id: source_id.to_string(),
+            version: ProtocolVersion::V1,
         }
     }
 
@@ -33,10 +38,15 @@ impl IssuerSM {
         self.source_id.clone()
     }
 
-    pub fn step(state: IssuerState, source_id: String) -> Self {
+    pub fn thread_id(&self) -> String {
+        self.state.thread_id()
+    }
+
+    pub fn step(state: IssuerState, source_id: String, version: ProtocolVersion) -> Self {
         IssuerSM {
             state,
-            source_id
+            source_id,
+            version,
         }
     }
 
@@ -60,6 +70,25 @@ impl IssuerSM {
         }
     }
 
+    pub fn send_revocation_notification(&self, comment: Option<String>) -> VcxResult<()> {
+        trace!("Issuer::send_revocation_notification >>> comment: {:?}", comment);
+
+        match &self.state {
+            IssuerState::Finished(state) if state.status == Status::Success => {
+                if !connection::is_valid_handle(state.connection_handle) {
+                    return Err(VcxError::from_msg(VcxErrorKind::InvalidConnectionHandle, "Cannot send Revocation Notification: Connection is not available"));
+                }
+
+                let notification = RevocationNotification::create()
+                    .set_thread_id(&state.thread_id)
+                    .set_comment(comment);
+
+                send_message(state.connection_handle, notification.to_a2a_message())
+            },
+            _ => Err(VcxError::from_msg(VcxErrorKind::NotReady, "Cannot send Revocation Notification: Credential has not been issued"))
+        }
+    }
+
     pub fn get_connection_handle(&self) -> u32 {
         self.state.get_connection_handle()
     }
@@ -86,6 +115,14 @@ impl IssuerSM {
         trace!("Issuer::find_message_to_handle >>> messages: {:?}", messages);
 
         for (uid, message) in messages {
+            let message = match message.into_v1() {
+                Ok(message) => message,
+                Err(err) => {
+                    warn!("Issuer::find_message_to_handle: cannot convert message {}: {}", uid, err);
+                    continue;
+                }
+            };
+
             match self.state {
                 IssuerState::Initial(_) => {
                     // do not process messages
@@ -157,15 +194,16 @@ impl IssuerSM {
     pub fn handle_message(self, cim: CredentialIssuanceMessage) -> VcxResult<IssuerSM> {
         trace!("IssuerSM::handle_message >>> cim: {:?}", cim);
 
-        let IssuerSM { state, source_id } = self;
+        let IssuerSM { state, source_id, mut version } = self;
         let state = match state {
             IssuerState::Initial(state_data) => match cim {
                 CredentialIssuanceMessage::CredentialInit(connection_handle) => {
+                    version = connection::get_protocol_version(connection_handle, MessageFamilies::CredentialIssuance)?;
                     let cred_offer = libindy_issuer_create_credential_offer(&state_data.cred_def_id)?;
                     let cred_offer_msg = CredentialOffer::create()
                         .set_offers_attach(&cred_offer)?;
                     let cred_offer_msg = _append_credential_preview(cred_offer_msg, &state_data.credential_json)?;
-                    send_message(connection_handle, cred_offer_msg.to_a2a_message())?;
+                    send_message(connection_handle, cred_offer_msg.to_a2a_message().into_version(version)?)?;
                     IssuerState::OfferSent((state_data, cred_offer, connection_handle, cred_offer_msg.id).into())
                 }
                 _ => {
@@ -199,7 +237,7 @@ impl IssuerSM {
                     match credential_msg {
                         Ok((credential_msg, cred_rev_id)) => {
                             let credential_msg = credential_msg.set_thread_id(&state_data.thread_id);
-                            send_message(state_data.connection_handle, credential_msg.to_a2a_message())?;
+                            send_message(state_data.connection_handle, credential_msg.to_a2a_message().into_version(version)?)?;
                             IssuerState::Finished((state_data, cred_rev_id).into())
                         }
                         Err(err) => {
@@ -237,7 +275,7 @@ impl IssuerSM {
             }
         };
 
-        Ok(IssuerSM::step(state, source_id))
+        Ok(IssuerSM::step(state, source_id, version))
     }
 
     pub fn credential_status(&self) -> u32 {
@@ -631,6 +669,27 @@ pub mod test {
         }
     }
 
+    mod send_revocation_notification {
+        use super::*;
+
+        #[test]
+        fn test_issuer_send_revocation_notification_from_finished_state() {
+            let _setup = SetupAriesMocks::init();
+
+            let issuer_sm = _issuer_sm().to_finished_state();
+            issuer_sm.send_revocation_notification(Some(String::from("Credential has been revoked"))).unwrap();
+        }
+
+        #[test]
+        fn test_issuer_send_revocation_notification_from_other_states() {
+            let _setup = SetupAriesMocks::init();
+
+            assert_eq!(VcxErrorKind::NotReady, _issuer_sm().send_revocation_notification(None).unwrap_err().kind());
+            assert_eq!(VcxErrorKind::NotReady, _issuer_sm().to_offer_sent_state().send_revocation_notification(None).unwrap_err().kind());
+            assert_eq!(VcxErrorKind::NotReady, _issuer_sm().to_request_received_state().send_revocation_notification(None).unwrap_err().kind());
+        }
+    }
+
     mod get_state {
         use super::*;
 
@@ -4,6 +4,7 @@ use v3::messages::issuance::credential_offer::CredentialOffer;
 use v3::messages::issuance::credential_request::CredentialRequest;
 use v3::messages::issuance::credential::Credential;
 use v3::messages::issuance::credential_ack::CredentialAck;
+use v3::messages::revocation_notification::revoke::RevocationNotification;
 use v3::messages::a2a::A2AMessage;
 
 
@@ -18,11 +19,20 @@ pub enum CredentialIssuanceMessage {
     Credential(Credential),
     CredentialAck(CredentialAck),
     ProblemReport(ProblemReport),
+    RevocationNotification(RevocationNotification),
     Unknown
 }
 
 impl From<A2AMessage> for CredentialIssuanceMessage {
     fn from(msg: A2AMessage) -> Self {
+        let msg = match msg.into_v1() {
+            Ok(msg) => msg,
+            Err(err) => {
+                warn!("Cannot convert Credential Issuance message: {}", err);
+                return CredentialIssuanceMessage::Unknown;
+            }
+        };
+
         match msg {
             A2AMessage::CredentialProposal(proposal) => {
                 CredentialIssuanceMessage::CredentialProposal(proposal)
@@ -42,6 +52,9 @@ impl From<A2AMessage> for CredentialIssuanceMessage {
             A2AMessage::CommonProblemReport(report) => {
                 CredentialIssuanceMessage::ProblemReport(report)
             },
+            A2AMessage::RevocationNotification(notification) => {
+                CredentialIssuanceMessage::RevocationNotification(notification)
+            },
             _ => {
                 CredentialIssuanceMessage::Unknown
             }
@@ -4,13 +4,16 @@ pub mod messages;
 pub mod holder;
 
 use error::prelude::*;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
 use v3::handlers::issuance::issuer::IssuerSM;
 use v3::handlers::issuance::messages::CredentialIssuanceMessage;
 use v3::handlers::issuance::holder::HolderSM;
 use v3::messages::issuance::credential::Credential;
 use v3::messages::issuance::credential_offer::CredentialOffer;
+use v3::messages::issuance::v2::credential_offer::CredentialOfferV2;
+use v3::messages::revocation_notification::revoke::RevocationNotification;
 use connection;
+use std::convert::TryInto;
 
 // Issuer
 
@@ -46,10 +49,18 @@ impl Issuer {
         Ok(self.issuer_sm.get_source_id())
     }
 
+    pub fn get_thread_id(&self) -> String {
+        self.issuer_sm.thread_id()
+    }
+
     pub fn revoke_credential(&self) -> VcxResult<()> {
         self.issuer_sm.revoke()
     }
 
+    pub fn send_revocation_notification(&self, comment: Option<String>) -> VcxResult<()> {
+        self.issuer_sm.send_revocation_notification(comment)
+    }
+
     pub fn update_status(&mut self, msg: Option<String>) -> VcxResult<()> {
         match msg {
             Some(msg) => {
@@ -86,7 +97,15 @@ impl Holder {
     pub fn create(credential_offer: CredentialOffer, source_id: &str) -> VcxResult<Holder> {
         trace!("Holder::holder_create_credential >>> credential_offer: {:?}, source_id: {:?}", credential_offer, source_id);
 
-        let holder_sm = HolderSM::new(credential_offer, source_id.to_string());
+        let holder_sm = HolderSM::new(credential_offer, source_id.to_string(), ProtocolVersion::V1);
+
+        Ok(Holder { holder_sm })
+    }
+
+    pub fn create_v2(credential_offer: CredentialOfferV2, source_id: &str) -> VcxResult<Holder> {
+        trace!("Holder::holder_create_credential_v2 >>> credential_offer: {:?}, source_id: {:?}", credential_offer, source_id);
+
+        let holder_sm = HolderSM::new(credential_offer.try_into()?, source_id.to_string(), ProtocolVersion::V2);
 
         Ok(Holder { holder_sm })
     }
@@ -118,6 +137,10 @@ impl Holder {
         self.holder_sm.get_source_id()
     }
 
+    pub fn get_thread_id(&self) -> String {
+        self.holder_sm.thread_id()
+    }
+
     pub fn get_credential(&self) -> VcxResult<(String, Credential)> {
         self.holder_sm.get_credential()
     }
@@ -130,32 +153,38 @@ impl Holder {
         Ok(self.holder_sm.credential_status())
     }
 
+    pub fn get_revocation_notification(&self) -> VcxResult<RevocationNotification> {
+        self.holder_sm.get_revocation_notification()
+    }
+
     pub fn step(&mut self, message: CredentialIssuanceMessage) -> VcxResult<()> {
         self.holder_sm = self.holder_sm.clone().handle_message(message)?;
         Ok(())
     }
 
-    pub fn get_credential_offer_message(connection_handle: u32, msg_id: &str) -> VcxResult<CredentialOffer> {
+    /// Returns `CredentialOffer` or `CredentialOfferV2` message.
+    pub fn get_credential_offer_message(connection_handle: u32, msg_id: &str) -> VcxResult<A2AMessage> {
         let message = connection::get_message_by_id(connection_handle, msg_id.to_string())?;
 
-        let credential_offer: CredentialOffer = match message {
-            A2AMessage::CredentialOffer(credential_offer) => credential_offer,
+        match message {
+            credential_offer @ A2AMessage::CredentialOffer(_) |
+            credential_offer @ A2AMessage::CredentialOfferV2(_) => Ok(credential_offer),
             msg => {
                 return Err(VcxError::from_msg(VcxErrorKind::InvalidMessages,
                                               format!("Message of different type was received: {:?}", msg)));
             }
-        };
-
-        Ok(credential_offer)
+        }
     }
 
-    pub fn get_credential_offer_messages(conn_handle: u32) -> VcxResult<Vec<CredentialOffer>> {
+    /// Returns `CredentialOffer` and `CredentialOfferV2` messages.
+    pub fn get_credential_offer_messages(conn_handle: u32) -> VcxResult<Vec<A2AMessage>> {
         let messages = connection::get_messages(conn_handle)?;
-        let msgs: Vec<CredentialOffer> = messages
+        let msgs: Vec<A2AMessage> = messages
             .into_iter()
             .filter_map(|(_, a2a_message)| {
                 match a2a_message {
-                    A2AMessage::CredentialOffer(credential_offer) => {
+                    credential_offer @ A2AMessage::CredentialOffer(_) |
+                    credential_offer @ A2AMessage::CredentialOfferV2(_) => {
                         Some(credential_offer)
                     }
                     _ => None
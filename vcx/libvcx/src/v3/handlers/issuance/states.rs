@@ -4,6 +4,7 @@ use v3::messages::issuance::credential_offer::CredentialOffer;
 use v3::messages::issuance::credential::Credential;
 use v3::messages::status::Status;
 use v3::messages::error::ProblemReport;
+use v3::messages::revocation_notification::revoke::RevocationNotification;
 
 // Possible Transitions:
 // Initial -> OfferSent
@@ -27,7 +28,7 @@ impl IssuerState {
             IssuerState::OfferSent(state) => state.connection_handle,
             IssuerState::RequestReceived(state) => state.connection_handle,
             IssuerState::CredentialSent(state) => state.connection_handle,
-            IssuerState::Finished(_) => 0
+            IssuerState::Finished(state) => state.connection_handle
         }
     }
 
@@ -101,7 +102,9 @@ pub struct FinishedState {
     pub cred_id: Option<String>,
     pub thread_id: String,
     pub revocation_info_v1: Option<RevocationInfoV1>,
-    pub status: Status
+    pub status: Status,
+    #[serde(default)]
+    pub connection_handle: u32,
 }
 
 impl From<(InitialState, String, u32, MessageId)> for OfferSentState {
@@ -126,6 +129,7 @@ impl From<InitialState> for FinishedState {
             thread_id: String::new(),
             revocation_info_v1: None,
             status: Status::Undefined,
+            connection_handle: 0,
         }
     }
 }
@@ -172,6 +176,7 @@ impl From<OfferSentState> for FinishedState {
                 tails_file: state.tails_file,
             }),
             status: Status::Undefined,
+            connection_handle: state.connection_handle,
         }
     }
 }
@@ -188,6 +193,7 @@ impl From<(OfferSentState, ProblemReport)> for FinishedState {
                 tails_file: state.tails_file,
             }),
             status: Status::Failed(err),
+            connection_handle: state.connection_handle,
         }
     }
 }
@@ -204,6 +210,7 @@ impl From<(RequestReceivedState, Option<String>)> for FinishedState {
                 tails_file: state.tails_file,
             }),
             status: Status::Success,
+            connection_handle: state.connection_handle,
         }
     }
 }
@@ -220,6 +227,7 @@ impl From<(RequestReceivedState, ProblemReport)> for FinishedState {
                 tails_file: state.tails_file,
             }),
             status: Status::Failed(err),
+            connection_handle: state.connection_handle,
         }
     }
 }
@@ -232,6 +240,7 @@ impl From<CredentialSentState> for FinishedState {
             thread_id: state.thread_id,
             revocation_info_v1: state.revocation_info_v1,
             status: Status::Success,
+            connection_handle: state.connection_handle,
         }
     }
 }
@@ -248,7 +257,7 @@ impl HolderState {
         match self {
             HolderState::OfferReceived(_) => 0,
             HolderState::RequestSent(state) => state.connection_handle,
-            HolderState::Finished(_) => 0
+            HolderState::Finished(state) => state.connection_handle
         }
     }
 }
@@ -279,7 +288,11 @@ pub struct FinishedHolderState {
     pub cred_id: Option<String>,
     pub credential: Option<Credential>,
     pub status: Status,
-    pub rev_reg_def_json: Option<String>
+    pub rev_reg_def_json: Option<String>,
+    #[serde(default)]
+    pub connection_handle: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revocation_notification: Option<RevocationNotification>,
 }
 
 impl From<(OfferReceivedState, String, String, u32)> for RequestSentState {
@@ -294,25 +307,29 @@ impl From<(OfferReceivedState, String, String, u32)> for RequestSentState {
 }
 
 impl From<(RequestSentState, String, Credential, Option<String>)> for FinishedHolderState {
-    fn from((_, cred_id, credential, rev_reg_def_json): (RequestSentState, String, Credential, Option<String>)) -> Self {
+    fn from((state, cred_id, credential, rev_reg_def_json): (RequestSentState, String, Credential, Option<String>)) -> Self {
         trace!("SM is now in Finished state");
         FinishedHolderState {
             cred_id: Some(cred_id),
             credential: Some(credential),
             status: Status::Success,
-            rev_reg_def_json: rev_reg_def_json
+            rev_reg_def_json: rev_reg_def_json,
+            connection_handle: state.connection_handle,
+            revocation_notification: None,
         }
     }
 }
 
 impl From<(RequestSentState, ProblemReport)> for FinishedHolderState {
-    fn from((_, problem_report): (RequestSentState, ProblemReport)) -> Self {
+    fn from((state, problem_report): (RequestSentState, ProblemReport)) -> Self {
         trace!("SM is now in Finished state");
         FinishedHolderState {
             cred_id: None,
             credential: None,
             status: Status::Failed(problem_report),
-            rev_reg_def_json: None
+            rev_reg_def_json: None,
+            connection_handle: state.connection_handle,
+            revocation_notification: None,
         }
     }
 }
@@ -324,7 +341,19 @@ impl From<(OfferReceivedState, ProblemReport)> for FinishedHolderState {
             cred_id: None,
             credential: None,
             status: Status::Failed(problem_report),
-            rev_reg_def_json: None
+            rev_reg_def_json: None,
+            connection_handle: 0,
+            revocation_notification: None,
+        }
+    }
+}
+
+impl From<(FinishedHolderState, RevocationNotification)> for FinishedHolderState {
+    fn from((state, notification): (FinishedHolderState, RevocationNotification)) -> Self {
+        trace!("SM is still in Finished state, credential is revoked");
+        FinishedHolderState {
+            revocation_notification: Some(notification),
+            ..state
         }
     }
 }
@@ -0,0 +1,35 @@
+use v3::messages::a2a::A2AMessage;
+use v3::messages::mediation::mediate::{MediateGrant, MediateDeny};
+use v3::messages::mediation::keylist::{KeylistUpdate, KeylistUpdateResponse, Keylist};
+use v3::messages::pickup::status::Status;
+use v3::messages::pickup::batch::Batch;
+
+#[derive(Debug, Clone)]
+pub enum MediationMessage {
+    MediateRequestSend(),
+    KeylistUpdateSend(KeylistUpdate),
+    KeylistQuerySend(),
+    StatusRequestSend(),
+    BatchPickupSend(u32),
+    MediateGrant(MediateGrant),
+    MediateDeny(MediateDeny),
+    KeylistUpdateResponse(KeylistUpdateResponse),
+    Keylist(Keylist),
+    Status(Status),
+    Batch(Batch),
+    Unknown,
+}
+
+impl From<A2AMessage> for MediationMessage {
+    fn from(msg: A2AMessage) -> Self {
+        match msg {
+            A2AMessage::MediateGrant(grant) => MediationMessage::MediateGrant(grant),
+            A2AMessage::MediateDeny(deny) => MediationMessage::MediateDeny(deny),
+            A2AMessage::KeylistUpdateResponse(response) => MediationMessage::KeylistUpdateResponse(response),
+            A2AMessage::Keylist(keylist) => MediationMessage::Keylist(keylist),
+            A2AMessage::PickupStatus(status) => MediationMessage::Status(status),
+            A2AMessage::Batch(batch) => MediationMessage::Batch(batch),
+            _ => MediationMessage::Unknown
+        }
+    }
+}
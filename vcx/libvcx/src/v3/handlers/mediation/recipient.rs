@@ -0,0 +1,302 @@
+use api::VcxStateType;
+use error::prelude::*;
+use v3::handlers::connection::agent::AgentInfo;
+use v3::handlers::mediation::messages::MediationMessage;
+use v3::handlers::mediation::states::{MediationState, NullState};
+use v3::messages::a2a::A2AMessage;
+use v3::messages::connection::did_doc::DidDoc;
+use v3::messages::mediation::mediate::MediateRequest;
+use v3::messages::mediation::keylist::KeylistQuery;
+use v3::messages::pickup::status::StatusRequest;
+use v3::messages::pickup::batch::BatchPickup;
+use v3::utils::transport;
+
+/// Recipient side of Coordinate Mediation (Aries RFC 0211) and Message Pickup (Aries RFC 0212) protocols.
+/// Keeps own copy of the pairwise keys and DIDDoc of the mediator connection, so messages to the mediator
+/// can be sent while a connection object is being processed (e.g. its keys are registered).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MediationRecipientSM {
+    state: MediationState,
+    agent_info: AgentInfo,
+    did_doc: DidDoc,
+}
+
+impl MediationRecipientSM {
+    pub fn new(agent_info: AgentInfo, did_doc: DidDoc) -> Self {
+        MediationRecipientSM {
+            state: MediationState::Null(NullState {}),
+            agent_info,
+            did_doc,
+        }
+    }
+
+    pub fn state(&self) -> u32 {
+        match self.state {
+            MediationState::Null(_) => VcxStateType::VcxStateInitialized as u32,
+            MediationState::Requested(_) => VcxStateType::VcxStateOfferSent as u32,
+            MediationState::Granted(_) => VcxStateType::VcxStateAccepted as u32,
+            MediationState::Denied(_) => VcxStateType::VcxStateRejected as u32,
+        }
+    }
+
+    pub fn pw_vk(&self) -> &str {
+        &self.agent_info.pw_vk
+    }
+
+    /// Endpoint and routing keys to put into DIDDoc of new connections once the mediation is granted.
+    pub fn routing(&self) -> Option<(String, Vec<String>)> {
+        match self.state {
+            MediationState::Granted(ref state) => Some((state.endpoint.clone(), state.routing_keys.clone())),
+            _ => None
+        }
+    }
+
+    pub fn is_granted(&self) -> bool {
+        self.routing().is_some()
+    }
+
+    /// Keys added or removed since the last confirmation of the mediator.
+    pub fn has_pending_updates(&self) -> bool {
+        match self.state {
+            MediationState::Granted(ref state) => !state.pending_updates.is_empty(),
+            _ => false
+        }
+    }
+
+    pub fn handle_message(self, message: MediationMessage) -> VcxResult<MediationRecipientSM> {
+        trace!("MediationRecipientSM::handle_message >>> message: {:?}", message);
+
+        let (sm, outbound) = self.step(message)?;
+
+        if let Some(outbound) = outbound {
+            sm.send_message(&outbound)?;
+        }
+
+        Ok(sm)
+    }
+
+    /// Moves to the next state without sending anything. Returns the message which should be sent
+    /// to the mediator, so the caller can send it after releasing whatever guards the state machine.
+    pub fn step(self, message: MediationMessage) -> VcxResult<(MediationRecipientSM, Option<A2AMessage>)> {
+        trace!("MediationRecipientSM::step >>> message: {:?}", message);
+
+        let MediationRecipientSM { state, agent_info, did_doc } = self;
+
+        let mut outbound = None;
+
+        let state = match state {
+            MediationState::Null(state) => match message {
+                MediationMessage::MediateRequestSend() => {
+                    let request = MediateRequest::create().request_return_route();
+                    outbound = Some(request.to_a2a_message());
+                    MediationState::Requested((state, request.id.0).into())
+                }
+                _ => {
+                    warn!("Mediation can only start on recipient side with sending Mediate Request");
+                    MediationState::Null(state)
+                }
+            },
+            MediationState::Requested(state) => match message {
+                MediationMessage::MediateGrant(grant) if grant.from_thread(&state.request_id) => {
+                    MediationState::Granted((state, grant).into())
+                }
+                MediationMessage::MediateDeny(deny) if deny.from_thread(&state.request_id) => {
+                    MediationState::Denied(state.into())
+                }
+                _ => {
+                    warn!("In this state Mediation can accept only Mediate Grant and Mediate Deny");
+                    MediationState::Requested(state)
+                }
+            },
+            MediationState::Granted(mut state) => match message {
+                MediationMessage::KeylistUpdateSend(update) => {
+                    let update = state.add_pending_updates(update);
+                    if !update.updates.is_empty() {
+                        outbound = Some(update.request_return_route().to_a2a_message());
+                    }
+                    MediationState::Granted(state)
+                }
+                MediationMessage::KeylistQuerySend() => {
+                    outbound = Some(KeylistQuery::create().request_return_route().to_a2a_message());
+                    MediationState::Granted(state)
+                }
+                MediationMessage::StatusRequestSend() => {
+                    outbound = Some(StatusRequest::create().request_return_route().to_a2a_message());
+                    MediationState::Granted(state)
+                }
+                MediationMessage::BatchPickupSend(batch_size) => {
+                    outbound = Some(BatchPickup::create().set_batch_size(batch_size).request_return_route().to_a2a_message());
+                    MediationState::Granted(state)
+                }
+                MediationMessage::KeylistUpdateResponse(response) => {
+                    state.apply_keylist_update(&response);
+                    MediationState::Granted(state)
+                }
+                MediationMessage::Keylist(keylist) => {
+                    state.apply_keylist(&keylist);
+                    MediationState::Granted(state)
+                }
+                MediationMessage::Status(status) => {
+                    state.message_count = Some(status.message_count);
+                    MediationState::Granted(state)
+                }
+                MediationMessage::Batch(batch) => {
+                    for message in batch.messages {
+                        let packed = ::serde_json::to_vec(&message.message)
+                            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot serialize batch message {}: {:?}", message.id, err)))?;
+                        transport::queue_inbound(packed)?;
+                    }
+                    state.message_count = None;
+                    MediationState::Granted(state)
+                }
+                _ => {
+                    warn!("Unexpected message for granted Mediation");
+                    MediationState::Granted(state)
+                }
+            },
+            MediationState::Denied(state) => {
+                warn!("Mediation is denied, no messages can be sent or received");
+                MediationState::Denied(state)
+            }
+        };
+
+        Ok((MediationRecipientSM { state, agent_info, did_doc }, outbound))
+    }
+
+    pub fn send_message(&self, message: &A2AMessage) -> VcxResult<()> {
+        self.agent_info.send_message(message, &self.did_doc)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    use utils::devsetup::SetupAriesMocks;
+    use v3::messages::a2a::MessageId;
+    use v3::messages::connection::did_doc::tests::_did_doc_4;
+    use v3::messages::mediation::mediate::tests::{_mediate_grant, _mediate_deny, _endpoint, _routing_keys};
+    use v3::messages::mediation::keylist::tests::{_keylist, _keylist_update_response, _recipient_key};
+    use v3::messages::mediation::keylist::KeylistUpdate;
+    use v3::messages::pickup::status::tests::_status;
+    use v3::messages::pickup::batch::tests::_batch;
+
+    fn _request_id() -> String {
+        MessageId::id().0
+    }
+
+    fn _mediation_sm() -> MediationRecipientSM {
+        MediationRecipientSM::new(AgentInfo::default(), _did_doc_4())
+    }
+
+    impl MediationRecipientSM {
+        fn to_requested_state(self) -> MediationRecipientSM {
+            self.handle_message(MediationMessage::MediateRequestSend()).unwrap()
+        }
+
+        fn to_granted_state(self) -> MediationRecipientSM {
+            self.to_requested_state()
+                .handle_message(MediationMessage::MediateGrant(_mediate_grant().set_thread_id(&_request_id()))).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_mediation_request() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm();
+        assert_eq!(VcxStateType::VcxStateInitialized as u32, mediation.state());
+
+        let mediation = mediation.to_requested_state();
+        assert_eq!(VcxStateType::VcxStateOfferSent as u32, mediation.state());
+        assert_eq!(None, mediation.routing());
+    }
+
+    #[test]
+    fn test_mediation_grant() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm().to_requested_state();
+
+        // grant for other thread is ignored
+        let mediation = mediation.handle_message(MediationMessage::MediateGrant(_mediate_grant())).unwrap();
+        assert_eq!(VcxStateType::VcxStateOfferSent as u32, mediation.state());
+
+        let mediation = mediation.handle_message(MediationMessage::MediateGrant(_mediate_grant().set_thread_id(&_request_id()))).unwrap();
+        assert_eq!(VcxStateType::VcxStateAccepted as u32, mediation.state());
+        assert_eq!(Some((_endpoint(), _routing_keys())), mediation.routing());
+    }
+
+    #[test]
+    fn test_mediation_deny() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm().to_requested_state()
+            .handle_message(MediationMessage::MediateDeny(_mediate_deny().set_thread_id(&_request_id()))).unwrap();
+
+        assert_eq!(VcxStateType::VcxStateRejected as u32, mediation.state());
+        assert!(!mediation.is_granted());
+    }
+
+    #[test]
+    fn test_mediation_keylist() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm().to_granted_state()
+            .handle_message(MediationMessage::KeylistUpdateSend(KeylistUpdate::create().add_key(&_recipient_key()))).unwrap()
+            .handle_message(MediationMessage::KeylistUpdateResponse(_keylist_update_response())).unwrap()
+            .handle_message(MediationMessage::KeylistQuerySend()).unwrap()
+            .handle_message(MediationMessage::Keylist(_keylist())).unwrap();
+
+        match mediation.state {
+            MediationState::Granted(ref state) => assert_eq!(vec![_recipient_key()], state.recipient_keys),
+            _ => panic!("Mediation must be granted")
+        }
+    }
+
+    #[test]
+    fn test_mediation_keylist_update_keeps_unconfirmed_keys_pending() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm().to_granted_state();
+
+        // nothing to resend
+        let (mediation, outbound) = mediation.step(MediationMessage::KeylistUpdateSend(KeylistUpdate::create())).unwrap();
+        assert!(outbound.is_none());
+
+        let (mediation, outbound) = mediation.step(MediationMessage::KeylistUpdateSend(KeylistUpdate::create().add_key(&_recipient_key()))).unwrap();
+        assert!(outbound.is_some());
+        assert!(mediation.has_pending_updates());
+
+        // unconfirmed key is resent
+        let (mediation, outbound) = mediation.step(MediationMessage::KeylistUpdateSend(KeylistUpdate::create())).unwrap();
+        match outbound {
+            Some(A2AMessage::KeylistUpdate(update)) => assert_eq!(_recipient_key(), update.updates[0].recipient_key),
+            other => panic!("Unexpected outbound message: {:?}", other)
+        }
+
+        let mediation = mediation.handle_message(MediationMessage::KeylistUpdateResponse(_keylist_update_response())).unwrap();
+        assert!(!mediation.has_pending_updates());
+    }
+
+    #[test]
+    fn test_mediation_pickup() {
+        let _setup = SetupAriesMocks::init();
+
+        let mediation = _mediation_sm().to_granted_state()
+            .handle_message(MediationMessage::StatusRequestSend()).unwrap()
+            .handle_message(MediationMessage::Status(_status())).unwrap();
+
+        match mediation.state {
+            MediationState::Granted(ref state) => assert_eq!(Some(_status().message_count), state.message_count),
+            _ => panic!("Mediation must be granted")
+        }
+
+        let _mediation = mediation
+            .handle_message(MediationMessage::BatchPickupSend(10)).unwrap()
+            .handle_message(MediationMessage::Batch(_batch())).unwrap();
+
+        let packed = ::serde_json::to_vec(&_batch().messages[0].message).unwrap();
+        assert!(transport::take_inbound().unwrap().contains(&packed));
+    }
+}
@@ -0,0 +1,193 @@
+use v3::messages::mediation::mediate::MediateGrant;
+use v3::messages::mediation::keylist::{KeylistAction, KeylistUpdate, KeylistUpdateItem, KeylistUpdateResponse, KeylistUpdateResult, Keylist};
+
+// Possible Transitions:
+//
+// Null -> Requested
+// Requested -> Granted, Denied
+// Granted -> Granted (key list updates, message pickup)
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum MediationState {
+    Null(NullState),
+    Requested(RequestedState),
+    Granted(GrantedState),
+    Denied(DeniedState),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NullState {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RequestedState {
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrantedState {
+    pub endpoint: String,
+    pub routing_keys: Vec<String>,
+    #[serde(default)]
+    pub recipient_keys: Vec<String>,
+    /// Key list updates not confirmed by the mediator yet, they are resent with the next key list update.
+    #[serde(default)]
+    pub pending_updates: Vec<KeylistUpdateItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_count: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeniedState {}
+
+impl From<(NullState, String)> for RequestedState {
+    fn from((_state, request_id): (NullState, String)) -> RequestedState {
+        trace!("MediationRecipientSM: transit state from NullState to RequestedState");
+        RequestedState { request_id }
+    }
+}
+
+impl From<(RequestedState, MediateGrant)> for GrantedState {
+    fn from((_state, grant): (RequestedState, MediateGrant)) -> GrantedState {
+        trace!("MediationRecipientSM: transit state from RequestedState to GrantedState");
+        GrantedState {
+            endpoint: grant.endpoint,
+            routing_keys: grant.routing_keys,
+            recipient_keys: Vec::new(),
+            pending_updates: Vec::new(),
+            message_count: None,
+        }
+    }
+}
+
+impl From<RequestedState> for DeniedState {
+    fn from(_state: RequestedState) -> DeniedState {
+        trace!("MediationRecipientSM: transit state from RequestedState to DeniedState");
+        DeniedState {}
+    }
+}
+
+impl GrantedState {
+    /// Adds the updates to the pending ones (the latest action for a key wins)
+    /// and returns the update which carries all of them.
+    pub fn add_pending_updates(&mut self, update: KeylistUpdate) -> KeylistUpdate {
+        for item in update.updates.iter() {
+            self.pending_updates.retain(|pending| pending.recipient_key != item.recipient_key);
+            self.pending_updates.push(item.clone());
+        }
+
+        KeylistUpdate { updates: self.pending_updates.clone(), ..update }
+    }
+
+    pub fn apply_keylist_update(&mut self, response: &KeylistUpdateResponse) {
+        for updated in response.updated.iter() {
+            // server errors are transient, so such updates stay pending and are resent later
+            if updated.result != KeylistUpdateResult::ServerError {
+                self.pending_updates.retain(|pending| !(pending.recipient_key == updated.recipient_key && pending.action == updated.action));
+            }
+
+            if !updated.is_applied() {
+                warn!("Mediator has not applied key list update: {:?}", updated);
+                continue;
+            }
+
+            self.recipient_keys.retain(|key| key != &updated.recipient_key);
+
+            if updated.action == KeylistAction::Add {
+                self.recipient_keys.push(updated.recipient_key.clone());
+            }
+        }
+    }
+
+    pub fn apply_keylist(&mut self, keylist: &Keylist) {
+        let first_page = keylist.pagination.as_ref().map(|pagination| pagination.offset == 0).unwrap_or(true);
+
+        if first_page {
+            self.recipient_keys.clear();
+        }
+
+        self.recipient_keys.extend(keylist.recipient_keys());
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::mediation::mediate::tests::{_mediate_grant, _endpoint, _routing_keys};
+    use v3::messages::mediation::keylist::tests::{_keylist, _keylist_update_response, _recipient_key};
+    use v3::messages::mediation::keylist::KeylistUpdated;
+
+    fn _granted_state() -> GrantedState {
+        (RequestedState { request_id: String::from("request_id") }, _mediate_grant()).into()
+    }
+
+    #[test]
+    fn test_granted_state_from_grant() {
+        let state = _granted_state();
+
+        assert_eq!(_endpoint(), state.endpoint);
+        assert_eq!(_routing_keys(), state.routing_keys);
+        assert!(state.recipient_keys.is_empty());
+    }
+
+    #[test]
+    fn test_granted_state_apply_keylist_update() {
+        let mut state = _granted_state();
+
+        state.apply_keylist_update(&_keylist_update_response());
+        assert_eq!(vec![_recipient_key()], state.recipient_keys);
+
+        let failed = KeylistUpdateResponse::create()
+            .set_updated(vec![KeylistUpdated { recipient_key: _recipient_key(), action: KeylistAction::Remove, result: KeylistUpdateResult::ServerError }]);
+        state.apply_keylist_update(&failed);
+        assert_eq!(vec![_recipient_key()], state.recipient_keys);
+
+        let removed = KeylistUpdateResponse::create()
+            .set_updated(vec![KeylistUpdated { recipient_key: _recipient_key(), action: KeylistAction::Remove, result: KeylistUpdateResult::Success }]);
+        state.apply_keylist_update(&removed);
+        assert!(state.recipient_keys.is_empty());
+    }
+
+    #[test]
+    fn test_granted_state_pending_updates() {
+        let mut state = _granted_state();
+
+        let update = state.add_pending_updates(KeylistUpdate::create().add_key(&_recipient_key()));
+        assert_eq!(1, update.updates.len());
+
+        // pending update is resent with the next one
+        let update = state.add_pending_updates(KeylistUpdate::create().add_key("other_key"));
+        assert_eq!(2, update.updates.len());
+
+        // the latest action for the key replaces the pending one
+        let update = state.add_pending_updates(KeylistUpdate::create().remove_key("other_key"));
+        assert_eq!(vec![_recipient_key(), String::from("other_key")], update.updates.iter().map(|item| item.recipient_key.clone()).collect::<Vec<String>>());
+        assert_eq!(KeylistAction::Remove, update.updates[1].action);
+
+        let failed = KeylistUpdateResponse::create()
+            .set_updated(vec![KeylistUpdated { recipient_key: _recipient_key(), action: KeylistAction::Add, result: KeylistUpdateResult::ServerError }]);
+        state.apply_keylist_update(&failed);
+        assert_eq!(2, state.pending_updates.len());
+        assert!(state.recipient_keys.is_empty());
+
+        state.apply_keylist_update(&_keylist_update_response());
+        assert_eq!(vec![KeylistUpdateItem { recipient_key: String::from("other_key"), action: KeylistAction::Remove }], state.pending_updates);
+        assert_eq!(vec![_recipient_key()], state.recipient_keys);
+    }
+
+    #[test]
+    fn test_granted_state_deserialization_without_pending_updates() {
+        let state: GrantedState = ::serde_json::from_str(r#"{"endpoint":"http://mediator","routing_keys":["routing_key"],"recipient_keys":["key"]}"#).unwrap();
+
+        assert_eq!(vec![String::from("key")], state.recipient_keys);
+        assert!(state.pending_updates.is_empty());
+    }
+
+    #[test]
+    fn test_granted_state_apply_keylist() {
+        let mut state = _granted_state();
+        state.recipient_keys = vec![String::from("stale_key")];
+
+        state.apply_keylist(&_keylist());
+        assert_eq!(vec![_recipient_key()], state.recipient_keys);
+    }
+}
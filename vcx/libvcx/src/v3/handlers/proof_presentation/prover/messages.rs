@@ -20,6 +20,14 @@ pub enum ProverMessages {
 
 impl From<A2AMessage> for ProverMessages {
     fn from(msg: A2AMessage) -> Self {
+        let msg = match msg.into_v1() {
+            Ok(msg) => msg,
+            Err(err) => {
+                warn!("Cannot convert Present Proof message: {}", err);
+                return ProverMessages::Unknown;
+            }
+        };
+
         match msg {
             A2AMessage::Ack(ack) | A2AMessage::PresentationAck(ack) => {
                 ProverMessages::PresentationAckReceived(ack)
@@ -4,9 +4,10 @@ use std::convert::TryInto;
 
 use v3::handlers::proof_presentation::prover::states::ProverSM;
 use v3::handlers::proof_presentation::prover::messages::ProverMessages;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
 use v3::messages::proof_presentation::presentation_proposal::PresentationPreview;
 use v3::messages::proof_presentation::presentation_request::PresentationRequest;
+use v3::messages::proof_presentation::v2::presentation_request::PresentationRequestV2;
 use ::{connection, settings};
 
 use messages::proofs::proof_message::ProofMessage;
@@ -22,7 +23,14 @@ impl Prover {
     pub fn create(source_id: &str, presentation_request: PresentationRequest) -> VcxResult<Prover> {
         trace!("Prover::create >>> source_id: {}, presentation_request: {:?}", source_id, presentation_request);
         Ok(Prover {
-            prover_sm: ProverSM::new(presentation_request, source_id.to_string()),
+            prover_sm: ProverSM::new(presentation_request, source_id.to_string(), ProtocolVersion::V1),
+        })
+    }
+
+    pub fn create_v2(source_id: &str, presentation_request: PresentationRequestV2) -> VcxResult<Prover> {
+        trace!("Prover::create_v2 >>> source_id: {}, presentation_request: {:?}", source_id, presentation_request);
+        Ok(Prover {
+            prover_sm: ProverSM::new(presentation_request.try_into()?, source_id.to_string(), ProtocolVersion::V2),
         })
     }
 
@@ -106,31 +114,33 @@ impl Prover {
         self.step(message)
     }
 
-    pub fn get_presentation_request(connection_handle: u32, msg_id: &str) -> VcxResult<PresentationRequest> {
+    /// Returns `PresentationRequest` or `PresentationRequestV2` message.
+    pub fn get_presentation_request(connection_handle: u32, msg_id: &str) -> VcxResult<A2AMessage> {
         trace!("Prover::get_presentation_request >>> connection_handle: {:?}, msg_id: {:?}", connection_handle, msg_id);
 
         let message = connection::get_message_by_id(connection_handle, msg_id.to_string())?;
 
-        let presentation_request: PresentationRequest = match message {
-            A2AMessage::PresentationRequest(presentation_request) => presentation_request,
+        match message {
+            presentation_request @ A2AMessage::PresentationRequest(_) |
+            presentation_request @ A2AMessage::PresentationRequestV2(_) => Ok(presentation_request),
             msg => {
                 return Err(VcxError::from_msg(VcxErrorKind::InvalidMessages,
                                               format!("Message of different type was received: {:?}", msg)));
             }
-        };
-
-        Ok(presentation_request)
+        }
     }
 
-    pub fn get_presentation_request_messages(connection_handle: u32, match_name: Option<&str>) -> VcxResult<Vec<PresentationRequest>> {
+    /// Returns `PresentationRequest` and `PresentationRequestV2` messages.
+    pub fn get_presentation_request_messages(connection_handle: u32, match_name: Option<&str>) -> VcxResult<Vec<A2AMessage>> {
         trace!("Prover::get_presentation_request_messages >>> connection_handle: {:?}, match_name: {:?}", connection_handle, match_name);
 
-        let presentation_requests: Vec<PresentationRequest> =
+        let presentation_requests: Vec<A2AMessage> =
             connection::get_messages(connection_handle)?
                 .into_iter()
                 .filter_map(|(_, message)| {
                     match message {
-                        A2AMessage::PresentationRequest(presentation_request) => {
+                        presentation_request @ A2AMessage::PresentationRequest(_) |
+                        presentation_request @ A2AMessage::PresentationRequestV2(_) => {
                             Some(presentation_request)
                         }
                         _ => None,
@@ -143,6 +153,8 @@ impl Prover {
 
     pub fn get_source_id(&self) -> String { self.prover_sm.source_id() }
 
+    pub fn get_thread_id(&self) -> String { self.prover_sm.thread_id() }
+
     pub fn step(&mut self, message: ProverMessages) -> VcxResult<()> {
         self.prover_sm = self.prover_sm.clone().step(message)?;
         Ok(())
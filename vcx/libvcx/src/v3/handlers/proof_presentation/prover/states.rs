@@ -2,7 +2,7 @@ use api::VcxStateType;
 
 use connection;
 use v3::handlers::proof_presentation::prover::messages::ProverMessages;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
 use v3::messages::proof_presentation::presentation_request::PresentationRequest;
 use v3::messages::proof_presentation::presentation_proposal::{PresentationProposal, PresentationPreview};
 use v3::messages::proof_presentation::presentation::Presentation;
@@ -22,11 +22,13 @@ pub struct ProverSM {
     source_id: String,
     thread_id: String,
     state: ProverState,
+    #[serde(default)]
+    version: ProtocolVersion,
 }
 
 impl ProverSM {
-    pub fn new(presentation_request: PresentationRequest, source_id: String) -> ProverSM {
-        ProverSM { source_id, thread_id: presentation_request.id.0.clone(), state: ProverState::Initiated(InitialState { presentation_request }) }
+    pub fn new(presentation_request: PresentationRequest, source_id: String, version: ProtocolVersion) -> ProverSM {
+        ProverSM { source_id, thread_id: presentation_request.id.0.clone(), state: ProverState::Initiated(InitialState { presentation_request }), version }
     }
 }
 
@@ -181,6 +183,14 @@ impl ProverSM {
         trace!("Prover::find_message_to_handle >>> messages: {:?}", messages);
 
         for (uid, message) in messages {
+            let message = match message.into_v1() {
+                Ok(message) => message,
+                Err(err) => {
+                    warn!("Prover::find_message_to_handle: cannot convert message {}: {}", uid, err);
+                    continue;
+                }
+            };
+
             match self.state {
                 ProverState::Initiated(_) => {
                     match message {
@@ -223,7 +233,7 @@ impl ProverSM {
     pub fn step(self, message: ProverMessages) -> VcxResult<ProverSM> {
         trace!("ProverSM::step >>> message: {:?}", message);
 
-        let ProverSM { source_id, state, thread_id } = self;
+        let ProverSM { source_id, state, thread_id, version } = self;
 
         let state = match state {
             ProverState::Initiated(state) => {
@@ -257,7 +267,7 @@ impl ProverSM {
                         ProverState::Finished(state.into())
                     }
                     ProverMessages::ProposePresentation((connection_handle, preview)) => {
-                        Self::_handle_presentation_proposal(connection_handle, preview, &state.presentation_request, &thread_id)?;
+                        Self::_handle_presentation_proposal(connection_handle, preview, &state.presentation_request, &thread_id, version)?;
                         ProverState::Finished(state.into())
                     }
                     _ => {
@@ -270,11 +280,11 @@ impl ProverSM {
                     ProverMessages::SendPresentation(connection_handle) => {
                         match state.presentation_request.service.clone() {
                             None => {
-                                connection::send_message(connection_handle, state.presentation.to_a2a_message())?;
+                                connection::send_message(connection_handle, state.presentation.to_a2a_message().into_version(version)?)?;
                                 ProverState::PresentationSent((state, connection_handle).into())
                             }
                             Some(service) => {
-                                connection::send_message_to_self_endpoint(state.presentation.to_a2a_message(), &service.into())?;
+                                connection::send_message_to_self_endpoint(state.presentation.to_a2a_message().into_version(version)?, &service.into())?;
                                 ProverState::Finished(state.into())
                             }
                         }
@@ -284,7 +294,7 @@ impl ProverSM {
                         ProverState::Finished(state.into())
                     }
                     ProverMessages::ProposePresentation((connection_handle, preview)) => {
-                        Self::_handle_presentation_proposal(connection_handle, preview, &state.presentation_request, &thread_id)?;
+                        Self::_handle_presentation_proposal(connection_handle, preview, &state.presentation_request, &thread_id, version)?;
                         ProverState::Finished(state.into())
                     }
                     _ => {
@@ -330,7 +340,7 @@ impl ProverSM {
             ProverState::Finished(state) => ProverState::Finished(state)
         };
 
-        Ok(ProverSM { source_id, state, thread_id })
+        Ok(ProverSM { source_id, state, thread_id, version })
     }
 
     fn _handle_reject_presentation_request(connection_handle: u32, reason: &str, presentation_request: &PresentationRequest, thread_id: &str) -> VcxResult<()> {
@@ -346,14 +356,16 @@ impl ProverSM {
         Ok(())
     }
 
-    fn _handle_presentation_proposal(connection_handle: u32, preview: PresentationPreview, presentation_request: &PresentationRequest, thread_id: &str) -> VcxResult<()> {
+    fn _handle_presentation_proposal(connection_handle: u32, preview: PresentationPreview, presentation_request: &PresentationRequest, thread_id: &str, version: ProtocolVersion) -> VcxResult<()> {
         let proposal = PresentationProposal::create()
             .set_presentation_preview(preview)
-            .set_thread_id(thread_id);
+            .set_thread_id(thread_id)
+            .to_a2a_message()
+            .into_version(version)?;
 
         match presentation_request.service.clone() {
-            None => connection::send_message(connection_handle, proposal.to_a2a_message())?,
-            Some(service) => connection::send_message_to_self_endpoint(proposal.to_a2a_message(), &service.into())?
+            None => connection::send_message(connection_handle, proposal)?,
+            Some(service) => connection::send_message_to_self_endpoint(proposal, &service.into())?
         }
 
         Ok(())
@@ -361,6 +373,8 @@ impl ProverSM {
 
     pub fn source_id(&self) -> String { self.source_id.clone() }
 
+    pub fn thread_id(&self) -> String { self.thread_id.clone() }
+
     pub fn state(&self) -> u32 {
         match self.state {
             ProverState::Initiated(_) => VcxStateType::VcxStateRequestReceived as u32,
@@ -437,7 +451,7 @@ pub mod test {
     use v3::messages::proof_presentation::presentation_proposal::tests::{_presentation_proposal, _presentation_preview};
 
     pub fn _prover_sm() -> ProverSM {
-        ProverSM::new(_presentation_request(), source_id())
+        ProverSM::new(_presentation_request(), source_id(), ProtocolVersion::V1)
     }
 
     impl ProverSM {
@@ -574,7 +588,7 @@ pub mod test {
         fn test_prover_handle_send_presentation_message_from_presentation_prepared_state_for_presentation_request_contains_service_decorator() {
             let _setup = SetupAriesMocks::init();
 
-            let mut prover_sm = ProverSM::new(_presentation_request_with_service(), source_id());
+            let mut prover_sm = ProverSM::new(_presentation_request_with_service(), source_id(), ProtocolVersion::V1);
             prover_sm = prover_sm.step(ProverMessages::PreparePresentation((_credentials(), _self_attested()))).unwrap();
             prover_sm = prover_sm.step(ProverMessages::SendPresentation(mock_connection())).unwrap();
 
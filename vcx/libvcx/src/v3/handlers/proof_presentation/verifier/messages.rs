@@ -14,6 +14,14 @@ pub enum VerifierMessages {
 
 impl From<A2AMessage> for VerifierMessages {
     fn from(msg: A2AMessage) -> Self {
+        let msg = match msg.into_v1() {
+            Ok(msg) => msg,
+            Err(err) => {
+                warn!("Cannot convert Present Proof message: {}", err);
+                return VerifierMessages::Unknown;
+            }
+        };
+
         match msg {
             A2AMessage::Presentation(presentation) => {
                 VerifierMessages::VerifyPresentation(presentation)
@@ -6,7 +6,8 @@ use connection;
 use error::prelude::*;
 use proof::Proof;
 use v3::handlers::proof_presentation::verifier::messages::VerifierMessages;
-use v3::messages::a2a::A2AMessage;
+use v3::messages::a2a::{A2AMessage, ProtocolVersion};
+use v3::messages::a2a::message_family::MessageFamilies;
 use v3::messages::error::ProblemReport;
 use v3::messages::proof_presentation::presentation::Presentation;
 use v3::messages::proof_presentation::presentation_ack::PresentationAck;
@@ -17,11 +18,13 @@ use v3::messages::status::Status;
 pub struct VerifierSM {
     source_id: String,
     state: VerifierState,
+    #[serde(default)]
+    version: ProtocolVersion,
 }
 
 impl VerifierSM {
     pub fn new(presentation_request: PresentationRequestData, source_id: String) -> VerifierSM {
-        VerifierSM { source_id, state: VerifierState::Initiated(InitialState { presentation_request_data: presentation_request }) }
+        VerifierSM { source_id, state: VerifierState::Initiated(InitialState { presentation_request_data: presentation_request }), version: ProtocolVersion::V1 }
     }
 }
 
@@ -97,7 +100,7 @@ impl From<(PresentationRequestSentState, ProblemReport)> for FinishedState {
 
 
 impl PresentationRequestSentState {
-    fn verify_presentation(&self, presentation: &Presentation) -> VcxResult<()> {
+    fn verify_presentation(&self, presentation: &Presentation, version: ProtocolVersion) -> VcxResult<()> {
         let valid = Proof::validate_indy_proof(&presentation.presentations_attach.content()?,
                                                &self.presentation_request.request_presentations_attach.content()?)?;
 
@@ -107,7 +110,7 @@ impl PresentationRequestSentState {
 
         if presentation.please_ack.is_some() {
             let ack = PresentationAck::create().set_thread_id(&self.presentation_request.id.0);
-            connection::send_message(self.connection_handle, A2AMessage::PresentationAck(ack))?;
+            connection::send_message(self.connection_handle, A2AMessage::PresentationAck(ack).into_version(version)?)?;
         }
 
         Ok(())
@@ -119,6 +122,14 @@ impl VerifierSM {
         trace!("VerifierSM::find_message_to_handle >>> messages: {:?}", messages);
 
         for (uid, message) in messages {
+            let message = match message.into_v1() {
+                Ok(message) => message,
+                Err(err) => {
+                    warn!("VerifierSM::find_message_to_handle: cannot convert message {}: {}", uid, err);
+                    continue;
+                }
+            };
+
             match self.state {
                 VerifierState::Initiated(_) => {
                     // do not process message
@@ -155,7 +166,7 @@ impl VerifierSM {
     pub fn step(self, message: VerifierMessages) -> VcxResult<VerifierSM> {
         trace!("VerifierSM::step >>> message: {:?}", message);
 
-        let VerifierSM { source_id, state } = self;
+        let VerifierSM { source_id, state, mut version } = self;
 
         let state = match state {
             VerifierState::Initiated(state) => {
@@ -176,7 +187,9 @@ impl VerifierSM {
                                 .set_comment(title)
                                 .set_request_presentations_attach(&presentation_request)?;
 
-                        connection::send_message(connection_handle, presentation_request.to_a2a_message())?;
+                        version = connection::get_protocol_version(connection_handle, MessageFamilies::PresentProof)?;
+
+                        connection::send_message(connection_handle, presentation_request.to_a2a_message().into_version(version)?)?;
                         VerifierState::PresentationRequestSent((state, presentation_request, connection_handle).into())
                     }
                     _ => {
@@ -187,7 +200,7 @@ impl VerifierSM {
             VerifierState::PresentationRequestSent(state) => {
                 match message {
                     VerifierMessages::VerifyPresentation(presentation) => {
-                        match state.verify_presentation(&presentation) {
+                        match state.verify_presentation(&presentation, version) {
                             Ok(()) => {
                                 VerifierState::Finished((state, presentation, RevocationStatus::NonRevoked).into())
                             }
@@ -227,7 +240,7 @@ impl VerifierSM {
             VerifierState::Finished(state) => VerifierState::Finished(state)
         };
 
-        Ok(VerifierSM { source_id, state })
+        Ok(VerifierSM { source_id, state, version })
     }
 
     pub fn source_id(&self) -> String { self.source_id.clone() }
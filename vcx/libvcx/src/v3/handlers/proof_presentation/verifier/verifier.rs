@@ -40,6 +40,8 @@ impl Verifier {
 
     pub fn get_source_id(&self) -> String { self.verifier_sm.source_id() }
 
+    pub fn get_thread_id(&self) -> String { self.verifier_sm.thread_id() }
+
     pub fn state(&self) -> u32 {
         trace!("Verifier::state >>>");
         self.verifier_sm.state()
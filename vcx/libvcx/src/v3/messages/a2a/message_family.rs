@@ -4,14 +4,21 @@ use settings::Actors;
 pub enum MessageFamilies {
     Routing,
     Connections,
+    DidExchange,
+    OutOfBand,
     Notification,
     Signature,
     CredentialIssuance,
+    CredentialIssuanceV2,
     ReportProblem,
     PresentProof,
+    PresentProofV2,
     TrustPing,
     DiscoveryFeatures,
     Basicmessage,
+    RevocationNotification,
+    CoordinateMediation,
+    MessagePickup,
     Unknown(String)
 }
 
@@ -22,14 +29,21 @@ impl MessageFamilies {
         match self {
             MessageFamilies::Routing => "1.0",
             MessageFamilies::Connections => "1.0",
+            MessageFamilies::DidExchange => "1.0",
+            MessageFamilies::OutOfBand => "1.0",
             MessageFamilies::Notification => "1.0",
             MessageFamilies::Signature => "1.0",
             MessageFamilies::CredentialIssuance => "1.0",
+            MessageFamilies::CredentialIssuanceV2 => "2.0",
             MessageFamilies::ReportProblem => "1.0",
             MessageFamilies::PresentProof => "1.0",
+            MessageFamilies::PresentProofV2 => "2.0",
             MessageFamilies::TrustPing => "1.0",
             MessageFamilies::DiscoveryFeatures => "1.0",
             MessageFamilies::Basicmessage => "1.0",
+            MessageFamilies::RevocationNotification => "1.0",
+            MessageFamilies::CoordinateMediation => "1.0",
+            MessageFamilies::MessagePickup => "1.0",
             MessageFamilies::Unknown(_) => "1.0"
         }
     }
@@ -38,18 +52,34 @@ impl MessageFamilies {
         format!("{};spec/{}/{}", Self::DID, self.to_string(), self.version().to_string())
     }
 
+    /// Families sharing the same name are distinguished by the major version of the message type.
+    pub fn with_version(self, version: &str) -> MessageFamilies {
+        match (self, version.starts_with("2.")) {
+            (MessageFamilies::CredentialIssuance, true) => MessageFamilies::CredentialIssuanceV2,
+            (MessageFamilies::PresentProof, true) => MessageFamilies::PresentProofV2,
+            (family, _) => family
+        }
+    }
+
     pub fn actors(&self) -> Option<(Actors, Actors)> {
         match self {
             MessageFamilies::Routing => None,
             MessageFamilies::Connections => Some((Actors::Inviter, Actors::Invitee)),
+            MessageFamilies::DidExchange => Some((Actors::Inviter, Actors::Invitee)),
+            MessageFamilies::OutOfBand => Some((Actors::Inviter, Actors::Invitee)),
             MessageFamilies::Notification => None,
             MessageFamilies::Signature => None,
             MessageFamilies::CredentialIssuance => Some((Actors::Issuer, Actors::Holder)),
+            MessageFamilies::CredentialIssuanceV2 => Some((Actors::Issuer, Actors::Holder)),
             MessageFamilies::ReportProblem => None,
             MessageFamilies::PresentProof => Some((Actors::Prover, Actors::Verifier)),
+            MessageFamilies::PresentProofV2 => Some((Actors::Prover, Actors::Verifier)),
             MessageFamilies::TrustPing => Some((Actors::Sender, Actors::Receiver)),
             MessageFamilies::DiscoveryFeatures => Some((Actors::Sender, Actors::Receiver)),
             MessageFamilies::Basicmessage => Some((Actors::Sender, Actors::Receiver)),
+            MessageFamilies::RevocationNotification => Some((Actors::Issuer, Actors::Holder)),
+            MessageFamilies::CoordinateMediation => None,
+            MessageFamilies::MessagePickup => None,
             MessageFamilies::Unknown(_) => None
         }
     }
@@ -60,6 +90,8 @@ impl From<String> for MessageFamilies {
         match family.as_str() {
             "routing" => MessageFamilies::Routing,
             "connections" => MessageFamilies::Connections,
+            "didexchange" => MessageFamilies::DidExchange,
+            "out-of-band" => MessageFamilies::OutOfBand,
             "signature" => MessageFamilies::Signature,
             "notification" => MessageFamilies::Notification,
             "issue-credential" => MessageFamilies::CredentialIssuance,
@@ -68,6 +100,9 @@ impl From<String> for MessageFamilies {
             "trust_ping" => MessageFamilies::TrustPing,
             "discover-features" => MessageFamilies::DiscoveryFeatures,
             "basicmessage" => MessageFamilies::Basicmessage,
+            "revocation_notification" => MessageFamilies::RevocationNotification,
+            "coordinate-mediation" => MessageFamilies::CoordinateMediation,
+            "messagepickup" => MessageFamilies::MessagePickup,
             family @ _ => MessageFamilies::Unknown(family.to_string())
         }
     }
@@ -78,14 +113,21 @@ impl ::std::string::ToString for MessageFamilies {
         match self {
             MessageFamilies::Routing => "routing".to_string(),
             MessageFamilies::Connections => "connections".to_string(),
+            MessageFamilies::DidExchange => "didexchange".to_string(),
+            MessageFamilies::OutOfBand => "out-of-band".to_string(),
             MessageFamilies::Notification => "notification".to_string(),
             MessageFamilies::Signature => "signature".to_string(),
             MessageFamilies::CredentialIssuance => "issue-credential".to_string(),
+            MessageFamilies::CredentialIssuanceV2 => "issue-credential".to_string(),
             MessageFamilies::ReportProblem => "report-problem".to_string(),
             MessageFamilies::PresentProof => "present-proof".to_string(),
+            MessageFamilies::PresentProofV2 => "present-proof".to_string(),
             MessageFamilies::TrustPing => "trust_ping".to_string(),
             MessageFamilies::DiscoveryFeatures => "discover-features".to_string(),
             MessageFamilies::Basicmessage => "basicmessage".to_string(),
+            MessageFamilies::RevocationNotification => "revocation_notification".to_string(),
+            MessageFamilies::CoordinateMediation => "coordinate-mediation".to_string(),
+            MessageFamilies::MessagePickup => "messagepickup".to_string(),
             MessageFamilies::Unknown(family) => family.to_string()
         }
     }
@@ -7,11 +7,18 @@ use self::message_family::MessageFamilies;
 
 use serde::{de, Deserialize, Deserializer, ser, Serialize, Serializer};
 use serde_json::Value;
+use std::convert::TryInto;
+
+use error::prelude::*;
 
 use v3::messages::connection::invite::Invitation;
 use v3::messages::connection::request::Request;
 use v3::messages::connection::response::SignedResponse;
 use v3::messages::connection::problem_report::ProblemReport as ConnectionProblemReport;
+use v3::messages::did_exchange::request::ExchangeRequest;
+use v3::messages::did_exchange::response::ExchangeResponse;
+use v3::messages::did_exchange::complete::Complete;
+use v3::messages::out_of_band::invitation::OutofbandInvitation;
 use v3::messages::trust_ping::ping::Ping;
 use v3::messages::trust_ping::ping_response::PingResponse;
 use v3::messages::forward::Forward;
@@ -27,11 +34,28 @@ use v3::messages::proof_presentation::presentation_proposal::PresentationProposa
 use v3::messages::proof_presentation::presentation_request::PresentationRequest;
 use v3::messages::proof_presentation::presentation::Presentation;
 
+use v3::messages::issuance::v2::credential_proposal::CredentialProposalV2;
+use v3::messages::issuance::v2::credential_offer::CredentialOfferV2;
+use v3::messages::issuance::v2::credential_request::CredentialRequestV2;
+use v3::messages::issuance::v2::credential::CredentialV2;
+
+use v3::messages::proof_presentation::v2::presentation_proposal::PresentationProposalV2;
+use v3::messages::proof_presentation::v2::presentation_request::PresentationRequestV2;
+use v3::messages::proof_presentation::v2::presentation::PresentationV2;
+
 use v3::messages::discovery::query::Query;
 use v3::messages::discovery::disclose::Disclose;
 
 use v3::messages::basic_message::message::BasicMessage;
 
+use v3::messages::revocation_notification::revoke::RevocationNotification;
+
+use v3::messages::mediation::mediate::{MediateRequest, MediateGrant, MediateDeny};
+use v3::messages::mediation::keylist::{KeylistUpdate, KeylistUpdateResponse, KeylistQuery, Keylist};
+
+use v3::messages::pickup::status::{StatusRequest, Status};
+use v3::messages::pickup::batch::{BatchPickup, Batch};
+
 #[derive(Debug, PartialEq, Clone)]
 pub enum A2AMessage {
     /// routing
@@ -43,6 +67,15 @@ pub enum A2AMessage {
     ConnectionResponse(SignedResponse),
     ConnectionProblemReport(ConnectionProblemReport),
 
+    /// DID Exchange 1.0
+    DidExchangeRequest(ExchangeRequest),
+    DidExchangeResponse(ExchangeResponse),
+    DidExchangeComplete(Complete),
+    DidExchangeProblemReport(ConnectionProblemReport),
+
+    /// out-of-band
+    OutofbandInvitation(OutofbandInvitation),
+
     /// trust ping
     Ping(Ping),
     PingResponse(PingResponse),
@@ -58,12 +91,25 @@ pub enum A2AMessage {
     Credential(Credential),
     CredentialAck(Ack),
 
+    /// credential issuance 2.0
+    CredentialProposalV2(CredentialProposalV2),
+    CredentialOfferV2(CredentialOfferV2),
+    CredentialRequestV2(CredentialRequestV2),
+    CredentialV2(CredentialV2),
+    CredentialAckV2(Ack),
+
     /// proof presentation
     PresentationProposal(PresentationProposal),
     PresentationRequest(PresentationRequest),
     Presentation(Presentation),
     PresentationAck(Ack),
 
+    /// proof presentation 2.0
+    PresentationProposalV2(PresentationProposalV2),
+    PresentationRequestV2(PresentationRequestV2),
+    PresentationV2(PresentationV2),
+    PresentationAckV2(Ack),
+
     /// discovery features
     Query(Query),
     Disclose(Disclose),
@@ -71,6 +117,24 @@ pub enum A2AMessage {
     /// basic message
     BasicMessage(BasicMessage),
 
+    /// revocation notification
+    RevocationNotification(RevocationNotification),
+
+    /// coordinate mediation
+    MediateRequest(MediateRequest),
+    MediateGrant(MediateGrant),
+    MediateDeny(MediateDeny),
+    KeylistUpdate(KeylistUpdate),
+    KeylistUpdateResponse(KeylistUpdateResponse),
+    KeylistQuery(KeylistQuery),
+    Keylist(Keylist),
+
+    /// message pickup
+    PickupStatusRequest(StatusRequest),
+    PickupStatus(Status),
+    BatchPickup(BatchPickup),
+    Batch(Batch),
+
     /// Any Raw Message
     Generic(Value),
 }
@@ -105,6 +169,31 @@ impl<'de> Deserialize<'de> for A2AMessage {
                     .map(|msg| A2AMessage::ConnectionResponse(msg))
                     .map_err(de::Error::custom)
             }
+            (MessageFamilies::DidExchange, A2AMessage::CONNECTION_REQUEST) => {
+                ExchangeRequest::deserialize(value)
+                    .map(|msg| A2AMessage::DidExchangeRequest(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::DidExchange, A2AMessage::CONNECTION_RESPONSE) => {
+                ExchangeResponse::deserialize(value)
+                    .map(|msg| A2AMessage::DidExchangeResponse(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::DidExchange, A2AMessage::DID_EXCHANGE_COMPLETE) => {
+                Complete::deserialize(value)
+                    .map(|msg| A2AMessage::DidExchangeComplete(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::DidExchange, A2AMessage::CONNECTION_PROBLEM_REPORT) => {
+                ConnectionProblemReport::deserialize(value)
+                    .map(|msg| A2AMessage::DidExchangeProblemReport(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::OutOfBand, A2AMessage::CONNECTION_INVITATION) => {
+                OutofbandInvitation::deserialize(value)
+                    .map(|msg| A2AMessage::OutofbandInvitation(msg))
+                    .map_err(de::Error::custom)
+            }
             (MessageFamilies::TrustPing, A2AMessage::PING) => {
                 Ping::deserialize(value)
                     .map(|msg| A2AMessage::Ping(msg))
@@ -155,6 +244,31 @@ impl<'de> Deserialize<'de> for A2AMessage {
                     .map(|msg| A2AMessage::CredentialAck(msg))
                     .map_err(de::Error::custom)
             }
+            (MessageFamilies::CredentialIssuanceV2, A2AMessage::CREDENTIAL) => {
+                CredentialV2::deserialize(value)
+                    .map(|msg| A2AMessage::CredentialV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CredentialIssuanceV2, A2AMessage::PROPOSE_CREDENTIAL) => {
+                CredentialProposalV2::deserialize(value)
+                    .map(|msg| A2AMessage::CredentialProposalV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CredentialIssuanceV2, A2AMessage::CREDENTIAL_OFFER) => {
+                CredentialOfferV2::deserialize(value)
+                    .map(|msg| A2AMessage::CredentialOfferV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CredentialIssuanceV2, A2AMessage::REQUEST_CREDENTIAL) => {
+                CredentialRequestV2::deserialize(value)
+                    .map(|msg| A2AMessage::CredentialRequestV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CredentialIssuanceV2, A2AMessage::ACK) => {
+                Ack::deserialize(value)
+                    .map(|msg| A2AMessage::CredentialAckV2(msg))
+                    .map_err(de::Error::custom)
+            }
             (MessageFamilies::PresentProof, A2AMessage::PROPOSE_PRESENTATION) => {
                 PresentationProposal::deserialize(value)
                     .map(|msg| A2AMessage::PresentationProposal(msg))
@@ -175,6 +289,26 @@ impl<'de> Deserialize<'de> for A2AMessage {
                     .map(|msg| A2AMessage::PresentationAck(msg))
                     .map_err(de::Error::custom)
             }
+            (MessageFamilies::PresentProofV2, A2AMessage::PROPOSE_PRESENTATION) => {
+                PresentationProposalV2::deserialize(value)
+                    .map(|msg| A2AMessage::PresentationProposalV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::PresentProofV2, A2AMessage::REQUEST_PRESENTATION) => {
+                PresentationRequestV2::deserialize(value)
+                    .map(|msg| A2AMessage::PresentationRequestV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::PresentProofV2, A2AMessage::PRESENTATION) => {
+                PresentationV2::deserialize(value)
+                    .map(|msg| A2AMessage::PresentationV2(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::PresentProofV2, A2AMessage::ACK) => {
+                Ack::deserialize(value)
+                    .map(|msg| A2AMessage::PresentationAckV2(msg))
+                    .map_err(de::Error::custom)
+            }
             (MessageFamilies::DiscoveryFeatures, A2AMessage::QUERY) => {
                 Query::deserialize(value)
                     .map(|msg| A2AMessage::Query(msg))
@@ -190,6 +324,66 @@ impl<'de> Deserialize<'de> for A2AMessage {
                     .map(|msg| A2AMessage::BasicMessage(msg))
                     .map_err(de::Error::custom)
             }
+            (MessageFamilies::RevocationNotification, A2AMessage::REVOKE) => {
+                RevocationNotification::deserialize(value)
+                    .map(|msg| A2AMessage::RevocationNotification(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_REQUEST) => {
+                MediateRequest::deserialize(value)
+                    .map(|msg| A2AMessage::MediateRequest(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_GRANT) => {
+                MediateGrant::deserialize(value)
+                    .map(|msg| A2AMessage::MediateGrant(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_DENY) => {
+                MediateDeny::deserialize(value)
+                    .map(|msg| A2AMessage::MediateDeny(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_UPDATE) => {
+                KeylistUpdate::deserialize(value)
+                    .map(|msg| A2AMessage::KeylistUpdate(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_UPDATE_RESPONSE) => {
+                KeylistUpdateResponse::deserialize(value)
+                    .map(|msg| A2AMessage::KeylistUpdateResponse(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_QUERY) => {
+                KeylistQuery::deserialize(value)
+                    .map(|msg| A2AMessage::KeylistQuery(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST) => {
+                Keylist::deserialize(value)
+                    .map(|msg| A2AMessage::Keylist(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::MessagePickup, A2AMessage::PICKUP_STATUS_REQUEST) => {
+                StatusRequest::deserialize(value)
+                    .map(|msg| A2AMessage::PickupStatusRequest(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::MessagePickup, A2AMessage::PICKUP_STATUS) => {
+                Status::deserialize(value)
+                    .map(|msg| A2AMessage::PickupStatus(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::MessagePickup, A2AMessage::BATCH_PICKUP) => {
+                BatchPickup::deserialize(value)
+                    .map(|msg| A2AMessage::BatchPickup(msg))
+                    .map_err(de::Error::custom)
+            }
+            (MessageFamilies::MessagePickup, A2AMessage::BATCH) => {
+                Batch::deserialize(value)
+                    .map(|msg| A2AMessage::Batch(msg))
+                    .map_err(de::Error::custom)
+            }
             (_, other_type) => {
                 warn!("Unexpected @type field structure: {}", other_type);
                 Ok(A2AMessage::Generic(value))
@@ -213,6 +407,11 @@ impl Serialize for A2AMessage {
             A2AMessage::ConnectionRequest(msg) => set_a2a_message_type(msg, MessageFamilies::Connections, A2AMessage::CONNECTION_REQUEST),
             A2AMessage::ConnectionResponse(msg) => set_a2a_message_type(msg, MessageFamilies::Connections, A2AMessage::CONNECTION_RESPONSE),
             A2AMessage::ConnectionProblemReport(msg) => set_a2a_message_type(msg, MessageFamilies::Connections, A2AMessage::CONNECTION_PROBLEM_REPORT),
+            A2AMessage::DidExchangeRequest(msg) => set_a2a_message_type(msg, MessageFamilies::DidExchange, A2AMessage::CONNECTION_REQUEST),
+            A2AMessage::DidExchangeResponse(msg) => set_a2a_message_type(msg, MessageFamilies::DidExchange, A2AMessage::CONNECTION_RESPONSE),
+            A2AMessage::DidExchangeComplete(msg) => set_a2a_message_type(msg, MessageFamilies::DidExchange, A2AMessage::DID_EXCHANGE_COMPLETE),
+            A2AMessage::DidExchangeProblemReport(msg) => set_a2a_message_type(msg, MessageFamilies::DidExchange, A2AMessage::CONNECTION_PROBLEM_REPORT),
+            A2AMessage::OutofbandInvitation(msg) => set_a2a_message_type(msg, MessageFamilies::OutOfBand, A2AMessage::CONNECTION_INVITATION),
             A2AMessage::Ping(msg) => set_a2a_message_type(msg, MessageFamilies::TrustPing, A2AMessage::PING),
             A2AMessage::PingResponse(msg) => set_a2a_message_type(msg, MessageFamilies::TrustPing, A2AMessage::PING_RESPONSE),
             A2AMessage::Ack(msg) => set_a2a_message_type(msg, MessageFamilies::Notification, A2AMessage::ACK),
@@ -226,9 +425,30 @@ impl Serialize for A2AMessage {
             A2AMessage::PresentationRequest(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProof, A2AMessage::REQUEST_PRESENTATION),
             A2AMessage::Presentation(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProof, A2AMessage::PRESENTATION),
             A2AMessage::PresentationAck(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProof, A2AMessage::ACK),
+            A2AMessage::CredentialOfferV2(msg) => set_a2a_message_type(msg, MessageFamilies::CredentialIssuanceV2, A2AMessage::CREDENTIAL_OFFER),
+            A2AMessage::CredentialV2(msg) => set_a2a_message_type(msg, MessageFamilies::CredentialIssuanceV2, A2AMessage::CREDENTIAL),
+            A2AMessage::CredentialProposalV2(msg) => set_a2a_message_type(msg, MessageFamilies::CredentialIssuanceV2, A2AMessage::PROPOSE_CREDENTIAL),
+            A2AMessage::CredentialRequestV2(msg) => set_a2a_message_type(msg, MessageFamilies::CredentialIssuanceV2, A2AMessage::REQUEST_CREDENTIAL),
+            A2AMessage::CredentialAckV2(msg) => set_a2a_message_type(msg, MessageFamilies::CredentialIssuanceV2, A2AMessage::ACK),
+            A2AMessage::PresentationProposalV2(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProofV2, A2AMessage::PROPOSE_PRESENTATION),
+            A2AMessage::PresentationRequestV2(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProofV2, A2AMessage::REQUEST_PRESENTATION),
+            A2AMessage::PresentationV2(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProofV2, A2AMessage::PRESENTATION),
+            A2AMessage::PresentationAckV2(msg) => set_a2a_message_type(msg, MessageFamilies::PresentProofV2, A2AMessage::ACK),
             A2AMessage::Query(msg) => set_a2a_message_type(msg, MessageFamilies::DiscoveryFeatures, A2AMessage::QUERY),
             A2AMessage::Disclose(msg) => set_a2a_message_type(msg, MessageFamilies::DiscoveryFeatures, A2AMessage::DISCLOSE),
             A2AMessage::BasicMessage(msg) => set_a2a_message_type(msg, MessageFamilies::Basicmessage, A2AMessage::BASIC_MESSAGE),
+            A2AMessage::RevocationNotification(msg) => set_a2a_message_type(msg, MessageFamilies::RevocationNotification, A2AMessage::REVOKE),
+            A2AMessage::MediateRequest(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_REQUEST),
+            A2AMessage::MediateGrant(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_GRANT),
+            A2AMessage::MediateDeny(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::MEDIATE_DENY),
+            A2AMessage::KeylistUpdate(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_UPDATE),
+            A2AMessage::KeylistUpdateResponse(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_UPDATE_RESPONSE),
+            A2AMessage::KeylistQuery(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST_QUERY),
+            A2AMessage::Keylist(msg) => set_a2a_message_type(msg, MessageFamilies::CoordinateMediation, A2AMessage::KEYLIST),
+            A2AMessage::PickupStatusRequest(msg) => set_a2a_message_type(msg, MessageFamilies::MessagePickup, A2AMessage::PICKUP_STATUS_REQUEST),
+            A2AMessage::PickupStatus(msg) => set_a2a_message_type(msg, MessageFamilies::MessagePickup, A2AMessage::PICKUP_STATUS),
+            A2AMessage::BatchPickup(msg) => set_a2a_message_type(msg, MessageFamilies::MessagePickup, A2AMessage::BATCH_PICKUP),
+            A2AMessage::Batch(msg) => set_a2a_message_type(msg, MessageFamilies::MessagePickup, A2AMessage::BATCH),
             A2AMessage::Generic(msg) => Ok(msg.clone())
         }.map_err(ser::Error::custom)?;
 
@@ -263,12 +483,26 @@ impl Default for MessageId {
     }
 }
 
+/// Version of Issue Credential and Present Proof protocols used within a thread.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ProtocolVersion {
+    V1,
+    V2,
+}
+
+impl Default for ProtocolVersion {
+    fn default() -> ProtocolVersion {
+        ProtocolVersion::V1
+    }
+}
+
 impl A2AMessage {
     const FORWARD: &'static str = "forward";
     const CONNECTION_INVITATION: &'static str = "invitation";
     const CONNECTION_REQUEST: &'static str = "request";
     const CONNECTION_RESPONSE: &'static str = "response";
     const CONNECTION_PROBLEM_REPORT: &'static str = "problem_report";
+    const DID_EXCHANGE_COMPLETE: &'static str = "complete";
     const PING: &'static str = "ping";
     const PING_RESPONSE: &'static str = "ping_response";
     const ACK: &'static str = "ack";
@@ -283,6 +517,88 @@ impl A2AMessage {
     const QUERY: &'static str = "query";
     const DISCLOSE: &'static str = "disclose";
     const BASIC_MESSAGE: &'static str = "message";
+    const REVOKE: &'static str = "revoke";
+    const MEDIATE_REQUEST: &'static str = "mediate-request";
+    const MEDIATE_GRANT: &'static str = "mediate-grant";
+    const MEDIATE_DENY: &'static str = "mediate-deny";
+    const KEYLIST_UPDATE: &'static str = "keylist-update";
+    const KEYLIST_UPDATE_RESPONSE: &'static str = "keylist-update-response";
+    const KEYLIST_QUERY: &'static str = "keylist-query";
+    const KEYLIST: &'static str = "keylist";
+    const PICKUP_STATUS_REQUEST: &'static str = "status-request";
+    const PICKUP_STATUS: &'static str = "status";
+    const BATCH_PICKUP: &'static str = "batch-pickup";
+    const BATCH: &'static str = "batch";
+
+    /// Id of the protocol thread the message belongs to:
+    /// `~thread.thid` decorator or `@id` of the message starting a new thread.
+    /// Revocation notification refers to the thread of the credential issuance.
+    pub fn thread_id(&self) -> Option<String> {
+        if let A2AMessage::RevocationNotification(notification) = self {
+            return Some(notification.thread_id.clone());
+        }
+
+        let message = ::serde_json::to_value(self).ok()?;
+
+        message["~thread"]["thid"].as_str()
+            .or_else(|| message["@id"].as_str())
+            .map(String::from)
+    }
+
+    pub fn protocol_version(&self) -> ProtocolVersion {
+        match self {
+            A2AMessage::CredentialProposalV2(_) |
+            A2AMessage::CredentialOfferV2(_) |
+            A2AMessage::CredentialRequestV2(_) |
+            A2AMessage::CredentialV2(_) |
+            A2AMessage::CredentialAckV2(_) |
+            A2AMessage::PresentationProposalV2(_) |
+            A2AMessage::PresentationRequestV2(_) |
+            A2AMessage::PresentationV2(_) |
+            A2AMessage::PresentationAckV2(_) => ProtocolVersion::V2,
+            _ => ProtocolVersion::V1
+        }
+    }
+
+    /// Converts 2.0 protocol messages into their 1.0 counterparts handled by the state machines.
+    pub fn into_v1(self) -> VcxResult<A2AMessage> {
+        let message = match self {
+            A2AMessage::CredentialProposalV2(msg) => A2AMessage::CredentialProposal(msg.try_into()?),
+            A2AMessage::CredentialOfferV2(msg) => A2AMessage::CredentialOffer(msg.try_into()?),
+            A2AMessage::CredentialRequestV2(msg) => A2AMessage::CredentialRequest(msg.try_into()?),
+            A2AMessage::CredentialV2(msg) => A2AMessage::Credential(msg.try_into()?),
+            A2AMessage::CredentialAckV2(msg) => A2AMessage::CredentialAck(msg),
+            A2AMessage::PresentationProposalV2(msg) => A2AMessage::PresentationProposal(msg.try_into()?),
+            A2AMessage::PresentationRequestV2(msg) => A2AMessage::PresentationRequest(msg.try_into()?),
+            A2AMessage::PresentationV2(msg) => A2AMessage::Presentation(msg.try_into()?),
+            A2AMessage::PresentationAckV2(msg) => A2AMessage::PresentationAck(msg),
+            msg => msg
+        };
+
+        Ok(message)
+    }
+
+    /// Converts 1.0 protocol messages produced by the state machines into the negotiated protocol version.
+    pub fn into_version(self, version: ProtocolVersion) -> VcxResult<A2AMessage> {
+        if version == ProtocolVersion::V1 {
+            return Ok(self);
+        }
+
+        let message = match self {
+            A2AMessage::CredentialProposal(msg) => A2AMessage::CredentialProposalV2(msg.try_into()?),
+            A2AMessage::CredentialOffer(msg) => A2AMessage::CredentialOfferV2(msg.into()),
+            A2AMessage::CredentialRequest(msg) => A2AMessage::CredentialRequestV2(msg.into()),
+            A2AMessage::Credential(msg) => A2AMessage::CredentialV2(msg.into()),
+            A2AMessage::CredentialAck(msg) => A2AMessage::CredentialAckV2(msg),
+            A2AMessage::PresentationProposal(msg) => A2AMessage::PresentationProposalV2(msg.try_into()?),
+            A2AMessage::PresentationRequest(msg) => A2AMessage::PresentationRequestV2(msg.into()),
+            A2AMessage::Presentation(msg) => A2AMessage::PresentationV2(msg.into()),
+            A2AMessage::PresentationAck(msg) => A2AMessage::PresentationAckV2(msg),
+            msg => msg
+        };
+
+        Ok(message)
+    }
 }
 
 #[macro_export]
@@ -303,3 +619,33 @@ macro_rules! a2a_message {
         }
     );
 }
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::issuance::credential_offer::tests::_credential_offer;
+    use v3::messages::proof_presentation::presentation_request::tests::_presentation_request;
+
+    #[test]
+    fn test_a2a_message_v2_serialization_works() {
+        let message = A2AMessage::CredentialOffer(_credential_offer()).into_version(ProtocolVersion::V2).unwrap();
+
+        let json = ::serde_json::to_value(&message).unwrap();
+        assert!(json["@type"].as_str().unwrap().ends_with("issue-credential/2.0/offer-credential"));
+
+        let message: A2AMessage = ::serde_json::from_value(json).unwrap();
+        assert_match!(A2AMessage::CredentialOfferV2(_), message);
+        assert_eq!(ProtocolVersion::V2, message.protocol_version());
+    }
+
+    #[test]
+    fn test_a2a_message_into_version_works() {
+        let message = A2AMessage::PresentationRequest(_presentation_request());
+
+        assert_eq!(message.clone(), message.clone().into_version(ProtocolVersion::V1).unwrap());
+
+        let message_v2 = message.clone().into_version(ProtocolVersion::V2).unwrap();
+        assert_match!(A2AMessage::PresentationRequestV2(_), message_v2);
+        assert_eq!(message, message_v2.into_v1().unwrap());
+    }
+}
@@ -20,10 +20,17 @@ impl ProtocolRegistry {
                 family @ MessageFamilies::ReportProblem |
                 family @ MessageFamilies::Notification |
                 family @ MessageFamilies::Connections |
+                family @ MessageFamilies::DidExchange |
+                family @ MessageFamilies::OutOfBand |
                 family @ MessageFamilies::CredentialIssuance |
+                family @ MessageFamilies::CredentialIssuanceV2 |
                 family @ MessageFamilies::PresentProof |
+                family @ MessageFamilies::PresentProofV2 |
                 family @ MessageFamilies::TrustPing |
                 family @ MessageFamilies::Basicmessage |
+                family @ MessageFamilies::RevocationNotification |
+                family @ MessageFamilies::CoordinateMediation |
+                family @ MessageFamilies::MessagePickup |
                 family @ MessageFamilies::DiscoveryFeatures => registry.add_protocol(&actors, family),
                 MessageFamilies::Signature => {}
                 MessageFamilies::Unknown(_) => {}
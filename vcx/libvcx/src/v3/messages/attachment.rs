@@ -1,4 +1,5 @@
 use std::str::from_utf8;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde_json;
 
 use error::{VcxResult, VcxError, VcxErrorKind};
@@ -35,6 +36,52 @@ impl Attachments {
             _ => return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, "Unsupported Attachment type"))
         }
     }
+
+    pub fn id(&self) -> Option<String> {
+        match self.get() {
+            Some(Attachment::JSON(ref attach)) => Some(attach.id.to_string()),
+            _ => None
+        }
+    }
+
+    /// Selects attachment described by the format entry of Aries 2.0 protocol messages.
+    pub fn select(&self, formats: &[AttachmentFormat], format: &str) -> VcxResult<Attachments> {
+        let attach_id = formats.iter()
+            .find(|attachment_format| attachment_format.format == format)
+            .map(|attachment_format| attachment_format.attach_id.as_str())
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidMessages, format!("Attachment of `{}` format not found", format)))?;
+
+        self.0.iter()
+            .find(|attachment| match attachment {
+                Attachment::JSON(ref attach) => attach.id.to_string() == attach_id,
+                Attachment::Blank => false
+            })
+            .map(|attachment| Attachments(vec![attachment.clone()]))
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidMessages, format!("Attachment not found: {}", attach_id)))
+    }
+}
+
+/// Binds an attachment of Aries 2.0 protocol message to the format of its content.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct AttachmentFormat {
+    pub attach_id: String,
+    pub format: String,
+}
+
+impl AttachmentFormat {
+    pub const INDY_CREDENTIAL_FILTER: &'static str = "hlindy/cred-filter@v2.0";
+    pub const INDY_CREDENTIAL_OFFER: &'static str = "hlindy/cred-abstract@v2.0";
+    pub const INDY_CREDENTIAL_REQUEST: &'static str = "hlindy/cred-req@v2.0";
+    pub const INDY_CREDENTIAL: &'static str = "hlindy/cred@v2.0";
+    pub const INDY_PROOF_REQUEST: &'static str = "hlindy/proof-req@v2.0";
+    pub const INDY_PROOF: &'static str = "hlindy/proof@v2.0";
+
+    /// Describes the single attachment of the message with the given format.
+    pub fn for_attachments(attachments: &Attachments, format: &str) -> Vec<AttachmentFormat> {
+        attachments.id()
+            .map(|attach_id| vec![AttachmentFormat { attach_id, format: format.to_string() }])
+            .unwrap_or_default()
+    }
 }
 
 #[serde(tag = "mime-type")]
@@ -52,18 +99,60 @@ pub struct Json {
     data: AttachmentData,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AttachmentId {
-    #[serde(rename = "libindy-cred-offer-0")]
     CredentialOffer,
-    #[serde(rename = "libindy-cred-request-0")]
     CredentialRequest,
-    #[serde(rename = "libindy-cred-0")]
     Credential,
-    #[serde(rename = "libindy-request-presentation-0")]
+    CredentialFilter,
+    PresentationProposal,
     PresentationRequest,
-    #[serde(rename = "libindy-presentation-0")]
-    Presentation
+    Presentation,
+    Other(String),
+}
+
+impl ::std::string::ToString for AttachmentId {
+    fn to_string(&self) -> String {
+        match self {
+            AttachmentId::CredentialOffer => "libindy-cred-offer-0".to_string(),
+            AttachmentId::CredentialRequest => "libindy-cred-request-0".to_string(),
+            AttachmentId::Credential => "libindy-cred-0".to_string(),
+            AttachmentId::CredentialFilter => "libindy-cred-filter-0".to_string(),
+            AttachmentId::PresentationProposal => "libindy-presentation-proposal-0".to_string(),
+            AttachmentId::PresentationRequest => "libindy-request-presentation-0".to_string(),
+            AttachmentId::Presentation => "libindy-presentation-0".to_string(),
+            AttachmentId::Other(id) => id.to_string(),
+        }
+    }
+}
+
+impl From<String> for AttachmentId {
+    fn from(id: String) -> Self {
+        match id.as_str() {
+            "libindy-cred-offer-0" => AttachmentId::CredentialOffer,
+            "libindy-cred-request-0" => AttachmentId::CredentialRequest,
+            "libindy-cred-0" => AttachmentId::Credential,
+            "libindy-cred-filter-0" => AttachmentId::CredentialFilter,
+            "libindy-presentation-proposal-0" => AttachmentId::PresentationProposal,
+            "libindy-request-presentation-0" => AttachmentId::PresentationRequest,
+            "libindy-presentation-0" => AttachmentId::Presentation,
+            _ => AttachmentId::Other(id)
+        }
+    }
+}
+
+impl Serialize for AttachmentId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for AttachmentId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        String::deserialize(deserializer)
+            .map(AttachmentId::from)
+            .map_err(de::Error::custom)
+    }
 }
 
 impl Json {
@@ -134,6 +223,32 @@ pub mod tests {
         assert_eq!(_json().to_string(), json_attachment.get_data().unwrap());
     }
 
+    #[test]
+    fn test_attachment_id_works() {
+        assert_eq!(json!("libindy-cred-0"), json!(AttachmentId::Credential));
+        assert_eq!(AttachmentId::Credential, ::serde_json::from_value(json!("libindy-cred-0")).unwrap());
+        assert_eq!(AttachmentId::Other("indy".to_string()), ::serde_json::from_value(json!("indy")).unwrap());
+        assert_eq!(json!("indy"), json!(AttachmentId::Other("indy".to_string())));
+    }
+
+    #[test]
+    fn test_select_attachment_by_format_works() {
+        let mut attachments = Attachments::new();
+        attachments.add_json_attachment(AttachmentId::Other("other".to_string()), json!({"field": "other"}), AttachmentEncoding::Base64).unwrap();
+        attachments.add_json_attachment(AttachmentId::Credential, _json(), AttachmentEncoding::Base64).unwrap();
+
+        let formats = vec![
+            AttachmentFormat { attach_id: "other".to_string(), format: "other/format@v1.0".to_string() },
+            AttachmentFormat { attach_id: AttachmentId::Credential.to_string(), format: AttachmentFormat::INDY_CREDENTIAL.to_string() },
+        ];
+
+        let selected = attachments.select(&formats, AttachmentFormat::INDY_CREDENTIAL).unwrap();
+        assert_eq!(_json().to_string(), selected.content().unwrap());
+        assert_eq!(formats[1..].to_vec(), AttachmentFormat::for_attachments(&selected, AttachmentFormat::INDY_CREDENTIAL));
+
+        assert_eq!(VcxErrorKind::InvalidMessages, attachments.select(&formats, AttachmentFormat::INDY_PROOF).unwrap_err().kind());
+    }
+
     #[test]
     fn test_attachments_works() {
         {
@@ -0,0 +1,43 @@
+use messages::thread::Thread;
+use v3::messages::a2a::{A2AMessage, MessageId};
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct Complete {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl Complete {
+    pub fn create() -> Complete {
+        Complete::default()
+    }
+
+    pub fn set_parent_thread_id(mut self, pthid: &str) -> Complete {
+        self.thread.pthid = Some(pthid.to_string());
+        self
+    }
+}
+
+threadlike!(Complete);
+a2a_message!(Complete, DidExchangeComplete);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    pub fn _complete() -> Complete {
+        Complete::create()
+            .set_thread_id("test_request_id")
+            .set_parent_thread_id("test_invitation_id")
+    }
+
+    #[test]
+    fn test_complete_build_works() {
+        let complete = _complete();
+
+        assert!(complete.from_thread("test_request_id"));
+        assert_eq!(Some(String::from("test_invitation_id")), complete.thread.pthid);
+    }
+}
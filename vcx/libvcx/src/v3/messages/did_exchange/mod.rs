@@ -0,0 +1,4 @@
+pub mod request;
+pub mod response;
+pub mod complete;
+pub mod signed_attachment;
@@ -0,0 +1,120 @@
+use error::prelude::*;
+use messages::thread::Thread;
+use v3::messages::a2a::{A2AMessage, MessageId};
+use v3::messages::connection::request::{Request, ConnectionData};
+use v3::messages::did_exchange::signed_attachment::SignedAttachment;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct ExchangeRequest {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    pub label: String,
+    pub did: String,
+    #[serde(rename = "did_doc~attach")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub did_doc_attach: Option<SignedAttachment>,
+}
+
+impl ExchangeRequest {
+    /// Wraps Connections 1.0 request built by the state machine and signs its DIDDoc with the pairwise key.
+    /// `pthid` refers to the invitation the request is answering.
+    pub fn from_request(request: &Request, pthid: &str, key: &str) -> VcxResult<ExchangeRequest> {
+        let mut thread = Thread::new();
+        thread.pthid = Some(pthid.to_string());
+
+        Ok(ExchangeRequest {
+            id: request.id.clone(),
+            thread,
+            label: request.label.clone(),
+            did: request.connection.did.clone(),
+            did_doc_attach: Some(SignedAttachment::create(&request.connection.did_doc).sign(key)?),
+        })
+    }
+
+    /// Verifies that the attached DIDDoc is signed with its own recipient key and converts it into Connections 1.0 request.
+    pub fn decode(self) -> VcxResult<Request> {
+        let did_doc_attach = self.did_doc_attach
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "DID Exchange request without attached DIDDoc is not supported"))?;
+
+        let did_doc = did_doc_attach.did_doc()?;
+
+        let key = did_doc.recipient_keys().get(0).cloned()
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "DID Exchange request DIDDoc does not contain recipient key"))?;
+
+        did_doc_attach.verify(&key)?;
+
+        Ok(Request {
+            id: self.id,
+            label: self.label,
+            connection: ConnectionData {
+                did: self.did,
+                did_doc,
+            },
+        })
+    }
+
+    pub fn parent_thread_id(&self) -> Option<&str> {
+        self.thread.pthid.as_ref().map(String::as_str)
+    }
+}
+
+a2a_message!(ExchangeRequest, DidExchangeRequest);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::request::tests::_request;
+    use utils::libindy::tests::test_setup;
+
+    fn _pthid() -> String {
+        String::from("test_invitation_id")
+    }
+
+    pub fn _exchange_request(key: &str) -> (Request, ExchangeRequest) {
+        let request = _request().set_keys(vec![key.to_string()], vec![]);
+        let exchange_request = ExchangeRequest::from_request(&request, &_pthid(), key).unwrap();
+        (request, exchange_request)
+    }
+
+    #[test]
+    fn test_exchange_request_decode_works() {
+        let setup = test_setup::key();
+
+        let (request, exchange_request) = _exchange_request(&setup.key);
+        assert_eq!(Some(_pthid().as_str()), exchange_request.parent_thread_id());
+        assert_eq!(request, exchange_request.decode().unwrap());
+    }
+
+    #[test]
+    fn test_exchange_request_decode_fails_without_did_doc() {
+        let setup = test_setup::key();
+
+        let (_, mut exchange_request) = _exchange_request(&setup.key);
+        exchange_request.did_doc_attach = None;
+
+        assert!(exchange_request.decode().is_err());
+    }
+
+    #[test]
+    fn test_exchange_request_decode_fails_for_unsigned_did_doc() {
+        let setup = test_setup::key();
+
+        let (request, mut exchange_request) = _exchange_request(&setup.key);
+        exchange_request.did_doc_attach = Some(SignedAttachment::create(&request.connection.did_doc));
+
+        assert_eq!(VcxErrorKind::InvalidJson, exchange_request.decode().unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_exchange_request_decode_fails_for_did_doc_signed_by_other_key() {
+        let setup = test_setup::key();
+
+        let (_, mut exchange_request) = _exchange_request(&setup.key);
+        let other_request = _request().set_keys(vec![::utils::constants::VERKEY.to_string()], vec![]);
+        exchange_request.did_doc_attach = Some(SignedAttachment::create(&other_request.connection.did_doc).sign(&setup.key).unwrap());
+
+        assert_eq!(VcxErrorKind::InvalidJson, exchange_request.decode().unwrap_err().kind());
+    }
+}
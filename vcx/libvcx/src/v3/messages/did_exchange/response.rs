@@ -0,0 +1,68 @@
+use error::prelude::*;
+use messages::thread::Thread;
+use v3::messages::a2a::{A2AMessage, MessageId};
+use v3::messages::connection::response::{Response, ConnectionData};
+use v3::messages::did_exchange::signed_attachment::SignedAttachment;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ExchangeResponse {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    pub did: String,
+    #[serde(rename = "did_doc~attach")]
+    pub did_doc_attach: SignedAttachment,
+}
+
+impl ExchangeResponse {
+    /// Signs DIDDoc of Connections 1.0 response built by the state machine with the invitation key.
+    pub fn from_response(response: &Response, key: &str) -> VcxResult<ExchangeResponse> {
+        Ok(ExchangeResponse {
+            id: response.id.clone(),
+            thread: response.thread.clone(),
+            did: response.connection.did.clone(),
+            did_doc_attach: SignedAttachment::create(&response.connection.did_doc).sign(key)?,
+        })
+    }
+
+    pub fn decode(self, key: &str) -> VcxResult<Response> {
+        self.did_doc_attach.verify(key)?;
+
+        Ok(Response {
+            id: self.id,
+            thread: self.thread,
+            connection: ConnectionData {
+                did: self.did,
+                did_doc: self.did_doc_attach.did_doc()?,
+            },
+            please_ack: None,
+        })
+    }
+}
+
+threadlike!(ExchangeResponse);
+a2a_message!(ExchangeResponse, DidExchangeResponse);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::response::tests::_response;
+    use utils::libindy::tests::test_setup;
+
+    #[test]
+    fn test_exchange_response_decode_works() {
+        let setup = test_setup::key();
+
+        let response = ExchangeResponse::from_response(&_response(), &setup.key).unwrap();
+        assert_eq!(_response(), response.decode(&setup.key).unwrap());
+    }
+
+    #[test]
+    fn test_exchange_response_decode_fails_for_other_key() {
+        let setup = test_setup::key();
+
+        let response = ExchangeResponse::from_response(&_response(), &setup.key).unwrap();
+        assert!(response.decode("CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW").is_err());
+    }
+}
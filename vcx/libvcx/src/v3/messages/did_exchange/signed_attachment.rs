@@ -0,0 +1,127 @@
+use base64;
+
+use error::prelude::*;
+use utils::libindy::crypto;
+use v3::messages::a2a::MessageId;
+use v3::messages::connection::did_doc::DidDoc;
+use v3::utils::did_key::{to_did_key, from_did_key};
+
+/// DIDDoc attached to DID Exchange messages.
+/// Response attachments are signed by the invitation key with the detached JWS.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignedAttachment {
+    #[serde(rename = "@id")]
+    pub id: String,
+    #[serde(rename = "mime-type")]
+    pub mime_type: String,
+    pub data: SignedAttachmentData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SignedAttachmentData {
+    pub base64: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jws: Option<Jws>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Jws {
+    pub header: JwsHeader,
+    pub protected: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JwsHeader {
+    pub kid: String,
+}
+
+impl SignedAttachment {
+    pub fn create(did_doc: &DidDoc) -> SignedAttachment {
+        SignedAttachment {
+            id: MessageId::new().0,
+            mime_type: String::from("application/json"),
+            data: SignedAttachmentData {
+                base64: base64::encode_config(&json!(did_doc).to_string(), base64::URL_SAFE_NO_PAD),
+                jws: None,
+            },
+        }
+    }
+
+    pub fn sign(mut self, key: &str) -> VcxResult<SignedAttachment> {
+        let kid = to_did_key(key)?;
+
+        let protected = json!({"alg": "EdDSA", "kid": kid}).to_string();
+        let protected = base64::encode_config(&protected, base64::URL_SAFE_NO_PAD);
+
+        let signature = crypto::sign(key, format!("{}.{}", protected, self.data.base64).as_bytes())?;
+        let signature = base64::encode_config(&signature, base64::URL_SAFE_NO_PAD);
+
+        self.data.jws = Some(Jws { header: JwsHeader { kid }, protected, signature });
+        Ok(self)
+    }
+
+    pub fn verify(&self, key: &str) -> VcxResult<()> {
+        let jws = self.data.jws.as_ref()
+            .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "DIDDoc attachment is not signed"))?;
+
+        if from_did_key(&jws.header.kid)? != key {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, format!("DIDDoc attachment is signed by unexpected key, expected: {}", key)));
+        }
+
+        let signature = _decode(&jws.signature)?;
+
+        if !crypto::verify(key, format!("{}.{}", jws.protected, self.data.base64).as_bytes(), &signature)? {
+            return Err(VcxError::from_msg(VcxErrorKind::InvalidJson, "DIDDoc attachment signature is invalid"));
+        }
+
+        Ok(())
+    }
+
+    pub fn did_doc(&self) -> VcxResult<DidDoc> {
+        let did_doc = _decode(&self.data.base64)?;
+
+        ::serde_json::from_slice(&did_doc)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize attached DIDDoc: {}", err)))
+    }
+}
+
+// base64url is expected, but some agents send standard base64 with padding
+fn _decode(data: &str) -> VcxResult<Vec<u8>> {
+    base64::decode_config(data.trim_end_matches('='), base64::URL_SAFE_NO_PAD)
+        .or_else(|_| base64::decode(data))
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot decode DIDDoc attachment: {:?}", err)))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::did_doc::tests::_did_doc;
+    use utils::libindy::tests::test_setup;
+
+    pub fn _signed_attachment() -> SignedAttachment {
+        SignedAttachment::create(&_did_doc())
+    }
+
+    #[test]
+    fn test_signed_attachment_did_doc_works() {
+        assert_eq!(_did_doc(), _signed_attachment().did_doc().unwrap());
+    }
+
+    #[test]
+    fn test_signed_attachment_sign_works() {
+        let setup = test_setup::key();
+
+        let attachment = _signed_attachment().sign(&setup.key).unwrap();
+        attachment.verify(&setup.key).unwrap();
+        assert_eq!(_did_doc(), attachment.did_doc().unwrap());
+    }
+
+    #[test]
+    fn test_signed_attachment_verify_fails_for_unsigned_attachment() {
+        let setup = test_setup::key();
+
+        let res = _signed_attachment().verify(&setup.key);
+        assert_eq!(VcxErrorKind::InvalidJson, res.unwrap_err().kind());
+    }
+}
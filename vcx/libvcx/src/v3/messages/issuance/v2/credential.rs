@@ -0,0 +1,72 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat};
+use v3::messages::ack::PleaseAck;
+use v3::messages::issuance::credential::Credential;
+use error::{VcxError, VcxResult};
+use messages::thread::Thread;
+use std::convert::TryInto;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CredentialV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "credentials~attach")]
+    pub credentials_attach: Attachments,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    #[serde(rename = "~please_ack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub please_ack: Option<PleaseAck>,
+}
+
+threadlike!(CredentialV2);
+a2a_message!(CredentialV2);
+
+impl From<Credential> for CredentialV2 {
+    fn from(credential: Credential) -> Self {
+        CredentialV2 {
+            id: credential.id,
+            comment: credential.comment,
+            formats: AttachmentFormat::for_attachments(&credential.credentials_attach, AttachmentFormat::INDY_CREDENTIAL),
+            credentials_attach: credential.credentials_attach,
+            thread: credential.thread,
+            please_ack: credential.please_ack,
+        }
+    }
+}
+
+impl TryInto<Credential> for CredentialV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<Credential> {
+        Ok(Credential {
+            credentials_attach: self.credentials_attach.select(&self.formats, AttachmentFormat::INDY_CREDENTIAL)?,
+            id: self.id,
+            comment: self.comment,
+            thread: self.thread,
+            please_ack: self.please_ack,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::issuance::credential::tests::_credential;
+
+    pub fn _credential_v2() -> CredentialV2 {
+        _credential().into()
+    }
+
+    #[test]
+    fn test_credential_v2_conversion_works() {
+        let credential = _credential_v2();
+        assert_eq!(AttachmentFormat::INDY_CREDENTIAL, credential.formats[0].format);
+
+        let credential: Credential = credential.try_into().unwrap();
+        assert_eq!(_credential(), credential);
+    }
+}
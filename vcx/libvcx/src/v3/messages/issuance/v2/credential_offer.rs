@@ -0,0 +1,83 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat};
+use v3::messages::issuance::CredentialPreviewData;
+use v3::messages::issuance::credential_offer::CredentialOffer;
+use v3::messages::issuance::v2::{credential_preview_v1, credential_preview_v2};
+use error::{VcxError, VcxResult};
+use messages::thread::Thread;
+use std::convert::TryInto;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CredentialOfferV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub credential_preview: CredentialPreviewData,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "offers~attach")]
+    pub offers_attach: Attachments,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "~thread")]
+    pub thread: Option<Thread>
+}
+
+a2a_message!(CredentialOfferV2);
+
+impl From<CredentialOffer> for CredentialOfferV2 {
+    fn from(offer: CredentialOffer) -> Self {
+        CredentialOfferV2 {
+            id: offer.id,
+            comment: offer.comment,
+            credential_preview: credential_preview_v2(offer.credential_preview),
+            formats: AttachmentFormat::for_attachments(&offer.offers_attach, AttachmentFormat::INDY_CREDENTIAL_OFFER),
+            offers_attach: offer.offers_attach,
+            thread: offer.thread,
+        }
+    }
+}
+
+impl TryInto<CredentialOffer> for CredentialOfferV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<CredentialOffer> {
+        Ok(CredentialOffer {
+            offers_attach: self.offers_attach.select(&self.formats, AttachmentFormat::INDY_CREDENTIAL_OFFER)?,
+            id: self.id,
+            comment: self.comment,
+            credential_preview: credential_preview_v1(self.credential_preview),
+            thread: self.thread,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::a2a::message_family::MessageFamilies;
+    use v3::messages::issuance::credential_offer::tests::_credential_offer;
+
+    pub fn _credential_offer_v2() -> CredentialOfferV2 {
+        _credential_offer().into()
+    }
+
+    #[test]
+    fn test_credential_offer_v2_conversion_works() {
+        let offer = _credential_offer_v2();
+
+        assert_eq!(MessageFamilies::CredentialIssuanceV2, offer.credential_preview._type.family);
+        assert_eq!(vec![AttachmentFormat { attach_id: "libindy-cred-offer-0".to_string(), format: AttachmentFormat::INDY_CREDENTIAL_OFFER.to_string() }], offer.formats);
+
+        let offer: CredentialOffer = offer.try_into().unwrap();
+        assert_eq!(_credential_offer(), offer);
+    }
+
+    #[test]
+    fn test_credential_offer_v2_conversion_fails_for_unknown_format() {
+        let mut offer = _credential_offer_v2();
+        offer.formats[0].format = String::from("other/cred-abstract@v1.0");
+
+        let res: VcxResult<CredentialOffer> = offer.try_into();
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,87 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat, AttachmentId};
+use v3::messages::issuance::CredentialPreviewData;
+use v3::messages::issuance::credential_proposal::CredentialProposal;
+use v3::messages::issuance::v2::{credential_preview_v1, credential_preview_v2};
+use error::{VcxError, VcxResult, VcxErrorKind};
+use messages::thread::Thread;
+use std::convert::TryInto;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CredentialProposalV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_preview: Option<CredentialPreviewData>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "filters~attach")]
+    pub filters_attach: Attachments,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(rename = "~thread")]
+    pub thread: Option<Thread>
+}
+
+a2a_message!(CredentialProposalV2);
+
+impl TryInto<CredentialProposalV2> for CredentialProposal {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<CredentialProposalV2> {
+        let filter = json!({
+            "schema_id": self.schema_id,
+            "cred_def_id": self.cred_def_id,
+        });
+
+        let mut filters_attach = Attachments::new();
+        filters_attach.add_base64_encoded_json_attachment(AttachmentId::CredentialFilter, filter)?;
+
+        Ok(CredentialProposalV2 {
+            id: self.id,
+            comment: self.comment,
+            credential_preview: Some(credential_preview_v2(self.credential_proposal)),
+            formats: AttachmentFormat::for_attachments(&filters_attach, AttachmentFormat::INDY_CREDENTIAL_FILTER),
+            filters_attach,
+            thread: self.thread,
+        })
+    }
+}
+
+impl TryInto<CredentialProposal> for CredentialProposalV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<CredentialProposal> {
+        let filter = self.filters_attach.select(&self.formats, AttachmentFormat::INDY_CREDENTIAL_FILTER)?.content()?;
+        let filter: ::serde_json::Value = ::serde_json::from_str(&filter)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Indy Credential Filter: {:?}", err)))?;
+
+        Ok(CredentialProposal {
+            id: self.id,
+            comment: self.comment,
+            credential_proposal: credential_preview_v1(self.credential_preview.unwrap_or_default()),
+            schema_id: filter["schema_id"].as_str().map(String::from).unwrap_or_default(),
+            cred_def_id: filter["cred_def_id"].as_str().map(String::from).unwrap_or_default(),
+            thread: self.thread,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::issuance::credential_proposal::tests::_credential_proposal;
+
+    pub fn _credential_proposal_v2() -> CredentialProposalV2 {
+        _credential_proposal().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_credential_proposal_v2_conversion_works() {
+        let proposal = _credential_proposal_v2();
+        assert_eq!(AttachmentFormat::INDY_CREDENTIAL_FILTER, proposal.formats[0].format);
+
+        let proposal: CredentialProposal = proposal.try_into().unwrap();
+        assert_eq!(_credential_proposal(), proposal);
+    }
+}
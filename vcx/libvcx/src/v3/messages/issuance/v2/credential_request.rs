@@ -0,0 +1,66 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat};
+use v3::messages::issuance::credential_request::CredentialRequest;
+use error::{VcxError, VcxResult};
+use messages::thread::Thread;
+use std::convert::TryInto;
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Default)]
+pub struct CredentialRequestV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "requests~attach")]
+    pub requests_attach: Attachments,
+    #[serde(rename = "~thread")]
+    pub thread: Thread
+}
+
+threadlike!(CredentialRequestV2);
+a2a_message!(CredentialRequestV2);
+
+impl From<CredentialRequest> for CredentialRequestV2 {
+    fn from(request: CredentialRequest) -> Self {
+        CredentialRequestV2 {
+            id: request.id,
+            comment: request.comment,
+            formats: AttachmentFormat::for_attachments(&request.requests_attach, AttachmentFormat::INDY_CREDENTIAL_REQUEST),
+            requests_attach: request.requests_attach,
+            thread: request.thread,
+        }
+    }
+}
+
+impl TryInto<CredentialRequest> for CredentialRequestV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<CredentialRequest> {
+        Ok(CredentialRequest {
+            requests_attach: self.requests_attach.select(&self.formats, AttachmentFormat::INDY_CREDENTIAL_REQUEST)?,
+            id: self.id,
+            comment: self.comment,
+            thread: self.thread,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::issuance::credential_request::tests::_credential_request;
+
+    pub fn _credential_request_v2() -> CredentialRequestV2 {
+        _credential_request().into()
+    }
+
+    #[test]
+    fn test_credential_request_v2_conversion_works() {
+        let request = _credential_request_v2();
+        assert_eq!(AttachmentFormat::INDY_CREDENTIAL_REQUEST, request.formats[0].format);
+
+        let request: CredentialRequest = request.try_into().unwrap();
+        assert_eq!(_credential_request(), request);
+    }
+}
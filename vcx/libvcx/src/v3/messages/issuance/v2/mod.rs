@@ -0,0 +1,29 @@
+/*
+    Messages of Aries Issue Credential 2.0 protocol (RFC 0453).
+    Every message describes its attachments with `formats` entries. Only Indy formats are supported:
+    messages are converted into their 1.0 counterparts on receiving and back on sending,
+    so both versions are handled by the same state machines.
+*/
+
+pub mod credential_proposal;
+pub mod credential_offer;
+pub mod credential_request;
+pub mod credential;
+
+use v3::messages::a2a::message_type::MessageType;
+use v3::messages::a2a::message_family::MessageFamilies;
+use v3::messages::issuance::CredentialPreviewData;
+
+fn credential_preview_v2(credential_preview: CredentialPreviewData) -> CredentialPreviewData {
+    CredentialPreviewData {
+        _type: MessageType::build(MessageFamilies::CredentialIssuanceV2, "credential-preview"),
+        ..credential_preview
+    }
+}
+
+fn credential_preview_v1(credential_preview: CredentialPreviewData) -> CredentialPreviewData {
+    CredentialPreviewData {
+        _type: CredentialPreviewData::default()._type,
+        ..credential_preview
+    }
+}
@@ -0,0 +1,254 @@
+use messages::thread::Thread;
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::transport::Transport;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum KeylistAction {
+    Add,
+    Remove,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum KeylistUpdateResult {
+    ClientError,
+    ServerError,
+    NoChange,
+    Success,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeylistUpdateItem {
+    pub recipient_key: String,
+    pub action: KeylistAction,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeylistUpdated {
+    pub recipient_key: String,
+    pub action: KeylistAction,
+    pub result: KeylistUpdateResult,
+}
+
+impl KeylistUpdated {
+    /// Key list of the mediator matches the requested action.
+    pub fn is_applied(&self) -> bool {
+        self.result == KeylistUpdateResult::Success || self.result == KeylistUpdateResult::NoChange
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct KeylistUpdate {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub updates: Vec<KeylistUpdateItem>,
+    #[serde(rename = "~transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+}
+
+impl KeylistUpdate {
+    pub fn create() -> KeylistUpdate {
+        KeylistUpdate::default()
+    }
+
+    pub fn add_key(mut self, recipient_key: &str) -> KeylistUpdate {
+        self.updates.push(KeylistUpdateItem { recipient_key: recipient_key.to_string(), action: KeylistAction::Add });
+        self
+    }
+
+    pub fn remove_key(mut self, recipient_key: &str) -> KeylistUpdate {
+        self.updates.push(KeylistUpdateItem { recipient_key: recipient_key.to_string(), action: KeylistAction::Remove });
+        self
+    }
+}
+
+return_route!(KeylistUpdate);
+a2a_message!(KeylistUpdate);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct KeylistUpdateResponse {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub updated: Vec<KeylistUpdated>,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl KeylistUpdateResponse {
+    pub fn create() -> KeylistUpdateResponse {
+        KeylistUpdateResponse::default()
+    }
+
+    pub fn set_updated(mut self, updated: Vec<KeylistUpdated>) -> KeylistUpdateResponse {
+        self.updated = updated;
+        self
+    }
+}
+
+threadlike!(KeylistUpdateResponse);
+a2a_message!(KeylistUpdateResponse);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Paginate {
+    pub limit: u32,
+    pub offset: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct KeylistQuery {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paginate: Option<Paginate>,
+    #[serde(rename = "~transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+}
+
+impl KeylistQuery {
+    pub fn create() -> KeylistQuery {
+        KeylistQuery::default()
+    }
+
+    pub fn set_paginate(mut self, limit: u32, offset: u32) -> KeylistQuery {
+        self.paginate = Some(Paginate { limit, offset });
+        self
+    }
+}
+
+return_route!(KeylistQuery);
+a2a_message!(KeylistQuery);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KeylistKey {
+    pub recipient_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Pagination {
+    pub count: u32,
+    pub offset: u32,
+    pub remaining: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Keylist {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub keys: Vec<KeylistKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<Pagination>,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl Keylist {
+    pub fn create() -> Keylist {
+        Keylist::default()
+    }
+
+    pub fn set_keys(mut self, keys: Vec<String>) -> Keylist {
+        self.keys = keys.into_iter().map(|recipient_key| KeylistKey { recipient_key }).collect();
+        self
+    }
+
+    pub fn recipient_keys(&self) -> Vec<String> {
+        self.keys.iter().map(|key| key.recipient_key.clone()).collect()
+    }
+}
+
+threadlike!(Keylist);
+a2a_message!(Keylist);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::response::tests::{_thread, _thread_id};
+
+    pub fn _recipient_key() -> String {
+        String::from("GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL")
+    }
+
+    pub fn _keylist_update() -> KeylistUpdate {
+        KeylistUpdate {
+            id: MessageId::id(),
+            updates: vec![KeylistUpdateItem { recipient_key: _recipient_key(), action: KeylistAction::Add }],
+            transport: Some(Transport::return_route_all()),
+        }
+    }
+
+    pub fn _keylist_update_response() -> KeylistUpdateResponse {
+        KeylistUpdateResponse {
+            id: MessageId::id(),
+            updated: vec![KeylistUpdated { recipient_key: _recipient_key(), action: KeylistAction::Add, result: KeylistUpdateResult::Success }],
+            thread: _thread(),
+        }
+    }
+
+    pub fn _keylist_query() -> KeylistQuery {
+        KeylistQuery {
+            id: MessageId::id(),
+            paginate: None,
+            transport: Some(Transport::return_route_all()),
+        }
+    }
+
+    pub fn _keylist() -> Keylist {
+        Keylist {
+            id: MessageId::id(),
+            keys: vec![KeylistKey { recipient_key: _recipient_key() }],
+            pagination: None,
+            thread: _thread(),
+        }
+    }
+
+    #[test]
+    fn test_keylist_update_build_works() {
+        let update: KeylistUpdate = KeylistUpdate::create()
+            .add_key(&_recipient_key())
+            .request_return_route();
+
+        assert_eq!(_keylist_update(), update);
+    }
+
+    #[test]
+    fn test_keylist_update_response_build_works() {
+        let response: KeylistUpdateResponse = KeylistUpdateResponse::create()
+            .set_updated(_keylist_update_response().updated)
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_keylist_update_response(), response);
+        assert!(response.updated[0].is_applied());
+    }
+
+    #[test]
+    fn test_keylist_query_build_works() {
+        let query: KeylistQuery = KeylistQuery::create()
+            .request_return_route();
+
+        assert_eq!(_keylist_query(), query);
+    }
+
+    #[test]
+    fn test_keylist_build_works() {
+        let keylist: Keylist = Keylist::create()
+            .set_keys(vec![_recipient_key()])
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_keylist(), keylist);
+        assert_eq!(vec![_recipient_key()], keylist.recipient_keys());
+    }
+
+    #[test]
+    fn test_keylist_update_serialization_works() {
+        let message = json!(_keylist_update().to_a2a_message());
+
+        assert_eq!("did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/coordinate-mediation/1.0/keylist-update", message["@type"]);
+        assert_eq!("add", message["updates"][0]["action"]);
+
+        let update: A2AMessage = ::serde_json::from_value(message).unwrap();
+        assert_eq!(A2AMessage::KeylistUpdate(_keylist_update()), update);
+    }
+}
@@ -0,0 +1,141 @@
+use messages::thread::Thread;
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::transport::Transport;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MediateRequest {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+}
+
+impl MediateRequest {
+    pub fn create() -> MediateRequest {
+        MediateRequest::default()
+    }
+}
+
+return_route!(MediateRequest);
+a2a_message!(MediateRequest);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MediateGrant {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub endpoint: String,
+    pub routing_keys: Vec<String>,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl MediateGrant {
+    pub fn create() -> MediateGrant {
+        MediateGrant::default()
+    }
+
+    pub fn set_endpoint(mut self, endpoint: &str) -> MediateGrant {
+        self.endpoint = endpoint.to_string();
+        self
+    }
+
+    pub fn set_routing_keys(mut self, routing_keys: Vec<String>) -> MediateGrant {
+        self.routing_keys = routing_keys;
+        self
+    }
+}
+
+threadlike!(MediateGrant);
+a2a_message!(MediateGrant);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct MediateDeny {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl MediateDeny {
+    pub fn create() -> MediateDeny {
+        MediateDeny::default()
+    }
+}
+
+threadlike!(MediateDeny);
+a2a_message!(MediateDeny);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::response::tests::{_thread, _thread_id};
+
+    pub fn _endpoint() -> String {
+        String::from("http://mediator.example.com:8080")
+    }
+
+    pub fn _routing_keys() -> Vec<String> {
+        vec![String::from("3LYuxJBJkngDbvJj4zjx13DBUdZ2P96eNybwd2n9L9AU")]
+    }
+
+    pub fn _mediate_request() -> MediateRequest {
+        MediateRequest {
+            id: MessageId::id(),
+            transport: Some(Transport::return_route_all()),
+        }
+    }
+
+    pub fn _mediate_grant() -> MediateGrant {
+        MediateGrant {
+            id: MessageId::id(),
+            endpoint: _endpoint(),
+            routing_keys: _routing_keys(),
+            thread: _thread(),
+        }
+    }
+
+    pub fn _mediate_deny() -> MediateDeny {
+        MediateDeny {
+            id: MessageId::id(),
+            thread: _thread(),
+        }
+    }
+
+    #[test]
+    fn test_mediate_request_build_works() {
+        let request: MediateRequest = MediateRequest::create()
+            .request_return_route();
+
+        assert_eq!(_mediate_request(), request);
+    }
+
+    #[test]
+    fn test_mediate_grant_build_works() {
+        let grant: MediateGrant = MediateGrant::create()
+            .set_endpoint(&_endpoint())
+            .set_routing_keys(_routing_keys())
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_mediate_grant(), grant);
+    }
+
+    #[test]
+    fn test_mediate_deny_build_works() {
+        let deny: MediateDeny = MediateDeny::create()
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_mediate_deny(), deny);
+    }
+
+    #[test]
+    fn test_mediate_request_serialization_works() {
+        let message = json!(_mediate_request().to_a2a_message());
+
+        assert_eq!("did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/coordinate-mediation/1.0/mediate-request", message["@type"]);
+        assert_eq!("all", message["~transport"]["return_route"]);
+
+        let request: A2AMessage = ::serde_json::from_value(message).unwrap();
+        assert_eq!(A2AMessage::MediateRequest(_mediate_request()), request);
+    }
+}
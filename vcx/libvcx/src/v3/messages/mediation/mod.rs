@@ -0,0 +1,2 @@
+pub mod mediate;
+pub mod keylist;
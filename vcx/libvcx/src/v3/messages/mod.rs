@@ -2,7 +2,11 @@
 pub mod a2a;
 #[macro_use]
 pub mod ack;
+#[macro_use]
+pub mod transport;
 pub mod connection;
+pub mod did_exchange;
+pub mod out_of_band;
 pub mod error;
 pub mod forward;
 pub mod attachment;
@@ -13,4 +17,7 @@ pub mod proof_presentation;
 pub mod discovery;
 pub mod trust_ping;
 pub mod basic_message;
+pub mod revocation_notification;
+pub mod mediation;
+pub mod pickup;
 pub mod localization;
\ No newline at end of file
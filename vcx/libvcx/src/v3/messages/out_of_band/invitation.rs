@@ -0,0 +1,159 @@
+use error::prelude::*;
+use v3::messages::a2a::{A2AMessage, MessageId};
+use v3::messages::a2a::message_family::MessageFamilies;
+use v3::messages::connection::invite::Invitation;
+use v3::utils::did_key::{to_did_key, from_did_key};
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+pub struct OutofbandInvitation {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(default)]
+    pub label: String,
+    #[serde(default)]
+    pub handshake_protocols: Vec<String>,
+    #[serde(default)]
+    #[serde(alias = "service")]
+    pub services: Vec<OutofbandService>,
+}
+
+/// Service is either inlined into the invitation or referenced through a public DID.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum OutofbandService {
+    Did(String),
+    Service(ServiceDescriptor),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Default)]
+pub struct ServiceDescriptor {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(rename = "recipientKeys")]
+    pub recipient_keys: Vec<String>,
+    #[serde(default)]
+    #[serde(rename = "routingKeys")]
+    pub routing_keys: Vec<String>,
+    #[serde(rename = "serviceEndpoint")]
+    pub service_endpoint: String,
+}
+
+impl OutofbandInvitation {
+    pub const DID_PREFIX: &'static str = "did:sov:";
+
+    pub fn create() -> OutofbandInvitation {
+        OutofbandInvitation::default()
+    }
+
+    pub fn set_id(mut self, id: String) -> OutofbandInvitation {
+        self.id = MessageId(id);
+        self
+    }
+
+    pub fn set_label(mut self, label: String) -> OutofbandInvitation {
+        self.label = label;
+        self
+    }
+
+    pub fn set_handshake_protocol(mut self, family: MessageFamilies) -> OutofbandInvitation {
+        self.handshake_protocols = vec![family.id()];
+        self
+    }
+
+    pub fn set_public_did(mut self, did: &str) -> OutofbandInvitation {
+        self.services = vec![OutofbandService::Did(format!("{}{}", Self::DID_PREFIX, did))];
+        self
+    }
+
+    /// Describes inline service of the invitation with keys in `did:key` form.
+    pub fn set_service(mut self, invitation: &Invitation) -> VcxResult<OutofbandInvitation> {
+        let service = ServiceDescriptor {
+            id: String::from("#inline"),
+            type_: String::from("did-communication"),
+            recipient_keys: invitation.recipient_keys.iter().map(|key| to_did_key(key)).collect::<VcxResult<_>>()?,
+            routing_keys: invitation.routing_keys.iter().map(|key| to_did_key(key)).collect::<VcxResult<_>>()?,
+            service_endpoint: invitation.service_endpoint.clone(),
+        };
+
+        self.services = vec![OutofbandService::Service(service)];
+        Ok(self)
+    }
+
+    pub fn supports(&self, family: &MessageFamilies) -> bool {
+        let protocol = format!("{}/{}", family.to_string(), family.version());
+        self.handshake_protocols.iter().any(|pid| pid.trim_end_matches('/').ends_with(&protocol))
+    }
+
+    /// Public DID the invitation refers to instead of inlined service.
+    pub fn public_did(&self) -> Option<String> {
+        match self.services.get(0) {
+            Some(OutofbandService::Did(did)) => Some(did.trim_start_matches(Self::DID_PREFIX).to_string()),
+            _ => None
+        }
+    }
+
+    /// Converts invitation with inlined service into the form handled by the connection state machine.
+    pub fn inline_invitation(&self) -> VcxResult<Invitation> {
+        let service = match self.services.get(0) {
+            Some(OutofbandService::Service(service)) => service,
+            _ => return Err(VcxError::from_msg(VcxErrorKind::InvalidInviteDetail, "Out-of-Band invitation does not contain inline service"))
+        };
+
+        Ok(Invitation::create()
+            .set_id(self.id.0.clone())
+            .set_label(self.label.clone())
+            .set_service_endpoint(service.service_endpoint.clone())
+            .set_recipient_keys(service.recipient_keys.iter().map(|key| from_did_key(key)).collect::<VcxResult<_>>()?)
+            .set_routing_keys(service.routing_keys.iter().map(|key| from_did_key(key)).collect::<VcxResult<_>>()?))
+    }
+}
+
+a2a_message!(OutofbandInvitation);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::invite::tests::_invitation;
+
+    pub fn _outofband_invitation() -> OutofbandInvitation {
+        OutofbandInvitation::create()
+            .set_id(_invitation().id.0)
+            .set_label(_invitation().label)
+            .set_handshake_protocol(MessageFamilies::DidExchange)
+            .set_service(&_invitation()).unwrap()
+    }
+
+    #[test]
+    fn test_outofband_invitation_inline_service_works() {
+        let invitation = _outofband_invitation();
+
+        assert!(invitation.supports(&MessageFamilies::DidExchange));
+        assert!(!invitation.supports(&MessageFamilies::Connections));
+        assert_eq!(None, invitation.public_did());
+        assert_eq!(_invitation(), invitation.inline_invitation().unwrap());
+    }
+
+    #[test]
+    fn test_outofband_invitation_public_did_works() {
+        let invitation = OutofbandInvitation::create()
+            .set_handshake_protocol(MessageFamilies::DidExchange)
+            .set_public_did("VsKV7grR1BUE29mG2Fm2kX");
+
+        assert_eq!(Some(String::from("VsKV7grR1BUE29mG2Fm2kX")), invitation.public_did());
+        assert!(invitation.inline_invitation().is_err());
+    }
+
+    #[test]
+    fn test_outofband_invitation_deserialization_works() {
+        let invitation: OutofbandInvitation = ::serde_json::from_value(json!({
+            "@id": "testid",
+            "label": "Faber",
+            "handshake_protocols": ["https://didcomm.org/didexchange/1.0"],
+            "services": ["did:sov:VsKV7grR1BUE29mG2Fm2kX"]
+        })).unwrap();
+
+        assert!(invitation.supports(&MessageFamilies::DidExchange));
+        assert_eq!(Some(String::from("VsKV7grR1BUE29mG2Fm2kX")), invitation.public_did());
+    }
+}
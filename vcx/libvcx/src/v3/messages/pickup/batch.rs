@@ -0,0 +1,115 @@
+use messages::thread::Thread;
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::transport::Transport;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct BatchPickup {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub batch_size: u32,
+    #[serde(rename = "~transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+}
+
+impl BatchPickup {
+    pub fn create() -> BatchPickup {
+        BatchPickup::default()
+    }
+
+    pub fn set_batch_size(mut self, batch_size: u32) -> BatchPickup {
+        self.batch_size = batch_size;
+        self
+    }
+}
+
+return_route!(BatchPickup);
+a2a_message!(BatchPickup);
+
+/// Message stored by the mediator for the recipient. `message` is the packed (encrypted) message.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BatchMessage {
+    #[serde(rename = "@id")]
+    pub id: String,
+    pub message: ::serde_json::Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Batch {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "messages~attach")]
+    pub messages: Vec<BatchMessage>,
+    #[serde(rename = "~thread")]
+    #[serde(default)]
+    pub thread: Thread,
+}
+
+impl Batch {
+    pub fn create() -> Batch {
+        Batch::default()
+    }
+
+    pub fn add_message(mut self, id: &str, message: ::serde_json::Value) -> Batch {
+        self.messages.push(BatchMessage { id: id.to_string(), message });
+        self
+    }
+}
+
+threadlike!(Batch);
+a2a_message!(Batch);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::response::tests::{_thread, _thread_id};
+
+    fn _packed_message() -> ::serde_json::Value {
+        json!({"protected": "eyJlbmMiOiJ4Y2hhY2hhMjBwb2x5MTMwNV9pZXRmIn0", "iv": "iv", "ciphertext": "ciphertext", "tag": "tag"})
+    }
+
+    pub fn _batch_pickup() -> BatchPickup {
+        BatchPickup {
+            id: MessageId::id(),
+            batch_size: 10,
+            transport: Some(Transport::return_route_all()),
+        }
+    }
+
+    pub fn _batch() -> Batch {
+        Batch {
+            id: MessageId::id(),
+            messages: vec![BatchMessage { id: String::from("message_1"), message: _packed_message() }],
+            thread: _thread(),
+        }
+    }
+
+    #[test]
+    fn test_batch_pickup_build_works() {
+        let pickup: BatchPickup = BatchPickup::create()
+            .set_batch_size(10)
+            .request_return_route();
+
+        assert_eq!(_batch_pickup(), pickup);
+    }
+
+    #[test]
+    fn test_batch_build_works() {
+        let batch: Batch = Batch::create()
+            .add_message("message_1", _packed_message())
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_batch(), batch);
+    }
+
+    #[test]
+    fn test_batch_serialization_works() {
+        let message = json!(_batch().to_a2a_message());
+
+        assert_eq!("did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/messagepickup/1.0/batch", message["@type"]);
+        assert_eq!(_packed_message(), message["messages~attach"][0]["message"]);
+
+        let batch: A2AMessage = ::serde_json::from_value(message).unwrap();
+        assert_eq!(A2AMessage::Batch(_batch()), batch);
+    }
+}
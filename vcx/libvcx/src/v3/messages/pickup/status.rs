@@ -0,0 +1,107 @@
+use messages::thread::Thread;
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::transport::Transport;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct StatusRequest {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(rename = "~transport")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub transport: Option<Transport>,
+}
+
+impl StatusRequest {
+    pub fn create() -> StatusRequest {
+        StatusRequest::default()
+    }
+}
+
+return_route!(StatusRequest);
+a2a_message!(StatusRequest, PickupStatusRequest);
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct Status {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub message_count: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_waited: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_added_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_delivered_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_removed_time: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_size: Option<u64>,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+impl Status {
+    pub fn create() -> Status {
+        Status::default()
+    }
+
+    pub fn set_message_count(mut self, message_count: u32) -> Status {
+        self.message_count = message_count;
+        self
+    }
+}
+
+threadlike!(Status);
+a2a_message!(Status, PickupStatus);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::connection::response::tests::{_thread, _thread_id};
+
+    pub fn _status_request() -> StatusRequest {
+        StatusRequest {
+            id: MessageId::id(),
+            transport: Some(Transport::return_route_all()),
+        }
+    }
+
+    pub fn _status() -> Status {
+        Status {
+            id: MessageId::id(),
+            message_count: 7,
+            duration_waited: None,
+            last_added_time: None,
+            last_delivered_time: None,
+            last_removed_time: None,
+            total_size: None,
+            thread: _thread(),
+        }
+    }
+
+    #[test]
+    fn test_status_request_build_works() {
+        let request: StatusRequest = StatusRequest::create()
+            .request_return_route();
+
+        assert_eq!(_status_request(), request);
+    }
+
+    #[test]
+    fn test_status_build_works() {
+        let status: Status = Status::create()
+            .set_message_count(7)
+            .set_thread_id(&_thread_id());
+
+        assert_eq!(_status(), status);
+    }
+
+    #[test]
+    fn test_status_serialization_works() {
+        let message = json!(_status().to_a2a_message());
+
+        assert_eq!("did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/messagepickup/1.0/status", message["@type"]);
+
+        let status: A2AMessage = ::serde_json::from_value(message).unwrap();
+        assert_eq!(A2AMessage::PickupStatus(_status()), status);
+    }
+}
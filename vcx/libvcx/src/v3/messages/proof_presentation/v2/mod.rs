@@ -0,0 +1,9 @@
+/*
+    Messages of Aries Present Proof 2.0 protocol (RFC 0454).
+    Only Indy attachment formats are supported: messages are converted into their 1.0 counterparts
+    on receiving and back on sending, so both versions are handled by the same state machines.
+*/
+
+pub mod presentation_proposal;
+pub mod presentation_request;
+pub mod presentation;
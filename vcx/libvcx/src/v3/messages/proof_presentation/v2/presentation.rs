@@ -0,0 +1,72 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat};
+use v3::messages::ack::PleaseAck;
+use v3::messages::proof_presentation::presentation::Presentation;
+use messages::thread::Thread;
+use error::prelude::*;
+use std::convert::TryInto;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct PresentationV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "presentations~attach")]
+    pub presentations_attach: Attachments,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+    #[serde(rename = "~please_ack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub please_ack: Option<PleaseAck>
+}
+
+threadlike!(PresentationV2);
+a2a_message!(PresentationV2);
+
+impl From<Presentation> for PresentationV2 {
+    fn from(presentation: Presentation) -> Self {
+        PresentationV2 {
+            id: presentation.id,
+            comment: presentation.comment,
+            formats: AttachmentFormat::for_attachments(&presentation.presentations_attach, AttachmentFormat::INDY_PROOF),
+            presentations_attach: presentation.presentations_attach,
+            thread: presentation.thread,
+            please_ack: presentation.please_ack,
+        }
+    }
+}
+
+impl TryInto<Presentation> for PresentationV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<Presentation> {
+        Ok(Presentation {
+            presentations_attach: self.presentations_attach.select(&self.formats, AttachmentFormat::INDY_PROOF)?,
+            id: self.id,
+            comment: self.comment,
+            thread: self.thread,
+            please_ack: self.please_ack,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::proof_presentation::presentation::tests::_presentation;
+
+    pub fn _presentation_v2() -> PresentationV2 {
+        _presentation().into()
+    }
+
+    #[test]
+    fn test_presentation_v2_conversion_works() {
+        let presentation = _presentation_v2();
+        assert_eq!(AttachmentFormat::INDY_PROOF, presentation.formats[0].format);
+
+        let presentation: Presentation = presentation.try_into().unwrap();
+        assert_eq!(_presentation(), presentation);
+    }
+}
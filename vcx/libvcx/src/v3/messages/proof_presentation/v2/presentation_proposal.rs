@@ -0,0 +1,158 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat, AttachmentId};
+use v3::messages::proof_presentation::presentation_proposal::{PresentationProposal, PresentationPreview, Attribute, Predicate};
+use messages::thread::Thread;
+use error::prelude::*;
+use std::convert::TryInto;
+
+/// Proposal of Present Proof 2.0 carries Indy proof request built from the presentation preview.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct PresentationProposalV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "proposals~attach")]
+    pub proposals_attach: Attachments,
+    #[serde(rename = "~thread")]
+    pub thread: Thread,
+}
+
+threadlike!(PresentationProposalV2);
+a2a_message!(PresentationProposalV2);
+
+fn _restrictions(cred_def_id: &Option<String>, filter: &Option<Vec<::serde_json::Value>>) -> Option<Vec<::serde_json::Value>> {
+    match (cred_def_id, filter) {
+        (_, Some(filter)) => Some(filter.clone()),
+        (Some(cred_def_id), None) => Some(vec![json!({"cred_def_id": cred_def_id})]),
+        (None, None) => None
+    }
+}
+
+fn _cred_def_id(restrictions: &::serde_json::Value) -> Option<String> {
+    restrictions[0]["cred_def_id"].as_str().map(String::from)
+}
+
+impl TryInto<PresentationProposalV2> for PresentationProposal {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<PresentationProposalV2> {
+        let mut requested_attributes = ::serde_json::Map::new();
+        let mut requested_predicates = ::serde_json::Map::new();
+
+        for (index, attribute) in self.presentation_proposal.attributes.iter().enumerate() {
+            let mut requested_attribute = json!({"name": attribute.name});
+            if let Some(restrictions) = _restrictions(&attribute.cred_def_id, &attribute.filter) {
+                requested_attribute["restrictions"] = json!(restrictions);
+            }
+            requested_attributes.insert(format!("attribute_{}", index), requested_attribute);
+        }
+
+        for (index, predicate) in self.presentation_proposal.predicates.iter().enumerate() {
+            let mut requested_predicate = json!({"name": predicate.name, "p_type": predicate.predicate, "p_value": predicate.threshold});
+            if let Some(restrictions) = _restrictions(&predicate.cred_def_id, &predicate.filter) {
+                requested_predicate["restrictions"] = json!(restrictions);
+            }
+            requested_predicates.insert(format!("predicate_{}", index), requested_predicate);
+        }
+
+        let proof_request = json!({
+            "name": "proposal",
+            "version": "1.0",
+            "requested_attributes": requested_attributes,
+            "requested_predicates": requested_predicates,
+        });
+
+        let mut proposals_attach = Attachments::new();
+        proposals_attach.add_base64_encoded_json_attachment(AttachmentId::PresentationProposal, proof_request)?;
+
+        Ok(PresentationProposalV2 {
+            id: self.id,
+            comment: self.comment,
+            formats: AttachmentFormat::for_attachments(&proposals_attach, AttachmentFormat::INDY_PROOF_REQUEST),
+            proposals_attach,
+            thread: self.thread,
+        })
+    }
+}
+
+impl TryInto<PresentationProposal> for PresentationProposalV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<PresentationProposal> {
+        let proof_request = self.proposals_attach.select(&self.formats, AttachmentFormat::INDY_PROOF_REQUEST)?.content()?;
+        let proof_request: ::serde_json::Value = ::serde_json::from_str(&proof_request)
+            .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize Indy Proof Request: {:?}", err)))?;
+
+        let attributes = proof_request["requested_attributes"].as_object()
+            .map(|attributes| attributes.values()
+                .map(|attribute| Attribute {
+                    name: attribute["name"].as_str().map(String::from).unwrap_or_default(),
+                    cred_def_id: _cred_def_id(&attribute["restrictions"]),
+                    mime_type: None,
+                    value: None,
+                    filter: attribute["restrictions"].as_array().cloned(),
+                })
+                .collect())
+            .unwrap_or_default();
+
+        let predicates = proof_request["requested_predicates"].as_object()
+            .map(|predicates| predicates.values()
+                .map(|predicate| Predicate {
+                    name: predicate["name"].as_str().map(String::from).unwrap_or_default(),
+                    cred_def_id: _cred_def_id(&predicate["restrictions"]),
+                    predicate: predicate["p_type"].as_str().map(String::from).unwrap_or_default(),
+                    threshold: predicate["p_value"].as_i64().unwrap_or_default(),
+                    filter: predicate["restrictions"].as_array().cloned(),
+                })
+                .collect())
+            .unwrap_or_default();
+
+        Ok(PresentationProposal {
+            id: self.id,
+            comment: self.comment,
+            presentation_proposal: PresentationPreview { attributes, predicates, ..PresentationPreview::default() },
+            thread: self.thread,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::proof_presentation::presentation_proposal::tests::_presentation_proposal;
+
+    pub fn _presentation_proposal_v2() -> PresentationProposalV2 {
+        _presentation_proposal().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_presentation_proposal_v2_conversion_works() {
+        let proposal = _presentation_proposal_v2();
+        assert_eq!(AttachmentFormat::INDY_PROOF_REQUEST, proposal.formats[0].format);
+
+        let proposal: PresentationProposal = proposal.try_into().unwrap();
+        assert_eq!(_presentation_proposal(), proposal);
+    }
+
+    #[test]
+    fn test_presentation_proposal_v2_conversion_works_for_predicates() {
+        let mut proposal = _presentation_proposal();
+        proposal.presentation_proposal.predicates.push(Predicate {
+            name: String::from("age"),
+            cred_def_id: Some(String::from("cred_def_id")),
+            predicate: String::from(">="),
+            threshold: 18,
+            filter: None,
+        });
+
+        let proposal_v2: PresentationProposalV2 = proposal.try_into().unwrap();
+        let proposal: PresentationProposal = proposal_v2.try_into().unwrap();
+
+        let predicate = &proposal.presentation_proposal.predicates[0];
+        assert_eq!("age", predicate.name);
+        assert_eq!(Some(String::from("cred_def_id")), predicate.cred_def_id);
+        assert_eq!(18, predicate.threshold);
+    }
+}
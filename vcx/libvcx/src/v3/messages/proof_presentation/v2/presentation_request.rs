@@ -0,0 +1,69 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::attachment::{Attachments, AttachmentFormat};
+use v3::messages::connection::service::Service;
+use v3::messages::proof_presentation::presentation_request::PresentationRequest;
+use error::prelude::*;
+use std::convert::TryInto;
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Default)]
+pub struct PresentationRequestV2 {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub will_confirm: Option<bool>,
+    pub formats: Vec<AttachmentFormat>,
+    #[serde(rename = "request_presentations~attach")]
+    pub request_presentations_attach: Attachments,
+    #[serde(rename = "~service")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<Service>,
+}
+
+a2a_message!(PresentationRequestV2);
+
+impl From<PresentationRequest> for PresentationRequestV2 {
+    fn from(request: PresentationRequest) -> Self {
+        PresentationRequestV2 {
+            id: request.id,
+            comment: request.comment,
+            will_confirm: Some(true),
+            formats: AttachmentFormat::for_attachments(&request.request_presentations_attach, AttachmentFormat::INDY_PROOF_REQUEST),
+            request_presentations_attach: request.request_presentations_attach,
+            service: request.service,
+        }
+    }
+}
+
+impl TryInto<PresentationRequest> for PresentationRequestV2 {
+    type Error = VcxError;
+
+    fn try_into(self) -> VcxResult<PresentationRequest> {
+        Ok(PresentationRequest {
+            request_presentations_attach: self.request_presentations_attach.select(&self.formats, AttachmentFormat::INDY_PROOF_REQUEST)?,
+            id: self.id,
+            comment: self.comment,
+            service: self.service,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::proof_presentation::presentation_request::tests::_presentation_request;
+
+    pub fn _presentation_request_v2() -> PresentationRequestV2 {
+        _presentation_request().into()
+    }
+
+    #[test]
+    fn test_presentation_request_v2_conversion_works() {
+        let request = _presentation_request_v2();
+        assert_eq!(AttachmentFormat::INDY_PROOF_REQUEST, request.formats[0].format);
+
+        let request: PresentationRequest = request.try_into().unwrap();
+        assert_eq!(_presentation_request(), request);
+    }
+}
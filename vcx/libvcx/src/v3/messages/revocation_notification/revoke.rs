@@ -0,0 +1,74 @@
+use v3::messages::a2a::{MessageId, A2AMessage};
+use v3::messages::ack::PleaseAck;
+
+/// Aries RFC 0183 notification sent by the Issuer to the Holder after revoking the credential.
+/// `thread_id` refers to the thread of Issue Credential protocol the credential was issued within.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct RevocationNotification {
+    #[serde(rename = "@id")]
+    pub id: MessageId,
+    pub thread_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(rename = "~please_ack")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub please_ack: Option<PleaseAck>,
+}
+
+impl RevocationNotification {
+    pub fn create() -> RevocationNotification {
+        RevocationNotification::default()
+    }
+
+    pub fn set_thread_id(mut self, thread_id: &str) -> RevocationNotification {
+        self.thread_id = thread_id.to_string();
+        self
+    }
+
+    pub fn set_comment(mut self, comment: Option<String>) -> RevocationNotification {
+        self.comment = comment;
+        self
+    }
+}
+
+please_ack!(RevocationNotification);
+a2a_message!(RevocationNotification);
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use v3::messages::issuance::credential_offer::tests::thread_id as _thread_id;
+
+    fn _comment() -> Option<String> {
+        Some(String::from("Credential has been revoked"))
+    }
+
+    pub fn _revocation_notification() -> RevocationNotification {
+        RevocationNotification {
+            id: MessageId::id(),
+            thread_id: _thread_id(),
+            comment: _comment(),
+            please_ack: None,
+        }
+    }
+
+    #[test]
+    fn test_revocation_notification_build_works() {
+        let notification: RevocationNotification = RevocationNotification::create()
+            .set_thread_id(&_thread_id())
+            .set_comment(_comment());
+
+        assert_eq!(_revocation_notification(), notification);
+    }
+
+    #[test]
+    fn test_revocation_notification_serialization_works() {
+        let message = json!(_revocation_notification().to_a2a_message());
+
+        assert_eq!("did:sov:BzCbsNYhMrjHiqZDTUASHg;spec/revocation_notification/1.0/revoke", message["@type"]);
+        assert_eq!(_thread_id(), message["thread_id"]);
+
+        let notification: A2AMessage = ::serde_json::from_value(message).unwrap();
+        assert_eq!(A2AMessage::RevocationNotification(_revocation_notification()), notification);
+    }
+}
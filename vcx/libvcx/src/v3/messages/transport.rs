@@ -0,0 +1,40 @@
+/// `~transport` decorator (Aries RFC 0092) asking the receiver to reply within the same HTTP connection.
+/// Used by edge agents without their own endpoint talking to a mediator.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Transport {
+    pub return_route: ReturnRoute,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReturnRoute {
+    None,
+    All,
+    Thread,
+}
+
+impl Transport {
+    pub fn return_route_all() -> Transport {
+        Transport { return_route: ReturnRoute::All }
+    }
+}
+
+#[macro_export]
+macro_rules! return_route (($type:ident) => (
+    impl $type {
+        pub fn request_return_route(mut self) -> $type {
+            self.transport = Some(Transport::return_route_all());
+            self
+        }
+    }
+));
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transport_serialization_works() {
+        assert_eq!(json!({"return_route": "all"}), json!(Transport::return_route_all()));
+    }
+}
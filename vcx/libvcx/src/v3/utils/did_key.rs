@@ -0,0 +1,57 @@
+extern crate rust_base58;
+
+use self::rust_base58::{FromBase58, ToBase58};
+
+use error::prelude::*;
+
+const DID_KEY_PREFIX: &str = "did:key:z";
+// multicodec prefix of Ed25519 public key
+const ED25519_CODEC: [u8; 2] = [0xed, 0x01];
+
+/// Converts base58 encoded Ed25519 verkey into `did:key` form.
+pub fn to_did_key(verkey: &str) -> VcxResult<String> {
+    let key = verkey.from_base58()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidVerkey, format!("Cannot decode verkey: {:?}", err)))?;
+
+    let mut bytes = ED25519_CODEC.to_vec();
+    bytes.extend(key);
+
+    Ok(format!("{}{}", DID_KEY_PREFIX, bytes.to_base58()))
+}
+
+/// Converts `did:key` of Ed25519 key into base58 encoded verkey.
+/// Plain verkeys are returned as is.
+pub fn from_did_key(key: &str) -> VcxResult<String> {
+    if !key.starts_with(DID_KEY_PREFIX) {
+        return Ok(key.to_string());
+    }
+
+    let bytes = key[DID_KEY_PREFIX.len()..].from_base58()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidVerkey, format!("Cannot decode did:key: {:?}", err)))?;
+
+    if !bytes.starts_with(&ED25519_CODEC) {
+        return Err(VcxError::from_msg(VcxErrorKind::InvalidVerkey, format!("Unsupported did:key type: {}", key)));
+    }
+
+    Ok(bytes[ED25519_CODEC.len()..].to_base58())
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const VERKEY: &str = "8HH5gYEeNc3z7PYXmd54d4x6qAfCNrqQqEB3nS7Zfu7K";
+    const DID_KEY: &str = "did:key:z6MkmjY8GnV5i9YTDtPETC2uUAW6ejw3nk5mXF5yci5ab7th";
+
+    #[test]
+    fn test_did_key_works() {
+        assert_eq!(DID_KEY, to_did_key(VERKEY).unwrap());
+        assert_eq!(VERKEY, from_did_key(DID_KEY).unwrap());
+        assert_eq!(VERKEY, from_did_key(VERKEY).unwrap());
+    }
+
+    #[test]
+    fn test_from_did_key_fails_for_invalid_key() {
+        assert!(from_did_key("did:key:z0OIl").is_err());
+    }
+}
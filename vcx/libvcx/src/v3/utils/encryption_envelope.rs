@@ -4,6 +4,7 @@ use error::prelude::*;
 use v3::messages::a2a::A2AMessage;
 use v3::messages::connection::did_doc::DidDoc;
 use v3::messages::forward::Forward;
+use v3::utils::transport;
 
 #[derive(Debug)]
 pub struct EncryptionEnvelope(pub Vec<u8>);
@@ -24,11 +25,17 @@ impl EncryptionEnvelope {
     fn encrypt_for_pairwise(message: &A2AMessage,
                             pw_verkey: Option<&str>,
                             did_doc: &DidDoc) -> VcxResult<Vec<u8>> {
-        let message = match message {
-            A2AMessage::Generic(message_) => message_.to_string(),
-            message => json!(message).to_string()
+        let mut message = match message {
+            A2AMessage::Generic(message_) => message_.clone(),
+            message => json!(message)
         };
 
+        if ::settings::is_agency_less() {
+            transport::request_return_route(&mut message);
+        }
+
+        let message = message.to_string();
+
         let receiver_keys = json!(did_doc.recipient_keys()).to_string();
 
         crypto::pack_message(pw_verkey, &receiver_keys, message.as_bytes())
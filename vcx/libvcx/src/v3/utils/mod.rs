@@ -1 +1,3 @@
-pub mod encryption_envelope;
\ No newline at end of file
+pub mod encryption_envelope;
+pub mod transport;
+pub mod did_key;
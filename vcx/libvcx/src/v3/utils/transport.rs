@@ -0,0 +1,182 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use error::prelude::*;
+use settings;
+use utils::httpclient;
+use utils::libindy::crypto;
+use v3::messages::a2a::A2AMessage;
+use v3::messages::connection::did_doc::DidDoc;
+use v3::messages::transport::{ReturnRoute, Transport};
+
+/*
+    Transport used in agency-less mode (`service_endpoint` setting is set).
+    Messages are posted directly to the peer endpoint and inbound messages are fed by the application
+    from its own HTTP server. Outbound messages ask for `~transport.return_route`, so the peer can reply
+    in the HTTP response.
+    While an inbound message asking for `~transport.return_route` is processed, the first message
+    sent back to its sender is returned to the application instead of being posted.
+*/
+
+lazy_static! {
+    static ref INBOUND_QUEUE: Mutex<VecDeque<Vec<u8>>> = Default::default();
+}
+
+const TRANSPORT_DECORATOR: &str = "~transport";
+
+thread_local! {
+    static RETURN_ROUTE: RefCell<Option<PendingReturnRoute>> = RefCell::new(None);
+}
+
+struct PendingReturnRoute {
+    sender_vk: String,
+    reply: Option<Vec<u8>>,
+}
+
+/// Inbound packed message opened by the wallet.
+#[derive(Debug)]
+pub struct InboundMessage {
+    pub message: A2AMessage,
+    pub recipient_vk: String,
+    pub sender_vk: Option<String>,
+    pub return_route: bool,
+}
+
+pub fn unpack(packed: &[u8]) -> VcxResult<InboundMessage> {
+    trace!("transport::unpack >>>");
+
+    let unpacked = crypto::unpack_message(packed)?;
+
+    let unpacked: ::serde_json::Value = ::serde_json::from_slice(unpacked.as_slice())
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize message: {}", err)))?;
+
+    let message = unpacked["message"].as_str()
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "Cannot find `message` field"))?;
+
+    let message: ::serde_json::Value = ::serde_json::from_str(message)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize A2A message: {}", err)))?;
+
+    let return_route = ::serde_json::from_value::<Transport>(message[TRANSPORT_DECORATOR].clone())
+        .map(|transport| transport.return_route != ReturnRoute::None)
+        .unwrap_or(false);
+
+    let message: A2AMessage = ::serde_json::from_value(message)
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::InvalidJson, format!("Cannot deserialize A2A message: {}", err)))?;
+
+    let recipient_vk = unpacked["recipient_verkey"].as_str()
+        .ok_or(VcxError::from_msg(VcxErrorKind::InvalidJson, "Cannot find `recipient_verkey` field"))?
+        .to_string();
+
+    Ok(InboundMessage {
+        message,
+        recipient_vk,
+        sender_vk: unpacked["sender_verkey"].as_str().map(String::from),
+        return_route,
+    })
+}
+
+/// Sends packed message to the peer. In agency-less mode a non-empty HTTP response body is treated
+/// as an inbound message returned by the peer and is queued for dispatching.
+pub fn send(envelope: Vec<u8>, did_doc: &DidDoc) -> VcxResult<()> {
+    let envelope = match _capture_reply(envelope, did_doc) {
+        Some(envelope) => envelope,
+        None => return Ok(())
+    };
+
+    let response = httpclient::post_message(&envelope, &did_doc.get_endpoint())?;
+
+    if settings::is_agency_less() && !response.is_empty() {
+        queue_inbound(response)?;
+    }
+
+    Ok(())
+}
+
+/// Adds `~transport.return_route` decorator to the outbound message unless it sets its own.
+pub fn request_return_route(message: &mut ::serde_json::Value) {
+    if let Some(message) = message.as_object_mut() {
+        message.entry(TRANSPORT_DECORATOR).or_insert(json!(Transport::return_route_all()));
+    }
+}
+
+pub fn start_return_route(sender_vk: &str) {
+    RETURN_ROUTE.with(|return_route| {
+        *return_route.borrow_mut() = Some(PendingReturnRoute { sender_vk: sender_vk.to_string(), reply: None });
+    })
+}
+
+pub fn finish_return_route() -> Option<Vec<u8>> {
+    RETURN_ROUTE.with(|return_route| {
+        return_route.borrow_mut().take().and_then(|return_route| return_route.reply)
+    })
+}
+
+pub fn take_inbound() -> VcxResult<Vec<Vec<u8>>> {
+    let mut queue = INBOUND_QUEUE.lock()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::Common(10), format!("Unable to lock inbound queue: {:?}", err)))?;
+
+    Ok(queue.drain(..).collect())
+}
+
+/// Queues packed message for dispatching by `vcx_messages_dispatch`
+/// (reply returned in HTTP response or message picked up from the mediator).
+pub fn queue_inbound(message: Vec<u8>) -> VcxResult<()> {
+    let mut queue = INBOUND_QUEUE.lock()
+        .map_err(|err| VcxError::from_msg(VcxErrorKind::Common(10), format!("Unable to lock inbound queue: {:?}", err)))?;
+
+    queue.push_back(message);
+    Ok(())
+}
+
+fn _capture_reply(envelope: Vec<u8>, did_doc: &DidDoc) -> Option<Vec<u8>> {
+    RETURN_ROUTE.with(|return_route| {
+        match *return_route.borrow_mut() {
+            Some(ref mut return_route) if return_route.reply.is_none() && did_doc.recipient_keys().contains(&return_route.sender_vk) => {
+                return_route.reply = Some(envelope);
+                None
+            }
+            _ => Some(envelope)
+        }
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use utils::devsetup::SetupAriesMocks;
+    use v3::messages::connection::did_doc::tests::{_did_doc_4, _recipient_keys};
+
+    #[test]
+    fn test_return_route_captures_first_reply_to_sender() {
+        let _setup = SetupAriesMocks::init();
+
+        start_return_route(&_recipient_keys()[0]);
+
+        send(vec![1, 2, 3], &_did_doc_4()).unwrap();
+
+        assert_eq!(Some(vec![1, 2, 3]), finish_return_route());
+        assert_eq!(None, finish_return_route());
+    }
+
+    #[test]
+    fn test_request_return_route_keeps_own_decorator() {
+        let mut message = json!({"@type": "https://didcomm.org/trust_ping/1.0/ping"});
+        request_return_route(&mut message);
+        assert_eq!("all", message["~transport"]["return_route"]);
+
+        let mut message = json!({"~transport": {"return_route": "thread"}});
+        request_return_route(&mut message);
+        assert_eq!("thread", message["~transport"]["return_route"]);
+    }
+
+    #[test]
+    fn test_return_route_ignores_other_recipients() {
+        let _setup = SetupAriesMocks::init();
+
+        start_return_route("other_key");
+
+        assert_eq!(Some(vec![1, 2, 3]), _capture_reply(vec![1, 2, 3], &_did_doc_4()));
+        assert_eq!(None, finish_return_route());
+    }
+}
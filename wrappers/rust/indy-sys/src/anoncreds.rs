@@ -56,6 +56,39 @@ extern {
                                          blob_storage_reader_handle: BlobStorageReaderHandle,
                                          cb: Option<ResponseStringStringStringCB>) -> Error;
 
+    pub fn indy_issuer_create_revoc_reg_pool(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             issuer_did: CString,
+                                             cred_def_id: CString,
+                                             config_json: CString,
+                                             tails_writer_handle: TailWriterHandle,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_create_credential_from_revoc_reg_pool(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             cred_offer_json: CString,
+                                                             cred_req_json: CString,
+                                                             cred_values_json: CString,
+                                                             blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                             cb: Option<ResponseStringStringStringCB>) -> Error;
+
+    pub fn indy_issuer_rollover_revoc_reg_pool(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               cred_def_id: CString,
+                                               tails_writer_handle: TailWriterHandle,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_get_revoc_reg_pool(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          cred_def_id: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_issuer_mark_revoc_reg_published(command_handle: CommandHandle,
+                                                wallet_handle: WalletHandle,
+                                                cred_def_id: CString,
+                                                rev_reg_id: CString,
+                                                cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_issuer_revoke_credential(command_handle: CommandHandle,
                                          wallet_handle: WalletHandle,
                                          blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
@@ -63,6 +96,14 @@ extern {
                                          cred_revoc_id: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_issuer_update_revocation_registry(command_handle: CommandHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                                  rev_reg_id: CString,
+                                                  revoked_ids_json: CString,
+                                                  unrevoked_ids_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_issuer_merge_revocation_registry_deltas(command_handle: CommandHandle,
                                                         rev_reg_delta_json: CString,
                                                         other_rev_reg_delta_json: CString,
@@ -73,6 +114,28 @@ extern {
                                             master_secret_id: CString,
                                             cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_export_master_secret(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            master_secret_id: CString,
+                                            recipient_vk: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_import_master_secret(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            my_vk: CString,
+                                            encrypted_master_secret_json: CString,
+                                            master_secret_id: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_get_master_secrets(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_retire_master_secret(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            master_secret_id: CString,
+                                            cb: Option<ResponseEmptyCB>) -> Error;
+
     pub fn indy_prover_create_credential_req(command_handle: CommandHandle,
                                              wallet_handle: WalletHandle,
                                              prover_did: CString,
@@ -162,6 +225,28 @@ extern {
                                     rev_states_json: CString,
                                     cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_create_proof_auto(command_handle: CommandHandle,
+                                         wallet_handle: WalletHandle,
+                                         proof_req_json: CString,
+                                         master_secret_id: CString,
+                                         schemas_json: CString,
+                                         credential_defs_json: CString,
+                                         rev_states_json: CString,
+                                         options_json: CString,
+                                         cb: Option<ResponseStringStringCB>) -> Error;
+
+    pub fn indy_prover_create_proof_with_revocation_cache(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          proof_req_json: CString,
+                                                          requested_credentials_json: CString,
+                                                          master_secret_id: CString,
+                                                          schemas_json: CString,
+                                                          credential_defs_json: CString,
+                                                          blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                          rev_reg_defs_json: CString,
+                                                          rev_reg_deltas_json: CString,
+                                                          cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_verifier_verify_proof(command_handle: CommandHandle,
                                       proof_request_json: CString,
                                       proof_json: CString,
@@ -171,6 +256,23 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    pub fn indy_verifier_verify_proof_with_report(command_handle: CommandHandle,
+                                                  proof_request_json: CString,
+                                                  proof_json: CString,
+                                                  schemas_json: CString,
+                                                  credential_defs_json: CString,
+                                                  rev_reg_defs_json: CString,
+                                                  rev_regs_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verifier_verify_proof_with_ledger(command_handle: CommandHandle,
+                                                  pool_handle: PoolHandle,
+                                                  wallet_handle: WalletHandle,
+                                                  proof_request_json: CString,
+                                                  proof_json: CString,
+                                                  options_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
                                         rev_reg_def_json: CString,
@@ -188,10 +290,48 @@ extern {
                                         cred_rev_id: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_prover_refresh_revocation_states(command_handle: CommandHandle,
+                                                 pool_handle: PoolHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_prover_prune_revocation_states(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               timestamp: u64,
+                                               cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_generate_nonce(command_handle: CommandHandle,
                                cb: Option<ResponseStringCB>) -> Error;
     pub fn indy_to_unqualified(command_handle: CommandHandle,
                                entity: CString,
                                cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_credential_to_w3c(command_handle: CommandHandle,
+                                  cred_json: CString,
+                                  cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_credential_from_w3c(command_handle: CommandHandle,
+                                    w3c_cred_json: CString,
+                                    cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_proof_to_w3c_presentation(command_handle: CommandHandle,
+                                          proof_json: CString,
+                                          proof_req_json: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_proof_from_w3c_presentation(command_handle: CommandHandle,
+                                            w3c_presentation_json: CString,
+                                            proof_req_json: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_encode_credential_values(command_handle: CommandHandle,
+                                         typed_values_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_decode_credential_value(command_handle: CommandHandle,
+                                        encoded: CString,
+                                        encoding_json: CString,
+                                        cb: Option<ResponseStringCB>) -> Error;
+    pub fn indy_build_predicate_info(command_handle: CommandHandle,
+                                     attr_name: CString,
+                                     p_type: CString,
+                                     value: CString,
+                                     encoding_json: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
 }
 
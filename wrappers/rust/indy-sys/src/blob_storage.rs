@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle};
+use {CString, CVoid, Error, CommandHandle};
 
 extern {
 
@@ -13,5 +13,12 @@ extern {
                                          type_: CString,
                                          config_json: CString,
                                          cb: Option<ResponseI32CB>) -> Error;
+
+    pub fn indy_set_tails_progress_callback(context: *const CVoid,
+                                            progress_cb: Option<TailsProgressCB>) -> Error;
 }
 
+pub type TailsProgressCB = extern fn(context: *const CVoid,
+                                     tails_generated: u32,
+                                     tails_total: u32);
+
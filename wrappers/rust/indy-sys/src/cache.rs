@@ -20,6 +20,13 @@ extern {
                              options_json: CString,
                              cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_resolve_did(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            wallet_handle: WalletHandle,
+                            did: CString,
+                            options_json: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_purge_schema_cache(command_handle: CommandHandle,
                                    wallet_handle: WalletHandle,
                                    options_json: CString,
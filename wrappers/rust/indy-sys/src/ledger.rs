@@ -10,6 +10,12 @@ extern {
                                         request_json: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_submit_request_with_policy(command_handle: CommandHandle,
+                                           pool_handle: PoolHandle,
+                                           request_json: CString,
+                                           read_policy_json: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_submit_request(command_handle: CommandHandle,
                                pool_handle: PoolHandle,
                                request_json: CString,
@@ -110,12 +116,29 @@ extern {
                                                  submitter_did: CString,
                                                  cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_parse_get_validator_info_response(command_handle: CommandHandle,
+                                                  get_validator_info_response: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_build_get_txn_request(command_handle: CommandHandle,
                                       submitter_did: CString,
                                       ledger_type: CString,
                                       seq_no: i32,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                       get_txn_response: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_extract_txn_inclusion_proof(command_handle: CommandHandle,
+                                            get_txn_response: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    pub fn indy_verify_txn_inclusion_proof(command_handle: CommandHandle,
+                                           proof_json: CString,
+                                           nodes_bls_keys_json: CString,
+                                           cb: Option<ResponseBoolCB>) -> Error;
+
     pub fn indy_build_pool_config_request(command_handle: CommandHandle,
                                           submitter_did: CString,
                                           writes: bool,
@@ -14,6 +14,10 @@ extern {
                                  config: CString,
                                  cb: Option<ResponseI32CB>) -> Error;
 
+    pub fn indy_open_pool_registry(command_handle: CommandHandle,
+                                   config: CString,
+                                   cb: Option<ResponseI32CB>) -> Error;
+
     pub fn indy_refresh_pool_ledger(command_handle: CommandHandle,
                                     handle: PoolHandle,
                                     cb: Option<ResponseEmptyCB>) -> Error;
@@ -21,6 +25,10 @@ extern {
     pub fn indy_list_pools(command_handle: CommandHandle,
                            cb: Option<ResponseStringCB>) -> Error;
 
+    pub fn indy_get_pool_status(command_handle: CommandHandle,
+                                handle: PoolHandle,
+                                cb: Option<ResponseStringCB>) -> Error;
+
     pub fn indy_close_pool_ledger(command_handle: CommandHandle,
                                   handle: PoolHandle,
                                   cb: Option<ResponseEmptyCB>) -> Error;
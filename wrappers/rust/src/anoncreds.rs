@@ -15,7 +15,7 @@ use ffi::{ResponseStringStringCB,
           ResponseI32CB,
           ResponseEmptyCB,
           ResponseBoolCB};
-use {CommandHandle, WalletHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
+use {CommandHandle, WalletHandle, PoolHandle, SearchHandle, BlobStorageReaderHandle, TailsWriterHandle};
 use ffi::BlobStorageReaderCfgHandle;
 
 /*
@@ -406,6 +406,172 @@ fn _issuer_create_credential(
     })
 }
 
+/// Create a pool of revocation registries managed by issuer for the given credential definition.
+///
+/// The pool tracks usage of registries in the wallet and picks active registry automatically
+/// in `issuer_create_credential_from_revoc_reg_pool`. The next registry is generated ahead of time
+/// by `issuer_rollover_revoc_reg_pool` and is used only after it is marked published.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `issuer_did`: a DID of the issuer
+/// * `cred_def_id`: id of stored in ledger credential definition
+/// * `config_json`: configuration of revocation registry pool as json:
+///     {
+///         "issuance_type": (optional) type of issuance (ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND),
+///         "max_cred_num": maximum number of credentials the each registry can process (default 100000),
+///         "tag_prefix": (optional) prefix of registry tags ("pool_" by default),
+///         "rollover_threshold": (optional) percentage of registry capacity which usage sets `rollover_needed` flag of the pool (90 by default),
+///     }
+/// * `tails_writer_handle`: handle of blob storage to store tails
+///
+/// # Returns
+/// * `revoc_reg_pool_json`: revocation registry pool json including registries which still need publishing to the ledger
+pub fn issuer_create_revoc_reg_pool(wallet_handle: WalletHandle,
+                                    issuer_did: &str,
+                                    cred_def_id: &str,
+                                    config_json: &str,
+                                    tails_writer_handle: TailsWriterHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_create_revoc_reg_pool(command_handle, wallet_handle, issuer_did, cred_def_id, config_json, tails_writer_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_create_revoc_reg_pool(command_handle: CommandHandle,
+                                 wallet_handle: WalletHandle,
+                                 issuer_did: &str,
+                                 cred_def_id: &str,
+                                 config_json: &str,
+                                 tails_writer_handle: TailsWriterHandle,
+                                 cb: Option<ResponseStringCB>) -> ErrorCode {
+    let issuer_did = c_str!(issuer_did);
+    let cred_def_id = c_str!(cred_def_id);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_revoc_reg_pool(command_handle, wallet_handle, issuer_did.as_ptr(), cred_def_id.as_ptr(), config_json.as_ptr(), tails_writer_handle, cb)
+    })
+}
+
+/// Issue Credential for the given Cred Request using active revocation registry of the pool
+/// created by `issuer_create_revoc_reg_pool`.
+///
+/// Full active registry is replaced by the next one only if it is published,
+/// otherwise `AnoncredsRevocationRegistryFullError` is returned.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_offer_json`: a cred offer created by issuer_create_credential_offer
+/// * `cred_req_json`: a credential request created by prover_create_credential_req
+/// * `cred_values_json`: a credential containing attribute values for each of requested attribute names
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+///
+/// # Returns
+/// * `cred_json`: Credential json containing signed credential values
+/// * `cred_revoc_id`: local id for revocation info
+/// * `revoc_reg_delta_json`: Revocation registry delta json with a newly issued credential
+pub fn issuer_create_credential_from_revoc_reg_pool(wallet_handle: WalletHandle,
+                                                    cred_offer_json: &str,
+                                                    cred_req_json: &str,
+                                                    cred_values_json: &str,
+                                                    blob_storage_reader_handle: BlobStorageReaderHandle) -> Box<dyn Future<Item=(String, Option<String>, Option<String>), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_opt_string_opt_string();
+
+    let err = _issuer_create_credential_from_revoc_reg_pool(command_handle, wallet_handle, cred_offer_json, cred_req_json, cred_values_json, blob_storage_reader_handle, cb);
+
+    ResultHandler::str_optstr_optstr(command_handle, err, receiver)
+}
+
+fn _issuer_create_credential_from_revoc_reg_pool(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 cred_offer_json: &str,
+                                                 cred_req_json: &str,
+                                                 cred_values_json: &str,
+                                                 blob_storage_reader_handle: BlobStorageReaderHandle,
+                                                 cb: Option<ResponseStringStringStringCB>) -> ErrorCode {
+    let cred_offer_json = c_str!(cred_offer_json);
+    let cred_req_json = c_str!(cred_req_json);
+    let cred_values_json = c_str!(cred_values_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_create_credential_from_revoc_reg_pool(command_handle, wallet_handle, cred_offer_json.as_ptr(), cred_req_json.as_ptr(), cred_values_json.as_ptr(), blob_storage_reader_handle, cb)
+    })
+}
+
+/// Generate the next revocation registry of the pool ahead of time (does nothing if the pool already has one).
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition
+/// * `tails_writer_handle`: handle of blob storage to store tails
+///
+/// # Returns
+/// * `revoc_reg_pool_json`: revocation registry pool json including registries which still need publishing to the ledger
+pub fn issuer_rollover_revoc_reg_pool(wallet_handle: WalletHandle, cred_def_id: &str, tails_writer_handle: TailsWriterHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_rollover_revoc_reg_pool(command_handle, wallet_handle, cred_def_id, tails_writer_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_rollover_revoc_reg_pool(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, tails_writer_handle: TailsWriterHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_rollover_revoc_reg_pool(command_handle, wallet_handle, cred_def_id.as_ptr(), tails_writer_handle, cb)
+    })
+}
+
+/// Get revocation registry pool stored in the wallet for the given credential definition.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition
+///
+/// # Returns
+/// * `revoc_reg_pool_json`: revocation registry pool json
+pub fn issuer_get_revoc_reg_pool(wallet_handle: WalletHandle, cred_def_id: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_revoc_reg_pool(command_handle, wallet_handle, cred_def_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_revoc_reg_pool(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_revoc_reg_pool(command_handle, wallet_handle, cred_def_id.as_ptr(), cb)
+    })
+}
+
+/// Mark revocation registry of the pool as published to the ledger.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition
+/// * `rev_reg_id`: id of published revocation registry
+pub fn issuer_mark_revoc_reg_published(wallet_handle: WalletHandle, cred_def_id: &str, rev_reg_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_mark_revoc_reg_published(command_handle, wallet_handle, cred_def_id, rev_reg_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_mark_revoc_reg_published(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, rev_reg_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+    let rev_reg_id = c_str!(rev_reg_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_mark_revoc_reg_published(command_handle, wallet_handle, cred_def_id.as_ptr(), rev_reg_id.as_ptr(), cb)
+    })
+}
+
 /// Revoke a credential identified by a cred_revoc_id (returned by indy_issuer_create_credential).
 ///
 /// The corresponding credential definition and revocation registry must be already
@@ -444,6 +610,44 @@ fn _issuer_revoke_credential(command_handle: CommandHandle,
     })
 }
 
+/// Revoke and unrevoke a batch of credentials identified by cred_revoc_ids (returned by indy_issuer_create_credential).
+///
+/// Revocation registry accumulator is updated for all the given ids at once and stored into the wallet
+/// only if all the ids are processed successfully.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_cfg_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `rev_reg_id`: id of revocation registry stored in wallet
+/// * `revoked_ids_json`: local ids for revocation info of credentials to revoke: [<cred_revoc_id>, ...]
+/// * `unrevoked_ids_json`: local ids for revocation info of credentials to unrevoke: [<cred_revoc_id>, ...]
+///
+/// # Returns
+/// * `revoc_reg_delta_json`: Revocation registry delta json with revoked and unrevoked credentials
+pub fn issuer_update_revocation_registry(wallet_handle: WalletHandle, blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle, rev_reg_id: &str, revoked_ids_json: &str, unrevoked_ids_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_update_revocation_registry(command_handle, wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id, revoked_ids_json, unrevoked_ids_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_update_revocation_registry(command_handle: CommandHandle,
+                                      wallet_handle: WalletHandle,
+                                      blob_storage_reader_cfg_handle: BlobStorageReaderCfgHandle,
+                                      rev_reg_id: &str,
+                                      revoked_ids_json: &str,
+                                      unrevoked_ids_json: &str,
+                                      cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_id = c_str!(rev_reg_id);
+    let revoked_ids_json = c_str!(revoked_ids_json);
+    let unrevoked_ids_json = c_str!(unrevoked_ids_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_update_revocation_registry(command_handle, wallet_handle, blob_storage_reader_cfg_handle, rev_reg_id.as_ptr(), revoked_ids_json.as_ptr(), unrevoked_ids_json.as_ptr(), cb)
+    })
+}
+
 /// Merge two revocation registry deltas (returned by create_credential or revoke_credential) to accumulate common delta.
 /// Send common delta to ledger to reduce the load.
 ///
@@ -496,6 +700,111 @@ fn _prover_create_master_secret(command_handle: CommandHandle, wallet_handle: Wa
     })
 }
 
+/// Exports a master secret stored in the wallet encrypted for the recipient key.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `master_secret_id`: id of master secret to export
+/// * `recipient_vk`: verkey of the recipient
+///
+/// # Returns
+/// Encrypted master secret json
+///     {
+///         "ciphertext": string - base64 encoded encrypted master secret with its id
+///     }
+pub fn prover_export_master_secret(wallet_handle: WalletHandle, master_secret_id: &str, recipient_vk: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_export_master_secret(command_handle, wallet_handle, master_secret_id, recipient_vk, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_export_master_secret(command_handle: CommandHandle, wallet_handle: WalletHandle, master_secret_id: &str, recipient_vk: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let master_secret_id = c_str!(master_secret_id);
+    let recipient_vk = c_str!(recipient_vk);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_export_master_secret(command_handle, wallet_handle, master_secret_id.as_ptr(), recipient_vk.as_ptr(), cb)
+    })
+}
+
+/// Imports a master secret exported by `prover_export_master_secret` into the wallet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `my_vk`: verkey the master secret was encrypted for. The key must be stored in the wallet.
+/// * `encrypted_master_secret_json`: encrypted master secret
+/// * `master_secret_id`: (optional, if not present id of exported master secret will be used) id of imported master secret
+///
+/// # Returns
+/// Id of imported master secret
+pub fn prover_import_master_secret(wallet_handle: WalletHandle, my_vk: &str, encrypted_master_secret_json: &str, master_secret_id: Option<&str>) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_import_master_secret(command_handle, wallet_handle, my_vk, encrypted_master_secret_json, master_secret_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_import_master_secret(command_handle: CommandHandle, wallet_handle: WalletHandle, my_vk: &str, encrypted_master_secret_json: &str, master_secret_id: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let my_vk = c_str!(my_vk);
+    let encrypted_master_secret_json = c_str!(encrypted_master_secret_json);
+    let master_secret_id_str = opt_c_str!(master_secret_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_import_master_secret(command_handle, wallet_handle, my_vk.as_ptr(), encrypted_master_secret_json.as_ptr(), opt_c_ptr!(master_secret_id, master_secret_id_str), cb)
+    })
+}
+
+/// Gets master secrets stored in the wallet together with credentials bound to each of them.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+///
+/// # Returns
+/// List of master secrets
+///     [{
+///         "id": string, - id of master secret
+///         "retired_at": Optional<int>, - time of retirement of master secret
+///         "cred_ids": [string] - ids of credentials bound to master secret
+///     }]
+pub fn prover_get_master_secrets(wallet_handle: WalletHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_get_master_secrets(command_handle, wallet_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_get_master_secrets(command_handle: CommandHandle, wallet_handle: WalletHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_get_master_secrets(command_handle, wallet_handle, cb)
+    })
+}
+
+/// Marks a master secret as retired. Retired master secret can't be used for new credential requests,
+/// but credentials bound to it remain provable.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `master_secret_id`: id of master secret to retire
+pub fn prover_retire_master_secret(wallet_handle: WalletHandle, master_secret_id: &str) -> Box<dyn Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _prover_retire_master_secret(command_handle, wallet_handle, master_secret_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _prover_retire_master_secret(command_handle: CommandHandle, wallet_handle: WalletHandle, master_secret_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let master_secret_id = c_str!(master_secret_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_retire_master_secret(command_handle, wallet_handle, master_secret_id.as_ptr(), cb)
+    })
+}
+
 /// Gets human readable credential by the given id.
 ///
 /// # Arguments
@@ -1235,6 +1544,92 @@ fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHand
     })
 }
 
+/// Creates a proof according to the given proof request choosing credentials from the wallet automatically.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `proof_req_json`: proof request json (see `prover_create_proof`)
+/// * `master_secret_id`: the id of the master secret stored in the wallet
+/// * `schemas_json`: all schemas participating in the proof request (see `prover_create_proof`)
+/// * `credential_defs_json`: all credential definitions participating in the proof request (see `prover_create_proof`)
+/// * `rev_states_json`: all revocation states participating in the proof request (see `prover_create_proof`)
+/// * `options_json`: (optional) selection options
+///     {
+///         "policy": (optional) one of "newest" (default), "oldest", "fewest_credentials", "prefer_non_revocable",
+///         "self_attested_attributes": (optional) {"<attr_referent>": <self attested value>}
+///     }
+///
+/// # Returns
+/// * `proof_json` - created proof (see `prover_create_proof`)
+/// * `requested_credentials_json` - credentials chosen for the proof (see `prover_create_proof`)
+pub fn prover_create_proof_auto(wallet_handle: WalletHandle, proof_req_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, options_json: Option<&str>) -> Box<dyn Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _prover_create_proof_auto(command_handle, wallet_handle, proof_req_json, master_secret_id, schemas_json, credential_defs_json, rev_states_json, options_json, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _prover_create_proof_auto(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, rev_states_json: &str, options_json: Option<&str>, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let proof_req_json = c_str!(proof_req_json);
+    let master_secret_id = c_str!(master_secret_id);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_states_json = c_str!(rev_states_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_create_proof_auto(command_handle, wallet_handle, proof_req_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_states_json.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Creates a proof according to the given proof request using revocation states stored in the wallet.
+/// Missing revocation states are built from provided revocation registry deltas by updating
+/// the last stored state and are stored in the wallet for further proofs.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `proof_req_json`: proof request json (see `prover_create_proof`)
+/// * `requested_credentials_json`: either a credential or self-attested attribute for each requested attribute (see `prover_create_proof`)
+/// * `master_secret_id`: the id of the master secret stored in the wallet
+/// * `schemas_json`: all schemas participating in the proof request (see `prover_create_proof`)
+/// * `credential_defs_json`: all credential definitions participating in the proof request (see `prover_create_proof`)
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+/// * `rev_reg_defs_json`: revocation registry definitions of requested credentials
+///     {
+///         "rev_reg_def1_id": <rev_reg_def1>,
+///     }
+/// * `rev_reg_deltas_json`: revocation registry deltas which cover the whole registry existence time till requested timestamps
+///     {
+///         "rev_reg_def1_id": {
+///             "timestamp1": <rev_reg_delta1>,
+///         },
+///     }
+///
+/// # Returns
+/// * `proof_json` - created proof (see `prover_create_proof`)
+pub fn prover_create_proof_with_revocation_cache(wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_defs_json: &str, rev_reg_deltas_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_create_proof_with_revocation_cache(command_handle, wallet_handle, proof_req_json, requested_credentials_json, master_secret_id, schemas_json, credential_defs_json, blob_storage_reader_handle, rev_reg_defs_json, rev_reg_deltas_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_create_proof_with_revocation_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, proof_req_json: &str, requested_credentials_json: &str, master_secret_id: &str, schemas_json: &str, credential_defs_json: &str, blob_storage_reader_handle: BlobStorageReaderHandle, rev_reg_defs_json: &str, rev_reg_deltas_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_req_json = c_str!(proof_req_json);
+    let requested_credentials_json = c_str!(requested_credentials_json);
+    let master_secret_id = c_str!(master_secret_id);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_reg_deltas_json = c_str!(rev_reg_deltas_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_create_proof_with_revocation_cache(command_handle, wallet_handle, proof_req_json.as_ptr(), requested_credentials_json.as_ptr(), master_secret_id.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), blob_storage_reader_handle, rev_reg_defs_json.as_ptr(), rev_reg_deltas_json.as_ptr(), cb)
+    })
+}
+
 
 /// Verifies a proof (of multiple credential).
 /// All required schemas, public keys and revocation registries must be provided.
@@ -1345,6 +1740,77 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof (of multiple credential) reporting the outcome for every requested referent,
+/// every sub proof and the cryptographic proof instead of stopping on the first rejected check.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json (see `verifier_verify_proof`)
+/// * `schemas_json`: all schemas participating in the proof (see `verifier_verify_proof`)
+/// * `credential_defs_json`: all credential definitions participating in the proof (see `verifier_verify_proof`)
+/// * `rev_reg_defs_json`: all revocation registry definitions participating in the proof (see `verifier_verify_proof`)
+/// * `rev_regs_json`: all revocation registries participating in the proof (see `verifier_verify_proof`)
+///
+/// # Returns
+/// * `verification_report_json`: {"valid": bool, "attributes": {}, "predicates": {}, "identifiers": [], "revocation": [], "crypto": {}}
+///   (see `indy_verifier_verify_proof_with_report` in libindy for the details)
+pub fn verifier_verify_proof_with_report(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_with_report(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_report(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_report(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies a proof (of multiple credential) resolving all the entities it refers to from the ledger.
+///
+/// Schemas and credential definitions are received through the cache, revocation registry definitions
+/// and revocation registries (for timestamps specified in the proof) are received from the ledger.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by Pool::open_ledger).
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet) used to cache received ledger entities.
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json (see `verifier_verify_proof`)
+/// * `options_json`:
+///  {
+///    submitterDid: (optional, string) DID to use as submitter of ledger read requests.
+///    cache: (optional, object) cache options applied to all received ledger entities.
+///  }
+///
+/// # Returns
+/// * `verification_result_json`: {"valid": bool, "failures": [{"check": string, "referent": Option<string>, "sub_proof_index": Option<int>, "message": string}]}
+pub fn verifier_verify_proof_with_ledger(pool_handle: PoolHandle, wallet_handle: WalletHandle, proof_request_json: &str, proof_json: &str, options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_with_ledger(command_handle, pool_handle, wallet_handle, proof_request_json, proof_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_ledger(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, proof_request_json: &str, proof_json: &str, options_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_ledger(command_handle, pool_handle, wallet_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), options_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential that corresponds to a particular time.
 ///
@@ -1431,6 +1897,54 @@ fn _update_revocation_state(command_handle: CommandHandle, blob_storage_reader_h
     })
 }
 
+/// Refreshes all revocation states stored in the wallet to the current ledger state.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `blob_storage_reader_handle`: configuration of blob storage reader handle that will allow to read revocation tails
+///
+/// # Returns
+/// * `refreshed_json`: list of refreshed revocation states
+///     [{"rev_reg_id": string, "cred_rev_id": string, "timestamp": integer}]
+pub fn prover_refresh_revocation_states(pool_handle: PoolHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_refresh_revocation_states(command_handle, pool_handle, wallet_handle, blob_storage_reader_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_refresh_revocation_states(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, blob_storage_reader_handle: BlobStorageReaderHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_refresh_revocation_states(command_handle, pool_handle, wallet_handle, blob_storage_reader_handle, cb)
+    })
+}
+
+/// Deletes revocation states stored in the wallet which are older than the given timestamp.
+/// The latest stored state of every credential is always kept.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by Wallet::open_wallet).
+/// * `timestamp`: states of revocation registry before this time (in seconds since Unix Epoch) are deleted
+///
+/// # Returns
+/// * `pruned_json`: list of deleted revocation states
+///     [{"rev_reg_id": string, "cred_rev_id": string, "timestamp": integer}]
+pub fn prover_prune_revocation_states(wallet_handle: WalletHandle, timestamp: u64) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _prover_prune_revocation_states(command_handle, wallet_handle, timestamp, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _prover_prune_revocation_states(command_handle: CommandHandle, wallet_handle: WalletHandle, timestamp: u64, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        anoncreds::indy_prover_prune_revocation_states(command_handle, wallet_handle, timestamp, cb)
+    })
+}
+
 /// Generates 80-bit numbers that can be used as a nonce for proof request.
 ///
 /// # Arguments
@@ -1494,3 +2008,180 @@ fn _to_unqualified(command_handle: CommandHandle, entity: &str, cb: Option<Respo
         anoncreds::indy_to_unqualified(command_handle, entity.as_ptr(), cb)
     })
 }
+
+/// Converts credential into W3C Verifiable Credential data model.
+///
+/// # Arguments
+/// * `cred_json`: credential json returned by `issuer_create_credential`
+///
+/// # Returns
+/// * `w3c_cred_json`: credential in W3C Verifiable Credential data model
+pub fn credential_to_w3c(cred_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _credential_to_w3c(command_handle, cred_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _credential_to_w3c(command_handle: CommandHandle, cred_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_json = c_str!(cred_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_credential_to_w3c(command_handle, cred_json.as_ptr(), cb)
+    })
+}
+
+/// Converts credential in W3C Verifiable Credential data model back into anoncreds credential.
+///
+/// # Arguments
+/// * `w3c_cred_json`: credential returned by `credential_to_w3c`
+///
+/// # Returns
+/// * `cred_json`: credential json
+pub fn credential_from_w3c(w3c_cred_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _credential_from_w3c(command_handle, w3c_cred_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _credential_from_w3c(command_handle: CommandHandle, w3c_cred_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let w3c_cred_json = c_str!(w3c_cred_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_credential_from_w3c(command_handle, w3c_cred_json.as_ptr(), cb)
+    })
+}
+
+/// Converts proof into W3C Verifiable Presentation data model.
+///
+/// # Arguments
+/// * `proof_json`: proof json returned by `prover_create_proof`
+/// * `proof_req_json`: proof request json the proof was created for
+///
+/// # Returns
+/// * `w3c_presentation_json`: proof in W3C Verifiable Presentation data model
+pub fn proof_to_w3c_presentation(proof_json: &str, proof_req_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _proof_to_w3c_presentation(command_handle, proof_json, proof_req_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _proof_to_w3c_presentation(command_handle: CommandHandle, proof_json: &str, proof_req_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_json = c_str!(proof_json);
+    let proof_req_json = c_str!(proof_req_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_proof_to_w3c_presentation(command_handle, proof_json.as_ptr(), proof_req_json.as_ptr(), cb)
+    })
+}
+
+/// Converts proof in W3C Verifiable Presentation data model back into anoncreds proof.
+///
+/// # Arguments
+/// * `w3c_presentation_json`: presentation returned by `proof_to_w3c_presentation`
+/// * `proof_req_json`: proof request json the proof was created for
+///
+/// # Returns
+/// * `proof_json`: proof json that can be verified by `verifier_verify_proof`
+pub fn proof_from_w3c_presentation(w3c_presentation_json: &str, proof_req_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _proof_from_w3c_presentation(command_handle, w3c_presentation_json, proof_req_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _proof_from_w3c_presentation(command_handle: CommandHandle, w3c_presentation_json: &str, proof_req_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let w3c_presentation_json = c_str!(w3c_presentation_json);
+    let proof_req_json = c_str!(proof_req_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_proof_from_w3c_presentation(command_handle, w3c_presentation_json.as_ptr(), proof_req_json.as_ptr(), cb)
+    })
+}
+
+/// Encodes credential attribute values according to their types.
+///
+/// # Arguments
+/// * `typed_values_json`: credential attribute values with optional encoding
+///     {
+///         "attr1" : string, // raw value which is encoded as "string"
+///         "attr2" : {"value": string | number | boolean, "encoding": Optional<{"type": string, "scale": Optional<int>}>},
+///     }
+///
+/// # Returns
+/// * `cred_values_json`: credential values json that can be passed to `issuer_create_credential`
+pub fn encode_credential_values(typed_values_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _encode_credential_values(command_handle, typed_values_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _encode_credential_values(command_handle: CommandHandle, typed_values_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let typed_values_json = c_str!(typed_values_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_encode_credential_values(command_handle, typed_values_json.as_ptr(), cb)
+    })
+}
+
+/// Decodes encoded credential attribute value back to raw value.
+///
+/// # Arguments
+/// * `encoded`: encoded value
+/// * `encoding_json`: encoding of value {"type": string, "scale": Optional<int>}
+///
+/// # Returns
+/// * `raw`: raw value
+pub fn decode_credential_value(encoded: &str, encoding_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _decode_credential_value(command_handle, encoded, encoding_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _decode_credential_value(command_handle: CommandHandle, encoded: &str, encoding_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let encoded = c_str!(encoded);
+    let encoding_json = c_str!(encoding_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_decode_credential_value(command_handle, encoded.as_ptr(), encoding_json.as_ptr(), cb)
+    })
+}
+
+/// Builds predicate info for proof request from typed value.
+///
+/// # Arguments
+/// * `attr_name`: attribute name
+/// * `p_type`: predicate type (">=", ">", "<=", "<")
+/// * `value`: raw predicate value
+/// * `encoding_json`: encoding of attribute {"type": string, "scale": Optional<int>}
+///
+/// # Returns
+/// * `predicate_info_json`: predicate info that can be used in `requested_predicates` of proof request
+pub fn build_predicate_info(attr_name: &str, p_type: &str, value: &str, encoding_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_predicate_info(command_handle, attr_name, p_type, value, encoding_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_predicate_info(command_handle: CommandHandle, attr_name: &str, p_type: &str, value: &str, encoding_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let attr_name = c_str!(attr_name);
+    let p_type = c_str!(p_type);
+    let value = c_str!(value);
+    let encoding_json = c_str!(encoding_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_build_predicate_info(command_handle, attr_name.as_ptr(), p_type.as_ptr(), value.as_ptr(), encoding_json.as_ptr(), cb)
+    })
+}
@@ -5,7 +5,9 @@ use {ErrorCode, IndyError};
 use std::ffi::CString;
 
 use ffi::blob_storage;
-use ffi::ResponseI32CB;
+use ffi::{CVoid, ResponseI32CB};
+
+use std::ptr::null;
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
 use {IndyHandle, CommandHandle};
@@ -39,3 +41,29 @@ fn _open_writer(command_handle: CommandHandle, xtype: &str, config_json: &str, c
 
     ErrorCode::from(unsafe { blob_storage::indy_open_blob_storage_writer(command_handle, xtype.as_ptr(), config_json.as_ptr(), cb) })
 }
+
+static mut TAILS_PROGRESS_CB: Option<fn(u32, u32)> = None;
+
+/// Set callback that reports progress of tails generation in `anoncreds::issuer_create_and_store_revoc_reg`.
+///
+/// # Arguments
+/// * `progress_cb` - (optional) function that receives number of generated tails and total number of tails.
+///     Pass `None` to stop progress reporting.
+pub fn set_tails_progress_callback(progress_cb: Option<fn(u32, u32)>) -> Result<(), IndyError> {
+    unsafe { TAILS_PROGRESS_CB = progress_cb; }
+
+    let res = ErrorCode::from(unsafe {
+        blob_storage::indy_set_tails_progress_callback(null(), progress_cb.map(|_| _tails_progress_cb as blob_storage::TailsProgressCB))
+    });
+
+    match res {
+        ErrorCode::Success => Ok(()),
+        err => Err(IndyError::new(err))
+    }
+}
+
+extern fn _tails_progress_cb(_context: *const CVoid, tails_generated: u32, tails_total: u32) {
+    if let Some(cb) = unsafe { TAILS_PROGRESS_CB } {
+        cb(tails_generated, tails_total)
+    }
+}
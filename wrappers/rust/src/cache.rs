@@ -128,6 +128,58 @@ pub fn _get_cred_def(command_handle: CommandHandle,
     )
 }
 
+/// Resolve DID into W3C DID Core compliant DID Document.
+/// DID Document is assembled from NYM transaction and `endpoint` and `diddocContent` attributes of the DID.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `did` - DID to resolve. Either unqualified (treated as `did:sov`) or fully qualified DID.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// # Returns
+/// DID resolution result json.
+/// {
+///     didDocument: W3C DID Document
+///     didResolutionMetadata: resolution metadata
+///     didDocumentMetadata: DID Document metadata
+/// }
+pub fn resolve_did(pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: &str,
+                   options_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, wallet_handle, did, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle,
+                pool_handle: PoolHandle,
+                wallet_handle: WalletHandle,
+                did: &str,
+                options_json: &str,
+                cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_resolve_did(command_handle, pool_handle, wallet_handle, did.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
 /// Purge schema cache.
 ///
 /// EXPERIMENTAL
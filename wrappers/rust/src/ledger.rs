@@ -7,7 +7,8 @@ use std::ptr::null;
 use futures::Future;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseBoolCB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
@@ -74,6 +75,40 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes read request message to validator pool using the given read policy
+/// instead of the one the pool was opened with.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json.
+/// * `read_policy_json` - Read policy json:
+/// {
+///     "retries": int (optional) - the number of additional rounds for a read request
+///         which can't be completed by queried nodes (0 by default).
+///     "retry_backoff": int (optional) - delay before the first retry in ms (500 by default).
+///     "hedge_after": int (optional) - if there is no acceptable reply after this delay (in ms)
+///         one more node is queried (disabled by default).
+///     "full_consensus": bool (optional) - require consensus of pool nodes
+///         even if a reply contains valid state proof (false by default).
+/// }
+///
+/// # Returns
+/// Request result as json.
+pub fn submit_request_with_policy(pool_handle: PoolHandle, request_json: &str, read_policy_json: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_with_policy(command_handle, pool_handle, request_json, read_policy_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_with_policy(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, read_policy_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let read_policy_json = c_str!(read_policy_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_request_with_policy(command_handle, pool_handle, request_json.as_ptr(), read_policy_json.as_ptr(), cb) })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<dyn Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -302,6 +337,78 @@ fn _build_get_txn_request(command_handle: CommandHandle, submitter_did: Option<&
     ErrorCode::from(unsafe { ledger::indy_build_get_txn_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), opt_c_ptr!(ledger_type, ledger_type_str), seq_no, cb) })
 }
 
+/// Parse a GET_TXN response to get the transaction in a typed form independent of the ledger transaction format version.
+///
+/// # Arguments
+/// * `get_txn_response` - response of GET_TXN request.
+///
+/// # Returns
+/// Transaction json:
+/// {
+///     "ver": string, "type": string,
+///     "typeName": string - NODE, NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY,
+///                 AUTH_RULE, AUTH_RULES, TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML or CUSTOM,
+///     "data": object - transaction data corresponding to "typeName",
+///     "metadata": {"seqNo", "txnTime", "txnId", "from", "reqId", "digest", "payloadDigest", "endorser", "taaAcceptance"}
+/// }
+pub fn parse_get_txn_response(get_txn_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_txn_response(command_handle, get_txn_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_txn_response(command_handle: CommandHandle, get_txn_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_response = c_str!(get_txn_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_txn_response(command_handle, get_txn_response.as_ptr(), cb) })
+}
+
+/// Extracts an inclusion proof of the transaction from a GET_TXN response.
+///
+/// # Arguments
+/// * `get_txn_response` - response of GET_TXN request.
+///
+/// # Returns
+/// Transaction inclusion proof json which can be verified later by `verify_txn_inclusion_proof`.
+pub fn extract_txn_inclusion_proof(get_txn_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _extract_txn_inclusion_proof(command_handle, get_txn_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _extract_txn_inclusion_proof(command_handle: CommandHandle, get_txn_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_response = c_str!(get_txn_response);
+
+    ErrorCode::from(unsafe { ledger::indy_extract_txn_inclusion_proof(command_handle, get_txn_response.as_ptr(), cb) })
+}
+
+/// Verifies an inclusion proof of the transaction offline.
+///
+/// # Arguments
+/// * `proof_json` - transaction inclusion proof as returned by `extract_txn_inclusion_proof`.
+/// * `nodes_bls_keys_json` - known BLS keys of pool nodes: `{<node alias>: <base58 encoded BLS key>}`.
+///
+/// # Returns
+/// true - if proof is valid, false - otherwise
+pub fn verify_txn_inclusion_proof(proof_json: &str, nodes_bls_keys_json: &str) -> Box<dyn Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_txn_inclusion_proof(command_handle, proof_json, nodes_bls_keys_json, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_txn_inclusion_proof(command_handle: CommandHandle, proof_json: &str, nodes_bls_keys_json: &str, cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let proof_json = c_str!(proof_json);
+    let nodes_bls_keys_json = c_str!(nodes_bls_keys_json);
+
+    ErrorCode::from(unsafe { ledger::indy_verify_txn_inclusion_proof(command_handle, proof_json.as_ptr(), nodes_bls_keys_json.as_ptr(), cb) })
+}
+
 /// Builds an ATTRIB request. Request to add attribute to a NYM record.
 ///
 /// # Arguments
@@ -610,6 +717,31 @@ fn _build_get_validator_info_request(command_handle: CommandHandle, submitter_di
     })
 }
 
+/// Parse a GET_VALIDATOR_INFO response to get typed information about pool nodes.
+///
+/// # Arguments
+/// * `get_validator_info_response` - response of GET_VALIDATOR_INFO request
+///     (map of node aliases to node replies as returned by submit_action).
+///
+/// # Returns
+/// Validator info json: map of node aliases to `{"validator_info": {..}}`
+/// or `{"error": <"timeout" or reason of rejection>}`.
+pub fn parse_get_validator_info_response(get_validator_info_response: &str) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_validator_info_response(command_handle, get_validator_info_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_validator_info_response(command_handle: CommandHandle, get_validator_info_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_validator_info_response = c_str!(get_validator_info_response);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_parse_get_validator_info_response(command_handle, get_validator_info_response.as_ptr(), cb)
+    })
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// # Arguments
@@ -77,6 +77,41 @@ fn _open_pool_ledger(command_handle: CommandHandle, pool_name: &str, config: Opt
     ErrorCode::from(unsafe { pool::indy_open_pool_ledger(command_handle, pool_name.as_ptr(), opt_c_ptr!(config, config_str), cb) })
 }
 
+/// Opens a registry of pool ledgers that routes requests to different networks by DID method namespace.
+///
+/// Pools of the registry are opened lazily on the first request that targets their network.
+/// Pool ledger configurations of all networks must be previously created
+/// with indy_create_pool_ledger_config method.
+///
+/// # Arguments
+/// * `config` - Registry configuration json.
+/// {
+///     "networks": {
+///         "<DID method namespace>": {  (example: "did:sov", "did:indy:sovrin", "did:indy:sovrin:staging")
+///             "pool_name": string, name of the pool ledger configuration.
+///             "config": <pool open config> (optional), runtime pool configuration json (see open_pool_ledger).
+///         },
+///         ...
+///     },
+///     "default": string (optional), namespace of the network to use for unqualified identifiers.
+/// }
+///
+/// # Returns
+/// Handle to opened pool registry that can be used instead of a pool handle.
+pub fn open_pool_registry(config: &str) -> Box<dyn Future<Item=CommandHandle, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+
+    let err = _open_pool_registry(command_handle, config, cb);
+
+    ResultHandler::handle(command_handle, err, receiver)
+}
+
+fn _open_pool_registry(command_handle: CommandHandle, config: &str, cb: Option<ResponseI32CB>) -> ErrorCode {
+    let config = c_str!(config);
+
+    ErrorCode::from(unsafe { pool::indy_open_pool_registry(command_handle, config.as_ptr(), cb) })
+}
+
 /// Refreshes a local copy of a pool ledger and updates pool nodes connections.
 ///
 /// # Arguments
@@ -106,6 +141,26 @@ fn _list_pools(command_handle: CommandHandle, cb: Option<ResponseStringCB>) -> E
     ErrorCode::from(unsafe { pool::indy_list_pools(command_handle, cb) })
 }
 
+/// Returns status of opened pool: known nodes, their connection state and the pool ledger.
+///
+/// # Arguments
+/// * `handle` - pool handle returned by open_ledger.
+///
+/// # Returns
+/// Pool status json with `name`, `ledger` (`size` and merkle `root`), `last_catchup` time
+/// and `nodes` (addresses, connection state and health of each node).
+pub fn get_pool_status(pool_handle: PoolHandle) -> Box<dyn Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_status(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_status(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_get_pool_status(command_handle, pool_handle, cb) })
+}
+
 /// Closes opened pool ledger, opened nodes connections and frees allocated resources.
 ///
 /// # Arguments